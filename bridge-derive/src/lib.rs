@@ -1,7 +1,8 @@
-use darling::FromDeriveInput;
+use darling::{FromDeriveInput, FromField};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use sha2::{Digest, Sha256};
+use syn::{Data, DeriveInput, Fields};
 
 #[derive(FromDeriveInput, Default)]
 #[darling(default, attributes(bridge_pack), forward_attrs(allow, doc, cfg))]
@@ -21,17 +22,59 @@ fn impl_derive_bridge_pack(input: syn::DeriveInput) -> TokenStream {
 
     let len = opts.length;
 
+    // First 8 bytes of sha256("account:<crate>:<Name>"), the crate name tells apart
+    // account types sharing a name across the bridge programs
+    let crate_name = std::env::var("CARGO_PKG_NAME").expect("Cargo package name");
+    let hash = Sha256::digest(format!("account:{}:{}", crate_name, ident).as_bytes());
+    let discriminator = &hash[..8];
+
     let output = quote! {
+        impl #ident {
+            /// Leading bytes of the account data, usable in `memcmp` filters
+            pub const DISCRIMINATOR: [u8; 8] = [#(#discriminator),*];
+
+            /// Like `unpack_unchecked`, but also accepts data written ahead of the first `pack`,
+            /// e.g. a proposal before its finalization, which has a zeroed discriminator
+            pub fn unpack_undiscriminated(src: &[u8]) -> Result<Self, ProgramError> {
+                if src.len() != <Self as Pack>::LEN {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                let (discriminator, mut src) = src.split_at(8);
+
+                if discriminator != Self::DISCRIMINATOR && discriminator != [0; 8] {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
+                let unpacked = Self::deserialize(&mut src)?;
+                Ok(unpacked)
+            }
+        }
+
         impl Pack for #ident {
-            const LEN: usize = #len;
+            const LEN: usize = 8 + #len;
 
             fn pack_into_slice(&self, dst: &mut [u8]) {
-                let mut data = self.try_to_vec().unwrap();
+                let (discriminator, dst) = dst.split_at_mut(8);
+                discriminator.copy_from_slice(&Self::DISCRIMINATOR);
+
+                let data = self.try_to_vec().unwrap();
                 let (left, _) = dst.split_at_mut(data.len());
                 left.copy_from_slice(&data);
             }
 
-            fn unpack_from_slice(mut src: &[u8]) -> Result<Self, ProgramError> {
+            fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+                if src.len() < 8 {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                let (discriminator, mut src) = src.split_at(8);
+
+                // Only a fully zeroed account may lack the discriminator, it hasn't been packed
+                // yet and is left uninitialized
+                let is_zeroed = discriminator == [0; 8] && src.iter().all(|byte| *byte == 0);
+                if discriminator != Self::DISCRIMINATOR && !is_zeroed {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
                 let unpacked = Self::deserialize(&mut src)?;
                 Ok(unpacked)
             }
@@ -39,3 +82,109 @@ fn impl_derive_bridge_pack(input: syn::DeriveInput) -> TokenStream {
     };
     output
 }
+
+#[derive(FromField, Default)]
+#[darling(default, attributes(account))]
+struct AccountOpts {
+    signer: bool,
+    writable: bool,
+    // Expected program id of an executable account
+    program: Option<syn::Expr>,
+    // Expected owner of the account
+    owner: Option<syn::Expr>,
+    // Seeds of the program address, may refer to `program_id` and preceding accounts
+    seeds: Option<syn::Expr>,
+}
+
+#[proc_macro_derive(Accounts, attributes(account))]
+pub fn derive_accounts(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    impl_derive_accounts(input).into()
+}
+
+fn impl_derive_accounts(input: syn::DeriveInput) -> TokenStream {
+    let DeriveInput { ident, data, .. } = input;
+
+    let fields = match data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => fields.named,
+            _ => panic!("Accounts can be derived only for structs with named fields"),
+        },
+        _ => panic!("Accounts can be derived only for structs"),
+    };
+
+    let mut parse = Vec::new();
+    let mut names = Vec::new();
+
+    for field in fields {
+        let opts = AccountOpts::from_field(&field).expect("Wrong options");
+        let name = field.ident.expect("Named field");
+
+        parse.push(quote! {
+            let #name = next_account_info(account_info_iter)?;
+        });
+
+        if opts.signer {
+            parse.push(quote! {
+                if !#name.is_signer {
+                    return Err(ProgramError::MissingRequiredSignature);
+                }
+            });
+        }
+
+        if opts.writable {
+            parse.push(quote! {
+                if !#name.is_writable {
+                    return Err(ProgramError::InvalidArgument);
+                }
+            });
+        }
+
+        if let Some(program) = opts.program {
+            parse.push(quote! {
+                if *#name.key != #program {
+                    return Err(ProgramError::IncorrectProgramId);
+                }
+            });
+        }
+
+        if let Some(owner) = opts.owner {
+            parse.push(quote! {
+                if *#name.owner != #owner {
+                    return Err(ProgramError::IllegalOwner);
+                }
+            });
+        }
+
+        if let Some(seeds) = opts.seeds {
+            parse.push(quote! {
+                {
+                    let seeds: &[&[u8]] = &#seeds;
+                    let (pubkey, _) =
+                        ::solana_program::pubkey::Pubkey::find_program_address(seeds, program_id);
+                    if pubkey != *#name.key {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+                }
+            });
+        }
+
+        names.push(name);
+    }
+
+    let output = quote! {
+        impl<'a, 'info> Accounts<'a, 'info> for #ident<'a, 'info> {
+            fn try_accounts(
+                program_id: &::solana_program::pubkey::Pubkey,
+                account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'info>>,
+            ) -> Result<Self, ProgramError> {
+                let _ = program_id;
+
+                #(#parse)*
+
+                Ok(Self { #(#names),* })
+            }
+        }
+    };
+    output
+}
@@ -0,0 +1,188 @@
+use borsh::BorshDeserialize;
+use bridge_utils::state::ACCOUNT_DISCRIMINATOR_LEN;
+
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+/// Raw account update as delivered by a Geyser plugin or an RPC `accountSubscribe` stream
+#[derive(Debug, Clone)]
+pub struct AccountUpdate {
+    pub pubkey: Pubkey,
+    pub owner: Pubkey,
+    pub slot: u64,
+    pub data: Vec<u8>,
+}
+
+/// Typed view of an account owned by one of the bridge programs
+#[derive(Debug)]
+pub enum BridgeAccount {
+    RoundLoaderSettings(round_loader::Settings),
+    RelayRound(round_loader::RelayRound),
+    RelayRoundProposal(round_loader::RelayRoundProposal),
+    RelayRoundDeltaProposal(round_loader::RelayRoundDeltaProposal),
+    UpgradeProposal(round_loader::UpgradeProposal),
+    ProposalVote(round_loader::ProposalVote),
+    RoundDigest(round_loader::RoundDigest),
+    RoundStats(round_loader::RoundStats),
+    EventConfiguration(round_loader::EventConfiguration),
+    TokenProxySettings(token_proxy::Settings),
+    TokenSettings(token_proxy::TokenSettings),
+    MultiVault(token_proxy::MultiVault),
+    DepositMultiTokenEver(token_proxy::DepositMultiTokenEver),
+    DepositMultiTokenSol(token_proxy::DepositMultiTokenSol),
+    DepositMultiTokenBatch(token_proxy::DepositMultiTokenBatch),
+    WithdrawalMultiTokenEver(token_proxy::WithdrawalMultiTokenEver),
+    WithdrawalMultiTokenSol(token_proxy::WithdrawalMultiTokenSol),
+    WithdrawalMultiTokenBundle(token_proxy::WithdrawalMultiTokenBundle),
+    SettingsHistory(token_proxy::SettingsHistory),
+    SettingsHistoryPage(token_proxy::SettingsHistoryPage),
+    VaultRotation(token_proxy::VaultRotation),
+    LpPosition(token_proxy::LpPosition),
+    DepositIndex(token_proxy::DepositIndex),
+    DepositIndexPage(token_proxy::DepositIndexPage),
+    RewardConfig(token_proxy::RewardConfig),
+    RelayRoundStats(token_proxy::RelayRoundStats),
+    RoundReward(token_proxy::RoundReward),
+    EventIndex(token_proxy::EventIndex),
+    TokenWhitelist(token_proxy::TokenWhitelist),
+}
+
+#[derive(BorshDeserialize)]
+struct AccountHeader {
+    is_initialized: bool,
+}
+
+/// Decode account data owned by the Round Loader or Token Proxy program.
+///
+/// Returns `None` for accounts that are not owned by the bridge programs and for
+/// closed (zeroed) accounts.
+pub fn decode_account(owner: &Pubkey, data: &[u8]) -> Result<Option<BridgeAccount>, ProgramError> {
+    if *owner != round_loader::id() && *owner != token_proxy::id() {
+        return Ok(None);
+    }
+
+    let discriminator = match data.get(..ACCOUNT_DISCRIMINATOR_LEN) {
+        Some(discriminator) => <[u8; ACCOUNT_DISCRIMINATOR_LEN]>::try_from(discriminator)
+            .map_err(|_| ProgramError::InvalidAccountData)?,
+        None => return Ok(None),
+    };
+
+    match AccountHeader::deserialize(&mut &data[ACCOUNT_DISCRIMINATOR_LEN..]) {
+        Ok(header) if header.is_initialized => {}
+        _ => return Ok(None),
+    };
+
+    let account = if *owner == round_loader::id() {
+        match discriminator {
+            round_loader::Settings::DISCRIMINATOR => {
+                BridgeAccount::RoundLoaderSettings(round_loader::Settings::unpack_from_slice(data)?)
+            }
+            round_loader::RelayRound::DISCRIMINATOR => {
+                BridgeAccount::RelayRound(round_loader::RelayRound::unpack_from_slice(data)?)
+            }
+            round_loader::RelayRoundProposal::DISCRIMINATOR => BridgeAccount::RelayRoundProposal(
+                round_loader::RelayRoundProposal::unpack_from_slice(data)?,
+            ),
+            round_loader::RelayRoundDeltaProposal::DISCRIMINATOR => {
+                BridgeAccount::RelayRoundDeltaProposal(
+                    round_loader::RelayRoundDeltaProposal::unpack_from_slice(data)?,
+                )
+            }
+            round_loader::UpgradeProposal::DISCRIMINATOR => BridgeAccount::UpgradeProposal(
+                round_loader::UpgradeProposal::unpack_from_slice(data)?,
+            ),
+            round_loader::ProposalVote::DISCRIMINATOR => {
+                BridgeAccount::ProposalVote(round_loader::ProposalVote::unpack_from_slice(data)?)
+            }
+            round_loader::RoundDigest::DISCRIMINATOR => {
+                BridgeAccount::RoundDigest(round_loader::RoundDigest::unpack_from_slice(data)?)
+            }
+            round_loader::RoundStats::DISCRIMINATOR => {
+                BridgeAccount::RoundStats(round_loader::RoundStats::unpack_from_slice(data)?)
+            }
+            round_loader::EventConfiguration::DISCRIMINATOR => BridgeAccount::EventConfiguration(
+                round_loader::EventConfiguration::unpack_from_slice(data)?,
+            ),
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+    } else {
+        match discriminator {
+            token_proxy::Settings::DISCRIMINATOR => {
+                BridgeAccount::TokenProxySettings(token_proxy::Settings::unpack_from_slice(data)?)
+            }
+            token_proxy::TokenSettings::DISCRIMINATOR => {
+                BridgeAccount::TokenSettings(token_proxy::TokenSettings::unpack_from_slice(data)?)
+            }
+            token_proxy::MultiVault::DISCRIMINATOR => {
+                BridgeAccount::MultiVault(token_proxy::MultiVault::unpack_from_slice(data)?)
+            }
+            token_proxy::DepositMultiTokenEver::DISCRIMINATOR => {
+                BridgeAccount::DepositMultiTokenEver(
+                    token_proxy::DepositMultiTokenEver::unpack_from_slice(data)?,
+                )
+            }
+            token_proxy::DepositMultiTokenSol::DISCRIMINATOR => {
+                BridgeAccount::DepositMultiTokenSol(
+                    token_proxy::DepositMultiTokenSol::unpack_from_slice(data)?,
+                )
+            }
+            token_proxy::DepositMultiTokenBatch::DISCRIMINATOR => {
+                BridgeAccount::DepositMultiTokenBatch(
+                    token_proxy::DepositMultiTokenBatch::unpack_from_slice(data)?,
+                )
+            }
+            token_proxy::WithdrawalMultiTokenEver::DISCRIMINATOR => {
+                BridgeAccount::WithdrawalMultiTokenEver(
+                    token_proxy::WithdrawalMultiTokenEver::unpack_from_slice(data)?,
+                )
+            }
+            token_proxy::WithdrawalMultiTokenSol::DISCRIMINATOR => {
+                BridgeAccount::WithdrawalMultiTokenSol(
+                    token_proxy::WithdrawalMultiTokenSol::unpack_from_slice(data)?,
+                )
+            }
+            token_proxy::WithdrawalMultiTokenBundle::DISCRIMINATOR => {
+                BridgeAccount::WithdrawalMultiTokenBundle(
+                    token_proxy::WithdrawalMultiTokenBundle::unpack_from_slice(data)?,
+                )
+            }
+            token_proxy::SettingsHistory::DISCRIMINATOR => BridgeAccount::SettingsHistory(
+                token_proxy::SettingsHistory::unpack_from_slice(data)?,
+            ),
+            token_proxy::SettingsHistoryPage::DISCRIMINATOR => BridgeAccount::SettingsHistoryPage(
+                token_proxy::SettingsHistoryPage::unpack_from_slice(data)?,
+            ),
+            token_proxy::VaultRotation::DISCRIMINATOR => {
+                BridgeAccount::VaultRotation(token_proxy::VaultRotation::unpack_from_slice(data)?)
+            }
+            token_proxy::LpPosition::DISCRIMINATOR => {
+                BridgeAccount::LpPosition(token_proxy::LpPosition::unpack_from_slice(data)?)
+            }
+            token_proxy::DepositIndex::DISCRIMINATOR => {
+                BridgeAccount::DepositIndex(token_proxy::DepositIndex::unpack_from_slice(data)?)
+            }
+            token_proxy::DepositIndexPage::DISCRIMINATOR => BridgeAccount::DepositIndexPage(
+                token_proxy::DepositIndexPage::unpack_from_slice(data)?,
+            ),
+            token_proxy::RewardConfig::DISCRIMINATOR => {
+                BridgeAccount::RewardConfig(token_proxy::RewardConfig::unpack_from_slice(data)?)
+            }
+            token_proxy::RelayRoundStats::DISCRIMINATOR => BridgeAccount::RelayRoundStats(
+                token_proxy::RelayRoundStats::unpack_from_slice(data)?,
+            ),
+            token_proxy::RoundReward::DISCRIMINATOR => {
+                BridgeAccount::RoundReward(token_proxy::RoundReward::unpack_from_slice(data)?)
+            }
+            token_proxy::EventIndex::DISCRIMINATOR => {
+                BridgeAccount::EventIndex(token_proxy::EventIndex::unpack_from_slice(data)?)
+            }
+            token_proxy::TokenWhitelist::DISCRIMINATOR => {
+                BridgeAccount::TokenWhitelist(token_proxy::TokenWhitelist::unpack_from_slice(data)?)
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+    };
+
+    Ok(Some(account))
+}
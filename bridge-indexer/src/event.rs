@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+
+use solana_program::pubkey::Pubkey;
+
+use round_loader::ProposalStatus;
+use token_proxy::WithdrawalTokenStatus;
+
+use crate::BridgeAccount;
+
+/// High level event derived from the difference between two account states
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BridgeEvent {
+    DepositCreated {
+        account: Pubkey,
+        slot: u64,
+        seed: u128,
+        author: Pubkey,
+    },
+    WithdrawalStatusChanged {
+        account: Pubkey,
+        slot: u64,
+        old: Option<WithdrawalTokenStatus>,
+        new: Option<WithdrawalTokenStatus>,
+    },
+    RelayRoundProposalStatusChanged {
+        account: Pubkey,
+        slot: u64,
+        old: Option<ProposalStatus>,
+        new: Option<ProposalStatus>,
+    },
+    RoundRotated {
+        account: Pubkey,
+        slot: u64,
+        round_number: u32,
+        round_end: u32,
+        relays: Vec<Pubkey>,
+    },
+    AccountClosed {
+        account: Pubkey,
+        slot: u64,
+    },
+}
+
+/// Minimal account state needed to compute events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountSnapshot {
+    Deposit {
+        seed: u128,
+        author: Pubkey,
+    },
+    Withdrawal {
+        status: WithdrawalTokenStatus,
+    },
+    RelayRoundProposal {
+        status: ProposalStatus,
+    },
+    RelayRound {
+        round_number: u32,
+        round_end: u32,
+        relays: Vec<Pubkey>,
+    },
+    Other,
+}
+
+impl From<&BridgeAccount> for AccountSnapshot {
+    fn from(account: &BridgeAccount) -> Self {
+        match account {
+            BridgeAccount::DepositMultiTokenEver(deposit) => AccountSnapshot::Deposit {
+                seed: deposit.meta.data.seed,
+                author: deposit.author,
+            },
+            BridgeAccount::DepositMultiTokenSol(deposit) => AccountSnapshot::Deposit {
+                seed: deposit.meta.data.seed,
+                author: deposit.author,
+            },
+            BridgeAccount::DepositMultiTokenBatch(deposit) => AccountSnapshot::Deposit {
+                seed: deposit.meta.data.seed,
+                author: deposit.author,
+            },
+            BridgeAccount::WithdrawalMultiTokenEver(withdrawal) => AccountSnapshot::Withdrawal {
+                status: withdrawal.meta.data.status,
+            },
+            BridgeAccount::WithdrawalMultiTokenSol(withdrawal) => AccountSnapshot::Withdrawal {
+                status: withdrawal.meta.data.status,
+            },
+            BridgeAccount::WithdrawalMultiTokenBundle(withdrawal) => AccountSnapshot::Withdrawal {
+                status: withdrawal.meta.data.status,
+            },
+            BridgeAccount::RelayRoundProposal(proposal) => AccountSnapshot::RelayRoundProposal {
+                status: proposal.meta.data.status,
+            },
+            BridgeAccount::RelayRoundDeltaProposal(proposal) => {
+                AccountSnapshot::RelayRoundProposal {
+                    status: proposal.meta.data.status,
+                }
+            }
+            BridgeAccount::RelayRound(relay_round) => AccountSnapshot::RelayRound {
+                round_number: relay_round.round_number,
+                round_end: relay_round.round_end,
+                relays: relay_round.relays.clone(),
+            },
+            _ => AccountSnapshot::Other,
+        }
+    }
+}
+
+/// Compute events produced by the transition `old -> new` of a single account
+pub fn diff_snapshots(
+    account: Pubkey,
+    slot: u64,
+    old: Option<&AccountSnapshot>,
+    new: Option<&AccountSnapshot>,
+) -> Vec<BridgeEvent> {
+    let mut events = Vec::new();
+
+    match (old, new) {
+        (None, Some(AccountSnapshot::Deposit { seed, author })) => {
+            events.push(BridgeEvent::DepositCreated {
+                account,
+                slot,
+                seed: *seed,
+                author: *author,
+            });
+        }
+        (
+            None,
+            Some(AccountSnapshot::RelayRound {
+                round_number,
+                round_end,
+                relays,
+            }),
+        ) => {
+            events.push(BridgeEvent::RoundRotated {
+                account,
+                slot,
+                round_number: *round_number,
+                round_end: *round_end,
+                relays: relays.clone(),
+            });
+        }
+        _ => {}
+    }
+
+    let old_status = old.and_then(withdrawal_status);
+    let new_status = new.and_then(withdrawal_status);
+    if old_status != new_status {
+        events.push(BridgeEvent::WithdrawalStatusChanged {
+            account,
+            slot,
+            old: old_status,
+            new: new_status,
+        });
+    }
+
+    let old_status = old.and_then(proposal_status);
+    let new_status = new.and_then(proposal_status);
+    if old_status != new_status {
+        events.push(BridgeEvent::RelayRoundProposalStatusChanged {
+            account,
+            slot,
+            old: old_status,
+            new: new_status,
+        });
+    }
+
+    if old.is_some() && new.is_none() {
+        events.push(BridgeEvent::AccountClosed { account, slot });
+    }
+
+    events
+}
+
+fn withdrawal_status(snapshot: &AccountSnapshot) -> Option<WithdrawalTokenStatus> {
+    match snapshot {
+        AccountSnapshot::Withdrawal { status } => Some(*status),
+        _ => None,
+    }
+}
+
+fn proposal_status(snapshot: &AccountSnapshot) -> Option<ProposalStatus> {
+    match snapshot {
+        AccountSnapshot::RelayRoundProposal { status } => Some(*status),
+        _ => None,
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::*;
+
+/// Turns a stream of account updates into bridge events.
+///
+/// Updates are tracked per slot until the slot is rooted, so that a fork switch
+/// can be reverted with `rollback` and the compensating events emitted.
+#[derive(Debug, Default)]
+pub struct Indexer {
+    accounts: HashMap<Pubkey, Vec<(u64, Option<AccountSnapshot>)>>,
+}
+
+impl Indexer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Latest known state of the account
+    pub fn get(&self, account: &Pubkey) -> Option<&AccountSnapshot> {
+        self.accounts
+            .get(account)
+            .and_then(|history| history.last())
+            .and_then(|(_, snapshot)| snapshot.as_ref())
+    }
+
+    /// Apply an account update. Updates older than the latest known one are ignored.
+    pub fn process(&mut self, update: &AccountUpdate) -> Result<Vec<BridgeEvent>, ProgramError> {
+        let new = decode_account(&update.owner, &update.data)?.map(|account| (&account).into());
+
+        let history = self.accounts.entry(update.pubkey).or_default();
+
+        let old = match history.last() {
+            Some((slot, _)) if *slot > update.slot => return Ok(Vec::new()),
+            Some((slot, _)) if *slot == update.slot => history.pop().and_then(|(_, s)| s),
+            Some((_, snapshot)) => snapshot.clone(),
+            None => None,
+        };
+
+        let events = diff_snapshots(update.pubkey, update.slot, old.as_ref(), new.as_ref());
+
+        if old.is_none() && new.is_none() && history.is_empty() {
+            self.accounts.remove(&update.pubkey);
+        } else {
+            history.push((update.slot, new));
+        }
+
+        Ok(events)
+    }
+
+    /// Revert all updates made after `slot`, returning events that undo them
+    pub fn rollback(&mut self, slot: u64) -> Vec<BridgeEvent> {
+        let mut events = Vec::new();
+
+        self.accounts.retain(|account, history| {
+            let old = match history.last() {
+                Some((last_slot, _)) if *last_slot > slot => history.last().unwrap().1.clone(),
+                _ => return true,
+            };
+
+            history.retain(|(s, _)| *s <= slot);
+
+            let new = history.last().and_then(|(_, snapshot)| snapshot.clone());
+            events.extend(diff_snapshots(*account, slot, old.as_ref(), new.as_ref()));
+
+            !history.is_empty()
+        });
+
+        events
+    }
+
+    /// Mark `slot` as rooted, dropping history that can no longer be rolled back
+    pub fn root(&mut self, slot: u64) {
+        self.accounts.retain(|_, history| {
+            if let Some(pos) = history.iter().rposition(|(s, _)| *s <= slot) {
+                history.drain(..pos);
+            }
+
+            !matches!(history.as_slice(), [(s, None)] if *s <= slot)
+        });
+    }
+}
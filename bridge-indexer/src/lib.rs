@@ -0,0 +1,7 @@
+mod decoder;
+mod event;
+mod indexer;
+
+pub use self::decoder::*;
+pub use self::event::*;
+pub use self::indexer::*;
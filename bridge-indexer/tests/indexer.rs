@@ -0,0 +1,365 @@
+use bridge_indexer::*;
+use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::types::{EverAddress, UInt256, Vote};
+
+use round_loader::{
+    ProposalStatus, RelayRound, RelayRoundProposal, RelayRoundProposalEventWithLen,
+    RelayRoundProposalMetaWithLen,
+};
+use token_proxy::{
+    DepositMultiTokenSol, DepositMultiTokenSolEventWithLen, DepositTokenMetaWithLen,
+    WithdrawalMultiTokenSol, WithdrawalMultiTokenSolEventWithLen, WithdrawalTokenMetaWithLen,
+    WithdrawalTokenStatus,
+};
+
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+fn pack<T: Pack>(state: T) -> Vec<u8> {
+    let mut data = vec![0; T::LEN];
+    T::pack(state, &mut data).unwrap();
+    data
+}
+
+fn update(pubkey: Pubkey, owner: Pubkey, slot: u64, data: Vec<u8>) -> AccountUpdate {
+    AccountUpdate {
+        pubkey,
+        owner,
+        slot,
+        data,
+    }
+}
+
+fn deposit(seed: u128, author: Pubkey) -> Vec<u8> {
+    pack(DepositMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Deposit(255),
+        author,
+        event: DepositMultiTokenSolEventWithLen::new(
+            0,
+            Pubkey::new_unique(),
+            "USDT".to_string(),
+            "USDT".to_string(),
+            6,
+            100,
+            EverAddress::with_standart(0, [1; 32]),
+            0,
+            UInt256::default(),
+            vec![],
+        ),
+        meta: DepositTokenMetaWithLen::new(seed),
+    })
+}
+
+fn withdrawal(status: WithdrawalTokenStatus) -> Vec<u8> {
+    let mut meta = WithdrawalTokenMetaWithLen::default();
+    meta.data.status = status;
+
+    pack(WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(255, None),
+        author: Pubkey::new_unique(),
+        round_number: 1,
+        required_votes: 1,
+        pda: PDA {
+            event_timestamp: 1,
+            event_transaction_lt: 1,
+            event_configuration: Pubkey::new_unique(),
+        },
+        event: WithdrawalMultiTokenSolEventWithLen::new(
+            0,
+            Pubkey::new_unique(),
+            100,
+            Pubkey::new_unique(),
+            vec![],
+        ),
+        meta,
+        signers: vec![Vote::None],
+    })
+}
+
+fn relay_round_proposal(status: ProposalStatus) -> Vec<u8> {
+    let mut meta = RelayRoundProposalMetaWithLen::new();
+    meta.data.status = status;
+
+    pack(RelayRoundProposal {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(255, None),
+        author: Pubkey::new_unique(),
+        round_number: 1,
+        required_votes: 1,
+        pda: PDA {
+            event_timestamp: 1,
+            event_transaction_lt: 1,
+            event_configuration: Pubkey::new_unique(),
+        },
+        event: RelayRoundProposalEventWithLen::new(2, vec![Pubkey::new_unique()], 100),
+        meta,
+        signers: vec![],
+        confirm_count: 0,
+        reject_count: 0,
+        executed_at: 0,
+    })
+}
+
+fn relay_round(round_number: u32, relays: Vec<Pubkey>, round_end: u32) -> Vec<u8> {
+    pack(RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(255),
+        round_number,
+        round_end,
+        relays,
+        relays_root: Default::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: round_end,
+        activated_at: 0,
+        frozen: false,
+    })
+}
+
+#[test]
+fn test_deposit_created() {
+    let mut indexer = Indexer::new();
+
+    let account = Pubkey::new_unique();
+    let author = Pubkey::new_unique();
+
+    let events = indexer
+        .process(&update(account, token_proxy::id(), 10, deposit(7, author)))
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![BridgeEvent::DepositCreated {
+            account,
+            slot: 10,
+            seed: 7,
+            author,
+        }]
+    );
+    assert_eq!(
+        indexer.get(&account),
+        Some(&AccountSnapshot::Deposit { seed: 7, author })
+    );
+
+    // Same state in a later slot produces nothing
+    let events = indexer
+        .process(&update(account, token_proxy::id(), 11, deposit(7, author)))
+        .unwrap();
+    assert!(events.is_empty());
+}
+
+#[test]
+fn test_withdrawal_status_changed() {
+    let mut indexer = Indexer::new();
+
+    let account = Pubkey::new_unique();
+
+    let events = indexer
+        .process(&update(
+            account,
+            token_proxy::id(),
+            10,
+            withdrawal(WithdrawalTokenStatus::New),
+        ))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![BridgeEvent::WithdrawalStatusChanged {
+            account,
+            slot: 10,
+            old: None,
+            new: Some(WithdrawalTokenStatus::New),
+        }]
+    );
+
+    let events = indexer
+        .process(&update(
+            account,
+            token_proxy::id(),
+            11,
+            withdrawal(WithdrawalTokenStatus::Processed),
+        ))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![BridgeEvent::WithdrawalStatusChanged {
+            account,
+            slot: 11,
+            old: Some(WithdrawalTokenStatus::New),
+            new: Some(WithdrawalTokenStatus::Processed),
+        }]
+    );
+}
+
+#[test]
+fn test_relay_round_proposal_status_changed() {
+    let mut indexer = Indexer::new();
+
+    let account = Pubkey::new_unique();
+
+    let events = indexer
+        .process(&update(
+            account,
+            round_loader::id(),
+            10,
+            relay_round_proposal(ProposalStatus::New),
+        ))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![BridgeEvent::RelayRoundProposalStatusChanged {
+            account,
+            slot: 10,
+            old: None,
+            new: Some(ProposalStatus::New),
+        }]
+    );
+
+    let events = indexer
+        .process(&update(
+            account,
+            round_loader::id(),
+            11,
+            relay_round_proposal(ProposalStatus::Executed),
+        ))
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![BridgeEvent::RelayRoundProposalStatusChanged {
+            account,
+            slot: 11,
+            old: Some(ProposalStatus::New),
+            new: Some(ProposalStatus::Executed),
+        }]
+    );
+}
+
+#[test]
+fn test_round_rotated() {
+    let mut indexer = Indexer::new();
+
+    let account = Pubkey::new_unique();
+    let relays = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let events = indexer
+        .process(&update(
+            account,
+            round_loader::id(),
+            10,
+            relay_round(2, relays.clone(), 100),
+        ))
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![BridgeEvent::RoundRotated {
+            account,
+            slot: 10,
+            round_number: 2,
+            round_end: 100,
+            relays,
+        }]
+    );
+}
+
+#[test]
+fn test_account_closed() {
+    let mut indexer = Indexer::new();
+
+    let account = Pubkey::new_unique();
+
+    indexer
+        .process(&update(
+            account,
+            token_proxy::id(),
+            10,
+            withdrawal(WithdrawalTokenStatus::Processed),
+        ))
+        .unwrap();
+
+    // Closed accounts are zeroed
+    let events = indexer
+        .process(&update(
+            account,
+            token_proxy::id(),
+            11,
+            vec![0; WithdrawalMultiTokenSol::LEN],
+        ))
+        .unwrap();
+
+    assert_eq!(
+        events,
+        vec![
+            BridgeEvent::WithdrawalStatusChanged {
+                account,
+                slot: 11,
+                old: Some(WithdrawalTokenStatus::Processed),
+                new: None,
+            },
+            BridgeEvent::AccountClosed { account, slot: 11 },
+        ]
+    );
+    assert_eq!(indexer.get(&account), None);
+}
+
+#[test]
+fn test_rollback() {
+    let mut indexer = Indexer::new();
+
+    let account = Pubkey::new_unique();
+
+    indexer
+        .process(&update(
+            account,
+            token_proxy::id(),
+            10,
+            withdrawal(WithdrawalTokenStatus::New),
+        ))
+        .unwrap();
+    indexer
+        .process(&update(
+            account,
+            token_proxy::id(),
+            12,
+            withdrawal(WithdrawalTokenStatus::Processed),
+        ))
+        .unwrap();
+
+    let events = indexer.rollback(11);
+
+    assert_eq!(
+        events,
+        vec![BridgeEvent::WithdrawalStatusChanged {
+            account,
+            slot: 11,
+            old: Some(WithdrawalTokenStatus::Processed),
+            new: Some(WithdrawalTokenStatus::New),
+        }]
+    );
+    assert_eq!(
+        indexer.get(&account),
+        Some(&AccountSnapshot::Withdrawal {
+            status: WithdrawalTokenStatus::New
+        })
+    );
+}
+
+#[test]
+fn test_foreign_account_ignored() {
+    let mut indexer = Indexer::new();
+
+    let account = Pubkey::new_unique();
+
+    let events = indexer
+        .process(&update(
+            account,
+            Pubkey::new_unique(),
+            10,
+            deposit(7, Pubkey::new_unique()),
+        ))
+        .unwrap();
+
+    assert!(events.is_empty());
+    assert_eq!(indexer.get(&account), None);
+}
@@ -0,0 +1,380 @@
+//! Borsh data converted to and from Python objects, driven by the Borsh schema of the type.
+//!
+//! Structs map to dicts, enums to `{variant: value}` dicts or to the variant name for variants
+//! without fields, `Option` to the value or `None`, byte arrays and vectors to `bytes`, pubkeys
+//! and hashes to base58 strings.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use borsh::schema::{BorshSchema, Declaration, Definition, Fields};
+use borsh::BorshSerialize;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList, PyTuple};
+
+use solana_program::hash::Hash;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+type Definitions = HashMap<Declaration, Definition>;
+
+/// Unpack the account and decode its state. Accounts are unpacked with their own layout first, so
+/// accounts created before a layout extension are decoded with the defaults of new fields.
+pub fn decode_account<T: Pack + BorshSerialize + BorshSchema>(
+    py: Python,
+    data: &[u8],
+) -> PyResult<PyObject> {
+    let account = T::unpack_from_slice(data).map_err(invalid)?;
+    let mut data = account.try_to_vec().map_err(invalid)?;
+
+    // Fields left out of full accounts, like the tail of a relay round, are read as defaults
+    data.resize(T::LEN, 0);
+
+    let container = T::schema_container();
+    decode_value(
+        py,
+        &container.definitions,
+        &container.declaration,
+        &mut data.as_slice(),
+    )
+}
+
+/// Encode the Python value as Borsh data of `T`
+pub fn encode<T: BorshSchema>(value: &PyAny) -> PyResult<Vec<u8>> {
+    let container = T::schema_container();
+
+    let mut data = Vec::new();
+    encode_value(
+        &container.definitions,
+        &container.declaration,
+        value,
+        &mut data,
+    )?;
+
+    Ok(data)
+}
+
+pub fn parse_pubkey(value: &str) -> PyResult<Pubkey> {
+    Pubkey::from_str(value).map_err(invalid)
+}
+
+pub fn invalid<E: ToString>(error: E) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+macro_rules! read_le {
+    ($data:expr, $ty:ty) => {
+        <$ty>::from_le_bytes(take($data)?)
+    };
+}
+
+fn decode_value(
+    py: Python,
+    definitions: &Definitions,
+    declaration: &str,
+    data: &mut &[u8],
+) -> PyResult<PyObject> {
+    let value = match declaration {
+        "nil" => py.None(),
+        "bool" => match read_le!(data, u8) {
+            0 => false.into_py(py),
+            1 => true.into_py(py),
+            _ => return Err(PyValueError::new_err("invalid bool")),
+        },
+        "u8" => read_le!(data, u8).into_py(py),
+        "u16" => read_le!(data, u16).into_py(py),
+        "u32" => read_le!(data, u32).into_py(py),
+        "u64" => read_le!(data, u64).into_py(py),
+        "u128" => read_le!(data, u128).into_py(py),
+        "i8" => read_le!(data, i8).into_py(py),
+        "i16" => read_le!(data, i16).into_py(py),
+        "i32" => read_le!(data, i32).into_py(py),
+        "i64" => read_le!(data, i64).into_py(py),
+        "i128" => read_le!(data, i128).into_py(py),
+        "string" => {
+            let len = read_le!(data, u32) as usize;
+            let bytes = take_slice(data, len)?;
+            String::from_utf8(bytes.to_vec())
+                .map_err(invalid)?
+                .into_py(py)
+        }
+        "Pubkey" => Pubkey::new_from_array(take(data)?).to_string().into_py(py),
+        "Hash" => Hash::new_from_array(take(data)?).to_string().into_py(py),
+        _ => {
+            let definition = definitions
+                .get(declaration)
+                .ok_or_else(|| PyValueError::new_err(format!("unknown type {declaration}")))?;
+
+            decode_definition(py, definitions, declaration, definition, data)?
+        }
+    };
+
+    Ok(value)
+}
+
+fn decode_definition(
+    py: Python,
+    definitions: &Definitions,
+    declaration: &str,
+    definition: &Definition,
+    data: &mut &[u8],
+) -> PyResult<PyObject> {
+    let value = match definition {
+        Definition::Array { length, elements } if elements == "u8" => {
+            PyBytes::new(py, take_slice(data, *length as usize)?).into_py(py)
+        }
+        Definition::Array { length, elements } => {
+            decode_elements(py, definitions, elements, *length as usize, data)?
+        }
+        Definition::Sequence { elements } if elements == "u8" => {
+            let len = read_le!(data, u32) as usize;
+            PyBytes::new(py, take_slice(data, len)?).into_py(py)
+        }
+        Definition::Sequence { elements } => {
+            let len = read_le!(data, u32) as usize;
+            decode_elements(py, definitions, elements, len, data)?
+        }
+        Definition::Tuple { elements } => {
+            let values = elements
+                .iter()
+                .map(|element| decode_value(py, definitions, element, data))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyTuple::new(py, values).into_py(py)
+        }
+        Definition::Enum { variants } => {
+            let tag = read_le!(data, u8) as usize;
+            let (name, variant) = variants
+                .get(tag)
+                .ok_or_else(|| PyValueError::new_err(format!("invalid {declaration} variant")))?;
+
+            if declaration.starts_with("Option<") {
+                return decode_value(py, definitions, variant, data);
+            }
+
+            if is_unit(definitions, variant) {
+                return Ok(name.into_py(py));
+            }
+
+            let dict = PyDict::new(py);
+            dict.set_item(name, decode_value(py, definitions, variant, data)?)?;
+            dict.into_py(py)
+        }
+        Definition::Struct { fields } => match fields {
+            Fields::NamedFields(fields) => {
+                let dict = PyDict::new(py);
+                for (name, field) in fields {
+                    dict.set_item(name, decode_value(py, definitions, field, data)?)?;
+                }
+                dict.into_py(py)
+            }
+            // Newtypes are decoded as the wrapped value
+            Fields::UnnamedFields(fields) if fields.len() == 1 => {
+                decode_value(py, definitions, &fields[0], data)?
+            }
+            Fields::UnnamedFields(fields) => {
+                let values = fields
+                    .iter()
+                    .map(|field| decode_value(py, definitions, field, data))
+                    .collect::<PyResult<Vec<_>>>()?;
+                PyTuple::new(py, values).into_py(py)
+            }
+            Fields::Empty => py.None(),
+        },
+    };
+
+    Ok(value)
+}
+
+fn decode_elements(
+    py: Python,
+    definitions: &Definitions,
+    elements: &str,
+    len: usize,
+    data: &mut &[u8],
+) -> PyResult<PyObject> {
+    let list = PyList::empty(py);
+    for _ in 0..len {
+        list.append(decode_value(py, definitions, elements, data)?)?;
+    }
+    Ok(list.into_py(py))
+}
+
+macro_rules! write_le {
+    ($data:expr, $value:expr, $ty:ty) => {
+        $data.extend_from_slice(&$value.extract::<$ty>()?.to_le_bytes())
+    };
+}
+
+fn encode_value(
+    definitions: &Definitions,
+    declaration: &str,
+    value: &PyAny,
+    data: &mut Vec<u8>,
+) -> PyResult<()> {
+    match declaration {
+        "nil" => (),
+        "bool" => data.push(value.extract::<bool>()? as u8),
+        "u8" => write_le!(data, value, u8),
+        "u16" => write_le!(data, value, u16),
+        "u32" => write_le!(data, value, u32),
+        "u64" => write_le!(data, value, u64),
+        "u128" => write_le!(data, value, u128),
+        "i8" => write_le!(data, value, i8),
+        "i16" => write_le!(data, value, i16),
+        "i32" => write_le!(data, value, i32),
+        "i64" => write_le!(data, value, i64),
+        "i128" => write_le!(data, value, i128),
+        "string" => {
+            let string = value.extract::<&str>()?;
+            data.extend_from_slice(&(string.len() as u32).to_le_bytes());
+            data.extend_from_slice(string.as_bytes());
+        }
+        "Pubkey" => data.extend_from_slice(parse_pubkey(value.extract()?)?.as_ref()),
+        "Hash" => {
+            let hash = Hash::from_str(value.extract()?).map_err(invalid)?;
+            data.extend_from_slice(hash.as_ref());
+        }
+        _ => {
+            let definition = definitions
+                .get(declaration)
+                .ok_or_else(|| PyValueError::new_err(format!("unknown type {declaration}")))?;
+
+            encode_definition(definitions, declaration, definition, value, data)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_definition(
+    definitions: &Definitions,
+    declaration: &str,
+    definition: &Definition,
+    value: &PyAny,
+    data: &mut Vec<u8>,
+) -> PyResult<()> {
+    match definition {
+        Definition::Array { length, elements } => {
+            let len = value.len()?;
+            if len != *length as usize {
+                return Err(PyValueError::new_err(format!(
+                    "{declaration} expects {length} elements, got {len}"
+                )));
+            }
+
+            encode_elements(definitions, elements, value, data)?;
+        }
+        Definition::Sequence { elements } => {
+            data.extend_from_slice(&(value.len()? as u32).to_le_bytes());
+            encode_elements(definitions, elements, value, data)?;
+        }
+        Definition::Tuple { elements } => {
+            for (i, element) in elements.iter().enumerate() {
+                encode_value(definitions, element, value.get_item(i)?, data)?;
+            }
+        }
+        Definition::Enum { variants } if declaration.starts_with("Option<") => {
+            if value.is_none() {
+                data.push(0);
+            } else {
+                data.push(1);
+                encode_value(definitions, &variants[1].1, value, data)?;
+            }
+        }
+        Definition::Enum { variants } => {
+            // Variants without fields are given by name
+            let (name, variant_value) = match value.downcast::<PyDict>() {
+                Ok(dict) if dict.len() == 1 => {
+                    let (name, variant_value) = dict.iter().next().expect("single item");
+                    (name.extract::<&str>()?, Some(variant_value))
+                }
+                Ok(_) => {
+                    return Err(PyValueError::new_err(format!(
+                        "{declaration} expects a single variant"
+                    )))
+                }
+                Err(_) => (value.extract::<&str>()?, None),
+            };
+
+            let tag = variants
+                .iter()
+                .position(|(variant_name, _)| variant_name == name)
+                .ok_or_else(|| PyValueError::new_err(format!("unknown {declaration} {name}")))?;
+            data.push(tag as u8);
+
+            let variant = &variants[tag].1;
+            match variant_value {
+                Some(variant_value) => encode_value(definitions, variant, variant_value, data)?,
+                None if is_unit(definitions, variant) => (),
+                None => {
+                    return Err(PyValueError::new_err(format!(
+                        "{declaration} {name} expects a value"
+                    )))
+                }
+            }
+        }
+        Definition::Struct { fields } => match fields {
+            Fields::NamedFields(fields) => {
+                for (name, field) in fields {
+                    encode_value(definitions, field, value.get_item(name)?, data)?;
+                }
+            }
+            Fields::UnnamedFields(fields) if fields.len() == 1 => {
+                encode_value(definitions, &fields[0], value, data)?;
+            }
+            Fields::UnnamedFields(fields) => {
+                for (i, field) in fields.iter().enumerate() {
+                    encode_value(definitions, field, value.get_item(i)?, data)?;
+                }
+            }
+            Fields::Empty => (),
+        },
+    }
+
+    Ok(())
+}
+
+fn encode_elements(
+    definitions: &Definitions,
+    elements: &str,
+    value: &PyAny,
+    data: &mut Vec<u8>,
+) -> PyResult<()> {
+    if elements == "u8" {
+        data.extend_from_slice(&value.extract::<Vec<u8>>()?);
+        return Ok(());
+    }
+
+    for element in value.iter()? {
+        encode_value(definitions, elements, element?, data)?;
+    }
+
+    Ok(())
+}
+
+fn is_unit(definitions: &Definitions, declaration: &str) -> bool {
+    declaration == "nil"
+        || matches!(
+            definitions.get(declaration),
+            Some(Definition::Struct {
+                fields: Fields::Empty
+            })
+        )
+}
+
+fn take<const N: usize>(data: &mut &[u8]) -> PyResult<[u8; N]> {
+    let bytes = take_slice(data, N)?;
+    Ok(bytes.try_into().expect("length checked"))
+}
+
+fn take_slice<'a>(data: &mut &'a [u8], len: usize) -> PyResult<&'a [u8]> {
+    if data.len() < len {
+        return Err(PyValueError::new_err("unexpected end of data"));
+    }
+
+    let (bytes, rest) = data.split_at(len);
+    *data = rest;
+
+    Ok(bytes)
+}
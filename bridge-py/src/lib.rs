@@ -0,0 +1,41 @@
+//! Python bindings of the bridge programs for off-chain monitoring.
+//!
+//! PDAs are derived with the seeds of the interface crates, so Python code doesn't duplicate
+//! them. Keys are passed and returned as base58 strings, see [`codec`] for the mapping of
+//! instruction data and account state to Python objects.
+
+use pyo3::prelude::*;
+
+/// Decode the account data as one of the listed account types, picked by the discriminator.
+/// Returns the account type name along with the decoded state.
+macro_rules! decode_account {
+    ($py:expr, $data:expr, [$($account:ident),* $(,)?]) => {{
+        let discriminator = $data.get(..bridge_utils::state::ACCOUNT_DISCRIMINATOR_LEN);
+
+        $(
+            if discriminator == Some(&$account::DISCRIMINATOR[..]) {
+                let account = crate::codec::decode_account::<$account>($py, $data)?;
+                return Ok((stringify!($account), account));
+            }
+        )*
+
+        Err(pyo3::exceptions::PyValueError::new_err("unknown account discriminator"))
+    }};
+}
+
+mod codec;
+mod round_loader;
+mod token_proxy;
+
+#[pymodule]
+pub fn bridge_py(py: Python, m: &PyModule) -> PyResult<()> {
+    let token_proxy_module = PyModule::new(py, "token_proxy")?;
+    token_proxy::register(token_proxy_module)?;
+    m.add_submodule(token_proxy_module)?;
+
+    let round_loader_module = PyModule::new(py, "round_loader")?;
+    round_loader::register(round_loader_module)?;
+    m.add_submodule(round_loader_module)?;
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use bridge_utils::state::AdminAuditLog;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use round_loader_interface::*;
+
+use crate::codec::{self, parse_pubkey};
+
+#[pyfunction]
+fn program_id() -> String {
+    id().to_string()
+}
+
+#[pyfunction(name = "get_settings_address")]
+fn settings_address() -> String {
+    get_settings_address().to_string()
+}
+
+#[pyfunction(name = "get_admin_audit_log_address")]
+fn admin_audit_log_address() -> String {
+    get_admin_audit_log_address().to_string()
+}
+
+#[pyfunction(name = "get_relay_round_address")]
+fn relay_round_address(round_number: u32) -> String {
+    get_relay_round_address(round_number).to_string()
+}
+
+#[pyfunction(name = "get_proposal_address")]
+fn proposal_address(
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &str,
+    event_data: &[u8],
+) -> PyResult<String> {
+    Ok(get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &parse_pubkey(event_configuration)?,
+        event_data,
+    )
+    .to_string())
+}
+
+#[pyfunction(name = "get_proposal_vote_address")]
+fn proposal_vote_address(proposal: &str, relay_index: u32) -> PyResult<String> {
+    Ok(get_proposal_vote_address(&parse_pubkey(proposal)?, relay_index).to_string())
+}
+
+#[pyfunction(name = "get_executor_reward_vault_address")]
+fn executor_reward_vault_address() -> String {
+    get_executor_reward_vault_address().to_string()
+}
+
+#[pyfunction(name = "get_round_digest_address")]
+fn round_digest_address(round_number: u32) -> String {
+    get_round_digest_address(round_number).to_string()
+}
+
+#[pyfunction(name = "get_round_stats_address")]
+fn round_stats_address(round_number: u32) -> String {
+    get_round_stats_address(round_number).to_string()
+}
+
+#[pyfunction(name = "get_event_configuration_address")]
+fn event_configuration_address(event_configuration: &str) -> PyResult<String> {
+    Ok(get_event_configuration_address(&parse_pubkey(event_configuration)?).to_string())
+}
+
+/// Borsh data of the Round Loader instruction, e.g. `{"VoteForProposal": {"vote": "Confirm"}}`
+#[pyfunction]
+fn encode_instruction(py: Python, instruction: &PyAny) -> PyResult<PyObject> {
+    let data = codec::encode::<RoundLoaderInstruction>(instruction)?;
+    Ok(PyBytes::new(py, &data).into_py(py))
+}
+
+/// Account type name and state of the Round Loader account
+#[pyfunction]
+fn decode_account(py: Python, data: &[u8]) -> PyResult<(&'static str, PyObject)> {
+    decode_account!(
+        py,
+        data,
+        [
+            Settings,
+            RelayRound,
+            RoundDigest,
+            RoundStats,
+            RelayRoundProposal,
+            RelayRoundDeltaProposal,
+            UpgradeProposal,
+            ProposalVote,
+            EventConfiguration,
+            AdminAuditLog,
+        ]
+    )
+}
+
+pub fn register(m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(program_id, m)?)?;
+    m.add_function(wrap_pyfunction!(settings_address, m)?)?;
+    m.add_function(wrap_pyfunction!(admin_audit_log_address, m)?)?;
+    m.add_function(wrap_pyfunction!(relay_round_address, m)?)?;
+    m.add_function(wrap_pyfunction!(proposal_address, m)?)?;
+    m.add_function(wrap_pyfunction!(proposal_vote_address, m)?)?;
+    m.add_function(wrap_pyfunction!(executor_reward_vault_address, m)?)?;
+    m.add_function(wrap_pyfunction!(round_digest_address, m)?)?;
+    m.add_function(wrap_pyfunction!(round_stats_address, m)?)?;
+    m.add_function(wrap_pyfunction!(event_configuration_address, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_instruction, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_account, m)?)?;
+
+    Ok(())
+}
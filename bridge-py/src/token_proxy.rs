@@ -0,0 +1,213 @@
+use std::str::FromStr;
+
+use bridge_utils::state::AdminAuditLog;
+use bridge_utils::types::EverAddress;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use token_proxy_interface::*;
+
+use crate::codec::{self, invalid, parse_pubkey};
+
+#[pyfunction]
+fn program_id() -> String {
+    id().to_string()
+}
+
+#[pyfunction(name = "get_settings_address")]
+fn settings_address() -> String {
+    get_settings_address().to_string()
+}
+
+#[pyfunction(name = "get_admin_audit_log_address")]
+fn admin_audit_log_address() -> String {
+    get_admin_audit_log_address().to_string()
+}
+
+#[pyfunction(name = "get_multivault_address")]
+fn multivault_address() -> String {
+    get_multivault_address().to_string()
+}
+
+#[pyfunction(name = "get_token_settings_ever_address")]
+fn token_settings_ever_address(token: &str) -> PyResult<String> {
+    let token = EverAddress::from_str(token).map_err(invalid)?;
+    Ok(get_token_settings_ever_address(&token).to_string())
+}
+
+#[pyfunction(name = "get_token_settings_sol_address")]
+fn token_settings_sol_address(mint: &str) -> PyResult<String> {
+    Ok(get_token_settings_sol_address(&parse_pubkey(mint)?).to_string())
+}
+
+#[pyfunction(name = "get_mint_address")]
+fn mint_address(token: &str) -> PyResult<String> {
+    let token = EverAddress::from_str(token).map_err(invalid)?;
+    Ok(get_mint_address(&token).to_string())
+}
+
+#[pyfunction(name = "get_vault_address")]
+fn vault_address(mint: &str) -> PyResult<String> {
+    Ok(get_vault_address(&parse_pubkey(mint)?).to_string())
+}
+
+#[pyfunction(name = "get_deposit_address")]
+fn deposit_address(sender: &str, seed: u128) -> PyResult<String> {
+    Ok(get_deposit_address(&parse_pubkey(sender)?, seed).to_string())
+}
+
+#[pyfunction(name = "get_settings_history_address")]
+fn settings_history_address() -> String {
+    get_settings_history_address().to_string()
+}
+
+#[pyfunction(name = "get_relay_round_stats_address")]
+fn relay_round_stats_address(round_number: u32) -> String {
+    get_relay_round_stats_address(round_number).to_string()
+}
+
+#[pyfunction(name = "get_event_index_address")]
+fn event_index_address(
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &str,
+) -> PyResult<String> {
+    Ok(get_event_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &parse_pubkey(event_configuration)?,
+    )
+    .to_string())
+}
+
+#[pyfunction(name = "get_nonce_address")]
+fn nonce_address(owner: &str) -> PyResult<String> {
+    Ok(get_nonce_address(&parse_pubkey(owner)?).to_string())
+}
+
+#[pyfunction(name = "get_withdrawal_ever_address")]
+#[allow(clippy::too_many_arguments)]
+fn withdrawal_ever_address(
+    chain_id: u32,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &str,
+    token: &str,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    recipient: &str,
+    amount: u128,
+    payload: Vec<u8>,
+) -> PyResult<String> {
+    Ok(get_withdrawal_ever_address(
+        chain_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &parse_pubkey(event_configuration)?,
+        EverAddress::from_str(token).map_err(invalid)?,
+        name,
+        symbol,
+        decimals,
+        parse_pubkey(recipient)?,
+        amount,
+        payload,
+    )
+    .to_string())
+}
+
+#[pyfunction(name = "get_withdrawal_sol_address")]
+#[allow(clippy::too_many_arguments)]
+fn withdrawal_sol_address(
+    chain_id: u32,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &str,
+    mint: &str,
+    recipient: &str,
+    amount: u128,
+    payload: Vec<u8>,
+) -> PyResult<String> {
+    Ok(get_withdrawal_sol_address(
+        chain_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &parse_pubkey(event_configuration)?,
+        parse_pubkey(mint)?,
+        parse_pubkey(recipient)?,
+        amount,
+        payload,
+    )
+    .to_string())
+}
+
+/// Borsh data of the Token Proxy instruction, e.g.
+/// `{"VoteForWithdrawRequest": {"vote": "Confirm"}}`
+#[pyfunction]
+fn encode_instruction(py: Python, instruction: &PyAny) -> PyResult<PyObject> {
+    let data = codec::encode::<TokenProxyInstruction>(instruction)?;
+    Ok(PyBytes::new(py, &data).into_py(py))
+}
+
+/// Account type name and state of the Token Proxy account
+#[pyfunction]
+fn decode_account(py: Python, data: &[u8]) -> PyResult<(&'static str, PyObject)> {
+    decode_account!(
+        py,
+        data,
+        [
+            Settings,
+            MultiVault,
+            TokenSettings,
+            DepositMultiTokenSol,
+            DepositMultiTokenBatch,
+            DepositMultiTokenEver,
+            WithdrawalMultiTokenEver,
+            WithdrawalMultiTokenSol,
+            WithdrawalMultiTokenBundle,
+            SettingsHistory,
+            SettingsHistoryPage,
+            DepositIndex,
+            DepositIndexPage,
+            VaultRotation,
+            LpPosition,
+            RewardConfig,
+            RelayRoundStats,
+            RoundReward,
+            EventIndex,
+            TokenWhitelist,
+            PayloadSchema,
+            KnownToken,
+            ExemptAccount,
+            Nonce,
+            DiscountConfig,
+            DiscountStake,
+            AdminAuditLog,
+        ]
+    )
+}
+
+pub fn register(m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(program_id, m)?)?;
+    m.add_function(wrap_pyfunction!(settings_address, m)?)?;
+    m.add_function(wrap_pyfunction!(admin_audit_log_address, m)?)?;
+    m.add_function(wrap_pyfunction!(multivault_address, m)?)?;
+    m.add_function(wrap_pyfunction!(token_settings_ever_address, m)?)?;
+    m.add_function(wrap_pyfunction!(token_settings_sol_address, m)?)?;
+    m.add_function(wrap_pyfunction!(mint_address, m)?)?;
+    m.add_function(wrap_pyfunction!(vault_address, m)?)?;
+    m.add_function(wrap_pyfunction!(deposit_address, m)?)?;
+    m.add_function(wrap_pyfunction!(settings_history_address, m)?)?;
+    m.add_function(wrap_pyfunction!(relay_round_stats_address, m)?)?;
+    m.add_function(wrap_pyfunction!(event_index_address, m)?)?;
+    m.add_function(wrap_pyfunction!(nonce_address, m)?)?;
+    m.add_function(wrap_pyfunction!(withdrawal_ever_address, m)?)?;
+    m.add_function(wrap_pyfunction!(withdrawal_sol_address, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_instruction, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_account, m)?)?;
+
+    Ok(())
+}
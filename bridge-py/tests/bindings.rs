@@ -0,0 +1,201 @@
+use borsh::BorshSerialize;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::Vote;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use round_loader_interface::{RelayRound, RoundLoaderInstruction};
+use token_proxy_interface::TokenProxyInstruction;
+
+use solana_program::hash::Hash;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+fn with_module<F>(f: F)
+where
+    F: for<'py> FnOnce(Python<'py>, &'py PyModule, &'py PyModule),
+{
+    Python::with_gil(|py| {
+        let module = pyo3::wrap_pymodule!(bridge_py::bridge_py)(py).into_ref(py);
+
+        let token_proxy = module
+            .getattr("token_proxy")
+            .unwrap()
+            .downcast::<PyModule>()
+            .unwrap();
+        let round_loader = module
+            .getattr("round_loader")
+            .unwrap()
+            .downcast::<PyModule>()
+            .unwrap();
+
+        f(py, token_proxy, round_loader)
+    })
+}
+
+fn relay_round(round_number: u32, relays: Vec<Pubkey>) -> Vec<u8> {
+    let mut data = vec![0; RelayRound::LEN];
+    RelayRound::pack(
+        RelayRound {
+            is_initialized: true,
+            account_kind: AccountKind::RelayRound(255),
+            round_number,
+            round_end: 2000,
+            relays,
+            relays_root: Hash::default(),
+            relays_count: 0,
+            suggested_proposal_deadline: 1800,
+            activated_at: 0,
+            frozen: false,
+        },
+        &mut data,
+    )
+    .unwrap();
+    data
+}
+
+#[test]
+fn test_bindings() {
+    with_module(|py, token_proxy, round_loader| {
+        // PDAs are derived with the seeds of the interface crates
+        let settings: String = token_proxy
+            .call_method0("get_settings_address")
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            settings,
+            token_proxy_interface::get_settings_address().to_string()
+        );
+
+        let mint = Pubkey::new_unique();
+        let vault: String = token_proxy
+            .call_method1("get_vault_address", (mint.to_string(),))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            vault,
+            token_proxy_interface::get_vault_address(&mint).to_string()
+        );
+
+        let relay_round_address: String = round_loader
+            .call_method1("get_relay_round_address", (7,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            relay_round_address,
+            round_loader_interface::get_relay_round_address(7).to_string()
+        );
+
+        // Instruction data matches the Borsh encoding of the program
+        let instruction = py
+            .eval("{'VoteForWithdrawRequest': {'vote': 'Confirm'}}", None, None)
+            .unwrap();
+        let data: Vec<u8> = token_proxy
+            .call_method1("encode_instruction", (instruction,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            data,
+            TokenProxyInstruction::VoteForWithdrawRequest {
+                vote: Vote::Confirm
+            }
+            .try_to_vec()
+            .unwrap()
+        );
+
+        let instruction = py
+            .eval("{'VoteForProposal': {'vote': 'Reject'}}", None, None)
+            .unwrap();
+        let data: Vec<u8> = round_loader
+            .call_method1("encode_instruction", (instruction,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(
+            data,
+            RoundLoaderInstruction::VoteForProposal { vote: Vote::Reject }
+                .try_to_vec()
+                .unwrap()
+        );
+
+        // Accounts are decoded by their discriminator
+        let relays = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let data = PyBytes::new(py, &relay_round(7, relays.clone()));
+
+        let (name, account): (String, &PyAny) = round_loader
+            .call_method1("decode_account", (data,))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(name, "RelayRound");
+        assert_eq!(
+            account
+                .get_item("round_number")
+                .unwrap()
+                .extract::<u32>()
+                .unwrap(),
+            7
+        );
+        assert_eq!(
+            account
+                .get_item("relays")
+                .unwrap()
+                .extract::<Vec<String>>()
+                .unwrap(),
+            relays.iter().map(Pubkey::to_string).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            account
+                .get_item("suggested_proposal_deadline")
+                .unwrap()
+                .extract::<u32>()
+                .unwrap(),
+            1800
+        );
+    });
+}
+
+#[test]
+fn test_bindings_reject_invalid_input() {
+    with_module(|py, token_proxy, round_loader| {
+        // Keys are base58 strings
+        let err = token_proxy
+            .call_method1("get_vault_address", ("not a key",))
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+
+        // Unknown instruction, unknown variant of a field and more than one instruction
+        for instruction in [
+            "{'NoSuchInstruction': {}}",
+            "{'VoteForProposal': {'vote': 'Maybe'}}",
+            "{'VoteForProposal': {'vote': 'Confirm'}, 'ExecuteProposal': None}",
+        ] {
+            let value = py.eval(instruction, None, None).unwrap();
+            let err = round_loader
+                .call_method1("encode_instruction", (value,))
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyValueError>(py), "{instruction}");
+        }
+
+        // Account of the other program
+        let data = PyBytes::new(py, &relay_round(7, vec![Pubkey::new_unique()]));
+        let err = token_proxy
+            .call_method1("decode_account", (data,))
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+        assert_eq!(err.value(py).to_string(), "unknown account discriminator");
+
+        // Known discriminator, truncated data
+        let data = relay_round(7, vec![Pubkey::new_unique()]);
+        let data = PyBytes::new(py, &data[..20]);
+        let err = round_loader
+            .call_method1("decode_account", (data,))
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+    });
+}
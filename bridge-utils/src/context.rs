@@ -0,0 +1,40 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// Accounts of an instruction, parsed and validated in order
+pub trait Accounts<'a, 'info>: Sized {
+    fn try_accounts(
+        program_id: &Pubkey,
+        account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'info>>,
+    ) -> Result<Self, ProgramError>;
+}
+
+/// Instruction handler input
+pub struct Context<'a, 'info, T> {
+    pub program_id: &'a Pubkey,
+    // Accounts described by the instruction
+    pub accounts: T,
+    // Optional accounts following the described ones
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+    // All instruction accounts, to be passed to CPI
+    pub account_infos: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info, T: Accounts<'a, 'info>> Context<'a, 'info, T> {
+    pub fn new(
+        program_id: &'a Pubkey,
+        account_infos: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let account_info_iter = &mut account_infos.iter();
+
+        let accounts = T::try_accounts(program_id, account_info_iter)?;
+
+        Ok(Self {
+            program_id,
+            accounts,
+            remaining_accounts: account_info_iter.as_slice(),
+            account_infos,
+        })
+    }
+}
@@ -0,0 +1,124 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::ed25519_program;
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+use solana_program::sysvar;
+
+use crate::errors::SolanaBridgeError;
+
+// Off-chain signatures are verified by the Ed25519 native program in the instruction preceding
+// the one relying on them, the program only checks what was verified via the instructions
+// sysvar. Layout of the Ed25519 instruction data:
+//   0. `u8` Number of signatures
+//   1. `u8` Padding
+//   2. ..Signature offsets, 7 `u16` each
+//   3. ..Public keys, signatures and messages the offsets point to
+
+pub const SIGNATURE_BYTES: usize = 64;
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+
+// Offsets referring to the data of the Ed25519 instruction itself
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+/// Ed25519 program instruction verifying a single signature of the message
+pub fn new_ed25519_instruction(
+    signer: &Pubkey,
+    signature: &[u8; SIGNATURE_BYTES],
+    message: &[u8],
+) -> Instruction {
+    let public_key_offset = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN;
+    let signature_offset = public_key_offset + PUBKEY_BYTES;
+    let message_data_offset = signature_offset + SIGNATURE_BYTES;
+
+    let mut data = Vec::with_capacity(message_data_offset + message.len());
+    data.extend_from_slice(&[1, 0]);
+
+    for offset in [
+        signature_offset as u16,
+        CURRENT_INSTRUCTION_INDEX,
+        public_key_offset as u16,
+        CURRENT_INSTRUCTION_INDEX,
+        message_data_offset as u16,
+        message.len() as u16,
+        CURRENT_INSTRUCTION_INDEX,
+    ] {
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::id(),
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Check that the instruction preceding the current one verified the signer's signature of
+/// the message
+pub fn validate_ed25519_signature(
+    instructions_sysvar_info: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<(), ProgramError> {
+    if !sysvar::instructions::check_id(instructions_sysvar_info.key) {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    let current_index =
+        sysvar::instructions::load_current_index_checked(instructions_sysvar_info)? as usize;
+
+    if current_index == 0 {
+        return Err(SolanaBridgeError::InvalidMessageSignature.into());
+    }
+
+    let instruction = sysvar::instructions::load_instruction_at_checked(
+        current_index - 1,
+        instructions_sysvar_info,
+    )?;
+
+    if instruction.program_id != ed25519_program::id() {
+        return Err(SolanaBridgeError::InvalidMessageSignature.into());
+    }
+
+    let (verified_signer, verified_message) = parse_ed25519_instruction(&instruction.data)
+        .ok_or(SolanaBridgeError::InvalidMessageSignature)?;
+
+    if verified_signer != signer.as_ref() || verified_message != message {
+        return Err(SolanaBridgeError::InvalidMessageSignature.into());
+    }
+
+    Ok(())
+}
+
+/// Public key and message of the single signature verified by the instruction
+fn parse_ed25519_instruction(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    if data.first() != Some(&1) {
+        return None;
+    }
+
+    let offsets =
+        data.get(SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN)?;
+    let offset = |i: usize| u16::from_le_bytes([offsets[2 * i], offsets[2 * i + 1]]);
+
+    let (public_key_offset, message_data_offset, message_data_size) =
+        (offset(2) as usize, offset(4) as usize, offset(5) as usize);
+
+    // Verified data has to come from the Ed25519 instruction itself
+    if offset(1) != CURRENT_INSTRUCTION_INDEX
+        || offset(3) != CURRENT_INSTRUCTION_INDEX
+        || offset(6) != CURRENT_INSTRUCTION_INDEX
+    {
+        return None;
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + PUBKEY_BYTES)?;
+    let message = data.get(message_data_offset..message_data_offset + message_data_size)?;
+
+    Some((public_key, message))
+}
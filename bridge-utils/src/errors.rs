@@ -22,6 +22,7 @@ pub enum SolanaBridgeError {
     InsufficientBalance,
     #[error("Insufficient vault balance")]
     InsufficientVaultBalance,
+    /// Not returned, re-votes are ignored. Kept to preserve the codes of the following variants.
     #[error("Relay already voted")]
     RelayAlreadyVoted,
     #[error("Operation overflowed")]
@@ -34,10 +35,129 @@ pub enum SolanaBridgeError {
     InvalidVote,
     #[error("Votes overflow")]
     VotesOverflow,
+    /// Not returned. Kept to preserve the codes of the following variants.
     #[error("Invalid token settings name")]
     InvalidTokenSettingsName,
     #[error("Failed to deserialize payload")]
     DeserializePayload,
+    #[error("Written payload doesn't match committed hash")]
+    InvalidPayloadHash,
+    #[error("Withdrawal limit exceeded")]
+    WithdrawalLimit,
+    #[error("Proposal votes must be migrated")]
+    ProposalNotMigrated,
+    #[error("Deposit preview finished, result is in return data")]
+    DepositPreview,
+    #[error("Mint has a freeze authority")]
+    MintFreezeAuthority,
+    #[error("Vault is not frozen")]
+    VaultNotFrozen,
+    #[error("Vault rotation is time locked")]
+    VaultRotationLocked,
+    #[error("Vault rotation is already executed")]
+    VaultRotationExecuted,
+    #[error("Yield adapter is time locked")]
+    YieldAdapterLocked,
+    #[error("Deployed liquidity exceeds the allowed share")]
+    DeployLimit,
+    #[error("Liquidity is deployed to the yield adapter")]
+    LiquidityDeployed,
+    #[error("Event is bound to another chain")]
+    InvalidChainId,
+    #[error("Only standard EVER addresses are supported")]
+    UnsupportedEverAddress,
+    #[error("Withdrawal receipt does not match the withdrawal")]
+    InvalidWithdrawalReceipt,
+    #[error("Relay round is not completed")]
+    RelayRoundNotCompleted,
+    #[error("Relay reward already claimed")]
+    RewardAlreadyClaimed,
+    #[error("Invalid relay merkle proof")]
+    InvalidMerkleProof,
+    #[error("EVER event is already represented by another withdrawal")]
+    EventAlreadyProcessed,
+    #[error("Guardian veto is disabled")]
+    VetoDisabled,
+    #[error("Veto reason is too long")]
+    VetoReasonLenLimit,
+    #[error("Role is already granted to the authority")]
+    RoleAlreadyGranted,
+    #[error("Role is not granted to the authority")]
+    RoleNotGranted,
+    #[error("Too many role members")]
+    RoleMembersLimit,
+    #[error("Too many instruction permissions")]
+    InstructionPermissionsLimit,
+    #[error("Token is not whitelisted for deposits")]
+    TokenNotWhitelisted,
+    #[error("Too many confirmation tiers")]
+    ConfirmationTiersLimit,
+    #[error("Event configuration is not registered")]
+    EventConfigurationNotRegistered,
+    #[error("Too many deposit batch entries")]
+    DepositBatchLimit,
+    #[error("Token operation is paused")]
+    OperationPaused,
+    #[error("Payload schema is not registered")]
+    PayloadSchemaNotRegistered,
+    #[error("Payload doesn't match the schema")]
+    PayloadSchemaMismatch,
+    #[error("Not enough co-signers")]
+    NotEnoughCosigners,
+    #[error("Withdrawal status transition is not allowed")]
+    InvalidStatusTransition,
+    #[error("Message is not signed by the authority")]
+    InvalidMessageSignature,
+    #[error("Signed message has expired")]
+    MessageExpired,
+    #[error("Nonce is already used")]
+    NonceUsed,
+    #[error("Unstake cooldown has not passed")]
+    UnstakeCooldown,
+    #[error("Daily withdrawal limit is below the per-transaction limit")]
+    DailyLimitBelowLimit,
+    #[error("Required number of votes or signers is out of range")]
+    InvalidQuorum,
+    #[error("Round TTL is not positive")]
+    InvalidRoundTtl,
+    #[error("Role is assigned to the default key")]
+    DefaultRoleKey,
+    #[error("Fee multiplier or divisor is out of range")]
+    InvalidFeeInfo,
+    #[error("Confirmation tiers are not in ascending order")]
+    InvalidConfirmationTiers,
+    #[error("Challenge window has not passed")]
+    ChallengeWindowActive,
+    #[error("Relay round is frozen or not active yet")]
+    RelayRoundNotActive,
+    #[error("Too many withdrawal bundle entries")]
+    WithdrawalBundleLimit,
+    #[error("Program is already initialized")]
+    AlreadyInitialized,
+    #[error("Emergency mode disabled")]
+    EmergencyDisabled,
+    #[error("Number of relays is out of bounds")]
+    InvalidRelaysCount,
+    #[error("Relays bounds are out of order or exceed the limits")]
+    InvalidRelaysBounds,
+    #[error("Relay round is within the retention window")]
+    RoundRetained,
+    #[error("Relay round is referenced by withdrawals")]
+    RoundInUse,
+    #[error("Token metadata doesn't match the known token")]
+    KnownTokenMismatch,
+    #[error("Proposal is already executed")]
+    ProposalExecuted,
+    #[error("EVER configuration doesn't match the token namespace")]
+    EverConfigurationMismatch,
+    #[error("Deposit amount exceeds the maximum")]
+    MaxAmountExceeded,
+    #[error("Relay can't report itself")]
+    SelfReport,
+    #[error("Guardian is active, its replacement needs its signature")]
+    GuardianActive,
+    #[error("Buffer content doesn't match the approved hash")]
+    BufferHashMismatch,
 }
 
 impl From<SolanaBridgeError> for ProgramError {
@@ -58,13 +178,75 @@ impl TryFrom<u32> for SolanaBridgeError {
             4 => Ok(SolanaBridgeError::InvalidRelay),
             5 => Ok(SolanaBridgeError::EmergencyEnabled),
             6 => Ok(SolanaBridgeError::InvalidWithdrawalStatus),
-            7 => Ok(SolanaBridgeError::InsufficientVaultBalance),
-            8 => Ok(SolanaBridgeError::RelayAlreadyVoted),
-            9 => Ok(SolanaBridgeError::Overflow),
-            10 => Ok(SolanaBridgeError::TokenNameLenLimit),
-            11 => Ok(SolanaBridgeError::TokenSymbolLenLimit),
-            12 => Ok(SolanaBridgeError::InvalidVote),
-            13 => Ok(SolanaBridgeError::VotesOverflow),
+            7 => Ok(SolanaBridgeError::InsufficientBalance),
+            8 => Ok(SolanaBridgeError::InsufficientVaultBalance),
+            9 => Ok(SolanaBridgeError::RelayAlreadyVoted),
+            10 => Ok(SolanaBridgeError::Overflow),
+            11 => Ok(SolanaBridgeError::TokenNameLenLimit),
+            12 => Ok(SolanaBridgeError::TokenSymbolLenLimit),
+            13 => Ok(SolanaBridgeError::InvalidVote),
+            14 => Ok(SolanaBridgeError::VotesOverflow),
+            15 => Ok(SolanaBridgeError::InvalidTokenSettingsName),
+            16 => Ok(SolanaBridgeError::DeserializePayload),
+            17 => Ok(SolanaBridgeError::InvalidPayloadHash),
+            18 => Ok(SolanaBridgeError::WithdrawalLimit),
+            19 => Ok(SolanaBridgeError::ProposalNotMigrated),
+            20 => Ok(SolanaBridgeError::DepositPreview),
+            21 => Ok(SolanaBridgeError::MintFreezeAuthority),
+            22 => Ok(SolanaBridgeError::VaultNotFrozen),
+            23 => Ok(SolanaBridgeError::VaultRotationLocked),
+            24 => Ok(SolanaBridgeError::VaultRotationExecuted),
+            25 => Ok(SolanaBridgeError::YieldAdapterLocked),
+            26 => Ok(SolanaBridgeError::DeployLimit),
+            27 => Ok(SolanaBridgeError::LiquidityDeployed),
+            28 => Ok(SolanaBridgeError::InvalidChainId),
+            29 => Ok(SolanaBridgeError::UnsupportedEverAddress),
+            30 => Ok(SolanaBridgeError::InvalidWithdrawalReceipt),
+            31 => Ok(SolanaBridgeError::RelayRoundNotCompleted),
+            32 => Ok(SolanaBridgeError::RewardAlreadyClaimed),
+            33 => Ok(SolanaBridgeError::InvalidMerkleProof),
+            34 => Ok(SolanaBridgeError::EventAlreadyProcessed),
+            35 => Ok(SolanaBridgeError::VetoDisabled),
+            36 => Ok(SolanaBridgeError::VetoReasonLenLimit),
+            37 => Ok(SolanaBridgeError::RoleAlreadyGranted),
+            38 => Ok(SolanaBridgeError::RoleNotGranted),
+            39 => Ok(SolanaBridgeError::RoleMembersLimit),
+            40 => Ok(SolanaBridgeError::InstructionPermissionsLimit),
+            41 => Ok(SolanaBridgeError::TokenNotWhitelisted),
+            42 => Ok(SolanaBridgeError::ConfirmationTiersLimit),
+            43 => Ok(SolanaBridgeError::EventConfigurationNotRegistered),
+            44 => Ok(SolanaBridgeError::DepositBatchLimit),
+            45 => Ok(SolanaBridgeError::OperationPaused),
+            46 => Ok(SolanaBridgeError::PayloadSchemaNotRegistered),
+            47 => Ok(SolanaBridgeError::PayloadSchemaMismatch),
+            48 => Ok(SolanaBridgeError::NotEnoughCosigners),
+            49 => Ok(SolanaBridgeError::InvalidStatusTransition),
+            50 => Ok(SolanaBridgeError::InvalidMessageSignature),
+            51 => Ok(SolanaBridgeError::MessageExpired),
+            52 => Ok(SolanaBridgeError::NonceUsed),
+            53 => Ok(SolanaBridgeError::UnstakeCooldown),
+            54 => Ok(SolanaBridgeError::DailyLimitBelowLimit),
+            55 => Ok(SolanaBridgeError::InvalidQuorum),
+            56 => Ok(SolanaBridgeError::InvalidRoundTtl),
+            57 => Ok(SolanaBridgeError::DefaultRoleKey),
+            58 => Ok(SolanaBridgeError::InvalidFeeInfo),
+            59 => Ok(SolanaBridgeError::InvalidConfirmationTiers),
+            60 => Ok(SolanaBridgeError::ChallengeWindowActive),
+            61 => Ok(SolanaBridgeError::RelayRoundNotActive),
+            62 => Ok(SolanaBridgeError::WithdrawalBundleLimit),
+            63 => Ok(SolanaBridgeError::AlreadyInitialized),
+            64 => Ok(SolanaBridgeError::EmergencyDisabled),
+            65 => Ok(SolanaBridgeError::InvalidRelaysCount),
+            66 => Ok(SolanaBridgeError::InvalidRelaysBounds),
+            67 => Ok(SolanaBridgeError::RoundRetained),
+            68 => Ok(SolanaBridgeError::RoundInUse),
+            69 => Ok(SolanaBridgeError::KnownTokenMismatch),
+            70 => Ok(SolanaBridgeError::ProposalExecuted),
+            71 => Ok(SolanaBridgeError::EverConfigurationMismatch),
+            72 => Ok(SolanaBridgeError::MaxAmountExceeded),
+            73 => Ok(SolanaBridgeError::SelfReport),
+            74 => Ok(SolanaBridgeError::GuardianActive),
+            75 => Ok(SolanaBridgeError::BufferHashMismatch),
             _ => Err(()),
         }
     }
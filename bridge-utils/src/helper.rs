@@ -1,9 +1,30 @@
 use solana_program::account_info::AccountInfo;
 use solana_program::bpf_loader_upgradeable;
 use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
-use solana_program::hash::Hash;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::{hash, Hash};
+use solana_program::program::{invoke, invoke_signed};
 use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::state::{AccountKind, AdminAuditLog, AdminAuditLogEntry};
+
+/// Derive the PDA from the seeds and the bump stored in the account at its creation.
+///
+/// Unlike `find_program_address`, doesn't search for the bump, so it is the cheaper way to
+/// validate accounts that keep their bump.
+pub fn create_pda(program_id: &Pubkey, seeds: &[&[u8]], nonce: u8) -> Result<Pubkey, ProgramError> {
+    let nonce_seed = [nonce];
+    let mut seeds = seeds.to_vec();
+    seeds.push(&nonce_seed);
+
+    Pubkey::create_program_address(&seeds, program_id).map_err(|_| ProgramError::InvalidArgument)
+}
 
 pub fn get_programdata_address(program_id: &Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0
@@ -17,6 +38,10 @@ pub fn get_associated_relay_round_address(program_id: &Pubkey, round_number: u32
     Pubkey::find_program_address(&[br"relay_round", &round_number.to_le_bytes()], program_id).0
 }
 
+pub fn get_associated_admin_audit_log_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"audit_log"], program_id).0
+}
+
 pub fn get_associated_proposal_address(
     program_id: &Pubkey,
     round_number: u32,
@@ -44,17 +69,12 @@ pub fn validate_programdata_account(
     nonce: u8,
     programdata_account: &Pubkey,
 ) -> Result<(), ProgramError> {
-    let (pda, expected_nonce) =
-        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+    let pda = create_pda(&bpf_loader_upgradeable::id(), &[program_id.as_ref()], nonce)?;
 
     if pda != *programdata_account {
         return Err(ProgramError::InvalidSeeds);
     }
 
-    if expected_nonce != nonce {
-        return Err(ProgramError::InvalidArgument);
-    }
-
     Ok(())
 }
 
@@ -65,7 +85,7 @@ pub fn validate_initializer_account(
     let upgrade_authority_address = match bincode::deserialize::<UpgradeableLoaderState>(
         &programdata_account_info.data.borrow(),
     )
-    .unwrap()
+    .map_err(|_| ProgramError::InvalidAccountData)?
     {
         UpgradeableLoaderState::ProgramData {
             upgrade_authority_address,
@@ -74,28 +94,43 @@ pub fn validate_initializer_account(
         _ => None,
     };
 
-    if upgrade_authority_address.unwrap() != *initializer_account {
+    if upgrade_authority_address != Some(*initializer_account) {
         return Err(ProgramError::IllegalOwner);
     }
 
     Ok(())
 }
 
+/// Check that the initializer is the upgrade authority of the program, returns the nonce of
+/// the programdata account.
+pub fn validate_initializer(
+    program_id: &Pubkey,
+    initializer_account: &Pubkey,
+    programdata_account_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let (programdata_pubkey, programdata_nonce) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+
+    if programdata_pubkey != *programdata_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    validate_initializer_account(initializer_account, programdata_account_info)?;
+
+    Ok(programdata_nonce)
+}
+
 pub fn validate_settings_account(
     program_id: &Pubkey,
     nonce: u8,
     account_info: &AccountInfo,
 ) -> Result<(), ProgramError> {
-    let (account, expected_nonce) = Pubkey::find_program_address(&[br"settings"], program_id);
+    let account = create_pda(program_id, &[br"settings"], nonce)?;
 
     if account != *account_info.key {
         return Err(ProgramError::InvalidArgument);
     }
 
-    if expected_nonce != nonce {
-        return Err(ProgramError::InvalidArgument);
-    }
-
     Ok(())
 }
 
@@ -110,7 +145,8 @@ pub fn validate_proposal_account(
     nonce: u8,
     proposal_account_info: &AccountInfo,
 ) -> Result<Pubkey, ProgramError> {
-    let (account, expected_nonce) = Pubkey::find_program_address(
+    let account = create_pda(
+        program_id,
         &[
             br"proposal",
             &round_number.to_le_bytes(),
@@ -119,40 +155,172 @@ pub fn validate_proposal_account(
             &event_configuration.to_bytes(),
             &event_data.to_bytes(),
         ],
-        program_id,
-    );
+        nonce,
+    )?;
 
     if account != *proposal_account_info.key {
         return Err(ProgramError::InvalidArgument);
     }
 
-    if expected_nonce != nonce {
-        return Err(ProgramError::InvalidArgument);
-    }
-
     Ok(account)
 }
 
+/// Check the deposit account is derived from the author and the seed. Deposits created before
+/// the author was part of the seeds are derived from the seed only.
 pub fn validate_deposit_account(
     program_id: &Pubkey,
+    author: &Pubkey,
     seed: u128,
     nonce: u8,
     deposit_account_info: &AccountInfo,
 ) -> Result<(), ProgramError> {
-    let (account, expected_nonce) =
-        Pubkey::find_program_address(&[br"deposit", &seed.to_le_bytes()], program_id);
+    let seed = seed.to_le_bytes();
+
+    let account = create_pda(program_id, &[br"deposit", &author.to_bytes(), &seed], nonce).ok();
+    let legacy_account = create_pda(program_id, &[br"deposit", &seed], nonce).ok();
 
-    if account != *deposit_account_info.key {
+    if account != Some(*deposit_account_info.key)
+        && legacy_account != Some(*deposit_account_info.key)
+    {
         return Err(ProgramError::InvalidArgument);
     }
 
-    if expected_nonce != nonce {
+    Ok(())
+}
+
+pub fn validate_admin_audit_log_account(
+    program_id: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let account = create_pda(program_id, &[br"audit_log"], nonce)?;
+
+    if account != *account_info.key {
         return Err(ProgramError::InvalidArgument);
     }
 
     Ok(())
 }
 
+/// Record privileged instruction into the admin audit log.
+///
+/// The audit log account is looked up among the instruction accounts, so it may be passed
+/// at any position. It is required, so the trail can't be skipped by leaving it out; nothing
+/// is recorded only while the log was not created yet.
+pub fn append_admin_audit_log(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    actor: &Pubkey,
+    instruction_data: &[u8],
+) -> Result<(), ProgramError> {
+    let audit_log_pubkey = get_associated_admin_audit_log_address(program_id);
+
+    let audit_log_account_info = accounts
+        .iter()
+        .find(|a| *a.key == audit_log_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if is_uncreated_account(audit_log_account_info) {
+        return Ok(());
+    }
+
+    if audit_log_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let mut audit_log_account_data = AdminAuditLog::unpack(&audit_log_account_info.data.borrow())?;
+
+    let audit_log_nonce = match audit_log_account_data.account_kind {
+        AccountKind::AdminAuditLog(nonce) => nonce,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+    validate_admin_audit_log_account(program_id, audit_log_nonce, audit_log_account_info)?;
+
+    let clock = Clock::get()?;
+
+    audit_log_account_data.append(AdminAuditLogEntry {
+        actor: *actor,
+        instruction: instruction_data.first().copied().unwrap_or_default(),
+        timestamp: clock.unix_timestamp,
+        params_hash: hash(instruction_data).to_bytes(),
+    });
+
+    AdminAuditLog::pack(
+        audit_log_account_data,
+        &mut audit_log_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
+
+/// Check the account holds enough lamports to be rent-exempt for its data.
+///
+/// Anyone can send lamports to an address, so existing accounts are not assumed to be funded.
+pub fn validate_rent_exempt(rent: &Rent, account_info: &AccountInfo) -> Result<(), ProgramError> {
+    if !rent.is_exempt(account_info.lamports(), account_info.data_len()) {
+        return Err(ProgramError::AccountNotRentExempt);
+    }
+
+    Ok(())
+}
+
+/// Whether the PDA is still to be created. Lamports sent to the address beforehand leave it
+/// owned by the system program.
+pub fn is_uncreated_account(account_info: &AccountInfo) -> bool {
+    *account_info.owner == solana_program::system_program::id()
+}
+
+/// Create a rent-exempt account of `space` bytes owned by `owner` at a PDA.
+///
+/// `create_account` fails once the address holds lamports, so anyone could block the
+/// creation by sending lamports to the address. Such an account is topped up to the rent
+/// exempt minimum, allocated and assigned instead.
+pub fn create_pda_account<'info>(
+    funder_account_info: &AccountInfo<'info>,
+    account_info: &AccountInfo<'info>,
+    rent: &Rent,
+    space: usize,
+    owner: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let required_lamports = 1.max(rent.minimum_balance(space));
+
+    if account_info.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                account_info.key,
+                required_lamports,
+                space as u64,
+                owner,
+            ),
+            accounts,
+            &[signer_seeds],
+        );
+    }
+
+    let top_up = required_lamports.saturating_sub(account_info.lamports());
+    if top_up > 0 {
+        invoke(
+            &system_instruction::transfer(funder_account_info.key, account_info.key, top_up),
+            accounts,
+        )?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(account_info.key, space as u64),
+        accounts,
+        &[signer_seeds],
+    )?;
+
+    invoke_signed(
+        &system_instruction::assign(account_info.key, owner),
+        accounts,
+        &[signer_seeds],
+    )
+}
+
 pub fn delete_account(account_info: &AccountInfo) {
     account_info.assign(&solana_program::system_program::id());
     let mut account_data = account_info.data.borrow_mut();
@@ -1,4 +1,13 @@
+pub mod context;
+pub mod ed25519;
 pub mod errors;
 pub mod helper;
+pub mod limit;
+pub mod merkle;
+#[cfg(feature = "schema")]
+pub mod schema;
+pub mod signing;
 pub mod state;
+pub mod token_cpi;
+pub mod ton;
 pub mod types;
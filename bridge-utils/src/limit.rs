@@ -0,0 +1,62 @@
+use solana_program::clock::SECONDS_PER_DAY;
+
+/// Amount accumulated against a daily limit, rolled over when a new epoch starts.
+///
+/// Borrows the epoch and amount fields of the account state it tracks.
+pub struct LimitTracker<'a> {
+    epoch: &'a mut i64,
+    amount: &'a mut u64,
+}
+
+impl<'a> LimitTracker<'a> {
+    pub fn new(epoch: &'a mut i64, amount: &'a mut u64) -> Self {
+        Self { epoch, amount }
+    }
+
+    /// Day number of the timestamp, timestamps before the unix epoch fall into negative days
+    pub fn epoch_of(unix_timestamp: i64) -> i64 {
+        unix_timestamp.div_euclid(SECONDS_PER_DAY as i64)
+    }
+
+    /// Epoch the amount is accumulated in
+    pub fn epoch(&self) -> i64 {
+        *self.epoch
+    }
+
+    /// Amount accumulated in the tracked epoch
+    pub fn amount(&self) -> u64 {
+        *self.amount
+    }
+
+    /// Start a new epoch once the clock has moved past the tracked one.
+    ///
+    /// A clock lagging behind the tracked epoch keeps counting into it, so skew never resets
+    /// the accumulated amount. Returns whether the epoch was rolled over.
+    pub fn roll_over(&mut self, current_epoch: i64) -> bool {
+        if current_epoch <= *self.epoch {
+            return false;
+        }
+
+        *self.epoch = current_epoch;
+        *self.amount = 0;
+
+        true
+    }
+
+    /// Count the amount in the tracked epoch, saturating instead of overflowing
+    pub fn add(&mut self, amount: u64) {
+        *self.amount = self.amount.saturating_add(amount);
+    }
+
+    /// Release an amount counted in `counted_epoch`, amounts of past epochs are already gone
+    pub fn release(&mut self, counted_epoch: i64, amount: u64) {
+        if counted_epoch == *self.epoch {
+            *self.amount = self.amount.saturating_sub(amount);
+        }
+    }
+
+    /// Whether the accumulated amount is over the limit
+    pub fn exceeds(&self, limit: u64) -> bool {
+        *self.amount > limit
+    }
+}
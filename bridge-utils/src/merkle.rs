@@ -0,0 +1,109 @@
+#[cfg(feature = "schema")]
+use borsh::BorshSchema;
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
+use serde::{Deserialize, Serialize};
+
+use solana_program::hash::{hash, hashv, Hash};
+use solana_program::pubkey::Pubkey;
+
+/// Proof that a key is a leaf of a merkle tree
+#[derive(Debug, Default, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct MerkleProof {
+    // Leaf position in the tree
+    pub index: u32,
+    // Sibling hashes from the leaf level up
+    pub path: Vec<Hash>,
+}
+
+pub fn get_merkle_leaf(key: &Pubkey) -> Hash {
+    hash(&key.to_bytes())
+}
+
+/// Merkle root of the keys in the given order. Leaves are sha256 of the keys, a node
+/// without a pair is moved to the next level as is.
+pub fn get_merkle_root(keys: &[Pubkey]) -> Hash {
+    let mut level = keys.iter().map(get_merkle_leaf).collect::<Vec<_>>();
+
+    if level.is_empty() {
+        return Hash::default();
+    }
+
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+
+    level[0]
+}
+
+/// Build a proof for the key at the index
+pub fn get_merkle_proof(keys: &[Pubkey], index: usize) -> Option<MerkleProof> {
+    if index >= keys.len() {
+        return None;
+    }
+
+    let mut level = keys.iter().map(get_merkle_leaf).collect::<Vec<_>>();
+    let mut position = index;
+    let mut path = Vec::new();
+
+    while level.len() > 1 {
+        let sibling = position ^ 1;
+        if sibling < level.len() {
+            path.push(level[sibling]);
+        }
+
+        level = next_level(&level);
+        position /= 2;
+    }
+
+    Some(MerkleProof {
+        index: index as u32,
+        path,
+    })
+}
+
+/// Check that the key is a leaf of the tree with the root and the number of leaves
+pub fn verify_merkle_proof(key: &Pubkey, proof: &MerkleProof, count: u32, root: &Hash) -> bool {
+    if proof.index >= count {
+        return false;
+    }
+
+    let mut node = get_merkle_leaf(key);
+    let mut position = proof.index;
+    let mut len = count;
+    let mut path = proof.path.iter();
+
+    while len > 1 {
+        let sibling = position ^ 1;
+        if sibling < len {
+            let sibling_node = match path.next() {
+                Some(sibling_node) => sibling_node,
+                None => return false,
+            };
+
+            node = if sibling > position {
+                hashv(&[node.as_ref(), sibling_node.as_ref()])
+            } else {
+                hashv(&[sibling_node.as_ref(), node.as_ref()])
+            };
+        }
+
+        position /= 2;
+        len -= len / 2;
+    }
+
+    path.next().is_none() && node == *root
+}
+
+fn next_level(level: &[Hash]) -> Vec<Hash> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hashv(&[left.as_ref(), right.as_ref()]),
+            [node] => *node,
+            _ => unreachable!(),
+        })
+        .collect()
+}
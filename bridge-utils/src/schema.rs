@@ -0,0 +1,108 @@
+//! Borsh schema export for clients decoding the bridge state without Rust.
+//!
+//! The schema lists packed accounts with their discriminator and length, logged events, and the
+//! definitions of every type they refer to, keyed by the Borsh declaration.
+
+use std::collections::{BTreeMap, HashMap};
+
+use borsh::schema::{BorshSchema, Declaration, Definition, Fields};
+use serde_json::{json, Value};
+
+use crate::state::ACCOUNT_DISCRIMINATOR_LEN;
+
+#[derive(Default)]
+pub struct SchemaExport {
+    accounts: Vec<Value>,
+    events: Vec<Value>,
+    definitions: HashMap<Declaration, Definition>,
+}
+
+impl SchemaExport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Packed account, the Borsh data follows the discriminator
+    pub fn account<T: BorshSchema>(&mut self, discriminator: [u8; 8], len: usize) -> &mut Self {
+        T::add_definitions_recursively(&mut self.definitions);
+
+        self.accounts.push(json!({
+            "type": T::declaration(),
+            "discriminator": hex::encode(discriminator),
+            "data_offset": ACCOUNT_DISCRIMINATOR_LEN,
+            "len": len,
+        }));
+
+        self
+    }
+
+    /// Event logged with `sol_log_data`
+    pub fn event<T: BorshSchema>(&mut self) -> &mut Self {
+        T::add_definitions_recursively(&mut self.definitions);
+
+        self.events.push(json!({
+            "type": T::declaration(),
+        }));
+
+        self
+    }
+
+    pub fn to_json(&self) -> Value {
+        // Sorted definitions keep the generated file stable between runs
+        let definitions = self
+            .definitions
+            .iter()
+            .map(|(declaration, definition)| (declaration.clone(), definition_to_json(definition)))
+            .collect::<BTreeMap<_, _>>();
+
+        json!({
+            "accounts": self.accounts,
+            "events": self.events,
+            "definitions": definitions,
+        })
+    }
+}
+
+fn definition_to_json(definition: &Definition) -> Value {
+    match definition {
+        Definition::Array { length, elements } => json!({
+            "kind": "array",
+            "length": length,
+            "elements": elements,
+        }),
+        Definition::Sequence { elements } => json!({
+            "kind": "sequence",
+            "elements": elements,
+        }),
+        Definition::Tuple { elements } => json!({
+            "kind": "tuple",
+            "elements": elements,
+        }),
+        Definition::Enum { variants } => json!({
+            "kind": "enum",
+            "variants": variants
+                .iter()
+                .map(|(name, declaration)| json!({ "name": name, "type": declaration }))
+                .collect::<Vec<_>>(),
+        }),
+        Definition::Struct { fields } => json!({
+            "kind": "struct",
+            "fields": fields_to_json(fields),
+        }),
+    }
+}
+
+fn fields_to_json(fields: &Fields) -> Vec<Value> {
+    match fields {
+        Fields::NamedFields(fields) => fields
+            .iter()
+            .map(|(name, declaration)| json!({ "name": name, "type": declaration }))
+            .collect(),
+        // Tuple struct fields are identified by their position
+        Fields::UnnamedFields(fields) => fields
+            .iter()
+            .map(|declaration| json!({ "type": declaration }))
+            .collect(),
+        Fields::Empty => vec![],
+    }
+}
@@ -0,0 +1,34 @@
+//! Domain separation of the messages signed off-chain.
+//!
+//! A signature authorizes a payload for a single instruction of a single program deployment,
+//! so it can't be replayed for another instruction, another deployment or another bridge.
+//! Signed bytes are `SIGNING_DOMAIN || program_id || instruction_tag || sha256(payload)`, where
+//! the instruction tag is the Borsh variant index of the instruction the signature is for.
+
+use solana_program::hash::{hash, HASH_BYTES};
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+
+/// Prefix of every message signed for the bridge programs
+pub const SIGNING_DOMAIN: &[u8] = b"EVER_SOL_BRIDGE";
+
+pub const SIGNING_PAYLOAD_LEN: usize = SIGNING_DOMAIN.len() + PUBKEY_BYTES + 1 + HASH_BYTES;
+
+/// Bytes signed off-chain to authorize the payload for the instruction of the program
+pub fn signing_payload(
+    program_id: &Pubkey,
+    instruction_tag: u8,
+    payload: &[u8],
+) -> [u8; SIGNING_PAYLOAD_LEN] {
+    let mut signing_payload = [0; SIGNING_PAYLOAD_LEN];
+
+    let (domain, rest) = signing_payload.split_at_mut(SIGNING_DOMAIN.len());
+    let (program, rest) = rest.split_at_mut(PUBKEY_BYTES);
+    let (tag, payload_hash) = rest.split_at_mut(1);
+
+    domain.copy_from_slice(SIGNING_DOMAIN);
+    program.copy_from_slice(program_id.as_ref());
+    tag[0] = instruction_tag;
+    payload_hash.copy_from_slice(hash(payload).as_ref());
+
+    signing_payload
+}
@@ -1,13 +1,22 @@
+#[cfg(feature = "schema")]
+use borsh::BorshSchema;
 use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_derive::BridgePack;
 use enum_as_inner::EnumAsInner;
+#[cfg(feature = "client")]
 use serde::{Deserialize, Serialize};
 
 use solana_program::program_error::ProgramError;
+use solana_program::program_pack::{IsInitialized, Pack, Sealed};
 use solana_program::pubkey::Pubkey;
 
 use super::types::Vote;
 
+/// Length of the discriminator leading every packed account
+pub const ACCOUNT_DISCRIMINATOR_LEN: usize = 8;
+
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
 pub struct Proposal {
     pub is_initialized: bool,
     pub account_kind: AccountKind,
@@ -22,36 +31,63 @@ pub struct Proposal {
 
 impl Proposal {
     pub fn pack_into_slice(&self, dst: &mut [u8]) {
+        // Discriminator of the concrete account type is kept as is
         let data = self.try_to_vec().unwrap();
-        let (left, _) = dst.split_at_mut(data.len());
+        let (left, _) = dst[ACCOUNT_DISCRIMINATOR_LEN..].split_at_mut(data.len());
         left.copy_from_slice(&data);
     }
 
-    pub fn unpack_from_slice(mut src: &[u8]) -> Result<Self, ProgramError> {
+    pub fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let mut src = src
+            .get(ACCOUNT_DISCRIMINATOR_LEN..)
+            .ok_or(ProgramError::InvalidAccountData)?;
         let unpacked = Self::deserialize(&mut src)?;
         Ok(unpacked)
     }
 }
 
-#[derive(Debug, Default, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+/// Counters of a relay round kept by the Token Proxy in its relay round stats account. The
+/// Round Loader reads them when the round is replaced, so the layout is shared here.
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RoundCounters {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    // Total number of votes cast in the round
+    pub total: u64,
+    // Votes cast by each relay, in the order of the round relays
+    pub votes: Vec<u32>,
+    // Withdrawals voted for in the round
+    pub withdrawals: u32,
+    // Sum of the voted withdrawal amounts, in base units of their tokens
+    pub volume: u128,
+    // Withdrawals created in the round that haven't reached a final status
+    pub active_withdrawals: u32,
+}
+
+impl RoundCounters {
+    pub fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let mut src = src
+            .get(ACCOUNT_DISCRIMINATOR_LEN..)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let unpacked = Self::deserialize(&mut src)?;
+        Ok(unpacked)
+    }
+}
+
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
 pub struct PDA {
     pub event_timestamp: u32,
     pub event_transaction_lt: u64,
     pub event_configuration: Pubkey,
 }
 
-#[derive(
-    Debug,
-    Copy,
-    Clone,
-    BorshSerialize,
-    BorshDeserialize,
-    Serialize,
-    Deserialize,
-    EnumAsInner,
-    PartialEq,
-    Eq,
-)]
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, EnumAsInner, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
 pub enum AccountKind {
     Settings(u8, u8),
     Deposit(u8),
@@ -59,6 +95,30 @@ pub enum AccountKind {
     RelayRound(u8),
     MultiVault(u8),
     TokenSettings(u8, u8), // Token settings nonce and mint/vault nonce
+    AdminAuditLog(u8),
+    ProposalVote(u8),
+    SettingsHistory(u8),
+    SettingsHistoryPage(u8),
+    VaultRotation(u8),
+    LpPosition(u8),
+    DepositIndex(u8),
+    DepositIndexPage(u8),
+    RewardConfig(u8),
+    RelayRoundStats(u8),
+    RoundReward(u8),
+    RoundDigest(u8),
+    EventIndex(u8),
+    TokenWhitelist(u8),
+    EventConfiguration(u8),
+    PayloadSchema(u8),
+    ExemptAccount(u8),
+    Nonce(u8),
+    DiscountConfig(u8, u8), // Discount config nonce and stake vault nonce
+    DiscountStake(u8),
+    RoundStats(u8),
+    KnownToken(u8),
+    ReportLog(u8),
+    ReportLogPage(u8),
 }
 
 impl AccountKind {
@@ -70,6 +130,89 @@ impl AccountKind {
             AccountKind::RelayRound(_) => 3,
             AccountKind::MultiVault(_) => 4,
             AccountKind::TokenSettings(_, _) => 5,
+            AccountKind::AdminAuditLog(_) => 6,
+            AccountKind::ProposalVote(_) => 7,
+            AccountKind::SettingsHistory(_) => 8,
+            AccountKind::SettingsHistoryPage(_) => 9,
+            AccountKind::VaultRotation(_) => 10,
+            AccountKind::LpPosition(_) => 11,
+            AccountKind::DepositIndex(_) => 12,
+            AccountKind::DepositIndexPage(_) => 13,
+            AccountKind::RewardConfig(_) => 14,
+            AccountKind::RelayRoundStats(_) => 15,
+            AccountKind::RoundReward(_) => 16,
+            AccountKind::RoundDigest(_) => 17,
+            AccountKind::EventIndex(_) => 18,
+            AccountKind::TokenWhitelist(_) => 19,
+            AccountKind::EventConfiguration(_) => 20,
+            AccountKind::PayloadSchema(_) => 21,
+            AccountKind::ExemptAccount(_) => 22,
+            AccountKind::Nonce(_) => 23,
+            AccountKind::DiscountConfig(_, _) => 24,
+            AccountKind::DiscountStake(_) => 25,
+            AccountKind::RoundStats(_) => 26,
+            AccountKind::KnownToken(_) => 27,
+            AccountKind::ReportLog(_) => 28,
+            AccountKind::ReportLogPage(_) => 29,
+        }
+    }
+}
+
+/// Number of admin actions kept in the audit log
+pub const ADMIN_AUDIT_LOG_CAPACITY: usize = 32;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 2400)] // 2351 + reserve
+pub struct AdminAuditLog {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Total number of recorded actions
+    pub total: u64,
+    // Ring buffer of the last actions
+    pub entries: Vec<AdminAuditLogEntry>,
+}
+
+impl AdminAuditLog {
+    pub fn append(&mut self, entry: AdminAuditLogEntry) {
+        if self.entries.len() < ADMIN_AUDIT_LOG_CAPACITY {
+            self.entries.push(entry);
+        } else {
+            let index = (self.total % ADMIN_AUDIT_LOG_CAPACITY as u64) as usize;
+            self.entries[index] = entry;
         }
+        self.total += 1;
     }
+
+    /// Entries ordered from the oldest to the newest one
+    pub fn ordered_entries(&self) -> Vec<&AdminAuditLogEntry> {
+        let split = (self.total % ADMIN_AUDIT_LOG_CAPACITY as u64) as usize;
+        if self.entries.len() < ADMIN_AUDIT_LOG_CAPACITY {
+            return self.entries.iter().collect();
+        }
+        self.entries[split..]
+            .iter()
+            .chain(self.entries[..split].iter())
+            .collect()
+    }
+}
+
+impl Sealed for AdminAuditLog {}
+
+impl IsInitialized for AdminAuditLog {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct AdminAuditLogEntry {
+    pub actor: Pubkey,
+    // Instruction tag
+    pub instruction: u8,
+    pub timestamp: i64,
+    // Hash of the instruction data
+    pub params_hash: [u8; 32],
 }
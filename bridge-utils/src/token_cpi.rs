@@ -0,0 +1,96 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+
+// SPL Token CPIs. Authorities owned by the program sign with `signer_seeds`, other authorities
+// have to sign the transaction and are passed with empty seeds.
+
+/// Transfer tokens from the source account
+pub fn transfer(
+    source_account_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    authority_account_info: &AccountInfo,
+    amount: u64,
+    accounts: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token::instruction::transfer(
+            &spl_token::id(),
+            source_account_info.key,
+            destination_account_info.key,
+            authority_account_info.key,
+            &[authority_account_info.key],
+            amount,
+        )?,
+        accounts,
+        signer_seeds,
+    )
+}
+
+/// Mint new tokens to the destination account
+pub fn mint_to(
+    mint_account_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    authority_account_info: &AccountInfo,
+    amount: u64,
+    accounts: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            &spl_token::id(),
+            mint_account_info.key,
+            destination_account_info.key,
+            authority_account_info.key,
+            &[authority_account_info.key],
+            amount,
+        )?,
+        accounts,
+        signer_seeds,
+    )
+}
+
+/// Burn tokens of the account
+pub fn burn(
+    account_info: &AccountInfo,
+    mint_account_info: &AccountInfo,
+    authority_account_info: &AccountInfo,
+    amount: u64,
+    accounts: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token::instruction::burn(
+            &spl_token::id(),
+            account_info.key,
+            mint_account_info.key,
+            authority_account_info.key,
+            &[authority_account_info.key],
+            amount,
+        )?,
+        accounts,
+        signer_seeds,
+    )
+}
+
+/// Close the token account, delivering its lamports to the destination
+pub fn close_account(
+    account_info: &AccountInfo,
+    destination_account_info: &AccountInfo,
+    authority_account_info: &AccountInfo,
+    accounts: &[AccountInfo],
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token::instruction::close_account(
+            &spl_token::id(),
+            account_info.key,
+            destination_account_info.key,
+            authority_account_info.key,
+            &[authority_account_info.key],
+        )?,
+        accounts,
+        signer_seeds,
+    )
+}
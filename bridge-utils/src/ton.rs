@@ -0,0 +1,474 @@
+use thiserror::Error;
+
+use solana_program::hash::hash;
+
+use crate::types::EverAddress;
+
+/// Maximum number of data bits in a cell
+pub const MAX_DATA_BITS: usize = 1023;
+/// Maximum number of cell references
+pub const MAX_REFS: usize = 4;
+/// Number of bytes ABI keeps in a single cell of a `bytes` or `string` chain
+pub const BYTES_PER_CELL: usize = 127;
+
+const BOC_MAGIC: [u8; 4] = [0xb5, 0xee, 0x9c, 0x72];
+
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CellError {
+    #[error("Cell data overflow")]
+    DataOverflow,
+    #[error("Cell references overflow")]
+    RefsOverflow,
+    #[error("Invalid bag of cells")]
+    InvalidBoc,
+    #[error("Exotic cells are not supported")]
+    ExoticCell,
+}
+
+/// Ordinary TVM cell
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Cell {
+    // Data bits, unused bits of the last byte are zeroed
+    data: Vec<u8>,
+    bit_len: usize,
+    refs: Vec<Cell>,
+}
+
+impl Cell {
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    pub fn references(&self) -> &[Cell] {
+        &self.refs
+    }
+
+    pub fn depth(&self) -> u16 {
+        self.refs
+            .iter()
+            .map(|cell| cell.depth() + 1)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Representation hash, the hash EVER contracts see as `tvm.hash(cell)`
+    pub fn repr_hash(&self) -> [u8; 32] {
+        let mut repr = self.descriptors().to_vec();
+        repr.extend(self.padded_data());
+
+        for cell in &self.refs {
+            repr.extend(cell.depth().to_be_bytes());
+        }
+        for cell in &self.refs {
+            repr.extend(cell.repr_hash());
+        }
+
+        hash(&repr).to_bytes()
+    }
+
+    fn descriptors(&self) -> [u8; 2] {
+        let d1 = self.refs.len() as u8;
+        let d2 = (self.bit_len / 8 + self.bit_len.div_ceil(8)) as u8;
+        [d1, d2]
+    }
+
+    /// Data with the completion tag appended to an incomplete last byte
+    fn padded_data(&self) -> Vec<u8> {
+        let mut data = self.data.clone();
+        let rem = self.bit_len % 8;
+        if rem != 0 {
+            if let Some(last) = data.last_mut() {
+                *last |= 0x80 >> rem;
+            }
+        }
+        data
+    }
+
+    /// Serialize the cell tree into a bag of cells without index and checksum
+    pub fn to_boc(&self) -> Vec<u8> {
+        let mut cells = Vec::new();
+        collect_cells(self, &mut cells);
+        cells.reverse();
+
+        let hashes = cells
+            .iter()
+            .map(|cell| cell.repr_hash())
+            .collect::<Vec<_>>();
+
+        let size_bytes = bytes_len(cells.len());
+
+        let mut cells_data = Vec::new();
+        for cell in &cells {
+            cells_data.extend(cell.descriptors());
+            cells_data.extend(cell.padded_data());
+            for r in &cell.refs {
+                let r_hash = r.repr_hash();
+                let index = hashes.iter().position(|h| *h == r_hash).unwrap_or_default();
+                write_uint(&mut cells_data, index, size_bytes);
+            }
+        }
+
+        let off_bytes = bytes_len(cells_data.len());
+
+        let mut boc = BOC_MAGIC.to_vec();
+        boc.push(size_bytes as u8);
+        boc.push(off_bytes as u8);
+        write_uint(&mut boc, cells.len(), size_bytes); // cells
+        write_uint(&mut boc, 1, size_bytes); // roots
+        write_uint(&mut boc, 0, size_bytes); // absent
+        write_uint(&mut boc, cells_data.len(), off_bytes);
+        write_uint(&mut boc, 0, size_bytes); // root index
+        boc.extend(cells_data);
+
+        boc
+    }
+
+    /// Deserialize a bag of cells with a single root of ordinary cells
+    pub fn from_boc(boc: &[u8]) -> Result<Cell, CellError> {
+        let mut reader = BocReader { data: boc, pos: 0 };
+
+        if reader.read_bytes(4)? != BOC_MAGIC {
+            return Err(CellError::InvalidBoc);
+        }
+
+        let flags = reader.read_bytes(1)?[0];
+        let has_idx = flags & 0x80 != 0;
+        let has_crc = flags & 0x40 != 0;
+        let size_bytes = (flags & 0x07) as usize;
+        let off_bytes = reader.read_bytes(1)?[0] as usize;
+
+        if size_bytes == 0 || size_bytes > 4 || off_bytes == 0 || off_bytes > 8 {
+            return Err(CellError::InvalidBoc);
+        }
+
+        let cells_count = reader.read_uint(size_bytes)?;
+        let roots_count = reader.read_uint(size_bytes)?;
+        let _absent = reader.read_uint(size_bytes)?;
+        let cells_size = reader.read_uint(off_bytes)?;
+
+        // Every cell takes at least two descriptor bytes
+        if roots_count != 1 || cells_size > boc.len() || cells_count > cells_size / 2 {
+            return Err(CellError::InvalidBoc);
+        }
+
+        let root_index = reader.read_uint(size_bytes)?;
+
+        if has_idx {
+            reader.read_bytes(cells_count * off_bytes)?;
+        }
+
+        let cells_end = reader.pos + cells_size;
+
+        let mut raw_cells = Vec::with_capacity(cells_count);
+        for _ in 0..cells_count {
+            let d1 = reader.read_bytes(1)?[0];
+            let d2 = reader.read_bytes(1)?[0];
+
+            if d1 & 0x08 != 0 || d1 >> 5 != 0 {
+                return Err(CellError::ExoticCell);
+            }
+            if d1 & 0x10 != 0 {
+                // Stored hash and depth of a level 0 cell
+                reader.read_bytes(32 + 2)?;
+            }
+
+            let refs_count = (d1 & 0x07) as usize;
+            if refs_count > MAX_REFS {
+                return Err(CellError::InvalidBoc);
+            }
+
+            let mut data = reader.read_bytes(d2.div_ceil(2) as usize)?.to_vec();
+            let mut bit_len = data.len() * 8;
+
+            if !d2.is_multiple_of(2) {
+                // Strip the completion tag
+                let last = data.last_mut().ok_or(CellError::InvalidBoc)?;
+                if *last == 0 {
+                    return Err(CellError::InvalidBoc);
+                }
+                let tag = last.trailing_zeros() as usize;
+                *last &= !(1 << tag);
+                bit_len -= tag + 1;
+            }
+
+            let mut refs = Vec::with_capacity(refs_count);
+            for _ in 0..refs_count {
+                refs.push(reader.read_uint(size_bytes)?);
+            }
+
+            raw_cells.push((data, bit_len, refs));
+        }
+
+        if reader.pos != cells_end {
+            return Err(CellError::InvalidBoc);
+        }
+
+        if has_crc {
+            let crc = crc32c(&boc[..reader.pos]);
+            if reader.read_bytes(4)? != crc.to_le_bytes() {
+                return Err(CellError::InvalidBoc);
+            }
+        }
+
+        // References always point to cells further in the bag
+        let mut cells: Vec<Option<Cell>> = vec![None; cells_count];
+        for (index, (data, bit_len, refs)) in raw_cells.into_iter().enumerate().rev() {
+            let refs = refs
+                .into_iter()
+                .map(|r| match cells.get(r) {
+                    Some(Some(cell)) if r > index => Ok(cell.clone()),
+                    _ => Err(CellError::InvalidBoc),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            cells[index] = Some(Cell {
+                data,
+                bit_len,
+                refs,
+            });
+        }
+
+        cells
+            .get_mut(root_index)
+            .and_then(Option::take)
+            .ok_or(CellError::InvalidBoc)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CellBuilder {
+    data: Vec<u8>,
+    bit_len: usize,
+    refs: Vec<Cell>,
+}
+
+impl CellBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bit_len(&self) -> usize {
+        self.bit_len
+    }
+
+    pub fn store_bit(&mut self, bit: bool) -> Result<&mut Self, CellError> {
+        if self.bit_len == MAX_DATA_BITS {
+            return Err(CellError::DataOverflow);
+        }
+
+        if self.bit_len.is_multiple_of(8) {
+            self.data.push(0);
+        }
+        if bit {
+            let last = self.data.len() - 1;
+            self.data[last] |= 0x80 >> (self.bit_len % 8);
+        }
+        self.bit_len += 1;
+
+        Ok(self)
+    }
+
+    /// Store the first `bits` bits of the data
+    pub fn store_bits(&mut self, data: &[u8], bits: usize) -> Result<&mut Self, CellError> {
+        if bits > data.len() * 8 {
+            return Err(CellError::DataOverflow);
+        }
+        if self.bit_len + bits > MAX_DATA_BITS {
+            return Err(CellError::DataOverflow);
+        }
+
+        for i in 0..bits {
+            self.store_bit(data[i / 8] & (0x80 >> (i % 8)) != 0)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Store the lowest `bits` bits of the value, big endian
+    pub fn store_uint(&mut self, value: u128, bits: usize) -> Result<&mut Self, CellError> {
+        if bits > 128 || (bits < 128 && value >> bits != 0) {
+            return Err(CellError::DataOverflow);
+        }
+
+        // Move the stored bits to the start of the buffer
+        let aligned = if bits == 0 { 0 } else { value << (128 - bits) };
+
+        self.store_bits(&aligned.to_be_bytes(), bits)
+    }
+
+    /// Store the value in two's complement, big endian
+    pub fn store_int(&mut self, value: i64, bits: usize) -> Result<&mut Self, CellError> {
+        if bits == 0 || bits > 64 {
+            return Err(CellError::DataOverflow);
+        }
+
+        let min = -(1i128 << (bits - 1));
+        let max = (1i128 << (bits - 1)) - 1;
+        if (value as i128) < min || (value as i128) > max {
+            return Err(CellError::DataOverflow);
+        }
+
+        let mask = if bits == 64 {
+            u64::MAX
+        } else {
+            (1u64 << bits) - 1
+        };
+
+        self.store_uint((value as u64 & mask) as u128, bits)
+    }
+
+    pub fn store_u256(&mut self, value: &[u8; 32]) -> Result<&mut Self, CellError> {
+        self.store_bits(value, 256)
+    }
+
+    pub fn store_reference(&mut self, cell: Cell) -> Result<&mut Self, CellError> {
+        if self.refs.len() == MAX_REFS {
+            return Err(CellError::RefsOverflow);
+        }
+
+        self.refs.push(cell);
+
+        Ok(self)
+    }
+
+    /// Store `MsgAddressInt` of the address
+    pub fn store_address(&mut self, address: &EverAddress) -> Result<&mut Self, CellError> {
+        match address {
+            EverAddress::AddrStd(addr) => {
+                self.store_uint(0b10, 2)?
+                    .store_bit(false)?
+                    .store_int(addr.workchain_id as i64, 8)?
+                    .store_u256(&addr.address)?;
+            }
+            EverAddress::AddrStdAnycast(addr) => {
+                self.store_uint(0b10, 2)?
+                    .store_bit(true)?
+                    .store_uint(addr.anycast.depth as u128, 5)?
+                    .store_uint(
+                        addr.anycast.rewrite_pfx as u128,
+                        addr.anycast.depth as usize,
+                    )?
+                    .store_int(addr.workchain_id as i64, 8)?
+                    .store_u256(&addr.address)?;
+            }
+            EverAddress::AddrVar(addr) => {
+                self.store_uint(0b11, 2)?;
+                match addr.anycast {
+                    Some(anycast) => {
+                        self.store_bit(true)?
+                            .store_uint(anycast.depth as u128, 5)?
+                            .store_uint(anycast.rewrite_pfx as u128, anycast.depth as usize)?;
+                    }
+                    None => {
+                        self.store_bit(false)?;
+                    }
+                }
+                self.store_uint(addr.addr_len as u128, 9)?
+                    .store_int(addr.workchain_id as i64, 32)?
+                    .store_bits(&addr.bytes(), addr.addr_len as usize)?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Store ABI `bytes` or `string` as a reference to a chain of cells
+    pub fn store_bytes_ref(&mut self, bytes: &[u8]) -> Result<&mut Self, CellError> {
+        self.store_reference(bytes_to_cell(bytes)?)
+    }
+
+    pub fn build(self) -> Cell {
+        Cell {
+            data: self.data,
+            bit_len: self.bit_len,
+            refs: self.refs,
+        }
+    }
+}
+
+/// Chain of cells holding the bytes, the layout ABI uses for `bytes` and `string`
+pub fn bytes_to_cell(bytes: &[u8]) -> Result<Cell, CellError> {
+    let mut next: Option<Cell> = None;
+
+    for chunk in bytes.chunks(BYTES_PER_CELL).rev() {
+        let mut builder = CellBuilder::new();
+        builder.store_bits(chunk, chunk.len() * 8)?;
+        if let Some(cell) = next.take() {
+            builder.store_reference(cell)?;
+        }
+        next = Some(builder.build());
+    }
+
+    Ok(next.unwrap_or_default())
+}
+
+/// Cell stored in the bag of cells, an empty cell for no data
+pub fn boc_to_cell(boc: &[u8]) -> Result<Cell, CellError> {
+    if boc.is_empty() {
+        return Ok(Cell::default());
+    }
+
+    Cell::from_boc(boc)
+}
+
+fn collect_cells<'a>(cell: &'a Cell, cells: &mut Vec<&'a Cell>) {
+    let cell_hash = cell.repr_hash();
+    if cells.iter().any(|c| c.repr_hash() == cell_hash) {
+        return;
+    }
+
+    for r in cell.refs.iter().rev() {
+        collect_cells(r, cells);
+    }
+
+    cells.push(cell);
+}
+
+fn bytes_len(value: usize) -> usize {
+    let bits = usize::BITS - value.leading_zeros();
+    (bits as usize).div_ceil(8).max(1)
+}
+
+fn write_uint(dst: &mut Vec<u8>, value: usize, bytes: usize) {
+    dst.extend(&(value as u64).to_be_bytes()[8 - bytes..]);
+}
+
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0x82f6_3b78
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+struct BocReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BocReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], CellError> {
+        let end = self.pos.checked_add(len).ok_or(CellError::InvalidBoc)?;
+        let bytes = self.data.get(self.pos..end).ok_or(CellError::InvalidBoc)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_uint(&mut self, len: usize) -> Result<usize, CellError> {
+        Ok(self
+            .read_bytes(len)?
+            .iter()
+            .fold(0, |value, byte| (value << 8) | *byte as usize))
+    }
+}
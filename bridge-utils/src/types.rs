@@ -1,32 +1,76 @@
 use std::str::FromStr;
 use std::{cmp, fmt, mem};
 
+#[cfg(feature = "schema")]
+use borsh::BorshSchema;
 use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 pub const RELAY_REPARATION: u64 = 20000;
 
-#[derive(
-    Debug, Copy, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
-)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
 pub enum Vote {
     None,
     Confirm,
     Reject,
 }
 
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
-)]
+/// Max number of bits in the anycast rewrite prefix
+pub const MAX_ANYCAST_DEPTH: u8 = 30;
+/// Max number of bits in the variable length address
+pub const MAX_ADDR_VAR_LEN: u16 = 511;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
 pub enum EverAddress {
     AddrStd(MsgAddrStd),
+    AddrVar(MsgAddrVar),
+    AddrStdAnycast(MsgAddrStdAnycast),
 }
 
 impl EverAddress {
     pub fn with_standart(workchain_id: i8, address: [u8; 32]) -> Self {
         EverAddress::AddrStd(MsgAddrStd::with_address(workchain_id, address))
     }
+
+    /// Standard address without anycast, the only kind bridge events are built for
+    pub fn is_standart(&self) -> bool {
+        matches!(self, EverAddress::AddrStd(_))
+    }
+
+    pub fn validate(&self) -> Result<(), EverAddressError> {
+        match self {
+            EverAddress::AddrStd(_) => Ok(()),
+            EverAddress::AddrVar(addr) => {
+                if let Some(anycast) = &addr.anycast {
+                    anycast.validate()?;
+                }
+
+                if addr.addr_len > MAX_ADDR_VAR_LEN {
+                    return Err(EverAddressError::AddressLen);
+                }
+
+                // Bits past the address length must be zero
+                let len = addr.addr_len as usize;
+                let padded = addr.bytes().iter().enumerate().any(|(i, byte)| {
+                    let used_bits = len.saturating_sub(i * 8).min(8);
+                    byte & (0xffu8.checked_shr(used_bits as u32).unwrap_or_default()) != 0
+                });
+
+                if padded {
+                    return Err(EverAddressError::AddressPadding);
+                }
+
+                Ok(())
+            }
+            EverAddress::AddrStdAnycast(addr) => addr.anycast.validate(),
+        }
+    }
 }
 
 impl FromStr for EverAddress {
@@ -38,13 +82,23 @@ impl FromStr for EverAddress {
             return Err("wrong format".to_string().into());
         }
 
-        let workchain_id = parts[len - 2].parse::<i8>()?;
+        let workchain_id = parts[len - 2].parse::<i32>()?;
         let address = hex::decode(parts[len - 1])?;
 
-        Ok(EverAddress::with_standart(
-            workchain_id,
-            <[u8; 32]>::try_from(address.as_slice())?,
-        ))
+        match (
+            i8::try_from(workchain_id),
+            <[u8; 32]>::try_from(address.as_slice()),
+        ) {
+            (Ok(workchain_id), Ok(address)) => {
+                Ok(EverAddress::with_standart(workchain_id, address))
+            }
+            _ => {
+                let addr = EverAddress::AddrVar(MsgAddrVar::with_address(workchain_id, &address)?);
+                addr.validate()?;
+
+                Ok(addr)
+            }
+        }
     }
 }
 
@@ -54,13 +108,25 @@ impl fmt::Display for EverAddress {
             EverAddress::AddrStd(addr) => {
                 write!(f, "{}:{}", addr.workchain_id, hex::encode(addr.address))
             }
+            EverAddress::AddrVar(addr) => {
+                let len = (addr.addr_len as usize).div_ceil(8);
+                write!(
+                    f,
+                    "{}:{}",
+                    addr.workchain_id,
+                    hex::encode(&addr.bytes()[..len])
+                )
+            }
+            EverAddress::AddrStdAnycast(addr) => {
+                write!(f, "{}:{}", addr.workchain_id, hex::encode(addr.address))
+            }
         }
     }
 }
 
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
-)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
 pub struct MsgAddrStd {
     pub workchain_id: i8,
     pub address: [u8; 32],
@@ -75,19 +141,93 @@ impl MsgAddrStd {
     }
 }
 
-#[derive(
-    Clone,
-    Copy,
-    PartialEq,
-    Eq,
-    Hash,
-    Debug,
-    Default,
-    Serialize,
-    Deserialize,
-    BorshSerialize,
-    BorshDeserialize,
-)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct MsgAddrVar {
+    pub anycast: Option<AnycastInfo>,
+    // Number of address bits
+    pub addr_len: u16,
+    pub workchain_id: i32,
+    // Address bits, zero padded to 512 bits
+    pub address: [UInt256; 2],
+}
+
+impl MsgAddrVar {
+    pub fn with_address(workchain_id: i32, address: &[u8]) -> Result<Self, EverAddressError> {
+        let mut bytes = [0; 64];
+        bytes
+            .get_mut(..address.len())
+            .ok_or(EverAddressError::AddressLen)?
+            .copy_from_slice(address);
+
+        let (hi, lo) = bytes.split_at(32);
+
+        Ok(MsgAddrVar {
+            anycast: None,
+            addr_len: u16::try_from(address.len() * 8).map_err(|_| EverAddressError::AddressLen)?,
+            workchain_id,
+            address: [UInt256::new(hi), UInt256::new(lo)],
+        })
+    }
+
+    pub fn bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(self.address[0].as_slice());
+        bytes[32..].copy_from_slice(self.address[1].as_slice());
+        bytes
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct MsgAddrStdAnycast {
+    pub anycast: AnycastInfo,
+    pub workchain_id: i8,
+    pub address: [u8; 32],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct AnycastInfo {
+    // Number of rewritten address bits
+    pub depth: u8,
+    // Rewrite prefix in the lowest `depth` bits
+    pub rewrite_pfx: u32,
+}
+
+impl AnycastInfo {
+    pub fn validate(&self) -> Result<(), EverAddressError> {
+        if self.depth == 0 || self.depth > MAX_ANYCAST_DEPTH {
+            return Err(EverAddressError::AnycastDepth);
+        }
+
+        if self.rewrite_pfx >> self.depth != 0 {
+            return Err(EverAddressError::AnycastPrefix);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize))]
+pub enum EverAddressError {
+    #[error("Anycast depth is out of range")]
+    AnycastDepth,
+    #[error("Anycast prefix is longer than its depth")]
+    AnycastPrefix,
+    #[error("Address length doesn't match address bits")]
+    AddressLen,
+    #[error("Address padding bits are set")]
+    AddressPadding,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
 pub struct UInt128([u8; 16]);
 
 impl From<[u8; 16]> for UInt128 {
@@ -108,19 +248,9 @@ impl UInt128 {
     }
 }
 
-#[derive(
-    Clone,
-    Copy,
-    PartialEq,
-    Eq,
-    Hash,
-    Debug,
-    Default,
-    Serialize,
-    Deserialize,
-    BorshSerialize,
-    BorshDeserialize,
-)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
 pub struct UInt256([u8; 32]);
 
 impl UInt256 {
@@ -172,10 +302,60 @@ impl FromStr for UInt256 {
     }
 }
 
-#[derive(Error, Debug, Serialize, Clone, PartialEq)]
+#[derive(Error, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize))]
 pub enum ParseUInt256Error {
     #[error("String is the wrong size")]
     WrongSize,
     #[error("Invalid HEX string")]
     Invalid,
 }
+
+#[cfg(all(feature = "devnet", feature = "localnet"))]
+compile_error!("Features `devnet` and `localnet` are mutually exclusive");
+
+/// Solana cluster the bridge programs are deployed to
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+pub enum Cluster {
+    Mainnet,
+    Devnet,
+    Localnet,
+}
+
+impl Cluster {
+    /// Cluster selected by the `devnet`/`localnet` cargo features, mainnet by default
+    pub const CURRENT: Cluster = if cfg!(feature = "devnet") {
+        Cluster::Devnet
+    } else if cfg!(feature = "localnet") {
+        Cluster::Localnet
+    } else {
+        Cluster::Mainnet
+    };
+
+    /// Whether `Initialize` may re-configure initialized settings. Allowed on local validators
+    /// only, so test setups can be re-run against the same deployment.
+    pub const fn reinit_allowed(self) -> bool {
+        matches!(self, Cluster::Localnet)
+    }
+}
+
+impl FromStr for Cluster {
+    type Err = ParseClusterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mainnet" | "mainnet-beta" => Ok(Cluster::Mainnet),
+            "devnet" => Ok(Cluster::Devnet),
+            "localnet" | "localhost" => Ok(Cluster::Localnet),
+            _ => Err(ParseClusterError::Unknown),
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize))]
+pub enum ParseClusterError {
+    #[error("Unknown cluster")]
+    Unknown,
+}
@@ -0,0 +1,126 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+const PROGRAM_ID: Pubkey = Pubkey::new_from_array([1; 32]);
+
+#[derive(Accounts)]
+struct TestAccounts<'a, 'info> {
+    #[account(signer)]
+    authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable, seeds = [br"settings"])]
+    settings_account_info: &'a AccountInfo<'info>,
+    #[account(seeds = [br"vault", authority_account_info.key.as_ref()])]
+    vault_account_info: &'a AccountInfo<'info>,
+}
+
+struct TestAccount {
+    key: Pubkey,
+    is_signer: bool,
+    is_writable: bool,
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+}
+
+impl TestAccount {
+    fn new(key: Pubkey, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            key,
+            is_signer,
+            is_writable,
+            lamports: 0,
+            data: vec![],
+            owner: Pubkey::default(),
+        }
+    }
+
+    fn info(&mut self) -> AccountInfo {
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            self.is_writable,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            false,
+            0,
+        )
+    }
+}
+
+fn try_context(accounts: &mut [TestAccount]) -> Result<(), ProgramError> {
+    let account_infos = accounts.iter_mut().map(|a| a.info()).collect::<Vec<_>>();
+
+    let ctx = Context::<TestAccounts>::new(&PROGRAM_ID, &account_infos)?;
+    assert!(ctx.remaining_accounts.is_empty());
+
+    Ok(())
+}
+
+fn test_accounts() -> Vec<TestAccount> {
+    let authority = Pubkey::new_unique();
+
+    let (settings, _) = Pubkey::find_program_address(&[br"settings"], &PROGRAM_ID);
+    let (vault, _) = Pubkey::find_program_address(&[br"vault", authority.as_ref()], &PROGRAM_ID);
+
+    vec![
+        TestAccount::new(authority, true, false),
+        TestAccount::new(settings, false, true),
+        TestAccount::new(vault, false, false),
+    ]
+}
+
+#[test]
+fn test_accounts_valid() {
+    let mut accounts = test_accounts();
+    assert_eq!(try_context(&mut accounts), Ok(()));
+}
+
+#[test]
+fn test_accounts_missing_signer() {
+    let mut accounts = test_accounts();
+    accounts[0].is_signer = false;
+
+    assert_eq!(
+        try_context(&mut accounts),
+        Err(ProgramError::MissingRequiredSignature)
+    );
+}
+
+#[test]
+fn test_accounts_readonly() {
+    let mut accounts = test_accounts();
+    accounts[1].is_writable = false;
+
+    assert_eq!(
+        try_context(&mut accounts),
+        Err(ProgramError::InvalidArgument)
+    );
+}
+
+#[test]
+fn test_accounts_wrong_seeds() {
+    // Vault of another authority
+    let mut accounts = test_accounts();
+    accounts[2].key = test_accounts()[2].key;
+
+    assert_eq!(
+        try_context(&mut accounts),
+        Err(ProgramError::InvalidArgument)
+    );
+}
+
+#[test]
+fn test_accounts_not_enough_keys() {
+    let mut accounts = test_accounts();
+    accounts.pop();
+
+    assert_eq!(
+        try_context(&mut accounts),
+        Err(ProgramError::NotEnoughAccountKeys)
+    );
+}
@@ -0,0 +1,27 @@
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::program_error::ProgramError;
+
+#[test]
+fn test_error_codes_round_trip() {
+    let last_code = SolanaBridgeError::BufferHashMismatch as u32;
+
+    for code in 0..=last_code {
+        let error = SolanaBridgeError::try_from(code).expect("known code");
+
+        assert_eq!(error as u32, code);
+        assert_eq!(ProgramError::from(error), ProgramError::Custom(code));
+    }
+
+    assert!(SolanaBridgeError::try_from(last_code + 1).is_err());
+}
+
+#[test]
+fn test_error_codes_are_stable() {
+    // Codes are part of the client API, variants are only appended
+    assert_eq!(SolanaBridgeError::DepositLimit as u32, 0);
+    assert_eq!(SolanaBridgeError::InsufficientVaultBalance as u32, 8);
+    assert_eq!(SolanaBridgeError::VotesOverflow as u32, 14);
+    assert_eq!(SolanaBridgeError::AlreadyInitialized as u32, 63);
+    assert_eq!(SolanaBridgeError::KnownTokenMismatch as u32, 69);
+}
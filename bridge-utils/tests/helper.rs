@@ -0,0 +1,116 @@
+use bridge_utils::helper::*;
+
+use solana_program::account_info::AccountInfo;
+use solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_create_pda() {
+    let program_id = Pubkey::new_unique();
+    let seed = 42u128.to_le_bytes();
+
+    let (pda, nonce) = Pubkey::find_program_address(&[br"deposit", &seed], &program_id);
+
+    // Stored bump derives the same address without the search
+    assert_eq!(
+        create_pda(&program_id, &[br"deposit", &seed], nonce).unwrap(),
+        pda
+    );
+
+    // Any other bump doesn't
+    for other_nonce in (0..=u8::MAX).filter(|n| *n != nonce) {
+        if let Ok(other_pda) = create_pda(&program_id, &[br"deposit", &seed], other_nonce) {
+            assert_ne!(other_pda, pda);
+        }
+    }
+}
+
+fn check_initializer(
+    program_id: &Pubkey,
+    initializer: &Pubkey,
+    programdata: Pubkey,
+    mut data: Vec<u8>,
+) -> Result<u8, ProgramError> {
+    let mut lamports = 0;
+    let owner = bpf_loader_upgradeable::id();
+
+    let programdata_account_info = AccountInfo::new(
+        &programdata,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    validate_initializer(program_id, initializer, &programdata_account_info)
+}
+
+#[test]
+fn test_validate_initializer() {
+    let program_id = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+
+    let (programdata, programdata_nonce) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+
+    let programdata_data = |upgrade_authority_address| {
+        bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address,
+        })
+        .unwrap()
+    };
+
+    // Upgrade authority of the program
+    assert_eq!(
+        check_initializer(
+            &program_id,
+            &authority,
+            programdata,
+            programdata_data(Some(authority)),
+        ),
+        Ok(programdata_nonce)
+    );
+
+    // Anyone else
+    assert_eq!(
+        check_initializer(
+            &program_id,
+            &Pubkey::new_unique(),
+            programdata,
+            programdata_data(Some(authority)),
+        ),
+        Err(ProgramError::IllegalOwner)
+    );
+
+    // Immutable program has no authority to initialize it
+    assert_eq!(
+        check_initializer(&program_id, &authority, programdata, programdata_data(None)),
+        Err(ProgramError::IllegalOwner)
+    );
+
+    // Programdata of another program
+    let (other_programdata, _) = Pubkey::find_program_address(
+        &[Pubkey::new_unique().as_ref()],
+        &bpf_loader_upgradeable::id(),
+    );
+    assert_eq!(
+        check_initializer(
+            &program_id,
+            &authority,
+            other_programdata,
+            programdata_data(Some(authority)),
+        ),
+        Err(ProgramError::InvalidArgument)
+    );
+
+    // Account which isn't programdata at all
+    assert_eq!(
+        check_initializer(&program_id, &authority, programdata, vec![0xff; 4]),
+        Err(ProgramError::InvalidAccountData)
+    );
+}
@@ -0,0 +1,76 @@
+use bridge_utils::limit::LimitTracker;
+use solana_program::clock::SECONDS_PER_DAY;
+
+#[test]
+fn test_limit_tracker_epoch_of() {
+    let day = SECONDS_PER_DAY as i64;
+
+    assert_eq!(LimitTracker::epoch_of(0), 0);
+    assert_eq!(LimitTracker::epoch_of(day - 1), 0);
+    assert_eq!(LimitTracker::epoch_of(day), 1);
+    assert_eq!(LimitTracker::epoch_of(-1), -1);
+    assert_eq!(LimitTracker::epoch_of(i64::MIN), i64::MIN / day - 1);
+}
+
+#[test]
+fn test_limit_tracker_roll_over() {
+    let mut epoch = 10;
+    let mut amount = 500;
+
+    let mut tracker = LimitTracker::new(&mut epoch, &mut amount);
+
+    // Same epoch keeps the amount
+    assert!(!tracker.roll_over(10));
+    assert_eq!(tracker.amount(), 500);
+
+    // Clock behind the tracked epoch keeps the amount
+    assert!(!tracker.roll_over(3));
+    assert_eq!(tracker.epoch(), 10);
+    assert_eq!(tracker.amount(), 500);
+
+    // Next epoch resets the amount
+    assert!(tracker.roll_over(11));
+    assert_eq!(tracker.epoch(), 11);
+    assert_eq!(tracker.amount(), 0);
+
+    assert_eq!(epoch, 11);
+    assert_eq!(amount, 0);
+}
+
+#[test]
+fn test_limit_tracker_add_and_release() {
+    let mut epoch = 1;
+    let mut amount = 0;
+
+    let mut tracker = LimitTracker::new(&mut epoch, &mut amount);
+
+    tracker.add(70);
+    assert!(!tracker.exceeds(100));
+
+    tracker.add(70);
+    assert!(tracker.exceeds(100));
+
+    // Amounts of past epochs are not released
+    tracker.release(0, 70);
+    assert_eq!(tracker.amount(), 140);
+
+    tracker.release(1, 70);
+    assert_eq!(tracker.amount(), 70);
+    assert!(!tracker.exceeds(100));
+
+    // Releasing more than counted stops at zero
+    tracker.release(1, 100);
+    assert_eq!(tracker.amount(), 0);
+}
+
+#[test]
+fn test_limit_tracker_overflow() {
+    let mut epoch = 1;
+    let mut amount = u64::MAX - 1;
+
+    let mut tracker = LimitTracker::new(&mut epoch, &mut amount);
+
+    tracker.add(10);
+    assert_eq!(tracker.amount(), u64::MAX);
+    assert!(tracker.exceeds(u64::MAX - 1));
+}
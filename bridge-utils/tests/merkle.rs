@@ -0,0 +1,56 @@
+use bridge_utils::merkle::*;
+
+use solana_program::hash::{hashv, Hash};
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_merkle_root() {
+    assert_eq!(get_merkle_root(&[]), Hash::default());
+
+    let keys = (0..3).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+    assert_eq!(get_merkle_root(&keys[..1]), get_merkle_leaf(&keys[0]));
+
+    // Node without a pair is moved to the next level as is
+    let leaves = keys.iter().map(get_merkle_leaf).collect::<Vec<_>>();
+    let root = hashv(&[
+        hashv(&[leaves[0].as_ref(), leaves[1].as_ref()]).as_ref(),
+        leaves[2].as_ref(),
+    ]);
+    assert_eq!(get_merkle_root(&keys), root);
+}
+
+#[test]
+fn test_merkle_proof() {
+    for count in 1..=17 {
+        let keys = (0..count).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        let root = get_merkle_root(&keys);
+
+        for (index, key) in keys.iter().enumerate() {
+            let proof = get_merkle_proof(&keys, index).unwrap();
+            assert!(verify_merkle_proof(key, &proof, count as u32, &root));
+
+            // Proof is bound to the key and the tree
+            assert!(!verify_merkle_proof(
+                &Pubkey::new_unique(),
+                &proof,
+                count as u32,
+                &root
+            ));
+            assert!(!verify_merkle_proof(
+                key,
+                &proof,
+                count as u32,
+                &Hash::new_unique()
+            ));
+
+            if !proof.path.is_empty() {
+                let mut truncated = proof.clone();
+                truncated.path.pop();
+                assert!(!verify_merkle_proof(key, &truncated, count as u32, &root));
+            }
+        }
+
+        assert_eq!(get_merkle_proof(&keys, count), None);
+    }
+}
@@ -0,0 +1,47 @@
+use bridge_utils::signing::*;
+
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_signing_payload_vectors() {
+    // Empty payload for a gasless deposit tag
+    assert_eq!(
+        hex::encode(signing_payload(&Pubkey::new_from_array([1; 32]), 79, b"")),
+        concat!(
+            "455645525f534f4c5f425249444745",
+            "0101010101010101010101010101010101010101010101010101010101010101",
+            "4f",
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        )
+    );
+
+    assert_eq!(
+        hex::encode(signing_payload(
+            &Pubkey::new_from_array([2; 32]),
+            0,
+            b"payload"
+        )),
+        concat!(
+            "455645525f534f4c5f425249444745",
+            "0202020202020202020202020202020202020202020202020202020202020202",
+            "00",
+            "239f59ed55e737c77147cf55ad0c1b030b6d7ee748a7426952f9b852d5a935e5",
+        )
+    );
+}
+
+#[test]
+fn test_signing_payload_separation() {
+    let program_id = Pubkey::new_unique();
+    let payload = signing_payload(&program_id, 1, b"payload");
+
+    assert!(payload.starts_with(SIGNING_DOMAIN));
+
+    // Any other deployment, instruction or payload signs other bytes
+    assert_ne!(
+        payload,
+        signing_payload(&Pubkey::new_unique(), 1, b"payload")
+    );
+    assert_ne!(payload, signing_payload(&program_id, 2, b"payload"));
+    assert_ne!(payload, signing_payload(&program_id, 1, b"payload2"));
+}
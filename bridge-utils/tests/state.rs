@@ -0,0 +1,54 @@
+use bridge_utils::state::*;
+
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+#[test]
+fn test_account_discriminator() {
+    let audit_log = AdminAuditLog {
+        is_initialized: true,
+        account_kind: AccountKind::AdminAuditLog(0),
+        total: 0,
+        entries: Vec::new(),
+    };
+
+    let mut data = vec![0; AdminAuditLog::LEN];
+    AdminAuditLog::pack(audit_log, &mut data).unwrap();
+    assert_eq!(
+        data[..ACCOUNT_DISCRIMINATOR_LEN],
+        AdminAuditLog::DISCRIMINATOR
+    );
+
+    let unpacked = AdminAuditLog::unpack(&data).unwrap();
+    assert_eq!(unpacked.account_kind, AccountKind::AdminAuditLog(0));
+
+    // Accounts of another type are rejected
+    data[0] ^= 1;
+    assert_eq!(
+        AdminAuditLog::unpack(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+
+    // Zeroed discriminator doesn't make an initialized account of another type acceptable
+    data[..ACCOUNT_DISCRIMINATOR_LEN].fill(0);
+    assert_eq!(
+        AdminAuditLog::unpack(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    assert_eq!(
+        AdminAuditLog::unpack_unchecked(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+
+    // Unless it's explicitly expected to be set later
+    let unpacked = AdminAuditLog::unpack_undiscriminated(&data).unwrap();
+    assert_eq!(unpacked.account_kind, AccountKind::AdminAuditLog(0));
+
+    // Zeroed account hasn't been packed yet
+    let data = vec![0; AdminAuditLog::LEN];
+    assert!(
+        !AdminAuditLog::unpack_unchecked(&data)
+            .unwrap()
+            .is_initialized
+    );
+}
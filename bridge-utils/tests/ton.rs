@@ -0,0 +1,156 @@
+use bridge_utils::ton::*;
+use bridge_utils::types::{AnycastInfo, EverAddress, MsgAddrStdAnycast, MsgAddrVar};
+
+// `te6ccgEBAQEAAgAAAA==`
+const EMPTY_CELL_BOC: &str = "b5ee9c72010101010002000000";
+// `te6cckEBAQEAAgAAAEysuc0=`
+const EMPTY_CELL_BOC_CRC: &str = "b5ee9c724101010100020000004cacb9cd";
+const EMPTY_CELL_HASH: &str = "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7";
+
+#[test]
+fn test_empty_cell() {
+    let cell = CellBuilder::new().build();
+
+    assert_eq!(hex::encode(cell.repr_hash()), EMPTY_CELL_HASH);
+    assert_eq!(hex::encode(cell.to_boc()), EMPTY_CELL_BOC);
+
+    let boc_cell = Cell::from_boc(&hex::decode(EMPTY_CELL_BOC).unwrap()).unwrap();
+    assert_eq!(boc_cell, cell);
+
+    let boc_cell = Cell::from_boc(&hex::decode(EMPTY_CELL_BOC_CRC).unwrap()).unwrap();
+    assert_eq!(boc_cell, cell);
+
+    let mut corrupted = hex::decode(EMPTY_CELL_BOC_CRC).unwrap();
+    *corrupted.last_mut().unwrap() ^= 1;
+    assert_eq!(Cell::from_boc(&corrupted), Err(CellError::InvalidBoc));
+
+    assert_eq!(boc_to_cell(&[]).unwrap(), cell);
+}
+
+#[test]
+fn test_cell_builder() {
+    let mut builder = CellBuilder::new();
+    builder
+        .store_uint(0b101, 3)
+        .unwrap()
+        .store_int(-1, 8)
+        .unwrap();
+
+    assert_eq!(builder.bit_len(), 11);
+
+    let cell = builder.build();
+    assert_eq!(cell.data(), &[0b1011_1111, 0b1110_0000]);
+
+    // Values must fit into the bits
+    assert_eq!(
+        CellBuilder::new().store_uint(8, 3).err(),
+        Some(CellError::DataOverflow)
+    );
+    assert_eq!(
+        CellBuilder::new().store_int(128, 8).err(),
+        Some(CellError::DataOverflow)
+    );
+
+    // Cells are limited to 1023 bits and 4 references
+    let mut builder = CellBuilder::new();
+    for _ in 0..3 {
+        builder.store_u256(&[0xff; 32]).unwrap();
+    }
+    builder.store_uint(0, 128).unwrap();
+    builder.store_uint(0, 127).unwrap();
+    assert_eq!(builder.store_bit(true).err(), Some(CellError::DataOverflow));
+
+    let mut builder = CellBuilder::new();
+    for _ in 0..MAX_REFS {
+        builder.store_reference(Cell::default()).unwrap();
+    }
+    assert_eq!(
+        builder.store_reference(Cell::default()).err(),
+        Some(CellError::RefsOverflow)
+    );
+}
+
+#[test]
+fn test_address() {
+    let address = [0x11; 32];
+
+    let mut builder = CellBuilder::new();
+    builder
+        .store_address(&EverAddress::with_standart(-1, address))
+        .unwrap();
+    assert_eq!(builder.bit_len(), 267);
+
+    let cell = builder.build();
+    // addr_std$10 anycast:nothing$0 workchain_id:-1
+    assert_eq!(&cell.data()[..2], &[0b1001_1111, 0b1110_0010]);
+
+    let mut builder = CellBuilder::new();
+    builder
+        .store_address(&EverAddress::AddrStdAnycast(MsgAddrStdAnycast {
+            anycast: AnycastInfo {
+                depth: 3,
+                rewrite_pfx: 0b101,
+            },
+            workchain_id: 0,
+            address,
+        }))
+        .unwrap();
+    assert_eq!(builder.bit_len(), 267 + 5 + 3);
+
+    let mut builder = CellBuilder::new();
+    builder
+        .store_address(&EverAddress::AddrVar(
+            MsgAddrVar::with_address(1, &[0x22; 40]).unwrap(),
+        ))
+        .unwrap();
+    assert_eq!(builder.bit_len(), 2 + 1 + 9 + 32 + 320);
+}
+
+#[test]
+fn test_bytes_chain() {
+    let bytes = (0..300).map(|i| i as u8).collect::<Vec<_>>();
+
+    let cell = bytes_to_cell(&bytes).unwrap();
+
+    let mut chunks = Vec::new();
+    let mut next = Some(&cell);
+    while let Some(cell) = next {
+        chunks.push(cell.data().len());
+        next = cell.references().first();
+    }
+    assert_eq!(chunks, vec![BYTES_PER_CELL, BYTES_PER_CELL, 46]);
+    assert_eq!(cell.depth(), 2);
+
+    assert_eq!(bytes_to_cell(&[]).unwrap(), Cell::default());
+}
+
+#[test]
+fn test_boc_roundtrip() {
+    let shared = bytes_to_cell(b"shared").unwrap();
+
+    let mut builder = CellBuilder::new();
+    builder
+        .store_uint(0x1234, 13)
+        .unwrap()
+        .store_reference(shared.clone())
+        .unwrap()
+        .store_reference(bytes_to_cell(&[7; 200]).unwrap())
+        .unwrap()
+        .store_reference(shared)
+        .unwrap();
+    let cell = builder.build();
+
+    let boc = cell.to_boc();
+
+    // Shared cell is stored once
+    assert_eq!(boc[6], 4);
+
+    let boc_cell = Cell::from_boc(&boc).unwrap();
+    assert_eq!(boc_cell, cell);
+    assert_eq!(boc_cell.repr_hash(), cell.repr_hash());
+
+    assert_eq!(
+        Cell::from_boc(&boc[..boc.len() - 1]),
+        Err(CellError::InvalidBoc)
+    );
+}
@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use bridge_utils::types::*;
+
+#[test]
+fn test_cluster_from_str() {
+    assert_eq!(Cluster::from_str("mainnet"), Ok(Cluster::Mainnet));
+    assert_eq!(Cluster::from_str("mainnet-beta"), Ok(Cluster::Mainnet));
+    assert_eq!(Cluster::from_str("devnet"), Ok(Cluster::Devnet));
+    assert_eq!(Cluster::from_str("localnet"), Ok(Cluster::Localnet));
+    assert_eq!(
+        Cluster::from_str("testnet"),
+        Err(ParseClusterError::Unknown)
+    );
+
+    // Default build targets mainnet
+    assert_eq!(Cluster::CURRENT, Cluster::Mainnet);
+}
+
+#[test]
+fn test_ever_address_validate() {
+    let std = EverAddress::with_standart(0, [1; 32]);
+    assert!(std.is_standart());
+    assert_eq!(std.validate(), Ok(()));
+
+    let var = EverAddress::AddrVar(MsgAddrVar::with_address(1, &[0x22; 40]).unwrap());
+    assert!(!var.is_standart());
+    assert_eq!(var.validate(), Ok(()));
+
+    let anycast = AnycastInfo {
+        depth: 8,
+        rewrite_pfx: 0xab,
+    };
+    assert_eq!(anycast.validate(), Ok(()));
+
+    // Depth is limited
+    let zero_depth = AnycastInfo {
+        depth: 0,
+        rewrite_pfx: 0,
+    };
+    assert_eq!(zero_depth.validate(), Err(EverAddressError::AnycastDepth));
+
+    let deep = AnycastInfo {
+        depth: MAX_ANYCAST_DEPTH + 1,
+        rewrite_pfx: 0,
+    };
+    assert_eq!(deep.validate(), Err(EverAddressError::AnycastDepth));
+
+    // Prefix fits into the depth
+    let anycast_std = EverAddress::AddrStdAnycast(MsgAddrStdAnycast {
+        anycast: AnycastInfo {
+            depth: 4,
+            rewrite_pfx: 0xab,
+        },
+        workchain_id: 0,
+        address: [1; 32],
+    });
+    assert_eq!(anycast_std.validate(), Err(EverAddressError::AnycastPrefix));
+
+    // Bits past the address length are zero
+    let mut padded = MsgAddrVar::with_address(1, &[0x22; 40]).unwrap();
+    padded.addr_len -= 4;
+    assert_eq!(
+        EverAddress::AddrVar(padded).validate(),
+        Err(EverAddressError::AddressPadding)
+    );
+
+    let mut long = MsgAddrVar::with_address(1, &[0; 64]).unwrap();
+    long.addr_len = MAX_ADDR_VAR_LEN + 1;
+    assert_eq!(
+        EverAddress::AddrVar(long).validate(),
+        Err(EverAddressError::AddressLen)
+    );
+
+    assert_eq!(
+        MsgAddrVar::with_address(1, &[0; 65]).unwrap_err(),
+        EverAddressError::AddressLen
+    );
+}
+
+#[test]
+fn test_ever_address_from_str() {
+    let std = EverAddress::from_str(&format!("0:{}", "11".repeat(32))).unwrap();
+    assert_eq!(std, EverAddress::with_standart(0, [0x11; 32]));
+    assert_eq!(std.to_string(), format!("0:{}", "11".repeat(32)));
+
+    // Workchain out of i8 or address of another length falls back to a variable address
+    let var = EverAddress::from_str(&format!("1000:{}", "22".repeat(40))).unwrap();
+    assert_eq!(
+        var,
+        EverAddress::AddrVar(MsgAddrVar::with_address(1000, &[0x22; 40]).unwrap())
+    );
+    assert_eq!(var.to_string(), format!("1000:{}", "22".repeat(40)));
+
+    assert!(EverAddress::from_str("0").is_err());
+    assert!(EverAddress::from_str("0:zz").is_err());
+    assert!(EverAddress::from_str(&format!("0:{}", "33".repeat(65))).is_err());
+}
@@ -0,0 +1,46 @@
+use borsh::BorshSerialize;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use solana_program::system_program;
+
+use crate::*;
+
+pub fn create_event_configuration_ix(
+    funder_pubkey: Pubkey,
+    authority_pubkey: Pubkey,
+    seed: u64,
+) -> Instruction {
+    let event_configuration_pubkey = get_event_configuration_address(seed);
+
+    let data = MockEverscaleInstruction::CreateEventConfiguration { seed }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(authority_pubkey, false),
+            AccountMeta::new(event_configuration_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn emit_event_ix(authority_pubkey: Pubkey, seed: u64, data: Vec<u8>) -> Instruction {
+    let event_configuration_pubkey = get_event_configuration_address(seed);
+
+    let data = MockEverscaleInstruction::EmitEvent { data }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(authority_pubkey, true),
+            AccountMeta::new(event_configuration_pubkey, false),
+        ],
+        data,
+    }
+}
@@ -0,0 +1,13 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum MockEverscaleInstruction {
+    CreateEventConfiguration {
+        // Event configuration seed
+        seed: u64,
+    },
+    EmitEvent {
+        // Event body, Borsh serialized event of the target program
+        data: Vec<u8>,
+    },
+}
@@ -0,0 +1,32 @@
+//! Stand-in for the EVER side of the bridge in functional tests.
+//!
+//! Event configurations are accounts of this program, so their addresses are real keys that can
+//! be registered in the Round Loader. Emitting an event logs it with `sol_log_data`, giving the
+//! tests the same `event_timestamp`, `event_transaction_lt` and `event_configuration` the relays
+//! would observe on EVER.
+
+mod instruction;
+mod processor;
+mod state;
+
+pub use self::instruction::*;
+pub use self::processor::*;
+pub use self::state::*;
+
+#[cfg(feature = "bindings")]
+mod bindings;
+
+#[cfg(feature = "bindings")]
+pub use self::bindings::*;
+
+#[cfg(not(feature = "no-entrypoint"))]
+mod entrypoint;
+
+use solana_program::pubkey::Pubkey;
+
+solana_program::declare_id!("EPJ6SHREmz1AkcFf3UARefHgL2oDsyhY3fYW874U1HDZ");
+
+/// Address of the event configuration created with the seed
+pub fn get_event_configuration_address(seed: u64) -> Pubkey {
+    Pubkey::find_program_address(&[br"event_configuration", &seed.to_le_bytes()], &id()).0
+}
@@ -0,0 +1,152 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+use solana_program::{msg, system_instruction};
+
+use crate::*;
+
+pub struct Processor;
+impl Processor {
+    pub fn process(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        let instruction = MockEverscaleInstruction::try_from_slice(instruction_data)?;
+
+        match instruction {
+            MockEverscaleInstruction::CreateEventConfiguration { seed } => {
+                msg!("Instruction: Create Event Configuration");
+                Self::process_create_event_configuration(program_id, accounts, seed)?;
+            }
+            MockEverscaleInstruction::EmitEvent { data } => {
+                msg!("Instruction: Emit Event");
+                Self::process_emit_event(program_id, accounts, data)?;
+            }
+        };
+
+        Ok(())
+    }
+
+    fn process_create_event_configuration(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        seed: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let funder_account_info = next_account_info(account_info_iter)?;
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let event_configuration_account_info = next_account_info(account_info_iter)?;
+        let _system_program_info = next_account_info(account_info_iter)?;
+
+        if !funder_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate Event Configuration Account
+        let (event_configuration_pubkey, event_configuration_nonce) = Pubkey::find_program_address(
+            &[br"event_configuration", &seed.to_le_bytes()],
+            program_id,
+        );
+
+        if event_configuration_pubkey != *event_configuration_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if event_configuration_account_info.owner == program_id {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // Create Event Configuration Account
+        let event_configuration_account_signer_seeds: &[&[_]] = &[
+            br"event_configuration",
+            &seed.to_le_bytes(),
+            &[event_configuration_nonce],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                event_configuration_account_info.key,
+                1.max(Rent::get()?.minimum_balance(EventConfiguration::LEN)),
+                EventConfiguration::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[event_configuration_account_signer_seeds],
+        )?;
+
+        // Init Event Configuration Account
+        let event_configuration_account_data = EventConfiguration {
+            is_initialized: true,
+            account_kind: AccountKind::EventConfiguration(event_configuration_nonce),
+            authority: *authority_account_info.key,
+            event_transaction_lt: 0,
+        };
+
+        EventConfiguration::pack(
+            event_configuration_account_data,
+            &mut event_configuration_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    fn process_emit_event(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        data: Vec<u8>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+
+        let authority_account_info = next_account_info(account_info_iter)?;
+        let event_configuration_account_info = next_account_info(account_info_iter)?;
+
+        if event_configuration_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        let mut event_configuration_account_data =
+            EventConfiguration::unpack(&event_configuration_account_info.data.borrow())?;
+
+        // Validate Authority Account
+        if !authority_account_info.is_signer
+            || *authority_account_info.key != event_configuration_account_data.authority
+        {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Logical time grows with every event of the configuration
+        event_configuration_account_data.event_transaction_lt = event_configuration_account_data
+            .event_transaction_lt
+            .checked_add(1)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let event = MockEvent {
+            event_configuration: *event_configuration_account_info.key,
+            event_timestamp: Clock::get()?.unix_timestamp as u32,
+            event_transaction_lt: event_configuration_account_data.event_transaction_lt,
+            data,
+        };
+
+        EventConfiguration::pack(
+            event_configuration_account_data,
+            &mut event_configuration_account_info.data.borrow_mut(),
+        )?;
+
+        solana_program::log::sol_log_data(&[&event.try_to_vec()?]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_derive::BridgePack;
+use bridge_utils::state::AccountKind;
+
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::{IsInitialized, Pack, Sealed};
+use solana_program::pubkey::Pubkey;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[bridge_pack(length = 50)] // 43 + reserve
+pub struct EventConfiguration {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Account allowed to emit events
+    pub authority: Pubkey,
+    // Logical time of the last emitted event
+    pub event_transaction_lt: u64,
+}
+
+impl Sealed for EventConfiguration {}
+
+impl IsInitialized for EventConfiguration {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Event logged by `EmitEvent`
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MockEvent {
+    pub event_configuration: Pubkey,
+    pub event_timestamp: u32,
+    pub event_transaction_lt: u64,
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "test-bpf")]
+
+use solana_program::instruction::InstructionError;
+use solana_program::program_pack::Pack;
+use solana_program_test::{processor, tokio, ProgramTest};
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use mock_everscale::*;
+
+#[tokio::test]
+async fn test_emit_event() {
+    let program_test = ProgramTest::new(
+        "mock_everscale",
+        mock_everscale::id(),
+        processor!(Processor::process),
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let authority = Keypair::new();
+    let seed = 1;
+
+    // Create Event Configuration
+    let mut transaction = Transaction::new_with_payer(
+        &[create_event_configuration_ix(
+            funder.pubkey(),
+            authority.pubkey(),
+            seed,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Emit two events
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            emit_event_ix(authority.pubkey(), seed, vec![1, 2, 3]),
+            emit_event_ix(authority.pubkey(), seed, vec![4]),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &authority], recent_blockhash);
+
+    let (result, events) =
+        test_fixtures::events::process_transaction_with_events(&mut banks_client, transaction)
+            .await;
+
+    assert!(result.is_ok());
+
+    let events: Vec<MockEvent> = test_fixtures::events::find_events(&events, &mock_everscale::id());
+
+    assert_eq!(events.len(), 2);
+
+    let event_configuration = get_event_configuration_address(seed);
+
+    assert_eq!(events[0].event_configuration, event_configuration);
+    assert_eq!(events[0].event_transaction_lt, 1);
+    assert_eq!(events[0].data, vec![1, 2, 3]);
+
+    assert_eq!(events[1].event_configuration, event_configuration);
+    assert_eq!(events[1].event_transaction_lt, 2);
+    assert_eq!(events[1].data, vec![4]);
+
+    let event_configuration_info = banks_client
+        .get_account(event_configuration)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let event_configuration_data =
+        EventConfiguration::unpack(&event_configuration_info.data).expect("unpack");
+
+    assert_eq!(event_configuration_data.authority, authority.pubkey());
+    assert_eq!(event_configuration_data.event_transaction_lt, 2);
+
+    // Only the authority emits events of the configuration
+    let intruder = Keypair::new();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[emit_event_ix(intruder.pubkey(), seed, vec![])],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &intruder], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
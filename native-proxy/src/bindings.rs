@@ -25,8 +25,9 @@ pub fn deposit_ix(
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let token_whitelist_pubkey = token_proxy::get_token_whitelist_address(&mint_pubkey);
 
-    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let deposit_pubkey = token_proxy::get_deposit_address(&author_pubkey, deposit_seed);
 
     let data = NativeProxyInstruction::Deposit {
         deposit_seed,
@@ -50,12 +51,13 @@ pub fn deposit_ix(
             AccountMeta::new(mint_pubkey, false),
             AccountMeta::new(multivault_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
             AccountMeta::new_readonly(token_proxy::id(), false),
             AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+            AccountMeta::new_readonly(token_whitelist_pubkey, false),
         ],
         data,
     }
@@ -13,4 +13,35 @@ pub use self::bindings::*;
 #[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
 
-solana_program::declare_id!("WrapR8ncp6aGqux2TACyJh4MUxcHAHTW9eYzzeXuTJA");
+use bridge_utils::types::Cluster;
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+
+/// Program id of the mainnet deployment
+pub const MAINNET_ID: Pubkey = pubkey!("WrapR8ncp6aGqux2TACyJh4MUxcHAHTW9eYzzeXuTJA");
+/// Program id of the devnet deployment
+pub const DEVNET_ID: Pubkey = pubkey!("BQyQ6BAehSxZ14woNhQY44gRJr9TiNtViARL1PBgiA6b");
+/// Program id of the local validator deployment
+pub const LOCALNET_ID: Pubkey = pubkey!("HSSh4diPa592hBtVzwK1TiUKQ2xVeuS46WChU3WLcHQZ");
+
+/// Program id of the deployment on the given cluster
+pub const fn program_id(cluster: Cluster) -> Pubkey {
+    match cluster {
+        Cluster::Mainnet => MAINNET_ID,
+        Cluster::Devnet => DEVNET_ID,
+        Cluster::Localnet => LOCALNET_ID,
+    }
+}
+
+/// Program id of the cluster selected by cargo features
+pub const ID: Pubkey = program_id(Cluster::CURRENT);
+
+/// Returns `true` if given pubkey is the program id
+pub fn check_id(id: &Pubkey) -> bool {
+    id == &ID
+}
+
+/// Returns the program id
+pub const fn id() -> Pubkey {
+    ID
+}
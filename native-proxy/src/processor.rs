@@ -10,9 +10,6 @@ use solana_program::{msg, system_instruction};
 
 use crate::*;
 
-const WSOL_SYMBOL: &str = "wSOL";
-const WSOL_NAME: &str = "Wrapped SOL";
-
 pub struct Processor;
 impl Processor {
     pub fn process(
@@ -48,6 +45,7 @@ impl Processor {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn process_deposit(
         _program_id: &Pubkey,
         accounts: &[AccountInfo],
@@ -103,8 +101,8 @@ impl Processor {
             accounts,
         )?;
 
-        let name = WSOL_NAME.to_string();
-        let symbol = WSOL_SYMBOL.to_string();
+        let name = token_proxy::WSOL_NAME.to_string();
+        let symbol = token_proxy::WSOL_SYMBOL.to_string();
 
         invoke(
             &token_proxy::deposit_multi_token_sol_ix(
@@ -120,6 +118,8 @@ impl Processor {
                 value,
                 expected_evers,
                 payload,
+                None,
+                None,
             ),
             accounts,
         )?;
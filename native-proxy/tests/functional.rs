@@ -52,9 +52,18 @@ async fn test_deposit() {
         is_initialized: true,
         account_kind: AccountKind::Settings(settings_nonce, 0),
         emergency: false,
-        guardian,
-        manager,
-        withdrawal_manager,
+        guardian: Pubkey::default(),
+        manager: Pubkey::default(),
+        withdrawal_manager: Pubkey::default(),
+        chain_id: 0,
+        event_seq: 0,
+        veto_disabled: false,
+        deposits_restricted_to_whitelist: false,
+        roles: token_proxy::Settings::initial_roles(guardian, manager, withdrawal_manager),
+        permissions: vec![],
+        emergency_cosigners: vec![],
+        emergency_cosigners_threshold: 0,
+        guardian_last_active: 0,
     };
 
     let mut settings_packed = vec![0; token_proxy::Settings::LEN];
@@ -153,6 +162,21 @@ async fn test_deposit() {
         fee_supply: Default::default(),
         fee_deposit_info: Default::default(),
         fee_withdrawal_info: Default::default(),
+        hook_program: None,
+        freeze_risk: false,
+        vault_generation: 0,
+        lp_supply: 0,
+        lp_liquidity: 0,
+        yield_adapter: None,
+        yield_adapter_unlock_time: 0,
+        max_deployed_share: 0,
+        deployed_amount: 0,
+        last_rewarded_round: None,
+        required_votes_override: None,
+        confirmation_tiers: vec![],
+        paused_operations: 0,
+        scheduled_limit_change: None,
+        ever_configuration: None,
     };
 
     let mut token_settings_packed = vec![0; token_proxy::TokenSettings::LEN];
@@ -225,7 +249,7 @@ async fn test_deposit() {
     assert_eq!(sender_data.amount, 0);
 
     // Check Deposit Account
-    let deposit_address = token_proxy::get_deposit_address(deposit_seed);
+    let deposit_address = token_proxy::get_deposit_address(&sender.pubkey(), deposit_seed);
     let deposit_info = banks_client
         .get_account(deposit_address)
         .await
@@ -238,7 +262,11 @@ async fn test_deposit() {
     assert_eq!(deposit_data.is_initialized, true);
 
     let (_, deposit_nonce) = Pubkey::find_program_address(
-        &[br"deposit", &deposit_seed.to_le_bytes()],
+        &[
+            br"deposit",
+            &sender.pubkey().to_bytes(),
+            &deposit_seed.to_le_bytes(),
+        ],
         &token_proxy::id(),
     );
     assert_eq!(
@@ -292,6 +320,6 @@ async fn test_deposit() {
     );
     assert_eq!(
         raw_deposit_data.meta,
-        deposit_data.meta.data.try_to_vec().unwrap()
+        deposit_data.meta.try_to_vec().unwrap()[4..]
     );
 }
@@ -0,0 +1,7 @@
+mod verify;
+mod vote;
+mod watcher;
+
+pub use self::verify::*;
+pub use self::vote::*;
+pub use self::watcher::*;
@@ -0,0 +1,114 @@
+use borsh::BorshSerialize;
+use thiserror::Error;
+
+use solana_program::pubkey::Pubkey;
+
+use token_proxy::{WithdrawalMultiTokenEverEvent, WithdrawalMultiTokenSolEvent};
+
+use crate::{PendingWithdrawal, Withdrawal};
+
+/// Withdrawal event as confirmed on the EVER side, fetched by the relay from its EVER node
+#[derive(Debug)]
+pub struct EverEvent {
+    pub round_number: u32,
+    pub event_timestamp: u32,
+    pub event_transaction_lt: u64,
+    pub event_configuration: Pubkey,
+    pub data: EverEventData,
+}
+
+#[derive(Debug)]
+pub enum EverEventData {
+    Ever(WithdrawalMultiTokenEverEvent),
+    Sol(WithdrawalMultiTokenSolEvent),
+}
+
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VerificationError {
+    #[error("Withdrawal account address mismatch")]
+    AddressMismatch,
+    #[error("Relay round mismatch")]
+    RoundMismatch,
+    #[error("Event transaction mismatch")]
+    TransactionMismatch,
+    #[error("Event kind mismatch")]
+    KindMismatch,
+    #[error("Event data mismatch")]
+    DataMismatch,
+}
+
+/// Check that the withdrawal account carries exactly the EVER event.
+///
+/// A relay confirms a withdrawal only if this succeeds and rejects it otherwise.
+pub fn verify_withdrawal(
+    pending: &PendingWithdrawal,
+    event: &EverEvent,
+) -> Result<(), VerificationError> {
+    let withdrawal = &pending.withdrawal;
+
+    if withdrawal.round_number() != event.round_number {
+        return Err(VerificationError::RoundMismatch);
+    }
+
+    let pda = withdrawal.pda();
+    if pda.event_timestamp != event.event_timestamp
+        || pda.event_transaction_lt != event.event_transaction_lt
+        || pda.event_configuration != event.event_configuration
+    {
+        return Err(VerificationError::TransactionMismatch);
+    }
+
+    // Events are compared in the serialized form the account address is derived from
+    let (account_data, event_data) = match (withdrawal, &event.data) {
+        (Withdrawal::Ever(withdrawal), EverEventData::Ever(data)) => (
+            withdrawal.event.data.try_to_vec().expect("pack"),
+            data.try_to_vec().expect("pack"),
+        ),
+        (Withdrawal::Sol(withdrawal), EverEventData::Sol(data)) => (
+            withdrawal.event.data.try_to_vec().expect("pack"),
+            data.try_to_vec().expect("pack"),
+        ),
+        _ => return Err(VerificationError::KindMismatch),
+    };
+
+    if account_data != event_data {
+        return Err(VerificationError::DataMismatch);
+    }
+
+    if get_withdrawal_address(event) != pending.account {
+        return Err(VerificationError::AddressMismatch);
+    }
+
+    Ok(())
+}
+
+/// Address of the withdrawal account created for the EVER event
+pub fn get_withdrawal_address(event: &EverEvent) -> Pubkey {
+    match &event.data {
+        EverEventData::Ever(data) => token_proxy::get_withdrawal_ever_address(
+            data.chain_id,
+            event.round_number,
+            event.event_timestamp,
+            event.event_transaction_lt,
+            &event.event_configuration,
+            data.token,
+            data.name.clone(),
+            data.symbol.clone(),
+            data.decimals,
+            data.recipient,
+            data.amount,
+            data.payload.clone(),
+        ),
+        EverEventData::Sol(data) => token_proxy::get_withdrawal_sol_address(
+            data.chain_id,
+            event.round_number,
+            event.event_timestamp,
+            event.event_transaction_lt,
+            &event.event_configuration,
+            data.mint,
+            data.recipient,
+            data.amount,
+            data.payload.clone(),
+        ),
+    }
+}
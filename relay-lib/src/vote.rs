@@ -0,0 +1,68 @@
+use bridge_utils::merkle::MerkleProof;
+use bridge_utils::types::Vote;
+
+use solana_program::hash::Hash;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+use round_loader::RelayRound;
+
+use crate::PendingWithdrawal;
+
+/// Vote the relay has already cast for the withdrawal, `Vote::None` if it has not voted yet.
+///
+/// Relays of a merkle round locate themselves with their membership proof.
+pub fn get_relay_vote(
+    pending: &PendingWithdrawal,
+    relay_round: &RelayRound,
+    relay: &Pubkey,
+    proof: Option<&MerkleProof>,
+) -> Result<Vote, ProgramError> {
+    if relay_round.round_number != pending.withdrawal.round_number() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let index = relay_round.find_relay(relay, proof)?;
+
+    pending
+        .withdrawal
+        .signers()
+        .get(index)
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Build the vote transaction for the withdrawal, signed and paid for by the relay
+pub fn build_vote_transaction(
+    relay: &Keypair,
+    pending: &PendingWithdrawal,
+    vote: Vote,
+    proof: Option<MerkleProof>,
+    recent_blockhash: Hash,
+) -> Transaction {
+    let relay_pubkey = relay.pubkey();
+    let token_settings_pubkey = pending.withdrawal.token_settings_address();
+    let round_number = pending.withdrawal.round_number();
+
+    let ix = match proof {
+        Some(proof) => token_proxy::vote_for_withdrawal_request_with_proof_ix(
+            relay_pubkey,
+            pending.account,
+            token_settings_pubkey,
+            round_number,
+            vote,
+            proof,
+        ),
+        None => token_proxy::vote_for_withdrawal_request_ix(
+            relay_pubkey,
+            pending.account,
+            token_settings_pubkey,
+            round_number,
+            vote,
+        ),
+    };
+
+    Transaction::new_signed_with_payer(&[ix], Some(&relay_pubkey), &[relay], recent_blockhash)
+}
@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+
+use bridge_indexer::{decode_account, AccountUpdate, BridgeAccount};
+use bridge_utils::state::{ACCOUNT_DISCRIMINATOR_LEN, PDA};
+use bridge_utils::types::Vote;
+
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use token_proxy::{WithdrawalMultiTokenEver, WithdrawalMultiTokenSol, WithdrawalTokenStatus};
+
+/// Discriminators of the withdrawal accounts, used to filter a `programSubscribe` stream
+pub const WITHDRAWAL_DISCRIMINATORS: [[u8; ACCOUNT_DISCRIMINATOR_LEN]; 2] = [
+    WithdrawalMultiTokenEver::DISCRIMINATOR,
+    WithdrawalMultiTokenSol::DISCRIMINATOR,
+];
+
+/// Withdrawal account state
+#[derive(Debug)]
+pub enum Withdrawal {
+    Ever(WithdrawalMultiTokenEver),
+    Sol(WithdrawalMultiTokenSol),
+}
+
+impl Withdrawal {
+    pub fn round_number(&self) -> u32 {
+        match self {
+            Withdrawal::Ever(withdrawal) => withdrawal.round_number,
+            Withdrawal::Sol(withdrawal) => withdrawal.round_number,
+        }
+    }
+
+    pub fn pda(&self) -> &PDA {
+        match self {
+            Withdrawal::Ever(withdrawal) => &withdrawal.pda,
+            Withdrawal::Sol(withdrawal) => &withdrawal.pda,
+        }
+    }
+
+    pub fn status(&self) -> WithdrawalTokenStatus {
+        match self {
+            Withdrawal::Ever(withdrawal) => withdrawal.meta.data.status,
+            Withdrawal::Sol(withdrawal) => withdrawal.meta.data.status,
+        }
+    }
+
+    /// Votes of the round relays, indexed by the relay position in the round
+    pub fn signers(&self) -> &[Vote] {
+        match self {
+            Withdrawal::Ever(withdrawal) => &withdrawal.signers,
+            Withdrawal::Sol(withdrawal) => &withdrawal.signers,
+        }
+    }
+
+    /// Token settings account the vote instruction checks the pause flags of
+    pub fn token_settings_address(&self) -> Pubkey {
+        match self {
+            Withdrawal::Ever(withdrawal) => {
+                token_proxy::get_token_settings_ever_address(&withdrawal.event.data.token)
+            }
+            Withdrawal::Sol(withdrawal) => {
+                token_proxy::get_token_settings_sol_address(&withdrawal.event.data.mint)
+            }
+        }
+    }
+}
+
+/// Withdrawal account seen for the first time
+#[derive(Debug)]
+pub struct PendingWithdrawal {
+    pub account: Pubkey,
+    pub slot: u64,
+    pub withdrawal: Withdrawal,
+}
+
+/// Turns a stream of Token Proxy account updates into new withdrawals awaiting relay votes
+#[derive(Debug, Default)]
+pub struct WithdrawalWatcher {
+    seen: HashSet<Pubkey>,
+}
+
+impl WithdrawalWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the withdrawal the first time its account shows up in the stream.
+    ///
+    /// Later updates of the same account (votes of other relays, status changes) are skipped,
+    /// as well as withdrawals that are no longer open for voting.
+    pub fn handle_update(
+        &mut self,
+        update: &AccountUpdate,
+    ) -> Result<Option<PendingWithdrawal>, ProgramError> {
+        let withdrawal = match decode_account(&update.owner, &update.data)? {
+            Some(BridgeAccount::WithdrawalMultiTokenEver(withdrawal)) => {
+                Withdrawal::Ever(withdrawal)
+            }
+            Some(BridgeAccount::WithdrawalMultiTokenSol(withdrawal)) => Withdrawal::Sol(withdrawal),
+            _ => return Ok(None),
+        };
+
+        if !self.seen.insert(update.pubkey) {
+            return Ok(None);
+        }
+
+        if withdrawal.status() != WithdrawalTokenStatus::New {
+            return Ok(None);
+        }
+
+        Ok(Some(PendingWithdrawal {
+            account: update.pubkey,
+            slot: update.slot,
+            withdrawal,
+        }))
+    }
+
+    /// Forget a withdrawal, e.g. after its account was closed
+    pub fn forget(&mut self, account: &Pubkey) {
+        self.seen.remove(account);
+    }
+}
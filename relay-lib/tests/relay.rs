@@ -0,0 +1,149 @@
+use bridge_indexer::AccountUpdate;
+use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::types::Vote;
+
+use solana_program::hash::Hash;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use relay_lib::*;
+use token_proxy::{
+    WithdrawalMultiTokenSol, WithdrawalMultiTokenSolEvent, WithdrawalMultiTokenSolEventWithLen,
+    WithdrawalTokenMetaWithLen,
+};
+
+const CHAIN_ID: u32 = 1;
+const ROUND_NUMBER: u32 = 7;
+
+fn sol_event(mint: Pubkey, recipient: Pubkey, amount: u128) -> EverEvent {
+    EverEvent {
+        round_number: ROUND_NUMBER,
+        event_timestamp: 1650988297,
+        event_transaction_lt: 1650988334,
+        event_configuration: Pubkey::new_unique(),
+        data: EverEventData::Sol(WithdrawalMultiTokenSolEvent {
+            chain_id: CHAIN_ID,
+            mint,
+            amount,
+            recipient,
+            payload: vec![],
+        }),
+    }
+}
+
+fn withdrawal_update(event: &EverEvent, amount: u128) -> AccountUpdate {
+    let data = match &event.data {
+        EverEventData::Sol(data) => data,
+        _ => unreachable!(),
+    };
+
+    let withdrawal = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(255, None),
+        author: Pubkey::new_unique(),
+        round_number: event.round_number,
+        required_votes: 2,
+        pda: PDA {
+            event_timestamp: event.event_timestamp,
+            event_transaction_lt: event.event_transaction_lt,
+            event_configuration: event.event_configuration,
+        },
+        event: WithdrawalMultiTokenSolEventWithLen::new(
+            data.chain_id,
+            data.mint,
+            amount,
+            data.recipient,
+            data.payload.clone(),
+        ),
+        meta: WithdrawalTokenMetaWithLen::default(),
+        signers: vec![Vote::None; 3],
+    };
+
+    let mut packed = vec![0; WithdrawalMultiTokenSol::LEN];
+    WithdrawalMultiTokenSol::pack(withdrawal, &mut packed).unwrap();
+
+    AccountUpdate {
+        pubkey: get_withdrawal_address(event),
+        owner: token_proxy::id(),
+        slot: 100,
+        data: packed,
+    }
+}
+
+#[test]
+fn test_watcher_yields_new_withdrawal_once() {
+    let event = sol_event(Pubkey::new_unique(), Pubkey::new_unique(), 100);
+    let update = withdrawal_update(&event, 100);
+
+    let mut watcher = WithdrawalWatcher::new();
+
+    let pending = watcher.handle_update(&update).unwrap().unwrap();
+    assert_eq!(pending.account, update.pubkey);
+    assert_eq!(pending.withdrawal.round_number(), ROUND_NUMBER);
+
+    // Later updates of the same account are skipped
+    assert!(watcher.handle_update(&update).unwrap().is_none());
+
+    // Accounts of other programs are skipped
+    let foreign = AccountUpdate {
+        pubkey: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        ..update
+    };
+    assert!(watcher.handle_update(&foreign).unwrap().is_none());
+}
+
+#[test]
+fn test_verify_withdrawal() {
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    let event = sol_event(mint, recipient, 100);
+
+    let mut watcher = WithdrawalWatcher::new();
+    let pending = watcher
+        .handle_update(&withdrawal_update(&event, 100))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(verify_withdrawal(&pending, &event), Ok(()));
+
+    // Amount differs from the EVER event
+    let mut other_event = sol_event(mint, recipient, 1_000_000);
+    other_event.event_timestamp = event.event_timestamp;
+    other_event.event_transaction_lt = event.event_transaction_lt;
+    other_event.event_configuration = event.event_configuration;
+    assert_eq!(
+        verify_withdrawal(&pending, &other_event),
+        Err(VerificationError::DataMismatch)
+    );
+
+    // Event from another transaction
+    let other_tx_event = sol_event(mint, recipient, 100);
+    assert_eq!(
+        verify_withdrawal(&pending, &other_tx_event),
+        Err(VerificationError::TransactionMismatch)
+    );
+}
+
+#[test]
+fn test_build_vote_transaction() {
+    let event = sol_event(Pubkey::new_unique(), Pubkey::new_unique(), 100);
+
+    let pending = WithdrawalWatcher::new()
+        .handle_update(&withdrawal_update(&event, 100))
+        .unwrap()
+        .unwrap();
+
+    let relay = Keypair::new();
+    let transaction =
+        build_vote_transaction(&relay, &pending, Vote::Confirm, None, Hash::new_unique());
+
+    assert!(transaction.is_signed());
+    assert_eq!(transaction.message.account_keys[0], relay.pubkey());
+    assert!(transaction
+        .message
+        .account_keys
+        .contains(&pending.withdrawal.token_settings_address()));
+}
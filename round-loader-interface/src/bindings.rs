@@ -0,0 +1,981 @@
+use borsh::BorshSerialize;
+use bridge_utils::merkle::MerkleProof;
+use bridge_utils::types::Vote;
+
+use solana_program::hash::{hash, Hash};
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
+use solana_program::{bpf_loader_upgradeable, system_program, sysvar};
+
+use crate::*;
+
+pub fn get_programdata_address() -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_programdata_address(program_id)
+}
+
+pub fn get_settings_address() -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_associated_settings_address(program_id)
+}
+
+pub fn get_admin_audit_log_address() -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_associated_admin_audit_log_address(program_id)
+}
+
+pub fn get_relay_round_address(round_number: u32) -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_associated_relay_round_address(program_id, round_number)
+}
+
+pub fn get_proposal_address(
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    event_data: &[u8],
+) -> Pubkey {
+    let program_id = &id();
+
+    let event_data = hash(event_data);
+
+    bridge_utils::helper::get_associated_proposal_address(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        &event_data.to_bytes(),
+    )
+}
+
+pub fn get_proposal_vote_address(proposal_pubkey: &Pubkey, relay_index: u32) -> Pubkey {
+    let program_id = &id();
+    get_associated_proposal_vote_address(program_id, proposal_pubkey, relay_index)
+}
+
+pub fn get_executor_reward_vault_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_executor_reward_vault_address(program_id)
+}
+
+pub fn get_upgrade_authority_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_upgrade_authority_address(program_id)
+}
+
+pub fn get_round_digest_address(round_number: u32) -> Pubkey {
+    let program_id = &id();
+    get_associated_round_digest_address(program_id, round_number)
+}
+
+pub fn get_round_stats_address(round_number: u32) -> Pubkey {
+    let program_id = &id();
+    get_associated_round_stats_address(program_id, round_number)
+}
+
+pub fn get_relay_round_stats_address(round_number: u32) -> Pubkey {
+    get_associated_relay_round_stats_address(&TOKEN_PROXY_ID, round_number)
+}
+
+pub fn get_event_configuration_address(event_configuration: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_event_configuration_address(program_id, event_configuration)
+}
+
+pub fn get_treasury_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_treasury_address(program_id)
+}
+
+pub fn get_report_log_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_report_log_address(program_id)
+}
+
+pub fn get_report_log_page_address(page: u64) -> Pubkey {
+    let program_id = &id();
+    get_associated_report_log_page_address(program_id, page)
+}
+
+pub fn initialize_ix(
+    funder_pubkey: &Pubkey,
+    initializer_pubkey: &Pubkey,
+    genesis_round_number: u32,
+    round_submitter: Pubkey,
+    min_required_votes: u32,
+    round_ttl: u32,
+) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = RoundLoaderInstruction::Initialize {
+        genesis_round_number,
+        round_submitter,
+        min_required_votes,
+        round_ttl,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*initializer_pubkey, true),
+            AccountMeta::new(setting_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_settings_ix(
+    author_pubkey: &Pubkey,
+    current_round_number: Option<u32>,
+    round_submitter: Option<Pubkey>,
+    min_required_votes: Option<u32>,
+    round_ttl: Option<u32>,
+    event_configurations_restricted: Option<bool>,
+    proposal_lead_time: Option<u32>,
+    activation_delay: Option<u32>,
+    guardian: Option<Pubkey>,
+    round_retention: Option<u16>,
+) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = RoundLoaderInstruction::UpdateSettings {
+        current_round_number,
+        round_submitter,
+        min_required_votes,
+        round_ttl,
+        event_configurations_restricted,
+        proposal_lead_time,
+        activation_delay,
+        guardian,
+        round_retention,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*author_pubkey, true),
+            AccountMeta::new(setting_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn create_relay_round_ix(
+    funder_pubkey: &Pubkey,
+    creator_pubkey: &Pubkey,
+    round_number: u32,
+    round_end: u32,
+    relays: Vec<Pubkey>,
+) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::CreateRelayRound {
+        round_number,
+        round_end,
+        relays,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*creator_pubkey, true),
+            AccountMeta::new(setting_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn create_proposal_ix(
+    funder_pubkey: &Pubkey,
+    creator_pubkey: &Pubkey,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    event_data: &[u8],
+) -> Instruction {
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        event_data,
+    );
+
+    let event_data = hash(event_data);
+
+    let data = RoundLoaderInstruction::CreateProposal {
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        event_data,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*creator_pubkey, true),
+            AccountMeta::new(proposal_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn write_proposal_ix(proposal_pubkey: &Pubkey, offset: u32, bytes: Vec<u8>) -> Instruction {
+    let data = RoundLoaderInstruction::WriteProposal { offset, bytes }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![AccountMeta::new(*proposal_pubkey, false)],
+        data,
+    }
+}
+
+pub fn finalize_proposal_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::FinalizeProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Vote of the relay at `relay_index` in the round. The program finds the index itself, the
+/// builder only needs it for the address of the vote account.
+pub fn vote_for_proposal_ix(
+    voter_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+    vote: Vote,
+    relay_index: u32,
+) -> Instruction {
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let vote_pubkey = get_proposal_vote_address(proposal_pubkey, relay_index);
+
+    let data = RoundLoaderInstruction::VoteForProposal { vote }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*voter_pubkey, true),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new(vote_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn execute_proposal_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::ExecuteProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn execute_proposal_by_admin_ix(
+    funder_pubkey: &Pubkey,
+    creator_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = RoundLoaderInstruction::ExecuteProposalByAdmin
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*creator_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn create_admin_audit_log_ix(funder_pubkey: &Pubkey) -> Instruction {
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = RoundLoaderInstruction::CreateAdminAuditLog
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(audit_log_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn rotate_relay_key_ix(
+    old_relay_pubkey: &Pubkey,
+    new_relay_pubkey: &Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::RotateRelayKey
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*old_relay_pubkey, true),
+            AccountMeta::new_readonly(*new_relay_pubkey, true),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn finalize_delta_proposal_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::FinalizeDeltaProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn execute_delta_proposal_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    current_round_number: u32,
+    round_number: u32,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let current_relay_round_pubkey = get_relay_round_address(current_round_number);
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::ExecuteDeltaProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new_readonly(current_relay_round_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn finalize_upgrade_proposal_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+    program_pubkey: &Pubkey,
+    buffer_pubkey: &Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let programdata_pubkey = bridge_utils::helper::get_programdata_address(program_pubkey);
+
+    let data = RoundLoaderInstruction::FinalizeUpgradeProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(programdata_pubkey, false),
+            AccountMeta::new_readonly(*buffer_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn execute_upgrade_proposal_ix(
+    proposal_pubkey: &Pubkey,
+    program_pubkey: &Pubkey,
+    buffer_pubkey: &Pubkey,
+    spill_pubkey: &Pubkey,
+) -> Instruction {
+    let programdata_pubkey = bridge_utils::helper::get_programdata_address(program_pubkey);
+    let upgrade_authority_pubkey = get_upgrade_authority_address();
+
+    let data = RoundLoaderInstruction::ExecuteUpgradeProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new(programdata_pubkey, false),
+            AccountMeta::new(*program_pubkey, false),
+            AccountMeta::new(*buffer_pubkey, false),
+            AccountMeta::new(*spill_pubkey, false),
+            AccountMeta::new_readonly(upgrade_authority_pubkey, false),
+            AccountMeta::new_readonly(bpf_loader_upgradeable::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn migrate_proposal_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+    relay_indexes: &[u32],
+) -> Instruction {
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::MigrateProposal
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(*funder_pubkey, true),
+        AccountMeta::new(*proposal_pubkey, false),
+        AccountMeta::new_readonly(relay_round_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    for relay_index in relay_indexes {
+        accounts.push(AccountMeta::new(
+            get_proposal_vote_address(proposal_pubkey, *relay_index),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn export_round_digest_ix(funder_pubkey: &Pubkey, round_number: u32) -> Instruction {
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let round_digest_pubkey = get_round_digest_address(round_number);
+
+    let data = RoundLoaderInstruction::ExportRoundDigest
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new(round_digest_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn execute_proposal_with_reward_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let mut ix = execute_proposal_ix(funder_pubkey, proposal_pubkey, round_number);
+    ix.accounts
+        .push(AccountMeta::new(get_executor_reward_vault_address(), false));
+    ix
+}
+
+pub fn execute_delta_proposal_with_reward_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    current_round_number: u32,
+    round_number: u32,
+) -> Instruction {
+    let mut ix = execute_delta_proposal_ix(
+        funder_pubkey,
+        proposal_pubkey,
+        current_round_number,
+        round_number,
+    );
+    ix.accounts
+        .push(AccountMeta::new(get_executor_reward_vault_address(), false));
+    ix
+}
+
+pub fn create_merkle_relay_round_ix(
+    funder_pubkey: &Pubkey,
+    creator_pubkey: &Pubkey,
+    round_number: u32,
+    round_end: u32,
+    relays_root: Hash,
+    relays_count: u32,
+) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::CreateMerkleRelayRound {
+        round_number,
+        relays_root,
+        relays_count,
+        round_end,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*creator_pubkey, true),
+            AccountMeta::new(setting_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn vote_for_proposal_with_proof_ix(
+    voter_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+    vote: Vote,
+    proof: MerkleProof,
+) -> Instruction {
+    let mut ix = vote_for_proposal_ix(
+        voter_pubkey,
+        proposal_pubkey,
+        round_number,
+        vote,
+        proof.index,
+    );
+    ix.data = RoundLoaderInstruction::VoteForProposalWithProof { vote, proof }
+        .try_to_vec()
+        .expect("pack");
+    ix
+}
+
+pub fn vote_for_proposal_with_index_ix(
+    voter_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+    vote: Vote,
+    index: u32,
+) -> Instruction {
+    let mut ix = vote_for_proposal_ix(voter_pubkey, proposal_pubkey, round_number, vote, index);
+    ix.data = RoundLoaderInstruction::VoteForProposalWithIndex { vote, index }
+        .try_to_vec()
+        .expect("pack");
+    ix
+}
+
+pub fn add_event_configuration_ix(
+    author_pubkey: &Pubkey,
+    event_configuration: Pubkey,
+) -> Instruction {
+    let event_configuration_pubkey = get_event_configuration_address(&event_configuration);
+    let setting_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = RoundLoaderInstruction::AddEventConfiguration {
+        event_configuration,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*author_pubkey, true),
+            AccountMeta::new(event_configuration_pubkey, false),
+            AccountMeta::new_readonly(setting_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn remove_event_configuration_ix(
+    author_pubkey: &Pubkey,
+    event_configuration: Pubkey,
+) -> Instruction {
+    let event_configuration_pubkey = get_event_configuration_address(&event_configuration);
+    let setting_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = RoundLoaderInstruction::RemoveEventConfiguration {
+        event_configuration,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*author_pubkey, true),
+            AccountMeta::new(event_configuration_pubkey, false),
+            AccountMeta::new_readonly(setting_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn close_proposal_ix(proposal_pubkey: &Pubkey, author_pubkey: &Pubkey) -> Instruction {
+    let data = RoundLoaderInstruction::CloseProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new(*author_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn freeze_relay_round_ix(guardian_pubkey: &Pubkey, round_number: u32) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = RoundLoaderInstruction::FreezeRelayRound
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*guardian_pubkey, true),
+            AccountMeta::new_readonly(setting_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn finalize_relay_bounds_proposal_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+
+    let data = RoundLoaderInstruction::FinalizeRelayBoundsProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn execute_relay_bounds_proposal_ix(proposal_pubkey: &Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = RoundLoaderInstruction::ExecuteRelayBoundsProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(*proposal_pubkey, false),
+        ],
+        data,
+    }
+}
+
+/// Execute the proposal and snapshot the statistics of the replaced round
+pub fn execute_proposal_with_round_stats_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    current_round_number: u32,
+    round_number: u32,
+) -> Instruction {
+    let mut ix = execute_proposal_with_reward_ix(funder_pubkey, proposal_pubkey, round_number);
+    ix.accounts
+        .extend(round_stats_accounts(current_round_number));
+    ix
+}
+
+/// Execute the delta proposal and snapshot the statistics of the replaced round
+pub fn execute_delta_proposal_with_round_stats_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    current_round_number: u32,
+    round_number: u32,
+) -> Instruction {
+    let mut ix = execute_delta_proposal_with_reward_ix(
+        funder_pubkey,
+        proposal_pubkey,
+        current_round_number,
+        round_number,
+    );
+    ix.accounts
+        .extend(round_stats_accounts(current_round_number));
+    ix
+}
+
+fn round_stats_accounts(round_number: u32) -> [AccountMeta; 2] {
+    [
+        AccountMeta::new(get_round_stats_address(round_number), false),
+        AccountMeta::new_readonly(get_relay_round_stats_address(round_number), false),
+    ]
+}
+
+pub fn close_old_round_ix(round_number: u32) -> Instruction {
+    let setting_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    let treasury_pubkey = get_treasury_address();
+
+    let data = RoundLoaderInstruction::CloseOldRound
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(setting_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(relay_round_stats_pubkey, false),
+            AccountMeta::new(treasury_pubkey, false),
+        ],
+        data,
+    }
+}
+
+/// Report a relay of the round. `page` is the current page of the report log, zero while the log
+/// doesn't exist.
+#[allow(clippy::too_many_arguments)]
+pub fn report_relay_ix(
+    reporter_pubkey: &Pubkey,
+    round_number: u32,
+    accused: Pubkey,
+    category: ReportCategory,
+    evidence_hash: Hash,
+    page: u64,
+    reporter_proof: Option<MerkleProof>,
+    accused_proof: Option<MerkleProof>,
+) -> Instruction {
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let report_log_pubkey = get_report_log_address();
+    let report_log_page_pubkey = get_report_log_page_address(page);
+
+    let data = RoundLoaderInstruction::ReportRelay {
+        accused,
+        category,
+        evidence_hash,
+        reporter_proof,
+        accused_proof,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*reporter_pubkey, true),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new(report_log_pubkey, false),
+            AccountMeta::new(report_log_page_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn finalize_spend_proposal_ix(
+    funder_pubkey: &Pubkey,
+    proposal_pubkey: &Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let relay_round_pubkey = get_relay_round_address(round_number);
+    let treasury_pubkey = get_treasury_address();
+
+    let data = RoundLoaderInstruction::FinalizeSpendProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(treasury_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn execute_spend_proposal_ix(
+    proposal_pubkey: &Pubkey,
+    recipient_pubkey: &Pubkey,
+) -> Instruction {
+    let treasury_pubkey = get_treasury_address();
+
+    let data = RoundLoaderInstruction::ExecuteSpendProposal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*proposal_pubkey, false),
+            AccountMeta::new(treasury_pubkey, false),
+            AccountMeta::new(*recipient_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn fund_treasury_ix(funder_pubkey: &Pubkey, amount: u64) -> Instruction {
+    let treasury_pubkey = get_treasury_address();
+
+    let data = RoundLoaderInstruction::FundTreasury { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*funder_pubkey, true),
+            AccountMeta::new(treasury_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
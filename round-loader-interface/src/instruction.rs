@@ -0,0 +1,328 @@
+#[cfg(feature = "schema")]
+use borsh::BorshSchema;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::merkle::MerkleProof;
+use bridge_utils::types::Vote;
+use solana_program::hash::Hash;
+use solana_program::pubkey::Pubkey;
+
+use crate::ReportCategory;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum RoundLoaderInstruction {
+    /// Vote for proposal
+    ///
+    /// # Account references
+    /// ...
+    VoteForProposal {
+        // Vote type
+        vote: Vote,
+    },
+
+    /// Initialize the genesis round
+    ///
+    /// # Account references
+    /// ...
+    Initialize {
+        // Genesis round number
+        genesis_round_number: u32,
+        // Relay Round submitter role
+        round_submitter: Pubkey,
+        // Minimum required votes
+        min_required_votes: u32,
+        // Round TTL
+        round_ttl: u32,
+    },
+
+    /// Update Settings
+    ///
+    /// # Account references
+    /// ...
+    UpdateSettings {
+        // Current round number
+        current_round_number: Option<u32>,
+        // Relay Round submitter role
+        round_submitter: Option<Pubkey>,
+        // Minimum required votes
+        min_required_votes: Option<u32>,
+        // Round TTL
+        round_ttl: Option<u32>,
+        // Accept withdrawals only for registered event configurations
+        event_configurations_restricted: Option<bool>,
+        // Seconds before the round end the replacement round should be proposed by
+        proposal_lead_time: Option<u32>,
+        // Seconds a round created by a proposal waits before it becomes active
+        activation_delay: Option<u32>,
+        // Authority allowed to freeze a round before it becomes active
+        guardian: Option<Pubkey>,
+        // Number of rounds behind the current one a round is kept for
+        round_retention: Option<u16>,
+    },
+
+    /// Create Relay Round
+    ///
+    /// Sets `RelayRoundResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    CreateRelayRound {
+        // Relay Round number
+        round_number: u32,
+        // Relays keys in a new round
+        relays: Vec<Pubkey>,
+        // Round end
+        round_end: u32,
+    },
+
+    /// Create proposal account for a new Relay Round
+    ///
+    /// # Account references
+    /// ...
+    CreateProposal {
+        // Round number
+        round_number: u32,
+        // Ever event timestamp
+        event_timestamp: u32,
+        // Ever event transaction lt
+        event_transaction_lt: u64,
+        // Ever event configuration
+        event_configuration: Pubkey,
+        // Sha256 of event data
+        event_data: Hash,
+    },
+
+    /// Write Relay Round data into an proposal account
+    ///
+    /// # Account references
+    /// ...
+    WriteProposal {
+        // Offset at which to write the given bytes
+        offset: u32,
+        // Serialized set of keys of for a new round
+        bytes: Vec<u8>,
+    },
+
+    /// Finalize an proposal account loaded with a new Relay Round data
+    ///
+    /// # Account references
+    /// ...
+    FinalizeProposal,
+
+    /// Execute proposal. The executor is reimbursed from the Executor Reward Vault
+    /// if the vault is passed after the listed accounts. Sets `RelayRoundResult` as return
+    /// data once the proposal is executed.
+    ///
+    /// # Account references
+    /// ...
+    ExecuteProposal,
+
+    /// Execute proposal by admin
+    ///
+    /// Sets `RelayRoundResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    ExecuteProposalByAdmin,
+
+    /// Create Admin Audit Log
+    ///
+    /// # Account references
+    /// ...
+    CreateAdminAuditLog,
+
+    /// Replace relay key in a Relay Round. Must be signed by both old and new keys.
+    ///
+    /// # Account references
+    /// ...
+    RotateRelayKey,
+
+    /// Finalize an proposal account loaded with a Relay Round delta
+    ///
+    /// # Account references
+    /// ...
+    FinalizeDeltaProposal,
+
+    /// Execute delta proposal. Relays of a new round are the relays of the round
+    /// the proposal was voted in with the delta applied. The executor is reimbursed
+    /// the same way as for `ExecuteProposal`. Sets `RelayRoundResult` as return data
+    /// once the proposal is executed.
+    ///
+    /// # Account references
+    /// ...
+    ExecuteDeltaProposal,
+
+    /// Finalize an proposal account loaded with a program upgrade
+    ///
+    /// # Account references
+    /// ...
+    FinalizeUpgradeProposal,
+
+    /// Execute upgrade proposal. Deploys the buffer through the upgradeable loader,
+    /// signing with the Round Loader upgrade authority.
+    ///
+    /// # Account references
+    /// ...
+    ExecuteUpgradeProposal,
+
+    /// Move votes of a proposal finalized before votes were kept in `ProposalVote` accounts.
+    /// Vote accounts of relays that already voted are passed in the Relay Round order.
+    ///
+    /// # Account references
+    /// ...
+    MigrateProposal,
+
+    /// Write a snapshot of a Relay Round into its Round Digest account, so the relay set
+    /// can be verified on the EVER side. Can be called again once the round keys are rotated.
+    ///
+    /// # Account references
+    /// ...
+    ExportRoundDigest,
+
+    /// Create Relay Round keeping only the merkle root of its relays. Relays of the round
+    /// vote with a proof of membership.
+    ///
+    /// Sets `RelayRoundResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    CreateMerkleRelayRound {
+        // Relay Round number
+        round_number: u32,
+        // Merkle root of relays keys in a new round
+        relays_root: Hash,
+        // Number of relays in a new round
+        relays_count: u32,
+        // Round end
+        round_end: u32,
+    },
+
+    /// Vote for proposal in a Relay Round keeping a merkle root of its relays
+    ///
+    /// # Account references
+    /// ...
+    VoteForProposalWithProof {
+        // Vote type
+        vote: Vote,
+        // Proof of the voter membership in the round
+        proof: MerkleProof,
+    },
+
+    /// Register an event configuration approved to emit withdrawal events
+    ///
+    /// # Account references
+    /// ...
+    AddEventConfiguration {
+        // Event configuration address
+        event_configuration: Pubkey,
+    },
+
+    /// Unregister an event configuration
+    ///
+    /// # Account references
+    /// ...
+    RemoveEventConfiguration {
+        // Event configuration address
+        event_configuration: Pubkey,
+    },
+
+    /// Close an executed proposal once its challenge window has passed, refunding
+    /// the account lamports to the proposal author
+    ///
+    /// # Account references
+    /// ...
+    CloseProposal,
+
+    /// Freeze a Relay Round created by a proposal before it becomes active. Frozen round
+    /// never becomes active.
+    ///
+    /// # Account references
+    /// ...
+    FreezeRelayRound,
+
+    /// Finalize an proposal account loaded with new relays bounds
+    ///
+    /// # Account references
+    /// ...
+    FinalizeRelayBoundsProposal,
+
+    /// Execute relays bounds proposal, the only way to change the bounds of the number
+    /// of relays in a round
+    ///
+    /// # Account references
+    /// ...
+    ExecuteRelayBoundsProposal,
+
+    /// Vote for proposal claiming the position of the voter in the Relay Round
+    ///
+    /// # Account references
+    /// ...
+    VoteForProposalWithIndex {
+        // Vote type
+        vote: Vote,
+        // Index of the voter in the round relays
+        index: u32,
+    },
+
+    /// Close a Relay Round more than `round_retention` rounds behind the current one,
+    /// sending its rent to the treasury
+    ///
+    /// # Account references
+    /// ...
+    CloseOldRound,
+
+    /// File a misbehavior report against another relay of the reporter round
+    ///
+    /// # Account references
+    /// ...
+    ReportRelay {
+        // Reported relay
+        accused: Pubkey,
+        // Kind of the misbehavior
+        category: ReportCategory,
+        // Hash of the evidence kept off-chain
+        evidence_hash: Hash,
+        // Proofs of the reporter and the accused membership in a merkle round
+        reporter_proof: Option<MerkleProof>,
+        accused_proof: Option<MerkleProof>,
+    },
+
+    /// Finalize an proposal account loaded with a treasury spend
+    ///
+    /// # Account references
+    /// ...
+    FinalizeSpendProposal,
+
+    /// Execute spend proposal, paying lamports from the treasury to the recipient
+    ///
+    /// # Account references
+    /// ...
+    ExecuteSpendProposal,
+
+    /// Transfer lamports of the funder to the treasury. The treasury also receives the rent of
+    /// relay rounds closed by `CloseOldRound`.
+    ///
+    /// The treasury holds lamports only. Token fees of the proxies are kept in their vaults
+    /// and withdrawn by the proxy owner, they are not swept to the treasury.
+    ///
+    /// # Account references
+    /// ...
+    FundTreasury {
+        // Lamports to transfer
+        amount: u64,
+    },
+}
+
+impl RoundLoaderInstruction {
+    /// Position of the authority account for instructions recorded in the admin audit log
+    pub fn admin_authority_index(&self) -> Option<usize> {
+        match self {
+            RoundLoaderInstruction::UpdateSettings { .. }
+            | RoundLoaderInstruction::AddEventConfiguration { .. }
+            | RoundLoaderInstruction::RemoveEventConfiguration { .. }
+            | RoundLoaderInstruction::FreezeRelayRound => Some(0),
+            RoundLoaderInstruction::ExecuteProposalByAdmin => Some(1),
+            _ => None,
+        }
+    }
+}
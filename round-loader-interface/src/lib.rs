@@ -0,0 +1,63 @@
+//! Round Loader ids, state, instructions and instruction builders.
+//!
+//! Contains no processor code, so on-chain programs can depend on it to CPI into Round Loader.
+
+mod bindings;
+mod instruction;
+#[cfg(feature = "schema")]
+mod schema;
+mod state;
+mod utils;
+
+pub use self::bindings::*;
+pub use self::instruction::*;
+#[cfg(feature = "schema")]
+pub use self::schema::*;
+pub use self::state::*;
+pub use self::utils::*;
+
+use bridge_utils::types::Cluster;
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+
+/// Program id of the mainnet deployment
+pub const MAINNET_ID: Pubkey = pubkey!("roundAsiEM445bGEp7ZwPWXUmWAHh6rpLEndJUKP1V4");
+/// Program id of the devnet deployment
+pub const DEVNET_ID: Pubkey = pubkey!("7EmWKfu9DsvA4ZHgQ9YyTi34dHkEJUiwxSjBJR4vKiT");
+/// Program id of the local validator deployment
+pub const LOCALNET_ID: Pubkey = pubkey!("2gVbgZFd9dBoamVYCPEjPSHmFkDA7xLgrZ43jyzDvKfo");
+
+/// Program id of the deployment on the given cluster
+pub const fn program_id(cluster: Cluster) -> Pubkey {
+    match cluster {
+        Cluster::Mainnet => MAINNET_ID,
+        Cluster::Devnet => DEVNET_ID,
+        Cluster::Localnet => LOCALNET_ID,
+    }
+}
+
+/// Program id of the cluster selected by cargo features
+pub const ID: Pubkey = program_id(Cluster::CURRENT);
+
+/// Token Proxy program id of the deployment on the given cluster. The Round Loader reads the
+/// relay round stats it keeps, so the ids are mirrored here.
+pub const fn token_proxy_program_id(cluster: Cluster) -> Pubkey {
+    match cluster {
+        Cluster::Mainnet => pubkey!("octuswa5MD5hrTwcNBKvdxDvDQoz7C7M9sk2cRRvZfg"),
+        Cluster::Devnet => pubkey!("4kF5hMX7Awn4UFBGUuxTNrszXbgGNBYhSSbs1CLBidgQ"),
+        Cluster::Localnet => pubkey!("Fe9kGHV4nzYozkhrhJ7MV1tofQ5GPc6srdfzDyz9cKTv"),
+    }
+}
+
+/// Token Proxy program id of the cluster selected by cargo features
+pub const TOKEN_PROXY_ID: Pubkey = token_proxy_program_id(Cluster::CURRENT);
+
+/// Returns `true` if given pubkey is the program id
+pub fn check_id(id: &Pubkey) -> bool {
+    id == &ID
+}
+
+/// Returns the program id
+pub const fn id() -> Pubkey {
+    ID
+}
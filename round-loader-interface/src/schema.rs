@@ -0,0 +1,36 @@
+use bridge_utils::schema::SchemaExport;
+use bridge_utils::state::AdminAuditLog;
+use solana_program::program_pack::Pack;
+
+use crate::*;
+
+macro_rules! accounts {
+    ($export:expr, $($account:ty),* $(,)?) => {
+        $($export.account::<$account>(<$account>::DISCRIMINATOR, <$account>::LEN);)*
+    };
+}
+
+/// Borsh schema of the Round Loader accounts
+pub fn state_schema() -> SchemaExport {
+    let mut export = SchemaExport::new();
+
+    accounts!(
+        export,
+        Settings,
+        RelayRound,
+        RoundDigest,
+        RoundStats,
+        RelayRoundProposal,
+        RelayRoundDeltaProposal,
+        UpgradeProposal,
+        RelayBoundsProposal,
+        SpendProposal,
+        ProposalVote,
+        EventConfiguration,
+        ReportLog,
+        ReportLogPage,
+        AdminAuditLog,
+    );
+
+    export
+}
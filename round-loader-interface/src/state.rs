@@ -0,0 +1,923 @@
+use std::io::{ErrorKind, Read, Write};
+
+#[cfg(feature = "schema")]
+use borsh::BorshSchema;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_derive::BridgePack;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::merkle::{get_merkle_root, verify_merkle_proof, MerkleProof};
+use bridge_utils::state::{AccountKind, ACCOUNT_DISCRIMINATOR_LEN, PDA};
+use bridge_utils::ton::{Cell, CellBuilder, CellError};
+use bridge_utils::types::Vote;
+#[cfg(feature = "client")]
+use serde::{Deserialize, Serialize};
+
+use solana_program::hash::{Hash, HASH_BYTES};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::{IsInitialized, Pack, Sealed};
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+
+/// Minimum Relays in round
+pub const MIN_RELAYS: usize = 3;
+
+/// Maximum Relays in round
+pub const MAX_RELAYS: usize = 100;
+
+/// Maximum Relays in round keeping a merkle root. Bounded by the per-relay slots of the
+/// withdrawal and relay stats accounts of Token Proxy.
+pub const MAX_MERKLE_RELAYS: usize = 120;
+
+/// Whether the relays bounds are ordered and within the absolute limits
+pub fn valid_relays_bounds(min_relays: usize, max_relays: usize) -> bool {
+    MIN_RELAYS <= min_relays && min_relays <= max_relays && max_relays <= MAX_MERKLE_RELAYS
+}
+
+/// Lamports paid from the Executor Reward Vault on top of the Relay Round rent
+pub const EXECUTOR_REWARD: u64 = 100_000;
+
+/// Seconds an executed proposal is kept for challenges before it can be closed
+pub const PROPOSAL_CHALLENGE_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+pub const LOAD_DATA_BEGIN_OFFSET: usize = ACCOUNT_DISCRIMINATOR_LEN
+    + 1                                     // is_initialized
+    + 3                                     // account_kind
+    + PUBKEY_BYTES                          // author
+    + 4                                     // round_number
+    + 4                                     // required_votes
+    + 4                                     // event_timestamp
+    + 8                                     // event_transaction_lt
+    + PUBKEY_BYTES                          // event_configuration
+;
+
+pub const LOAD_DATA_END_OFFSET: usize = LOAD_DATA_BEGIN_OFFSET
+    + 4                                         // relays len
+    + 4                                         // round_num
+    + 4 + PUBKEY_BYTES * MAX_RELAYS             // relays
+    + 4                                         // round_end
+;
+
+const RELAY_ROUND_PROPOSAL_META_LEN: usize = 1  // status
+;
+
+const RELAY_ROUND_TAIL_LEN: usize = 32          // relays_root
+    + 4                                         // relays_count
+    + 4                                         // suggested_proposal_deadline
+    + 4                                         // activated_at
+    + 1                                         // frozen
+;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 99 + reserve
+pub struct Settings {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub current_round_number: u32,
+    pub round_submitter: Pubkey,
+    pub min_required_votes: u32,
+    pub round_ttl: u32,
+    // Withdrawals are accepted only for registered event configurations
+    pub event_configurations_restricted: bool,
+    // Seconds before the round end the replacement round should be proposed by
+    pub proposal_lead_time: u32,
+    // Seconds a round created by a proposal waits before it becomes active
+    pub activation_delay: u32,
+    // Authority allowed to freeze a round before it becomes active
+    pub guardian: Pubkey,
+    // Bounds of the number of relays in a round created by a proposal, changed only
+    // by a relay bounds proposal
+    pub min_relays: u32,
+    pub max_relays: u32,
+    // Number of rounds behind the current one a round is kept for, zero keeps all rounds
+    pub round_retention: u16,
+}
+
+impl Settings {
+    /// Time the replacement of a round ending at `round_end` should be proposed by
+    pub fn proposal_deadline(&self, round_end: u32) -> u32 {
+        round_end.saturating_sub(self.proposal_lead_time)
+    }
+
+    /// Time a round created by a proposal executed at `now` becomes active
+    pub fn activation_time(&self, now: u32) -> u32 {
+        now.saturating_add(self.activation_delay)
+    }
+
+    /// Whether the round is far enough behind the current one to be closed
+    pub fn is_round_prunable(&self, round_number: u32) -> bool {
+        self.round_retention != 0
+            && self.current_round_number.saturating_sub(round_number) > self.round_retention as u32
+    }
+
+    /// Bounds of the number of relays in a round created by a proposal. Settings created
+    /// before the bounds keep zeros and get the default bounds.
+    pub fn relays_bounds(&self) -> (usize, usize) {
+        let min_relays = match self.min_relays {
+            0 => MIN_RELAYS,
+            min_relays => min_relays as usize,
+        };
+
+        let max_relays = match self.max_relays {
+            0 => MAX_MERKLE_RELAYS,
+            max_relays => max_relays as usize,
+        };
+
+        (min_relays, max_relays)
+    }
+
+    /// Check the cross-field invariants of the settings
+    pub fn audit(&self) -> Result<(), SolanaBridgeError> {
+        if self.round_submitter == Pubkey::default() {
+            return Err(SolanaBridgeError::DefaultRoleKey);
+        }
+
+        if self.min_required_votes == 0 {
+            return Err(SolanaBridgeError::InvalidQuorum);
+        }
+
+        if self.round_ttl == 0 {
+            return Err(SolanaBridgeError::InvalidRoundTtl);
+        }
+
+        let (min_relays, max_relays) = self.relays_bounds();
+        if !valid_relays_bounds(min_relays, max_relays) {
+            return Err(SolanaBridgeError::InvalidRelaysBounds);
+        }
+
+        Ok(())
+    }
+}
+
+impl Sealed for Settings {}
+
+impl IsInitialized for Settings {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 3215)]
+pub struct RelayRound {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    pub round_end: u32,
+    // Relays of a round kept as a full list, empty if the round keeps a merkle root
+    pub relays: Vec<Pubkey>,
+    // Merkle root of the relays of a round too large for the full list
+    pub relays_root: Hash,
+    // Number of relays under the merkle root, at most `MAX_MERKLE_RELAYS`
+    pub relays_count: u32,
+    // Time the replacement round should be proposed by, the round end minus the lead time
+    pub suggested_proposal_deadline: u32,
+    // Time the round becomes active, zero for rounds active since their creation
+    pub activated_at: u32,
+    // Round frozen by the guardian during its challenge window never becomes active
+    pub frozen: bool,
+}
+
+impl RelayRound {
+    /// Whether relays of the round can confirm events
+    pub fn is_active(&self, now: u32) -> bool {
+        !self.frozen && now >= self.activated_at
+    }
+
+    /// Whether the fields after the relays fit into the account
+    pub fn keeps_tail(&self) -> bool {
+        let free_space = MAX_RELAYS.saturating_sub(self.relays.len()) * PUBKEY_BYTES;
+        self.is_merkle() || free_space >= RELAY_ROUND_TAIL_LEN
+    }
+
+    pub fn is_merkle(&self) -> bool {
+        self.relays_root != Hash::default()
+    }
+
+    pub fn relays_len(&self) -> usize {
+        if self.is_merkle() {
+            self.relays_count as usize
+        } else {
+            self.relays.len()
+        }
+    }
+
+    /// Merkle root of the round relays, computed for rounds kept as a full list
+    pub fn get_relays_root(&self) -> Hash {
+        if self.is_merkle() {
+            self.relays_root
+        } else {
+            get_merkle_root(&self.relays)
+        }
+    }
+
+    /// Position of the relay in the round. Relays of a merkle round prove their membership.
+    pub fn find_relay(
+        &self,
+        relay: &Pubkey,
+        proof: Option<&MerkleProof>,
+    ) -> Result<usize, ProgramError> {
+        if self.is_merkle() {
+            let proof = proof.ok_or(SolanaBridgeError::InvalidMerkleProof)?;
+
+            if !verify_merkle_proof(relay, proof, self.relays_count, &self.relays_root) {
+                return Err(SolanaBridgeError::InvalidMerkleProof.into());
+            }
+
+            Ok(proof.index as usize)
+        } else {
+            self.relays
+                .iter()
+                .position(|pubkey| pubkey == relay)
+                .ok_or_else(|| SolanaBridgeError::InvalidRelay.into())
+        }
+    }
+
+    /// Position of the relay claimed by the voter. Checks the single slot instead of scanning
+    /// the round.
+    pub fn check_relay_index(&self, relay: &Pubkey, index: u32) -> Result<usize, ProgramError> {
+        if self.is_merkle() {
+            return Err(SolanaBridgeError::InvalidMerkleProof.into());
+        }
+
+        match self.relays.get(index as usize) {
+            Some(pubkey) if pubkey == relay => Ok(index as usize),
+            _ => Err(SolanaBridgeError::InvalidRelay.into()),
+        }
+    }
+}
+
+// Rounds with the full list of `MAX_RELAYS` take the whole account, so the fields after the
+// relays are written only if they fit and read as default when the data ends
+impl BorshSerialize for RelayRound {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.is_initialized, writer)?;
+        BorshSerialize::serialize(&self.account_kind, writer)?;
+        BorshSerialize::serialize(&self.round_number, writer)?;
+        BorshSerialize::serialize(&self.round_end, writer)?;
+        BorshSerialize::serialize(&self.relays, writer)?;
+
+        if self.keeps_tail() {
+            BorshSerialize::serialize(&self.relays_root, writer)?;
+            BorshSerialize::serialize(&self.relays_count, writer)?;
+            BorshSerialize::serialize(&self.suggested_proposal_deadline, writer)?;
+            BorshSerialize::serialize(&self.activated_at, writer)?;
+            BorshSerialize::serialize(&self.frozen, writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for RelayRound {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let is_initialized = bool::deserialize_reader(reader)?;
+        let account_kind = AccountKind::deserialize_reader(reader)?;
+        let round_number = u32::deserialize_reader(reader)?;
+        let round_end = u32::deserialize_reader(reader)?;
+        let relays = Vec::<Pubkey>::deserialize_reader(reader)?;
+
+        let mut merkle = [0; 32 + 4];
+        match reader.read_exact(&mut merkle) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => merkle = [0; 32 + 4],
+            Err(e) => return Err(e),
+        }
+
+        let (relays_root, relays_count) = merkle.split_at(32);
+
+        let mut deadline = [0; 4];
+        match reader.read_exact(&mut deadline) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => deadline = [0; 4],
+            Err(e) => return Err(e),
+        }
+
+        // Rounds without the deadline are proposed for by their end
+        let suggested_proposal_deadline = match u32::from_le_bytes(deadline) {
+            0 => round_end,
+            deadline => deadline,
+        };
+
+        // Rounds created before the activation delay are active and not frozen
+        let mut activation = [0; 4 + 1];
+        match reader.read_exact(&mut activation) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => activation = [0; 4 + 1],
+            Err(e) => return Err(e),
+        }
+
+        let (activated_at, frozen) = activation.split_at(4);
+
+        Ok(Self {
+            is_initialized,
+            account_kind,
+            round_number,
+            round_end,
+            relays,
+            relays_root: Hash::new(relays_root),
+            relays_count: u32::from_le_bytes(relays_count.try_into().expect("slice with len 4")),
+            suggested_proposal_deadline,
+            activated_at: u32::from_le_bytes(activated_at.try_into().expect("slice with len 4")),
+            frozen: frozen[0] != 0,
+        })
+    }
+}
+
+impl Sealed for RelayRound {}
+
+impl IsInitialized for RelayRound {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Canonical snapshot of a Relay Round, verified by the EVER-side contracts by its hash
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 75 + reserve
+pub struct RoundDigest {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    pub round_end: u32,
+    // Merkle root of the round relays
+    pub relays_root: Hash,
+    // Representation hash of the snapshot cell
+    pub hash: Hash,
+}
+
+impl RoundDigest {
+    /// Snapshot cell as the EVER-side contracts build it, fields in declaration order
+    pub fn to_cell(&self) -> Result<Cell, CellError> {
+        let mut builder = CellBuilder::new();
+        builder
+            .store_uint(self.round_number as u128, 32)?
+            .store_u256(&self.relays_root.to_bytes())?
+            .store_uint(self.round_end as u128, 32)?;
+
+        Ok(builder.build())
+    }
+}
+
+impl Sealed for RoundDigest {}
+
+impl IsInitialized for RoundDigest {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Statistics of a finished Relay Round, snapshotted from the Token Proxy when the next round is
+/// executed
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 500)] // 447 + reserve
+pub struct RoundStats {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    // Withdrawals voted for in the round
+    pub withdrawals: u32,
+    // Sum of the voted withdrawal amounts, in base units of their tokens
+    pub volume: u128,
+    // Total number of votes cast in the round
+    pub total_votes: u64,
+    // Votes cast by each relay, in the order of the round relays
+    pub votes: Vec<u32>,
+    pub snapshot_at: i64,
+}
+
+impl Sealed for RoundStats {}
+
+impl IsInitialized for RoundStats {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 3415)]
+pub struct RelayRoundProposal {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: RelayRoundProposalEventWithLen,
+    pub meta: RelayRoundProposalMetaWithLen,
+    // Votes of proposals finalized before votes moved to `ProposalVote` accounts
+    pub signers: Vec<Vote>,
+    pub confirm_count: u32,
+    pub reject_count: u32,
+    // Time the proposal was executed at, zero until then
+    pub executed_at: i64,
+}
+
+impl RelayRoundProposal {
+    /// Whether the proposal can be closed, refunding its rent to the author
+    pub fn is_closable(&self, now: i64) -> bool {
+        self.meta.data.status == ProposalStatus::Executed
+            && now >= self.executed_at.saturating_add(PROPOSAL_CHALLENGE_WINDOW)
+    }
+}
+
+impl Sealed for RelayRoundProposal {}
+
+impl IsInitialized for RelayRoundProposal {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayRoundProposalEvent {
+    pub round_num: u32,
+    pub relays: Vec<Pubkey>,
+    pub round_end: u32,
+}
+
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayRoundProposalEventWithLen {
+    pub len: u32,
+    pub data: RelayRoundProposalEvent,
+}
+
+impl RelayRoundProposalEventWithLen {
+    pub fn new(round_num: u32, relays: Vec<Pubkey>, round_end: u32) -> Self {
+        Self {
+            len: (4 + 4 + 4 + PUBKEY_BYTES * relays.len()) as u32,
+            data: RelayRoundProposalEvent {
+                round_num,
+                round_end,
+                relays,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 3415)]
+pub struct RelayRoundDeltaProposal {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: RelayRoundDeltaProposalEventWithLen,
+    pub meta: RelayRoundProposalMetaWithLen,
+    // Votes of proposals finalized before votes moved to `ProposalVote` accounts
+    pub signers: Vec<Vote>,
+    pub confirm_count: u32,
+    pub reject_count: u32,
+}
+
+impl Sealed for RelayRoundDeltaProposal {}
+
+impl IsInitialized for RelayRoundDeltaProposal {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// New Relay Round described relative to the round the proposal was voted in
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayRoundDeltaProposalEvent {
+    pub round_num: u32,
+    pub added: Vec<Pubkey>,
+    pub removed: Vec<Pubkey>,
+    pub round_end: u32,
+}
+
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayRoundDeltaProposalEventWithLen {
+    pub len: u32,
+    pub data: RelayRoundDeltaProposalEvent,
+}
+
+impl RelayRoundDeltaProposalEventWithLen {
+    pub fn new(round_num: u32, added: Vec<Pubkey>, removed: Vec<Pubkey>, round_end: u32) -> Self {
+        Self {
+            len: (4 + 4 + 4 + 4 + PUBKEY_BYTES * (added.len() + removed.len())) as u32,
+            data: RelayRoundDeltaProposalEvent {
+                round_num,
+                added,
+                removed,
+                round_end,
+            },
+        }
+    }
+}
+
+impl RelayRoundDeltaProposalEvent {
+    /// Apply the delta to the relays of the current round
+    pub fn apply(&self, relays: &[Pubkey]) -> Result<Vec<Pubkey>, ProgramError> {
+        let mut relays = relays.to_vec();
+
+        for relay in &self.removed {
+            let index = relays
+                .iter()
+                .position(|pubkey| pubkey == relay)
+                .ok_or(SolanaBridgeError::InvalidRelay)?;
+            relays.remove(index);
+        }
+
+        for relay in &self.added {
+            if relays.contains(relay) {
+                return Err(SolanaBridgeError::InvalidRelay.into());
+            }
+            relays.push(*relay);
+        }
+
+        if relays.len() > MAX_RELAYS {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(relays)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 3415)]
+pub struct UpgradeProposal {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: UpgradeProposalEventWithLen,
+    pub meta: RelayRoundProposalMetaWithLen,
+    // Votes of proposals finalized before votes moved to `ProposalVote` accounts
+    pub signers: Vec<Vote>,
+    pub confirm_count: u32,
+    pub reject_count: u32,
+}
+
+impl Sealed for UpgradeProposal {}
+
+impl IsInitialized for UpgradeProposal {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Upgrade of a program whose upgrade authority is the Round Loader upgrade authority
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct UpgradeProposalEvent {
+    pub program: Pubkey,
+    pub buffer: Pubkey,
+    pub spill: Pubkey,
+    // Hash of the program bytes in the buffer, see `get_buffer_hash`
+    pub buffer_hash: Hash,
+}
+
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct UpgradeProposalEventWithLen {
+    pub len: u32,
+    pub data: UpgradeProposalEvent,
+}
+
+impl UpgradeProposalEventWithLen {
+    pub fn new(program: Pubkey, buffer: Pubkey, spill: Pubkey, buffer_hash: Hash) -> Self {
+        Self {
+            len: (PUBKEY_BYTES * 3 + HASH_BYTES) as u32,
+            data: UpgradeProposalEvent {
+                program,
+                buffer,
+                spill,
+                buffer_hash,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 3415)]
+pub struct RelayBoundsProposal {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: RelayBoundsProposalEventWithLen,
+    pub meta: RelayRoundProposalMetaWithLen,
+    // Votes of proposals finalized before votes moved to `ProposalVote` accounts
+    pub signers: Vec<Vote>,
+    pub confirm_count: u32,
+    pub reject_count: u32,
+}
+
+impl Sealed for RelayBoundsProposal {}
+
+impl IsInitialized for RelayBoundsProposal {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// New bounds of the number of relays in a round created by a proposal
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayBoundsProposalEvent {
+    pub min_relays: u32,
+    pub max_relays: u32,
+}
+
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayBoundsProposalEventWithLen {
+    pub len: u32,
+    pub data: RelayBoundsProposalEvent,
+}
+
+impl RelayBoundsProposalEventWithLen {
+    pub fn new(min_relays: u32, max_relays: u32) -> Self {
+        Self {
+            len: (4 + 4) as u32,
+            data: RelayBoundsProposalEvent {
+                min_relays,
+                max_relays,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 3415)]
+pub struct SpendProposal {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: SpendProposalEventWithLen,
+    pub meta: RelayRoundProposalMetaWithLen,
+    // Votes of proposals finalized before votes moved to `ProposalVote` accounts
+    pub signers: Vec<Vote>,
+    pub confirm_count: u32,
+    pub reject_count: u32,
+}
+
+impl Sealed for SpendProposal {}
+
+impl IsInitialized for SpendProposal {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Lamports paid from the treasury to the recipient
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct SpendProposalEvent {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct SpendProposalEventWithLen {
+    pub len: u32,
+    pub data: SpendProposalEvent,
+}
+
+impl SpendProposalEventWithLen {
+    pub fn new(recipient: Pubkey, amount: u64) -> Self {
+        Self {
+            len: (PUBKEY_BYTES + 8) as u32,
+            data: SpendProposalEvent { recipient, amount },
+        }
+    }
+}
+
+/// Layout shared by all proposals, with the event kept as raw bytes
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct GenericProposal {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: Vec<u8>,
+    pub meta: Vec<u8>,
+    pub signers: Vec<Vote>,
+    pub confirm_count: u32,
+    pub reject_count: u32,
+}
+
+impl GenericProposal {
+    pub fn pack_into_slice(&self, dst: &mut [u8]) {
+        // Discriminator of the concrete account type is kept as is
+        let data = self.try_to_vec().unwrap();
+        let (left, _) = dst[ACCOUNT_DISCRIMINATOR_LEN..].split_at_mut(data.len());
+        left.copy_from_slice(&data);
+    }
+
+    pub fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let mut src = src
+            .get(ACCOUNT_DISCRIMINATOR_LEN..)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let unpacked = Self::deserialize(&mut src)?;
+        Ok(unpacked)
+    }
+
+    /// Status leading the meta of every proposal kind
+    pub fn status(&self) -> Result<ProposalStatus, ProgramError> {
+        let meta = RelayRoundProposalMeta::deserialize(&mut self.meta.as_slice())?;
+        Ok(meta.status)
+    }
+}
+
+/// Vote of a single relay, its address is derived from the proposal and the relay position in
+/// the round. The position outlives key rotations, so a relay slot votes once.
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 68 + reserve
+pub struct ProposalVote {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub vote: Vote,
+}
+
+impl Sealed for ProposalVote {}
+
+impl IsInitialized for ProposalVote {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayRoundProposalMeta {
+    pub status: ProposalStatus,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayRoundProposalMetaWithLen {
+    pub len: u32,
+    pub data: RelayRoundProposalMeta,
+}
+
+impl RelayRoundProposalMetaWithLen {
+    pub fn new() -> Self {
+        Self {
+            len: RELAY_ROUND_PROPOSAL_META_LEN as u32,
+            data: RelayRoundProposalMeta {
+                status: ProposalStatus::New,
+            },
+        }
+    }
+}
+
+impl Default for RelayRoundProposalMetaWithLen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Copy, BorshSerialize, BorshDeserialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum ProposalStatus {
+    New,
+    Executed,
+}
+
+/// Relay round created by the instruction, returned in the return data
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayRoundResult {
+    pub relay_round: Pubkey,
+    pub round_number: u32,
+    pub round_end: u32,
+}
+
+/// Event configuration approved to emit withdrawal events
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 36 + reserve
+pub struct EventConfiguration {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub event_configuration: Pubkey,
+}
+
+impl Sealed for EventConfiguration {}
+
+impl IsInitialized for EventConfiguration {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Number of reports kept in a single report log page
+pub const REPORT_LOG_PAGE_CAPACITY: usize = 16;
+
+/// Misbehavior reports filed by relays against other relays of their round
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 11 + reserve
+pub struct ReportLog {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Total number of filed reports
+    pub total: u64,
+}
+
+impl ReportLog {
+    /// Page the next report is written to
+    pub fn current_page(&self) -> u64 {
+        self.total / REPORT_LOG_PAGE_CAPACITY as u64
+    }
+}
+
+impl Sealed for ReportLog {}
+
+impl IsInitialized for ReportLog {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1800)] // 1759 + reserve
+pub struct ReportLogPage {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub page: u64,
+    // Reports ordered from the oldest to the newest one
+    pub reports: Vec<RelayReport>,
+}
+
+impl Sealed for ReportLogPage {}
+
+impl IsInitialized for ReportLogPage {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RelayReport {
+    pub reporter: Pubkey,
+    pub accused: Pubkey,
+    // Round both relays belong to
+    pub round_number: u32,
+    pub category: ReportCategory,
+    // Hash of the evidence kept off-chain
+    pub evidence_hash: Hash,
+    pub reported_at: i64,
+}
+
+#[derive(Copy, BorshSerialize, BorshDeserialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum ReportCategory {
+    // Conflicting votes for the same event
+    Equivocation,
+    // Vote confirming an event that never happened
+    InvalidVote,
+    // Relay doesn't vote
+    Downtime,
+    // Relay key is leaked or used by someone else
+    KeyCompromise,
+    Other,
+}
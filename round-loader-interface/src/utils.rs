@@ -0,0 +1,126 @@
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::helper::create_pda;
+
+use solana_program::account_info::AccountInfo;
+use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
+use solana_program::hash::{hash, Hash};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use crate::EventConfiguration;
+
+pub fn validate_relay_round_account(
+    program_id: &Pubkey,
+    round_number: u32,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let account = create_pda(
+        program_id,
+        &[br"relay_round", &round_number.to_le_bytes()],
+        nonce,
+    )?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+/// Hash of the program bytes held by an upgrade buffer account, without the buffer header
+pub fn get_buffer_hash(buffer_data: &[u8]) -> Result<Hash, ProgramError> {
+    let program_data = buffer_data
+        .get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok(hash(program_data))
+}
+
+pub fn get_associated_upgrade_authority_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"upgrade_authority"], program_id).0
+}
+
+pub fn get_associated_proposal_vote_address(
+    program_id: &Pubkey,
+    proposal: &Pubkey,
+    relay_index: u32,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"vote", &proposal.to_bytes(), &relay_index.to_le_bytes()],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_executor_reward_vault_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"executor_reward_vault"], program_id).0
+}
+
+pub fn get_associated_round_digest_address(program_id: &Pubkey, round_number: u32) -> Pubkey {
+    Pubkey::find_program_address(&[br"round_digest", &round_number.to_le_bytes()], program_id).0
+}
+
+pub fn get_associated_round_stats_address(program_id: &Pubkey, round_number: u32) -> Pubkey {
+    Pubkey::find_program_address(&[br"round_stats", &round_number.to_le_bytes()], program_id).0
+}
+
+pub fn get_associated_relay_round_stats_address(
+    token_proxy_program_id: &Pubkey,
+    round_number: u32,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"relay_round_stats", &round_number.to_le_bytes()],
+        token_proxy_program_id,
+    )
+    .0
+}
+
+pub fn get_associated_treasury_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"treasury"], program_id).0
+}
+
+pub fn get_associated_report_log_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"report_log"], program_id).0
+}
+
+pub fn get_associated_report_log_page_address(program_id: &Pubkey, page: u64) -> Pubkey {
+    Pubkey::find_program_address(&[br"report_log", &page.to_le_bytes()], program_id).0
+}
+
+pub fn get_associated_event_configuration_address(
+    program_id: &Pubkey,
+    event_configuration: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"event_configuration", &event_configuration.to_bytes()],
+        program_id,
+    )
+    .0
+}
+
+/// Check that the event configuration is registered in the Round Loader. The registry account
+/// is looked up among the instruction accounts.
+pub fn validate_event_configuration(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    event_configuration: &Pubkey,
+) -> Result<(), ProgramError> {
+    let event_configuration_pubkey =
+        get_associated_event_configuration_address(program_id, event_configuration);
+
+    let event_configuration_account_info = accounts
+        .iter()
+        .find(|a| *a.key == event_configuration_pubkey && a.owner == program_id)
+        .ok_or(SolanaBridgeError::EventConfigurationNotRegistered)?;
+
+    let event_configuration_account_data =
+        EventConfiguration::unpack(&event_configuration_account_info.data.borrow())?;
+
+    if event_configuration_account_data.event_configuration != *event_configuration {
+        return Err(SolanaBridgeError::EventConfigurationNotRegistered.into());
+    }
+
+    Ok(())
+}
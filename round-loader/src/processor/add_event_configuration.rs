@@ -0,0 +1,119 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AddEventConfigurationAccounts<'a, 'info> {
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub event_configuration_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, AddEventConfigurationAccounts<'a, 'info>>,
+    event_configuration: Pubkey,
+) -> ProgramResult {
+    let AddEventConfigurationAccounts {
+        author_account_info,
+        event_configuration_account_info,
+        settings_account_info,
+        programdata_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        author_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Event Configuration Account
+    let (event_configuration_pubkey, event_configuration_nonce) = Pubkey::find_program_address(
+        &[br"event_configuration", &event_configuration.to_bytes()],
+        program_id,
+    );
+
+    if event_configuration_pubkey != *event_configuration_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if event_configuration_account_info.owner == program_id {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Create Event Configuration Account
+    let event_configuration_account_signer_seeds: &[&[_]] = &[
+        br"event_configuration",
+        &event_configuration.to_bytes(),
+        &[event_configuration_nonce],
+    ];
+
+    invoke_signed(
+        &system_instruction::create_account(
+            author_account_info.key,
+            event_configuration_account_info.key,
+            1.max(rent.minimum_balance(EventConfiguration::LEN)),
+            EventConfiguration::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[event_configuration_account_signer_seeds],
+    )?;
+
+    // Init Event Configuration Account
+    let event_configuration_account_data = EventConfiguration {
+        is_initialized: true,
+        account_kind: AccountKind::EventConfiguration(event_configuration_nonce),
+        event_configuration,
+    };
+
+    EventConfiguration::pack(
+        event_configuration_account_data,
+        &mut event_configuration_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::RoundCounters;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CloseOldRoundAccounts<'a, 'info> {
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    pub relay_round_stats_account_info: &'a AccountInfo<'info>,
+    #[account(writable, seeds = [br"treasury"])]
+    pub treasury_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CloseOldRoundAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let CloseOldRoundAccounts {
+        settings_account_info,
+        relay_round_account_info,
+        relay_round_stats_account_info,
+        treasury_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let round_number = relay_round_account_data.round_number;
+
+    validate_relay_round_account(
+        program_id,
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    if !settings_account_data.is_round_prunable(round_number) {
+        return Err(SolanaBridgeError::RoundRetained.into());
+    }
+
+    // Withdrawals that haven't reached a final status still depend on the round
+    if *relay_round_stats_account_info.key
+        != get_associated_relay_round_stats_address(&TOKEN_PROXY_ID, round_number)
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if relay_round_stats_account_info.owner == &TOKEN_PROXY_ID
+        && relay_round_stats_account_info.lamports() != 0
+    {
+        let counters =
+            RoundCounters::unpack_from_slice(&relay_round_stats_account_info.data.borrow())?;
+
+        if counters.active_withdrawals != 0 {
+            return Err(SolanaBridgeError::RoundInUse.into());
+        }
+    }
+
+    // Send the round rent to the treasury
+    let treasury_starting_lamports = treasury_account_info.lamports();
+    **treasury_account_info.lamports.borrow_mut() = treasury_starting_lamports
+        .checked_add(relay_round_account_info.lamports())
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    **relay_round_account_info.lamports.borrow_mut() = 0;
+
+    bridge_utils::helper::delete_account(relay_round_account_info);
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CloseProposalAccounts<'a, 'info> {
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub author_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CloseProposalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let CloseProposalAccounts {
+        proposal_account_info,
+        author_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Proposal Account
+    let proposal_account_data = RelayRoundProposal::unpack(&proposal_account_info.data.borrow())?;
+
+    let round_number = proposal_account_data.round_number;
+    let event_timestamp = proposal_account_data.pda.event_timestamp;
+    let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+    let event_configuration = proposal_account_data.pda.event_configuration;
+    let event_data = hash(&proposal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = proposal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        proposal_account_info,
+    )?;
+
+    // Rent is refunded to the author only
+    if *author_account_info.key != proposal_account_data.author {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if proposal_account_data.meta.data.status != ProposalStatus::Executed {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Executed proposal is kept until its challenge window has passed
+    if !proposal_account_data.is_closable(Clock::get()?.unix_timestamp) {
+        return Err(SolanaBridgeError::ChallengeWindowActive.into());
+    }
+
+    // Refund the proposal lamports to the author
+    let author_starting_lamports = author_account_info.lamports();
+    **author_account_info.lamports.borrow_mut() = author_starting_lamports
+        .checked_add(proposal_account_info.lamports())
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    **proposal_account_info.lamports.borrow_mut() = 0;
+
+    bridge_utils::helper::delete_account(proposal_account_info);
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::state::{AccountKind, AdminAuditLog};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+#[derive(Accounts)]
+pub struct CreateAdminAuditLogAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub audit_log_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CreateAdminAuditLogAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let CreateAdminAuditLogAccounts {
+        funder_account_info,
+        audit_log_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Create Admin Audit Log Account
+    let (audit_log_pubkey, audit_log_nonce) =
+        Pubkey::find_program_address(&[br"audit_log"], program_id);
+    let audit_log_account_signer_seeds: &[&[_]] = &[br"audit_log", &[audit_log_nonce]];
+
+    if audit_log_pubkey != *audit_log_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            audit_log_account_info.key,
+            1.max(rent.minimum_balance(AdminAuditLog::LEN)),
+            AdminAuditLog::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[audit_log_account_signer_seeds],
+    )?;
+
+    // Init Admin Audit Log Account
+    let audit_log_account_data = AdminAuditLog {
+        is_initialized: true,
+        account_kind: AccountKind::AdminAuditLog(audit_log_nonce),
+        total: 0,
+        entries: Vec::new(),
+    };
+
+    AdminAuditLog::pack(
+        audit_log_account_data,
+        &mut audit_log_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
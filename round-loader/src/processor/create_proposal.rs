@@ -0,0 +1,118 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::state::{AccountKind, ACCOUNT_DISCRIMINATOR_LEN, PDA};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::Hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateProposalAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub creator_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CreateProposalAccounts<'a, 'info>>,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    event_data: Hash,
+) -> ProgramResult {
+    let CreateProposalAccounts {
+        funder_account_info,
+        creator_account_info,
+        proposal_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Create Proposal Account
+    let (proposal_pubkey, proposal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data.to_bytes(),
+        ],
+        program_id,
+    );
+    let proposal_account_signer_seeds: &[&[_]] = &[
+        br"proposal",
+        &round_number.to_le_bytes(),
+        &event_timestamp.to_le_bytes(),
+        &event_transaction_lt.to_le_bytes(),
+        &event_configuration.to_bytes(),
+        &event_data.to_bytes(),
+        &[proposal_nonce],
+    ];
+
+    if proposal_pubkey != *proposal_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if bridge_utils::helper::is_uncreated_account(proposal_account_info) {
+        bridge_utils::helper::create_pda_account(
+            funder_account_info,
+            proposal_account_info,
+            rent,
+            RelayRoundProposal::LEN,
+            program_id,
+            accounts,
+            proposal_account_signer_seeds,
+        )?;
+
+        // Init Proposal Account
+        let proposal_account_data = RelayRoundProposal {
+            account_kind: AccountKind::Proposal(proposal_nonce, None),
+            author: *creator_account_info.key,
+            round_number,
+            required_votes: 0,
+            pda: PDA {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+            },
+            is_initialized: Default::default(),
+            signers: Default::default(),
+            confirm_count: Default::default(),
+            reject_count: Default::default(),
+            executed_at: Default::default(),
+            event: Default::default(),
+            meta: Default::default(),
+        };
+
+        RelayRoundProposal::pack(
+            proposal_account_data,
+            &mut proposal_account_info.data.borrow_mut(),
+        )?;
+
+        // Proposal type is only known on finalize, which sets the discriminator
+        proposal_account_info.data.borrow_mut()[..ACCOUNT_DISCRIMINATOR_LEN].fill(0);
+    } else {
+        if proposal_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::Hash;
+use solana_program::program::{invoke_signed, set_return_data};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::{snapshot_round_stats, validate_relays_count, validate_round_activation};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExecuteProposalByAdminAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub creator_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ExecuteProposalByAdminAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let ExecuteProposalByAdminAccounts {
+        funder_account_info,
+        creator_account_info,
+        settings_account_info,
+        proposal_account_info,
+        relay_round_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.round_submitter != *creator_account_info.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let mut proposal = RelayRoundProposal::unpack(&proposal_account_info.data.borrow())?;
+
+    // Create a new Relay Round Account
+    let round_number = proposal.event.data.round_num;
+
+    let (relay_round_pubkey, relay_round_nonce) =
+        Pubkey::find_program_address(&[br"relay_round", &round_number.to_le_bytes()], program_id);
+    let relay_round_account_signer_seeds: &[&[_]] = &[
+        br"relay_round",
+        &round_number.to_le_bytes(),
+        &[relay_round_nonce],
+    ];
+
+    if relay_round_pubkey != *relay_round_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            relay_round_account_info.key,
+            1.max(rent.minimum_balance(RelayRound::LEN)),
+            RelayRound::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[relay_round_account_signer_seeds],
+    )?;
+
+    let round_end = proposal
+        .event
+        .data
+        .round_end
+        .saturating_add(settings_account_data.round_ttl);
+
+    // Init a new Relay Round Account
+    let relay_round_account_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end,
+        relays: proposal.event.data.relays.clone(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: settings_account_data.proposal_deadline(round_end),
+        activated_at: settings_account_data.activation_time(clock.unix_timestamp as u32),
+        frozen: false,
+    };
+
+    validate_round_activation(&settings_account_data, &relay_round_account_data)?;
+    validate_relays_count(
+        &settings_account_data,
+        relay_round_account_data.relays_len(),
+    )?;
+
+    RelayRound::pack(
+        relay_round_account_data,
+        &mut relay_round_account_info.data.borrow_mut(),
+    )?;
+
+    // Snapshot the statistics of the replaced round
+    snapshot_round_stats(
+        program_id,
+        accounts,
+        funder_account_info,
+        settings_account_data.current_round_number,
+        clock.unix_timestamp,
+    )?;
+
+    // Update Settings Account
+    settings_account_data.current_round_number = round_number;
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    proposal.meta.data.status = ProposalStatus::Executed;
+    proposal.executed_at = clock.unix_timestamp;
+
+    // Update Proposal Account
+    RelayRoundProposal::pack(proposal, &mut proposal_account_info.data.borrow_mut())?;
+
+    set_return_data(
+        &RelayRoundResult {
+            relay_round: relay_round_pubkey,
+            round_number,
+            round_end,
+        }
+        .try_to_vec()?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExecuteRelayBoundsProposalAccounts<'a, 'info> {
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ExecuteRelayBoundsProposalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let ExecuteRelayBoundsProposalAccounts {
+        settings_account_info,
+        proposal_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Settings Account
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Proposal Account
+    let mut proposal_account_data =
+        RelayBoundsProposal::unpack(&proposal_account_info.data.borrow())?;
+
+    let round_number = proposal_account_data.round_number;
+    let event_timestamp = proposal_account_data.pda.event_timestamp;
+    let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+    let event_configuration = proposal_account_data.pda.event_configuration;
+    let event_data = hash(&proposal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = proposal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        proposal_account_info,
+    )?;
+
+    if !proposal_account_data.signers.is_empty() {
+        return Err(SolanaBridgeError::ProposalNotMigrated.into());
+    }
+
+    // Do we have enough signers.
+    let sig_count = proposal_account_data.confirm_count;
+
+    if proposal_account_data.meta.data.status == ProposalStatus::New
+        && sig_count >= proposal_account_data.required_votes
+    {
+        let event = &proposal_account_data.event.data;
+
+        settings_account_data.min_relays = event.min_relays;
+        settings_account_data.max_relays = event.max_relays;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        proposal_account_data.meta.data.status = ProposalStatus::Executed;
+    }
+
+    // Update Proposal Account
+    RelayBoundsProposal::pack(
+        proposal_account_data,
+        &mut proposal_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
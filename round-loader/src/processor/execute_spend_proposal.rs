@@ -0,0 +1,116 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExecuteSpendProposalAccounts<'a, 'info> {
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    #[account(writable, seeds = [br"treasury"])]
+    pub treasury_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub recipient_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ExecuteSpendProposalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let ExecuteSpendProposalAccounts {
+        proposal_account_info,
+        treasury_account_info,
+        recipient_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate Proposal Account
+    let mut proposal_account_data = SpendProposal::unpack(&proposal_account_info.data.borrow())?;
+
+    let round_number = proposal_account_data.round_number;
+    let event_timestamp = proposal_account_data.pda.event_timestamp;
+    let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+    let event_configuration = proposal_account_data.pda.event_configuration;
+    let event_data = hash(&proposal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = proposal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        proposal_account_info,
+    )?;
+
+    if !proposal_account_data.signers.is_empty() {
+        return Err(SolanaBridgeError::ProposalNotMigrated.into());
+    }
+
+    // Validate Recipient Account
+    let event = &proposal_account_data.event.data;
+    if event.recipient != *recipient_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Do we have enough signers.
+    let sig_count = proposal_account_data.confirm_count;
+
+    if proposal_account_data.meta.data.status == ProposalStatus::New
+        && sig_count >= proposal_account_data.required_votes
+    {
+        // Treasury must stay rent exempt
+        let rent = Rent::get()?;
+        let available = treasury_account_info
+            .lamports()
+            .saturating_sub(rent.minimum_balance(0));
+
+        if available < event.amount {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        let (_, treasury_nonce) = Pubkey::find_program_address(&[br"treasury"], program_id);
+        let treasury_account_signer_seeds: &[&[_]] = &[br"treasury", &[treasury_nonce]];
+
+        invoke_signed(
+            &system_instruction::transfer(
+                treasury_account_info.key,
+                recipient_account_info.key,
+                event.amount,
+            ),
+            accounts,
+            &[treasury_account_signer_seeds],
+        )?;
+
+        proposal_account_data.meta.data.status = ProposalStatus::Executed;
+    }
+
+    // Update Proposal Account
+    SpendProposal::pack(
+        proposal_account_data,
+        &mut proposal_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
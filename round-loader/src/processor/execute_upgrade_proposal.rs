@@ -0,0 +1,124 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::bpf_loader_upgradeable;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExecuteUpgradeProposalAccounts<'a, 'info> {
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    pub program_account_info: &'a AccountInfo<'info>,
+    pub buffer_account_info: &'a AccountInfo<'info>,
+    pub spill_account_info: &'a AccountInfo<'info>,
+    pub upgrade_authority_account_info: &'a AccountInfo<'info>,
+    #[account(program = bpf_loader_upgradeable::id())]
+    pub bpf_loader_upgradeable_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub clock_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ExecuteUpgradeProposalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let ExecuteUpgradeProposalAccounts {
+        proposal_account_info,
+        program_account_info,
+        buffer_account_info,
+        spill_account_info,
+        upgrade_authority_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate Proposal Account
+    let mut proposal_account_data = UpgradeProposal::unpack(&proposal_account_info.data.borrow())?;
+
+    let round_number = proposal_account_data.round_number;
+    let event_timestamp = proposal_account_data.pda.event_timestamp;
+    let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+    let event_configuration = proposal_account_data.pda.event_configuration;
+    let event_data = hash(&proposal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = proposal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        proposal_account_info,
+    )?;
+
+    // Accounts must match the approved upgrade
+    let event = &proposal_account_data.event.data;
+    if event.program != *program_account_info.key
+        || event.buffer != *buffer_account_info.key
+        || event.spill != *spill_account_info.key
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if get_buffer_hash(&buffer_account_info.data.borrow())? != event.buffer_hash {
+        return Err(SolanaBridgeError::BufferHashMismatch.into());
+    }
+
+    // Validate Upgrade Authority Account
+    let (upgrade_authority_pubkey, upgrade_authority_nonce) =
+        Pubkey::find_program_address(&[br"upgrade_authority"], program_id);
+    let upgrade_authority_signer_seeds: &[&[_]] =
+        &[br"upgrade_authority", &[upgrade_authority_nonce]];
+
+    if upgrade_authority_pubkey != *upgrade_authority_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !proposal_account_data.signers.is_empty() {
+        return Err(SolanaBridgeError::ProposalNotMigrated.into());
+    }
+
+    // Do we have enough signers.
+    let sig_count = proposal_account_data.confirm_count;
+
+    if proposal_account_data.meta.data.status == ProposalStatus::New
+        && sig_count >= proposal_account_data.required_votes
+    {
+        invoke_signed(
+            &bpf_loader_upgradeable::upgrade(
+                program_account_info.key,
+                buffer_account_info.key,
+                upgrade_authority_account_info.key,
+                spill_account_info.key,
+            ),
+            accounts,
+            &[upgrade_authority_signer_seeds],
+        )?;
+
+        proposal_account_data.meta.data.status = ProposalStatus::Executed;
+    }
+
+    // Update Proposal Account
+    UpgradeProposal::pack(
+        proposal_account_data,
+        &mut proposal_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::Hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExportRoundDigestAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub round_digest_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ExportRoundDigestAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let ExportRoundDigestAccounts {
+        funder_account_info,
+        relay_round_account_info,
+        round_digest_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = Rent::get()?;
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let round_number = relay_round_account_data.round_number;
+
+    validate_relay_round_account(
+        program_id,
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Create Round Digest Account
+    let (round_digest_pubkey, round_digest_nonce) =
+        Pubkey::find_program_address(&[br"round_digest", &round_number.to_le_bytes()], program_id);
+    let round_digest_account_signer_seeds: &[&[_]] = &[
+        br"round_digest",
+        &round_number.to_le_bytes(),
+        &[round_digest_nonce],
+    ];
+
+    if round_digest_pubkey != *round_digest_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if bridge_utils::helper::is_uncreated_account(round_digest_account_info) {
+        bridge_utils::helper::create_pda_account(
+            funder_account_info,
+            round_digest_account_info,
+            &rent,
+            RoundDigest::LEN,
+            program_id,
+            accounts,
+            round_digest_account_signer_seeds,
+        )?;
+    } else {
+        if round_digest_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+    }
+
+    // Write snapshot of the current round keys
+    let mut round_digest_account_data = RoundDigest {
+        is_initialized: true,
+        account_kind: AccountKind::RoundDigest(round_digest_nonce),
+        round_number,
+        round_end: relay_round_account_data.round_end,
+        relays_root: relay_round_account_data.get_relays_root(),
+        hash: Hash::default(),
+    };
+
+    let cell = round_digest_account_data
+        .to_cell()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    round_digest_account_data.hash = Hash::new_from_array(cell.repr_hash());
+
+    RoundDigest::pack(
+        round_digest_account_data,
+        &mut round_digest_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::types::RELAY_REPARATION;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::system_instruction;
+
+use super::validate_proposal_payload;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct FinalizeRelayBoundsProposalAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, FinalizeRelayBoundsProposalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let FinalizeRelayBoundsProposalAccounts {
+        funder_account_info,
+        proposal_account_info,
+        settings_account_info,
+        relay_round_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Proposal Account
+    let mut proposal_account_data =
+        RelayBoundsProposal::unpack_undiscriminated(&proposal_account_info.data.borrow())?;
+    let round_number = proposal_account_data.round_number;
+    let event_timestamp = proposal_account_data.pda.event_timestamp;
+    let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+    let event_configuration = proposal_account_data.pda.event_configuration;
+    let event_data = proposal_account_data.event.data.try_to_vec()?;
+    let (nonce, _) = proposal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    // Written payload must match the hash committed in the proposal address
+    validate_proposal_payload(&proposal_account_info.data.borrow(), &event_data)?;
+
+    let proposal_pubkey = bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &hash(&event_data),
+        nonce,
+        proposal_account_info,
+    )
+    .map_err(|_| SolanaBridgeError::InvalidPayloadHash)?;
+
+    if proposal_account_data.is_initialized {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let round_number = relay_round_account_data.round_number;
+
+    validate_relay_round_account(
+        program_id,
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Validate proposed bounds
+    let event = &proposal_account_data.event.data;
+    if !valid_relays_bounds(event.min_relays as usize, event.max_relays as usize) {
+        return Err(SolanaBridgeError::InvalidRelaysBounds.into());
+    }
+
+    let mut required_votes = (relay_round_account_data.relays_len() * 2 / 3 + 1) as u32;
+    if settings_account_data.min_required_votes > required_votes {
+        required_votes = settings_account_data.min_required_votes;
+    }
+
+    proposal_account_data.is_initialized = true;
+    proposal_account_data.round_number = round_number;
+    proposal_account_data.required_votes = required_votes;
+    proposal_account_data.signers = Vec::new();
+    proposal_account_data.confirm_count = 0;
+    proposal_account_data.reject_count = 0;
+
+    proposal_account_data.meta = RelayRoundProposalMetaWithLen::default();
+
+    RelayBoundsProposal::pack(
+        proposal_account_data,
+        &mut proposal_account_info.data.borrow_mut(),
+    )?;
+
+    // Send voting reparation for Relay to withdrawal account
+    invoke(
+        &system_instruction::transfer(
+            funder_account_info.key,
+            &proposal_pubkey,
+            RELAY_REPARATION * relay_round_account_data.relays_len() as u64,
+        ),
+        accounts,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::types::RELAY_REPARATION;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::system_instruction;
+
+use super::validate_proposal_payload;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct FinalizeUpgradeProposalAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    #[account(owner = bpf_loader_upgradeable::id())]
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(owner = bpf_loader_upgradeable::id())]
+    pub buffer_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, FinalizeUpgradeProposalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let FinalizeUpgradeProposalAccounts {
+        funder_account_info,
+        proposal_account_info,
+        settings_account_info,
+        relay_round_account_info,
+        programdata_account_info,
+        buffer_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Proposal Account
+    let mut proposal_account_data =
+        UpgradeProposal::unpack_undiscriminated(&proposal_account_info.data.borrow())?;
+    let round_number = proposal_account_data.round_number;
+    let event_timestamp = proposal_account_data.pda.event_timestamp;
+    let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+    let event_configuration = proposal_account_data.pda.event_configuration;
+    let event_data = proposal_account_data.event.data.try_to_vec()?;
+    let (nonce, _) = proposal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    // Written payload must match the hash committed in the proposal address
+    validate_proposal_payload(&proposal_account_info.data.borrow(), &event_data)?;
+
+    let proposal_pubkey = bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &hash(&event_data),
+        nonce,
+        proposal_account_info,
+    )
+    .map_err(|_| SolanaBridgeError::InvalidPayloadHash)?;
+
+    if proposal_account_data.is_initialized {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let round_number = relay_round_account_data.round_number;
+
+    validate_relay_round_account(
+        program_id,
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    let upgrade_authority = get_associated_upgrade_authority_address(program_id);
+
+    // Validate Program Data Account
+    let programdata_pubkey =
+        bridge_utils::helper::get_programdata_address(&proposal_account_data.event.data.program);
+
+    if programdata_pubkey != *programdata_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    match bincode::deserialize::<UpgradeableLoaderState>(&programdata_account_info.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?
+    {
+        UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address,
+            ..
+        } if upgrade_authority_address == Some(upgrade_authority) => {}
+        _ => return Err(ProgramError::IllegalOwner),
+    }
+
+    // Validate Buffer Account. Once its authority is ours the buffer can't be rewritten.
+    if proposal_account_data.event.data.buffer != *buffer_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    match bincode::deserialize::<UpgradeableLoaderState>(&buffer_account_info.data.borrow())
+        .map_err(|_| ProgramError::InvalidAccountData)?
+    {
+        UpgradeableLoaderState::Buffer { authority_address }
+            if authority_address == Some(upgrade_authority) => {}
+        _ => return Err(ProgramError::IllegalOwner),
+    }
+
+    // Relays vote for the buffer content, not only for its address
+    if get_buffer_hash(&buffer_account_info.data.borrow())?
+        != proposal_account_data.event.data.buffer_hash
+    {
+        return Err(SolanaBridgeError::BufferHashMismatch.into());
+    }
+
+    let mut required_votes = (relay_round_account_data.relays_len() * 2 / 3 + 1) as u32;
+    if settings_account_data.min_required_votes > required_votes {
+        required_votes = settings_account_data.min_required_votes;
+    }
+
+    proposal_account_data.is_initialized = true;
+    proposal_account_data.round_number = round_number;
+    proposal_account_data.required_votes = required_votes;
+    proposal_account_data.signers = Vec::new();
+    proposal_account_data.confirm_count = 0;
+    proposal_account_data.reject_count = 0;
+
+    proposal_account_data.meta = RelayRoundProposalMetaWithLen::default();
+
+    UpgradeProposal::pack(
+        proposal_account_data,
+        &mut proposal_account_info.data.borrow_mut(),
+    )?;
+
+    // Send voting reparation for Relay to withdrawal account
+    invoke(
+        &system_instruction::transfer(
+            funder_account_info.key,
+            &proposal_pubkey,
+            RELAY_REPARATION * relay_round_account_data.relays_len() as u64,
+        ),
+        accounts,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct FreezeRelayRoundAccounts<'a, 'info> {
+    #[account(signer)]
+    pub guardian_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, FreezeRelayRoundAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let FreezeRelayRoundAccounts {
+        guardian_account_info,
+        settings_account_info,
+        relay_round_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Guardian Account
+    if settings_account_data.guardian != *guardian_account_info.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Validate Relay Round Account
+    let mut relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_relay_round_account(
+        program_id,
+        relay_round_account_data.round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Only a round in its challenge window can be frozen
+    if relay_round_account_data.is_active(clock.unix_timestamp as u32) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    relay_round_account_data.frozen = true;
+
+    RelayRound::pack(
+        relay_round_account_data,
+        &mut relay_round_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
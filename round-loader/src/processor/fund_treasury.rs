@@ -0,0 +1,42 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::system_instruction;
+
+#[derive(Accounts)]
+pub struct FundTreasuryAccounts<'a, 'info> {
+    #[account(writable, signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable, seeds = [br"treasury"])]
+    pub treasury_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, FundTreasuryAccounts<'a, 'info>>,
+    amount: u64,
+) -> ProgramResult {
+    let FundTreasuryAccounts {
+        funder_account_info,
+        treasury_account_info,
+        ..
+    } = ctx.accounts;
+    let accounts = ctx.account_infos;
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Treasury is a system account, spends are signed by its seeds
+    invoke(
+        &system_instruction::transfer(funder_account_info.key, treasury_account_info.key, amount),
+        accounts,
+    )?;
+
+    Ok(())
+}
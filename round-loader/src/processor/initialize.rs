@@ -0,0 +1,122 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::Cluster;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct InitializeAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub initializer_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, InitializeAccounts<'a, 'info>>,
+    genesis_round_number: u32,
+    round_submitter: Pubkey,
+    min_required_votes: u32,
+    round_ttl: u32,
+) -> ProgramResult {
+    let InitializeAccounts {
+        funder_account_info,
+        initializer_account_info,
+        settings_account_info,
+        programdata_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Initializer Account
+    let programdata_nonce = bridge_utils::helper::validate_initializer(
+        program_id,
+        initializer_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Create Settings Account
+    let (settings_pubkey, settings_nonce) =
+        Pubkey::find_program_address(&[br"settings"], program_id);
+    let settings_account_signer_seeds: &[&[_]] = &[br"settings", &[settings_nonce]];
+
+    if settings_pubkey != *settings_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if settings_account_info.owner == program_id {
+        if !Cluster::CURRENT.reinit_allowed() {
+            return Err(SolanaBridgeError::AlreadyInitialized.into());
+        }
+
+        // Re-configure the settings, the current round is kept
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        settings_account_data.round_submitter = round_submitter;
+        settings_account_data.min_required_votes = min_required_votes;
+        settings_account_data.round_ttl = round_ttl;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        return Ok(());
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            settings_account_info.key,
+            1.max(rent.minimum_balance(Settings::LEN)),
+            Settings::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[settings_account_signer_seeds],
+    )?;
+
+    // Init Settings Account
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        current_round_number: genesis_round_number,
+        round_submitter,
+        min_required_votes,
+        round_ttl,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::{Proposal, ACCOUNT_DISCRIMINATOR_LEN};
+use bridge_utils::types::Vote;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use super::create_proposal_vote;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct MigrateProposalAccounts<'a, 'info> {
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, MigrateProposalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let MigrateProposalAccounts {
+        funder_account_info,
+        proposal_account_info,
+        relay_round_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    // Validate Proposal Account. Legacy proposals keep votes inline.
+    let proposal_account_data = Proposal::unpack_from_slice(&proposal_account_info.data.borrow())?;
+    if !proposal_account_data.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let round_number = proposal_account_data.round_number;
+    let event_timestamp = proposal_account_data.pda.event_timestamp;
+    let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+    let event_configuration = proposal_account_data.pda.event_configuration;
+    let event_data = hash(&proposal_account_data.event);
+    let (nonce, _) = proposal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        proposal_account_info,
+    )?;
+
+    // Nothing to migrate
+    if proposal_account_data.signers.is_empty() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Validate Relay Round Account the proposal was voted in
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_relay_round_account(
+        program_id,
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    if relay_round_account_data.relays.len() != proposal_account_data.signers.len() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Move cast votes into vote accounts
+    let mut confirm_count = 0;
+    let mut reject_count = 0;
+
+    for (relay_index, (relay, vote)) in relay_round_account_data
+        .relays
+        .iter()
+        .zip(proposal_account_data.signers.iter())
+        .enumerate()
+    {
+        match vote {
+            Vote::Confirm => confirm_count += 1,
+            Vote::Reject => reject_count += 1,
+            Vote::None => continue,
+        }
+
+        let vote_account_info = next_account_info(account_info_iter)?;
+
+        create_proposal_vote(
+            program_id,
+            accounts,
+            funder_account_info,
+            proposal_account_info,
+            relay,
+            relay_index as u32,
+            *vote,
+            vote_account_info,
+        )?;
+    }
+
+    let migrated_proposal_account_data = GenericProposal {
+        is_initialized: proposal_account_data.is_initialized,
+        account_kind: proposal_account_data.account_kind,
+        author: proposal_account_data.author,
+        round_number: proposal_account_data.round_number,
+        required_votes: proposal_account_data.required_votes,
+        pda: proposal_account_data.pda,
+        event: proposal_account_data.event,
+        meta: proposal_account_data.meta,
+        signers: Vec::new(),
+        confirm_count,
+        reject_count,
+    };
+
+    let mut proposal_data = proposal_account_info.data.borrow_mut();
+    proposal_data[ACCOUNT_DISCRIMINATOR_LEN..].fill(0);
+    migrated_proposal_account_data.pack_into_slice(&mut proposal_data);
+
+    Ok(())
+}
@@ -0,0 +1,541 @@
+pub mod add_event_configuration;
+pub mod close_old_round;
+pub mod close_proposal;
+pub mod create_admin_audit_log;
+pub mod create_proposal;
+pub mod create_relay_round;
+pub mod execute_delta_proposal;
+pub mod execute_proposal;
+pub mod execute_proposal_by_admin;
+pub mod execute_relay_bounds_proposal;
+pub mod execute_spend_proposal;
+pub mod execute_upgrade_proposal;
+pub mod export_round_digest;
+pub mod finalize_delta_proposal;
+pub mod finalize_proposal;
+pub mod finalize_relay_bounds_proposal;
+pub mod finalize_spend_proposal;
+pub mod finalize_upgrade_proposal;
+pub mod freeze_relay_round;
+pub mod fund_treasury;
+pub mod initialize;
+pub mod migrate_proposal;
+pub mod remove_event_configuration;
+pub mod report_relay;
+pub mod rotate_relay_key;
+pub mod update_settings;
+pub mod vote_for_proposal;
+pub mod write_proposal;
+
+use borsh::BorshDeserialize;
+use bridge_utils::context::Context;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::{AccountKind, RoundCounters};
+use bridge_utils::types::Vote;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::Hash;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+pub struct Processor;
+impl Processor {
+    pub fn process(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        let instruction = RoundLoaderInstruction::try_from_slice(instruction_data)?;
+
+        let admin_authority_index = instruction.admin_authority_index();
+
+        match instruction {
+            RoundLoaderInstruction::Initialize {
+                genesis_round_number,
+                round_submitter,
+                min_required_votes,
+                round_ttl,
+            } => {
+                msg!("Instruction: Initialize");
+                initialize::process(
+                    Context::new(program_id, accounts)?,
+                    genesis_round_number,
+                    round_submitter,
+                    min_required_votes,
+                    round_ttl,
+                )?;
+            }
+            RoundLoaderInstruction::UpdateSettings {
+                current_round_number,
+                round_submitter,
+                min_required_votes,
+                round_ttl,
+                event_configurations_restricted,
+                proposal_lead_time,
+                activation_delay,
+                guardian,
+                round_retention,
+            } => {
+                msg!("Instruction: Update Settings");
+                update_settings::process(
+                    Context::new(program_id, accounts)?,
+                    current_round_number,
+                    round_submitter,
+                    min_required_votes,
+                    round_ttl,
+                    event_configurations_restricted,
+                    proposal_lead_time,
+                    activation_delay,
+                    guardian,
+                    round_retention,
+                )?;
+            }
+            RoundLoaderInstruction::CreateRelayRound {
+                round_number,
+                relays,
+                round_end,
+            } => {
+                msg!("Instruction: Create Relay Round");
+                create_relay_round::process(
+                    Context::new(program_id, accounts)?,
+                    round_number,
+                    relays,
+                    Hash::default(),
+                    0,
+                    round_end,
+                )?;
+            }
+            RoundLoaderInstruction::CreateProposal {
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                event_data,
+            } => {
+                msg!("Instruction: Create");
+                create_proposal::process(
+                    Context::new(program_id, accounts)?,
+                    round_number,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    event_data,
+                )?;
+            }
+            RoundLoaderInstruction::WriteProposal { offset, bytes } => {
+                msg!("Instruction: Write");
+                write_proposal::process(Context::new(program_id, accounts)?, offset, bytes)?;
+            }
+            RoundLoaderInstruction::FinalizeProposal => {
+                msg!("Instruction: Finalize");
+                finalize_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::VoteForProposal { vote } => {
+                msg!("Instruction: Vote");
+                vote_for_proposal::process(Context::new(program_id, accounts)?, vote, None, None)?;
+            }
+            RoundLoaderInstruction::ExecuteProposal => {
+                msg!("Instruction: Execute");
+                execute_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::ExecuteProposalByAdmin => {
+                msg!("Instruction: Execute by admin");
+                execute_proposal_by_admin::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::CreateAdminAuditLog => {
+                msg!("Instruction: Create Admin Audit Log");
+                create_admin_audit_log::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::RotateRelayKey => {
+                msg!("Instruction: Rotate Relay Key");
+                rotate_relay_key::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::FinalizeDeltaProposal => {
+                msg!("Instruction: Finalize Delta");
+                finalize_delta_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::ExecuteDeltaProposal => {
+                msg!("Instruction: Execute Delta");
+                execute_delta_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::FinalizeUpgradeProposal => {
+                msg!("Instruction: Finalize Upgrade");
+                finalize_upgrade_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::ExecuteUpgradeProposal => {
+                msg!("Instruction: Execute Upgrade");
+                execute_upgrade_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::MigrateProposal => {
+                msg!("Instruction: Migrate Proposal");
+                migrate_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::ExportRoundDigest => {
+                msg!("Instruction: Export Round Digest");
+                export_round_digest::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::CreateMerkleRelayRound {
+                round_number,
+                relays_root,
+                relays_count,
+                round_end,
+            } => {
+                msg!("Instruction: Create Merkle Relay Round");
+                create_relay_round::process(
+                    Context::new(program_id, accounts)?,
+                    round_number,
+                    Vec::new(),
+                    relays_root,
+                    relays_count,
+                    round_end,
+                )?;
+            }
+            RoundLoaderInstruction::VoteForProposalWithProof { vote, proof } => {
+                msg!("Instruction: Vote With Proof");
+                vote_for_proposal::process(
+                    Context::new(program_id, accounts)?,
+                    vote,
+                    None,
+                    Some(proof),
+                )?;
+            }
+            RoundLoaderInstruction::AddEventConfiguration {
+                event_configuration,
+            } => {
+                msg!("Instruction: Add Event Configuration");
+                add_event_configuration::process(
+                    Context::new(program_id, accounts)?,
+                    event_configuration,
+                )?;
+            }
+            RoundLoaderInstruction::RemoveEventConfiguration {
+                event_configuration,
+            } => {
+                msg!("Instruction: Remove Event Configuration");
+                remove_event_configuration::process(
+                    Context::new(program_id, accounts)?,
+                    event_configuration,
+                )?;
+            }
+            RoundLoaderInstruction::CloseProposal => {
+                msg!("Instruction: Close Proposal");
+                close_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::FreezeRelayRound => {
+                msg!("Instruction: Freeze Relay Round");
+                freeze_relay_round::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::FinalizeRelayBoundsProposal => {
+                msg!("Instruction: Finalize Relay Bounds");
+                finalize_relay_bounds_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::ExecuteRelayBoundsProposal => {
+                msg!("Instruction: Execute Relay Bounds");
+                execute_relay_bounds_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::VoteForProposalWithIndex { vote, index } => {
+                msg!("Instruction: Vote With Index");
+                vote_for_proposal::process(
+                    Context::new(program_id, accounts)?,
+                    vote,
+                    Some(index),
+                    None,
+                )?;
+            }
+            RoundLoaderInstruction::CloseOldRound => {
+                msg!("Instruction: Close Old Round");
+                close_old_round::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::ReportRelay {
+                accused,
+                category,
+                evidence_hash,
+                reporter_proof,
+                accused_proof,
+            } => {
+                msg!("Instruction: Report Relay");
+                report_relay::process(
+                    Context::new(program_id, accounts)?,
+                    accused,
+                    category,
+                    evidence_hash,
+                    reporter_proof,
+                    accused_proof,
+                )?;
+            }
+            RoundLoaderInstruction::FinalizeSpendProposal => {
+                msg!("Instruction: Finalize Spend");
+                finalize_spend_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::ExecuteSpendProposal => {
+                msg!("Instruction: Execute Spend");
+                execute_spend_proposal::process(Context::new(program_id, accounts)?)?;
+            }
+            RoundLoaderInstruction::FundTreasury { amount } => {
+                msg!("Instruction: Fund Treasury");
+                fund_treasury::process(Context::new(program_id, accounts)?, amount)?;
+            }
+        };
+
+        if let Some(index) = admin_authority_index {
+            let authority_account_info = accounts
+                .get(index)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            bridge_utils::helper::append_admin_audit_log(
+                program_id,
+                accounts,
+                authority_account_info.key,
+                instruction_data,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_proposal_payload(data: &[u8], event_data: &[u8]) -> Result<(), ProgramError> {
+    let offset = LOAD_DATA_BEGIN_OFFSET;
+
+    let len = u32::try_from_slice(&data[offset..offset + 4])? as usize;
+    if len != event_data.len() || data[offset + 4..offset + 4 + len] != *event_data {
+        return Err(SolanaBridgeError::InvalidPayloadHash.into());
+    }
+
+    Ok(())
+}
+
+fn write_proposal_data(data: &mut [u8], offset: usize, bytes: &[u8]) -> Result<(), ProgramError> {
+    let offset = LOAD_DATA_BEGIN_OFFSET + offset;
+
+    let len = bytes.len();
+    if LOAD_DATA_END_OFFSET < offset + len {
+        msg!(
+            "Write overflow: {} < {}",
+            LOAD_DATA_END_OFFSET,
+            offset + len
+        );
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+
+    data[offset..offset + len].copy_from_slice(bytes);
+
+    Ok(())
+}
+
+fn create_proposal_vote<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    funder_account_info: &AccountInfo<'a>,
+    proposal_account_info: &AccountInfo<'a>,
+    voter: &Pubkey,
+    relay_index: u32,
+    vote: Vote,
+    vote_account_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    // Vote is keyed on the relay position, which stays the same across key rotations
+    let (vote_pubkey, vote_nonce) = Pubkey::find_program_address(
+        &[
+            br"vote",
+            &proposal_account_info.key.to_bytes(),
+            &relay_index.to_le_bytes(),
+        ],
+        program_id,
+    );
+    let vote_account_signer_seeds: &[&[_]] = &[
+        br"vote",
+        &proposal_account_info.key.to_bytes(),
+        &relay_index.to_le_bytes(),
+        &[vote_nonce],
+    ];
+
+    if vote_pubkey != *vote_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Anyone can send lamports to the address, so it must not block the vote
+    bridge_utils::helper::create_pda_account(
+        funder_account_info,
+        vote_account_info,
+        &Rent::get()?,
+        ProposalVote::LEN,
+        program_id,
+        accounts,
+        vote_account_signer_seeds,
+    )?;
+
+    let vote_account_data = ProposalVote {
+        is_initialized: true,
+        account_kind: AccountKind::ProposalVote(vote_nonce),
+        proposal: *proposal_account_info.key,
+        voter: *voter,
+        vote,
+    };
+
+    ProposalVote::pack(vote_account_data, &mut vote_account_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+/// Reimburse the executor from the Executor Reward Vault, if it is passed and funded
+fn reimburse_executor<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    account_info_iter: &mut std::slice::Iter<AccountInfo<'a>>,
+    funder_account_info: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let vault_account_info = match next_account_info(account_info_iter) {
+        Ok(account_info) => account_info,
+        Err(_) => return Ok(()),
+    };
+
+    let (vault_pubkey, vault_nonce) =
+        Pubkey::find_program_address(&[br"executor_reward_vault"], program_id);
+    let vault_account_signer_seeds: &[&[_]] = &[br"executor_reward_vault", &[vault_nonce]];
+
+    if vault_pubkey != *vault_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Vault must stay rent exempt
+    let rent = Rent::get()?;
+    let available = vault_account_info
+        .lamports()
+        .saturating_sub(rent.minimum_balance(0));
+
+    if available < amount {
+        msg!("Executor reward vault is depleted");
+        return Ok(());
+    }
+
+    invoke_signed(
+        &system_instruction::transfer(vault_account_info.key, funder_account_info.key, amount),
+        accounts,
+        &[vault_account_signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Check the round created by a proposal keeps its activation time
+fn validate_round_activation(settings: &Settings, relay_round: &RelayRound) -> ProgramResult {
+    // Round without room for the activation time would be active right away
+    if settings.activation_delay > 0 && !relay_round.keeps_tail() {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+
+    Ok(())
+}
+
+/// Check the number of relays of a round created by a proposal is within the settings bounds
+fn validate_relays_count(settings: &Settings, relays_count: usize) -> ProgramResult {
+    let (min_relays, max_relays) = settings.relays_bounds();
+
+    if relays_count < min_relays || relays_count > max_relays {
+        return Err(SolanaBridgeError::InvalidRelaysCount.into());
+    }
+
+    Ok(())
+}
+
+/// Snapshot the statistics of the replaced round into its round stats account.
+///
+/// The stats account is looked up among the instruction accounts and nothing is recorded if it
+/// is missing. Counters are read from the Token Proxy relay round stats, which must be passed
+/// along; rounds without recorded votes are snapshotted with zero counters.
+fn snapshot_round_stats<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    funder_account_info: &AccountInfo<'a>,
+    round_number: u32,
+    timestamp: i64,
+) -> ProgramResult {
+    let (round_stats_pubkey, round_stats_nonce) =
+        Pubkey::find_program_address(&[br"round_stats", &round_number.to_le_bytes()], program_id);
+
+    let round_stats_account_info = match accounts.iter().find(|a| *a.key == round_stats_pubkey) {
+        Some(account_info) => account_info,
+        None => return Ok(()),
+    };
+
+    let relay_round_stats_pubkey =
+        get_associated_relay_round_stats_address(&TOKEN_PROXY_ID, round_number);
+
+    let relay_round_stats_account_info = accounts
+        .iter()
+        .find(|a| *a.key == relay_round_stats_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let (withdrawals, volume, total_votes, votes) = if relay_round_stats_account_info.owner
+        == &TOKEN_PROXY_ID
+        && relay_round_stats_account_info.lamports() != 0
+    {
+        let counters =
+            RoundCounters::unpack_from_slice(&relay_round_stats_account_info.data.borrow())?;
+
+        if !counters.is_initialized || counters.round_number != round_number {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        (
+            counters.withdrawals,
+            counters.volume,
+            counters.total,
+            counters.votes,
+        )
+    } else {
+        (0, 0, 0, Vec::new())
+    };
+
+    let rent = Rent::get()?;
+
+    if bridge_utils::helper::is_uncreated_account(round_stats_account_info) {
+        let round_stats_account_signer_seeds: &[&[_]] = &[
+            br"round_stats",
+            &round_number.to_le_bytes(),
+            &[round_stats_nonce],
+        ];
+
+        bridge_utils::helper::create_pda_account(
+            funder_account_info,
+            round_stats_account_info,
+            &rent,
+            RoundStats::LEN,
+            program_id,
+            accounts,
+            round_stats_account_signer_seeds,
+        )?;
+    } else {
+        if round_stats_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+    }
+
+    let round_stats_account_data = RoundStats {
+        is_initialized: true,
+        account_kind: AccountKind::RoundStats(round_stats_nonce),
+        round_number,
+        withdrawals,
+        volume,
+        total_votes,
+        votes,
+        snapshot_at: timestamp,
+    };
+
+    RoundStats::pack(
+        round_stats_account_data,
+        &mut round_stats_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RemoveEventConfigurationAccounts<'a, 'info> {
+    #[account(writable, signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub event_configuration_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, RemoveEventConfigurationAccounts<'a, 'info>>,
+    event_configuration: Pubkey,
+) -> ProgramResult {
+    let RemoveEventConfigurationAccounts {
+        author_account_info,
+        event_configuration_account_info,
+        settings_account_info,
+        programdata_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        author_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Event Configuration Account
+    if event_configuration_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let event_configuration_account_data =
+        EventConfiguration::unpack(&event_configuration_account_info.data.borrow())?;
+
+    let event_configuration_nonce = event_configuration_account_data
+        .account_kind
+        .into_event_configuration()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let event_configuration_pubkey = Pubkey::create_program_address(
+        &[
+            br"event_configuration",
+            &event_configuration.to_bytes(),
+            &[event_configuration_nonce],
+        ],
+        program_id,
+    )?;
+
+    if event_configuration_pubkey != *event_configuration_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Close Event Configuration Account
+    let author_starting_lamports = author_account_info.lamports();
+    **author_account_info.lamports.borrow_mut() = author_starting_lamports
+        .checked_add(event_configuration_account_info.lamports())
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    **event_configuration_account_info.lamports.borrow_mut() = 0;
+
+    bridge_utils::helper::delete_account(event_configuration_account_info);
+
+    Ok(())
+}
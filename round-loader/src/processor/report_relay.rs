@@ -0,0 +1,167 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::merkle::MerkleProof;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::Hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ReportRelayAccounts<'a, 'info> {
+    #[account(writable, signer)]
+    pub reporter_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    #[account(writable, seeds = [br"report_log"])]
+    pub report_log_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub report_log_page_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ReportRelayAccounts<'a, 'info>>,
+    accused: Pubkey,
+    category: ReportCategory,
+    evidence_hash: Hash,
+    reporter_proof: Option<MerkleProof>,
+    accused_proof: Option<MerkleProof>,
+) -> ProgramResult {
+    let ReportRelayAccounts {
+        reporter_account_info,
+        relay_round_account_info,
+        report_log_account_info,
+        report_log_page_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+    let rent = Rent::get()?;
+
+    if *reporter_account_info.key == accused {
+        return Err(SolanaBridgeError::SelfReport.into());
+    }
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let round_number = relay_round_account_data.round_number;
+
+    validate_relay_round_account(
+        program_id,
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Frozen round never became active, so its relays had nothing to misbehave in
+    if relay_round_account_data.frozen {
+        return Err(SolanaBridgeError::RelayRoundNotActive.into());
+    }
+
+    // Both the reporter and the accused are relays of the round
+    relay_round_account_data.find_relay(reporter_account_info.key, reporter_proof.as_ref())?;
+    relay_round_account_data.find_relay(&accused, accused_proof.as_ref())?;
+
+    // The log is created by the first reporter
+    let (_, report_log_nonce) = Pubkey::find_program_address(&[br"report_log"], program_id);
+
+    let mut report_log_account_data =
+        if bridge_utils::helper::is_uncreated_account(report_log_account_info) {
+            let report_log_account_signer_seeds: &[&[_]] = &[br"report_log", &[report_log_nonce]];
+
+            bridge_utils::helper::create_pda_account(
+                reporter_account_info,
+                report_log_account_info,
+                &rent,
+                ReportLog::LEN,
+                program_id,
+                accounts,
+                report_log_account_signer_seeds,
+            )?;
+
+            ReportLog {
+                is_initialized: true,
+                account_kind: AccountKind::ReportLog(report_log_nonce),
+                total: 0,
+            }
+        } else {
+            ReportLog::unpack(&report_log_account_info.data.borrow())?
+        };
+
+    // Validate Report Log Page Account. The page is created by the reporter when the previous
+    // one is full.
+    let page = report_log_account_data.current_page();
+    let (report_log_page_pubkey, report_log_page_nonce) =
+        Pubkey::find_program_address(&[br"report_log", &page.to_le_bytes()], program_id);
+
+    if report_log_page_pubkey != *report_log_page_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut report_log_page_account_data =
+        if bridge_utils::helper::is_uncreated_account(report_log_page_account_info) {
+            let report_log_page_account_signer_seeds: &[&[_]] = &[
+                br"report_log",
+                &page.to_le_bytes(),
+                &[report_log_page_nonce],
+            ];
+
+            bridge_utils::helper::create_pda_account(
+                reporter_account_info,
+                report_log_page_account_info,
+                &rent,
+                ReportLogPage::LEN,
+                program_id,
+                accounts,
+                report_log_page_account_signer_seeds,
+            )?;
+
+            ReportLogPage {
+                is_initialized: true,
+                account_kind: AccountKind::ReportLogPage(report_log_page_nonce),
+                page,
+                reports: Vec::new(),
+            }
+        } else {
+            ReportLogPage::unpack(&report_log_page_account_info.data.borrow())?
+        };
+
+    report_log_page_account_data.reports.push(RelayReport {
+        reporter: *reporter_account_info.key,
+        accused,
+        round_number,
+        category,
+        evidence_hash,
+        reported_at: clock.unix_timestamp,
+    });
+
+    report_log_account_data.total += 1;
+
+    ReportLogPage::pack(
+        report_log_page_account_data,
+        &mut report_log_page_account_info.data.borrow_mut(),
+    )?;
+
+    ReportLog::pack(
+        report_log_account_data,
+        &mut report_log_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
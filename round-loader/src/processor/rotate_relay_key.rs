@@ -0,0 +1,82 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RotateRelayKeyAccounts<'a, 'info> {
+    pub old_relay_account_info: &'a AccountInfo<'info>,
+    pub new_relay_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, RotateRelayKeyAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let RotateRelayKeyAccounts {
+        old_relay_account_info,
+        new_relay_account_info,
+        relay_round_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    let clock = Clock::get()?;
+
+    if !old_relay_account_info.is_signer || !new_relay_account_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate Relay Round Account
+    let mut relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_relay_round_account(
+        program_id,
+        relay_round_account_data.round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
+        return Err(SolanaBridgeError::RelayRoundExpired.into());
+    }
+
+    // New key must not be a relay of the round yet
+    if relay_round_account_data
+        .relays
+        .contains(new_relay_account_info.key)
+    {
+        return Err(SolanaBridgeError::InvalidRelay.into());
+    }
+
+    // Keep relay position. Votes are keyed on it, so the new key can't vote again on proposals
+    // the old one voted for.
+    let index = relay_round_account_data
+        .relays
+        .iter()
+        .position(|pubkey| pubkey == old_relay_account_info.key)
+        .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+    relay_round_account_data.relays[index] = *new_relay_account_info.key;
+
+    RelayRound::pack(
+        relay_round_account_data,
+        &mut relay_round_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
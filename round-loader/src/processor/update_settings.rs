@@ -0,0 +1,113 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UpdateSettingsAccounts<'a, 'info> {
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, UpdateSettingsAccounts<'a, 'info>>,
+    current_round_number: Option<u32>,
+    round_submitter: Option<Pubkey>,
+    min_required_votes: Option<u32>,
+    round_ttl: Option<u32>,
+    event_configurations_restricted: Option<bool>,
+    proposal_lead_time: Option<u32>,
+    activation_delay: Option<u32>,
+    guardian: Option<Pubkey>,
+    round_retention: Option<u16>,
+) -> ProgramResult {
+    let UpdateSettingsAccounts {
+        author_account_info,
+        settings_account_info,
+        programdata_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Settings Account
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_info.owner != program_id {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        author_account_info.key,
+        programdata_account_info,
+    )?;
+
+    if let Some(current_round_number) = current_round_number {
+        settings_account_data.current_round_number = current_round_number;
+    }
+
+    if let Some(round_submitter) = round_submitter {
+        settings_account_data.round_submitter = round_submitter;
+    }
+
+    if let Some(min_required_votes) = min_required_votes {
+        settings_account_data.min_required_votes = min_required_votes;
+    }
+
+    if let Some(round_ttl) = round_ttl {
+        settings_account_data.round_ttl = round_ttl;
+    }
+
+    if let Some(event_configurations_restricted) = event_configurations_restricted {
+        settings_account_data.event_configurations_restricted = event_configurations_restricted;
+    }
+
+    if let Some(proposal_lead_time) = proposal_lead_time {
+        settings_account_data.proposal_lead_time = proposal_lead_time;
+    }
+
+    if let Some(activation_delay) = activation_delay {
+        settings_account_data.activation_delay = activation_delay;
+    }
+
+    if let Some(guardian) = guardian {
+        settings_account_data.guardian = guardian;
+    }
+
+    if let Some(round_retention) = round_retention {
+        settings_account_data.round_retention = round_retention;
+    }
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::merkle::MerkleProof;
+use bridge_utils::types::{Vote, RELAY_REPARATION};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use super::create_proposal_vote;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct VoteForProposalAccounts<'a, 'info> {
+    #[account(writable, signer)]
+    pub voter_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    pub vote_account_info: &'a AccountInfo<'info>,
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, VoteForProposalAccounts<'a, 'info>>,
+    vote: Vote,
+    index: Option<u32>,
+    proof: Option<MerkleProof>,
+) -> ProgramResult {
+    let VoteForProposalAccounts {
+        voter_account_info,
+        proposal_account_info,
+        relay_round_account_info,
+        vote_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate vote
+    if vote == Vote::None {
+        return Err(SolanaBridgeError::InvalidVote.into());
+    }
+
+    // Validate Proposal Account. All proposals share the layout.
+    let mut proposal_account_data =
+        GenericProposal::unpack_from_slice(&proposal_account_info.data.borrow())?;
+    if !proposal_account_data.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let round_number = proposal_account_data.round_number;
+    let event_timestamp = proposal_account_data.pda.event_timestamp;
+    let event_transaction_lt = proposal_account_data.pda.event_transaction_lt;
+    let event_configuration = proposal_account_data.pda.event_configuration;
+    let event_data = hash(&proposal_account_data.event);
+    let (nonce, _) = proposal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        proposal_account_info,
+    )?;
+
+    if !proposal_account_data.signers.is_empty() {
+        return Err(SolanaBridgeError::ProposalNotMigrated.into());
+    }
+
+    // Votes of an executed proposal change nothing but drain its reparation
+    if proposal_account_data.status()? == ProposalStatus::Executed {
+        return Err(SolanaBridgeError::ProposalExecuted.into());
+    }
+
+    // Validate Relay Round Account the proposal is voted in
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_relay_round_account(
+        program_id,
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Frozen round and round in its challenge window can't vote
+    if !relay_round_account_data.is_active(Clock::get()?.unix_timestamp as u32) {
+        return Err(SolanaBridgeError::RelayRoundNotActive.into());
+    }
+
+    // Vote for proposal request. The claimed index saves the scan of large rounds.
+    let relay_index = match index {
+        Some(index) => relay_round_account_data.check_relay_index(voter_account_info.key, index)?,
+        None => relay_round_account_data.find_relay(voter_account_info.key, proof.as_ref())?,
+    };
+
+    if vote_account_info.owner == program_id {
+        return Err(SolanaBridgeError::RelayAlreadyVoted.into());
+    }
+
+    // Vote for proposal
+    create_proposal_vote(
+        program_id,
+        accounts,
+        voter_account_info,
+        proposal_account_info,
+        voter_account_info.key,
+        relay_index as u32,
+        vote,
+        vote_account_info,
+    )?;
+
+    let votes = match vote {
+        Vote::Confirm => &mut proposal_account_data.confirm_count,
+        Vote::Reject => &mut proposal_account_data.reject_count,
+        Vote::None => return Err(SolanaBridgeError::InvalidVote.into()),
+    };
+    *votes = votes
+        .checked_add(1)
+        .ok_or(SolanaBridgeError::VotesOverflow)?;
+
+    proposal_account_data.pack_into_slice(&mut proposal_account_info.data.borrow_mut());
+
+    // Get back voting reparation to Relay
+    let proposal_starting_lamports = proposal_account_info.lamports();
+    **proposal_account_info.lamports.borrow_mut() = proposal_starting_lamports
+        .checked_sub(RELAY_REPARATION)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    let voter_starting_lamports = voter_account_info.lamports();
+    **voter_account_info.lamports.borrow_mut() = voter_starting_lamports
+        .checked_add(RELAY_REPARATION)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    // Reparation must not be paid out of the rent of the proposal account
+    bridge_utils::helper::validate_rent_exempt(&Rent::get()?, proposal_account_info)?;
+
+    Ok(())
+}
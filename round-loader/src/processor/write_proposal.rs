@@ -0,0 +1,39 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::state::Proposal;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+
+use super::write_proposal_data;
+
+#[derive(Accounts)]
+pub struct WriteProposalAccounts<'a, 'info> {
+    #[account(writable)]
+    pub proposal_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WriteProposalAccounts<'a, 'info>>,
+    offset: u32,
+    bytes: Vec<u8>,
+) -> ProgramResult {
+    let WriteProposalAccounts {
+        proposal_account_info,
+    } = ctx.accounts;
+
+    // Don't allow to write to finalized proposal of any kind
+    if let Ok(proposal) = Proposal::unpack_from_slice(&proposal_account_info.data.borrow()) {
+        if proposal.is_initialized {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+    }
+
+    write_proposal_data(
+        &mut proposal_account_info.data.borrow_mut(),
+        offset as usize,
+        &bytes,
+    )?;
+
+    Ok(())
+}
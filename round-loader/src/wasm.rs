@@ -23,6 +23,20 @@ pub fn get_relay_round_address_request(round_number: u32) -> Result<JsValue, JsV
     return serde_wasm_bindgen::to_value(&relay_round_pubkey).handle_error();
 }
 
+#[wasm_bindgen(js_name = "getRoundStatsAddress")]
+pub fn get_round_stats_address_request(round_number: u32) -> Result<JsValue, JsValue> {
+    let round_stats_pubkey = get_round_stats_address(round_number);
+
+    return serde_wasm_bindgen::to_value(&round_stats_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getReportLogPageAddress")]
+pub fn get_report_log_page_address_request(page: u64) -> Result<JsValue, JsValue> {
+    let report_log_page_pubkey = get_report_log_page_address(page);
+
+    return serde_wasm_bindgen::to_value(&report_log_page_pubkey).handle_error();
+}
+
 #[wasm_bindgen(js_name = "initialize")]
 pub fn initialize_ix(
     funder_pubkey: String,
@@ -67,12 +81,18 @@ pub fn initialize_ix(
 }
 
 #[wasm_bindgen(js_name = "updateSettings")]
+#[allow(clippy::too_many_arguments)]
 pub fn update_settings_ix(
     author_pubkey: String,
     current_round_number: Option<u32>,
     round_submitter: Option<String>,
     min_required_votes: Option<u32>,
     round_ttl: Option<u32>,
+    event_configurations_restricted: Option<bool>,
+    proposal_lead_time: Option<u32>,
+    activation_delay: Option<u32>,
+    guardian: Option<String>,
+    round_retention: Option<u16>,
 ) -> Result<JsValue, JsValue> {
     let program_id = &id();
 
@@ -86,11 +106,21 @@ pub fn update_settings_ix(
         .transpose()
         .handle_error()?;
 
+    let guardian = guardian
+        .map(|value| Pubkey::from_str(value.as_str()))
+        .transpose()
+        .handle_error()?;
+
     let data = RoundLoaderInstruction::UpdateSettings {
         current_round_number,
         round_submitter,
         min_required_votes,
         round_ttl,
+        event_configurations_restricted,
+        proposal_lead_time,
+        activation_delay,
+        guardian,
+        round_retention,
     }
     .try_to_vec()
     .handle_error()?;
@@ -225,6 +255,161 @@ pub fn execute_by_admin_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
+#[wasm_bindgen(js_name = "addEventConfiguration")]
+pub fn add_event_configuration_ix(
+    author_pubkey: String,
+    event_configuration: String,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+
+    let event_configuration_pubkey =
+        get_associated_event_configuration_address(program_id, &event_configuration);
+    let setting_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let program_data_pubkey = bridge_utils::helper::get_programdata_address(program_id);
+
+    let data = RoundLoaderInstruction::AddEventConfiguration {
+        event_configuration,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(event_configuration_pubkey, false),
+            AccountMeta::new_readonly(setting_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "removeEventConfiguration")]
+pub fn remove_event_configuration_ix(
+    author_pubkey: String,
+    event_configuration: String,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+
+    let event_configuration_pubkey =
+        get_associated_event_configuration_address(program_id, &event_configuration);
+    let setting_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let program_data_pubkey = bridge_utils::helper::get_programdata_address(program_id);
+
+    let data = RoundLoaderInstruction::RemoveEventConfiguration {
+        event_configuration,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(event_configuration_pubkey, false),
+            AccountMeta::new_readonly(setting_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "closeProposal")]
+pub fn close_proposal_ix(
+    proposal_pubkey: String,
+    author_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let proposal_pubkey = Pubkey::from_str(proposal_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+
+    let data = RoundLoaderInstruction::CloseProposal
+        .try_to_vec()
+        .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(proposal_pubkey, false),
+            AccountMeta::new(author_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "freezeRelayRound")]
+pub fn freeze_relay_round_ix(
+    guardian_pubkey: String,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let guardian_pubkey = Pubkey::from_str(guardian_pubkey.as_str()).handle_error()?;
+
+    let setting_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(program_id, round_number);
+
+    let data = RoundLoaderInstruction::FreezeRelayRound
+        .try_to_vec()
+        .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(guardian_pubkey, true),
+            AccountMeta::new_readonly(setting_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "closeOldRound")]
+pub fn close_old_round_ix(round_number: u32) -> Result<JsValue, JsValue> {
+    let program_id = &id();
+
+    let setting_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(program_id, round_number);
+    let relay_round_stats_pubkey =
+        get_associated_relay_round_stats_address(&TOKEN_PROXY_ID, round_number);
+    let treasury_pubkey = get_associated_treasury_address(program_id);
+
+    let data = RoundLoaderInstruction::CloseOldRound
+        .try_to_vec()
+        .handle_error()?;
+
+    let ix = Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(setting_pubkey, false),
+            AccountMeta::new(relay_round_pubkey, false),
+            AccountMeta::new_readonly(relay_round_stats_pubkey, false),
+            AccountMeta::new(treasury_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
 #[wasm_bindgen(js_name = "unpackSettings")]
 pub fn unpack_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
     let settings = Settings::unpack(&data).handle_error()?;
@@ -236,6 +421,13 @@ pub fn unpack_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
         round_submitter: settings.round_submitter,
         min_required_votes: settings.min_required_votes,
         round_ttl: settings.round_ttl,
+        event_configurations_restricted: settings.event_configurations_restricted,
+        proposal_lead_time: settings.proposal_lead_time,
+        activation_delay: settings.activation_delay,
+        guardian: settings.guardian,
+        min_relays: settings.min_relays,
+        max_relays: settings.max_relays,
+        round_retention: settings.round_retention,
     };
 
     return serde_wasm_bindgen::to_value(&s).handle_error();
@@ -251,6 +443,9 @@ pub fn unpack_relay_round(data: Vec<u8>) -> Result<JsValue, JsValue> {
         round_number: relay_round.round_number,
         round_end: relay_round.round_end,
         relays: relay_round.relays,
+        suggested_proposal_deadline: relay_round.suggested_proposal_deadline,
+        activated_at: relay_round.activated_at,
+        frozen: relay_round.frozen,
     };
 
     return serde_wasm_bindgen::to_value(&rr).handle_error();
@@ -270,11 +465,46 @@ pub fn unpack_relay_round_proposal(data: Vec<u8>) -> Result<JsValue, JsValue> {
         event: relay_round_proposal.event,
         meta: relay_round_proposal.meta,
         signers: relay_round_proposal.signers,
+        confirm_count: relay_round_proposal.confirm_count,
+        reject_count: relay_round_proposal.reject_count,
+        executed_at: relay_round_proposal.executed_at,
     };
 
     return serde_wasm_bindgen::to_value(&rrp).handle_error();
 }
 
+#[wasm_bindgen(js_name = "unpackRoundStats")]
+pub fn unpack_round_stats(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let round_stats = RoundStats::unpack(&data).handle_error()?;
+
+    let rs = WasmRoundStats {
+        is_initialized: round_stats.is_initialized,
+        account_kind: round_stats.account_kind,
+        round_number: round_stats.round_number,
+        withdrawals: round_stats.withdrawals,
+        volume: round_stats.volume,
+        total_votes: round_stats.total_votes,
+        votes: round_stats.votes,
+        snapshot_at: round_stats.snapshot_at,
+    };
+
+    return serde_wasm_bindgen::to_value(&rs).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackReportLogPage")]
+pub fn unpack_report_log_page(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let report_log_page = ReportLogPage::unpack(&data).handle_error()?;
+
+    let rlp = WasmReportLogPage {
+        is_initialized: report_log_page.is_initialized,
+        account_kind: report_log_page.account_kind,
+        page: report_log_page.page,
+        reports: report_log_page.reports,
+    };
+
+    return serde_wasm_bindgen::to_value(&rlp).handle_error();
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct WasmSettings {
     pub is_initialized: bool,
@@ -283,6 +513,13 @@ pub struct WasmSettings {
     pub round_submitter: Pubkey,
     pub min_required_votes: u32,
     pub round_ttl: u32,
+    pub event_configurations_restricted: bool,
+    pub proposal_lead_time: u32,
+    pub activation_delay: u32,
+    pub guardian: Pubkey,
+    pub min_relays: u32,
+    pub max_relays: u32,
+    pub round_retention: u16,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -292,6 +529,9 @@ pub struct WasmRelayRound {
     pub round_number: u32,
     pub round_end: u32,
     pub relays: Vec<Pubkey>,
+    pub suggested_proposal_deadline: u32,
+    pub activated_at: u32,
+    pub frozen: bool,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -305,6 +545,29 @@ pub struct WasmRelayRoundProposal {
     pub event: RelayRoundProposalEventWithLen,
     pub meta: RelayRoundProposalMetaWithLen,
     pub signers: Vec<Vote>,
+    pub confirm_count: u32,
+    pub reject_count: u32,
+    pub executed_at: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmRoundStats {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    pub withdrawals: u32,
+    pub volume: u128,
+    pub total_votes: u64,
+    pub votes: Vec<u32>,
+    pub snapshot_at: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmReportLogPage {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub page: u64,
+    pub reports: Vec<RelayReport>,
 }
 
 impl<T, E> HandleError for Result<T, E>
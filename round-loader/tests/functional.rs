@@ -1,18 +1,21 @@
 #![cfg(feature = "test-bpf")]
 
-use borsh::BorshSerialize;
-use bridge_utils::types::Vote;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::types::{Cluster, Vote, RELAY_REPARATION};
 use std::str::FromStr;
 
-use bridge_utils::state::AccountKind;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::merkle::{get_merkle_proof, get_merkle_root};
+use bridge_utils::state::{AccountKind, Proposal, RoundCounters, PDA};
 use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
-use solana_program::hash::hash;
+use solana_program::hash::{hash, hashv, Hash};
+use solana_program::instruction::InstructionError;
 use solana_program::rent::Rent;
 use solana_program::{bpf_loader_upgradeable, program_pack::Pack, pubkey::Pubkey};
 use solana_program_test::{processor, tokio, ProgramTest};
 use solana_sdk::account::{Account, ReadableAccount};
 use solana_sdk::signature::{Keypair, Signer};
-use solana_sdk::transaction::Transaction;
+use solana_sdk::transaction::{Transaction, TransactionError};
 
 use round_loader::*;
 
@@ -150,10 +153,40 @@ async fn test_init_relay_loader() {
         AccountKind::RelayRound(relay_round_nonce)
     );
 
+    // Round before the current one can't be created
+    let mut transaction = Transaction::new_with_payer(
+        &[create_relay_round_ix(
+            &funder.pubkey(),
+            &creator.pubkey(),
+            round_number - 1,
+            round_end as u32,
+            relays.clone(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &creator], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::InvalidRelayRound as u32)
+        )
+    );
+
     // Update Settings
     let new_min_required_votes = 12;
     let new_current_round_number = 3;
     let new_round_submitter = Pubkey::new_unique();
+    let new_proposal_lead_time = 3600;
+    let new_activation_delay = 86400;
+    let new_guardian = Pubkey::new_unique();
+    let new_round_retention = 10;
 
     let mut transaction = Transaction::new_with_payer(
         &[update_settings_ix(
@@ -162,6 +195,11 @@ async fn test_init_relay_loader() {
             Some(new_round_submitter),
             Some(new_min_required_votes),
             None,
+            None,
+            Some(new_proposal_lead_time),
+            Some(new_activation_delay),
+            Some(new_guardian),
+            Some(new_round_retention),
         )],
         Some(&initializer.pubkey()),
     );
@@ -183,6 +221,161 @@ async fn test_init_relay_loader() {
     assert_eq!(settings_data.current_round_number, new_current_round_number);
     assert_eq!(settings_data.round_submitter, new_round_submitter);
     assert_eq!(settings_data.min_required_votes, new_min_required_votes);
+    assert_eq!(settings_data.proposal_lead_time, new_proposal_lead_time);
+    assert_eq!(settings_data.activation_delay, new_activation_delay);
+    assert_eq!(settings_data.guardian, new_guardian);
+    assert_eq!(settings_data.round_retention, new_round_retention);
+}
+
+#[tokio::test]
+async fn test_double_init() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let initializer = Keypair::new();
+    let attacker = Keypair::new();
+
+    for authority in [&initializer, &attacker] {
+        program_test.add_account(
+            authority.pubkey(),
+            Account {
+                lamports: 10_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    let (programdata_address, _) = Pubkey::find_program_address(
+        &[round_loader::id().as_ref()],
+        &bpf_loader_upgradeable::id(),
+    );
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(initializer.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let genesis_round_number = 5;
+    let round_submitter = Pubkey::new_unique();
+    let min_required_votes = 1;
+    let round_ttl = 1209600;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[initialize_ix(
+            &funder.pubkey(),
+            &initializer.pubkey(),
+            genesis_round_number,
+            round_submitter,
+            min_required_votes,
+            round_ttl,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Only the upgrade authority can initialize the program
+    let new_round_submitter = Pubkey::new_unique();
+    let new_min_required_votes = 3;
+    let new_round_ttl = 86400;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[initialize_ix(
+            &funder.pubkey(),
+            &attacker.pubkey(),
+            0,
+            attacker.pubkey(),
+            new_min_required_votes,
+            new_round_ttl,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &attacker], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+    );
+
+    // Initialized settings are re-configured on localnet only
+    let mut transaction = Transaction::new_with_payer(
+        &[initialize_ix(
+            &funder.pubkey(),
+            &initializer.pubkey(),
+            0,
+            new_round_submitter,
+            new_min_required_votes,
+            new_round_ttl,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    let result = banks_client.process_transaction(transaction).await;
+
+    let settings_info = banks_client
+        .get_account(get_settings_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.current_round_number, genesis_round_number);
+
+    if Cluster::CURRENT.reinit_allowed() {
+        result.expect("process_transaction");
+
+        assert_eq!(settings_data.round_submitter, new_round_submitter);
+        assert_eq!(settings_data.min_required_votes, new_min_required_votes);
+        assert_eq!(settings_data.round_ttl, new_round_ttl);
+    } else {
+        assert_eq!(
+            result.expect_err("process_transaction").unwrap(),
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(SolanaBridgeError::AlreadyInitialized as u32)
+            )
+        );
+
+        assert_eq!(settings_data.round_submitter, round_submitter);
+        assert_eq!(settings_data.min_required_votes, min_required_votes);
+        assert_eq!(settings_data.round_ttl, round_ttl);
+    }
 }
 
 #[tokio::test]
@@ -240,6 +433,13 @@ async fn test_create_proposal() {
         round_submitter: Pubkey::new_unique(),
         min_required_votes: 1,
         round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -269,6 +469,11 @@ async fn test_create_proposal() {
         round_number,
         round_end: chrono::Utc::now().timestamp() as u32,
         relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
     };
 
     let mut relay_round_packed = vec![0; RelayRound::LEN];
@@ -285,9 +490,6 @@ async fn test_create_proposal() {
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
     // Create Proposal
     let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
@@ -312,6 +514,63 @@ async fn test_create_proposal() {
         &serialized_write_data,
     );
 
+    // Add Proposal Account funded by an attacker ahead of its creation
+    let attacked_event_configuration = Pubkey::new_unique();
+
+    let attacked_proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &attacked_event_configuration,
+        &serialized_write_data,
+    );
+
+    program_test.add_account(
+        attacked_proposal_pubkey,
+        Account {
+            lamports: 1,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_proposal_ix(
+            &funder.pubkey(),
+            &proposal_creator.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            attacked_event_configuration,
+            &serialized_write_data,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Pre-funded account is topped up rather than blocking the proposal
+    let attacked_proposal_info = banks_client
+        .get_account(attacked_proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    assert_eq!(attacked_proposal_info.owner, round_loader::id());
+    assert_eq!(attacked_proposal_info.data.len(), RelayRoundProposal::LEN);
+    assert!(
+        attacked_proposal_info.lamports >= Rent::default().minimum_balance(RelayRoundProposal::LEN)
+    );
+
     let mut transaction = Transaction::new_with_payer(
         &[create_proposal_ix(
             &funder.pubkey(),
@@ -388,7 +647,8 @@ async fn test_create_proposal() {
     assert_eq!(proposal_data.pda.event_transaction_lt, event_transaction_lt);
     assert_eq!(proposal_data.pda.event_configuration, event_configuration);
 
-    assert_eq!(proposal_data.signers, vec![Vote::None; relays.len()]);
+    assert_eq!(proposal_data.signers, vec![]);
+    assert_eq!(proposal_data.confirm_count, 0);
 
     assert_eq!(proposal_data.event.data.relays, new_relays);
     assert_eq!(proposal_data.event.data.round_end, new_round_end);
@@ -412,7 +672,7 @@ async fn test_create_proposal() {
     );
 
     // Vote for Proposal
-    for relay in &relays {
+    for (index, relay) in relays.iter().enumerate() {
         let blockhash = banks_client
             .get_latest_blockhash()
             .await
@@ -424,6 +684,7 @@ async fn test_create_proposal() {
                 &proposal_pubkey,
                 round_number,
                 Vote::Confirm,
+                index as u32,
             )],
             Some(&relay.pubkey()),
         );
@@ -462,6 +723,19 @@ async fn test_create_proposal() {
 
     let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
     assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+    assert_eq!(proposal_data.confirm_count, relays.len() as u32);
+
+    // Check Vote Account
+    let vote_info = banks_client
+        .get_account(get_proposal_vote_address(&proposal_pubkey, 0))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vote_data = ProposalVote::unpack(vote_info.data()).expect("vote unpack");
+    assert_eq!(vote_data.proposal, proposal_pubkey);
+    assert_eq!(vote_data.voter, relays[0].pubkey());
+    assert_eq!(vote_data.vote, Vote::Confirm);
 
     // Check created Relay Round
     let relay_round_address = get_relay_round_address(new_round_number);
@@ -490,7 +764,7 @@ async fn test_create_proposal() {
 }
 
 #[tokio::test]
-async fn test_create_proposal_and_execute_by_admin() {
+async fn test_vote_for_proposal_with_index() {
     let mut program_test = ProgramTest::new(
         "round_loader",
         round_loader::id(),
@@ -498,6 +772,8 @@ async fn test_create_proposal_and_execute_by_admin() {
     );
 
     // Setup environment
+
+    // Add Creator Account
     let proposal_creator = Keypair::new();
     program_test.add_account(
         proposal_creator.pubkey(),
@@ -514,7 +790,7 @@ async fn test_create_proposal_and_execute_by_admin() {
 
     // Add Relays Accounts
     let mut relays = vec![];
-    for _ in 0..100 {
+    for _ in 0..3 {
         relays.push(Keypair::new());
     }
 
@@ -522,7 +798,7 @@ async fn test_create_proposal_and_execute_by_admin() {
         program_test.add_account(
             relay.pubkey(),
             Account {
-                lamports: 10_000_000_000,
+                lamports: 100_000_000,
                 data: vec![],
                 owner: solana_program::system_program::id(),
                 executable: false,
@@ -531,19 +807,6 @@ async fn test_create_proposal_and_execute_by_admin() {
         );
     }
 
-    // Setup environment
-    let round_submitter = Keypair::new();
-    program_test.add_account(
-        round_submitter.pubkey(),
-        Account {
-            lamports: 10_000_000_000,
-            data: vec![],
-            owner: solana_program::system_program::id(),
-            executable: false,
-            rent_epoch: 0,
-        },
-    );
-
     // Add Settings Account
     let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
 
@@ -552,9 +815,16 @@ async fn test_create_proposal_and_execute_by_admin() {
         is_initialized: true,
         account_kind: AccountKind::Settings(settings_nonce, 0),
         current_round_number: round_number,
-        round_submitter: round_submitter.pubkey(),
+        round_submitter: Pubkey::new_unique(),
         min_required_votes: 1,
         round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
     };
 
     let mut settings_packed = vec![0; Settings::LEN];
@@ -584,6 +854,11 @@ async fn test_create_proposal_and_execute_by_admin() {
         round_number,
         round_end: chrono::Utc::now().timestamp() as u32,
         relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
     };
 
     let mut relay_round_packed = vec![0; RelayRound::LEN];
@@ -600,9 +875,6 @@ async fn test_create_proposal_and_execute_by_admin() {
         },
     );
 
-    // Start Program Test
-    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
-
     // Create Proposal
     let event_timestamp = 1650988297;
     let event_transaction_lt = 1650988334;
@@ -627,6 +899,9 @@ async fn test_create_proposal_and_execute_by_admin() {
         &serialized_write_data,
     );
 
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
     let mut transaction = Transaction::new_with_payer(
         &[create_proposal_ix(
             &funder.pubkey(),
@@ -669,112 +944,5282 @@ async fn test_create_proposal_and_execute_by_admin() {
     // Finalize Proposal
     let mut transaction = Transaction::new_with_payer(
         &[finalize_proposal_ix(
-            &proposal_creator.pubkey(),
+            &funder.pubkey(),
             &proposal_pubkey,
             round_number,
         )],
         Some(&funder.pubkey()),
     );
-    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+    transaction.sign(&[&funder], recent_blockhash);
 
     banks_client
         .process_transaction(transaction)
         .await
         .expect("process_transaction");
 
-    // Check created Proposal
-    let proposal_info = banks_client
-        .get_account(proposal_pubkey)
-        .await
-        .expect("get_account")
-        .expect("account");
-
-    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
-
-    assert_eq!(proposal_data.is_initialized, true);
-    assert_eq!(proposal_data.round_number, round_number);
-    assert_eq!(
-        proposal_data.required_votes,
-        (relays.len() * 2 / 3 + 1) as u32
+    // Relay claiming the index of another relay is rejected
+    let mut transaction = Transaction::new_with_payer(
+        &[vote_for_proposal_with_index_ix(
+            &relays[0].pubkey(),
+            &proposal_pubkey,
+            round_number,
+            Vote::Confirm,
+            1,
+        )],
+        Some(&relays[0].pubkey()),
     );
+    transaction.sign(&[&relays[0]], recent_blockhash);
 
-    assert_eq!(proposal_data.pda.event_timestamp, event_timestamp);
-    assert_eq!(proposal_data.pda.event_transaction_lt, event_transaction_lt);
-
-    assert_eq!(proposal_data.signers, vec![Vote::None; relays.len()]);
-
-    assert_eq!(proposal_data.event.data.relays, new_relays);
-    assert_eq!(proposal_data.event.data.round_end, new_round_end);
-
-    assert_eq!(proposal_data.meta.data.status, ProposalStatus::New);
-
-    let event_data = hash(&serialized_write_data);
-    let (_, proposal_nonce) = Pubkey::find_program_address(
-        &[
-            br"proposal",
-            &round_number.to_le_bytes(),
-            &event_timestamp.to_le_bytes(),
-            &event_transaction_lt.to_le_bytes(),
-            &event_configuration.to_bytes(),
-            &event_data.to_bytes(),
-        ],
-        &round_loader::id(),
-    );
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
 
     assert_eq!(
-        proposal_data.account_kind,
-        AccountKind::Proposal(proposal_nonce, None)
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::InvalidRelay as u32)
+        )
     );
 
-    // Execute Proposal by admin
+    // Index out of the round is rejected
     let mut transaction = Transaction::new_with_payer(
-        &[execute_proposal_by_admin_ix(
-            &funder.pubkey(),
-            &round_submitter.pubkey(),
+        &[vote_for_proposal_with_index_ix(
+            &relays[0].pubkey(),
             &proposal_pubkey,
-            new_round_number,
+            round_number,
+            Vote::Confirm,
+            relays.len() as u32,
         )],
-        Some(&funder.pubkey()),
+        Some(&relays[0].pubkey()),
     );
-    transaction.sign(&[&funder, &round_submitter], recent_blockhash);
+    transaction.sign(&[&relays[0]], recent_blockhash);
 
-    banks_client
+    let err = banks_client
         .process_transaction(transaction)
         .await
-        .expect("process_transaction");
+        .expect_err("process_transaction")
+        .unwrap();
 
-    // Check created Proposal
-    let proposal_info = banks_client
-        .get_account(proposal_pubkey)
-        .await
-        .expect("get_account")
-        .expect("account");
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::InvalidRelay as u32)
+        )
+    );
 
-    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
-    assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+    // Vote for Proposal by own index
+    for (index, relay) in relays.iter().enumerate() {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_with_index_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                index as u32,
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Check voted Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.confirm_count, relays.len() as u32);
+
+    let vote_info = banks_client
+        .get_account(get_proposal_vote_address(&proposal_pubkey, 0))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vote_data = ProposalVote::unpack(vote_info.data()).expect("vote unpack");
+    assert_eq!(vote_data.voter, relays[0].pubkey());
+    assert_eq!(vote_data.vote, Vote::Confirm);
+}
+
+#[tokio::test]
+async fn test_create_proposal_and_execute_by_admin() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..100 {
+        relays.push(Keypair::new());
+    }
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 10_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Setup environment
+    let round_submitter = Keypair::new();
+    program_test.add_account(
+        round_submitter.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: round_submitter.pubkey(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let new_relays = vec![Pubkey::new_unique(); 100];
+    let new_round_number = round_number + 1;
+    let new_round_end = 1759950990;
+    let write_data =
+        RelayRoundProposalEventWithLen::new(new_round_number, new_relays.clone(), new_round_end);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_proposal_ix(
+            &funder.pubkey(),
+            &proposal_creator.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            &serialized_write_data,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Write Proposal
+    let chunk_size = 800;
+
+    for (chunk, i) in write_data.try_to_vec().unwrap().chunks(chunk_size).zip(0..) {
+        let mut transaction = Transaction::new_with_payer(
+            &[write_proposal_ix(
+                &proposal_pubkey,
+                (i * chunk_size) as u32,
+                chunk.to_vec(),
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Finalize Proposal
+    let mut transaction = Transaction::new_with_payer(
+        &[finalize_proposal_ix(
+            &proposal_creator.pubkey(),
+            &proposal_pubkey,
+            round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check created Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+
+    assert_eq!(proposal_data.is_initialized, true);
+    assert_eq!(proposal_data.round_number, round_number);
+    assert_eq!(
+        proposal_data.required_votes,
+        (relays.len() * 2 / 3 + 1) as u32
+    );
+
+    assert_eq!(proposal_data.pda.event_timestamp, event_timestamp);
+    assert_eq!(proposal_data.pda.event_transaction_lt, event_transaction_lt);
+
+    assert_eq!(proposal_data.signers, vec![]);
+    assert_eq!(proposal_data.confirm_count, 0);
+
+    assert_eq!(proposal_data.event.data.relays, new_relays);
+    assert_eq!(proposal_data.event.data.round_end, new_round_end);
+
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::New);
+
+    let event_data = hash(&serialized_write_data);
+    let (_, proposal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data.to_bytes(),
+        ],
+        &round_loader::id(),
+    );
+
+    assert_eq!(
+        proposal_data.account_kind,
+        AccountKind::Proposal(proposal_nonce, None)
+    );
+
+    // Execute Proposal by admin
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_proposal_by_admin_ix(
+            &funder.pubkey(),
+            &round_submitter.pubkey(),
+            &proposal_pubkey,
+            new_round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &round_submitter], recent_blockhash);
+
+    let result = banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .expect("process_transaction");
+
+    assert!(result.result.is_ok());
+
+    // Check created Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+
+    // Check created Relay Round
+    let relay_round_address = get_relay_round_address(new_round_number);
+
+    let return_data = result
+        .metadata
+        .expect("metadata")
+        .return_data
+        .expect("return data");
+
+    let relay_round_result =
+        RelayRoundResult::try_from_slice(&return_data.data).expect("relay round result unpack");
+
+    assert_eq!(relay_round_result.relay_round, relay_round_address);
+    assert_eq!(relay_round_result.round_number, new_round_number);
+
+    let relay_round_account = banks_client
+        .get_account(relay_round_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    let relay_round_data =
+        RelayRound::unpack(relay_round_account.data()).expect("relay round unpack");
+
+    assert_eq!(relay_round_data.is_initialized, true);
+    assert_eq!(relay_round_data.round_number, new_round_number);
+    assert_eq!(relay_round_data.relays, new_relays);
+
+    // Check Settings
+    let settings_account = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    let settings_data = Settings::unpack(settings_account.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.current_round_number, new_round_number);
+}
+
+#[tokio::test]
+async fn test_rotate_relay_key() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let round_number: u32 = 7;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..3 {
+        relays.push(Keypair::new());
+    }
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let now = 1_700_000_000;
+    let round_end = now as u32 + 3600;
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: round_end,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let mut context = program_test.start_with_context().await;
+    let funder = context.payer.insecure_clone();
+
+    let recent_blockhash = test_fixtures::clock::set_timestamp(&mut context, now).await;
+
+    // Rotate Relay Key
+    let new_relay = Keypair::new();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[rotate_relay_key_ix(
+            &relays[1].pubkey(),
+            &new_relay.pubkey(),
+            round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &relays[1], &new_relay], recent_blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let relay_round_info = context
+        .banks_client
+        .get_account(relay_round_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+
+    assert_eq!(
+        relay_round_data.relays,
+        vec![relays[0].pubkey(), new_relay.pubkey(), relays[2].pubkey()]
+    );
+
+    // Old key is no longer a relay of the round
+    let another_relay = Keypair::new();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[rotate_relay_key_ix(
+            &relays[1].pubkey(),
+            &another_relay.pubkey(),
+            round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &relays[1], &another_relay], recent_blockhash);
+
+    assert!(context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .is_err());
+
+    // Keys can't be rotated once the round is over
+    let recent_blockhash = test_fixtures::clock::warp_seconds(&mut context, 3600).await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[rotate_relay_key_ix(
+            &new_relay.pubkey(),
+            &another_relay.pubkey(),
+            round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &new_relay, &another_relay], recent_blockhash);
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::RelayRoundExpired as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_rotate_relay_key_after_vote() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..3 {
+        relays.push(Keypair::new());
+    }
+
+    let new_relay = Keypair::new();
+
+    for relay in relays.iter().chain([&new_relay]) {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let round_end = chrono::Utc::now().timestamp() as u32 + 1209600;
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: round_end,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let new_relays = vec![Pubkey::new_unique(); 100];
+    let new_round_number = round_number + 1;
+    let new_round_end = 1759950990;
+    let write_data =
+        RelayRoundProposalEventWithLen::new(new_round_number, new_relays.clone(), new_round_end);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_proposal_ix(
+            &funder.pubkey(),
+            &proposal_creator.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            &serialized_write_data,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Write Proposal
+    let chunk_size = 800;
+
+    for (chunk, i) in write_data.try_to_vec().unwrap().chunks(chunk_size).zip(0..) {
+        let mut transaction = Transaction::new_with_payer(
+            &[write_proposal_ix(
+                &proposal_pubkey,
+                (i * chunk_size) as u32,
+                chunk.to_vec(),
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Finalize Proposal
+    let mut transaction = Transaction::new_with_payer(
+        &[finalize_proposal_ix(
+            &funder.pubkey(),
+            &proposal_pubkey,
+            round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Relay votes with its first key
+    let mut transaction = Transaction::new_with_payer(
+        &[vote_for_proposal_ix(
+            &relays[1].pubkey(),
+            &proposal_pubkey,
+            round_number,
+            Vote::Confirm,
+            1,
+        )],
+        Some(&relays[1].pubkey()),
+    );
+    transaction.sign(&[&relays[1]], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Rotate Relay Key
+    let mut transaction = Transaction::new_with_payer(
+        &[rotate_relay_key_ix(
+            &relays[1].pubkey(),
+            &new_relay.pubkey(),
+            round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &relays[1], &new_relay], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Rotated key can't vote again for the same relay slot
+    for ix in [
+        vote_for_proposal_ix(
+            &new_relay.pubkey(),
+            &proposal_pubkey,
+            round_number,
+            Vote::Confirm,
+            1,
+        ),
+        vote_for_proposal_with_index_ix(
+            &new_relay.pubkey(),
+            &proposal_pubkey,
+            round_number,
+            Vote::Reject,
+            1,
+        ),
+    ] {
+        let mut transaction = Transaction::new_with_payer(&[ix], Some(&new_relay.pubkey()));
+        transaction.sign(&[&new_relay], recent_blockhash);
+
+        let err = banks_client
+            .process_transaction(transaction)
+            .await
+            .expect_err("process_transaction")
+            .unwrap();
+
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(SolanaBridgeError::RelayAlreadyVoted as u32)
+            )
+        );
+    }
+
+    // Other relays still vote
+    let mut transaction = Transaction::new_with_payer(
+        &[vote_for_proposal_ix(
+            &relays[0].pubkey(),
+            &proposal_pubkey,
+            round_number,
+            Vote::Confirm,
+            0,
+        )],
+        Some(&relays[0].pubkey()),
+    );
+    transaction.sign(&[&relays[0]], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.confirm_count, 2);
+    assert_eq!(proposal_data.reject_count, 0);
+
+    let vote_info = banks_client
+        .get_account(get_proposal_vote_address(&proposal_pubkey, 1))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let vote_data = ProposalVote::unpack(vote_info.data()).expect("vote unpack");
+    assert_eq!(vote_data.voter, relays[1].pubkey());
+}
+
+#[tokio::test]
+async fn test_create_delta_proposal() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..5 {
+        relays.push(Keypair::new());
+    }
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let added = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+    let removed = vec![relays[0].pubkey()];
+    let new_round_number = round_number + 1;
+    let new_round_end = 1759950990;
+    let write_data = RelayRoundDeltaProposalEventWithLen::new(
+        new_round_number,
+        added.clone(),
+        removed,
+        new_round_end,
+    );
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    // Delta fits into a single transaction
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+            finalize_delta_proposal_ix(&funder.pubkey(), &proposal_pubkey, round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check created Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data =
+        RelayRoundDeltaProposal::unpack(proposal_info.data()).expect("proposal unpack");
+
+    assert_eq!(proposal_data.is_initialized, true);
+    assert_eq!(proposal_data.round_number, round_number);
+    assert_eq!(
+        proposal_data.required_votes,
+        (relays.len() * 2 / 3 + 1) as u32
+    );
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::New);
+    assert_eq!(proposal_data.signers, vec![]);
+    assert_eq!(proposal_data.confirm_count, 0);
+    assert_eq!(proposal_data.event.data.added, added);
+
+    // Vote for Proposal
+    for (index, relay) in relays.iter().enumerate() {
+        let blockhash = banks_client
+            .get_latest_blockhash()
+            .await
+            .expect("get_latest_blockhash");
+
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                index as u32,
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Execute Proposal
+    let blockhash = banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get_latest_blockhash");
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_delta_proposal_ix(
+            &funder.pubkey(),
+            &proposal_pubkey,
+            round_number,
+            new_round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check executed Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data =
+        RelayRoundDeltaProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+
+    // Check created Relay Round
+    let relay_round_address = get_relay_round_address(new_round_number);
+
+    let relay_round_account = banks_client
+        .get_account(relay_round_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    let relay_round_data =
+        RelayRound::unpack(relay_round_account.data()).expect("relay round unpack");
+
+    let mut expected_relays: Vec<Pubkey> = relays[1..].iter().map(|pair| pair.pubkey()).collect();
+    expected_relays.extend(added);
+
+    assert_eq!(relay_round_data.round_number, new_round_number);
+    assert_eq!(relay_round_data.round_end, new_round_end + 1209600);
+    assert_eq!(relay_round_data.relays, expected_relays);
+
+    // Check Settings
+    let settings_account = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    let settings_data = Settings::unpack(settings_account.data()).expect("settings unpack");
+
+    assert_eq!(settings_data.current_round_number, new_round_number);
+}
+
+#[tokio::test]
+async fn test_finalize_proposal_with_corrupted_payload() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: vec![Pubkey::new_unique(); 3],
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let new_relays = vec![Pubkey::new_unique(); 3];
+    let new_round_number = round_number + 1;
+    let new_round_end = 1759950990;
+    let write_data =
+        RelayRoundProposalEventWithLen::new(new_round_number, new_relays, new_round_end);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    // Write a payload with one relay overwritten
+    let mut corrupted_write_data = write_data.try_to_vec().unwrap();
+    let relay_offset = 4 + 4 + 4;
+    corrupted_write_data[relay_offset..relay_offset + 32]
+        .copy_from_slice(&Pubkey::new_unique().to_bytes());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, corrupted_write_data),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Finalize must be rejected
+    let mut transaction = Transaction::new_with_payer(
+        &[finalize_proposal_ix(
+            &funder.pubkey(),
+            &proposal_pubkey,
+            round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("finalize corrupted proposal")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::InvalidPayloadHash as u32)
+        )
+    );
+
+    // Rewrite payload and finalize
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+            finalize_proposal_ix(&funder.pubkey(), &proposal_pubkey, round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.is_initialized, true);
+}
+
+#[tokio::test]
+async fn test_upgrade_proposal() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..5 {
+        relays.push(Keypair::new());
+    }
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let mut context = program_test.start_with_context().await;
+    let funder = context.payer.insecure_clone();
+
+    // Use SPL Memo as both the deployed and the new program binary
+    let memo_program = Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr").unwrap();
+    let elf = context
+        .banks_client
+        .get_account(memo_program)
+        .await
+        .expect("get_account")
+        .expect("account")
+        .data;
+
+    let upgrade_authority = get_upgrade_authority_address();
+
+    // Add upgradeable Program Account
+    let program_address = Pubkey::new_unique();
+    let programdata_address = bridge_utils::helper::get_programdata_address(&program_address);
+
+    let program_data =
+        bincode::serialize::<UpgradeableLoaderState>(&UpgradeableLoaderState::Program {
+            programdata_address,
+        })
+        .unwrap();
+
+    context.set_account(
+        &program_address,
+        &Account {
+            lamports: Rent::default().minimum_balance(program_data.len()),
+            data: program_data,
+            owner: bpf_loader_upgradeable::id(),
+            executable: true,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    let mut programdata_data =
+        bincode::serialize::<UpgradeableLoaderState>(&UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(upgrade_authority),
+        })
+        .unwrap();
+    programdata_data.extend_from_slice(&elf);
+
+    context.set_account(
+        &programdata_address,
+        &Account {
+            lamports: Rent::default().minimum_balance(programdata_data.len()),
+            data: programdata_data,
+            owner: bpf_loader_upgradeable::id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    // Add Buffer Account still controlled by its uploader
+    let buffer_address = Pubkey::new_unique();
+    let buffer_uploader = Pubkey::new_unique();
+
+    let buffer_account = |authority_address| {
+        let mut buffer_data =
+            bincode::serialize::<UpgradeableLoaderState>(&UpgradeableLoaderState::Buffer {
+                authority_address: Some(authority_address),
+            })
+            .unwrap();
+        buffer_data.extend_from_slice(&elf);
+
+        Account {
+            lamports: Rent::default().minimum_balance(buffer_data.len()),
+            data: buffer_data,
+            owner: bpf_loader_upgradeable::id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    };
+
+    context.set_account(&buffer_address, &buffer_account(buffer_uploader).into());
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let spill_address = Pubkey::new_unique();
+    let write_data = UpgradeProposalEventWithLen::new(
+        program_address,
+        buffer_address,
+        spill_address,
+        hash(&elf),
+    );
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], context.last_blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let finalize_ix = finalize_upgrade_proposal_ix(
+        &funder.pubkey(),
+        &proposal_pubkey,
+        round_number,
+        &program_address,
+        &buffer_address,
+    );
+
+    // Buffer that can still be rewritten is rejected
+    let mut transaction =
+        Transaction::new_with_payer(&[finalize_ix.clone()], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], context.last_blockhash);
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+    );
+
+    // Buffer with content other than the approved one is rejected
+    let mut tampered_buffer = buffer_account(upgrade_authority);
+    *tampered_buffer.data.last_mut().unwrap() ^= 0xff;
+    context.set_account(&buffer_address, &tampered_buffer.clone().into());
+
+    let blockhash = context
+        .get_new_latest_blockhash()
+        .await
+        .expect("get_new_latest_blockhash");
+
+    let mut transaction =
+        Transaction::new_with_payer(&[finalize_ix.clone()], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], blockhash);
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::BufferHashMismatch as u32)
+        )
+    );
+
+    // Hand the buffer over to the upgrade authority
+    context.set_account(&buffer_address, &buffer_account(upgrade_authority).into());
+
+    let blockhash = context
+        .get_new_latest_blockhash()
+        .await
+        .expect("get_new_latest_blockhash");
+
+    let mut transaction = Transaction::new_with_payer(&[finalize_ix], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check created Proposal
+    let proposal_info = context
+        .banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = UpgradeProposal::unpack(proposal_info.data()).expect("proposal unpack");
+
+    assert_eq!(proposal_data.is_initialized, true);
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::New);
+    assert_eq!(proposal_data.signers, vec![]);
+    assert_eq!(proposal_data.confirm_count, 0);
+    assert_eq!(proposal_data.event.data.program, program_address);
+
+    // Vote for Proposal
+    for (index, relay) in relays.iter().enumerate() {
+        let blockhash = context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .expect("get_latest_blockhash");
+
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                index as u32,
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], blockhash);
+
+        context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    let buffer_lamports = context
+        .banks_client
+        .get_balance(buffer_address)
+        .await
+        .expect("get_balance");
+
+    // Spill account is committed in the proposal
+    let blockhash = context
+        .banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get_latest_blockhash");
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_upgrade_proposal_ix(
+            &proposal_pubkey,
+            &program_address,
+            &buffer_address,
+            &funder.pubkey(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], blockhash);
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+
+    // Buffer content is checked again before the upgrade
+    context.set_account(&buffer_address, &tampered_buffer.into());
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_upgrade_proposal_ix(
+            &proposal_pubkey,
+            &program_address,
+            &buffer_address,
+            &spill_address,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], blockhash);
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::BufferHashMismatch as u32)
+        )
+    );
+
+    context.set_account(&buffer_address, &buffer_account(upgrade_authority).into());
+
+    let blockhash = context
+        .get_new_latest_blockhash()
+        .await
+        .expect("get_new_latest_blockhash");
+
+    // Execute Proposal
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_upgrade_proposal_ix(
+            &proposal_pubkey,
+            &program_address,
+            &buffer_address,
+            &spill_address,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check executed Proposal
+    let proposal_info = context
+        .banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = UpgradeProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+
+    // Buffer is consumed and its rent is sent to the spill account
+    let buffer_info = context
+        .banks_client
+        .get_account(buffer_address)
+        .await
+        .expect("get_account");
+    assert!(buffer_info.is_none());
+
+    let spill_lamports = context
+        .banks_client
+        .get_balance(spill_address)
+        .await
+        .expect("get_balance");
+    assert_eq!(spill_lamports, buffer_lamports);
+
+    // Check upgraded Program Data
+    let programdata_info = context
+        .banks_client
+        .get_account(programdata_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    match bincode::deserialize::<UpgradeableLoaderState>(&programdata_info.data).unwrap() {
+        UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address,
+            ..
+        } => assert_eq!(upgrade_authority_address, Some(upgrade_authority)),
+        _ => panic!("programdata expected"),
+    }
+    assert_eq!(
+        &programdata_info.data[metadata_len..metadata_len + elf.len()],
+        &elf[..]
+    );
+}
+
+#[tokio::test]
+async fn test_relay_bounds_proposal() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..5 {
+        relays.push(Keypair::new());
+    }
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let new_min_relays = 4;
+    let new_max_relays = 10;
+    let write_data = RelayBoundsProposalEventWithLen::new(new_min_relays, new_max_relays);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+            finalize_relay_bounds_proposal_ix(&funder.pubkey(), &proposal_pubkey, round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Vote for Proposal
+    for (index, relay) in relays.iter().enumerate() {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                index as u32,
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Execute Proposal
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_relay_bounds_proposal_ix(&proposal_pubkey)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check executed Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayBoundsProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+
+    // Check updated Settings
+    let settings_info = banks_client
+        .get_account(settings_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+    assert_eq!(settings_data.min_relays, new_min_relays);
+    assert_eq!(settings_data.max_relays, new_max_relays);
+
+    // Round below the new lower bound is rejected
+    let new_relays = vec![Pubkey::new_unique(); 3];
+    let write_data = RelayRoundProposalEventWithLen::new(round_number + 1, new_relays, 1759950990);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+            finalize_proposal_ix(&funder.pubkey(), &proposal_pubkey, round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            2,
+            InstructionError::Custom(SolanaBridgeError::InvalidRelaysCount as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_spend_proposal() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..5 {
+        relays.push(Keypair::new());
+    }
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Treasury Account holding closed round rent
+    let treasury_address = get_treasury_address();
+    let treasury_balance = 1_000_000_000;
+
+    program_test.add_account(
+        treasury_address,
+        Account {
+            lamports: treasury_balance,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let recipient = Pubkey::new_unique();
+    let amount = 400_000_000;
+    let write_data = SpendProposalEventWithLen::new(recipient, amount);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+            finalize_spend_proposal_ix(&funder.pubkey(), &proposal_pubkey, round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Spend beyond the treasury balance is rejected
+    let overspend_data = SpendProposalEventWithLen::new(recipient, treasury_balance);
+
+    let serialized_overspend_data = overspend_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let overspend_proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_overspend_data,
+    );
+
+    let overspend_ixs = [
+        create_proposal_ix(
+            &funder.pubkey(),
+            &proposal_creator.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            &serialized_overspend_data,
+        ),
+        write_proposal_ix(
+            &overspend_proposal_pubkey,
+            0,
+            overspend_data.try_to_vec().unwrap(),
+        ),
+        finalize_spend_proposal_ix(&funder.pubkey(), &overspend_proposal_pubkey, round_number),
+    ];
+
+    let mut transaction = Transaction::new_with_payer(&overspend_ixs, Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(2, InstructionError::InsufficientFunds)
+    );
+
+    // Anyone funds the treasury
+    let mut transaction = Transaction::new_with_payer(
+        &[fund_treasury_ix(&funder.pubkey(), treasury_balance)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let treasury_info = banks_client
+        .get_account(treasury_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(treasury_info.lamports, 2 * treasury_balance);
+
+    let recent_blockhash = banks_client
+        .get_new_latest_blockhash(&recent_blockhash)
+        .await
+        .expect("get_new_latest_blockhash");
+
+    let mut transaction = Transaction::new_with_payer(&overspend_ixs, Some(&funder.pubkey()));
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Spend isn't paid before the relays approve it
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_spend_proposal_ix(&proposal_pubkey, &recipient)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let recipient_info = banks_client
+        .get_account(recipient)
+        .await
+        .expect("get_account");
+    assert!(recipient_info.is_none());
+
+    // Vote for Proposal
+    for (index, relay) in relays.iter().enumerate() {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                index as u32,
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Execute Proposal
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            execute_spend_proposal_ix(&proposal_pubkey, &recipient),
+            execute_spend_proposal_ix(&proposal_pubkey, &recipient),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check executed Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = SpendProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+
+    // Spend is paid once
+    let recipient_info = banks_client
+        .get_account(recipient)
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(recipient_info.lamports, amount);
+
+    let treasury_info = banks_client
+        .get_account(treasury_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(treasury_info.lamports, 2 * treasury_balance - amount);
+}
+
+#[tokio::test]
+async fn test_migrate_proposal() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..5 {
+        relays.push(Keypair::new());
+    }
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Proposal Account finalized with inline votes
+    let event_timestamp: u32 = 1650988297;
+    let event_transaction_lt: u64 = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let new_relays = vec![Pubkey::new_unique(); 3];
+    let new_round_number = round_number + 1;
+    let new_round_end = 1759950990;
+    let write_data =
+        RelayRoundProposalEventWithLen::new(new_round_number, new_relays.clone(), new_round_end);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let (proposal_pubkey, proposal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &hash(&serialized_write_data).to_bytes(),
+        ],
+        &round_loader::id(),
+    );
+
+    let signers = vec![
+        Vote::Confirm,
+        Vote::Confirm,
+        Vote::None,
+        Vote::Reject,
+        Vote::None,
+    ];
+
+    let proposal_account_data = Proposal {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(proposal_nonce, None),
+        author: proposal_creator.pubkey(),
+        round_number,
+        required_votes: 4,
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        event: serialized_write_data,
+        meta: RelayRoundProposalMetaWithLen::default()
+            .data
+            .try_to_vec()
+            .unwrap(),
+        signers: signers.clone(),
+    };
+
+    let mut proposal_packed = vec![0; RelayRoundProposal::LEN];
+    proposal_account_data.pack_into_slice(&mut proposal_packed);
+
+    program_test.add_account(
+        proposal_pubkey,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRoundProposal::LEN)
+                + RELAY_REPARATION * relays.len() as u64,
+            data: proposal_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Voting requires migration
+    let mut transaction = Transaction::new_with_payer(
+        &[vote_for_proposal_ix(
+            &relays[2].pubkey(),
+            &proposal_pubkey,
+            round_number,
+            Vote::Confirm,
+            2,
+        )],
+        Some(&relays[2].pubkey()),
+    );
+    transaction.sign(&[&relays[2]], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::ProposalNotMigrated as u32)
+        )
+    );
+
+    // Migrate Proposal
+    let voters = vec![0, 1, 3];
+
+    let mut transaction = Transaction::new_with_payer(
+        &[migrate_proposal_ix(
+            &funder.pubkey(),
+            &proposal_pubkey,
+            round_number,
+            &voters,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check migrated Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+
+    assert_eq!(proposal_data.signers, vec![]);
+    assert_eq!(proposal_data.confirm_count, 2);
+    assert_eq!(proposal_data.reject_count, 1);
+    assert_eq!(proposal_data.required_votes, 4);
+    assert_eq!(proposal_data.event.data.relays, new_relays);
+
+    for (index, vote) in voters
+        .iter()
+        .zip([Vote::Confirm, Vote::Confirm, Vote::Reject])
+    {
+        let vote_info = banks_client
+            .get_account(get_proposal_vote_address(&proposal_pubkey, *index))
+            .await
+            .expect("get_account")
+            .expect("account");
+
+        let vote_data = ProposalVote::unpack(vote_info.data()).expect("vote unpack");
+        assert_eq!(vote_data.voter, relays[*index as usize].pubkey());
+        assert_eq!(vote_data.vote, vote);
+    }
+
+    // Migrated votes can't be cast twice
+    for (index, relay) in relays.iter().enumerate() {
+        let blockhash = banks_client
+            .get_latest_blockhash()
+            .await
+            .expect("get_latest_blockhash");
+
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                index as u32,
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], blockhash);
+
+        let result = banks_client.process_transaction(transaction).await;
+
+        if voters.contains(&(index as u32)) {
+            assert_eq!(
+                result.expect_err("process_transaction").unwrap(),
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(SolanaBridgeError::RelayAlreadyVoted as u32)
+                )
+            );
+        } else {
+            result.expect("process_transaction");
+        }
+    }
+
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+
+    assert_eq!(proposal_data.confirm_count, 4);
+    assert_eq!(proposal_data.reject_count, 1);
+
+    // Execute Proposal
+    let blockhash = banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get_latest_blockhash");
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_proposal_ix(
+            &funder.pubkey(),
+            &proposal_pubkey,
+            new_round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+
+    // Check created Relay Round
+    let relay_round_account = banks_client
+        .get_account(get_relay_round_address(new_round_number))
+        .await
+        .expect("get_account")
+        .expect("account");
+    let relay_round_data =
+        RelayRound::unpack(relay_round_account.data()).expect("relay round unpack");
+
+    assert_eq!(relay_round_data.relays, new_relays);
+}
+
+#[tokio::test]
+async fn test_execute_proposal_with_reward() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..5 {
+        relays.push(Keypair::new());
+    }
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 86400,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Executor Reward Vault Account
+    let vault_address = get_executor_reward_vault_address();
+    let vault_balance = 10_000_000_000;
+
+    program_test.add_account(
+        vault_address,
+        Account {
+            lamports: vault_balance,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Executor Account
+    let executor = Keypair::new();
+    let executor_balance = 1_000_000_000;
+
+    program_test.add_account(
+        executor.pubkey(),
+        Account {
+            lamports: executor_balance,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let new_relays = vec![Pubkey::new_unique(); 3];
+    let new_round_number = round_number + 1;
+    let new_round_end = 1759950990;
+    let write_data =
+        RelayRoundProposalEventWithLen::new(new_round_number, new_relays.clone(), new_round_end);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+            finalize_proposal_ix(&funder.pubkey(), &proposal_pubkey, round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Vote for Proposal
+    for (index, relay) in relays.iter().enumerate() {
+        let blockhash = banks_client
+            .get_latest_blockhash()
+            .await
+            .expect("get_latest_blockhash");
+
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                index as u32,
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Execute Proposal by anyone
+    let blockhash = banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get_latest_blockhash");
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_proposal_with_reward_ix(
+            &executor.pubkey(),
+            &proposal_pubkey,
+            new_round_number,
+        )],
+        Some(&executor.pubkey()),
+    );
+    transaction.sign(&[&executor], blockhash);
+
+    let fee = banks_client
+        .get_fee_for_message(transaction.message().clone())
+        .await
+        .expect("get_fee_for_message")
+        .expect("fee");
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check executed Proposal
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.meta.data.status, ProposalStatus::Executed);
+
+    // Check the new Relay Round
+    let new_relay_round_info = banks_client
+        .get_account(get_relay_round_address(new_round_number))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let new_relay_round_data =
+        RelayRound::unpack(new_relay_round_info.data()).expect("relay round unpack");
+    assert_eq!(
+        new_relay_round_data.suggested_proposal_deadline,
+        new_relay_round_data.round_end - 86400
+    );
+
+    // Executor got the Relay Round rent back together with the reward
+    let reimbursement = Rent::default().minimum_balance(RelayRound::LEN) + EXECUTOR_REWARD;
+
+    let executor_info = banks_client
+        .get_account(executor.pubkey())
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(
+        executor_info.lamports,
+        executor_balance - fee + EXECUTOR_REWARD
+    );
+
+    let vault_info = banks_client
+        .get_account(vault_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(vault_info.lamports, vault_balance - reimbursement);
+}
+
+#[tokio::test]
+async fn test_close_proposal() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let now = 1_700_000_000;
+    let round_number = 0;
+
+    // Add executed Proposal Account
+    let proposal_author = Pubkey::new_unique();
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let event = RelayRoundProposalEventWithLen::new(
+        round_number + 1,
+        vec![Pubkey::new_unique(); 3],
+        1759950990,
+    );
+    let event_data = event
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_address = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+    );
+
+    let (_, proposal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &hash(&event_data).to_bytes(),
+        ],
+        &round_loader::id(),
+    );
+
+    let mut meta = RelayRoundProposalMetaWithLen::new();
+    meta.data.status = ProposalStatus::Executed;
+
+    let proposal_account_data = RelayRoundProposal {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(proposal_nonce, None),
+        author: proposal_author,
+        round_number,
+        required_votes: 1,
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        },
+        event,
+        meta,
+        signers: vec![],
+        confirm_count: 1,
+        reject_count: 0,
+        executed_at: now,
+    };
+
+    let proposal_lamports = Rent::default().minimum_balance(RelayRoundProposal::LEN);
+
+    let mut proposal_packed = vec![0; RelayRoundProposal::LEN];
+    RelayRoundProposal::pack(proposal_account_data, &mut proposal_packed).unwrap();
+    program_test.add_account(
+        proposal_address,
+        Account {
+            lamports: proposal_lamports,
+            data: proposal_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let mut context = program_test.start_with_context().await;
+    let funder = context.payer.insecure_clone();
+
+    let recent_blockhash = test_fixtures::clock::set_timestamp(&mut context, now).await;
+
+    // Proposal is kept during the challenge window
+    let mut transaction = Transaction::new_with_payer(
+        &[close_proposal_ix(&proposal_address, &proposal_author)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::ChallengeWindowActive as u32)
+        )
+    );
+
+    // Anyone can close the proposal once the window has passed
+    let recent_blockhash =
+        test_fixtures::clock::warp_seconds(&mut context, PROPOSAL_CHALLENGE_WINDOW).await;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[close_proposal_ix(&proposal_address, &proposal_author)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let proposal_info = context
+        .banks_client
+        .get_account(proposal_address)
+        .await
+        .expect("get_account");
+    assert!(proposal_info.is_none());
+
+    // Rent is refunded to the author
+    let author_info = context
+        .banks_client
+        .get_account(proposal_author)
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(author_info.lamports, proposal_lamports);
+}
+
+#[tokio::test]
+async fn test_vote_for_proposal_errors() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let now = 1_700_000_000;
+    let relay = Keypair::new();
+
+    program_test.add_account(
+        relay.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Accounts, the active one and the frozen one
+    for (round_number, frozen) in [(0u32, false), (1, true)] {
+        let (_, relay_round_nonce) = Pubkey::find_program_address(
+            &[br"relay_round", &round_number.to_le_bytes()],
+            &round_loader::id(),
+        );
+
+        let round_end = now as u32 + 1209600;
+
+        let relay_round_data = RelayRound {
+            is_initialized: true,
+            account_kind: AccountKind::RelayRound(relay_round_nonce),
+            round_number,
+            round_end,
+            relays: vec![relay.pubkey()],
+            relays_root: Hash::default(),
+            relays_count: 0,
+            suggested_proposal_deadline: round_end,
+            activated_at: 0,
+            frozen,
+        };
+
+        let mut relay_round_packed = vec![0; RelayRound::LEN];
+        RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+        program_test.add_account(
+            get_relay_round_address(round_number),
+            Account {
+                lamports: Rent::default().minimum_balance(RelayRound::LEN),
+                data: relay_round_packed,
+                owner: round_loader::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Proposal Accounts: executed, with saturated confirmations and voted in the frozen round
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let mut proposals = Vec::new();
+
+    for (round_number, event_timestamp, status, confirm_count) in [
+        (0u32, 1650988297u32, ProposalStatus::Executed, 1u32),
+        (0, 1650988298, ProposalStatus::New, u32::MAX),
+        (1, 1650988299, ProposalStatus::New, 0),
+    ] {
+        let event = RelayRoundProposalEventWithLen::new(
+            round_number + 1,
+            vec![Pubkey::new_unique(); 3],
+            1759950990,
+        );
+        let event_data = event
+            .data
+            .try_to_vec()
+            .expect("serialize proposal event data");
+
+        let proposal_address = get_proposal_address(
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            &event_data,
+        );
+
+        let (_, proposal_nonce) = Pubkey::find_program_address(
+            &[
+                br"proposal",
+                &round_number.to_le_bytes(),
+                &event_timestamp.to_le_bytes(),
+                &event_transaction_lt.to_le_bytes(),
+                &event_configuration.to_bytes(),
+                &hash(&event_data).to_bytes(),
+            ],
+            &round_loader::id(),
+        );
+
+        let mut meta = RelayRoundProposalMetaWithLen::new();
+        meta.data.status = status;
+
+        let proposal_account_data = RelayRoundProposal {
+            is_initialized: true,
+            account_kind: AccountKind::Proposal(proposal_nonce, None),
+            author: Pubkey::new_unique(),
+            round_number,
+            required_votes: 1,
+            pda: PDA {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+            },
+            event,
+            meta,
+            signers: vec![],
+            confirm_count,
+            reject_count: 0,
+            executed_at: 0,
+        };
+
+        let mut proposal_packed = vec![0; RelayRoundProposal::LEN];
+        RelayRoundProposal::pack(proposal_account_data, &mut proposal_packed).unwrap();
+        program_test.add_account(
+            proposal_address,
+            Account {
+                lamports: Rent::default().minimum_balance(RelayRoundProposal::LEN)
+                    + RELAY_REPARATION,
+                data: proposal_packed,
+                owner: round_loader::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        proposals.push((proposal_address, round_number));
+    }
+
+    // Start Program Test
+    let mut context = program_test.start_with_context().await;
+    let funder = context.payer.insecure_clone();
+
+    let recent_blockhash = test_fixtures::clock::set_timestamp(&mut context, now).await;
+
+    let (executed_proposal, _) = proposals[0];
+    let (saturated_proposal, _) = proposals[1];
+    let (frozen_round_proposal, frozen_round_number) = proposals[2];
+
+    let cases = [
+        (
+            executed_proposal,
+            0,
+            Vote::None,
+            SolanaBridgeError::InvalidVote,
+        ),
+        (
+            executed_proposal,
+            0,
+            Vote::Confirm,
+            SolanaBridgeError::ProposalExecuted,
+        ),
+        (
+            saturated_proposal,
+            0,
+            Vote::Confirm,
+            SolanaBridgeError::VotesOverflow,
+        ),
+        (
+            frozen_round_proposal,
+            frozen_round_number,
+            Vote::Confirm,
+            SolanaBridgeError::RelayRoundNotActive,
+        ),
+    ];
+
+    for (proposal_address, round_number, vote, error) in cases {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_ix(
+                &relay.pubkey(),
+                &proposal_address,
+                round_number,
+                vote,
+                0,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, &relay], recent_blockhash);
+
+        let err = context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .expect_err("process_transaction");
+
+        assert_eq!(
+            err.unwrap(),
+            TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_freeze_relay_round() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let now = 1_700_000_000;
+    let activation_delay = 3600;
+    let guardian = Keypair::new();
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: 2,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay,
+        guardian: guardian.pubkey(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Accounts, the active one and the one in its challenge window
+    for (round_number, activated_at) in [(1u32, 0), (2, now as u32 + activation_delay)] {
+        let (_, relay_round_nonce) = Pubkey::find_program_address(
+            &[br"relay_round", &round_number.to_le_bytes()],
+            &round_loader::id(),
+        );
+
+        let round_end = now as u32 + 1209600;
+
+        let relay_round_data = RelayRound {
+            is_initialized: true,
+            account_kind: AccountKind::RelayRound(relay_round_nonce),
+            round_number,
+            round_end,
+            relays: vec![Pubkey::new_unique(); 3],
+            relays_root: Hash::default(),
+            relays_count: 0,
+            suggested_proposal_deadline: round_end,
+            activated_at,
+            frozen: false,
+        };
+
+        let mut relay_round_packed = vec![0; RelayRound::LEN];
+        RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+        program_test.add_account(
+            get_relay_round_address(round_number),
+            Account {
+                lamports: Rent::default().minimum_balance(RelayRound::LEN),
+                data: relay_round_packed,
+                owner: round_loader::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Start Program Test
+    let mut context = program_test.start_with_context().await;
+    let funder = context.payer.insecure_clone();
+
+    let recent_blockhash = test_fixtures::clock::set_timestamp(&mut context, now).await;
+
+    // Only the guardian can freeze a round
+    let mut transaction = Transaction::new_with_payer(
+        &[freeze_relay_round_ix(&funder.pubkey(), 2)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+    );
+
+    // Freeze the round in its challenge window
+    let mut transaction = Transaction::new_with_payer(
+        &[freeze_relay_round_ix(&guardian.pubkey(), 2)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let relay_round_info = context
+        .banks_client
+        .get_account(get_relay_round_address(2))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+    assert!(relay_round_data.frozen);
+
+    // Frozen round never becomes active
+    assert!(!relay_round_data.is_active(now as u32 + activation_delay));
+
+    // Active round can't be frozen
+    let mut transaction = Transaction::new_with_payer(
+        &[freeze_relay_round_ix(&guardian.pubkey(), 1)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &guardian], recent_blockhash);
+
+    let err = context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn test_export_round_digest() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let round_number: u32 = 11;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..3 {
+        relays.push(Keypair::new());
+    }
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let now = 1_700_000_000;
+    let round_end = now as u32 + 3600;
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: round_end,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let mut context = program_test.start_with_context().await;
+    let funder = context.payer.insecure_clone();
+
+    let recent_blockhash = test_fixtures::clock::set_timestamp(&mut context, now).await;
+
+    // Export Round Digest
+    let mut transaction = Transaction::new_with_payer(
+        &[export_round_digest_ix(&funder.pubkey(), round_number)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], recent_blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let round_digest_info = context
+        .banks_client
+        .get_account(get_round_digest_address(round_number))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let round_digest_data =
+        RoundDigest::unpack(round_digest_info.data()).expect("round digest unpack");
+
+    // Odd relay is moved to the root level as is
+    let leaves = relays
+        .iter()
+        .map(|relay| hash(&relay.pubkey().to_bytes()))
+        .collect::<Vec<_>>();
+    let relays_root = hashv(&[
+        hashv(&[leaves[0].as_ref(), leaves[1].as_ref()]).as_ref(),
+        leaves[2].as_ref(),
+    ]);
+
+    assert_eq!(round_digest_data.round_number, round_number);
+    assert_eq!(round_digest_data.round_end, round_end);
+    assert_eq!(round_digest_data.relays_root, relays_root);
+    assert_eq!(
+        round_digest_data.hash.to_bytes(),
+        round_digest_data
+            .to_cell()
+            .expect("digest cell")
+            .repr_hash()
+    );
+
+    // Digest follows the rotated keys
+    let new_relay = Keypair::new();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            rotate_relay_key_ix(&relays[1].pubkey(), &new_relay.pubkey(), round_number),
+            export_round_digest_ix(&funder.pubkey(), round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &relays[1], &new_relay], recent_blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let round_digest_info = context
+        .banks_client
+        .get_account(get_round_digest_address(round_number))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let rotated_round_digest_data =
+        RoundDigest::unpack(round_digest_info.data()).expect("round digest unpack");
+
+    assert_eq!(
+        rotated_round_digest_data.relays_root,
+        get_merkle_root(&[relays[0].pubkey(), new_relay.pubkey(), relays[2].pubkey()])
+    );
+    assert_ne!(rotated_round_digest_data.hash, round_digest_data.hash);
+}
+
+#[tokio::test]
+async fn test_merkle_relay_round() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let round_submitter = Keypair::new();
+    let round_number: u32 = 1;
+    let round_ttl = 3600;
+
+    // Relay set larger than a Relay Round can keep as a full list
+    let mut relays = vec![];
+    for _ in 0..MAX_MERKLE_RELAYS {
+        relays.push(Keypair::new());
+    }
+
+    let relay_keys = relays.iter().map(|pair| pair.pubkey()).collect::<Vec<_>>();
+
+    for relay in [&relays[0], &relays[1], relays.last().unwrap()] {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: 0,
+        round_submitter: round_submitter.pubkey(),
+        min_required_votes: 1,
+        round_ttl,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        get_settings_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Merkle Relay Round
+    let relays_root = get_merkle_root(&relay_keys);
+    let round_end = chrono::Utc::now().timestamp() as u32;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_merkle_relay_round_ix(
+            &funder.pubkey(),
+            &round_submitter.pubkey(),
+            round_number,
+            round_end,
+            relays_root,
+            relays.len() as u32,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &round_submitter], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let relay_round_info = banks_client
+        .get_account(get_relay_round_address(round_number))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+
+    assert!(relay_round_data.is_merkle());
+    assert_eq!(relay_round_data.relays, vec![]);
+    assert_eq!(relay_round_data.relays_root, relays_root);
+    assert_eq!(relay_round_data.relays_len(), relays.len());
+    assert_eq!(relay_round_data.round_end, round_end + round_ttl);
+
+    // Create Proposal
+    let proposal_creator = &relays[0];
+
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let write_data = RelayRoundProposalEventWithLen::new(
+        round_number + 1,
+        vec![Pubkey::new_unique(); 3],
+        1759950990,
+    );
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+            finalize_proposal_ix(&funder.pubkey(), &proposal_pubkey, round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(
+        proposal_data.required_votes,
+        (relays.len() * 2 / 3 + 1) as u32
+    );
+
+    // Relays of a merkle round can't vote without a proof
+    let mut transaction = Transaction::new_with_payer(
+        &[vote_for_proposal_ix(
+            &relays[0].pubkey(),
+            &proposal_pubkey,
+            round_number,
+            Vote::Confirm,
+            0,
+        )],
+        Some(&relays[0].pubkey()),
+    );
+    transaction.sign(&[&relays[0]], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::InvalidMerkleProof as u32)
+        )
+    );
+
+    // Proof of another relay is rejected
+    let mut transaction = Transaction::new_with_payer(
+        &[vote_for_proposal_with_proof_ix(
+            &relays[1].pubkey(),
+            &proposal_pubkey,
+            round_number,
+            Vote::Confirm,
+            get_merkle_proof(&relay_keys, 0).unwrap(),
+        )],
+        Some(&relays[1].pubkey()),
+    );
+    transaction.sign(&[&relays[1]], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::InvalidMerkleProof as u32)
+        )
+    );
+
+    // Vote for Proposal
+    for (index, relay) in [(0, &relays[0]), (relays.len() - 1, relays.last().unwrap())] {
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_with_proof_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                get_merkle_proof(&relay_keys, index).unwrap(),
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], recent_blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    let proposal_info = banks_client
+        .get_account(proposal_pubkey)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let proposal_data = RelayRoundProposal::unpack(proposal_info.data()).expect("proposal unpack");
+    assert_eq!(proposal_data.confirm_count, 2);
+}
+
+#[tokio::test]
+async fn test_event_configuration_registry() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let initializer = Keypair::new();
+    program_test.add_account(
+        initializer.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let (programdata_address, _) = Pubkey::find_program_address(
+        &[round_loader::id().as_ref()],
+        &bpf_loader_upgradeable::id(),
+    );
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(initializer.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        programdata_address,
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let event_configuration = Pubkey::new_unique();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            initialize_ix(
+                &funder.pubkey(),
+                &initializer.pubkey(),
+                0,
+                funder.pubkey(),
+                1,
+                1209600,
+            ),
+            update_settings_ix(
+                &initializer.pubkey(),
+                None,
+                None,
+                None,
+                None,
+                Some(true),
+                None,
+                None,
+                None,
+                None,
+            ),
+            add_event_configuration_ix(&initializer.pubkey(), event_configuration),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Settings Account
+    let settings_info = banks_client
+        .get_account(get_settings_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+    assert_eq!(settings_data.event_configurations_restricted, true);
+
+    // Check Event Configuration Account
+    let event_configuration_address = get_event_configuration_address(&event_configuration);
+
+    let event_configuration_info = banks_client
+        .get_account(event_configuration_address)
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let event_configuration_data = EventConfiguration::unpack(event_configuration_info.data())
+        .expect("event configuration unpack");
+
+    assert_eq!(event_configuration_data.is_initialized, true);
+    assert_eq!(
+        event_configuration_data.event_configuration,
+        event_configuration
+    );
+
+    let (_, event_configuration_nonce) = Pubkey::find_program_address(
+        &[br"event_configuration", &event_configuration.to_bytes()],
+        &round_loader::id(),
+    );
+    assert_eq!(
+        event_configuration_data.account_kind,
+        AccountKind::EventConfiguration(event_configuration_nonce)
+    );
+
+    // Only the upgrade authority may register event configurations
+    let stranger = Keypair::new();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[add_event_configuration_ix(
+            &stranger.pubkey(),
+            Pubkey::new_unique(),
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &stranger], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("stranger must not register event configurations");
+    assert!(matches!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, _)
+    ));
+
+    // Remove Event Configuration
+    let mut transaction = Transaction::new_with_payer(
+        &[remove_event_configuration_ix(
+            &initializer.pubkey(),
+            event_configuration,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let event_configuration_info = banks_client
+        .get_account(event_configuration_address)
+        .await
+        .expect("get_account");
+    assert!(event_configuration_info.is_none());
+}
+
+#[tokio::test]
+async fn test_relay_round_saturation() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let round_submitter = Keypair::new();
+    let proposal_creator = Keypair::new();
+
+    let round_number = 0;
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: round_submitter.pubkey(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        get_settings_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account with the full list of relays
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relays = (0..MAX_RELAYS)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.clone(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        get_relay_round_address(round_number),
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let relay_round_info = banks_client
+        .get_account(get_relay_round_address(round_number))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+    assert_eq!(relay_round_data.relays, relays);
+
+    // Merkle round larger than the relay slots of withdrawals is rejected
+    let round_end = chrono::Utc::now().timestamp() as u32;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_merkle_relay_round_ix(
+            &funder.pubkey(),
+            &round_submitter.pubkey(),
+            round_number + 1,
+            round_end,
+            Hash::new_unique(),
+            (MAX_MERKLE_RELAYS + 1) as u32,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &round_submitter], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_merkle_relay_round_ix(
+            &funder.pubkey(),
+            &round_submitter.pubkey(),
+            round_number + 1,
+            round_end,
+            Hash::new_unique(),
+            MAX_MERKLE_RELAYS as u32,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &round_submitter], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let relay_round_info = banks_client
+        .get_account(get_relay_round_address(round_number + 1))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+    assert_eq!(relay_round_data.relays_len(), MAX_MERKLE_RELAYS);
+
+    // Proposal of a round over the full list limit doesn't fit the proposal account
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let new_relays = vec![Pubkey::new_unique(); MAX_RELAYS + 1];
+    let write_data = RelayRoundProposalEventWithLen::new(round_number + 2, new_relays, 1759950990);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_proposal_ix(
+            &funder.pubkey(),
+            &proposal_creator.pubkey(),
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+            &serialized_write_data,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let chunk_size = 800;
+    let chunks = write_data.try_to_vec().unwrap();
+    let chunks = chunks.chunks(chunk_size).collect::<Vec<_>>();
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let mut transaction = Transaction::new_with_payer(
+            &[write_proposal_ix(
+                &proposal_pubkey,
+                (i * chunk_size) as u32,
+                chunk.to_vec(),
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder], recent_blockhash);
+
+        let result = banks_client.process_transaction(transaction).await;
+
+        if i + 1 < chunks.len() {
+            result.expect("process_transaction");
+        } else {
+            assert_eq!(
+                result.expect_err("process_transaction").unwrap(),
+                TransactionError::InstructionError(0, InstructionError::AccountDataTooSmall)
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_round_stats_snapshot() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+
+    // Add Creator Account
+    let proposal_creator = Keypair::new();
+    program_test.add_account(
+        proposal_creator.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let round_number = 0;
+
+    // Add Relays Accounts
+    let mut relays = vec![];
+    for _ in 0..3 {
+        relays.push(Keypair::new());
+    }
+
+    for relay in &relays {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 100_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_address = get_settings_address();
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number: round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 86400,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 0,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        settings_address,
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Account
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_address = get_relay_round_address(round_number);
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end: chrono::Utc::now().timestamp() as u32,
+        relays: relays.iter().map(|pair| pair.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: 0,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        relay_round_address,
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Token Proxy Relay Round Stats Account
+    let (_, relay_round_stats_nonce) = Pubkey::find_program_address(
+        &[br"relay_round_stats", &round_number.to_le_bytes()],
+        &TOKEN_PROXY_ID,
+    );
+
+    let counters = RoundCounters {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRoundStats(relay_round_stats_nonce),
+        round_number,
+        total: 5,
+        votes: vec![2, 2, 1],
+        withdrawals: 2,
+        volume: 1_500_000,
+        active_withdrawals: 0,
+    };
+
+    let mut relay_round_stats_packed =
+        hash(b"account:token-proxy-interface:RelayRoundStats").to_bytes()[..8].to_vec();
+    relay_round_stats_packed.extend(counters.try_to_vec().unwrap());
+    // Length of the Token Proxy relay round stats account
+    relay_round_stats_packed.resize(508, 0);
+
+    program_test.add_account(
+        get_relay_round_stats_address(round_number),
+        Account {
+            lamports: Rent::default().minimum_balance(relay_round_stats_packed.len()),
+            data: relay_round_stats_packed,
+            owner: TOKEN_PROXY_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Executor Reward Vault Account
+    program_test.add_account(
+        get_executor_reward_vault_address(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Create Proposal
+    let event_timestamp = 1650988297;
+    let event_transaction_lt = 1650988334;
+    let event_configuration = Pubkey::new_unique();
+
+    let new_relays = vec![Pubkey::new_unique(); 3];
+    let new_round_number = round_number + 1;
+    let new_round_end = 1759950990;
+    let write_data =
+        RelayRoundProposalEventWithLen::new(new_round_number, new_relays.clone(), new_round_end);
+
+    let serialized_write_data = write_data
+        .data
+        .try_to_vec()
+        .expect("serialize proposal event data");
+
+    let proposal_pubkey = get_proposal_address(
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &serialized_write_data,
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[
+            create_proposal_ix(
+                &funder.pubkey(),
+                &proposal_creator.pubkey(),
+                round_number,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                &serialized_write_data,
+            ),
+            write_proposal_ix(&proposal_pubkey, 0, write_data.try_to_vec().unwrap()),
+            finalize_proposal_ix(&funder.pubkey(), &proposal_pubkey, round_number),
+        ],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &proposal_creator], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Vote for Proposal
+    for (index, relay) in relays.iter().enumerate() {
+        let blockhash = banks_client
+            .get_latest_blockhash()
+            .await
+            .expect("get_latest_blockhash");
+
+        let mut transaction = Transaction::new_with_payer(
+            &[vote_for_proposal_ix(
+                &relay.pubkey(),
+                &proposal_pubkey,
+                round_number,
+                Vote::Confirm,
+                index as u32,
+            )],
+            Some(&relay.pubkey()),
+        );
+        transaction.sign(&[relay], blockhash);
+
+        banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("process_transaction");
+    }
+
+    // Execute Proposal together with the snapshot of the replaced round
+    let blockhash = banks_client
+        .get_latest_blockhash()
+        .await
+        .expect("get_latest_blockhash");
+
+    let mut transaction = Transaction::new_with_payer(
+        &[execute_proposal_with_round_stats_ix(
+            &funder.pubkey(),
+            &proposal_pubkey,
+            round_number,
+            new_round_number,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder], blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // Check Round Stats
+    let round_stats_info = banks_client
+        .get_account(get_round_stats_address(round_number))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let round_stats_data = RoundStats::unpack(round_stats_info.data()).expect("round stats unpack");
+
+    assert!(round_stats_data.is_initialized);
+    assert_eq!(round_stats_data.round_number, round_number);
+    assert_eq!(round_stats_data.withdrawals, 2);
+    assert_eq!(round_stats_data.volume, 1_500_000);
+    assert_eq!(round_stats_data.total_votes, 5);
+    assert_eq!(round_stats_data.votes, vec![2, 2, 1]);
+    assert_ne!(round_stats_data.snapshot_at, 0);
+}
+
+#[tokio::test]
+async fn test_close_old_round() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let current_round_number = 5;
+
+    // Add Settings Account
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, 0),
+        current_round_number,
+        round_submitter: Pubkey::new_unique(),
+        min_required_votes: 1,
+        round_ttl: 1209600,
+        event_configurations_restricted: false,
+        proposal_lead_time: 0,
+        activation_delay: 0,
+        guardian: Pubkey::default(),
+        min_relays: MIN_RELAYS as u32,
+        max_relays: MAX_MERKLE_RELAYS as u32,
+        round_retention: 2,
+    };
+
+    let mut settings_packed = vec![0; Settings::LEN];
+    Settings::pack(settings_account_data, &mut settings_packed).unwrap();
+    program_test.add_account(
+        get_settings_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(Settings::LEN),
+            data: settings_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Add Relay Round Accounts
+    for round_number in 1..=3 {
+        let (_, relay_round_nonce) = Pubkey::find_program_address(
+            &[br"relay_round", &round_number.to_le_bytes()],
+            &round_loader::id(),
+        );
+
+        let relay_round_data = RelayRound {
+            is_initialized: true,
+            account_kind: AccountKind::RelayRound(relay_round_nonce),
+            round_number,
+            round_end: 0,
+            relays: vec![Pubkey::new_unique(); 3],
+            relays_root: Hash::default(),
+            relays_count: 0,
+            suggested_proposal_deadline: 0,
+            activated_at: 0,
+            frozen: false,
+        };
+
+        let mut relay_round_packed = vec![0; RelayRound::LEN];
+        RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+        program_test.add_account(
+            get_relay_round_address(round_number),
+            Account {
+                lamports: Rent::default().minimum_balance(RelayRound::LEN),
+                data: relay_round_packed,
+                owner: round_loader::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Withdrawals of the first round are final, the one of the second round is not yet
+    for (round_number, withdrawals, active_withdrawals) in [(1u32, 2, 0), (2, 1, 1)] {
+        let (_, relay_round_stats_nonce) = Pubkey::find_program_address(
+            &[br"relay_round_stats", &round_number.to_le_bytes()],
+            &TOKEN_PROXY_ID,
+        );
+
+        let counters = RoundCounters {
+            is_initialized: true,
+            account_kind: AccountKind::RelayRoundStats(relay_round_stats_nonce),
+            round_number,
+            total: 3,
+            votes: vec![1, 1, 1],
+            withdrawals,
+            volume: 1_000_000,
+            active_withdrawals,
+        };
+
+        let mut relay_round_stats_packed =
+            hash(b"account:token-proxy-interface:RelayRoundStats").to_bytes()[..8].to_vec();
+        relay_round_stats_packed.extend(counters.try_to_vec().unwrap());
+        // Length of the Token Proxy relay round stats account
+        relay_round_stats_packed.resize(508, 0);
+
+        program_test.add_account(
+            get_relay_round_stats_address(round_number),
+            Account {
+                lamports: Rent::default().minimum_balance(relay_round_stats_packed.len()),
+                data: relay_round_stats_packed,
+                owner: TOKEN_PROXY_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    // Round within the retention window is kept
+    let mut transaction =
+        Transaction::new_with_payer(&[close_old_round_ix(3)], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::RoundRetained as u32)
+        )
+    );
+
+    // Round referenced by withdrawals is kept
+    let mut transaction =
+        Transaction::new_with_payer(&[close_old_round_ix(2)], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction")
+        .unwrap();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(SolanaBridgeError::RoundInUse as u32)
+        )
+    );
+
+    // Round whose withdrawals are all final is closed
+    let mut transaction =
+        Transaction::new_with_payer(&[close_old_round_ix(1)], Some(&funder.pubkey()));
+    transaction.sign(&[&funder], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let relay_round_info = banks_client
+        .get_account(get_relay_round_address(1))
+        .await
+        .expect("get_account");
+    assert!(relay_round_info.is_none());
+
+    let treasury_info = banks_client
+        .get_account(get_treasury_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+    assert_eq!(
+        treasury_info.lamports,
+        Rent::default().minimum_balance(RelayRound::LEN)
+    );
+}
+
+#[tokio::test]
+async fn test_report_relay() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let now = 1_700_000_000;
+    let relays = (0..3).map(|_| Keypair::new()).collect::<Vec<_>>();
+    let outsider = Keypair::new();
+
+    for relay in relays.iter().chain([&outsider]) {
+        program_test.add_account(
+            relay.pubkey(),
+            Account {
+                lamports: 10_000_000_000,
+                data: vec![],
+                owner: solana_program::system_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Add Relay Round Account
+    let round_number = 5;
+    let round_end = now as u32 + 1209600;
+
+    let (_, relay_round_nonce) = Pubkey::find_program_address(
+        &[br"relay_round", &round_number.to_le_bytes()],
+        &round_loader::id(),
+    );
+
+    let relay_round_data = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end,
+        relays: relays.iter().map(|relay| relay.pubkey()).collect(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: round_end,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    let mut relay_round_packed = vec![0; RelayRound::LEN];
+    RelayRound::pack(relay_round_data, &mut relay_round_packed).unwrap();
+
+    program_test.add_account(
+        get_relay_round_address(round_number),
+        Account {
+            lamports: Rent::default().minimum_balance(RelayRound::LEN),
+            data: relay_round_packed,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    // Start Program Test
+    let mut context = program_test.start_with_context().await;
+    let funder = context.payer.insecure_clone();
+
+    let recent_blockhash = test_fixtures::clock::set_timestamp(&mut context, now).await;
+
+    // Report relay, the first report creates the log
+    let evidence_hash = hash(b"conflicting votes");
+
+    let mut transaction = Transaction::new_with_payer(
+        &[report_relay_ix(
+            &relays[0].pubkey(),
+            round_number,
+            relays[1].pubkey(),
+            ReportCategory::Equivocation,
+            evidence_hash,
+            0,
+            None,
+            None,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &relays[0]], recent_blockhash);
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let report_log_info = context
+        .banks_client
+        .get_account(get_report_log_address())
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let report_log_data = ReportLog::unpack(report_log_info.data()).expect("report log unpack");
+    assert_eq!(report_log_data.total, 1);
+    assert_eq!(report_log_data.current_page(), 0);
+
+    let report_log_page_info = context
+        .banks_client
+        .get_account(get_report_log_page_address(0))
+        .await
+        .expect("get_account")
+        .expect("account");
 
-    // Check created Relay Round
-    let relay_round_address = get_relay_round_address(new_round_number);
+    let report_log_page_data =
+        ReportLogPage::unpack(report_log_page_info.data()).expect("report log page unpack");
+    assert_eq!(report_log_page_data.page, 0);
+    assert_eq!(
+        report_log_page_data.reports,
+        vec![RelayReport {
+            reporter: relays[0].pubkey(),
+            accused: relays[1].pubkey(),
+            round_number,
+            category: ReportCategory::Equivocation,
+            evidence_hash,
+            reported_at: now,
+        }]
+    );
 
-    let relay_round_account = banks_client
-        .get_account(relay_round_address)
+    // Relays can't report themselves and only relays of the round report or get reported
+    let cases = [
+        (
+            &relays[2],
+            relays[2].pubkey(),
+            SolanaBridgeError::SelfReport,
+        ),
+        (
+            &outsider,
+            relays[1].pubkey(),
+            SolanaBridgeError::InvalidRelay,
+        ),
+        (
+            &relays[2],
+            outsider.pubkey(),
+            SolanaBridgeError::InvalidRelay,
+        ),
+    ];
+
+    for (reporter, accused, error) in cases {
+        let mut transaction = Transaction::new_with_payer(
+            &[report_relay_ix(
+                &reporter.pubkey(),
+                round_number,
+                accused,
+                ReportCategory::Downtime,
+                evidence_hash,
+                0,
+                None,
+                None,
+            )],
+            Some(&funder.pubkey()),
+        );
+        transaction.sign(&[&funder, reporter], recent_blockhash);
+
+        let err = context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .expect_err("process_transaction");
+
+        assert_eq!(
+            err.unwrap(),
+            TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_init_with_foreign_programdata() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let initializer = Keypair::new();
+    program_test.add_account(
+        initializer.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(initializer.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    // Programdata of the program and a copy of it at another address
+    let (programdata_address, programdata_nonce) = Pubkey::find_program_address(
+        &[round_loader::id().as_ref()],
+        &bpf_loader_upgradeable::id(),
+    );
+
+    let foreign_programdata_address = Pubkey::new_unique();
+
+    for address in [programdata_address, foreign_programdata_address] {
+        program_test.add_account(
+            address,
+            Account {
+                lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+                data: programdata_data_serialized.clone(),
+                owner: round_loader::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+    }
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let genesis_round_number = 0;
+    let round_submitter = Pubkey::new_unique();
+
+    let initialize_with_programdata_ix = |programdata_address| {
+        let mut ix = initialize_ix(
+            &funder.pubkey(),
+            &initializer.pubkey(),
+            genesis_round_number,
+            round_submitter,
+            1,
+            1209600,
+        );
+
+        for account in &mut ix.accounts {
+            if account.pubkey == get_programdata_address() {
+                account.pubkey = programdata_address;
+            }
+        }
+
+        ix
+    };
+
+    // Authority is only read from the programdata of the program
+    let mut transaction = Transaction::new_with_payer(
+        &[initialize_with_programdata_ix(foreign_programdata_address)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+    );
+
+    let mut transaction = Transaction::new_with_payer(
+        &[initialize_with_programdata_ix(programdata_address)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let settings_info = banks_client
+        .get_account(get_settings_address())
         .await
         .expect("get_account")
         .expect("account");
-    let relay_round_data =
-        RelayRound::unpack(relay_round_account.data()).expect("relay round unpack");
 
-    assert_eq!(relay_round_data.is_initialized, true);
-    assert_eq!(relay_round_data.round_number, new_round_number);
-    assert_eq!(relay_round_data.relays, new_relays);
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
 
-    // Check Settings
-    let settings_account = banks_client
-        .get_account(settings_address)
+    let (_, settings_nonce) = Pubkey::find_program_address(&[br"settings"], &round_loader::id());
+    assert_eq!(
+        settings_data.account_kind,
+        AccountKind::Settings(settings_nonce, programdata_nonce)
+    );
+    assert_eq!(settings_data.round_submitter, round_submitter);
+}
+
+#[tokio::test]
+async fn test_proposal_lead_time() {
+    let mut program_test = ProgramTest::new(
+        "round_loader",
+        round_loader::id(),
+        processor!(Processor::process),
+    );
+
+    // Setup environment
+    let initializer = Keypair::new();
+    program_test.add_account(
+        initializer.pubkey(),
+        Account {
+            lamports: 10_000_000_000,
+            data: vec![],
+            owner: solana_program::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let programdata_data = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(initializer.pubkey()),
+    };
+
+    let programdata_data_serialized =
+        bincode::serialize::<UpgradeableLoaderState>(&programdata_data).unwrap();
+
+    program_test.add_account(
+        get_programdata_address(),
+        Account {
+            lamports: Rent::default().minimum_balance(programdata_data_serialized.len()),
+            data: programdata_data_serialized,
+            owner: round_loader::id(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let stranger = Keypair::new();
+
+    // Start Program Test
+    let (mut banks_client, funder, recent_blockhash) = program_test.start().await;
+
+    let round_submitter = Keypair::new();
+    let round_ttl = 1209600;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[initialize_ix(
+            &funder.pubkey(),
+            &initializer.pubkey(),
+            0,
+            round_submitter.pubkey(),
+            1,
+            round_ttl,
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let update_lead_time_ix = |author: &Pubkey, proposal_lead_time: u32| {
+        update_settings_ix(
+            author,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(proposal_lead_time),
+            None,
+            None,
+            None,
+        )
+    };
+
+    // Only the upgrade authority sets the lead time
+    let mut transaction = Transaction::new_with_payer(
+        &[update_lead_time_ix(&stranger.pubkey(), 3600)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &stranger], recent_blockhash);
+
+    let err = banks_client
+        .process_transaction(transaction)
+        .await
+        .expect_err("process_transaction");
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+    );
+
+    let settings_info = banks_client
+        .get_account(get_settings_address())
         .await
         .expect("get_account")
         .expect("account");
-    let settings_data = Settings::unpack(settings_account.data()).expect("settings unpack");
 
-    assert_eq!(settings_data.current_round_number, new_round_number);
+    let settings_data = Settings::unpack(settings_info.data()).expect("settings unpack");
+    assert_eq!(settings_data.proposal_lead_time, 0);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[update_lead_time_ix(&initializer.pubkey(), 3600)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    // New round is due to be replaced a lead time before its end
+    let round_end = 1_700_000_000;
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_relay_round_ix(
+            &funder.pubkey(),
+            &round_submitter.pubkey(),
+            1,
+            round_end,
+            vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &round_submitter], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let relay_round_info = banks_client
+        .get_account(get_relay_round_address(1))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+    assert_eq!(relay_round_data.round_end, round_end + round_ttl);
+    assert_eq!(
+        relay_round_data.suggested_proposal_deadline,
+        round_end + round_ttl - 3600
+    );
+
+    // Lead time longer than the round doesn't move the deadline before the epoch
+    let mut transaction = Transaction::new_with_payer(
+        &[update_lead_time_ix(&initializer.pubkey(), u32::MAX)],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &initializer], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let mut transaction = Transaction::new_with_payer(
+        &[create_relay_round_ix(
+            &funder.pubkey(),
+            &round_submitter.pubkey(),
+            2,
+            round_end,
+            vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        )],
+        Some(&funder.pubkey()),
+    );
+    transaction.sign(&[&funder, &round_submitter], recent_blockhash);
+
+    banks_client
+        .process_transaction(transaction)
+        .await
+        .expect("process_transaction");
+
+    let relay_round_info = banks_client
+        .get_account(get_relay_round_address(2))
+        .await
+        .expect("get_account")
+        .expect("account");
+
+    let relay_round_data = RelayRound::unpack(relay_round_info.data()).expect("relay round unpack");
+    assert_eq!(
+        relay_round_data.suggested_proposal_deadline,
+        relay_round_data.round_end
+    );
 }
@@ -5,12 +5,16 @@ function print_help() {
   echo ''
   echo 'Options:'
   echo '  -h,--help         Print this help message and exit'
+  echo '  -c,--cluster      Target cluster of the following options: mainnet (default), devnet or localnet'
   echo '  -p,--programs     Build solana programs'
   echo '  -w,--wasm         Build WASM bindings'
   echo '  -b,--bindings     Build Rust bindings'
   echo '  -t,--tests        Run tests'
+  echo '  -s,--size         Check on-chain program sizes'
 }
 
+FEATURES=()
+
 while [[ $# -gt 0 ]]; do
   key="$1"
   case $key in
@@ -18,12 +22,21 @@ while [[ $# -gt 0 ]]; do
         print_help
         exit 0
       ;;
+      -c|--cluster)
+        case $2 in
+          mainnet) FEATURES=() ;;
+          devnet|localnet) FEATURES=(--features "$2") ;;
+          *) echo "ERROR: Unknown cluster $2"; exit 1 ;;
+        esac
+        shift # past argument
+        shift # past value
+      ;;
       -p|--programs)
         shift # past argument
 
-        cargo-build-sbf --manifest-path=./token-proxy/Cargo.toml --sbf-out-dir=dist/program
-        cargo-build-sbf --manifest-path=./round-loader/Cargo.toml --sbf-out-dir=dist/program
-        cargo-build-sbf --manifest-path=./native-proxy/Cargo.toml --sbf-out-dir=dist/program
+        cargo-build-sbf --manifest-path=./token-proxy/Cargo.toml --sbf-out-dir=dist/program "${FEATURES[@]}"
+        cargo-build-sbf --manifest-path=./round-loader/Cargo.toml --sbf-out-dir=dist/program "${FEATURES[@]}"
+        cargo-build-sbf --manifest-path=./native-proxy/Cargo.toml --sbf-out-dir=dist/program "${FEATURES[@]}"
       ;;
       -w|--wasm)
         shift # past argument
@@ -34,16 +47,22 @@ while [[ $# -gt 0 ]]; do
       -b|--bindings)
         shift # past argument
 
-        cargo build --release --manifest-path=./token-proxy/Cargo.toml  --features=bindings
-        cargo build --release --manifest-path=./round-loader/Cargo.toml --features=bindings
-        cargo build --release --manifest-path=./native-proxy/Cargo.toml --features=bindings
+        cargo build --release --manifest-path=./token-proxy/Cargo.toml  --features=bindings "${FEATURES[@]}"
+        cargo build --release --manifest-path=./round-loader/Cargo.toml --features=bindings "${FEATURES[@]}"
+        cargo build --release --manifest-path=./native-proxy/Cargo.toml --features=bindings "${FEATURES[@]}"
       ;;
       -t|--tests)
         shift # past argument
 
-        cargo-test-sbf --manifest-path=./token-proxy/Cargo.toml
-        cargo-test-sbf --manifest-path=./round-loader/Cargo.toml
-        cargo-test-sbf --manifest-path=./native-proxy/Cargo.toml
+        cargo-test-sbf --manifest-path=./token-proxy/Cargo.toml "${FEATURES[@]}"
+        cargo-test-sbf --manifest-path=./round-loader/Cargo.toml "${FEATURES[@]}"
+        cargo-test-sbf --manifest-path=./native-proxy/Cargo.toml "${FEATURES[@]}"
+        cargo-test-sbf --manifest-path=./mock-everscale/Cargo.toml
+      ;;
+      -s|--size)
+        shift # past argument
+
+        ./scripts/check-size.sh || exit 1
       ;;
       *) # unknown option
         echo 'ERROR: Unexpected'
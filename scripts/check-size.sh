@@ -0,0 +1,88 @@
+#!/usr/bin/env bash
+
+function print_help() {
+  echo 'Usage: check-size.sh [OPTIONS]'
+  echo ''
+  echo 'Checks that client-only features stay out of the on-chain programs'
+  echo 'and that the built programs do not grow past the recorded sizes.'
+  echo ''
+  echo 'Options:'
+  echo '  -h,--help         Print this help message and exit'
+  echo '  -u,--update       Record current program sizes as the new baseline'
+}
+
+programs=(token-proxy round-loader native-proxy)
+baseline=scripts/program-sizes.txt
+update=false
+
+while [[ $# -gt 0 ]]; do
+  key="$1"
+  case $key in
+      -h|--help)
+        print_help
+        exit 0
+      ;;
+      -u|--update)
+        update=true
+        shift # past argument
+      ;;
+      *) # unknown option
+        echo 'ERROR: Unknown option'
+        echo ''
+        print_help
+        exit 1
+      ;;
+  esac
+done
+
+failed=false
+
+# On-chain builds must not pull serde derives from the bridge crates
+for program in "${programs[@]}"; do
+  if ! tree=$(cargo tree -p "$program" -e normal -f '{p} [{f}]' --prefix none); then
+    exit 1
+  fi
+
+  leaked=$(echo "$tree" | grep "$(pwd)/" | grep -E '\[([^]]*,)?client(,[^]]*)?\]' | sort -u)
+
+  if [ -n "$leaked" ]; then
+    echo "ERROR: $program on-chain build enables client features:"
+    echo "$leaked"
+    failed=true
+  fi
+done
+
+if [ "$update" == true ]; then
+  : > "$baseline"
+fi
+
+for program in "${programs[@]}"; do
+  binary="dist/program/${program//-/_}.so"
+
+  if [ ! -f "$binary" ]; then
+    echo "WARNING: $binary not found, run build.sh --programs first"
+    continue
+  fi
+
+  size=$(ls -nl "$binary" | awk '{print $5}')
+
+  if [ "$update" == true ]; then
+    echo "$program $size" >> "$baseline"
+    continue
+  fi
+
+  expected=$(awk -v p="$program" '$1 == p {print $2}' "$baseline" 2>/dev/null)
+
+  if [ -z "$expected" ]; then
+    echo "WARNING: no recorded size for $program, run with --update"
+  elif [ "$size" -gt "$expected" ]; then
+    echo "ERROR: $program grew from $expected to $size bytes"
+    failed=true
+  else
+    echo "$program: $size bytes (recorded $expected)"
+  fi
+done
+
+if [ "$failed" == true ]; then
+  exit 1
+fi
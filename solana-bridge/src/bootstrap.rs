@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+use solana_program::instruction::Instruction;
+use solana_program::message::Message;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::signature::SIGNATURE_BYTES;
+
+/// Bridge deployment description used to bootstrap devnet/testnet instances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BootstrapConfig {
+    pub round_loader: RoundLoaderConfig,
+    pub token_proxy: TokenProxyConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundLoaderConfig {
+    pub genesis_round_number: u32,
+    pub round_submitter: Pubkey,
+    pub min_required_votes: u32,
+    pub round_ttl: u32,
+    pub round_end: u32,
+    pub relays: Vec<Pubkey>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenProxyConfig {
+    pub guardian: Pubkey,
+    pub manager: Pubkey,
+    pub withdrawal_manager: Pubkey,
+    // Network discriminator deposit and withdrawal events are bound to
+    pub chain_id: u32,
+    pub tokens: Vec<TokenConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenConfig {
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub deposit_limit: u64,
+    pub withdrawal_limit: u64,
+    pub withdrawal_daily_limit: u64,
+    // Whitelist the mint even if it has a freeze authority
+    #[serde(default)]
+    pub allow_freeze_authority: bool,
+}
+
+/// Build instructions that set up both programs from scratch.
+///
+/// Signers: `funder`, `initializer` (upgrade authority of both programs) and
+/// `round_submitter`. Every instruction fails on an already initialized deployment,
+/// so the sequence can only be applied once. The instructions must be sent in a single
+/// transaction, see [`initialize_all_message`], otherwise a failure in the middle leaves
+/// a half initialized deployment that can't be bootstrapped again.
+pub fn initialize_all_ix(
+    funder_pubkey: &Pubkey,
+    initializer_pubkey: &Pubkey,
+    config: &BootstrapConfig,
+) -> Vec<Instruction> {
+    let round_loader = &config.round_loader;
+    let token_proxy = &config.token_proxy;
+
+    let mut instructions = vec![
+        round_loader::initialize_ix(
+            funder_pubkey,
+            initializer_pubkey,
+            round_loader.genesis_round_number,
+            round_loader.round_submitter,
+            round_loader.min_required_votes,
+            round_loader.round_ttl,
+        ),
+        round_loader::create_relay_round_ix(
+            funder_pubkey,
+            &round_loader.round_submitter,
+            round_loader.genesis_round_number,
+            round_loader.round_end,
+            round_loader.relays.clone(),
+        ),
+        token_proxy::initialize_settings_ix(
+            *funder_pubkey,
+            *initializer_pubkey,
+            token_proxy.guardian,
+            token_proxy.manager,
+            token_proxy.withdrawal_manager,
+            token_proxy.chain_id,
+        ),
+    ];
+
+    for token in &token_proxy.tokens {
+        instructions.push(token_proxy::create_token_settings_sol_by_owner_ix(
+            *funder_pubkey,
+            *initializer_pubkey,
+            token.mint,
+            token.name.clone(),
+            token.symbol.clone(),
+            token.deposit_limit,
+            token.withdrawal_limit,
+            token.withdrawal_daily_limit,
+            token.allow_freeze_authority,
+            None,
+        ));
+    }
+
+    instructions
+}
+
+/// Accounts created by the bootstrap sequence
+pub fn get_bootstrap_addresses(config: &BootstrapConfig) -> Vec<Pubkey> {
+    let mut addresses = vec![
+        round_loader::get_settings_address(),
+        round_loader::get_relay_round_address(config.round_loader.genesis_round_number),
+        token_proxy::get_settings_address(),
+    ];
+
+    for token in &config.token_proxy.tokens {
+        addresses.push(token_proxy::get_token_settings_sol_address(&token.mint));
+        addresses.push(token_proxy::get_vault_address(&token.mint));
+    }
+
+    addresses
+}
+
+/// Build the bootstrap sequence as a single message so that it is applied atomically.
+///
+/// Fails with `InvalidArgument` if the sequence doesn't fit a single transaction, in which
+/// case the number of tokens in the config has to be reduced and the rest created with
+/// `CreateTokenSettingsSol` after the bootstrap.
+pub fn initialize_all_message(
+    funder_pubkey: &Pubkey,
+    initializer_pubkey: &Pubkey,
+    config: &BootstrapConfig,
+) -> Result<Message, ProgramError> {
+    let instructions = initialize_all_ix(funder_pubkey, initializer_pubkey, config);
+    let message = Message::new(&instructions, Some(funder_pubkey));
+
+    // Signature count is a compact-u16 prefix, one byte for any realistic signer count
+    let transaction_size = 1
+        + message.header.num_required_signatures as usize * SIGNATURE_BYTES
+        + message.serialize().len();
+
+    if transaction_size > PACKET_DATA_SIZE {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(message)
+}
+
+/// Guard against bootstrapping twice. `exists` holds whether each address of
+/// [`get_bootstrap_addresses`] already exists on-chain, in the same order.
+pub fn check_not_initialized(exists: &[bool]) -> Result<(), ProgramError> {
+    if exists.iter().any(|exists| *exists) {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    Ok(())
+}
+
+/// Bootstrap a deployment over RPC in a single transaction. Refuses to run if any of the
+/// bootstrap accounts already exists.
+#[cfg(feature = "rpc")]
+pub fn initialize_all<T: solana_sdk::signers::Signers + ?Sized>(
+    client: &solana_client::rpc_client::RpcClient,
+    funder_pubkey: &Pubkey,
+    initializer_pubkey: &Pubkey,
+    config: &BootstrapConfig,
+    signers: &T,
+) -> Result<solana_sdk::signature::Signature, Box<dyn std::error::Error>> {
+    let accounts = client.get_multiple_accounts(&get_bootstrap_addresses(config))?;
+    check_not_initialized(
+        &accounts
+            .iter()
+            .map(|account| account.is_some())
+            .collect::<Vec<_>>(),
+    )?;
+
+    let message = initialize_all_message(funder_pubkey, initializer_pubkey, config)?;
+
+    let mut transaction = solana_sdk::transaction::Transaction::new_unsigned(message);
+    transaction.try_sign(signers, client.get_latest_blockhash()?)?;
+
+    let signature = client.send_and_confirm_transaction(&transaction)?;
+
+    Ok(signature)
+}
@@ -0,0 +1,136 @@
+//! Compute budget for bridge transactions.
+//!
+//! Transactions without a compute unit price are the first to be dropped during congestion,
+//! and the default limit of 200k units per instruction overstates what most bridge
+//! instructions need, which makes the priority fee more expensive than it has to be.
+//! `with_compute_budget` prepends a compute unit limit sized to the instructions of the
+//! transaction and an optional compute unit price.
+
+use borsh::BorshDeserialize;
+
+use solana_program::instruction::Instruction;
+use solana_sdk::compute_budget::{self, ComputeBudgetInstruction};
+
+use round_loader::RoundLoaderInstruction;
+use token_proxy::TokenProxyInstruction;
+
+/// Compute units the runtime allows a transaction to use
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Compute units the runtime gives an instruction without an explicit limit
+pub const DEFAULT_INSTRUCTION_COMPUTE_UNITS: u32 = 200_000;
+
+/// Compute units of the compute budget instructions themselves
+const COMPUTE_BUDGET_INSTRUCTION_COMPUTE_UNITS: u32 = 150;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ComputeBudget {
+    /// Compute unit price in micro-lamports, no priority fee is paid if not set
+    pub unit_price: Option<u64>,
+    /// Compute unit limit of the transaction, estimated from its instructions if not set
+    pub unit_limit: Option<u32>,
+}
+
+/// Prepend compute budget instructions to the instructions of a transaction
+pub fn with_compute_budget(
+    instructions: Vec<Instruction>,
+    budget: &ComputeBudget,
+) -> Vec<Instruction> {
+    let mut budget_instructions = Vec::with_capacity(2);
+
+    if let Some(unit_price) = budget.unit_price {
+        budget_instructions.push(ComputeBudgetInstruction::set_compute_unit_price(unit_price));
+    }
+
+    let unit_limit = budget.unit_limit.unwrap_or_else(|| {
+        get_compute_unit_limit(&instructions)
+            .saturating_add(COMPUTE_BUDGET_INSTRUCTION_COMPUTE_UNITS * 2)
+            .min(MAX_COMPUTE_UNIT_LIMIT)
+    });
+    budget_instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(unit_limit));
+
+    budget_instructions.extend(instructions);
+    budget_instructions
+}
+
+/// Compute unit limit covering all the instructions
+pub fn get_compute_unit_limit(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .map(get_instruction_compute_units)
+        .fold(0u32, |limit, units| limit.saturating_add(units))
+        .min(MAX_COMPUTE_UNIT_LIMIT)
+}
+
+/// Compute units an instruction needs with the largest account set it takes, with headroom.
+/// Instructions of programs other than the bridge ones get the runtime default.
+pub fn get_instruction_compute_units(instruction: &Instruction) -> u32 {
+    if instruction.program_id == token_proxy::id() {
+        match TokenProxyInstruction::try_from_slice(&instruction.data) {
+            Ok(instruction) => get_token_proxy_compute_units(&instruction),
+            Err(_) => DEFAULT_INSTRUCTION_COMPUTE_UNITS,
+        }
+    } else if instruction.program_id == round_loader::id() {
+        match RoundLoaderInstruction::try_from_slice(&instruction.data) {
+            Ok(instruction) => get_round_loader_compute_units(&instruction),
+            Err(_) => DEFAULT_INSTRUCTION_COMPUTE_UNITS,
+        }
+    } else if instruction.program_id == compute_budget::id() {
+        COMPUTE_BUDGET_INSTRUCTION_COMPUTE_UNITS
+    } else {
+        DEFAULT_INSTRUCTION_COMPUTE_UNITS
+    }
+}
+
+fn get_token_proxy_compute_units(instruction: &TokenProxyInstruction) -> u32 {
+    match instruction {
+        TokenProxyInstruction::VoteForWithdrawRequest { .. }
+        | TokenProxyInstruction::VoteForWithdrawRequestWithProof { .. }
+        | TokenProxyInstruction::VoteForWithdrawRequestWithIndex { .. } => 40_000,
+        TokenProxyInstruction::WithdrawMultiTokenEverRequest { .. }
+        | TokenProxyInstruction::WithdrawMultiTokenSolRequest { .. }
+        | TokenProxyInstruction::CreateWithdrawMultiTokenEverRequest { .. }
+        | TokenProxyInstruction::FinalizeWithdrawMultiTokenEverRequest { .. } => 80_000,
+        TokenProxyInstruction::WriteWithdrawMultiTokenEverRequest { .. } => 20_000,
+        TokenProxyInstruction::BackfillEventIndex => 40_000,
+        // Crank takes any number of token settings accounts
+        TokenProxyInstruction::Tick => 100_000,
+        TokenProxyInstruction::WithdrawMultiTokenSol
+        | TokenProxyInstruction::WithdrawMultiTokenSolUnwrap
+        | TokenProxyInstruction::FillWithdrawSol { .. }
+        | TokenProxyInstruction::CancelWithdrawSol { .. } => 80_000,
+        TokenProxyInstruction::WithdrawMultiTokenEver => 100_000,
+        TokenProxyInstruction::DepositMultiTokenEver { .. } => 100_000,
+        // The first deposit of a token creates its settings and vault
+        TokenProxyInstruction::DepositMultiTokenSol { .. }
+        | TokenProxyInstruction::DepositMultiTokenSolGasless { .. }
+        | TokenProxyInstruction::DepositNativeSol { .. } => 150_000,
+        TokenProxyInstruction::DepositMultiTokenBatch { .. } => 300_000,
+        TokenProxyInstruction::PreviewDeposit { .. } => 50_000,
+        // Payloads invoke arbitrary programs
+        TokenProxyInstruction::ExecutePayloadEver
+        | TokenProxyInstruction::ExecutePayloadSol
+        | TokenProxyInstruction::WithdrawProxy { .. } => 400_000,
+        _ => DEFAULT_INSTRUCTION_COMPUTE_UNITS,
+    }
+}
+
+fn get_round_loader_compute_units(instruction: &RoundLoaderInstruction) -> u32 {
+    match instruction {
+        RoundLoaderInstruction::VoteForProposal { .. }
+        | RoundLoaderInstruction::VoteForProposalWithProof { .. }
+        | RoundLoaderInstruction::VoteForProposalWithIndex { .. } => 40_000,
+        RoundLoaderInstruction::CreateProposal { .. } => 40_000,
+        RoundLoaderInstruction::WriteProposal { .. } => 20_000,
+        RoundLoaderInstruction::FinalizeProposal
+        | RoundLoaderInstruction::FinalizeDeltaProposal
+        | RoundLoaderInstruction::FinalizeUpgradeProposal => 60_000,
+        RoundLoaderInstruction::ExecuteProposal
+        | RoundLoaderInstruction::ExecuteDeltaProposal
+        | RoundLoaderInstruction::ExecuteProposalByAdmin => 80_000,
+        RoundLoaderInstruction::CreateRelayRound { .. }
+        | RoundLoaderInstruction::CreateMerkleRelayRound { .. }
+        | RoundLoaderInstruction::ExportRoundDigest => 40_000,
+        _ => DEFAULT_INSTRUCTION_COMPUTE_UNITS,
+    }
+}
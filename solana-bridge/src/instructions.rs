@@ -50,18 +50,25 @@ pub fn vote_for_proposal_ix(
     }
 }
 
+/// Accounts created by the execution, e.g. settings of a token deployed by the payout, are paid
+/// by the funder, if any. It follows the other accounts.
 pub fn execute_proposal_ix(
     program_id: Pubkey,
     instruction: u8,
+    funder_pubkey: Option<Pubkey>,
     proposal_pubkey: Pubkey,
     accounts: Vec<(Pubkey, bool, bool)>,
 ) -> Instruction {
     let data = ExecuteProposal { instruction }.try_to_vec().expect("pack");
 
-    let mut accounts_with_meta = Vec::with_capacity(accounts.len() + 1);
+    let mut accounts_with_meta = Vec::with_capacity(accounts.len() + 2);
     accounts_with_meta.push((proposal_pubkey, false, false));
     accounts_with_meta.extend(accounts);
 
+    if let Some(funder_pubkey) = funder_pubkey {
+        accounts_with_meta.push((funder_pubkey, false, true));
+    }
+
     let accounts = accounts_with_meta
         .into_iter()
         .map(|(account, read_only, is_signer)| {
@@ -110,9 +117,11 @@ pub fn execute_payload_ix(
     }
 }
 
+/// Rent of the withdrawal is paid by the funder, so it can sponsor requests of the creator
 #[allow(clippy::too_many_arguments)]
 pub fn withdrawal_ever_request_ix(
     program_id: Pubkey,
+    funder_pubkey: Pubkey,
     creator_pubkey: Pubkey,
     withdrawal_pubkey: Pubkey,
     round_number: u32,
@@ -122,15 +131,24 @@ pub fn withdrawal_ever_request_ix(
     event: token_proxy::WithdrawalMultiTokenEverEvent,
     attached_amount: u64,
 ) -> Instruction {
+    let settings_pubkey = bridge_utils::helper::get_associated_settings_address(&program_id);
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
     let relay_round_pubkey =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
+    let event_index_pubkey = token_proxy::get_event_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+    );
+
     let mut accounts = vec![
-        AccountMeta::new(creator_pubkey, true),
+        AccountMeta::new(funder_pubkey, true),
         AccountMeta::new(creator_pubkey, true),
         AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(event_index_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
         AccountMeta::new_readonly(rl_settings_pubkey, false),
         AccountMeta::new_readonly(relay_round_pubkey, false),
         AccountMeta::new_readonly(system_program::id(), false),
@@ -147,6 +165,25 @@ pub fn withdrawal_ever_request_ix(
         accounts.push(AccountMeta::new(spl_token::id(), false));
     }
 
+    let token_settings_pubkey =
+        token_proxy::get_associated_token_settings_ever_address(&program_id, &event.token);
+    accounts.push(AccountMeta::new_readonly(token_settings_pubkey, false));
+
+    let known_token_pubkey =
+        token_proxy::get_associated_known_token_address(&program_id, &event.token);
+    accounts.push(AccountMeta::new_readonly(known_token_pubkey, false));
+
+    let rl_event_configuration_pubkey =
+        round_loader::get_event_configuration_address(&event_configuration);
+    accounts.push(AccountMeta::new_readonly(
+        rl_event_configuration_pubkey,
+        false,
+    ));
+
+    let relay_round_stats_pubkey =
+        token_proxy::get_associated_relay_round_stats_address(&program_id, round_number);
+    accounts.push(AccountMeta::new(relay_round_stats_pubkey, false));
+
     let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenEverRequest {
         attached_amount,
         event_timestamp,
@@ -170,9 +207,11 @@ pub fn withdrawal_ever_request_ix(
     }
 }
 
+/// SOL counterpart of [`withdrawal_ever_request_ix`]
 #[allow(clippy::too_many_arguments)]
 pub fn withdrawal_sol_request_ix(
     program_id: Pubkey,
+    funder_pubkey: Pubkey,
     creator_pubkey: Pubkey,
     withdrawal_pubkey: Pubkey,
     round_number: u32,
@@ -184,17 +223,26 @@ pub fn withdrawal_sol_request_ix(
 ) -> Instruction {
     let token_settings_pubkey =
         token_proxy::get_associated_token_settings_sol_address(&program_id, &event.mint);
+    let settings_pubkey = bridge_utils::helper::get_associated_settings_address(&program_id);
 
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
     let relay_round_pubkey =
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
+    let event_index_pubkey = token_proxy::get_event_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+    );
+
     let mut accounts = vec![
-        AccountMeta::new(creator_pubkey, true),
+        AccountMeta::new(funder_pubkey, true),
         AccountMeta::new(creator_pubkey, true),
         AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(event_index_pubkey, false),
         AccountMeta::new_readonly(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
         AccountMeta::new_readonly(rl_settings_pubkey, false),
         AccountMeta::new_readonly(relay_round_pubkey, false),
         AccountMeta::new_readonly(system_program::id(), false),
@@ -210,6 +258,17 @@ pub fn withdrawal_sol_request_ix(
         accounts.push(AccountMeta::new(spl_token::id(), false));
     }
 
+    let rl_event_configuration_pubkey =
+        round_loader::get_event_configuration_address(&event_configuration);
+    accounts.push(AccountMeta::new_readonly(
+        rl_event_configuration_pubkey,
+        false,
+    ));
+
+    let relay_round_stats_pubkey =
+        token_proxy::get_associated_relay_round_stats_address(&program_id, round_number);
+    accounts.push(AccountMeta::new(relay_round_stats_pubkey, false));
+
     let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenSolRequest {
         attached_amount,
         event_timestamp,
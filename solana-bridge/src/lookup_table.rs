@@ -0,0 +1,139 @@
+//! Address Lookup Tables for bridge transactions.
+//!
+//! Withdrawal and vote instructions reference the same static accounts over and over
+//! (settings, relay rounds, vaults, sysvars). Putting them into a lookup table replaces
+//! each 32 byte key with a 1 byte index in a `v0` transaction, which leaves room for
+//! more instructions per transaction in batch operations.
+
+use std::collections::BTreeSet;
+
+use solana_program::address_lookup_table::instruction::{create_lookup_table, extend_lookup_table};
+use solana_program::address_lookup_table::state::{AddressLookupTable, LOOKUP_TABLE_MAX_ADDRESSES};
+use solana_program::address_lookup_table::AddressLookupTableAccount;
+use solana_program::clock::Slot;
+use solana_program::hash::Hash;
+use solana_program::instruction::Instruction;
+use solana_program::message::{v0, VersionedMessage};
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
+use solana_sdk::signers::Signers;
+use solana_sdk::transaction::VersionedTransaction;
+
+/// Addresses an extend instruction carries, small enough to fit a legacy transaction
+pub const MAX_EXTEND_ADDRESSES: usize = 20;
+
+/// Static accounts of the bridge programs worth keeping in a lookup table
+pub fn get_bridge_lookup_table_addresses(mints: &[Pubkey], round_numbers: &[u32]) -> Vec<Pubkey> {
+    let mut addresses = vec![
+        token_proxy::id(),
+        round_loader::id(),
+        system_program::id(),
+        spl_token::id(),
+        sysvar::rent::id(),
+        sysvar::clock::id(),
+        token_proxy::get_settings_address(),
+        token_proxy::get_multivault_address(),
+        round_loader::get_settings_address(),
+    ];
+
+    for round_number in round_numbers {
+        addresses.push(round_loader::get_relay_round_address(*round_number));
+    }
+
+    for mint in mints {
+        addresses.push(*mint);
+        addresses.push(token_proxy::get_token_settings_sol_address(mint));
+        addresses.push(token_proxy::get_vault_address(mint));
+    }
+
+    addresses
+}
+
+/// Create a lookup table owned by the authority, returns the instruction and the table address
+pub fn create_lookup_table_ix(
+    authority_pubkey: &Pubkey,
+    funder_pubkey: &Pubkey,
+    recent_slot: Slot,
+) -> (Instruction, Pubkey) {
+    create_lookup_table(*authority_pubkey, *funder_pubkey, recent_slot)
+}
+
+/// Add the addresses the table doesn't hold yet. Returns an instruction per
+/// `MAX_EXTEND_ADDRESSES`, each one to be sent in its own transaction.
+pub fn extend_lookup_table_ix(
+    lookup_table: &AddressLookupTableAccount,
+    authority_pubkey: &Pubkey,
+    funder_pubkey: &Pubkey,
+    addresses: &[Pubkey],
+) -> Result<Vec<Instruction>, ProgramError> {
+    let mut known = lookup_table.addresses.iter().collect::<BTreeSet<_>>();
+
+    let missing = addresses
+        .iter()
+        .filter(|address| known.insert(*address))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if lookup_table.addresses.len() + missing.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let instructions = missing
+        .chunks(MAX_EXTEND_ADDRESSES)
+        .map(|chunk| {
+            extend_lookup_table(
+                lookup_table.key,
+                *authority_pubkey,
+                Some(*funder_pubkey),
+                chunk.to_vec(),
+            )
+        })
+        .collect();
+
+    Ok(instructions)
+}
+
+/// Decode lookup table account data
+pub fn unpack_lookup_table(
+    key: &Pubkey,
+    data: &[u8],
+) -> Result<AddressLookupTableAccount, ProgramError> {
+    let lookup_table =
+        AddressLookupTable::deserialize(data).map_err(|_| ProgramError::InvalidAccountData)?;
+
+    Ok(AddressLookupTableAccount {
+        key: *key,
+        addresses: lookup_table.addresses.to_vec(),
+    })
+}
+
+/// Build and sign a `v0` transaction resolving accounts through the lookup tables.
+/// Signers and accounts missing from the tables are kept in the static keys.
+pub fn build_versioned_transaction<T: Signers + ?Sized>(
+    payer_pubkey: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+    signers: &T,
+) -> Result<VersionedTransaction, Box<dyn std::error::Error>> {
+    let message =
+        v0::Message::try_compile(payer_pubkey, instructions, lookup_tables, recent_blockhash)?;
+
+    let transaction = VersionedTransaction::try_new(VersionedMessage::V0(message), signers)?;
+
+    Ok(transaction)
+}
+
+/// Fetch a lookup table over RPC
+#[cfg(feature = "rpc")]
+pub fn fetch_lookup_table(
+    client: &solana_client::rpc_client::RpcClient,
+    lookup_table_pubkey: &Pubkey,
+) -> Result<AddressLookupTableAccount, Box<dyn std::error::Error>> {
+    let account = client.get_account(lookup_table_pubkey)?;
+
+    let lookup_table = unpack_lookup_table(lookup_table_pubkey, &account.data)?;
+
+    Ok(lookup_table)
+}
@@ -0,0 +1,269 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use bridge_indexer::{decode_account, BridgeAccount};
+use bridge_utils::limit::LimitTracker;
+use bridge_utils::types::Vote;
+
+use solana_program::pubkey::Pubkey;
+
+use token_proxy::{TokenKind, WithdrawalTokenStatus};
+
+/// Bridge health metrics
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Metrics {
+    /// Withdrawals that are neither processed nor cancelled
+    pub pending_withdrawals: u64,
+    /// Age in seconds of the oldest pending withdrawal, measured from the EVER event timestamp
+    pub oldest_pending_age: Option<i64>,
+    /// Daily withdrawal volume per mint
+    pub daily_volume: BTreeMap<Pubkey, DailyVolume>,
+    /// Relay vote participation per round
+    pub vote_participation: BTreeMap<u32, VoteParticipation>,
+    /// Proposal deadline of the latest relay round
+    pub round_deadline: Option<RoundDeadline>,
+    /// Bridge program accounts that could not be decoded
+    pub decode_errors: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DailyVolume {
+    pub amount: u64,
+    pub limit: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VoteParticipation {
+    /// Votes that could have been cast
+    pub expected: u64,
+    /// Votes that were actually cast
+    pub cast: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RoundDeadline {
+    pub round_number: u32,
+    pub round_end: u32,
+    pub suggested_proposal_deadline: u32,
+    /// Whether a proposal of the next round exists
+    pub next_round_proposed: bool,
+}
+
+impl RoundDeadline {
+    /// The deadline has passed and nobody has proposed the next round yet
+    pub fn is_overdue(&self, now: i64) -> bool {
+        !self.next_round_proposed && now >= self.suggested_proposal_deadline as i64
+    }
+}
+
+impl VoteParticipation {
+    pub fn ratio(&self) -> f64 {
+        if self.expected == 0 {
+            return 0.0;
+        }
+        self.cast as f64 / self.expected as f64
+    }
+}
+
+impl Metrics {
+    /// Compute metrics from raw `(owner, data)` pairs of bridge program accounts.
+    ///
+    /// Accounts that fail to decode are skipped and counted in `decode_errors`, so a single
+    /// account of an unknown or outdated layout does not hide the rest of the metrics.
+    pub fn from_accounts<'a, I>(now: i64, accounts: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a Pubkey, &'a [u8])>,
+    {
+        let mut metrics = Metrics::default();
+        let mut proposed_rounds = BTreeSet::new();
+
+        let current_epoch = LimitTracker::epoch_of(now);
+
+        for (owner, data) in accounts {
+            let account = match decode_account(owner, data) {
+                Ok(account) => account,
+                Err(_) => {
+                    metrics.decode_errors += 1;
+                    continue;
+                }
+            };
+
+            match account {
+                Some(BridgeAccount::WithdrawalMultiTokenEver(withdrawal)) => metrics
+                    .add_withdrawal(
+                        now,
+                        withdrawal.round_number,
+                        withdrawal.pda.event_timestamp,
+                        withdrawal.meta.data.status,
+                        &withdrawal.signers,
+                    ),
+                Some(BridgeAccount::WithdrawalMultiTokenSol(withdrawal)) => metrics.add_withdrawal(
+                    now,
+                    withdrawal.round_number,
+                    withdrawal.pda.event_timestamp,
+                    withdrawal.meta.data.status,
+                    &withdrawal.signers,
+                ),
+                Some(BridgeAccount::TokenSettings(token_settings)) => {
+                    let mint = match token_settings.kind {
+                        TokenKind::Ever { mint, .. } => mint,
+                        TokenKind::Solana { mint, .. } => mint,
+                    };
+
+                    let amount = if token_settings.withdrawal_epoch >= current_epoch {
+                        token_settings.withdrawal_daily_amount
+                    } else {
+                        0
+                    };
+
+                    metrics.daily_volume.insert(
+                        mint,
+                        DailyVolume {
+                            amount,
+                            limit: token_settings.withdrawal_daily_limit,
+                        },
+                    );
+                }
+                Some(BridgeAccount::RelayRound(relay_round)) => {
+                    let is_latest = metrics.round_deadline.as_ref().map_or(true, |deadline| {
+                        deadline.round_number < relay_round.round_number
+                    });
+
+                    if is_latest {
+                        metrics.round_deadline = Some(RoundDeadline {
+                            round_number: relay_round.round_number,
+                            round_end: relay_round.round_end,
+                            suggested_proposal_deadline: relay_round.suggested_proposal_deadline,
+                            next_round_proposed: false,
+                        });
+                    }
+                }
+                Some(BridgeAccount::RelayRoundProposal(proposal)) => {
+                    proposed_rounds.insert(proposal.event.data.round_num);
+                }
+                Some(BridgeAccount::RelayRoundDeltaProposal(proposal)) => {
+                    proposed_rounds.insert(proposal.event.data.round_num);
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(deadline) = metrics.round_deadline.as_mut() {
+            deadline.next_round_proposed =
+                proposed_rounds.contains(&deadline.round_number.saturating_add(1));
+        }
+
+        metrics
+    }
+
+    fn add_withdrawal(
+        &mut self,
+        now: i64,
+        round_number: u32,
+        event_timestamp: u32,
+        status: WithdrawalTokenStatus,
+        signers: &[Vote],
+    ) {
+        let participation = self.vote_participation.entry(round_number).or_default();
+        participation.expected += signers.len() as u64;
+        participation.cast += signers.iter().filter(|vote| **vote != Vote::None).count() as u64;
+
+        if status == WithdrawalTokenStatus::Processed || status == WithdrawalTokenStatus::Cancelled
+        {
+            return;
+        }
+
+        self.pending_withdrawals += 1;
+
+        let age = now - event_timestamp as i64;
+        self.oldest_pending_age = Some(self.oldest_pending_age.map_or(age, |a| a.max(age)));
+    }
+
+    /// Encode metrics in the Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# TYPE bridge_pending_withdrawals gauge");
+        let _ = writeln!(
+            out,
+            "bridge_pending_withdrawals {}",
+            self.pending_withdrawals
+        );
+
+        let _ = writeln!(out, "# TYPE bridge_oldest_pending_age_seconds gauge");
+        let _ = writeln!(
+            out,
+            "bridge_oldest_pending_age_seconds {}",
+            self.oldest_pending_age.unwrap_or_default()
+        );
+
+        let _ = writeln!(out, "# TYPE bridge_daily_withdrawal_volume gauge");
+        for (mint, volume) in &self.daily_volume {
+            let _ = writeln!(
+                out,
+                "bridge_daily_withdrawal_volume{{mint=\"{}\"}} {}",
+                mint, volume.amount
+            );
+        }
+
+        let _ = writeln!(out, "# TYPE bridge_daily_withdrawal_limit gauge");
+        for (mint, volume) in &self.daily_volume {
+            let _ = writeln!(
+                out,
+                "bridge_daily_withdrawal_limit{{mint=\"{}\"}} {}",
+                mint, volume.limit
+            );
+        }
+
+        let _ = writeln!(out, "# TYPE bridge_vote_participation gauge");
+        for (round_number, participation) in &self.vote_participation {
+            let _ = writeln!(
+                out,
+                "bridge_vote_participation{{round=\"{}\"}} {}",
+                round_number,
+                participation.ratio()
+            );
+        }
+
+        let _ = writeln!(out, "# TYPE bridge_decode_errors gauge");
+        let _ = writeln!(out, "bridge_decode_errors {}", self.decode_errors);
+
+        if let Some(deadline) = &self.round_deadline {
+            let _ = writeln!(out, "# TYPE bridge_round_proposal_deadline gauge");
+            let _ = writeln!(
+                out,
+                "bridge_round_proposal_deadline{{round=\"{}\"}} {}",
+                deadline.round_number, deadline.suggested_proposal_deadline
+            );
+
+            let _ = writeln!(out, "# TYPE bridge_next_round_proposed gauge");
+            let _ = writeln!(
+                out,
+                "bridge_next_round_proposed{{round=\"{}\"}} {}",
+                deadline.round_number, deadline.next_round_proposed as u8
+            );
+        }
+
+        out
+    }
+}
+
+/// Fetch all Token Proxy and Round Loader accounts over RPC and compute metrics
+#[cfg(feature = "rpc")]
+pub fn fetch_metrics(
+    client: &solana_client::rpc_client::RpcClient,
+) -> Result<Metrics, Box<dyn std::error::Error>> {
+    let now = client.get_block_time(client.get_slot()?)?;
+
+    let mut accounts = client.get_program_accounts(&token_proxy::id())?;
+    accounts.extend(client.get_program_accounts(&round_loader::id())?);
+
+    let metrics = Metrics::from_accounts(
+        now,
+        accounts
+            .iter()
+            .map(|(_, account)| (&account.owner, account.data.as_slice())),
+    );
+
+    Ok(metrics)
+}
@@ -0,0 +1,79 @@
+use solana_bridge::bootstrap::*;
+
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+fn config(tokens: usize) -> BootstrapConfig {
+    BootstrapConfig {
+        round_loader: RoundLoaderConfig {
+            genesis_round_number: 0,
+            round_submitter: Pubkey::new_unique(),
+            min_required_votes: 1,
+            round_ttl: 3600,
+            round_end: 1_000_000,
+            relays: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        },
+        token_proxy: TokenProxyConfig {
+            guardian: Pubkey::new_unique(),
+            manager: Pubkey::new_unique(),
+            withdrawal_manager: Pubkey::new_unique(),
+            chain_id: 1,
+            tokens: (0..tokens)
+                .map(|_| TokenConfig {
+                    mint: Pubkey::new_unique(),
+                    name: "USDT".to_string(),
+                    symbol: "USDT".to_string(),
+                    deposit_limit: u64::MAX,
+                    withdrawal_limit: u64::MAX,
+                    withdrawal_daily_limit: u64::MAX,
+                    allow_freeze_authority: false,
+                })
+                .collect(),
+        },
+    }
+}
+
+#[test]
+fn test_initialize_all_message() {
+    let funder = Pubkey::new_unique();
+    let initializer = Pubkey::new_unique();
+    let config = config(1);
+
+    let message = initialize_all_message(&funder, &initializer, &config).unwrap();
+
+    // Everything goes in one transaction
+    assert_eq!(message.instructions.len(), 4);
+    assert_eq!(message.account_keys[0], funder);
+    assert_eq!(message.header.num_required_signatures, 3);
+
+    for address in get_bootstrap_addresses(&config) {
+        assert!(message.account_keys.contains(&address));
+    }
+}
+
+#[test]
+fn test_initialize_all_message_too_large() {
+    let funder = Pubkey::new_unique();
+    let initializer = Pubkey::new_unique();
+
+    // Sequence is never split across transactions
+    let err = initialize_all_message(&funder, &initializer, &config(20)).unwrap_err();
+    assert_eq!(err, ProgramError::InvalidArgument);
+}
+
+#[test]
+fn test_check_not_initialized() {
+    let config = config(1);
+    let addresses = get_bootstrap_addresses(&config);
+    assert_eq!(addresses.len(), 5);
+
+    check_not_initialized(&vec![false; addresses.len()]).unwrap();
+
+    // Any account left by a previous run blocks another one
+    let mut exists = vec![false; addresses.len()];
+    exists[2] = true;
+    assert_eq!(
+        check_not_initialized(&exists).unwrap_err(),
+        ProgramError::AccountAlreadyInitialized
+    );
+}
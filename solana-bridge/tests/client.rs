@@ -0,0 +1,58 @@
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::{EverAddress, Vote};
+
+use token_proxy::{WithdrawalMultiTokenSolEventWithLen, WithdrawalTokenStatus};
+
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_client_serde_round_trip() {
+    let address = EverAddress::with_standart(-1, [7; 32]);
+    let json = serde_json::to_value(address).unwrap();
+    assert_eq!(json["AddrStd"]["workchain_id"], -1);
+    assert_eq!(
+        serde_json::from_value::<EverAddress>(json).unwrap(),
+        address
+    );
+
+    let kind = AccountKind::Settings(254, 253);
+    let json = serde_json::to_string(&kind).unwrap();
+    assert_eq!(serde_json::from_str::<AccountKind>(&json).unwrap(), kind);
+
+    let vote: Vote = serde_json::from_str("\"Confirm\"").unwrap();
+    assert_eq!(vote, Vote::Confirm);
+
+    let status: WithdrawalTokenStatus = serde_json::from_str("\"Processed\"").unwrap();
+    assert_eq!(status, WithdrawalTokenStatus::Processed);
+
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let event = WithdrawalMultiTokenSolEventWithLen::new(0, mint, 100, recipient, vec![1, 2]);
+
+    let json = serde_json::to_string(&event).unwrap();
+    let decoded: WithdrawalMultiTokenSolEventWithLen = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.len, event.len);
+    assert_eq!(decoded.data.mint, mint);
+    assert_eq!(decoded.data.recipient, recipient);
+    assert_eq!(decoded.data.amount, 100);
+    assert_eq!(decoded.data.payload, vec![1, 2]);
+}
+
+#[test]
+fn test_client_serde_rejects_malformed() {
+    // Address must be exactly 32 bytes
+    let short = serde_json::json!({
+        "AddrStd": { "workchain_id": 0, "address": vec![7u8; 31] }
+    });
+    assert!(serde_json::from_value::<EverAddress>(short).is_err());
+
+    // Unknown variants are not mapped to a default
+    assert!(serde_json::from_str::<Vote>("\"Abstain\"").is_err());
+    assert!(serde_json::from_str::<WithdrawalTokenStatus>("\"Finalized\"").is_err());
+
+    // Workchain id doesn't fit i8
+    let wide = serde_json::json!({
+        "AddrStd": { "workchain_id": 128, "address": vec![7u8; 32] }
+    });
+    assert!(serde_json::from_value::<EverAddress>(wide).is_err());
+}
@@ -0,0 +1,99 @@
+use borsh::BorshDeserialize;
+use solana_bridge::compute_budget::*;
+
+use round_loader::RoundLoaderInstruction;
+use token_proxy::TokenProxyInstruction;
+
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::{self, ComputeBudgetInstruction};
+
+fn token_proxy_ix(instruction: TokenProxyInstruction) -> Instruction {
+    Instruction::new_with_borsh(token_proxy::id(), &instruction, vec![])
+}
+
+fn round_loader_ix(instruction: RoundLoaderInstruction) -> Instruction {
+    Instruction::new_with_borsh(round_loader::id(), &instruction, vec![])
+}
+
+fn budget_instruction(instruction: &Instruction) -> ComputeBudgetInstruction {
+    assert_eq!(instruction.program_id, compute_budget::id());
+    ComputeBudgetInstruction::try_from_slice(&instruction.data).expect("compute budget unpack")
+}
+
+#[test]
+fn test_with_compute_budget() {
+    let instructions = vec![
+        token_proxy_ix(TokenProxyInstruction::WithdrawMultiTokenSol),
+        round_loader_ix(RoundLoaderInstruction::ExecuteProposal),
+    ];
+
+    assert_eq!(get_compute_unit_limit(&instructions), 160_000);
+
+    let budget = ComputeBudget {
+        unit_price: Some(1_000),
+        unit_limit: None,
+    };
+    let with_budget = with_compute_budget(instructions.clone(), &budget);
+
+    // Price and limit go first, the bridge instructions keep their order
+    assert_eq!(with_budget.len(), 4);
+    assert_eq!(
+        budget_instruction(&with_budget[0]),
+        ComputeBudgetInstruction::SetComputeUnitPrice(1_000)
+    );
+    assert_eq!(
+        budget_instruction(&with_budget[1]),
+        ComputeBudgetInstruction::SetComputeUnitLimit(160_300)
+    );
+    assert_eq!(with_budget[2..], instructions[..]);
+
+    // Explicit limit is taken as is
+    let budget = ComputeBudget {
+        unit_price: None,
+        unit_limit: Some(50_000),
+    };
+    let with_budget = with_compute_budget(instructions, &budget);
+
+    assert_eq!(with_budget.len(), 3);
+    assert_eq!(
+        budget_instruction(&with_budget[0]),
+        ComputeBudgetInstruction::SetComputeUnitLimit(50_000)
+    );
+}
+
+#[test]
+fn test_compute_units_of_unknown_instructions() {
+    // Data that isn't an instruction of the program
+    let garbage = Instruction::new_with_bytes(token_proxy::id(), &[0xff; 4], vec![]);
+    assert_eq!(
+        get_instruction_compute_units(&garbage),
+        DEFAULT_INSTRUCTION_COMPUTE_UNITS
+    );
+
+    let garbage = Instruction::new_with_bytes(round_loader::id(), &[], vec![]);
+    assert_eq!(
+        get_instruction_compute_units(&garbage),
+        DEFAULT_INSTRUCTION_COMPUTE_UNITS
+    );
+
+    // Instructions of other programs
+    let foreign = Instruction::new_with_bytes(Pubkey::new_unique(), &[0; 4], vec![]);
+    assert_eq!(
+        get_instruction_compute_units(&foreign),
+        DEFAULT_INSTRUCTION_COMPUTE_UNITS
+    );
+
+    // Limit never exceeds what the runtime allows
+    let instructions = vec![token_proxy_ix(TokenProxyInstruction::ExecutePayloadSol); 8];
+    assert_eq!(
+        get_compute_unit_limit(&instructions),
+        MAX_COMPUTE_UNIT_LIMIT
+    );
+
+    let with_budget = with_compute_budget(instructions, &ComputeBudget::default());
+    assert_eq!(
+        budget_instruction(&with_budget[0]),
+        ComputeBudgetInstruction::SetComputeUnitLimit(MAX_COMPUTE_UNIT_LIMIT)
+    );
+}
@@ -0,0 +1,108 @@
+use bridge_utils::types::EverAddress;
+use solana_bridge::instructions::*;
+
+use solana_program::message::Message;
+use solana_program::pubkey::Pubkey;
+
+#[test]
+fn test_withdrawal_request_funder() {
+    let funder = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let withdrawal = Pubkey::new_unique();
+
+    let event = token_proxy::WithdrawalMultiTokenEverEvent {
+        chain_id: 1,
+        token: EverAddress::with_standart(0, [1; 32]),
+        name: "USDT".to_string(),
+        symbol: "USDT".to_string(),
+        decimals: 6,
+        amount: 100,
+        recipient: Pubkey::new_unique(),
+        payload: vec![],
+    };
+
+    let ix = withdrawal_ever_request_ix(
+        token_proxy::id(),
+        funder,
+        creator,
+        withdrawal,
+        1,
+        1650988297,
+        1650988334,
+        Pubkey::new_unique(),
+        event,
+        0,
+    );
+
+    // Funder pays rent, the creator stays the author of the withdrawal
+    assert_eq!(ix.accounts[0].pubkey, funder);
+    assert!(ix.accounts[0].is_signer && ix.accounts[0].is_writable);
+    assert_eq!(ix.accounts[1].pubkey, creator);
+    assert!(ix.accounts[1].is_signer);
+    assert_eq!(ix.accounts[2].pubkey, withdrawal);
+
+    // Both sign the transaction, the funder pays its fee
+    let message = Message::new(&[ix], Some(&funder));
+    assert_eq!(message.header.num_required_signatures, 2);
+    assert_eq!(message.account_keys[0], funder);
+
+    let event = token_proxy::WithdrawalMultiTokenSolEvent {
+        chain_id: 1,
+        mint: Pubkey::new_unique(),
+        amount: 100,
+        recipient: Pubkey::new_unique(),
+        payload: vec![],
+    };
+
+    let ix = withdrawal_sol_request_ix(
+        token_proxy::id(),
+        funder,
+        creator,
+        withdrawal,
+        1,
+        1650988297,
+        1650988334,
+        Pubkey::new_unique(),
+        event,
+        0,
+    );
+
+    assert_eq!(ix.accounts[0].pubkey, funder);
+    assert!(ix.accounts[0].is_signer && ix.accounts[0].is_writable);
+    assert_eq!(ix.accounts[1].pubkey, creator);
+    assert!(ix.accounts[1].is_signer);
+}
+
+#[test]
+fn test_execute_proposal_funder() {
+    let funder = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    let ix = execute_proposal_ix(
+        token_proxy::id(),
+        0,
+        Some(funder),
+        proposal,
+        vec![(account, true, false)],
+    );
+
+    assert_eq!(ix.accounts.len(), 3);
+    assert_eq!(ix.accounts[0].pubkey, proposal);
+    assert_eq!(ix.accounts[1].pubkey, account);
+    assert!(!ix.accounts[1].is_writable);
+    assert_eq!(ix.accounts[2].pubkey, funder);
+    assert!(ix.accounts[2].is_signer && ix.accounts[2].is_writable);
+
+    // Nothing is created, nobody pays
+    let ix = execute_proposal_ix(
+        token_proxy::id(),
+        0,
+        None,
+        proposal,
+        vec![(account, true, false)],
+    );
+
+    assert_eq!(ix.accounts.len(), 2);
+    assert!(ix.accounts.iter().all(|meta| !meta.is_signer));
+}
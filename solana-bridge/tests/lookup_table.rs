@@ -0,0 +1,187 @@
+use solana_bridge::lookup_table::*;
+
+use solana_program::address_lookup_table::state::{
+    AddressLookupTable, LookupTableMeta, LOOKUP_TABLE_MAX_ADDRESSES, LOOKUP_TABLE_META_SIZE,
+};
+use solana_program::address_lookup_table::AddressLookupTableAccount;
+use solana_program::hash::Hash;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::message::VersionedMessage;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+fn lookup_table(addresses: Vec<Pubkey>) -> AddressLookupTableAccount {
+    AddressLookupTableAccount {
+        key: Pubkey::new_unique(),
+        addresses,
+    }
+}
+
+fn contains(instruction: &Instruction, address: &Pubkey) -> bool {
+    instruction
+        .data
+        .windows(32)
+        .any(|window| window == address.as_ref())
+}
+
+#[test]
+fn test_extend_lookup_table() {
+    let authority = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+
+    let mint = Pubkey::new_unique();
+    let bridge_addresses = get_bridge_lookup_table_addresses(&[mint], &[7]);
+    assert_eq!(bridge_addresses.len(), 13);
+    assert!(bridge_addresses.contains(&token_proxy::get_vault_address(&mint)));
+
+    // Table already holds the first two addresses
+    let table = lookup_table(bridge_addresses[..2].to_vec());
+
+    let mut addresses = bridge_addresses.clone();
+    addresses.extend((0..12).map(|_| Pubkey::new_unique()));
+    // Duplicates are added once
+    addresses.push(bridge_addresses[5]);
+
+    let instructions = extend_lookup_table_ix(&table, &authority, &funder, &addresses).unwrap();
+
+    // 23 missing addresses take two transactions
+    assert_eq!(instructions.len(), 2);
+
+    for instruction in &instructions {
+        assert_eq!(instruction.accounts[0].pubkey, table.key);
+        assert_eq!(instruction.accounts[1].pubkey, authority);
+        assert!(instruction.accounts[1].is_signer);
+    }
+
+    for address in &addresses[2..] {
+        let count = instructions
+            .iter()
+            .filter(|instruction| contains(instruction, address))
+            .count();
+        assert_eq!(count, 1);
+    }
+
+    for address in &table.addresses {
+        assert!(!instructions
+            .iter()
+            .any(|instruction| contains(instruction, address)));
+    }
+
+    // Nothing to add to a complete table
+    let table = lookup_table(bridge_addresses.clone());
+    let instructions =
+        extend_lookup_table_ix(&table, &authority, &funder, &bridge_addresses).unwrap();
+    assert!(instructions.is_empty());
+}
+
+#[test]
+fn test_extend_full_lookup_table() {
+    let authority = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+
+    let table = lookup_table(
+        (0..LOOKUP_TABLE_MAX_ADDRESSES - 1)
+            .map(|_| Pubkey::new_unique())
+            .collect(),
+    );
+
+    let instructions =
+        extend_lookup_table_ix(&table, &authority, &funder, &[Pubkey::new_unique()]).unwrap();
+    assert_eq!(instructions.len(), 1);
+
+    // Table can't take more addresses than the runtime allows
+    let err = extend_lookup_table_ix(
+        &table,
+        &authority,
+        &funder,
+        &[Pubkey::new_unique(), Pubkey::new_unique()],
+    )
+    .unwrap_err();
+    assert_eq!(err, ProgramError::InvalidArgument);
+}
+
+#[test]
+fn test_unpack_lookup_table() {
+    let key = Pubkey::new_unique();
+    let addresses = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let mut data = vec![0; LOOKUP_TABLE_META_SIZE];
+    AddressLookupTable::overwrite_meta_data(&mut data, LookupTableMeta::default()).unwrap();
+    for address in &addresses {
+        data.extend_from_slice(address.as_ref());
+    }
+
+    let table = unpack_lookup_table(&key, &data).unwrap();
+    assert_eq!(table.key, key);
+    assert_eq!(table.addresses, addresses);
+
+    // Truncated address
+    assert_eq!(
+        unpack_lookup_table(&key, &data[..data.len() - 1]).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+
+    // Not a lookup table at all
+    assert_eq!(
+        unpack_lookup_table(&key, &[0xff; 8]).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+}
+
+#[test]
+fn test_build_versioned_transaction() {
+    let payer = Keypair::new();
+    let authority = Keypair::new();
+
+    let mint = Pubkey::new_unique();
+    let table = lookup_table(get_bridge_lookup_table_addresses(&[mint], &[7]));
+
+    let instruction = Instruction::new_with_bytes(
+        token_proxy::id(),
+        &[1, 2, 3],
+        vec![
+            AccountMeta::new_readonly(authority.pubkey(), true),
+            AccountMeta::new(token_proxy::get_vault_address(&mint), false),
+            AccountMeta::new_readonly(token_proxy::get_settings_address(), false),
+        ],
+    );
+
+    let transaction = build_versioned_transaction(
+        &payer.pubkey(),
+        &[instruction.clone()],
+        &[table.clone()],
+        Hash::default(),
+        &[&payer, &authority],
+    )
+    .unwrap();
+
+    assert_eq!(transaction.signatures.len(), 2);
+
+    let message = match transaction.message {
+        VersionedMessage::V0(message) => message,
+        _ => panic!("expected v0 message"),
+    };
+
+    // Signers stay static, accounts of the table are looked up
+    assert!(message.account_keys.contains(&payer.pubkey()));
+    assert!(message.account_keys.contains(&authority.pubkey()));
+    assert!(!message
+        .account_keys
+        .contains(&token_proxy::get_vault_address(&mint)));
+
+    assert_eq!(message.address_table_lookups.len(), 1);
+    assert_eq!(message.address_table_lookups[0].account_key, table.key);
+    assert_eq!(message.address_table_lookups[0].writable_indexes.len(), 1);
+    assert_eq!(message.address_table_lookups[0].readonly_indexes.len(), 1);
+
+    // Every required signer has to sign
+    assert!(build_versioned_transaction(
+        &payer.pubkey(),
+        &[instruction],
+        &[table],
+        Hash::default(),
+        &[&payer],
+    )
+    .is_err());
+}
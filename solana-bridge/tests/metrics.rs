@@ -0,0 +1,222 @@
+use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::types::Vote;
+use solana_bridge::metrics::{Metrics, RoundDeadline};
+
+use round_loader::{
+    RelayRound, RelayRoundProposal, RelayRoundProposalEventWithLen, RelayRoundProposalMetaWithLen,
+};
+
+use token_proxy::{
+    WithdrawalMultiTokenSol, WithdrawalMultiTokenSolEventWithLen, WithdrawalTokenMetaWithLen,
+    WithdrawalTokenStatus,
+};
+
+use solana_program::hash::Hash;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+fn pack<T: Pack>(state: T) -> Vec<u8> {
+    let mut data = vec![0; T::LEN];
+    T::pack(state, &mut data).unwrap();
+    data
+}
+
+fn withdrawal(status: WithdrawalTokenStatus, event_timestamp: u32, signers: Vec<Vote>) -> Vec<u8> {
+    let mut meta = WithdrawalTokenMetaWithLen::default();
+    meta.data.status = status;
+
+    let withdrawal = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(255, None),
+        author: Pubkey::new_unique(),
+        round_number: 1,
+        required_votes: 1,
+        pda: PDA {
+            event_timestamp,
+            event_transaction_lt: 1,
+            event_configuration: Pubkey::new_unique(),
+        },
+        event: WithdrawalMultiTokenSolEventWithLen::new(
+            0,
+            Pubkey::new_unique(),
+            100,
+            Pubkey::new_unique(),
+            vec![],
+        ),
+        meta,
+        signers,
+    };
+
+    pack(withdrawal)
+}
+
+fn relay_round(round_number: u32, round_end: u32, suggested_proposal_deadline: u32) -> Vec<u8> {
+    pack(RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(255),
+        round_number,
+        round_end,
+        relays: vec![Pubkey::new_unique()],
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline,
+        activated_at: 0,
+        frozen: false,
+    })
+}
+
+fn relay_round_proposal(round_num: u32) -> Vec<u8> {
+    pack(RelayRoundProposal {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(255, None),
+        author: Pubkey::new_unique(),
+        round_number: round_num - 1,
+        required_votes: 1,
+        pda: PDA {
+            event_timestamp: 1,
+            event_transaction_lt: 1,
+            event_configuration: Pubkey::new_unique(),
+        },
+        event: RelayRoundProposalEventWithLen::new(round_num, vec![Pubkey::new_unique()], 100),
+        meta: RelayRoundProposalMetaWithLen::new(),
+        signers: vec![],
+        confirm_count: 0,
+        reject_count: 0,
+        executed_at: 0,
+    })
+}
+
+#[test]
+fn test_metrics_from_accounts() {
+    let owner = token_proxy::id();
+
+    let pending = withdrawal(
+        WithdrawalTokenStatus::New,
+        100,
+        vec![Vote::Confirm, Vote::None],
+    );
+    let processed = withdrawal(
+        WithdrawalTokenStatus::Processed,
+        50,
+        vec![Vote::Confirm, Vote::Confirm],
+    );
+
+    let metrics = Metrics::from_accounts(
+        160,
+        [(&owner, pending.as_slice()), (&owner, processed.as_slice())],
+    );
+
+    assert_eq!(metrics.pending_withdrawals, 1);
+    assert_eq!(metrics.oldest_pending_age, Some(60));
+    assert_eq!(metrics.vote_participation[&1].expected, 4);
+    assert_eq!(metrics.vote_participation[&1].cast, 3);
+    assert_eq!(metrics.decode_errors, 0);
+}
+
+#[test]
+fn test_metrics_skip_undecodable_accounts() {
+    let owner = token_proxy::id();
+    let foreign_owner = Pubkey::new_unique();
+
+    let pending = withdrawal(WithdrawalTokenStatus::New, 100, vec![Vote::None]);
+
+    // Unknown discriminator of an initialized account
+    let mut unknown = vec![0xff; 8];
+    unknown.push(1);
+
+    // Known discriminator, truncated body
+    let truncated = pending[..16].to_vec();
+
+    let metrics = Metrics::from_accounts(
+        160,
+        [
+            (&owner, unknown.as_slice()),
+            (&owner, pending.as_slice()),
+            (&owner, truncated.as_slice()),
+            // Accounts of other programs are not bridge accounts at all
+            (&foreign_owner, unknown.as_slice()),
+        ],
+    );
+
+    assert_eq!(metrics.pending_withdrawals, 1);
+    assert_eq!(metrics.decode_errors, 2);
+    assert!(metrics.to_prometheus().contains("bridge_decode_errors 2\n"));
+}
+
+#[test]
+fn test_metrics_round_deadline() {
+    let owner = round_loader::id();
+
+    let previous = relay_round(6, 1000, 900);
+    let latest = relay_round(7, 2000, 1800);
+
+    let metrics = Metrics::from_accounts(
+        1800,
+        [(&owner, latest.as_slice()), (&owner, previous.as_slice())],
+    );
+
+    // Latest round is tracked whatever the order of the accounts
+    assert_eq!(
+        metrics.round_deadline,
+        Some(RoundDeadline {
+            round_number: 7,
+            round_end: 2000,
+            suggested_proposal_deadline: 1800,
+            next_round_proposed: false,
+        })
+    );
+
+    let deadline = metrics.round_deadline.as_ref().unwrap();
+    assert!(!deadline.is_overdue(1799));
+    assert!(deadline.is_overdue(1800));
+
+    let prometheus = metrics.to_prometheus();
+    assert!(prometheus.contains("bridge_round_proposal_deadline{round=\"7\"} 1800\n"));
+    assert!(prometheus.contains("bridge_next_round_proposed{round=\"7\"} 0\n"));
+}
+
+#[test]
+fn test_metrics_round_deadline_proposed() {
+    let owner = round_loader::id();
+
+    let latest = relay_round(7, 2000, 1800);
+
+    // Proposal of another round doesn't replace the latest one
+    let stale_proposal = relay_round_proposal(7);
+
+    let metrics = Metrics::from_accounts(
+        1900,
+        [
+            (&owner, latest.as_slice()),
+            (&owner, stale_proposal.as_slice()),
+        ],
+    );
+
+    let deadline = metrics.round_deadline.as_ref().unwrap();
+    assert!(!deadline.next_round_proposed);
+    assert!(deadline.is_overdue(1900));
+
+    let next_proposal = relay_round_proposal(8);
+
+    let metrics = Metrics::from_accounts(
+        1900,
+        [
+            (&owner, next_proposal.as_slice()),
+            (&owner, latest.as_slice()),
+        ],
+    );
+
+    let deadline = metrics.round_deadline.as_ref().unwrap();
+    assert!(deadline.next_round_proposed);
+    assert!(!deadline.is_overdue(1900));
+    assert!(metrics
+        .to_prometheus()
+        .contains("bridge_next_round_proposed{round=\"7\"} 1\n"));
+
+    // No relay round, no deadline
+    let metrics = Metrics::from_accounts(1900, [(&owner, next_proposal.as_slice())]);
+    assert_eq!(metrics.round_deadline, None);
+    assert!(!metrics
+        .to_prometheus()
+        .contains("bridge_round_proposal_deadline"));
+}
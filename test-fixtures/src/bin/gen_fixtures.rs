@@ -0,0 +1,77 @@
+//! Dump account fixtures as JSON.
+//!
+//! Usage: `gen-fixtures [output file]`, prints to stdout when no file is given.
+
+use std::env;
+use std::fs;
+
+use bridge_utils::state::PDA;
+use bridge_utils::types::EverAddress;
+use token_proxy_interface::{
+    WithdrawalMultiTokenEverEventWithLen, WithdrawalMultiTokenSolEventWithLen,
+};
+
+use solana_program::pubkey::Pubkey;
+
+const ROUND_NUMBER: u32 = 7;
+const ROUND_END: u32 = 1_900_000_000;
+const RELAYS_COUNT: u8 = 3;
+
+fn main() {
+    // Fixed keys keep the fixtures stable between runs
+    let key = |seed: u8| Pubkey::new_from_array([seed; 32]);
+
+    let relays = (1..=RELAYS_COUNT).map(key).collect::<Vec<_>>();
+
+    let pda = || PDA {
+        event_timestamp: 1650988297,
+        event_transaction_lt: 1650988334,
+        event_configuration: key(10),
+    };
+
+    let token = EverAddress::with_standart(0, key(11).to_bytes());
+
+    let withdrawal_ever_event = WithdrawalMultiTokenEverEventWithLen::new(
+        0,
+        token,
+        "USDT ETHEREUM OCTUSBRIDGE".to_string(),
+        "USDT".to_string(),
+        9,
+        1000,
+        key(12),
+        vec![],
+    );
+
+    let withdrawal_sol_event =
+        WithdrawalMultiTokenSolEventWithLen::new(0, key(13), 1000, key(12), vec![]);
+
+    let fixtures = serde_json::json!({
+        "settings": test_fixtures::token_proxy_settings(key(20), key(21), key(22), 0).to_json(),
+        "relay_round": test_fixtures::relay_round(ROUND_NUMBER, &relays, ROUND_END).to_json(),
+        "withdrawal_ever_event": test_fixtures::encode(&withdrawal_ever_event),
+        "withdrawal_ever": test_fixtures::withdrawal_ever(
+            key(14),
+            ROUND_NUMBER,
+            pda(),
+            withdrawal_ever_event,
+            relays.len(),
+        )
+        .to_json(),
+        "withdrawal_sol_event": test_fixtures::encode(&withdrawal_sol_event),
+        "withdrawal_sol": test_fixtures::withdrawal_sol(
+            key(14),
+            ROUND_NUMBER,
+            pda(),
+            withdrawal_sol_event,
+            relays.len(),
+        )
+        .to_json(),
+    });
+
+    let output = serde_json::to_string_pretty(&fixtures).expect("json");
+
+    match env::args().nth(1) {
+        Some(path) => fs::write(path, output).expect("write fixtures"),
+        None => println!("{output}"),
+    }
+}
@@ -0,0 +1,34 @@
+//! Dump the Borsh schema of the Token Proxy and Round Loader state as JSON.
+//!
+//! Usage: `gen-schema [output dir]`, writes `schema.json` into the interface crates when no
+//! directory is given.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    let schemas = [
+        (
+            "token-proxy-interface",
+            token_proxy_interface::state_schema().to_json(),
+        ),
+        (
+            "round-loader-interface",
+            round_loader_interface::state_schema().to_json(),
+        ),
+    ];
+
+    for (crate_name, schema) in schemas {
+        let path = match env::args().nth(1) {
+            Some(dir) => PathBuf::from(dir).join(format!("{crate_name}.json")),
+            None => PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join(crate_name)
+                .join("schema.json"),
+        };
+
+        let output = serde_json::to_string_pretty(&schema).expect("json");
+        fs::write(path, output).expect("write schema");
+    }
+}
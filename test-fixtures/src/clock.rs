@@ -0,0 +1,45 @@
+//! Clock control for `ProgramTestContext`.
+//!
+//! Programs read the time with `Clock::get()`, so overriding the clock sysvar is enough to move
+//! them through daily limits, round expiry and timelocks. Every call returns a new blockhash,
+//! so a transaction repeated after the warp is not rejected as already processed.
+
+use solana_program::clock::{Clock, UnixTimestamp};
+use solana_program::hash::Hash;
+use solana_program_test::ProgramTestContext;
+
+/// Current clock of the test validator
+pub async fn get_clock(context: &mut ProgramTestContext) -> Clock {
+    context.banks_client.get_sysvar().await.expect("get_sysvar")
+}
+
+/// Set the unix timestamp seen by the programs
+pub async fn set_timestamp(
+    context: &mut ProgramTestContext,
+    unix_timestamp: UnixTimestamp,
+) -> Hash {
+    let mut clock = get_clock(context).await;
+    clock.unix_timestamp = unix_timestamp;
+    context.set_sysvar(&clock);
+
+    context
+        .get_new_latest_blockhash()
+        .await
+        .expect("get_new_latest_blockhash")
+}
+
+/// Move the unix timestamp forward by the number of seconds
+pub async fn warp_seconds(context: &mut ProgramTestContext, seconds: i64) -> Hash {
+    let clock = get_clock(context).await;
+    set_timestamp(context, clock.unix_timestamp + seconds).await
+}
+
+/// Warp the test validator by the number of slots, keeping the unix timestamp
+pub async fn warp_slots(context: &mut ProgramTestContext, slots: u64) -> Hash {
+    let clock = get_clock(context).await;
+    context
+        .warp_to_slot(clock.slot + slots)
+        .expect("warp_to_slot");
+
+    set_timestamp(context, clock.unix_timestamp).await
+}
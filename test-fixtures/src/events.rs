@@ -0,0 +1,115 @@
+//! Events captured from `ProgramTest` transactions.
+//!
+//! Programs log events with `sol_log_data`, which shows up as a `Program data:` line holding the
+//! base64 encoded fields. Token Proxy logs the Borsh serialized event followed by its sequence
+//! number, so captured events are decoded with the typed event definitions of the interface.
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use borsh::BorshDeserialize;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::BanksClient;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use std::str::FromStr;
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoggedEvent {
+    // Program executing when the event was logged
+    pub program_id: Pubkey,
+    // Fields passed to `sol_log_data`
+    pub fields: Vec<Vec<u8>>,
+}
+
+impl LoggedEvent {
+    /// Decode the event, `None` if it has another type
+    pub fn decode<T: BorshDeserialize>(&self) -> Option<T> {
+        T::try_from_slice(self.fields.first()?).ok()
+    }
+
+    /// Event sequence number, logged after the event
+    pub fn event_seq(&self) -> Option<u64> {
+        let bytes = self.fields.get(1)?;
+        Some(u64::from_le_bytes(bytes.as_slice().try_into().ok()?))
+    }
+}
+
+/// Events in the log messages of a transaction, attributed to the program logging them
+pub fn capture_events(log_messages: &[String]) -> Vec<LoggedEvent> {
+    let mut invoke_stack = Vec::new();
+    let mut events = Vec::new();
+
+    for message in log_messages {
+        if let Some(data) = message.strip_prefix(PROGRAM_DATA_PREFIX) {
+            let program_id = match invoke_stack.last() {
+                Some(program_id) => program_id,
+                None => continue,
+            };
+
+            let fields = data
+                .split_whitespace()
+                .map(|field| general_purpose::STANDARD.decode(field).expect("base64"))
+                .collect();
+
+            events.push(LoggedEvent {
+                program_id: *program_id,
+                fields,
+            });
+
+            continue;
+        }
+
+        let message = match message.strip_prefix("Program ") {
+            Some(message) => message,
+            None => continue,
+        };
+
+        let mut words = message.split_whitespace();
+
+        let program_id = match words.next().map(Pubkey::from_str) {
+            Some(Ok(program_id)) => program_id,
+            _ => continue,
+        };
+
+        match words.next() {
+            Some("invoke") => invoke_stack.push(program_id),
+            Some("success") | Some("failed:") => {
+                invoke_stack.pop();
+            }
+            _ => (),
+        }
+    }
+
+    events
+}
+
+/// Events of the program decoded as `T`, events of other types are skipped
+pub fn find_events<T: BorshDeserialize>(events: &[LoggedEvent], program_id: &Pubkey) -> Vec<T> {
+    events
+        .iter()
+        .filter(|event| event.program_id == *program_id)
+        .filter_map(|event| event.decode())
+        .collect()
+}
+
+/// Process the transaction, returning its result along with the events it logged.
+///
+/// Events of a failed transaction are captured as well, up to the failing instruction.
+pub async fn process_transaction_with_events(
+    banks_client: &mut BanksClient,
+    transaction: Transaction,
+) -> (Result<(), TransactionError>, Vec<LoggedEvent>) {
+    let result = banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .expect("process_transaction");
+
+    let events = result
+        .metadata
+        .map(|metadata| capture_events(&metadata.log_messages))
+        .unwrap_or_default();
+
+    (result.result, events)
+}
@@ -0,0 +1,214 @@
+//! Account fixtures and clock control for the functional tests of the bridge programs.
+//!
+//! Fixtures hold packed account data as base64, ready for
+//! `ProgramTest::add_account_with_base64_data`. The `gen-fixtures` binary dumps them as JSON.
+//! Events logged by the programs are captured from the transaction logs with [`events`].
+
+pub mod clock;
+pub mod events;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use borsh::BorshSerialize;
+use bridge_utils::merkle::get_merkle_root;
+use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::types::{Vote, RELAY_REPARATION};
+use round_loader_interface::RelayRound;
+use token_proxy_interface::{
+    Settings, WithdrawalMultiTokenEver, WithdrawalMultiTokenEverEventWithLen,
+    WithdrawalMultiTokenSol, WithdrawalMultiTokenSolEventWithLen, WithdrawalTokenMetaWithLen,
+};
+
+use solana_program::hash::{hash, Hash};
+use solana_program::program_pack::{IsInitialized, Pack};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountFixture {
+    pub address: Pubkey,
+    pub lamports: u64,
+    pub owner: Pubkey,
+    // Account data encoded as base64
+    pub data: String,
+}
+
+impl AccountFixture {
+    /// Rent exempt account holding the packed state
+    pub fn pack<T: Pack + IsInitialized>(address: Pubkey, owner: Pubkey, state: T) -> Self {
+        let mut data = vec![0; T::LEN];
+        T::pack(state, &mut data).expect("pack");
+
+        Self {
+            address,
+            lamports: Rent::default().minimum_balance(T::LEN),
+            owner,
+            data: general_purpose::STANDARD.encode(data),
+        }
+    }
+
+    pub fn with_extra_lamports(mut self, lamports: u64) -> Self {
+        self.lamports += lamports;
+        self
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "address": self.address.to_string(),
+            "lamports": self.lamports,
+            "owner": self.owner.to_string(),
+            "data": self.data,
+        })
+    }
+}
+
+/// Borsh serialized value encoded as base64
+pub fn encode<T: BorshSerialize>(value: &T) -> String {
+    general_purpose::STANDARD.encode(value.try_to_vec().expect("pack"))
+}
+
+/// Token Proxy settings
+pub fn token_proxy_settings(
+    guardian: Pubkey,
+    manager: Pubkey,
+    withdrawal_manager: Pubkey,
+    programdata_nonce: u8,
+) -> AccountFixture {
+    let program_id = &token_proxy_interface::id();
+    let (settings_pubkey, settings_nonce) =
+        Pubkey::find_program_address(&[br"settings"], program_id);
+
+    let settings = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian: Pubkey::default(),
+        manager: Pubkey::default(),
+        withdrawal_manager: Pubkey::default(),
+        chain_id: 0,
+        event_seq: 0,
+        veto_disabled: false,
+        deposits_restricted_to_whitelist: false,
+        roles: Settings::initial_roles(guardian, manager, withdrawal_manager),
+        permissions: vec![],
+        emergency_cosigners: vec![],
+        emergency_cosigners_threshold: 0,
+        guardian_last_active: 0,
+    };
+
+    AccountFixture::pack(settings_pubkey, *program_id, settings)
+}
+
+/// Relay round owned by the Round Loader
+pub fn relay_round(round_number: u32, relays: &[Pubkey], round_end: u32) -> AccountFixture {
+    let program_id = &round_loader_interface::id();
+    let (relay_round_pubkey, relay_round_nonce) =
+        Pubkey::find_program_address(&[br"relay_round", &round_number.to_le_bytes()], program_id);
+
+    let relay_round = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end,
+        relays: relays.to_vec(),
+        relays_root: Hash::default(),
+        relays_count: 0,
+        suggested_proposal_deadline: round_end,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    AccountFixture::pack(relay_round_pubkey, *program_id, relay_round)
+}
+
+/// Relay round owned by the Round Loader keeping only the merkle root of its relays
+pub fn merkle_relay_round(round_number: u32, relays: &[Pubkey], round_end: u32) -> AccountFixture {
+    let program_id = &round_loader_interface::id();
+    let (relay_round_pubkey, relay_round_nonce) =
+        Pubkey::find_program_address(&[br"relay_round", &round_number.to_le_bytes()], program_id);
+
+    let relay_round = RelayRound {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRound(relay_round_nonce),
+        round_number,
+        round_end,
+        relays: vec![],
+        relays_root: get_merkle_root(relays),
+        relays_count: relays.len() as u32,
+        suggested_proposal_deadline: round_end,
+        activated_at: 0,
+        frozen: false,
+    };
+
+    AccountFixture::pack(relay_round_pubkey, *program_id, relay_round)
+}
+
+/// Withdrawal of an EVER token waiting for relay votes
+pub fn withdrawal_ever(
+    author: Pubkey,
+    round_number: u32,
+    pda: PDA,
+    event: WithdrawalMultiTokenEverEventWithLen,
+    relays_count: usize,
+) -> AccountFixture {
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+    let (withdrawal_pubkey, withdrawal_nonce) =
+        find_proposal_address(round_number, &pda, &event_data);
+
+    let withdrawal = WithdrawalMultiTokenEver {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author,
+        round_number,
+        required_votes: (relays_count * 2 / 3 + 1) as u32,
+        pda,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        signers: vec![Vote::None; relays_count],
+    };
+
+    AccountFixture::pack(withdrawal_pubkey, token_proxy_interface::id(), withdrawal)
+        .with_extra_lamports(RELAY_REPARATION * relays_count as u64)
+}
+
+/// Withdrawal of a SOL token waiting for relay votes
+pub fn withdrawal_sol(
+    author: Pubkey,
+    round_number: u32,
+    pda: PDA,
+    event: WithdrawalMultiTokenSolEventWithLen,
+    relays_count: usize,
+) -> AccountFixture {
+    let event_data = hash(&event.data.try_to_vec().expect("pack")).to_bytes();
+    let (withdrawal_pubkey, withdrawal_nonce) =
+        find_proposal_address(round_number, &pda, &event_data);
+
+    let withdrawal = WithdrawalMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+        author,
+        round_number,
+        required_votes: (relays_count * 2 / 3 + 1) as u32,
+        pda,
+        event,
+        meta: WithdrawalTokenMetaWithLen::default(),
+        signers: vec![Vote::None; relays_count],
+    };
+
+    AccountFixture::pack(withdrawal_pubkey, token_proxy_interface::id(), withdrawal)
+        .with_extra_lamports(RELAY_REPARATION * relays_count as u64)
+}
+
+fn find_proposal_address(round_number: u32, pda: &PDA, event_data: &[u8; 32]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &pda.event_timestamp.to_le_bytes(),
+            &pda.event_transaction_lt.to_le_bytes(),
+            &pda.event_configuration.to_bytes(),
+            event_data,
+        ],
+        &token_proxy_interface::id(),
+    )
+}
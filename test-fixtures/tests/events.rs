@@ -0,0 +1,92 @@
+use base64::engine::general_purpose;
+use base64::Engine;
+use borsh::{BorshDeserialize, BorshSerialize};
+use test_fixtures::events::{capture_events, find_events};
+
+use solana_program::pubkey::Pubkey;
+
+#[derive(Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+struct TestEvent {
+    account: Pubkey,
+    amount: u64,
+}
+
+fn program_data(fields: &[&[u8]]) -> String {
+    let fields = fields
+        .iter()
+        .map(|field| general_purpose::STANDARD.encode(field))
+        .collect::<Vec<_>>();
+
+    format!("Program data: {}", fields.join(" "))
+}
+
+#[test]
+fn test_capture_events() {
+    let program_id = Pubkey::new_unique();
+    let callee_id = Pubkey::new_unique();
+
+    let event = TestEvent {
+        account: Pubkey::new_unique(),
+        amount: 100,
+    };
+    let event_data = event.try_to_vec().unwrap();
+
+    let log_messages = vec![
+        format!("Program {program_id} invoke [1]"),
+        "Program log: Instruction: Deposit".to_string(),
+        format!("Program {callee_id} invoke [2]"),
+        program_data(&[b"callee"]),
+        format!("Program {callee_id} consumed 1000 of 200000 compute units"),
+        format!("Program {callee_id} success"),
+        program_data(&[&event_data, &7u64.to_le_bytes()]),
+        format!("Program {program_id} success"),
+    ];
+
+    let events = capture_events(&log_messages);
+    assert_eq!(events.len(), 2);
+
+    // Events are attributed to the program executing when they were logged
+    assert_eq!(events[0].program_id, callee_id);
+    assert_eq!(events[0].fields, vec![b"callee".to_vec()]);
+    assert_eq!(events[0].event_seq(), None);
+
+    assert_eq!(events[1].program_id, program_id);
+    assert_eq!(events[1].decode::<TestEvent>(), Some(event));
+    assert_eq!(events[1].event_seq(), Some(7));
+
+    let test_events: Vec<TestEvent> = find_events(&events, &program_id);
+    assert_eq!(test_events.len(), 1);
+    assert_eq!(test_events[0].amount, 100);
+}
+
+#[test]
+fn test_capture_events_skips_foreign_data() {
+    let program_id = Pubkey::new_unique();
+    let callee_id = Pubkey::new_unique();
+
+    let log_messages = vec![
+        // Data outside of any invocation has no program to attribute it to
+        program_data(&[b"orphan"]),
+        format!("Program {program_id} invoke [1]"),
+        format!("Program {callee_id} invoke [2]"),
+        format!("Program {callee_id} failed: custom program error: 0x1"),
+        // Sequence number of the wrong size
+        program_data(&[b"event", &[1, 2, 3]]),
+        format!("Program {program_id} failed: custom program error: 0x1"),
+        "Program log: not a program id".to_string(),
+    ];
+
+    let events = capture_events(&log_messages);
+    assert_eq!(events.len(), 1);
+
+    // Failed callee is popped off the stack as well
+    assert_eq!(events[0].program_id, program_id);
+    assert_eq!(events[0].event_seq(), None);
+
+    // Data of another type isn't decoded
+    assert_eq!(events[0].decode::<TestEvent>(), None);
+    assert!(find_events::<TestEvent>(&events, &program_id).is_empty());
+
+    // Events of other programs aren't returned
+    assert!(find_events::<Vec<u8>>(&events, &callee_id).is_empty());
+}
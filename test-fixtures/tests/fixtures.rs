@@ -0,0 +1,170 @@
+use base64::engine::general_purpose;
+use base64::Engine;
+use bridge_utils::state::PDA;
+use bridge_utils::types::RELAY_REPARATION;
+use round_loader_interface::RelayRound;
+use token_proxy_interface::{
+    get_withdrawal_sol_address, Role, Settings, WithdrawalMultiTokenSol,
+    WithdrawalMultiTokenSolEventWithLen,
+};
+
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+
+use std::process::Command;
+
+fn decode(data: &str) -> Vec<u8> {
+    general_purpose::STANDARD.decode(data).expect("base64")
+}
+
+fn pda() -> PDA {
+    PDA {
+        event_timestamp: 1650988297,
+        event_transaction_lt: 1650988334,
+        event_configuration: Pubkey::new_from_array([10; 32]),
+    }
+}
+
+#[test]
+fn test_settings_fixture() {
+    let guardian = Pubkey::new_unique();
+    let manager = Pubkey::new_unique();
+    let withdrawal_manager = Pubkey::new_unique();
+
+    let settings = test_fixtures::token_proxy_settings(guardian, manager, withdrawal_manager, 0);
+
+    assert_eq!(settings.owner, token_proxy_interface::id());
+    assert_eq!(
+        settings.address,
+        token_proxy_interface::get_settings_address()
+    );
+    assert_eq!(
+        settings.lamports,
+        Rent::default().minimum_balance(Settings::LEN)
+    );
+
+    let data = decode(&settings.data);
+    assert_eq!(data.len(), Settings::LEN);
+
+    let settings_data = Settings::unpack(&data).expect("settings unpack");
+    assert_eq!(settings_data.role_members(Role::Guardian), vec![guardian]);
+    assert_eq!(settings_data.role_members(Role::Manager), vec![manager]);
+
+    // Fixture of one account doesn't unpack as another one
+    assert_eq!(
+        RelayRound::unpack(&data).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+}
+
+#[test]
+fn test_withdrawal_fixture() {
+    let relays_count = 3;
+
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let amount = 1000;
+
+    let event = || WithdrawalMultiTokenSolEventWithLen::new(0, mint, amount, recipient, vec![]);
+
+    let withdrawal =
+        test_fixtures::withdrawal_sol(Pubkey::new_unique(), 7, pda(), event(), relays_count);
+
+    // Fixture sits at the address the program derives for the event
+    assert_eq!(
+        withdrawal.address,
+        get_withdrawal_sol_address(
+            0,
+            7,
+            pda().event_timestamp,
+            pda().event_transaction_lt,
+            &pda().event_configuration,
+            mint,
+            recipient,
+            amount,
+            vec![],
+        )
+    );
+
+    // Relays get their voting reparation back from the withdrawal
+    assert_eq!(
+        withdrawal.lamports,
+        Rent::default().minimum_balance(WithdrawalMultiTokenSol::LEN)
+            + RELAY_REPARATION * relays_count as u64
+    );
+
+    let withdrawal_data =
+        WithdrawalMultiTokenSol::unpack(&decode(&withdrawal.data)).expect("withdrawal unpack");
+    assert_eq!(withdrawal_data.required_votes, 3);
+    assert_eq!(withdrawal_data.signers.len(), relays_count);
+
+    // Withdrawal of another round or event is another account
+    let other_round =
+        test_fixtures::withdrawal_sol(Pubkey::new_unique(), 8, pda(), event(), relays_count);
+    assert_ne!(other_round.address, withdrawal.address);
+
+    let other_event = test_fixtures::withdrawal_sol(
+        Pubkey::new_unique(),
+        7,
+        pda(),
+        WithdrawalMultiTokenSolEventWithLen::new(0, mint, amount + 1, recipient, vec![]),
+        relays_count,
+    );
+    assert_ne!(other_event.address, withdrawal.address);
+}
+
+#[test]
+fn test_gen_fixtures() {
+    let path = std::env::temp_dir().join(format!("fixtures-{}.json", std::process::id()));
+
+    let status = Command::new(env!("CARGO_BIN_EXE_gen-fixtures"))
+        .arg(&path)
+        .status()
+        .expect("run gen-fixtures");
+    assert!(status.success());
+
+    let fixtures: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).expect("read fixtures"))
+            .expect("json");
+    std::fs::remove_file(&path).expect("remove fixtures");
+
+    for name in [
+        "settings",
+        "relay_round",
+        "withdrawal_ever",
+        "withdrawal_sol",
+    ] {
+        let fixture = &fixtures[name];
+        assert!(fixture["address"].is_string(), "{name} address");
+        assert!(fixture["lamports"].is_u64(), "{name} lamports");
+        assert!(fixture["owner"].is_string(), "{name} owner");
+    }
+
+    let relay_round = RelayRound::unpack(&decode(
+        fixtures["relay_round"]["data"].as_str().expect("data"),
+    ))
+    .expect("relay round unpack");
+    assert_eq!(relay_round.relays.len(), 3);
+
+    // Fixed keys keep the output stable between runs
+    let output = Command::new(env!("CARGO_BIN_EXE_gen-fixtures"))
+        .output()
+        .expect("run gen-fixtures");
+    assert!(output.status.success());
+
+    let printed: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(printed, fixtures);
+
+    // Output file that can't be written
+    let status = Command::new(env!("CARGO_BIN_EXE_gen-fixtures"))
+        .arg(
+            std::env::temp_dir()
+                .join("missing-dir")
+                .join("fixtures.json"),
+        )
+        .status()
+        .expect("run gen-fixtures");
+    assert!(!status.success());
+}
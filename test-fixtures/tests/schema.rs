@@ -0,0 +1,121 @@
+use std::collections::BTreeSet;
+use std::process::Command;
+
+use serde_json::Value;
+
+use solana_program::program_pack::Pack;
+
+fn account<'a>(schema: &'a Value, name: &str) -> Option<&'a Value> {
+    schema["accounts"]
+        .as_array()
+        .expect("accounts")
+        .iter()
+        .find(|account| account["type"] == name)
+}
+
+fn names(values: &Value, key: &str) -> Vec<String> {
+    values
+        .as_array()
+        .expect("array")
+        .iter()
+        .map(|value| value[key].as_str().expect("name").to_string())
+        .collect()
+}
+
+#[test]
+fn test_state_schema() {
+    let schema = token_proxy_interface::state_schema().to_json();
+
+    let settings = account(&schema, "Settings").expect("settings");
+    let discriminator = token_proxy_interface::Settings::DISCRIMINATOR
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    assert_eq!(settings["discriminator"], discriminator);
+    assert_eq!(settings["data_offset"], 8);
+    assert_eq!(settings["len"], token_proxy_interface::Settings::LEN);
+
+    // Definitions follow the fields down to shared types
+    let definition = &schema["definitions"]["Settings"];
+    assert_eq!(definition["kind"], "struct");
+
+    let fields = names(&definition["fields"], "name");
+    assert_eq!(fields[..2], ["is_initialized", "account_kind"]);
+    assert!(fields.contains(&"event_seq".to_string()));
+
+    let account_kind = &schema["definitions"]["AccountKind"];
+    assert_eq!(account_kind["kind"], "enum");
+    assert!(names(&account_kind["variants"], "name").contains(&"Settings".to_string()));
+
+    // Every account has its own discriminator
+    for schema in [
+        token_proxy_interface::state_schema().to_json(),
+        round_loader_interface::state_schema().to_json(),
+    ] {
+        let accounts = schema["accounts"].as_array().expect("accounts");
+        let discriminators = accounts
+            .iter()
+            .map(|account| account["discriminator"].as_str().expect("discriminator"))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(discriminators.len(), accounts.len());
+    }
+}
+
+#[test]
+fn test_state_schema_per_program() {
+    let token_proxy = token_proxy_interface::state_schema().to_json();
+    let round_loader = round_loader_interface::state_schema().to_json();
+
+    // Accounts of one program aren't listed in the schema of the other one
+    assert!(account(&round_loader, "SpendProposal").is_some());
+    assert!(account(&token_proxy, "SpendProposal").is_none());
+
+    assert!(account(&token_proxy, "TokenSettings").is_some());
+    assert!(account(&round_loader, "TokenSettings").is_none());
+    assert!(round_loader["definitions"].get("TokenSettings").is_none());
+
+    // Settings of both programs are exported under the same name with their own layout
+    assert_ne!(
+        account(&token_proxy, "Settings").unwrap()["len"],
+        account(&round_loader, "Settings").unwrap()["len"]
+    );
+    assert_ne!(
+        token_proxy["definitions"]["Settings"],
+        round_loader["definitions"]["Settings"]
+    );
+}
+
+#[test]
+fn test_gen_schema() {
+    let dir = std::env::temp_dir().join(format!("schema-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create dir");
+
+    let status = Command::new(env!("CARGO_BIN_EXE_gen-schema"))
+        .arg(&dir)
+        .status()
+        .expect("run gen-schema");
+    assert!(status.success());
+
+    let read = |name: &str| -> Value {
+        serde_json::from_str(&std::fs::read_to_string(dir.join(name)).expect("read schema"))
+            .expect("json")
+    };
+
+    assert_eq!(
+        read("token-proxy-interface.json"),
+        token_proxy_interface::state_schema().to_json()
+    );
+    assert_eq!(
+        read("round-loader-interface.json"),
+        round_loader_interface::state_schema().to_json()
+    );
+
+    std::fs::remove_dir_all(&dir).expect("remove dir");
+
+    // Output directory that doesn't exist
+    let status = Command::new(env!("CARGO_BIN_EXE_gen-schema"))
+        .arg(&dir)
+        .status()
+        .expect("run gen-schema");
+    assert!(!status.success());
+}
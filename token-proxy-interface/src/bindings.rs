@@ -0,0 +1,4092 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::merkle::MerkleProof;
+use bridge_utils::state::{Proposal, PDA};
+use bridge_utils::types::{EverAddress, UInt256, Vote};
+use round_loader_interface::RelayRound;
+
+use solana_program::hash::hash;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::{system_program, sysvar};
+
+use crate::*;
+
+pub fn get_programdata_address() -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_programdata_address(program_id)
+}
+
+pub fn get_settings_address() -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_associated_settings_address(program_id)
+}
+
+pub fn get_admin_audit_log_address() -> Pubkey {
+    let program_id = &id();
+    bridge_utils::helper::get_associated_admin_audit_log_address(program_id)
+}
+
+pub fn get_multivault_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_multivault_address(program_id)
+}
+
+pub fn get_token_settings_ever_address(token: &EverAddress) -> Pubkey {
+    let program_id = &id();
+    get_associated_token_settings_ever_address(program_id, token)
+}
+
+pub fn get_token_settings_sol_address(mint: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_token_settings_sol_address(program_id, mint)
+}
+
+pub fn get_namespaced_token_settings_sol_address(
+    mint: &Pubkey,
+    ever_configuration: Option<&Pubkey>,
+) -> Pubkey {
+    let program_id = &id();
+    find_token_settings_sol_address(program_id, mint, ever_configuration).0
+}
+
+pub fn get_mint_address(token: &EverAddress) -> Pubkey {
+    let program_id = &id();
+    get_associated_mint_address(program_id, token)
+}
+
+pub fn get_vault_address(mint: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_vault_address(program_id, mint)
+}
+
+pub fn get_vault_address_of_generation(mint: &Pubkey, generation: u8) -> Pubkey {
+    let program_id = &id();
+    find_vault_address(program_id, mint, None, generation).0
+}
+
+pub fn get_namespaced_vault_address(mint: &Pubkey, ever_configuration: Option<&Pubkey>) -> Pubkey {
+    let program_id = &id();
+    find_vault_address(program_id, mint, ever_configuration, 0).0
+}
+
+pub fn get_vault_rotation_address(vault: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_vault_rotation_address(program_id, vault)
+}
+
+pub fn get_lp_position_address(mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_lp_position_address(program_id, mint, owner)
+}
+
+pub fn get_deposit_address(sender: &Pubkey, seed: u128) -> Pubkey {
+    let program_id = &id();
+    get_associated_deposit_address(program_id, sender, seed)
+}
+
+pub fn get_namespaced_deposit_address(
+    sender: &Pubkey,
+    seed: u128,
+    ever_configuration: Option<&Pubkey>,
+) -> Pubkey {
+    let program_id = &id();
+    find_deposit_address(program_id, sender, seed, ever_configuration).0
+}
+
+pub fn get_proxy_address(mint: &Pubkey, recipient: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_proxy_address(program_id, mint, recipient)
+}
+
+pub fn get_hook_authority_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_hook_authority_address(program_id)
+}
+
+/// Accounts to append to deposit and withdrawal instructions of a token with a hook program
+pub fn hook_accounts(hook_program: Pubkey) -> Vec<AccountMeta> {
+    let hook_authority_pubkey = get_hook_authority_address();
+
+    vec![
+        AccountMeta::new_readonly(hook_authority_pubkey, false),
+        AccountMeta::new_readonly(hook_program, false),
+    ]
+}
+
+pub fn get_settings_history_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_settings_history_address(program_id)
+}
+
+pub fn get_settings_history_page_address(page: u64) -> Pubkey {
+    let program_id = &id();
+    get_associated_settings_history_page_address(program_id, page)
+}
+
+/// Accounts to append to role and limit change instructions to record them in the settings history
+pub fn settings_history_accounts(page: u64) -> Vec<AccountMeta> {
+    let settings_history_pubkey = get_settings_history_address();
+    let settings_history_page_pubkey = get_settings_history_page_address(page);
+
+    vec![
+        AccountMeta::new(settings_history_pubkey, false),
+        AccountMeta::new(settings_history_page_pubkey, false),
+    ]
+}
+
+pub fn get_reward_config_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_reward_config_address(program_id)
+}
+
+pub fn get_token_whitelist_address(mint: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_token_whitelist_address(program_id, mint)
+}
+
+pub fn get_payload_schema_address(schema_id: u32) -> Pubkey {
+    let program_id = &id();
+    get_associated_payload_schema_address(program_id, schema_id)
+}
+
+pub fn get_known_token_address(token: &EverAddress) -> Pubkey {
+    let program_id = &id();
+    get_associated_known_token_address(program_id, token)
+}
+
+pub fn get_exempt_account_address(authority: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_exempt_account_address(program_id, authority)
+}
+
+pub fn get_deposit_delegate_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_deposit_delegate_address(program_id)
+}
+
+pub fn get_nonce_address(owner: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_nonce_address(program_id, owner)
+}
+
+/// Nonce for the next message signed by the owner, `nonce_account_data` is the data of the
+/// owner's Nonce Account if it exists
+pub fn get_fresh_nonce(nonce_account_data: Option<&[u8]>) -> Result<u64, ProgramError> {
+    match nonce_account_data {
+        Some(data) => Ok(Nonce::unpack(data)?.fresh_nonce()),
+        None => Ok(0),
+    }
+}
+
+pub fn get_discount_config_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_discount_config_address(program_id)
+}
+
+pub fn get_discount_vault_address() -> Pubkey {
+    let program_id = &id();
+    get_associated_discount_vault_address(program_id)
+}
+
+pub fn get_discount_stake_address(owner: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_discount_stake_address(program_id, owner)
+}
+
+pub fn get_relay_round_stats_address(round_number: u32) -> Pubkey {
+    let program_id = &id();
+    get_associated_relay_round_stats_address(program_id, round_number)
+}
+
+pub fn get_round_reward_address(token_settings: &Pubkey, round_number: u32) -> Pubkey {
+    let program_id = &id();
+    get_associated_round_reward_address(program_id, token_settings, round_number)
+}
+
+pub fn get_event_index_address(
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+) -> Pubkey {
+    let program_id = &id();
+    get_associated_event_index_address(
+        program_id,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+    )
+}
+
+pub fn get_deposit_index_address(owner: &Pubkey) -> Pubkey {
+    let program_id = &id();
+    get_associated_deposit_index_address(program_id, owner)
+}
+
+pub fn get_deposit_index_page_address(owner: &Pubkey, page: u64) -> Pubkey {
+    let program_id = &id();
+    get_associated_deposit_index_page_address(program_id, owner, page)
+}
+
+/// Accounts to append to deposit instructions to record them in the deposit index of the author
+pub fn deposit_index_accounts(owner: &Pubkey, page: u64) -> Vec<AccountMeta> {
+    let deposit_index_pubkey = get_deposit_index_address(owner);
+    let deposit_index_page_pubkey = get_deposit_index_page_address(owner, page);
+
+    vec![
+        AccountMeta::new(deposit_index_pubkey, false),
+        AccountMeta::new(deposit_index_page_pubkey, false),
+    ]
+}
+
+/// Deposit index pages holding `limit` deposits of the owner starting from `offset`,
+/// the oldest deposit has offset 0
+pub fn get_deposit_index_page_addresses(owner: &Pubkey, offset: u64, limit: u64) -> Vec<Pubkey> {
+    if limit == 0 {
+        return Vec::new();
+    }
+
+    let capacity = DEPOSIT_INDEX_PAGE_CAPACITY as u64;
+    let first_page = offset / capacity;
+    let last_page = offset.saturating_add(limit - 1) / capacity;
+
+    (first_page..=last_page)
+        .map(|page| get_deposit_index_page_address(owner, page))
+        .collect()
+}
+
+/// Deposits from loaded deposit index pages, skipping `offset` deposits and taking at most
+/// `limit` of them. Pages must be ordered and start from the page holding `offset`.
+pub fn paginate_deposit_index(pages: &[DepositIndexPage], offset: u64, limit: u64) -> Vec<Pubkey> {
+    let skip = match pages.first() {
+        Some(page) => offset.saturating_sub(page.page * DEPOSIT_INDEX_PAGE_CAPACITY as u64),
+        None => return Vec::new(),
+    };
+
+    pages
+        .iter()
+        .flat_map(|page| page.deposits.iter().copied())
+        .skip(skip as usize)
+        .take(limit as usize)
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn get_withdrawal_ever_address(
+    chain_id: u32,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    recipient: Pubkey,
+    amount: u128,
+    payload: Vec<u8>,
+) -> Pubkey {
+    let program_id = &id();
+
+    let event_data = hash(
+        &WithdrawalMultiTokenEverEvent {
+            chain_id,
+            token,
+            name,
+            symbol,
+            decimals,
+            amount,
+            recipient,
+            payload,
+        }
+        .try_to_vec()
+        .expect("pack"),
+    )
+    .to_bytes();
+
+    bridge_utils::helper::get_associated_proposal_address(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        &event_data,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn get_withdrawal_sol_address(
+    chain_id: u32,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    mint: Pubkey,
+    recipient: Pubkey,
+    amount: u128,
+    payload: Vec<u8>,
+) -> Pubkey {
+    let program_id = &id();
+
+    let event_data = hash(
+        &WithdrawalMultiTokenSolEvent {
+            chain_id,
+            mint,
+            amount,
+            recipient,
+            payload,
+        }
+        .try_to_vec()
+        .expect("pack"),
+    )
+    .to_bytes();
+
+    bridge_utils::helper::get_associated_proposal_address(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        &event_data,
+    )
+}
+
+pub fn initialize_settings_ix(
+    funder_pubkey: Pubkey,
+    initializer_pubkey: Pubkey,
+    guardian: Pubkey,
+    manager: Pubkey,
+    withdrawal_manager: Pubkey,
+    chain_id: u32,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let program_data_pubkey = get_programdata_address();
+    let multivault_pubkey = get_multivault_address();
+
+    let data = TokenProxyInstruction::Initialize {
+        guardian,
+        manager,
+        withdrawal_manager,
+        chain_id,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(initializer_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_ever_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    token: &EverAddress,
+    deposit_seed: u128,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+) -> Instruction {
+    let mint_pubkey = get_mint_address(token);
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+    let deposit_pubkey = get_deposit_address(&author_pubkey, deposit_seed);
+
+    let data = TokenProxyInstruction::DepositMultiTokenEver {
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(author_token_pubkey, false),
+        AccountMeta::new(deposit_pubkey, false),
+        AccountMeta::new(mint_pubkey, false),
+        AccountMeta::new(multivault_pubkey, false),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    if let Some(schema_id) = payload_schema {
+        accounts.push(AccountMeta::new_readonly(
+            get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_sol_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+    ever_configuration: Option<Pubkey>,
+) -> Instruction {
+    let vault_pubkey = get_namespaced_vault_address(&mint_pubkey, ever_configuration.as_ref());
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let token_settings_pubkey =
+        get_namespaced_token_settings_sol_address(&mint_pubkey, ever_configuration.as_ref());
+    let token_whitelist_pubkey = get_token_whitelist_address(&mint_pubkey);
+
+    let deposit_pubkey =
+        get_namespaced_deposit_address(&author_pubkey, deposit_seed, ever_configuration.as_ref());
+
+    let data = TokenProxyInstruction::DepositMultiTokenSol {
+        deposit_seed,
+        name,
+        symbol,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+        ever_configuration,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(author_token_pubkey, false),
+        AccountMeta::new(vault_pubkey, false),
+        AccountMeta::new(deposit_pubkey, false),
+        AccountMeta::new(mint_pubkey, false),
+        AccountMeta::new(multivault_pubkey, false),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(token_whitelist_pubkey, false),
+    ];
+
+    if let Some(schema_id) = payload_schema {
+        accounts.push(AccountMeta::new_readonly(
+            get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+/// Deposit delivering exactly `transfer_amount` to the EVER recipient, the gross amount
+/// including the fee is capped by `max_amount`
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_sol_exact_output_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    transfer_amount: u128,
+    max_amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+    ever_configuration: Option<Pubkey>,
+) -> Instruction {
+    let vault_pubkey = get_namespaced_vault_address(&mint_pubkey, ever_configuration.as_ref());
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let token_settings_pubkey =
+        get_namespaced_token_settings_sol_address(&mint_pubkey, ever_configuration.as_ref());
+    let token_whitelist_pubkey = get_token_whitelist_address(&mint_pubkey);
+
+    let deposit_pubkey =
+        get_namespaced_deposit_address(&author_pubkey, deposit_seed, ever_configuration.as_ref());
+
+    let data = TokenProxyInstruction::DepositMultiTokenSolExactOutput {
+        deposit_seed,
+        name,
+        symbol,
+        transfer_amount,
+        max_amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+        ever_configuration,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(author_token_pubkey, false),
+        AccountMeta::new(vault_pubkey, false),
+        AccountMeta::new(deposit_pubkey, false),
+        AccountMeta::new(mint_pubkey, false),
+        AccountMeta::new(multivault_pubkey, false),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(token_whitelist_pubkey, false),
+    ];
+
+    if let Some(schema_id) = payload_schema {
+        accounts.push(AccountMeta::new_readonly(
+            get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+/// Deposit delivering exactly `transfer_amount` in Ever decimals to the EVER recipient, the gross
+/// amount burned including the fee is capped by `max_amount`
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_ever_exact_output_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    token: &EverAddress,
+    deposit_seed: u128,
+    transfer_amount: u128,
+    max_amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+) -> Instruction {
+    let mint_pubkey = get_mint_address(token);
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+    let deposit_pubkey = get_deposit_address(&author_pubkey, deposit_seed);
+
+    let data = TokenProxyInstruction::DepositMultiTokenEverExactOutput {
+        deposit_seed,
+        transfer_amount,
+        max_amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(author_token_pubkey, false),
+        AccountMeta::new(deposit_pubkey, false),
+        AccountMeta::new(mint_pubkey, false),
+        AccountMeta::new(multivault_pubkey, false),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    if let Some(schema_id) = payload_schema {
+        accounts.push(AccountMeta::new_readonly(
+            get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+/// Let the deposit delegate spend the owner's tokens in gasless deposits
+pub fn approve_deposit_delegate_ix(
+    owner_pubkey: Pubkey,
+    owner_token_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    spl_token::instruction::approve(
+        &spl_token::id(),
+        &owner_token_pubkey,
+        &get_deposit_delegate_address(),
+        &owner_pubkey,
+        &[],
+        amount,
+    )
+    .expect("approve")
+}
+
+/// Gasless deposit of the signed message. Has to follow the Ed25519 instruction verifying the
+/// owner's signature of `GaslessDepositMessage::signing_payload`, see
+/// `bridge_utils::ed25519::new_ed25519_instruction`. Tokens are spent from the signed
+/// `owner_token_account`.
+pub fn deposit_multi_token_sol_gasless_ix(
+    funder_pubkey: Pubkey,
+    message: &GaslessDepositMessage,
+    name: String,
+    symbol: String,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+) -> Instruction {
+    let vault_pubkey = get_vault_address(&message.mint);
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&message.mint);
+    let token_whitelist_pubkey = get_token_whitelist_address(&message.mint);
+    let delegate_pubkey = get_deposit_delegate_address();
+    let nonce_pubkey = get_nonce_address(&message.owner);
+
+    let deposit_pubkey = get_deposit_address(&message.owner, message.deposit_seed);
+
+    let data = TokenProxyInstruction::DepositMultiTokenSolGasless {
+        deposit_seed: message.deposit_seed,
+        name,
+        symbol,
+        amount: message.amount,
+        recipient: message.recipient,
+        value: message.value,
+        expected_evers: message.expected_evers,
+        payload,
+        payload_schema,
+        nonce: message.nonce,
+        expiry: message.expiry,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new_readonly(message.owner, false),
+        AccountMeta::new(message.owner_token_account, false),
+        AccountMeta::new(vault_pubkey, false),
+        AccountMeta::new(deposit_pubkey, false),
+        AccountMeta::new(message.mint, false),
+        AccountMeta::new(multivault_pubkey, false),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(delegate_pubkey, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+        AccountMeta::new(nonce_pubkey, false),
+        AccountMeta::new_readonly(token_whitelist_pubkey, false),
+    ];
+
+    if let Some(schema_id) = payload_schema {
+        accounts.push(AccountMeta::new_readonly(
+            get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_native_sol_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    deposit_seed: u128,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+) -> Instruction {
+    let mint_pubkey = spl_token::native_mint::id();
+
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let token_whitelist_pubkey = get_token_whitelist_address(&mint_pubkey);
+
+    let deposit_pubkey = get_deposit_address(&author_pubkey, deposit_seed);
+
+    let data = TokenProxyInstruction::DepositNativeSol {
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(vault_pubkey, false),
+        AccountMeta::new(deposit_pubkey, false),
+        AccountMeta::new_readonly(mint_pubkey, false),
+        AccountMeta::new(multivault_pubkey, false),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(token_whitelist_pubkey, false),
+    ];
+
+    if let Some(schema_id) = payload_schema {
+        accounts.push(AccountMeta::new_readonly(
+            get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_multi_token_batch_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    deposit_seed: u128,
+    entries: Vec<DepositBatchAmount>,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let deposit_pubkey = get_deposit_address(&author_pubkey, deposit_seed);
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(deposit_pubkey, false),
+        AccountMeta::new(multivault_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+    ];
+
+    for entry in &entries {
+        let author_token_pubkey =
+            spl_associated_token_account::get_associated_token_address(&author_pubkey, &entry.mint);
+
+        accounts.push(AccountMeta::new(author_token_pubkey, false));
+        accounts.push(AccountMeta::new(get_vault_address(&entry.mint), false));
+        accounts.push(AccountMeta::new_readonly(entry.mint, false));
+        accounts.push(AccountMeta::new(
+            get_token_settings_sol_address(&entry.mint),
+            false,
+        ));
+    }
+
+    for entry in &entries {
+        accounts.push(AccountMeta::new_readonly(
+            get_token_whitelist_address(&entry.mint),
+            false,
+        ));
+    }
+
+    if let Some(schema_id) = payload_schema {
+        accounts.push(AccountMeta::new_readonly(
+            get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    let data = TokenProxyInstruction::DepositMultiTokenBatch {
+        deposit_seed,
+        entries,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdrawal_multi_token_ever_request_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    chain_id: u32,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    recipient: Pubkey,
+    amount: u128,
+    payload: Vec<u8>,
+    attached_amount: u64,
+) -> Instruction {
+    let withdrawal_pubkey = get_withdrawal_ever_address(
+        chain_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        token,
+        name.clone(),
+        symbol.clone(),
+        decimals,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+    let event_index_pubkey =
+        get_event_index_address(event_timestamp, event_transaction_lt, &event_configuration);
+    let settings_pubkey = get_settings_address();
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader_interface::id());
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(event_index_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(rl_settings_pubkey, false),
+        AccountMeta::new_readonly(relay_round_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+    ];
+
+    if !payload.is_empty() {
+        let mint_pubkey = get_mint_address(&token);
+        let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient);
+
+        accounts.push(AccountMeta::new(proxy_pubkey, false));
+        accounts.push(AccountMeta::new(mint_pubkey, false));
+        accounts.push(AccountMeta::new(spl_token::id(), false));
+    }
+
+    // Token settings keep the required votes override
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+    accounts.push(AccountMeta::new_readonly(token_settings_pubkey, false));
+
+    // Token metadata is checked against the known-token mirror
+    let known_token_pubkey = get_known_token_address(&token);
+    accounts.push(AccountMeta::new_readonly(known_token_pubkey, false));
+
+    // Registered event configurations are checked in the Round Loader
+    let rl_event_configuration_pubkey =
+        round_loader_interface::get_event_configuration_address(&event_configuration);
+    accounts.push(AccountMeta::new_readonly(
+        rl_event_configuration_pubkey,
+        false,
+    ));
+
+    // Withdrawal is counted in the stats of its round until it is final
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    accounts.push(AccountMeta::new(relay_round_stats_pubkey, false));
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenEverRequest {
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        token,
+        name,
+        symbol,
+        decimals,
+        recipient,
+        amount,
+        payload,
+        attached_amount,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdrawal_multi_token_sol_request_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    chain_id: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    mint: Pubkey,
+    round_number: u32,
+    recipient: Pubkey,
+    amount: u128,
+    payload: Vec<u8>,
+    attached_amount: u64,
+    ever_configuration: Option<Pubkey>,
+) -> Instruction {
+    let withdrawal_pubkey = get_withdrawal_sol_address(
+        chain_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        mint,
+        recipient,
+        amount,
+        payload.clone(),
+    );
+    let event_index_pubkey =
+        get_event_index_address(event_timestamp, event_transaction_lt, &event_configuration);
+
+    let settings_pubkey = get_settings_address();
+    let token_settings_pubkey =
+        get_namespaced_token_settings_sol_address(&mint, ever_configuration.as_ref());
+
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader_interface::id());
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(event_index_pubkey, false),
+        AccountMeta::new_readonly(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(rl_settings_pubkey, false),
+        AccountMeta::new_readonly(relay_round_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+    ];
+
+    if !payload.is_empty() {
+        let proxy_pubkey = get_proxy_address(&mint, &recipient);
+
+        accounts.push(AccountMeta::new(proxy_pubkey, false));
+        accounts.push(AccountMeta::new(mint, false));
+        accounts.push(AccountMeta::new(spl_token::id(), false));
+    }
+
+    // Registered event configurations are checked in the Round Loader
+    let rl_event_configuration_pubkey =
+        round_loader_interface::get_event_configuration_address(&event_configuration);
+    accounts.push(AccountMeta::new_readonly(
+        rl_event_configuration_pubkey,
+        false,
+    ));
+
+    // Withdrawal is counted in the stats of its round until it is final
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    accounts.push(AccountMeta::new(relay_round_stats_pubkey, false));
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenSolRequest {
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        recipient,
+        amount,
+        payload,
+        attached_amount,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+/// Append the stats of the withdrawal round to an instruction that may finalize the withdrawal,
+/// releasing the round once the withdrawal is processed or cancelled
+pub fn with_relay_round_stats(mut ix: Instruction, round_number: u32) -> Instruction {
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    ix.accounts
+        .push(AccountMeta::new(relay_round_stats_pubkey, false));
+    ix
+}
+
+pub fn vote_for_withdrawal_request_ix(
+    voter_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    round_number: u32,
+    vote: Vote,
+) -> Instruction {
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+
+    let data = TokenProxyInstruction::VoteForWithdrawRequest { vote }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(voter_pubkey, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new(relay_round_stats_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn vote_for_withdrawal_request_with_proof_ix(
+    voter_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    round_number: u32,
+    vote: Vote,
+    proof: MerkleProof,
+) -> Instruction {
+    let mut ix = vote_for_withdrawal_request_ix(
+        voter_pubkey,
+        withdrawal_pubkey,
+        token_settings_pubkey,
+        round_number,
+        vote,
+    );
+    ix.data = TokenProxyInstruction::VoteForWithdrawRequestWithProof { vote, proof }
+        .try_to_vec()
+        .expect("pack");
+    ix
+}
+
+pub fn vote_for_withdrawal_request_with_index_ix(
+    voter_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    round_number: u32,
+    vote: Vote,
+    index: u32,
+) -> Instruction {
+    let mut ix = vote_for_withdrawal_request_ix(
+        voter_pubkey,
+        withdrawal_pubkey,
+        token_settings_pubkey,
+        round_number,
+        vote,
+    );
+    ix.data = TokenProxyInstruction::VoteForWithdrawRequestWithIndex { vote, index }
+        .try_to_vec()
+        .expect("pack");
+    ix
+}
+
+pub fn withdrawal_ever_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    token: EverAddress,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenEver
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn withdrawal_ever_with_payload_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    token: EverAddress,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+
+    let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenEver
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(proxy_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+/// Associated token account the withdrawal of the mint is paid out to.
+///
+/// Program-derived recipients, e.g. DAO treasuries, are off the curve and are rejected unless
+/// `allow_owner_off_curve` is set, the same way the SPL token clients do.
+pub fn get_recipient_token_address(
+    recipient_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    allow_owner_off_curve: bool,
+) -> Result<Pubkey, ProgramError> {
+    if !allow_owner_off_curve && !recipient_pubkey.is_on_curve() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(spl_associated_token_account::get_associated_token_address(
+        recipient_pubkey,
+        mint_pubkey,
+    ))
+}
+
+/// Create the associated token account of the withdrawal recipient unless it already exists
+pub fn create_recipient_token_account_ix(
+    funder_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    allow_owner_off_curve: bool,
+) -> Result<Instruction, ProgramError> {
+    get_recipient_token_address(&recipient_pubkey, &mint_pubkey, allow_owner_off_curve)?;
+
+    Ok(
+        spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            &funder_pubkey,
+            &recipient_pubkey,
+            &mint_pubkey,
+            &spl_token::id(),
+        ),
+    )
+}
+
+pub fn create_ever_token_ix(
+    funder_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    token: EverAddress,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+
+    let recipient_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenEver
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            // If token settings account is not created
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(recipient_pubkey, false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn create_ever_token_with_payload_ix(
+    funder_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    token: EverAddress,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let mint_pubkey = get_mint_address(&token);
+    let token_settings_pubkey = get_token_settings_ever_address(&token);
+
+    let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
+
+    let recipient_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenEver
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(proxy_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            // If token settings account is not created
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new_readonly(recipient_pubkey, false),
+            AccountMeta::new_readonly(spl_associated_token_account::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn withdrawal_sol_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenSol
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn withdrawal_sol_unwrap_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+) -> Instruction {
+    let mint_pubkey = spl_token::native_mint::id();
+
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let recipient_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&recipient_pubkey, &mint_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenSolUnwrap
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new(recipient_pubkey, true),
+        ],
+        data,
+    }
+}
+
+pub fn withdrawal_sol_with_payload_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenSol
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(proxy_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn change_guardian_ix(owner: Pubkey, new_guardian: Pubkey) -> Instruction {
+    change_guardian_with_cosigners_ix(owner, new_guardian, &[])
+}
+
+/// Change the guardian co-signed by the current one, required unless it is inactive
+pub fn change_guardian_with_cosigners_ix(
+    owner: Pubkey,
+    new_guardian: Pubkey,
+    cosigners: &[Pubkey],
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeGuardian { new_guardian }
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(owner, true),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(program_data_pubkey, false),
+        AccountMeta::new(audit_log_pubkey, false),
+    ];
+    accounts.extend(
+        cosigners
+            .iter()
+            .map(|cosigner| AccountMeta::new_readonly(*cosigner, true)),
+    );
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn guardian_heartbeat_ix(guardian: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::GuardianHeartbeat
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(guardian, true),
+            AccountMeta::new(settings_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_manager_ix(owner: Pubkey, new_manager: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeManager { new_manager }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_withdrawal_manager_ix(owner: Pubkey, new_withdrawal_manager: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeWithdrawalManager {
+        new_withdrawal_manager,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_deposit_limit_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_deposit_limit: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(manager_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_required_votes_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    required_votes_override: Option<u32>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::ChangeRequiredVotes {
+        required_votes_override,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(manager_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_confirmation_tiers_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    confirmation_tiers: Vec<ConfirmationTier>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::ChangeConfirmationTiers { confirmation_tiers }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(manager_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_withdrawal_limits_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::ChangeWithdrawalLimits {
+        new_withdrawal_limit,
+        new_withdrawal_daily_limit,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(manager_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn schedule_limit_change_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
+    effective_at: i64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::ScheduleLimitChange {
+        new_withdrawal_limit,
+        new_withdrawal_daily_limit,
+        effective_at,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(manager_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_deposit_limit_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_deposit_limit: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_withdrawal_limits_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeWithdrawalLimits {
+        new_withdrawal_limit,
+        new_withdrawal_daily_limit,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn enable_emergency_ix(guardian_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::EnableEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(guardian_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn enable_emergency_by_owner_ix(owner_pubkey: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::EnableEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn disable_emergency_ix(owner_pubkey: Pubkey) -> Instruction {
+    disable_emergency_with_cosigners_ix(owner_pubkey, &[])
+}
+
+pub fn disable_emergency_with_cosigners_ix(
+    owner_pubkey: Pubkey,
+    cosigners: &[Pubkey],
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::DisableEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(owner_pubkey, true),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(program_data_pubkey, false),
+        AccountMeta::new(audit_log_pubkey, false),
+    ];
+    accounts.extend(
+        cosigners
+            .iter()
+            .map(|cosigner| AccountMeta::new_readonly(*cosigner, true)),
+    );
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn set_emergency_cosigners_ix(
+    owner_pubkey: Pubkey,
+    cosigners: Vec<Pubkey>,
+    threshold: u8,
+    current_cosigners: &[Pubkey],
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::SetEmergencyCosigners {
+        cosigners,
+        threshold,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(owner_pubkey, true),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(program_data_pubkey, false),
+        AccountMeta::new(audit_log_pubkey, false),
+    ];
+    accounts.extend(
+        current_cosigners
+            .iter()
+            .map(|cosigner| AccountMeta::new_readonly(*cosigner, true)),
+    );
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn enable_emergency_token_ix(
+    guardian_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::EnableTokenEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(guardian_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn enable_emergency_token_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::EnableTokenEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_paused_operations_ix(
+    authority_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    paused_operations: u8,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::ChangePausedOperations { paused_operations }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_paused_operations_by_owner_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    paused_operations: u8,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangePausedOperations { paused_operations }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn disable_emergency_token_ix(
+    owner_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::DisableTokenEmergencyMode
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn approve_withdrawal_ever_ix(
+    authority_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    token: &EverAddress,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+
+    let data = TokenProxyInstruction::ApproveWithdrawEver
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn approve_withdrawal_sol_ix(
+    authority_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::ApproveWithdrawSol
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn update_fee_ix(
+    authority_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    fee_type: FeeType,
+    multiplier: u64,
+    divisor: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::UpdateFee {
+        fee_type,
+        multiplier,
+        divisor,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn update_token_name_ix(
+    authority_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    symbol: String,
+    name: String,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::UpdateTokenName { symbol, name }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn withdrawal_ever_fee_ix(
+    authority_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    token: &EverAddress,
+    amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+
+    let data = TokenProxyInstruction::WithdrawEverFee { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn withdrawal_sol_fee_ix(
+    authority_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawSolFee { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn claim_partial_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::ClaimPartial { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(recipient_pubkey, true),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn change_bounty_for_withdrawal_sol_ix(
+    author_pubkey: &Pubkey,
+    withdrawal_pubkey: &Pubkey,
+    bounty: u64,
+) -> Instruction {
+    let data = TokenProxyInstruction::ChangeBountyForWithdrawSol { bounty }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(*author_pubkey, true),
+            AccountMeta::new(*withdrawal_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn transfer_withdrawal_authorship_ix(
+    author_pubkey: &Pubkey,
+    withdrawal_pubkey: &Pubkey,
+    new_author: Pubkey,
+) -> Instruction {
+    let data = TokenProxyInstruction::TransferWithdrawalAuthorship { new_author }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*author_pubkey, true),
+            AccountMeta::new(*withdrawal_pubkey, false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn cancel_withdrawal_sol_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let deposit_pubkey = get_deposit_address(&author_pubkey, deposit_seed);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::CancelWithdrawSol {
+        deposit_seed,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(multivault_pubkey, false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn fill_withdrawal_sol_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    deposit_seed: u128,
+    recipient: EverAddress,
+    amount: u64,
+    withdrawals: Vec<(Pubkey, Pubkey)>,
+    vault_pubkey: Option<Pubkey>,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+) -> Instruction {
+    let author_token_pubkey =
+        spl_associated_token_account::get_associated_token_address(&author_pubkey, &mint_pubkey);
+
+    let settings_pubkey = get_settings_address();
+    let multivault_pubkey = get_multivault_address();
+    let deposit_pubkey = get_deposit_address(&author_pubkey, deposit_seed);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::FillWithdrawSol {
+        deposit_seed,
+        recipient,
+        amount,
+        value,
+        expected_evers,
+        payload,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(author_token_pubkey, false),
+        AccountMeta::new(mint_pubkey, false),
+        AccountMeta::new(deposit_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(token_settings_pubkey, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new(multivault_pubkey, false),
+    ];
+
+    for (withdrawal_pubkey, to_pubkey) in withdrawals {
+        let recipient_token_pubkey =
+            spl_associated_token_account::get_associated_token_address(&to_pubkey, &mint_pubkey);
+
+        accounts.push(AccountMeta::new(withdrawal_pubkey, false));
+        accounts.push(AccountMeta::new(recipient_token_pubkey, false));
+    }
+
+    if let Some(vault_pubkey) = vault_pubkey {
+        accounts.push(AccountMeta::new(vault_pubkey, false));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn withdrawal_proxy_ix(
+    recipient_pubkey: Pubkey,
+    recipient_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let proxy_pubkey = get_proxy_address(&mint_pubkey, &recipient_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawProxy { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(recipient_pubkey, true),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(proxy_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn close_deposit_ix(author_pubkey: Pubkey, deposit_pubkey: Pubkey) -> Instruction {
+    let data = TokenProxyInstruction::CloseDeposit
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(deposit_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn close_withdrawal_ix(
+    withdrawal_pubkey: Pubkey,
+    withdrawal_author_pubkey: Pubkey,
+) -> Instruction {
+    let data = TokenProxyInstruction::CloseWithdrawal
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(withdrawal_author_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn withdrawal_multi_vault_ix(
+    authority_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let multi_vault_pubkey = get_multivault_address();
+
+    let data = TokenProxyInstruction::WithdrawMultiVault { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(recipient_pubkey, false),
+            AccountMeta::new(multi_vault_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_token_settings_sol_ix(
+    funder_pubkey: Pubkey,
+    manager_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    name: String,
+    symbol: String,
+    deposit_limit: u64,
+    withdrawal_limit: u64,
+    withdrawal_daily_limit: u64,
+    allow_freeze_authority: bool,
+    ever_configuration: Option<Pubkey>,
+) -> Instruction {
+    let vault_pubkey = get_namespaced_vault_address(&mint_pubkey, ever_configuration.as_ref());
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey =
+        get_namespaced_token_settings_sol_address(&mint_pubkey, ever_configuration.as_ref());
+
+    let data = TokenProxyInstruction::CreateTokenSettingsSol {
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        allow_freeze_authority,
+        ever_configuration,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(manager_pubkey, true),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_token_settings_sol_by_owner_ix(
+    funder_pubkey: Pubkey,
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    name: String,
+    symbol: String,
+    deposit_limit: u64,
+    withdrawal_limit: u64,
+    withdrawal_daily_limit: u64,
+    allow_freeze_authority: bool,
+    ever_configuration: Option<Pubkey>,
+) -> Instruction {
+    let vault_pubkey = get_namespaced_vault_address(&mint_pubkey, ever_configuration.as_ref());
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey =
+        get_namespaced_token_settings_sol_address(&mint_pubkey, ever_configuration.as_ref());
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::CreateTokenSettingsSol {
+        name,
+        symbol,
+        deposit_limit,
+        withdrawal_limit,
+        withdrawal_daily_limit,
+        allow_freeze_authority,
+        ever_configuration,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn create_admin_audit_log_ix(funder_pubkey: Pubkey) -> Instruction {
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::CreateAdminAuditLog
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(audit_log_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn create_withdrawal_multi_token_ever_request_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    event: &WithdrawalMultiTokenEverEvent,
+) -> Instruction {
+    let withdrawal_pubkey = get_withdrawal_ever_address(
+        event.chain_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        event.token,
+        event.name.clone(),
+        event.symbol.clone(),
+        event.decimals,
+        event.recipient,
+        event.amount,
+        event.payload.clone(),
+    );
+    let event_index_pubkey =
+        get_event_index_address(event_timestamp, event_transaction_lt, &event_configuration);
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+
+    let event_data = hash(&event.try_to_vec().expect("pack"));
+
+    let data = TokenProxyInstruction::CreateWithdrawMultiTokenEverRequest {
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        event_data,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(event_index_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn write_withdrawal_multi_token_ever_request_ix(
+    author_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    offset: u32,
+    bytes: Vec<u8>,
+) -> Instruction {
+    let data = TokenProxyInstruction::WriteWithdrawMultiTokenEverRequest { offset, bytes }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(author_pubkey, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn finalize_withdrawal_multi_token_ever_request_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    round_number: u32,
+    event_configuration: Pubkey,
+    event: &WithdrawalMultiTokenEverEvent,
+    attached_amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader_interface::id());
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(rl_settings_pubkey, false),
+        AccountMeta::new_readonly(relay_round_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+    ];
+
+    if !event.payload.is_empty() {
+        let mint_pubkey = get_mint_address(&event.token);
+        let proxy_pubkey = get_proxy_address(&mint_pubkey, &event.recipient);
+
+        accounts.push(AccountMeta::new(proxy_pubkey, false));
+        accounts.push(AccountMeta::new(mint_pubkey, false));
+        accounts.push(AccountMeta::new(spl_token::id(), false));
+    }
+
+    // Token settings keep the required votes override
+    let token_settings_pubkey = get_token_settings_ever_address(&event.token);
+    accounts.push(AccountMeta::new_readonly(token_settings_pubkey, false));
+
+    // Token metadata is checked against the known-token mirror
+    let known_token_pubkey = get_known_token_address(&event.token);
+    accounts.push(AccountMeta::new_readonly(known_token_pubkey, false));
+
+    // Registered event configurations are checked in the Round Loader
+    let rl_event_configuration_pubkey =
+        round_loader_interface::get_event_configuration_address(&event_configuration);
+    accounts.push(AccountMeta::new_readonly(
+        rl_event_configuration_pubkey,
+        false,
+    ));
+
+    // Withdrawal is counted in the stats of its round until it is final
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    accounts.push(AccountMeta::new(relay_round_stats_pubkey, false));
+
+    let data = TokenProxyInstruction::FinalizeWithdrawMultiTokenEverRequest { attached_amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn change_hook_program_ix(
+    manager_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    new_hook_program: Option<Pubkey>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::ChangeHookProgram { new_hook_program }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(manager_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn retry_pending_withdrawal_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    vault_pubkey: Option<Pubkey>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let data = TokenProxyInstruction::RetryPendingWithdrawal
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(recipient_pubkey, false),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(mint_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+    ];
+
+    if let Some(vault_pubkey) = vault_pubkey {
+        accounts.push(AccountMeta::new(vault_pubkey, false));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn create_settings_history_ix(funder_pubkey: Pubkey) -> Instruction {
+    let settings_history_pubkey = get_settings_history_address();
+
+    let data = TokenProxyInstruction::CreateSettingsHistory
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(settings_history_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn create_settings_history_page_ix(funder_pubkey: Pubkey, page: u64) -> Instruction {
+    let settings_history_page_pubkey = get_settings_history_page_address(page);
+
+    let data = TokenProxyInstruction::CreateSettingsHistoryPage { page }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(settings_history_page_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn create_deposit_index_ix(funder_pubkey: Pubkey, owner_pubkey: Pubkey) -> Instruction {
+    let deposit_index_pubkey = get_deposit_index_address(&owner_pubkey);
+
+    let data = TokenProxyInstruction::CreateDepositIndex
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(owner_pubkey, false),
+            AccountMeta::new(deposit_index_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn preview_deposit_ever_ix(
+    funder_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    token: &EverAddress,
+    amount: u64,
+    value: u64,
+) -> Instruction {
+    let mint_pubkey = get_mint_address(token);
+    let settings_pubkey = get_settings_address();
+    let token_settings_pubkey = get_token_settings_ever_address(token);
+
+    let data = TokenProxyInstruction::PreviewDeposit { amount, value }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(funder_pubkey, false),
+            AccountMeta::new_readonly(author_token_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn preview_deposit_sol_ix(
+    funder_pubkey: Pubkey,
+    author_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    amount: u64,
+    value: u64,
+) -> Instruction {
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let settings_pubkey = get_settings_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+
+    let data = TokenProxyInstruction::PreviewDeposit { amount, value }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(funder_pubkey, false),
+            AccountMeta::new_readonly(author_token_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(vault_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn recover_frozen_vault_ix(
+    funder_pubkey: Pubkey,
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    vault_generation: u8,
+) -> Instruction {
+    let vault_pubkey = get_vault_address_of_generation(&mint_pubkey, vault_generation);
+    let new_vault_pubkey = get_vault_address_of_generation(&mint_pubkey, vault_generation + 1);
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::RecoverFrozenVault
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(owner_pubkey, true),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(vault_pubkey, false),
+            AccountMeta::new(new_vault_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn recover_authority_ix(
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    ever_configuration: Option<&Pubkey>,
+    vault_generation: u8,
+    new_program_id: Pubkey,
+    cosigners: &[Pubkey],
+) -> Instruction {
+    let vault_pubkey =
+        find_vault_address(&id(), &mint_pubkey, ever_configuration, vault_generation).0;
+    let new_vault_pubkey =
+        find_vault_address(&new_program_id, &mint_pubkey, ever_configuration, 0).0;
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey =
+        get_namespaced_token_settings_sol_address(&mint_pubkey, ever_configuration);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::RecoverAuthority { new_program_id }
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(owner_pubkey, true),
+        AccountMeta::new_readonly(token_settings_pubkey, false),
+        AccountMeta::new_readonly(mint_pubkey, false),
+        AccountMeta::new(vault_pubkey, false),
+        AccountMeta::new(new_vault_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(program_data_pubkey, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new(audit_log_pubkey, false),
+    ];
+    accounts.extend(
+        cosigners
+            .iter()
+            .map(|cosigner| AccountMeta::new_readonly(*cosigner, true)),
+    );
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn schedule_vault_rotation_ix(
+    funder_pubkey: Pubkey,
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    vault_generation: u8,
+) -> Instruction {
+    let vault_pubkey = get_vault_address_of_generation(&mint_pubkey, vault_generation);
+    let vault_rotation_pubkey = get_vault_rotation_address(&vault_pubkey);
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ScheduleVaultRotation
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(owner_pubkey, true),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new(vault_rotation_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn rotate_vault_ix(
+    funder_pubkey: Pubkey,
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    vault_generation: u8,
+) -> Instruction {
+    let vault_pubkey = get_vault_address_of_generation(&mint_pubkey, vault_generation);
+    let new_vault_pubkey = get_vault_address_of_generation(&mint_pubkey, vault_generation + 1);
+    let vault_rotation_pubkey = get_vault_rotation_address(&vault_pubkey);
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::RotateVault
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(owner_pubkey, true),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(new_vault_pubkey, false),
+            AccountMeta::new(vault_rotation_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn deposit_liquidity_ix(
+    funder_pubkey: Pubkey,
+    provider_pubkey: Pubkey,
+    provider_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let lp_position_pubkey = get_lp_position_address(&mint_pubkey, &provider_pubkey);
+
+    let data = TokenProxyInstruction::DepositLiquidity { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(provider_pubkey, true),
+            AccountMeta::new(provider_token_pubkey, false),
+            AccountMeta::new(lp_position_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn withdraw_liquidity_ix(
+    provider_pubkey: Pubkey,
+    provider_token_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    shares: u64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let lp_position_pubkey = get_lp_position_address(&mint_pubkey, &provider_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawLiquidity { shares }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(provider_pubkey, true),
+            AccountMeta::new(provider_token_pubkey, false),
+            AccountMeta::new(lp_position_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn change_yield_adapter_ix(
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    new_yield_adapter: Option<Pubkey>,
+    max_deployed_share: u8,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeYieldAdapter {
+        new_yield_adapter,
+        max_deployed_share,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(owner_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn deploy_liquidity_ix(
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    yield_adapter: Pubkey,
+    amount: u64,
+    adapter_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::DeployLiquidity { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(owner_pubkey, true),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(vault_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(program_data_pubkey, false),
+        AccountMeta::new(audit_log_pubkey, false),
+        AccountMeta::new_readonly(yield_adapter, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+    ];
+
+    accounts.extend(adapter_accounts);
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn recall_liquidity_ix(
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    yield_adapter: Pubkey,
+    amount: u64,
+    adapter_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let vault_pubkey = get_vault_address(&mint_pubkey);
+    let token_settings_pubkey = get_token_settings_sol_address(&mint_pubkey);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::RecallLiquidity { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(owner_pubkey, true),
+        AccountMeta::new(token_settings_pubkey, false),
+        AccountMeta::new(vault_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(program_data_pubkey, false),
+        AccountMeta::new(audit_log_pubkey, false),
+        AccountMeta::new_readonly(yield_adapter, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    accounts.extend(adapter_accounts);
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+/// Assemble a receipt of the withdrawal from its account data and the data of the relay round
+/// it is voted in. `slot` is the slot the accounts were read at.
+pub fn build_withdrawal_receipt(
+    withdrawal_pubkey: Pubkey,
+    withdrawal_data: &[u8],
+    relay_round_data: &[u8],
+    slot: u64,
+) -> Result<WithdrawalReceipt, ProgramError> {
+    let withdrawal = Proposal::unpack_from_slice(withdrawal_data)?;
+    let relay_round = RelayRound::unpack(relay_round_data)?;
+
+    if relay_round.round_number != withdrawal.round_number {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let status = WithdrawalTokenStatus::deserialize_reader(&mut withdrawal.meta.as_slice())?;
+
+    Ok(WithdrawalReceipt {
+        withdrawal: withdrawal_pubkey,
+        author: withdrawal.author,
+        round_number: withdrawal.round_number,
+        required_votes: withdrawal.required_votes,
+        pda: withdrawal.pda,
+        event: withdrawal.event,
+        status,
+        relays: relay_round.relays,
+        signers: withdrawal.signers,
+        round_end: relay_round.round_end,
+        slot,
+    })
+}
+
+/// Withdrawal votes cast by each relay of the round
+pub fn get_relay_vote_counts(
+    relay_round_stats_data: &[u8],
+    relay_round_data: &[u8],
+) -> Result<Vec<(Pubkey, u32)>, ProgramError> {
+    let relay_round_stats = RelayRoundStats::unpack(relay_round_stats_data)?;
+    let relay_round = RelayRound::unpack(relay_round_data)?;
+
+    if relay_round.round_number != relay_round_stats.round_number {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(relay_round
+        .relays
+        .into_iter()
+        .enumerate()
+        .map(|(index, relay)| (relay, relay_round_stats.relay_votes(index)))
+        .collect())
+}
+
+/// Relays of the round that cast less than `min_votes` withdrawal votes
+pub fn get_inactive_relays(
+    relay_round_stats_data: &[u8],
+    relay_round_data: &[u8],
+    min_votes: u32,
+) -> Result<Vec<Pubkey>, ProgramError> {
+    Ok(
+        get_relay_vote_counts(relay_round_stats_data, relay_round_data)?
+            .into_iter()
+            .filter(|(_, votes)| *votes < min_votes)
+            .map(|(relay, _)| relay)
+            .collect(),
+    )
+}
+
+pub fn attest_receipt_ix(receipt: WithdrawalReceipt) -> Instruction {
+    let withdrawal_pubkey = receipt.withdrawal;
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        receipt.round_number,
+    );
+
+    let data = TokenProxyInstruction::AttestReceipt { receipt }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(withdrawal_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn update_reward_config_ix(owner_pubkey: Pubkey, relay_share: u8) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let reward_config_pubkey = get_reward_config_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::UpdateRewardConfig { relay_share }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(reward_config_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn update_discount_config_ix(
+    owner_pubkey: Pubkey,
+    mint_pubkey: Pubkey,
+    min_stake: u64,
+    discount_bps: u16,
+    unstake_cooldown: i64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let discount_config_pubkey = get_discount_config_address();
+    let discount_vault_pubkey = get_discount_vault_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::UpdateDiscountConfig {
+        mint: mint_pubkey,
+        min_stake,
+        discount_bps,
+        unstake_cooldown,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(discount_config_pubkey, false),
+            AccountMeta::new(discount_vault_pubkey, false),
+            AccountMeta::new_readonly(mint_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn stake_for_discount_ix(
+    owner_pubkey: Pubkey,
+    owner_token_pubkey: Pubkey,
+    amount: u64,
+) -> Instruction {
+    let discount_config_pubkey = get_discount_config_address();
+    let discount_vault_pubkey = get_discount_vault_address();
+    let discount_stake_pubkey = get_discount_stake_address(&owner_pubkey);
+
+    let data = TokenProxyInstruction::StakeForDiscount { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner_pubkey, true),
+            AccountMeta::new(owner_token_pubkey, false),
+            AccountMeta::new(discount_stake_pubkey, false),
+            AccountMeta::new(discount_vault_pubkey, false),
+            AccountMeta::new_readonly(discount_config_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn request_discount_unstake_ix(owner_pubkey: Pubkey, amount: u64) -> Instruction {
+    let discount_config_pubkey = get_discount_config_address();
+    let discount_stake_pubkey = get_discount_stake_address(&owner_pubkey);
+
+    let data = TokenProxyInstruction::RequestDiscountUnstake { amount }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(owner_pubkey, true),
+            AccountMeta::new(discount_stake_pubkey, false),
+            AccountMeta::new_readonly(discount_config_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn withdraw_discount_stake_ix(owner_pubkey: Pubkey, owner_token_pubkey: Pubkey) -> Instruction {
+    let discount_config_pubkey = get_discount_config_address();
+    let discount_vault_pubkey = get_discount_vault_address();
+    let discount_stake_pubkey = get_discount_stake_address(&owner_pubkey);
+
+    let data = TokenProxyInstruction::WithdrawDiscountStake
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new_readonly(owner_pubkey, true),
+            AccountMeta::new(owner_token_pubkey, false),
+            AccountMeta::new(discount_stake_pubkey, false),
+            AccountMeta::new(discount_vault_pubkey, false),
+            AccountMeta::new_readonly(discount_config_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn create_relay_round_stats_ix(funder_pubkey: Pubkey, round_number: u32) -> Instruction {
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+
+    let data = TokenProxyInstruction::CreateRelayRoundStats
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(relay_round_stats_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn distribute_round_rewards_ix(
+    funder_pubkey: Pubkey,
+    token_settings_pubkey: Pubkey,
+    round_number: u32,
+) -> Instruction {
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    let round_reward_pubkey = get_round_reward_address(&token_settings_pubkey, round_number);
+    let reward_config_pubkey = get_reward_config_address();
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+
+    let data = TokenProxyInstruction::DistributeRoundRewards
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(relay_round_stats_pubkey, false),
+            AccountMeta::new(round_reward_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(reward_config_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn claim_relay_reward_ix(
+    relay_pubkey: Pubkey,
+    relay_token_pubkey: Pubkey,
+    kind: &TokenKind,
+    round_number: u32,
+) -> Instruction {
+    let (token_settings_pubkey, mint_pubkey, vault_pubkey) = match kind {
+        TokenKind::Ever { mint, token, .. } => {
+            (get_token_settings_ever_address(token), *mint, None)
+        }
+        TokenKind::Solana { mint, vault } => {
+            (get_token_settings_sol_address(mint), *mint, Some(*vault))
+        }
+    };
+    let round_reward_pubkey = get_round_reward_address(&token_settings_pubkey, round_number);
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+
+    let data = TokenProxyInstruction::ClaimRelayReward
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new(relay_pubkey, true),
+        AccountMeta::new(relay_token_pubkey, false),
+        AccountMeta::new(round_reward_pubkey, false),
+        AccountMeta::new_readonly(relay_round_stats_pubkey, false),
+        AccountMeta::new_readonly(relay_round_pubkey, false),
+        AccountMeta::new_readonly(token_settings_pubkey, false),
+        AccountMeta::new(mint_pubkey, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+    ];
+
+    if let Some(vault_pubkey) = vault_pubkey {
+        accounts.push(AccountMeta::new(vault_pubkey, false));
+    }
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn backfill_event_index_ix(
+    funder_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    pda: &PDA,
+) -> Instruction {
+    let event_index_pubkey = get_event_index_address(
+        pda.event_timestamp,
+        pda.event_transaction_lt,
+        &pda.event_configuration,
+    );
+
+    let data = TokenProxyInstruction::BackfillEventIndex
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new_readonly(withdrawal_pubkey, false),
+            AccountMeta::new(event_index_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn veto_withdrawal_ix(
+    guardian: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    reason: String,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let data = TokenProxyInstruction::VetoWithdrawal { reason }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(guardian, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn disable_veto_ix(owner: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::DisableVeto
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn expand_settings_ix(funder_pubkey: Pubkey, owner: Pubkey, chain_id: u32) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ExpandSettings { chain_id }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn tick_ix(token_settings_pubkeys: &[Pubkey]) -> Instruction {
+    let data = TokenProxyInstruction::Tick.try_to_vec().expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: token_settings_pubkeys
+            .iter()
+            .map(|pubkey| AccountMeta::new(*pubkey, false))
+            .collect(),
+        data,
+    }
+}
+
+pub fn audit_settings_ix(token_settings_pubkeys: &[Pubkey]) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader_interface::id());
+
+    let data = TokenProxyInstruction::AuditSettings
+        .try_to_vec()
+        .expect("pack");
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(settings_pubkey, false),
+        AccountMeta::new_readonly(rl_settings_pubkey, false),
+    ];
+
+    accounts.extend(
+        token_settings_pubkeys
+            .iter()
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false)),
+    );
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn grant_role_ix(owner: Pubkey, role: Role, authority: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::GrantRole { role, authority }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn revoke_role_ix(owner: Pubkey, role: Role, authority: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::RevokeRole { role, authority }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_instruction_permission_ix(owner: Pubkey, instruction: u8, roles: u16) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeInstructionPermission { instruction, roles }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn set_payload_schema_ix(
+    authority_pubkey: Pubkey,
+    schema_id: u32,
+    min_len: u32,
+    max_len: u32,
+    header_len: u32,
+    header_hash: Option<[u8; 32]>,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let payload_schema_pubkey = get_payload_schema_address(schema_id);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::SetPayloadSchema {
+        schema_id,
+        min_len,
+        max_len,
+        header_len,
+        header_hash,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(payload_schema_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn set_known_token_ix(
+    authority_pubkey: Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    decimals: u8,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let known_token_pubkey = get_known_token_address(&token);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::SetKnownToken {
+        token,
+        name,
+        symbol,
+        decimals,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(known_token_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn set_exempt_account_ix(
+    authority_pubkey: Pubkey,
+    exempt_authority: Pubkey,
+    expires_at: i64,
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let exempt_account_pubkey = get_exempt_account_address(&exempt_authority);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::SetExemptAccount {
+        authority: exempt_authority,
+        expires_at,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(exempt_account_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+/// Let a deposit or withdrawal use the limit exemption of the authority, the use is recorded
+/// in the admin audit log
+pub fn with_limit_exemption(mut ix: Instruction, exempt_authority: &Pubkey) -> Instruction {
+    ix.accounts.push(AccountMeta::new_readonly(
+        get_exempt_account_address(exempt_authority),
+        false,
+    ));
+
+    let audit_log_pubkey = get_admin_audit_log_address();
+    if !ix
+        .accounts
+        .iter()
+        .any(|meta| meta.pubkey == audit_log_pubkey)
+    {
+        ix.accounts.push(AccountMeta::new(audit_log_pubkey, false));
+    }
+
+    ix
+}
+
+/// Let the withdrawal payout apply the fee discount of the recipient's stake
+pub fn with_fee_discount(mut ix: Instruction, recipient: &Pubkey) -> Instruction {
+    ix.accounts.extend([
+        AccountMeta::new_readonly(get_discount_config_address(), false),
+        AccountMeta::new_readonly(get_discount_stake_address(recipient), false),
+    ]);
+    ix
+}
+
+pub fn add_token_to_whitelist_ix(authority_pubkey: Pubkey, mint: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_whitelist_pubkey = get_token_whitelist_address(&mint);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::AddTokenToWhitelist { mint }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_whitelist_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn remove_token_from_whitelist_ix(authority_pubkey: Pubkey, mint: Pubkey) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let token_whitelist_pubkey = get_token_whitelist_address(&mint);
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::RemoveTokenFromWhitelist { mint }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_whitelist_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn change_deposit_whitelist_mode_ix(authority_pubkey: Pubkey, restricted: bool) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+    let program_data_pubkey = get_programdata_address();
+
+    let data = TokenProxyInstruction::ChangeDepositWhitelistMode { restricted }
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+            AccountMeta::new(audit_log_pubkey, false),
+        ],
+        data,
+    }
+}
+
+pub fn get_withdrawal_bundle_address(
+    chain_id: u32,
+    round_number: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+    entries: Vec<WithdrawalMultiTokenBundleEntry>,
+    recipient: Pubkey,
+) -> Pubkey {
+    let program_id = &id();
+
+    let event_data = hash(
+        &WithdrawalMultiTokenBundleEvent {
+            chain_id,
+            entries,
+            recipient,
+        }
+        .try_to_vec()
+        .expect("pack"),
+    )
+    .to_bytes();
+
+    bridge_utils::helper::get_associated_proposal_address(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        &event_data,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn withdrawal_multi_token_bundle_request_ix(
+    funder_pubkey: Pubkey,
+    author_pubkey: Pubkey,
+    chain_id: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    round_number: u32,
+    recipient: Pubkey,
+    entries: Vec<WithdrawalMultiTokenBundleEntry>,
+) -> Instruction {
+    let withdrawal_pubkey = get_withdrawal_bundle_address(
+        chain_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        entries.clone(),
+        recipient,
+    );
+    let event_index_pubkey =
+        get_event_index_address(event_timestamp, event_transaction_lt, &event_configuration);
+
+    let settings_pubkey = get_settings_address();
+
+    let rl_settings_pubkey =
+        bridge_utils::helper::get_associated_settings_address(&round_loader_interface::id());
+    let relay_round_pubkey = bridge_utils::helper::get_associated_relay_round_address(
+        &round_loader_interface::id(),
+        round_number,
+    );
+
+    let mut accounts = vec![
+        AccountMeta::new(funder_pubkey, true),
+        AccountMeta::new(author_pubkey, true),
+        AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(event_index_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(rl_settings_pubkey, false),
+        AccountMeta::new_readonly(relay_round_pubkey, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(sysvar::rent::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+    ];
+
+    for entry in &entries {
+        accounts.push(AccountMeta::new_readonly(
+            get_token_settings_sol_address(&entry.mint),
+            false,
+        ));
+    }
+
+    // Registered event configurations are checked in the Round Loader
+    let rl_event_configuration_pubkey =
+        round_loader_interface::get_event_configuration_address(&event_configuration);
+    accounts.push(AccountMeta::new_readonly(
+        rl_event_configuration_pubkey,
+        false,
+    ));
+
+    // Withdrawal is counted in the stats of its round until it is final
+    let relay_round_stats_pubkey = get_relay_round_stats_address(round_number);
+    accounts.push(AccountMeta::new(relay_round_stats_pubkey, false));
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenBundleRequest {
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        recipient,
+        entries,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+/// Token settings, vault and associated recipient token accounts of every bundle entry
+fn bundle_entry_accounts(recipient: &Pubkey, mints: &[Pubkey]) -> Vec<AccountMeta> {
+    mints
+        .iter()
+        .flat_map(|mint| {
+            [
+                AccountMeta::new(get_token_settings_sol_address(mint), false),
+                AccountMeta::new(get_vault_address(mint), false),
+                AccountMeta::new(
+                    spl_associated_token_account::get_associated_token_address(recipient, mint),
+                    false,
+                ),
+            ]
+        })
+        .collect()
+}
+
+pub fn withdrawal_bundle_ix(
+    withdrawal_pubkey: Pubkey,
+    recipient: Pubkey,
+    mints: &[Pubkey],
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+
+    let mut accounts = vec![
+        AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+    ];
+    accounts.extend(bundle_entry_accounts(&recipient, mints));
+
+    let data = TokenProxyInstruction::WithdrawMultiTokenBundle
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn approve_withdrawal_bundle_ix(
+    authority_pubkey: Pubkey,
+    withdrawal_pubkey: Pubkey,
+    recipient: Pubkey,
+    mints: &[Pubkey],
+) -> Instruction {
+    let settings_pubkey = get_settings_address();
+    let audit_log_pubkey = get_admin_audit_log_address();
+
+    let mut accounts = vec![
+        AccountMeta::new(authority_pubkey, true),
+        AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new_readonly(settings_pubkey, false),
+        AccountMeta::new_readonly(spl_token::id(), false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+    ];
+    accounts.extend(bundle_entry_accounts(&recipient, mints));
+    accounts.push(AccountMeta::new(audit_log_pubkey, false));
+
+    let data = TokenProxyInstruction::ApproveWithdrawBundle
+        .try_to_vec()
+        .expect("pack");
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
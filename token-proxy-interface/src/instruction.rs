@@ -0,0 +1,1218 @@
+#[cfg(feature = "schema")]
+use borsh::BorshSchema;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::merkle::MerkleProof;
+use bridge_utils::types::{EverAddress, UInt256, Vote};
+
+use solana_program::hash::Hash;
+use solana_program::pubkey::Pubkey;
+
+use crate::{
+    ConfirmationTier, DepositBatchAmount, FeeType, Role, WithdrawalMultiTokenBundleEntry,
+    WithdrawalReceipt,
+};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum TokenProxyInstruction {
+    /// Vote for withdraw EVER/SOL request
+    ///
+    /// # Account references
+    /// ...
+    VoteForWithdrawRequest {
+        // Vote type
+        vote: Vote,
+    },
+
+    /// Withdraw Multi Token EVER
+    ///
+    /// Sets `WithdrawalResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenEver,
+
+    /// Withdraw Multi Token SOL
+    ///
+    /// Sets `WithdrawalResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenSol,
+
+    /// Execute Payload EVER
+    ///
+    /// # Account references
+    /// ...
+    ExecutePayloadEver,
+
+    /// Execute Payload SOL
+    ///
+    /// # Account references
+    /// ...
+    ExecutePayloadSol,
+
+    /// Initialize Token Proxy
+    ///
+    /// # Account references
+    /// ...
+    Initialize {
+        // Guardian pubkey
+        guardian: Pubkey,
+        // Manager pubkey
+        manager: Pubkey,
+        // Withdrawal manager pubkey
+        withdrawal_manager: Pubkey,
+        // Network discriminator deposit and withdrawal events are bound to
+        chain_id: u32,
+    },
+
+    /// Deposit Multi token EVER
+    ///
+    /// Sets `DepositResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    DepositMultiTokenEver {
+        // Deposit seed
+        deposit_seed: u128,
+        // Deposit amount
+        amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Schema the payload is validated against
+        payload_schema: Option<u32>,
+    },
+
+    /// Deposit Multi token SOL
+    ///
+    /// Sets `DepositResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    DepositMultiTokenSol {
+        // Deposit seed
+        deposit_seed: u128,
+        // Mint name
+        name: String,
+        // Mint symbol
+        symbol: String,
+        // Deposit amount
+        amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Schema the payload is validated against
+        payload_schema: Option<u32>,
+        // EVER event configuration the token is namespaced by, None for the default namespace
+        ever_configuration: Option<Pubkey>,
+    },
+
+    /// Withdraw Multi token EVER request
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenEverRequest {
+        // Ever event timestamp
+        event_timestamp: u32,
+        // Ever event transaction lt
+        event_transaction_lt: u64,
+        // Ever event configuration
+        event_configuration: Pubkey,
+        // Ever token root address
+        token: EverAddress,
+        // token name
+        name: String,
+        // token symbol
+        symbol: String,
+        // decimals
+        decimals: u8,
+        // Solana recipient address
+        recipient: Pubkey,
+        // Withdrawal amount
+        amount: u128,
+        // Random payload to transfer to sol
+        payload: Vec<u8>,
+        // Attached SOL amount to proxy account
+        attached_amount: u64,
+    },
+
+    /// Withdraw multi token SOL request
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenSolRequest {
+        // Ever event timestamp
+        event_timestamp: u32,
+        // Ever event transaction lt
+        event_transaction_lt: u64,
+        // Ever event configuration
+        event_configuration: Pubkey,
+        // Solana recipient address
+        recipient: Pubkey,
+        // Withdrawal amount
+        amount: u128,
+        // Random payload to transfer to sol
+        payload: Vec<u8>,
+        // Attached SOL amount to proxy account
+        attached_amount: u64,
+    },
+
+    /// Change Guardian Role
+    ///
+    /// # Account references
+    /// ...
+    ChangeGuardian {
+        // New guardian pubkey
+        new_guardian: Pubkey,
+    },
+
+    /// Change Manager Role
+    ///
+    /// # Account references
+    /// ...
+    ChangeManager {
+        // New guardian pubkey
+        new_manager: Pubkey,
+    },
+
+    /// Change Withdrawal Manager Role
+    ///
+    /// # Account references
+    /// ...
+    ChangeWithdrawalManager {
+        // New withdrawal manager pubkey
+        new_withdrawal_manager: Pubkey,
+    },
+
+    /// Change deposit limit
+    ///
+    /// # Account references
+    /// ...
+    ChangeDepositLimit {
+        // Deposit limit
+        new_deposit_limit: u64,
+    },
+
+    /// Change withdrawal limits
+    ///
+    /// # Account references
+    /// ...
+    ChangeWithdrawalLimits {
+        // Withdrawal limit
+        new_withdrawal_limit: Option<u64>,
+        // Withdrawal daily limit
+        new_withdrawal_daily_limit: Option<u64>,
+    },
+
+    /// Enable emergency mode
+    ///
+    /// # Account references
+    /// ...
+    EnableEmergencyMode,
+
+    /// Disable emergency mode. Co-signers configured in the settings sign along with the owner.
+    ///
+    /// # Account references
+    /// ...
+    DisableEmergencyMode,
+
+    /// Enable token emergency mode
+    ///
+    /// # Account references
+    /// ...
+    EnableTokenEmergencyMode,
+
+    /// Disable token emergency mode
+    ///
+    /// # Account references
+    /// ...
+    DisableTokenEmergencyMode,
+
+    /// Approve Withdraw Ever
+    ///
+    /// # Account references
+    /// ...
+    ApproveWithdrawEver,
+
+    /// Approve Withdraw SOL
+    ///
+    /// # Account references
+    /// ...
+    ApproveWithdrawSol,
+
+    /// Update fee
+    ///
+    /// # Account references
+    /// ...
+    UpdateFee {
+        // Fee type
+        fee_type: FeeType,
+        // Fee multiplier
+        multiplier: u64,
+        // Fee divisor
+        divisor: u64,
+    },
+
+    /// Update token naming
+    ///
+    /// # Account references
+    /// ...
+    UpdateTokenName {
+        // Token symbol
+        symbol: String,
+        // Token name
+        name: String,
+    },
+
+    /// Withdraw EVER fee
+    ///
+    /// # Account references
+    /// ...
+    WithdrawEverFee {
+        // Amount to withdraw
+        amount: u64,
+    },
+
+    /// Withdraw SOL fee
+    ///
+    /// # Account references
+    /// ...
+    WithdrawSolFee {
+        // Amount to withdraw
+        amount: u64,
+    },
+
+    /// Change Bounty for Withdraw SOL
+    ///
+    /// # Account references
+    /// ...
+    ChangeBountyForWithdrawSol {
+        // New bounty value
+        bounty: u64,
+    },
+
+    /// Cancel Withdraw SOL
+    ///
+    /// # Account references
+    /// ...
+    CancelWithdrawSol {
+        // Deposit seed
+        deposit_seed: u128,
+        // Recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+    },
+
+    /// Fill Withdraw SOL
+    ///
+    /// # Account references
+    /// ...
+    FillWithdrawSol {
+        // Deposit seed
+        deposit_seed: u128,
+        // Recipient address
+        recipient: EverAddress,
+        // Deposit amount
+        amount: u64,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+    },
+
+    /// Withdraw tokens from Proxy Account
+    ///
+    /// # Account references
+    /// ...
+    WithdrawProxy {
+        // Amount to withdraw
+        amount: u64,
+    },
+
+    /// Close Deposit Account to return SOL
+    ///
+    /// # Account references
+    /// ...
+    CloseDeposit,
+
+    /// Close Withdrawal Account to return SOL
+    ///
+    /// # Account references
+    /// ...
+    CloseWithdrawal,
+
+    /// Withdraw SOL
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiVault {
+        // Amount SOL to withdraw
+        amount: u64,
+    },
+
+    /// Create Token Settings and Vault for SOL token without deposit
+    ///
+    /// # Account references
+    /// ...
+    CreateTokenSettingsSol {
+        // Token name
+        name: String,
+        // Token symbol
+        symbol: String,
+        // Deposit limit
+        deposit_limit: u64,
+        // Withdrawal limit
+        withdrawal_limit: u64,
+        // Withdrawal daily limit
+        withdrawal_daily_limit: u64,
+        // Whitelist a mint with a freeze authority, the token is flagged with freeze risk
+        allow_freeze_authority: bool,
+        // EVER event configuration the token is namespaced by, None for the default namespace
+        ever_configuration: Option<Pubkey>,
+    },
+
+    /// Create Admin Audit Log
+    ///
+    /// # Account references
+    /// ...
+    CreateAdminAuditLog,
+
+    /// Create withdrawal account for an EVER token request whose event is written in chunks
+    ///
+    /// # Account references
+    /// ...
+    CreateWithdrawMultiTokenEverRequest {
+        // Ever event timestamp
+        event_timestamp: u32,
+        // Ever event transaction lt
+        event_transaction_lt: u64,
+        // Ever event configuration
+        event_configuration: Pubkey,
+        // Sha256 of event data
+        event_data: Hash,
+    },
+
+    /// Write EVER token request event data into a withdrawal account
+    ///
+    /// # Account references
+    /// ...
+    WriteWithdrawMultiTokenEverRequest {
+        // Offset at which to write the given bytes
+        offset: u32,
+        // Serialized event chunk
+        bytes: Vec<u8>,
+    },
+
+    /// Finalize a withdrawal account loaded with EVER token request event data
+    ///
+    /// # Account references
+    /// ...
+    FinalizeWithdrawMultiTokenEverRequest {
+        // Attached SOL amount to proxy account
+        attached_amount: u64,
+    },
+
+    /// Change hook program notified about token deposits and withdrawal payouts
+    ///
+    /// # Account references
+    /// ...
+    ChangeHookProgram {
+        // Hook program, `None` to disable notifications
+        new_hook_program: Option<Pubkey>,
+    },
+
+    /// Pay out a withdrawal held for approval if it fits into the current limits
+    ///
+    /// # Account references
+    /// ...
+    RetryPendingWithdrawal,
+
+    /// Create Settings History to record role and limit changes
+    ///
+    /// # Account references
+    /// ...
+    CreateSettingsHistory,
+
+    /// Create a Settings History page
+    ///
+    /// # Account references
+    /// ...
+    CreateSettingsHistoryPage {
+        // Page number
+        page: u64,
+    },
+
+    /// Check a deposit without executing it.
+    ///
+    /// Always fails with `DepositPreview` error, the `DepositPreview` result is set as return data.
+    ///
+    /// # Account references
+    /// ...
+    PreviewDeposit {
+        // Deposit amount in Solana decimals
+        amount: u64,
+        // SOL amount attached to the deposit
+        value: u64,
+    },
+
+    /// Move a SOL token to a new vault after its vault was frozen by the mint freeze authority.
+    /// Tokens locked in the frozen vault stay there.
+    ///
+    /// # Account references
+    /// ...
+    RecoverFrozenVault,
+
+    /// Schedule moving a SOL token to a new vault. The rotation can be executed once
+    /// `VAULT_ROTATION_TIMELOCK` has passed; scheduling it again restarts the timelock.
+    ///
+    /// # Account references
+    /// ...
+    ScheduleVaultRotation,
+
+    /// Move a SOL token with its balance to the scheduled new vault.
+    /// The vault rotation account is kept as a redirect from the old vault.
+    ///
+    /// # Account references
+    /// ...
+    RotateVault,
+
+    /// Claim part of a pending SOL withdrawal from the vault.
+    /// The withdrawal is processed once the whole amount is claimed.
+    ///
+    /// # Account references
+    /// ...
+    ClaimPartial {
+        // Claimed amount in Solana decimals
+        amount: u64,
+    },
+
+    /// Add liquidity for a SOL token to the vault in exchange for shares in the LP position
+    ///
+    /// # Account references
+    /// ...
+    DepositLiquidity {
+        // Amount in Solana decimals
+        amount: u64,
+    },
+
+    /// Redeem shares of the LP position for vault tokens
+    ///
+    /// # Account references
+    /// ...
+    WithdrawLiquidity {
+        // Number of redeemed shares
+        shares: u64,
+    },
+
+    /// Whitelist the yield adapter program for a SOL token.
+    /// Liquidity can be deployed to it once `YIELD_ADAPTER_TIMELOCK` has passed.
+    ///
+    /// # Account references
+    /// ...
+    ChangeYieldAdapter {
+        // Yield adapter program, `None` to disable deployments
+        new_yield_adapter: Option<Pubkey>,
+        // Percent of the token liquidity allowed to be deployed
+        max_deployed_share: u8,
+    },
+
+    /// Deploy idle vault liquidity to the yield adapter
+    ///
+    /// # Account references
+    /// ...
+    DeployLiquidity {
+        // Amount in Solana decimals
+        amount: u64,
+    },
+
+    /// Recall deployed liquidity from the yield adapter back to the vault
+    ///
+    /// # Account references
+    /// ...
+    RecallLiquidity {
+        // Amount in Solana decimals
+        amount: u64,
+    },
+
+    /// Create Deposit Index to record deposits of the owner. Index pages are created
+    /// by deposits once the previous page is full.
+    ///
+    /// # Account references
+    /// ...
+    CreateDepositIndex,
+
+    /// Check that a withdrawal receipt matches the withdrawal and its relay round.
+    ///
+    /// Fails with `InvalidWithdrawalReceipt` error if it does not.
+    ///
+    /// # Account references
+    /// ...
+    AttestReceipt {
+        // Receipt to check
+        receipt: WithdrawalReceipt,
+    },
+
+    /// Set the part of token fees distributed to relays.
+    /// Reward Config is created with the first update.
+    ///
+    /// # Account references
+    /// ...
+    UpdateRewardConfig {
+        // Percent of the token fee supply distributed to relays of a round
+        relay_share: u8,
+    },
+
+    /// Create Relay Round Stats to count withdrawal votes of the round relays.
+    /// Votes create the stats of their round when missing.
+    ///
+    /// # Account references
+    /// ...
+    CreateRelayRoundStats,
+
+    /// Move the relay share of token fees to the reward of a completed round.
+    /// Relays claim the reward in proportion to their votes in the round.
+    /// Rounds are rewarded one after another, a round without votes is skipped.
+    ///
+    /// # Account references
+    /// ...
+    DistributeRoundRewards,
+
+    /// Transfer the relay part of the round reward to the relay
+    ///
+    /// # Account references
+    /// ...
+    ClaimRelayReward,
+
+    /// Vote for withdraw EVER/SOL request in a Relay Round keeping a merkle root of its relays
+    ///
+    /// # Account references
+    /// ...
+    VoteForWithdrawRequestWithProof {
+        // Vote type
+        vote: Vote,
+        // Proof of the voter membership in the round
+        proof: MerkleProof,
+    },
+
+    /// Record the EVER event of a withdrawal created before the event index was introduced
+    ///
+    /// # Account references
+    /// ...
+    BackfillEventIndex,
+
+    /// Cancel a withdrawal that is not processed yet
+    ///
+    /// # Account references
+    /// ...
+    VetoWithdrawal {
+        // Reason recorded in the veto event
+        reason: String,
+    },
+
+    /// Disable guardian vetoes for good
+    ///
+    /// # Account references
+    /// ...
+    DisableVeto,
+
+    /// Reset time-based state of token settings, e.g. the daily withdrawal amount, and apply
+    /// scheduled limit changes
+    ///
+    /// # Account references
+    /// ...
+    Tick,
+
+    /// Grant a role to an authority
+    ///
+    /// # Account references
+    /// ...
+    GrantRole { role: Role, authority: Pubkey },
+
+    /// Revoke a role from an authority
+    ///
+    /// # Account references
+    /// ...
+    RevokeRole { role: Role, authority: Pubkey },
+
+    /// Override roles allowed to invoke an instruction
+    ///
+    /// # Account references
+    /// ...
+    ChangeInstructionPermission {
+        // Borsh tag of the instruction
+        instruction: u8,
+        // Mask of the allowed roles
+        roles: u16,
+    },
+
+    /// Allow deposits of the mint while deposits are restricted to the whitelist
+    ///
+    /// # Account references
+    /// ...
+    AddTokenToWhitelist {
+        // Mint to allow
+        mint: Pubkey,
+    },
+
+    /// Disallow deposits of the mint while deposits are restricted to the whitelist
+    ///
+    /// # Account references
+    /// ...
+    RemoveTokenFromWhitelist {
+        // Mint to disallow
+        mint: Pubkey,
+    },
+
+    /// Restrict deposits to whitelisted mints or lift the restriction
+    ///
+    /// # Account references
+    /// ...
+    ChangeDepositWhitelistMode {
+        // Whether only whitelisted mints can be deposited
+        restricted: bool,
+    },
+
+    /// Change votes required to confirm withdrawals of the token.
+    /// The round quorum is used when the override is lower.
+    ///
+    /// # Account references
+    /// ...
+    ChangeRequiredVotes {
+        // Required votes, the round quorum if missing
+        required_votes_override: Option<u32>,
+    },
+
+    /// Change confirmation requirements of large withdrawals of the token
+    ///
+    /// # Account references
+    /// ...
+    ChangeConfirmationTiers {
+        // Amount tiers in ascending order
+        confirmation_tiers: Vec<ConfirmationTier>,
+    },
+
+    /// Deposit several Multi tokens SOL to the same EVER recipient with one event
+    ///
+    /// Sets `DepositBatchResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    DepositMultiTokenBatch {
+        // Deposit seed
+        deposit_seed: u128,
+        // Mints and amounts, token accounts are passed as remaining accounts in the same order
+        entries: Vec<DepositBatchAmount>,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Schema the payload is validated against
+        payload_schema: Option<u32>,
+    },
+
+    /// Deposit native SOL, wrapped into wSOL right in the vault
+    ///
+    /// Sets `DepositResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    DepositNativeSol {
+        // Deposit seed
+        deposit_seed: u128,
+        // Lamports to wrap and deposit
+        amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Schema the payload is validated against
+        payload_schema: Option<u32>,
+    },
+
+    /// Withdraw Multi Token SOL, unwrapping wSOL into lamports of the recipient
+    ///
+    /// Sets `WithdrawalResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenSolUnwrap,
+
+    /// Reassign the rent refund and bounty rights of a withdrawal to a new author
+    ///
+    /// # Account references
+    /// ...
+    TransferWithdrawalAuthorship {
+        // New withdrawal author
+        new_author: Pubkey,
+    },
+
+    /// Grow Settings account allocated with an older layout, new fields are zero-initialized.
+    /// Settings written before the discriminator and roles are migrated, their authorities are
+    /// granted the initial roles. The guardian activity of Settings lacking it is seeded with the
+    /// current time, also on Settings of the current layout.
+    ///
+    /// # Account references
+    /// ...
+    ExpandSettings {
+        // Network the events are bound to, only set on the migrated Settings
+        chain_id: u32,
+    },
+
+    /// Set the mask of the token operations paused independently of the emergency mode.
+    /// Roles can only pause operations, resuming them is up to the owner.
+    ///
+    /// # Account references
+    /// ...
+    ChangePausedOperations {
+        // Mask of the paused operations, see `TokenOperation`
+        paused_operations: u8,
+    },
+
+    /// Register or update constraints of deposit payloads declaring the schema
+    ///
+    /// # Account references
+    /// ...
+    SetPayloadSchema {
+        // Schema id declared by deposits
+        schema_id: u32,
+        // Minimum payload length
+        min_len: u32,
+        // Maximum payload length
+        max_len: u32,
+        // Length of the payload header
+        header_len: u32,
+        // Expected hash of the payload header
+        header_hash: Option<[u8; 32]>,
+    },
+
+    /// Set co-signers required to disable the emergency mode. The current co-signers have to
+    /// approve the change, so a single owner key can't lift the requirement.
+    ///
+    /// # Account references
+    /// ...
+    SetEmergencyCosigners {
+        // Authorities allowed to co-sign
+        cosigners: Vec<Pubkey>,
+        // Co-signers required out of `cosigners`
+        threshold: u8,
+    },
+
+    /// Exempt the authority from the per-transaction deposit and withdrawal limits until the
+    /// expiry timestamp. Daily limits still apply. Expiry in the past revokes the exemption.
+    ///
+    /// # Account references
+    /// ...
+    SetExemptAccount {
+        // Market maker
+        authority: Pubkey,
+        // Exemption is void from this timestamp on
+        expires_at: i64,
+    },
+
+    /// Deposit Multi token SOL authorized by the token owner with a signed `GaslessDepositMessage`,
+    /// submitted and paid for by a relayer. The owner has to approve the deposit delegate to
+    /// spend its tokens, the Ed25519 program has to verify the signature of the message signing
+    /// payload in the preceding instruction. Every nonce of the owner is accepted once.
+    ///
+    /// Sets `DepositResult` as return data.
+    ///
+    /// # Account references
+    /// ...
+    DepositMultiTokenSolGasless {
+        // Deposit seed
+        deposit_seed: u128,
+        // Mint name
+        name: String,
+        // Mint symbol
+        symbol: String,
+        // Deposit amount
+        amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Schema the payload is validated against
+        payload_schema: Option<u32>,
+        // Owner's nonce of the signed message
+        nonce: u64,
+        // Signed message is void from this timestamp on
+        expiry: i64,
+    },
+
+    /// Schedule withdrawal limits to apply at the given time, replacing the scheduled change.
+    /// No new limits cancel the scheduled change.
+    ///
+    /// # Account references
+    /// ...
+    ScheduleLimitChange {
+        // Withdrawal limit
+        new_withdrawal_limit: Option<u64>,
+        // Withdrawal daily limit
+        new_withdrawal_daily_limit: Option<u64>,
+        // Limits apply from this timestamp on
+        effective_at: i64,
+    },
+
+    /// Set the withdrawal fee discount of the discount token stakers.
+    /// Discount Config and the stake vault are created with the first update.
+    ///
+    /// # Account references
+    /// ...
+    UpdateDiscountConfig {
+        // Token staked for the discount
+        mint: Pubkey,
+        // Active stake required for the discount
+        min_stake: u64,
+        // Withdrawal fee discount, in basis points
+        discount_bps: u16,
+        // Seconds unstaked tokens stay locked
+        unstake_cooldown: i64,
+    },
+
+    /// Lock discount tokens in the stake of the owner.
+    /// Discount Stake is created with the first stake.
+    ///
+    /// # Account references
+    /// ...
+    StakeForDiscount {
+        // Staked amount
+        amount: u64,
+    },
+
+    /// Start the cooldown of the staked amount, restarting the cooldown of the amount
+    /// already unstaking. The amount stops counting for the discount at once.
+    ///
+    /// # Account references
+    /// ...
+    RequestDiscountUnstake {
+        // Unstaked amount
+        amount: u64,
+    },
+
+    /// Return the unstaking amount to the owner once the cooldown has passed
+    ///
+    /// # Account references
+    /// ...
+    WithdrawDiscountStake,
+
+    /// Check the settings invariants, fails with the broken one
+    ///
+    /// # Account references
+    /// ...
+    AuditSettings,
+
+    /// Create a withdrawal paying out several Multi tokens SOL to the same recipient
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenBundleRequest {
+        // Ever event timestamp
+        event_timestamp: u32,
+        // Ever event transaction lt
+        event_transaction_lt: u64,
+        // Ever event configuration
+        event_configuration: Pubkey,
+        // Solana recipient address
+        recipient: Pubkey,
+        // Mints and amounts, token settings are passed as remaining accounts in the same order
+        entries: Vec<WithdrawalMultiTokenBundleEntry>,
+    },
+
+    /// Pay out every token of the bundle withdrawal at once
+    ///
+    /// # Account references
+    /// ...
+    WithdrawMultiTokenBundle,
+
+    /// Approve bundle withdrawal
+    ///
+    /// # Account references
+    /// ...
+    ApproveWithdrawBundle,
+
+    /// Move the whole vault balance of a SOL token to the first vault of the token in
+    /// a deployment under another program id. Requires emergency mode and its co-signers.
+    ///
+    /// # Account references
+    /// ...
+    RecoverAuthority {
+        // Program id of the new deployment
+        new_program_id: Pubkey,
+    },
+
+    /// Register or update the metadata of an EVER token. Withdrawal requests of a known token
+    /// have to carry the same name, symbol and decimals.
+    ///
+    /// # Account references
+    /// ...
+    SetKnownToken {
+        // EVER token root
+        token: EverAddress,
+        // Token name
+        name: String,
+        // Token symbol
+        symbol: String,
+        // Token decimals
+        decimals: u8,
+    },
+
+    /// Deposit Multi token SOL delivering exactly `transfer_amount` to the EVER recipient.
+    /// The fee is added on top and the gross amount is pulled from the creator.
+    ///
+    /// Sets `DepositResult` as return data.
+    ///
+    /// # Account references
+    /// Same as `DepositMultiTokenSol`
+    DepositMultiTokenSolExactOutput {
+        // Deposit seed
+        deposit_seed: u128,
+        // Mint name
+        name: String,
+        // Mint symbol
+        symbol: String,
+        // Amount the recipient receives net of fees
+        transfer_amount: u128,
+        // Maximum gross amount to pull from the creator
+        max_amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Schema the payload is validated against
+        payload_schema: Option<u32>,
+        // EVER event configuration the token is namespaced by, None for the default namespace
+        ever_configuration: Option<Pubkey>,
+    },
+
+    /// Deposit Multi token EVER delivering exactly `transfer_amount` in Ever decimals to the
+    /// EVER recipient. The fee is added on top and the gross amount is burned from the creator.
+    ///
+    /// Sets `DepositResult` as return data.
+    ///
+    /// # Account references
+    /// Same as `DepositMultiTokenEver`
+    DepositMultiTokenEverExactOutput {
+        // Deposit seed
+        deposit_seed: u128,
+        // Amount the recipient receives net of fees, in Ever decimals
+        transfer_amount: u128,
+        // Maximum gross amount to burn from the creator
+        max_amount: u64,
+        // Ever recipient address
+        recipient: EverAddress,
+        // Sol amount to transfer to ever
+        value: u64,
+        // Expected SOL amount in EVER
+        expected_evers: UInt256,
+        // Random payload to transfer to ever
+        payload: Vec<u8>,
+        // Schema the payload is validated against
+        payload_schema: Option<u32>,
+    },
+
+    /// Prove the guardian still holds its key, without acting on anything
+    ///
+    /// # Account references
+    /// ...
+    GuardianHeartbeat,
+
+    /// Vote for withdraw EVER/SOL request claiming the position of the voter in the Relay Round
+    ///
+    /// # Account references
+    /// ...
+    VoteForWithdrawRequestWithIndex {
+        // Vote type
+        vote: Vote,
+        // Index of the voter in the round relays
+        index: u32,
+    },
+}
+
+impl TokenProxyInstruction {
+    /// Borsh tag of `DepositMultiTokenSolGasless`, signed along with its message
+    pub const DEPOSIT_MULTI_TOKEN_SOL_GASLESS_TAG: u8 = 79;
+
+    /// Position of the authority account for instructions recorded in the admin audit log
+    pub fn admin_authority_index(&self) -> Option<usize> {
+        match self {
+            TokenProxyInstruction::ChangeGuardian { .. }
+            | TokenProxyInstruction::ChangeManager { .. }
+            | TokenProxyInstruction::ChangeWithdrawalManager { .. }
+            | TokenProxyInstruction::ChangeDepositLimit { .. }
+            | TokenProxyInstruction::ChangeWithdrawalLimits { .. }
+            | TokenProxyInstruction::ChangeHookProgram { .. }
+            | TokenProxyInstruction::EnableEmergencyMode
+            | TokenProxyInstruction::DisableEmergencyMode
+            | TokenProxyInstruction::EnableTokenEmergencyMode
+            | TokenProxyInstruction::DisableTokenEmergencyMode
+            | TokenProxyInstruction::ApproveWithdrawEver
+            | TokenProxyInstruction::ApproveWithdrawSol
+            | TokenProxyInstruction::ApproveWithdrawBundle
+            | TokenProxyInstruction::UpdateFee { .. }
+            | TokenProxyInstruction::UpdateTokenName { .. }
+            | TokenProxyInstruction::WithdrawEverFee { .. }
+            | TokenProxyInstruction::WithdrawSolFee { .. }
+            | TokenProxyInstruction::WithdrawMultiVault { .. }
+            | TokenProxyInstruction::RecoverFrozenVault
+            | TokenProxyInstruction::ScheduleVaultRotation
+            | TokenProxyInstruction::RotateVault
+            | TokenProxyInstruction::RecoverAuthority { .. }
+            | TokenProxyInstruction::ChangeYieldAdapter { .. }
+            | TokenProxyInstruction::DeployLiquidity { .. }
+            | TokenProxyInstruction::RecallLiquidity { .. }
+            | TokenProxyInstruction::UpdateRewardConfig { .. }
+            | TokenProxyInstruction::VetoWithdrawal { .. }
+            | TokenProxyInstruction::DisableVeto
+            | TokenProxyInstruction::GrantRole { .. }
+            | TokenProxyInstruction::RevokeRole { .. }
+            | TokenProxyInstruction::ChangeInstructionPermission { .. }
+            | TokenProxyInstruction::AddTokenToWhitelist { .. }
+            | TokenProxyInstruction::RemoveTokenFromWhitelist { .. }
+            | TokenProxyInstruction::ChangeDepositWhitelistMode { .. }
+            | TokenProxyInstruction::ChangeRequiredVotes { .. }
+            | TokenProxyInstruction::ChangeConfirmationTiers { .. }
+            | TokenProxyInstruction::ExpandSettings { .. }
+            | TokenProxyInstruction::ChangePausedOperations { .. }
+            | TokenProxyInstruction::SetPayloadSchema { .. }
+            | TokenProxyInstruction::SetKnownToken { .. }
+            | TokenProxyInstruction::SetEmergencyCosigners { .. }
+            | TokenProxyInstruction::SetExemptAccount { .. }
+            | TokenProxyInstruction::ScheduleLimitChange { .. }
+            | TokenProxyInstruction::UpdateDiscountConfig { .. } => Some(0),
+            TokenProxyInstruction::CreateTokenSettingsSol { .. } => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Borsh tag of the instruction
+    pub fn tag(&self) -> u8 {
+        self.try_to_vec().expect("pack")[0]
+    }
+
+    /// Mask of the roles allowed to invoke the instruction besides the program owner
+    pub fn default_roles(&self) -> u16 {
+        match self {
+            TokenProxyInstruction::EnableEmergencyMode
+            | TokenProxyInstruction::EnableTokenEmergencyMode
+            | TokenProxyInstruction::ChangePausedOperations { .. } => {
+                Role::Guardian.mask() | Role::Pauser.mask()
+            }
+            TokenProxyInstruction::VetoWithdrawal { .. } => {
+                Role::Guardian.mask() | Role::Compliance.mask()
+            }
+            TokenProxyInstruction::UpdateFee { .. }
+            | TokenProxyInstruction::WithdrawEverFee { .. }
+            | TokenProxyInstruction::WithdrawSolFee { .. } => {
+                Role::Manager.mask() | Role::FeeManager.mask()
+            }
+            TokenProxyInstruction::ChangeDepositLimit { .. }
+            | TokenProxyInstruction::ChangeWithdrawalLimits { .. }
+            | TokenProxyInstruction::ScheduleLimitChange { .. }
+            | TokenProxyInstruction::ChangeHookProgram { .. }
+            | TokenProxyInstruction::UpdateTokenName { .. }
+            | TokenProxyInstruction::CreateTokenSettingsSol { .. }
+            | TokenProxyInstruction::WithdrawMultiVault { .. }
+            | TokenProxyInstruction::AddTokenToWhitelist { .. }
+            | TokenProxyInstruction::RemoveTokenFromWhitelist { .. }
+            | TokenProxyInstruction::ChangeDepositWhitelistMode { .. }
+            | TokenProxyInstruction::ChangeRequiredVotes { .. }
+            | TokenProxyInstruction::ChangeConfirmationTiers { .. }
+            | TokenProxyInstruction::SetPayloadSchema { .. }
+            | TokenProxyInstruction::SetKnownToken { .. } => Role::Manager.mask(),
+            TokenProxyInstruction::ApproveWithdrawEver
+            | TokenProxyInstruction::ApproveWithdrawSol
+            | TokenProxyInstruction::ApproveWithdrawBundle => Role::WithdrawalManager.mask(),
+            _ => 0,
+        }
+    }
+}
+
+/// Instruction invoked on the token hook program
+///
+/// # Account references
+///   0. `[signer]` Hook authority, Token Proxy PDA with `["hook"]` seeds
+///   1. `[]` Token settings account
+///   2. `[]` Deposit or withdrawal account
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum HookInstruction {
+    /// Tokens were deposited to be transferred to Everscale
+    Deposit {
+        // Token mint
+        mint: Pubkey,
+        // Deposit author
+        author: Pubkey,
+        // Deposited amount in Solana decimals
+        amount: u64,
+        // Serialized deposit event
+        event: Vec<u8>,
+    },
+
+    /// Tokens were paid out to the withdrawal recipient
+    Withdrawal {
+        // Token mint
+        mint: Pubkey,
+        // Withdrawal recipient
+        recipient: Pubkey,
+        // Paid out amount in Solana decimals
+        amount: u64,
+        // Serialized withdrawal event
+        event: Vec<u8>,
+    },
+}
+
+/// Instruction invoked on the yield adapter program
+///
+/// # Account references
+///   0. `[writable]` Vault account, it doesn't sign for the adapter
+///   1. `[]` Token settings account
+///   2. `[writable]` Adapter token account, receives the deployed tokens
+///   3. ..`[]` Other accounts required by the yield adapter
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum YieldAdapterInstruction {
+    /// Take tokens transferred from the vault to the adapter token account
+    Deposit {
+        // Amount in Solana decimals
+        amount: u64,
+    },
+
+    /// Return tokens to the vault, at least the requested amount
+    Withdraw {
+        // Amount in Solana decimals
+        amount: u64,
+    },
+}
@@ -0,0 +1,50 @@
+//! Token Proxy ids, state, instructions and instruction builders.
+//!
+//! Contains no processor code, so on-chain programs can depend on it to CPI into Token Proxy.
+
+mod bindings;
+mod instruction;
+#[cfg(feature = "schema")]
+mod schema;
+mod state;
+mod utils;
+
+pub use self::bindings::*;
+pub use self::instruction::*;
+#[cfg(feature = "schema")]
+pub use self::schema::*;
+pub use self::state::*;
+pub use self::utils::*;
+
+use bridge_utils::types::Cluster;
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+
+/// Program id of the mainnet deployment
+pub const MAINNET_ID: Pubkey = pubkey!("octuswa5MD5hrTwcNBKvdxDvDQoz7C7M9sk2cRRvZfg");
+/// Program id of the devnet deployment
+pub const DEVNET_ID: Pubkey = pubkey!("4kF5hMX7Awn4UFBGUuxTNrszXbgGNBYhSSbs1CLBidgQ");
+/// Program id of the local validator deployment
+pub const LOCALNET_ID: Pubkey = pubkey!("Fe9kGHV4nzYozkhrhJ7MV1tofQ5GPc6srdfzDyz9cKTv");
+
+/// Program id of the deployment on the given cluster
+pub const fn program_id(cluster: Cluster) -> Pubkey {
+    match cluster {
+        Cluster::Mainnet => MAINNET_ID,
+        Cluster::Devnet => DEVNET_ID,
+        Cluster::Localnet => LOCALNET_ID,
+    }
+}
+
+/// Program id of the cluster selected by cargo features
+pub const ID: Pubkey = program_id(Cluster::CURRENT);
+
+/// Returns `true` if given pubkey is the program id
+pub fn check_id(id: &Pubkey) -> bool {
+    id == &ID
+}
+
+/// Returns the program id
+pub const fn id() -> Pubkey {
+    ID
+}
@@ -0,0 +1,69 @@
+use bridge_utils::schema::SchemaExport;
+use bridge_utils::state::AdminAuditLog;
+use solana_program::program_pack::Pack;
+
+use crate::*;
+
+macro_rules! accounts {
+    ($export:expr, $($account:ty),* $(,)?) => {
+        $($export.account::<$account>(<$account>::DISCRIMINATOR, <$account>::LEN);)*
+    };
+}
+
+/// Borsh schema of the Token Proxy accounts and logged events
+pub fn state_schema() -> SchemaExport {
+    let mut export = SchemaExport::new();
+
+    accounts!(
+        export,
+        Settings,
+        MultiVault,
+        TokenSettings,
+        DepositMultiTokenSol,
+        DepositMultiTokenBatch,
+        DepositMultiTokenEver,
+        WithdrawalMultiTokenEver,
+        WithdrawalMultiTokenSol,
+        WithdrawalMultiTokenBundle,
+        SettingsHistory,
+        SettingsHistoryPage,
+        DepositIndex,
+        DepositIndexPage,
+        VaultRotation,
+        LpPosition,
+        RewardConfig,
+        RelayRoundStats,
+        RoundReward,
+        EventIndex,
+        TokenWhitelist,
+        PayloadSchema,
+        KnownToken,
+        ExemptAccount,
+        Nonce,
+        DiscountConfig,
+        DiscountStake,
+        AdminAuditLog,
+    );
+
+    export
+        .event::<DepositMultiTokenEvent>()
+        .event::<DepositBatchEvent>()
+        .event::<WithdrawMultiTokenRequestEvent>()
+        .event::<TokenSettingsEvent>()
+        .event::<UpdateWithdrawalStatusEvent>()
+        .event::<VetoWithdrawalEvent>()
+        .event::<UpdateFeeEvent>()
+        .event::<UpdateWithdrawalLimitsEvent>()
+        .event::<ScheduleLimitChangeEvent>()
+        .event::<DepositLiquidityEvent>()
+        .event::<WithdrawLiquidityEvent>()
+        .event::<DeployLiquidityEvent>()
+        .event::<RecallLiquidityEvent>()
+        .event::<RotateVaultEvent>()
+        .event::<RecoverFrozenVaultEvent>()
+        .event::<RecoverAuthorityEvent>()
+        .event::<UpdateTokenNameEvent>()
+        .event::<LiquidityRequestEvent>();
+
+    export
+}
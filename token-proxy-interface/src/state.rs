@@ -0,0 +1,2318 @@
+#[cfg(feature = "schema")]
+use borsh::BorshSchema;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_derive::BridgePack;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::signing::{signing_payload, SIGNING_PAYLOAD_LEN};
+use bridge_utils::state::{AccountKind, ACCOUNT_DISCRIMINATOR_LEN, PDA};
+use bridge_utils::ton::{boc_to_cell, Cell, CellBuilder, CellError};
+use bridge_utils::types::{EverAddress, UInt256, Vote};
+use enum_as_inner::EnumAsInner;
+#[cfg(feature = "client")]
+use serde::{Deserialize, Serialize};
+
+use solana_program::clock::Clock;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::{IsInitialized, Pack, Sealed};
+use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
+
+use crate::TokenProxyInstruction;
+
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 32;
+pub const MAX_VETO_REASON_LEN: usize = 64;
+
+pub const WSOL_NAME: &str = "Wrapped SOL";
+pub const WSOL_SYMBOL: &str = "wSOL";
+
+/// Maximum authorities holding roles
+pub const MAX_ROLE_MEMBERS: usize = 16;
+
+/// Maximum instructions with overridden permissions
+pub const MAX_INSTRUCTION_PERMISSIONS: usize = 32;
+
+/// Maximum co-signers of disabling the emergency mode
+pub const MAX_EMERGENCY_COSIGNERS: usize = 8;
+
+/// Seconds without a guardian signature after which the owner replaces it alone
+pub const GUARDIAN_INACTIVITY_WINDOW: i64 = 90 * 24 * 60 * 60;
+
+/// Maximum amount tiers of a token
+pub const MAX_CONFIRMATION_TIERS: usize = 4;
+
+/// Maximum tokens deposited by a single batch deposit
+pub const MAX_DEPOSIT_BATCH_ENTRIES: usize = 4;
+
+/// Maximum tokens paid out by a single bundle withdrawal
+pub const MAX_WITHDRAWAL_BUNDLE_ENTRIES: usize = 4;
+
+pub const WITHDRAWAL_TOKEN_PERIOD: i64 = 86400;
+
+/// Percent of withdrawal fees paid to liquidity providers
+pub const LP_WITHDRAWAL_FEE_SHARE: u64 = 50;
+
+/// Seconds between scheduling a vault rotation and executing it
+pub const VAULT_ROTATION_TIMELOCK: i64 = 172800;
+
+/// Seconds between changing the yield adapter and deploying liquidity to it
+pub const YIELD_ADAPTER_TIMELOCK: i64 = 172800;
+
+const WITHDRAWAL_MULTI_TOKEN_EVER_EVENT_LEN: usize =
+    4                                         // chain id
+    + 1 + 1 + PUBKEY_BYTES                      // ever token root address
+    + 1                                       // decimals
+    + 16                                      // amount
+    + PUBKEY_BYTES                            // solana recipient address
+;
+
+const WITHDRAWAL_MULTI_TOKEN_SOL_EVENT_LEN: usize =
+    4                                         // chain id
+    + PUBKEY_BYTES                            // solana mint address
+    + 16                                      // amount
+    + PUBKEY_BYTES                            // solana recipient address
+;
+
+const WITHDRAWAL_MULTI_TOKEN_BUNDLE_EVENT_LEN: usize =
+    4                                         // chain id
+    + 4                                       // entries len
+    + PUBKEY_BYTES                            // solana recipient address
+;
+
+const WITHDRAWAL_MULTI_TOKEN_BUNDLE_ENTRY_LEN: usize =
+    PUBKEY_BYTES                              // solana mint address
+    + 16                                      // amount
+;
+
+const WITHDRAWAL_TOKEN_META_LEN: usize = 1  // status
+    + 8                                     // bounty
+    + 8                                     // epoch
+    + 8                                     // amount claimed
+    + 1                                     // approval required
+    + 1 + 16                                // quorum reached at
+    + 1 + 16                                // processed at
+    + 1 + 2                                 // token nonces
+    + 4 + 8 * MAX_WITHDRAWAL_BUNDLE_ENTRIES // fees
+;
+
+pub const WITHDRAWAL_LOAD_DATA_BEGIN_OFFSET: usize = ACCOUNT_DISCRIMINATOR_LEN
+    + 1                                                // is_initialized
+    + 3                                                // account_kind
+    + PUBKEY_BYTES                                     // author
+    + 4                                                // round_number
+    + 4                                                // required_votes
+    + 4                                                // event_timestamp
+    + 8                                                // event_transaction_lt
+    + PUBKEY_BYTES                                     // event_configuration
+;
+
+pub const WITHDRAWAL_LOAD_DATA_END_OFFSET: usize = WithdrawalMultiTokenEver::LEN
+    - 4 - WITHDRAWAL_TOKEN_META_LEN                    // meta
+    - 4 - round_loader_interface::MAX_MERKLE_RELAYS    // signers
+;
+
+const DEPOSIT_MULTI_TOKEN_SOL_EVENT_LEN: usize = 4             // chain id
+    + PUBKEY_BYTES                                              // solana mint address
+    + 1                                                         // decimals
+    + 16                                                        // amount
+    + 8                                                         // value
+    + 32                                                        // expected evers
+    + 1 + 1 + PUBKEY_BYTES                                      // ever recipient address
+;
+
+const DEPOSIT_MULTI_TOKEN_BATCH_EVENT_LEN: usize = 4           // chain id
+    + 4                                                         // entries len
+    + 8                                                         // value
+    + 32                                                        // expected evers
+    + 1 + 1 + PUBKEY_BYTES                                      // ever recipient address
+;
+
+const DEPOSIT_MULTI_TOKEN_BATCH_ENTRY_LEN: usize = PUBKEY_BYTES // solana mint address
+    + 1                                                         // decimals
+    + 16                                                        // amount
+;
+
+const DEPOSIT_MULTI_TOKEN_EVER_EVENT_LEN: usize =
+    4                                                       // chain id
+    + 1 + 1 + PUBKEY_BYTES                                    // ever token root address
+    + 16                                                    // amount
+    + 8                                                     // value
+    + 32                                                    // expected evers
+    + 1 + 1 + PUBKEY_BYTES                                  // ever recipient address
+;
+
+const DEPOSIT_TOKEN_META_LEN: usize = 16    // seed
+    + 1 + PUBKEY_BYTES                          // ever configuration
+;
+
+const DEFAULT_MULTIPLIER: u64 = 5;
+const DEFAULT_DIVISOR: u64 = 10_000;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct Settings {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub emergency: bool,
+    // Deprecated, authorities are kept in `roles`. Reserved to keep the layout of accounts
+    // created before roles, only accounts migrated from it hold the former authorities.
+    pub guardian: Pubkey,
+    pub manager: Pubkey,
+    pub withdrawal_manager: Pubkey,
+    // Network the deposit and withdrawal events are bound to
+    pub chain_id: u32,
+    // Sequence number of the last emitted event
+    pub event_seq: u64,
+    // Guardian can't veto withdrawals
+    pub veto_disabled: bool,
+    // Only whitelisted mints can be deposited
+    pub deposits_restricted_to_whitelist: bool,
+    // Authorities and the roles they hold
+    pub roles: Vec<RoleMember>,
+    // Roles allowed to invoke an instruction, overriding the default ones
+    pub permissions: Vec<InstructionPermission>,
+    // Authorities co-signing disabling of the emergency mode together with the owner
+    pub emergency_cosigners: Vec<Pubkey>,
+    // Co-signers required to disable the emergency mode, the owner alone if zero
+    pub emergency_cosigners_threshold: u8,
+    // Last time a guardian signed an instruction, zero if it never did since the field was added
+    pub guardian_last_active: i64,
+}
+
+impl Settings {
+    /// Role members set on initialization
+    pub fn initial_roles(
+        guardian: Pubkey,
+        manager: Pubkey,
+        withdrawal_manager: Pubkey,
+    ) -> Vec<RoleMember> {
+        vec![
+            RoleMember {
+                role: Role::Guardian,
+                authority: guardian,
+            },
+            RoleMember {
+                role: Role::Manager,
+                authority: manager,
+            },
+            RoleMember {
+                role: Role::WithdrawalManager,
+                authority: withdrawal_manager,
+            },
+        ]
+    }
+
+    pub fn has_role(&self, role: Role, authority: &Pubkey) -> bool {
+        self.roles
+            .iter()
+            .any(|member| member.role == role && member.authority == *authority)
+    }
+
+    pub fn role_members(&self, role: Role) -> Vec<Pubkey> {
+        self.roles
+            .iter()
+            .filter(|member| member.role == role)
+            .map(|member| member.authority)
+            .collect()
+    }
+
+    /// Roles mask allowed to invoke the instruction
+    pub fn permitted_roles(&self, permission: &InstructionPermission) -> u16 {
+        self.permissions
+            .iter()
+            .find(|p| p.instruction == permission.instruction)
+            .unwrap_or(permission)
+            .roles
+    }
+
+    /// Whether the authority holds a role allowed to invoke the instruction
+    pub fn is_permitted(&self, permission: &InstructionPermission, authority: &Pubkey) -> bool {
+        let roles = self.permitted_roles(permission);
+
+        self.roles
+            .iter()
+            .any(|member| member.authority == *authority && member.role.mask() & roles != 0)
+    }
+
+    pub fn grant_role(&mut self, role: Role, authority: Pubkey) -> Result<(), ProgramError> {
+        if self.has_role(role, &authority) {
+            return Err(SolanaBridgeError::RoleAlreadyGranted.into());
+        }
+
+        if self.roles.len() >= MAX_ROLE_MEMBERS {
+            return Err(SolanaBridgeError::RoleMembersLimit.into());
+        }
+
+        self.roles.push(RoleMember { role, authority });
+
+        Ok(())
+    }
+
+    pub fn revoke_role(&mut self, role: Role, authority: &Pubkey) -> Result<(), ProgramError> {
+        if !self.has_role(role, authority) {
+            return Err(SolanaBridgeError::RoleNotGranted.into());
+        }
+
+        self.roles
+            .retain(|member| member.role != role || member.authority != *authority);
+
+        Ok(())
+    }
+
+    /// Replace all members of the role with a single authority
+    pub fn set_role(&mut self, role: Role, authority: Pubkey) {
+        self.roles.retain(|member| member.role != role);
+        self.roles.push(RoleMember { role, authority });
+    }
+
+    /// Whether enough co-signers of the emergency mode are among the signers
+    pub fn is_emergency_cosigned(&self, signers: &[Pubkey]) -> bool {
+        let cosigned = self
+            .emergency_cosigners
+            .iter()
+            .filter(|cosigner| signers.contains(cosigner))
+            .count();
+
+        cosigned >= self.emergency_cosigners_threshold as usize
+    }
+
+    /// Whether no guardian signed an instruction within the inactivity window.
+    ///
+    /// Activity of guardians of settings created before the field was added is unknown until
+    /// it is seeded by `ExpandSettings` or their first signature.
+    pub fn is_guardian_inactive(&self, now: i64) -> bool {
+        if self.guardian_last_active == 0 {
+            return false;
+        }
+
+        now >= self
+            .guardian_last_active
+            .saturating_add(GUARDIAN_INACTIVITY_WINDOW)
+    }
+
+    /// Record the signature of the authority if it is a guardian
+    pub fn record_guardian_activity(&mut self, authority: &Pubkey, now: i64) {
+        if self.has_role(Role::Guardian, authority) {
+            self.guardian_last_active = now;
+        }
+    }
+
+    /// Check the cross-field invariants of the settings
+    pub fn audit(&self) -> Result<(), SolanaBridgeError> {
+        if self
+            .roles
+            .iter()
+            .map(|member| &member.authority)
+            .chain(self.emergency_cosigners.iter())
+            .any(|authority| *authority == Pubkey::default())
+        {
+            return Err(SolanaBridgeError::DefaultRoleKey);
+        }
+
+        if self.emergency_cosigners_threshold as usize > self.emergency_cosigners.len() {
+            return Err(SolanaBridgeError::InvalidQuorum);
+        }
+
+        Ok(())
+    }
+
+    /// Override roles allowed to invoke the instruction
+    pub fn set_permission(
+        &mut self,
+        permission: InstructionPermission,
+    ) -> Result<(), ProgramError> {
+        match self
+            .permissions
+            .iter_mut()
+            .find(|p| p.instruction == permission.instruction)
+        {
+            Some(p) => p.roles = permission.roles,
+            None => {
+                if self.permissions.len() >= MAX_INSTRUCTION_PERMISSIONS {
+                    return Err(SolanaBridgeError::InstructionPermissionsLimit.into());
+                }
+                self.permissions.push(permission);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Sealed for Settings {}
+
+impl IsInitialized for Settings {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum Role {
+    Guardian,
+    Manager,
+    WithdrawalManager,
+    Pauser,
+    FeeManager,
+    Compliance,
+}
+
+impl Role {
+    pub fn mask(&self) -> u16 {
+        1 << *self as u8
+    }
+
+    /// Settings history field recording grants and revokes of the role
+    pub fn settings_field(&self) -> SettingsField {
+        match self {
+            Role::Guardian => SettingsField::Guardian,
+            Role::Manager => SettingsField::Manager,
+            Role::WithdrawalManager => SettingsField::WithdrawalManager,
+            Role::Pauser => SettingsField::Pauser,
+            Role::FeeManager => SettingsField::FeeManager,
+            Role::Compliance => SettingsField::Compliance,
+        }
+    }
+}
+
+/// Token operation that can be paused independently of the emergency mode
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum TokenOperation {
+    Deposit,
+    WithdrawalRequest,
+    Vote,
+    Payout,
+}
+
+impl TokenOperation {
+    pub fn mask(&self) -> u8 {
+        1 << *self as u8
+    }
+}
+
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct ConfirmationTier {
+    // Withdrawal amount the tier starts from, in event units
+    pub min_amount: u128,
+    // Votes required on top of the round quorum
+    pub extra_votes: u32,
+    // Withdrawal manager has to approve the withdrawal
+    pub approval_required: bool,
+}
+
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct ScheduledLimitChange {
+    // New withdrawal limit, unchanged if none
+    pub withdrawal_limit: Option<u64>,
+    // New withdrawal daily limit, unchanged if none
+    pub withdrawal_daily_limit: Option<u64>,
+    // Limits apply from this timestamp on
+    pub effective_at: i64,
+}
+
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositBatchAmount {
+    pub mint: Pubkey,
+    // Deposit amount
+    pub amount: u64,
+}
+
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RoleMember {
+    pub role: Role,
+    pub authority: Pubkey,
+}
+
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct InstructionPermission {
+    // Borsh tag of the Token Proxy instruction
+    pub instruction: u8,
+    // Mask of the roles allowed to invoke it
+    pub roles: u16,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct MultiVault {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+}
+
+impl Sealed for MultiVault {}
+
+impl IsInitialized for MultiVault {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct TokenSettings {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub kind: TokenKind,
+    pub name: String,
+    pub symbol: String,
+    pub deposit_limit: u64,
+    pub withdrawal_limit: u64,
+    pub withdrawal_daily_limit: u64,
+    pub withdrawal_daily_amount: u64,
+    pub withdrawal_epoch: i64,
+    pub emergency: bool,
+    pub fee_supply: u64,
+    pub fee_deposit_info: FeeInfo,
+    pub fee_withdrawal_info: FeeInfo,
+    pub hook_program: Option<Pubkey>,
+    // Mint has a freeze authority, whitelisted by admin override
+    pub freeze_risk: bool,
+    // Incremented each time the vault is moved to a new account
+    pub vault_generation: u8,
+    // Total shares of liquidity providers
+    pub lp_supply: u64,
+    // Vault tokens owned by liquidity providers, including their cut of withdrawal fees
+    pub lp_liquidity: u64,
+    // Whitelisted program idle vault liquidity is deployed to
+    pub yield_adapter: Option<Pubkey>,
+    // Liquidity can be deployed to the yield adapter after this time
+    pub yield_adapter_unlock_time: i64,
+    // Percent of the token liquidity allowed to be deployed, the rest stays in the vault
+    pub max_deployed_share: u8,
+    // Vault tokens held by the yield adapter
+    pub deployed_amount: u64,
+    // Last relay round the token fees were distributed to
+    pub last_rewarded_round: Option<u32>,
+    // Votes required to confirm a withdrawal of the token, raises the round quorum
+    pub required_votes_override: Option<u32>,
+    // Confirmation requirements of large withdrawals, in ascending order of amounts
+    pub confirmation_tiers: Vec<ConfirmationTier>,
+    // Mask of the paused operations, see `TokenOperation`
+    pub paused_operations: u8,
+    // Withdrawal limits applied once their time comes
+    pub scheduled_limit_change: Option<ScheduledLimitChange>,
+    // EVER event configuration the token accounts are namespaced by, None for the default namespace
+    pub ever_configuration: Option<Pubkey>,
+}
+
+impl TokenSettings {
+    /// Whether withdrawals of the EVER event configuration are paid from the token. Tokens of
+    /// the default namespace accept any registered configuration.
+    pub fn accepts_event_configuration(&self, event_configuration: &Pubkey) -> bool {
+        self.ever_configuration.map_or(true, |ever_configuration| {
+            ever_configuration == *event_configuration
+        })
+    }
+
+    /// Whether the operation is paused for the token
+    pub fn is_paused(&self, operation: TokenOperation) -> bool {
+        self.paused_operations & operation.mask() != 0
+    }
+
+    /// Tier with the highest threshold reached by the withdrawal amount
+    pub fn confirmation_tier(&self, amount: u128) -> Option<&ConfirmationTier> {
+        self.confirmation_tiers
+            .iter()
+            .rev()
+            .find(|tier| amount >= tier.min_amount)
+    }
+
+    /// Tokens locked by deposits for the given vault balance. Liquidity of liquidity providers
+    /// doesn't count, liquidity deployed to the yield adapter does.
+    pub fn locked_amount(&self, vault_amount: u64) -> u64 {
+        vault_amount
+            .saturating_add(self.deployed_amount)
+            .saturating_sub(self.lp_liquidity)
+    }
+
+    /// Apply the scheduled limit change if it is effective, returns whether the limits changed
+    pub fn apply_scheduled_limit_change(&mut self, now: i64) -> bool {
+        match self.scheduled_limit_change {
+            Some(change) if change.effective_at <= now => {
+                if let Some(withdrawal_limit) = change.withdrawal_limit {
+                    self.withdrawal_limit = withdrawal_limit;
+                }
+                if let Some(withdrawal_daily_limit) = change.withdrawal_daily_limit {
+                    self.withdrawal_daily_limit = withdrawal_daily_limit;
+                }
+                self.scheduled_limit_change = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Check the cross-field invariants of the token settings
+    pub fn audit(&self) -> Result<(), SolanaBridgeError> {
+        if self.withdrawal_daily_limit < self.withdrawal_limit {
+            return Err(SolanaBridgeError::DailyLimitBelowLimit);
+        }
+
+        if self.required_votes_override == Some(0) {
+            return Err(SolanaBridgeError::InvalidQuorum);
+        }
+
+        if self
+            .confirmation_tiers
+            .windows(2)
+            .any(|tiers| tiers[0].min_amount >= tiers[1].min_amount)
+        {
+            return Err(SolanaBridgeError::InvalidConfirmationTiers);
+        }
+
+        for fee_info in [&self.fee_deposit_info, &self.fee_withdrawal_info] {
+            if fee_info.divisor == 0 || fee_info.multiplier > fee_info.divisor {
+                return Err(SolanaBridgeError::InvalidFeeInfo);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Sealed for TokenSettings {}
+
+impl IsInitialized for TokenSettings {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct Deposit {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub event: Vec<u8>,
+    pub meta: Vec<u8>,
+}
+
+impl Deposit {
+    pub fn pack_into_slice(&self, dst: &mut [u8]) {
+        // Discriminator of the concrete account type is kept as is
+        let data = self.try_to_vec().unwrap();
+        let (left, _) = dst[ACCOUNT_DISCRIMINATOR_LEN..].split_at_mut(data.len());
+        left.copy_from_slice(&data);
+    }
+
+    pub fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let mut src = src
+            .get(ACCOUNT_DISCRIMINATOR_LEN..)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let unpacked = Self::deserialize(&mut src)?;
+        Ok(unpacked)
+    }
+}
+
+impl Sealed for Deposit {}
+
+impl IsInitialized for Deposit {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct DepositMultiTokenSol {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub event: DepositMultiTokenSolEventWithLen,
+    pub meta: DepositTokenMetaWithLen,
+}
+
+impl Sealed for DepositMultiTokenSol {}
+
+impl IsInitialized for DepositMultiTokenSol {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositMultiTokenSolEvent {
+    pub chain_id: u32,
+    pub base_token: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub amount: u128,
+    pub recipient: EverAddress,
+    pub value: u64,
+    pub expected_evers: UInt256,
+    pub payload: Vec<u8>,
+}
+
+impl DepositMultiTokenSolEvent {
+    /// Event data cell decoded by the EVER-side event contract, fields in declaration order
+    pub fn to_cell(&self) -> Result<Cell, CellError> {
+        let mut builder = CellBuilder::new();
+        builder
+            .store_uint(self.chain_id as u128, 32)?
+            .store_u256(&self.base_token.to_bytes())?
+            .store_bytes_ref(self.name.as_bytes())?
+            .store_bytes_ref(self.symbol.as_bytes())?
+            .store_uint(self.decimals as u128, 8)?
+            .store_uint(self.amount, 128)?
+            .store_address(&self.recipient)?
+            .store_uint(self.value as u128, 64)?
+            .store_u256(self.expected_evers.as_slice())?
+            .store_reference(boc_to_cell(&self.payload)?)?;
+
+        Ok(builder.build())
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositMultiTokenSolEventWithLen {
+    pub len: u32,
+    pub data: DepositMultiTokenSolEvent,
+}
+
+impl DepositMultiTokenSolEventWithLen {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain_id: u32,
+        base_token: Pubkey,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        amount: u128,
+        recipient: EverAddress,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+    ) -> Self {
+        Self {
+            len: (DEPOSIT_MULTI_TOKEN_SOL_EVENT_LEN as u32)
+                + 4
+                + (name.len() as u32)
+                + 4
+                + (symbol.len() as u32)
+                + 4
+                + (payload.len() as u32),
+            data: DepositMultiTokenSolEvent {
+                chain_id,
+                base_token,
+                name,
+                symbol,
+                decimals,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct DepositMultiTokenBatch {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub event: DepositMultiTokenBatchEventWithLen,
+    pub meta: DepositTokenMetaWithLen,
+}
+
+impl Sealed for DepositMultiTokenBatch {}
+
+impl IsInitialized for DepositMultiTokenBatch {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositMultiTokenBatchEntry {
+    pub base_token: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub amount: u128,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositMultiTokenBatchEvent {
+    pub chain_id: u32,
+    pub entries: Vec<DepositMultiTokenBatchEntry>,
+    pub recipient: EverAddress,
+    pub value: u64,
+    pub expected_evers: UInt256,
+    pub payload: Vec<u8>,
+}
+
+impl DepositMultiTokenBatchEvent {
+    /// Event data cell decoded by the EVER-side event contract. Entries are stored as a chain
+    /// of reference cells, each one referencing the next entry.
+    pub fn to_cell(&self) -> Result<Cell, CellError> {
+        let mut entries = None;
+        for entry in self.entries.iter().rev() {
+            let mut builder = CellBuilder::new();
+            builder
+                .store_u256(&entry.base_token.to_bytes())?
+                .store_bytes_ref(entry.name.as_bytes())?
+                .store_bytes_ref(entry.symbol.as_bytes())?
+                .store_uint(entry.decimals as u128, 8)?
+                .store_uint(entry.amount, 128)?;
+            if let Some(next) = entries {
+                builder.store_reference(next)?;
+            }
+            entries = Some(builder.build());
+        }
+
+        let mut builder = CellBuilder::new();
+        builder
+            .store_uint(self.chain_id as u128, 32)?
+            .store_uint(self.entries.len() as u128, 8)?
+            .store_reference(entries.unwrap_or_else(|| CellBuilder::new().build()))?
+            .store_address(&self.recipient)?
+            .store_uint(self.value as u128, 64)?
+            .store_u256(self.expected_evers.as_slice())?
+            .store_reference(boc_to_cell(&self.payload)?)?;
+
+        Ok(builder.build())
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositMultiTokenBatchEventWithLen {
+    pub len: u32,
+    pub data: DepositMultiTokenBatchEvent,
+}
+
+impl DepositMultiTokenBatchEventWithLen {
+    pub fn new(
+        chain_id: u32,
+        entries: Vec<DepositMultiTokenBatchEntry>,
+        recipient: EverAddress,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+    ) -> Self {
+        let entries_len: u32 = entries
+            .iter()
+            .map(|entry| {
+                (DEPOSIT_MULTI_TOKEN_BATCH_ENTRY_LEN as u32)
+                    + 4
+                    + (entry.name.len() as u32)
+                    + 4
+                    + (entry.symbol.len() as u32)
+            })
+            .sum();
+
+        Self {
+            len: (DEPOSIT_MULTI_TOKEN_BATCH_EVENT_LEN as u32)
+                + entries_len
+                + 4
+                + (payload.len() as u32),
+            data: DepositMultiTokenBatchEvent {
+                chain_id,
+                entries,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct DepositMultiTokenEver {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub event: DepositMultiTokenEverEventWithLen,
+    pub meta: DepositTokenMetaWithLen,
+}
+
+impl Sealed for DepositMultiTokenEver {}
+
+impl IsInitialized for DepositMultiTokenEver {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositMultiTokenEverEvent {
+    pub chain_id: u32,
+    pub token: EverAddress,
+    pub amount: u128,
+    pub recipient: EverAddress,
+    pub value: u64,
+    pub expected_evers: UInt256,
+    pub payload: Vec<u8>,
+}
+
+impl DepositMultiTokenEverEvent {
+    /// Event data cell decoded by the EVER-side event contract, fields in declaration order
+    pub fn to_cell(&self) -> Result<Cell, CellError> {
+        let mut builder = CellBuilder::new();
+        builder
+            .store_uint(self.chain_id as u128, 32)?
+            .store_address(&self.token)?
+            .store_uint(self.amount, 128)?
+            .store_address(&self.recipient)?
+            .store_uint(self.value as u128, 64)?
+            .store_u256(self.expected_evers.as_slice())?
+            .store_reference(boc_to_cell(&self.payload)?)?;
+
+        Ok(builder.build())
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositMultiTokenEverEventWithLen {
+    pub len: u32,
+    pub data: DepositMultiTokenEverEvent,
+}
+
+impl DepositMultiTokenEverEventWithLen {
+    pub fn new(
+        chain_id: u32,
+        token: EverAddress,
+        amount: u128,
+        recipient: EverAddress,
+        value: u64,
+        expected_evers: UInt256,
+        payload: Vec<u8>,
+    ) -> Self {
+        Self {
+            len: (DEPOSIT_MULTI_TOKEN_EVER_EVENT_LEN + 4 + payload.len()) as u32,
+            data: DepositMultiTokenEverEvent {
+                chain_id,
+                token,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositTokenMeta {
+    pub seed: u128,
+    // Namespace of the deposited token, None for the default namespace
+    pub ever_configuration: Option<Pubkey>,
+}
+
+impl DepositTokenMeta {
+    /// Meta written before new fields were added is shorter, missing fields are zeroed
+    pub fn unpack_from_slice(src: &[u8]) -> std::io::Result<Self> {
+        let mut data = vec![0; src.len().max(DEPOSIT_TOKEN_META_LEN)];
+        data[..src.len()].copy_from_slice(src);
+
+        Self::deserialize_reader(&mut data.as_slice())
+    }
+}
+
+/// Meta takes `len` bytes of the account, the serialized `data` is zero padded to them
+#[derive(Debug)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositTokenMetaWithLen {
+    pub len: u32,
+    pub data: DepositTokenMeta,
+}
+
+impl BorshSerialize for DepositTokenMetaWithLen {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Padded whether the namespace is set or not
+        let mut data = self.data.try_to_vec()?;
+        if data.len() > self.len as usize {
+            return Err(std::io::ErrorKind::InvalidData.into());
+        }
+        data.resize(self.len as usize, 0);
+
+        self.len.serialize(writer)?;
+        writer.write_all(&data)
+    }
+}
+
+impl BorshDeserialize for DepositTokenMetaWithLen {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = u32::deserialize_reader(reader)? as usize;
+
+        let mut data = vec![0; len];
+        reader.read_exact(&mut data)?;
+
+        Ok(Self {
+            len: DEPOSIT_TOKEN_META_LEN as u32,
+            data: DepositTokenMeta::unpack_from_slice(&data)?,
+        })
+    }
+}
+
+impl DepositTokenMetaWithLen {
+    pub fn new(seed: u128) -> Self {
+        Self {
+            len: DEPOSIT_TOKEN_META_LEN as u32,
+            data: DepositTokenMeta {
+                seed,
+                ever_configuration: None,
+            },
+        }
+    }
+
+    pub fn with_ever_configuration(mut self, ever_configuration: Option<Pubkey>) -> Self {
+        self.data.ever_configuration = ever_configuration;
+        self
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct WithdrawalMultiTokenEver {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: WithdrawalMultiTokenEverEventWithLen,
+    pub meta: WithdrawalTokenMetaWithLen,
+    pub signers: Vec<Vote>,
+}
+
+impl Sealed for WithdrawalMultiTokenEver {}
+
+impl IsInitialized for WithdrawalMultiTokenEver {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Leading fields shared by all proposal accounts, readable while the event is being written
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct ProposalHeader {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalMultiTokenEverEvent {
+    pub chain_id: u32,
+    pub token: EverAddress,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub amount: u128,
+    pub recipient: Pubkey,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalMultiTokenEverEventWithLen {
+    pub len: u32,
+    pub data: WithdrawalMultiTokenEverEvent,
+}
+
+impl WithdrawalMultiTokenEverEventWithLen {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain_id: u32,
+        token: EverAddress,
+        name: String,
+        symbol: String,
+        decimals: u8,
+        amount: u128,
+        recipient: Pubkey,
+        payload: Vec<u8>,
+    ) -> Self {
+        Self {
+            len: WITHDRAWAL_MULTI_TOKEN_EVER_EVENT_LEN as u32
+                + 4
+                + name.len() as u32
+                + 4
+                + symbol.len() as u32
+                + 4
+                + (payload.len() as u32),
+            data: WithdrawalMultiTokenEverEvent {
+                chain_id,
+                token,
+                name,
+                symbol,
+                decimals,
+                amount,
+                recipient,
+                payload,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct WithdrawalMultiTokenSol {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: WithdrawalMultiTokenSolEventWithLen,
+    pub meta: WithdrawalTokenMetaWithLen,
+    pub signers: Vec<Vote>,
+}
+
+impl Sealed for WithdrawalMultiTokenSol {}
+
+impl IsInitialized for WithdrawalMultiTokenSol {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalMultiTokenSolEvent {
+    pub chain_id: u32,
+    pub mint: Pubkey,
+    pub amount: u128,
+    pub recipient: Pubkey,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalMultiTokenSolEventWithLen {
+    pub len: u32,
+    pub data: WithdrawalMultiTokenSolEvent,
+}
+
+impl WithdrawalMultiTokenSolEventWithLen {
+    pub fn new(
+        chain_id: u32,
+        mint: Pubkey,
+        amount: u128,
+        recipient: Pubkey,
+        payload: Vec<u8>,
+    ) -> Self {
+        Self {
+            len: WITHDRAWAL_MULTI_TOKEN_SOL_EVENT_LEN as u32 + 4 + (payload.len() as u32),
+            data: WithdrawalMultiTokenSolEvent {
+                chain_id,
+                mint,
+                amount,
+                recipient,
+                payload,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 1000)]
+pub struct WithdrawalMultiTokenBundle {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    pub event: WithdrawalMultiTokenBundleEventWithLen,
+    pub meta: WithdrawalTokenMetaWithLen,
+    pub signers: Vec<Vote>,
+}
+
+impl Sealed for WithdrawalMultiTokenBundle {}
+
+impl IsInitialized for WithdrawalMultiTokenBundle {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalMultiTokenBundleEntry {
+    pub mint: Pubkey,
+    pub amount: u128,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalMultiTokenBundleEvent {
+    pub chain_id: u32,
+    pub entries: Vec<WithdrawalMultiTokenBundleEntry>,
+    pub recipient: Pubkey,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalMultiTokenBundleEventWithLen {
+    pub len: u32,
+    pub data: WithdrawalMultiTokenBundleEvent,
+}
+
+impl WithdrawalMultiTokenBundleEventWithLen {
+    pub fn new(
+        chain_id: u32,
+        entries: Vec<WithdrawalMultiTokenBundleEntry>,
+        recipient: Pubkey,
+    ) -> Self {
+        Self {
+            len: (WITHDRAWAL_MULTI_TOKEN_BUNDLE_EVENT_LEN
+                + WITHDRAWAL_MULTI_TOKEN_BUNDLE_ENTRY_LEN * entries.len()) as u32,
+            data: WithdrawalMultiTokenBundleEvent {
+                chain_id,
+                entries,
+                recipient,
+            },
+        }
+    }
+}
+
+#[derive(Debug, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalTokenMeta {
+    pub status: WithdrawalTokenStatus,
+    pub bounty: u64,
+    pub epoch: i64,
+    // Amount already transferred to the recipient by partial claims
+    pub amount_claimed: u64,
+    // Amount tier of the withdrawal requires withdrawal manager approval
+    pub approval_required: bool,
+    // Withdrawal got enough relay votes
+    pub quorum_reached_at: Option<Timestamp>,
+    // Withdrawal was paid out in full
+    pub processed_at: Option<Timestamp>,
+    // Bumps of the token settings and mint of the EVER token the withdrawal deploys
+    pub token_nonces: Option<(u8, u8)>,
+    // Fees booked when the withdrawal reached quorum, one per token in order of the event
+    pub fees: Vec<u64>,
+}
+
+impl BorshSerialize for WithdrawalTokenMeta {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        // Padded to the length written ahead of the meta, signers follow it in the account
+        let mut data = Vec::with_capacity(WITHDRAWAL_TOKEN_META_LEN);
+        self.status.serialize(&mut data)?;
+        self.bounty.serialize(&mut data)?;
+        self.epoch.serialize(&mut data)?;
+        self.amount_claimed.serialize(&mut data)?;
+        self.approval_required.serialize(&mut data)?;
+        self.quorum_reached_at.serialize(&mut data)?;
+        self.processed_at.serialize(&mut data)?;
+        self.token_nonces.serialize(&mut data)?;
+        self.fees.serialize(&mut data)?;
+        data.resize(WITHDRAWAL_TOKEN_META_LEN, 0);
+
+        writer.write_all(&data)
+    }
+}
+
+impl WithdrawalTokenMeta {
+    /// Move the withdrawal to the next status, recording when it reached quorum and was paid out
+    pub fn transition(
+        &mut self,
+        next: WithdrawalTokenStatus,
+        clock: &Clock,
+    ) -> Result<(), SolanaBridgeError> {
+        // Any status but cancellation leaving a new withdrawal means the relays confirmed it
+        let quorum_reached =
+            self.status == WithdrawalTokenStatus::New && next != WithdrawalTokenStatus::Cancelled;
+
+        self.status.transition(next)?;
+
+        if quorum_reached {
+            self.quorum_reached_at = Some(clock.into());
+        }
+
+        if next == WithdrawalTokenStatus::Processed {
+            self.processed_at = Some(clock.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Point in time of the cluster
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct Timestamp {
+    pub slot: u64,
+    pub unix_timestamp: i64,
+}
+
+impl From<&Clock> for Timestamp {
+    fn from(clock: &Clock) -> Self {
+        Self {
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+        }
+    }
+}
+
+#[derive(Debug, BorshSerialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalTokenMetaWithLen {
+    pub len: u32,
+    pub data: WithdrawalTokenMeta,
+}
+
+impl BorshDeserialize for WithdrawalTokenMetaWithLen {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = u32::deserialize_reader(reader)? as usize;
+
+        // Meta written before new fields were added is shorter, missing fields are zeroed
+        let mut data = vec![0; len.max(WITHDRAWAL_TOKEN_META_LEN)];
+        reader.read_exact(&mut data[..len])?;
+
+        Ok(Self {
+            len: WITHDRAWAL_TOKEN_META_LEN as u32,
+            data: WithdrawalTokenMeta::deserialize_reader(&mut data.as_slice())?,
+        })
+    }
+}
+
+impl WithdrawalTokenMetaWithLen {
+    pub fn new(bounty: u64, epoch: i64) -> Self {
+        Self {
+            len: WITHDRAWAL_TOKEN_META_LEN as u32,
+            data: WithdrawalTokenMeta {
+                epoch,
+                bounty,
+                status: WithdrawalTokenStatus::New,
+                amount_claimed: 0,
+                approval_required: false,
+                quorum_reached_at: None,
+                processed_at: None,
+                token_nonces: None,
+                fees: Vec::new(),
+            },
+        }
+    }
+
+    pub fn with_approval(mut self, approval_required: bool) -> Self {
+        self.data.approval_required = approval_required;
+        self
+    }
+
+    pub fn with_token_nonces(mut self, token_nonces: Option<(u8, u8)>) -> Self {
+        self.data.token_nonces = token_nonces;
+        self
+    }
+}
+
+impl Default for WithdrawalTokenMetaWithLen {
+    fn default() -> Self {
+        Self::new(Default::default(), Default::default())
+    }
+}
+
+#[derive(Debug, Copy, Clone, BorshSerialize, BorshDeserialize, EnumAsInner, PartialEq, Eq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum TokenKind {
+    Ever {
+        mint: Pubkey,
+        token: EverAddress,
+        decimals: u8,
+    },
+    Solana {
+        mint: Pubkey,
+        vault: Pubkey,
+    },
+}
+
+#[derive(Copy, BorshSerialize, BorshDeserialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum WithdrawalTokenStatus {
+    New,
+    Processed,
+    Cancelled,
+    Pending,
+    WaitingForApprove,
+    WaitingForExecute,
+    PartiallyProcessed,
+}
+
+impl WithdrawalTokenStatus {
+    /// Processed and cancelled withdrawals no longer change
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            WithdrawalTokenStatus::Processed | WithdrawalTokenStatus::Cancelled
+        )
+    }
+
+    /// Move the withdrawal to the next status, rejecting transitions its lifecycle doesn't allow.
+    ///
+    /// Pending and partially processed withdrawals may stay in place while waiting for vault
+    /// liquidity, processed and cancelled withdrawals are final.
+    pub fn transition(&mut self, next: WithdrawalTokenStatus) -> Result<(), SolanaBridgeError> {
+        use WithdrawalTokenStatus::*;
+
+        let allowed = match *self {
+            New => matches!(
+                next,
+                WaitingForApprove | Pending | WaitingForExecute | Processed | Cancelled
+            ),
+            WaitingForApprove => {
+                matches!(next, Pending | WaitingForExecute | Processed | Cancelled)
+            }
+            Pending => matches!(
+                next,
+                Pending | PartiallyProcessed | WaitingForExecute | Processed | Cancelled
+            ),
+            PartiallyProcessed => matches!(next, PartiallyProcessed | Processed | Cancelled),
+            WaitingForExecute => matches!(next, Processed | Cancelled),
+            Processed | Cancelled => false,
+        };
+
+        if !allowed {
+            return Err(SolanaBridgeError::InvalidStatusTransition);
+        }
+
+        *self = next;
+
+        Ok(())
+    }
+}
+
+#[derive(Copy, BorshSerialize, BorshDeserialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct FeeInfo {
+    pub multiplier: u64,
+    pub divisor: u64,
+}
+
+impl Default for FeeInfo {
+    fn default() -> Self {
+        FeeInfo {
+            multiplier: DEFAULT_MULTIPLIER,
+            divisor: DEFAULT_DIVISOR,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum FeeType {
+    Deposit,
+    Withdrawal,
+}
+
+impl std::str::FromStr for FeeType {
+    type Err = Box<dyn std::error::Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "Deposit" => Ok(FeeType::Deposit),
+            "Withdrawal" => Ok(FeeType::Withdrawal),
+            _ => Err("wrong fee type".to_string().into()),
+        }
+    }
+}
+
+// Events
+/// Number of settings changes kept in a single history page
+pub const SETTINGS_HISTORY_PAGE_CAPACITY: usize = 32;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)]
+pub struct SettingsHistory {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Total number of recorded changes
+    pub total: u64,
+}
+
+impl SettingsHistory {
+    /// Page the next change is written to
+    pub fn current_page(&self) -> u64 {
+        self.total / SETTINGS_HISTORY_PAGE_CAPACITY as u64
+    }
+}
+
+impl Sealed for SettingsHistory {}
+
+impl IsInitialized for SettingsHistory {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 4500)] // 4495 + reserve
+pub struct SettingsHistoryPage {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub page: u64,
+    // Changes ordered from the oldest to the newest one
+    pub changes: Vec<SettingsChange>,
+}
+
+impl Sealed for SettingsHistoryPage {}
+
+impl IsInitialized for SettingsHistoryPage {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct SettingsChange {
+    pub field: SettingsField,
+    // Token settings account for per-token fields
+    pub token_settings: Option<Pubkey>,
+    pub old_value: SettingsValue,
+    pub new_value: SettingsValue,
+    pub actor: Pubkey,
+    pub slot: u64,
+}
+
+#[derive(Copy, BorshSerialize, BorshDeserialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum SettingsField {
+    Guardian,
+    Manager,
+    WithdrawalManager,
+    DepositLimit,
+    WithdrawalLimit,
+    WithdrawalDailyLimit,
+    VetoDisabled,
+    Pauser,
+    FeeManager,
+    Compliance,
+    DepositsRestrictedToWhitelist,
+    RequiredVotes,
+    ConfirmationTiers,
+    PausedOperations,
+}
+
+#[derive(Copy, BorshSerialize, BorshDeserialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum SettingsValue {
+    Address(Pubkey),
+    Amount(u64),
+    Flag(bool),
+}
+
+/// Number of deposits kept in a single deposit index page
+pub const DEPOSIT_INDEX_PAGE_CAPACITY: usize = 64;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)]
+pub struct DepositIndex {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Deposit author
+    pub owner: Pubkey,
+    // Total number of indexed deposits
+    pub total: u64,
+}
+
+impl DepositIndex {
+    /// Page the next deposit is written to
+    pub fn current_page(&self) -> u64 {
+        self.total / DEPOSIT_INDEX_PAGE_CAPACITY as u64
+    }
+}
+
+impl Sealed for DepositIndex {}
+
+impl IsInitialized for DepositIndex {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 2100)] // 2095 + reserve
+pub struct DepositIndexPage {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub owner: Pubkey,
+    pub page: u64,
+    // Deposit accounts ordered from the oldest to the newest one
+    pub deposits: Vec<Pubkey>,
+}
+
+impl Sealed for DepositIndexPage {}
+
+impl IsInitialized for DepositIndexPage {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Result of a deposit preview, returned in the return data
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositPreview {
+    pub status: DepositPreviewStatus,
+    // Deposit fee in Solana decimals
+    pub fee: u64,
+    // Amount that reaches Everscale, in Ever decimals for EVER tokens
+    pub transfer_amount: u128,
+    // Lamports the funder pays: attached value and rent of created accounts
+    pub required_lamports: u64,
+}
+
+#[derive(Copy, BorshSerialize, BorshDeserialize, Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub enum DepositPreviewStatus {
+    Ok,
+    EmergencyEnabled,
+    TokenEmergencyEnabled,
+    TokenAccountMissing,
+    InsufficientTokenBalance,
+    AmountTooSmall,
+    DepositLimit,
+    InsufficientFunds,
+    DepositPaused,
+}
+
+/// Deposit created by the instruction, returned in the return data
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositResult {
+    pub deposit: Pubkey,
+    // Deposit fee in Solana decimals
+    pub fee: u64,
+    // Amount that reaches Everscale, in Ever decimals for EVER tokens
+    pub transfer_amount: u128,
+}
+
+/// Deposit signed off-chain by the token owner, see `DepositMultiTokenSolGasless`.
+///
+/// The owner signs the signing payload of the Borsh serialized message.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct GaslessDepositMessage {
+    pub owner: Pubkey,
+    // Token account spent by the Delegate, others may approve the Delegate too
+    pub owner_token_account: Pubkey,
+    pub mint: Pubkey,
+    pub deposit_seed: u128,
+    pub amount: u64,
+    pub recipient: EverAddress,
+    pub value: u64,
+    pub expected_evers: UInt256,
+    // Hash of the deposit payload
+    pub payload_hash: [u8; 32],
+    pub nonce: u64,
+    pub expiry: i64,
+}
+
+impl GaslessDepositMessage {
+    /// Bytes the owner signs for the deployment of the program
+    pub fn signing_payload(&self, program_id: &Pubkey) -> [u8; SIGNING_PAYLOAD_LEN] {
+        signing_payload(
+            program_id,
+            TokenProxyInstruction::DEPOSIT_MULTI_TOKEN_SOL_GASLESS_TAG,
+            &self.try_to_vec().expect("pack"),
+        )
+    }
+}
+
+/// Batch deposit created by the instruction, returned in the return data
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositBatchResult {
+    pub deposit: Pubkey,
+    // Deposit fees in Solana decimals, in order of entries
+    pub fees: Vec<u64>,
+    // Amounts that reach Everscale, in order of entries
+    pub transfer_amounts: Vec<u128>,
+}
+
+/// Withdrawal processed by the instruction, returned in the return data
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalResult {
+    pub withdrawal: Pubkey,
+    pub status: WithdrawalTokenStatus,
+    // Withdrawal fee in Solana decimals
+    pub fee: u64,
+    // Amount without fee in Solana decimals
+    pub transfer_amount: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositMultiTokenEvent {
+    pub account: Pubkey,
+    pub recipient: EverAddress,
+    pub transfer_amount: u128,
+    pub seed: u128,
+    pub value: u64,
+    pub expected_evers: UInt256,
+    pub event_data: Vec<u8>,
+    // Deposited amount including the fee, in Solana decimals
+    pub amount: u128,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositBatchEvent {
+    pub account: Pubkey,
+    pub recipient: EverAddress,
+    pub transfer_amounts: Vec<u128>,
+    pub seed: u128,
+    pub value: u64,
+    pub expected_evers: UInt256,
+    pub event_data: Vec<u8>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawMultiTokenRequestEvent {
+    pub account: Pubkey,
+    pub token: String,
+    pub recipient: Pubkey,
+    pub amount: u128,
+    pub event_timestamp: u32,
+    pub event_transaction_lt: u64,
+    pub event_configuration: Pubkey,
+    pub event_data: Vec<u8>,
+    pub bounty: i64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct TokenSettingsEvent {
+    pub account: Pubkey,
+    pub symbol: String,
+    pub name: String,
+    pub mint: Pubkey,
+    pub vault: Option<Pubkey>,
+    pub ever_decimals: Option<u8>,
+    pub solana_decimals: Option<u8>,
+    pub root: Option<EverAddress>,
+    pub fee: FeeInfo,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct UpdateWithdrawalStatusEvent {
+    pub account: Pubkey,
+    pub status: WithdrawalTokenStatus,
+    pub quorum_reached_at: Option<Timestamp>,
+    pub processed_at: Option<Timestamp>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct VetoWithdrawalEvent {
+    pub account: Pubkey,
+    pub guardian: Pubkey,
+    pub reason: String,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct UpdateFeeEvent {
+    pub token_settings: Pubkey,
+    pub fee_type: FeeType,
+    pub multiplier: u64,
+    pub divisor: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct UpdateWithdrawalLimitsEvent {
+    pub token_settings: Pubkey,
+    pub withdrawal_limit: u64,
+    pub withdrawal_daily_limit: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct ScheduleLimitChangeEvent {
+    pub token_settings: Pubkey,
+    // None if the scheduled change is cancelled
+    pub change: Option<ScheduledLimitChange>,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DepositLiquidityEvent {
+    pub lp_position: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawLiquidityEvent {
+    pub lp_position: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct DeployLiquidityEvent {
+    pub token_settings: Pubkey,
+    pub yield_adapter: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RecallLiquidityEvent {
+    pub token_settings: Pubkey,
+    pub yield_adapter: Pubkey,
+    pub amount: u64,
+    // Returned on top of the recalled amount
+    pub yield_amount: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RotateVaultEvent {
+    pub token_settings: Pubkey,
+    pub vault: Pubkey,
+    pub new_vault: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RecoverFrozenVaultEvent {
+    pub token_settings: Pubkey,
+    pub frozen_vault: Pubkey,
+    pub frozen_amount: u64,
+    pub vault: Pubkey,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct RecoverAuthorityEvent {
+    pub token_settings: Pubkey,
+    pub vault: Pubkey,
+    pub new_program_id: Pubkey,
+    pub new_vault: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct UpdateTokenNameEvent {
+    pub token_settings: Pubkey,
+    pub symbol: String,
+    pub name: String,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct LiquidityRequestEvent {
+    pub deposit: Pubkey,
+    pub withdrawal: Pubkey,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 200)]
+pub struct VaultRotation {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub token_settings: Pubkey,
+    // Vault the tokens are moved from
+    pub vault: Pubkey,
+    // Vault the tokens are moved to. Once executed the account redirects the old vault here.
+    pub new_vault: Pubkey,
+    pub unlock_time: i64,
+    pub executed: bool,
+}
+
+impl Sealed for VaultRotation {}
+
+impl IsInitialized for VaultRotation {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 200)]
+pub struct LpPosition {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub token_settings: Pubkey,
+    pub owner: Pubkey,
+    // Share of the token liquidity provided by liquidity providers
+    pub shares: u64,
+}
+
+impl Sealed for LpPosition {}
+
+impl IsInitialized for LpPosition {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Self-contained record of a withdrawal and the relay votes cast for it
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "client", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+pub struct WithdrawalReceipt {
+    pub withdrawal: Pubkey,
+    pub author: Pubkey,
+    pub round_number: u32,
+    pub required_votes: u32,
+    pub pda: PDA,
+    // Serialized withdrawal event
+    pub event: Vec<u8>,
+    // Withdrawal status at the receipt slot
+    pub status: WithdrawalTokenStatus,
+    // Relays of the round, in the order of the votes
+    pub relays: Vec<Pubkey>,
+    pub signers: Vec<Vote>,
+    pub round_end: u32,
+    // Slot the withdrawal was read at
+    pub slot: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)]
+pub struct RewardConfig {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Percent of the token fee supply distributed to relays of a round
+    pub relay_share: u8,
+}
+
+impl Sealed for RewardConfig {}
+
+impl IsInitialized for RewardConfig {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 500)] // 443 + reserve
+pub struct RelayRoundStats {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub round_number: u32,
+    // Total number of votes cast in the round
+    pub total: u64,
+    // Votes cast by each relay, in the order of the round relays
+    pub votes: Vec<u32>,
+    // Withdrawals voted for in the round
+    pub withdrawals: u32,
+    // Sum of the voted withdrawal amounts, in base units of their tokens
+    pub volume: u128,
+    // Withdrawals created in the round that haven't reached a final status
+    pub active_withdrawals: u32,
+}
+
+impl RelayRoundStats {
+    /// Votes cast by the relay with the given position in the round
+    pub fn relay_votes(&self, index: usize) -> u32 {
+        self.votes.get(index).copied().unwrap_or_default()
+    }
+}
+
+impl Sealed for RelayRoundStats {}
+
+impl IsInitialized for RelayRoundStats {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 200)] // 151 + reserve
+pub struct RoundReward {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub token_settings: Pubkey,
+    pub round_number: u32,
+    // Fees distributed to relays of the round, in Solana decimals
+    pub amount: u64,
+    // Whether the reward was claimed, in the order of the round relays
+    pub claimed: Vec<bool>,
+}
+
+impl RoundReward {
+    /// Reward of the relay proportional to its votes in the round
+    pub fn relay_reward(&self, relay_round_stats: &RelayRoundStats, index: usize) -> u64 {
+        let votes = relay_round_stats.relay_votes(index);
+        if relay_round_stats.total == 0 {
+            return 0;
+        }
+
+        (self.amount as u128 * votes as u128 / relay_round_stats.total as u128) as u64
+    }
+}
+
+impl Sealed for RoundReward {}
+
+impl IsInitialized for RoundReward {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 35 + reserve
+pub struct EventIndex {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Withdrawal the EVER event is represented by
+    pub withdrawal: Pubkey,
+}
+
+impl Sealed for EventIndex {}
+
+impl IsInitialized for EventIndex {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 36 + reserve
+pub struct TokenWhitelist {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Mint allowed to be deposited while deposits are restricted to the whitelist
+    pub mint: Pubkey,
+}
+
+impl Sealed for TokenWhitelist {}
+
+impl IsInitialized for TokenWhitelist {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 52 + reserve
+pub struct PayloadSchema {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub schema_id: u32,
+    // Bounds of the payload length, inclusive
+    pub min_len: u32,
+    pub max_len: u32,
+    // Leading bytes of the payload checked against the header hash
+    pub header_len: u32,
+    pub header_hash: Option<[u8; 32]>,
+}
+
+impl PayloadSchema {
+    /// Whether the payload satisfies the schema constraints
+    pub fn matches(&self, payload: &[u8]) -> bool {
+        if payload.len() < self.min_len as usize || payload.len() > self.max_len as usize {
+            return false;
+        }
+
+        match self.header_hash {
+            Some(header_hash) => payload
+                .get(..self.header_len as usize)
+                .map_or(false, |header| hash(header).to_bytes() == header_hash),
+            None => true,
+        }
+    }
+}
+
+impl Sealed for PayloadSchema {}
+
+impl IsInitialized for PayloadSchema {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 200)] // 186 + reserve
+pub struct KnownToken {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // EVER token root
+    pub token: EverAddress,
+    // Mint of the token on Solana
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl KnownToken {
+    /// Whether the metadata supplied by a relay is the one of the known token
+    pub fn matches(&self, name: &str, symbol: &str, decimals: u8) -> bool {
+        self.name == name && self.symbol == symbol && self.decimals == decimals
+    }
+}
+
+impl Sealed for KnownToken {}
+
+impl IsInitialized for KnownToken {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 44 + reserve
+pub struct ExemptAccount {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Market maker bypassing the per-transaction deposit and withdrawal limits
+    pub authority: Pubkey,
+    // Exemption is void from this timestamp on
+    pub expires_at: i64,
+}
+
+impl ExemptAccount {
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
+
+impl Sealed for ExemptAccount {}
+
+impl IsInitialized for ExemptAccount {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Number of nonces tracked by the nonce bitmap
+pub const NONCE_WINDOW: u64 = 256;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 75 + reserve
+pub struct Nonce {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Signer of the sponsored operations
+    pub owner: Pubkey,
+    // Lowest nonce of the window, older nonces are considered used
+    pub base: u64,
+    // Used nonces of the window, bit `i` stands for `base + i`
+    pub bitmap: [u8; 32],
+}
+
+impl Nonce {
+    /// Whether the nonce can't be used anymore
+    pub fn is_used(&self, nonce: u64) -> bool {
+        if nonce < self.base {
+            return true;
+        }
+
+        let offset = nonce - self.base;
+        offset < NONCE_WINDOW && self.is_set(offset)
+    }
+
+    /// Mark the nonce used, sliding the window forward when the nonce is beyond it
+    pub fn use_nonce(&mut self, nonce: u64) -> Result<(), SolanaBridgeError> {
+        if self.is_used(nonce) {
+            return Err(SolanaBridgeError::NonceUsed);
+        }
+
+        if nonce - self.base >= NONCE_WINDOW {
+            let base = nonce - (NONCE_WINDOW - 1);
+            let shift = base - self.base;
+
+            // Nonces still in the window keep their bits
+            let mut bitmap = [0; 32];
+            for offset in shift.min(NONCE_WINDOW)..NONCE_WINDOW {
+                if self.is_set(offset) {
+                    let shifted = offset - shift;
+                    bitmap[(shifted / 8) as usize] |= 1 << (shifted % 8);
+                }
+            }
+
+            self.base = base;
+            self.bitmap = bitmap;
+        }
+
+        self.set(nonce - self.base);
+
+        Ok(())
+    }
+
+    /// Nonce following the highest used one, fresh for the next signed message
+    pub fn fresh_nonce(&self) -> u64 {
+        (0..NONCE_WINDOW)
+            .rev()
+            .find(|offset| self.is_set(*offset))
+            .map_or(self.base, |offset| self.base + offset + 1)
+    }
+
+    fn is_set(&self, offset: u64) -> bool {
+        self.bitmap[(offset / 8) as usize] & (1 << (offset % 8)) != 0
+    }
+
+    fn set(&mut self, offset: u64) {
+        self.bitmap[(offset / 8) as usize] |= 1 << (offset % 8);
+    }
+}
+
+impl Sealed for Nonce {}
+
+impl IsInitialized for Nonce {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+/// Withdrawal fee discounts are in basis points of the fee
+pub const DISCOUNT_BPS_DENOMINATOR: u16 = 10_000;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 54 + reserve
+pub struct DiscountConfig {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    // Token staked for the discount, fixed once the config is created
+    pub mint: Pubkey,
+    // Active stake required for the discount
+    pub min_stake: u64,
+    // Withdrawal fee discount, in basis points
+    pub discount_bps: u16,
+    // Seconds unstaked tokens stay locked
+    pub unstake_cooldown: i64,
+}
+
+impl DiscountConfig {
+    /// Withdrawal fee left after the discount of the stake
+    pub fn discounted_fee(&self, fee: u64, stake: &DiscountStake) -> u64 {
+        if stake.active_amount() < self.min_stake {
+            return fee;
+        }
+
+        let discount = fee as u128 * self.discount_bps as u128 / DISCOUNT_BPS_DENOMINATOR as u128;
+        fee.saturating_sub(discount as u64)
+    }
+}
+
+impl Sealed for DiscountConfig {}
+
+impl IsInitialized for DiscountConfig {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, BridgePack)]
+#[cfg_attr(feature = "schema", derive(BorshSchema))]
+#[bridge_pack(length = 100)] // 59 + reserve
+pub struct DiscountStake {
+    pub is_initialized: bool,
+    pub account_kind: AccountKind,
+    pub owner: Pubkey,
+    // Tokens held in the stake vault for the owner
+    pub amount: u64,
+    // Part of the amount requested to be unstaked, it doesn't count for the discount
+    pub unstaking_amount: u64,
+    // Unstaking amount can be withdrawn from this timestamp on
+    pub unstake_available_at: i64,
+}
+
+impl DiscountStake {
+    /// Stake counted for the discount
+    pub fn active_amount(&self) -> u64 {
+        self.amount.saturating_sub(self.unstaking_amount)
+    }
+}
+
+impl Sealed for DiscountStake {}
+
+impl IsInitialized for DiscountStake {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
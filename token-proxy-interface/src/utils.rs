@@ -0,0 +1,468 @@
+use borsh::BorshSerialize;
+use bridge_utils::helper::create_pda;
+use bridge_utils::types::EverAddress;
+use solana_program::account_info::AccountInfo;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub fn get_associated_settings_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"settings"], program_id).0
+}
+
+pub fn get_associated_multivault_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"multivault"], program_id).0
+}
+
+pub fn get_associated_token_settings_ever_address(
+    program_id: &Pubkey,
+    token: &EverAddress,
+) -> Pubkey {
+    find_token_settings_ever_address(program_id, token).0
+}
+
+pub fn find_token_settings_ever_address(program_id: &Pubkey, token: &EverAddress) -> (Pubkey, u8) {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+    Pubkey::find_program_address(&[br"settings", token_hash.as_ref()], program_id)
+}
+
+pub fn get_associated_token_settings_sol_address(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    find_token_settings_sol_address(program_id, mint, None).0
+}
+
+/// Token settings of the mint in the namespace of the EVER event configuration. Tokens of the
+/// default namespace keep seeds without configuration.
+pub fn find_token_settings_sol_address(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    ever_configuration: Option<&Pubkey>,
+) -> (Pubkey, u8) {
+    let seeds = get_token_settings_sol_seeds(mint, ever_configuration);
+    let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    Pubkey::find_program_address(&seeds, program_id)
+}
+
+/// Seeds of the token settings, without the bump seed:
+///   - default namespace: `["settings", mint]`
+///   - configuration namespace: `["settings", mint, configuration]`
+pub fn get_token_settings_sol_seeds(
+    mint: &Pubkey,
+    ever_configuration: Option<&Pubkey>,
+) -> Vec<Vec<u8>> {
+    let mut seeds = vec![br"settings".to_vec(), mint.to_bytes().to_vec()];
+
+    if let Some(ever_configuration) = ever_configuration {
+        seeds.push(ever_configuration.to_bytes().to_vec());
+    }
+
+    seeds
+}
+
+pub fn get_associated_mint_address(program_id: &Pubkey, token: &EverAddress) -> Pubkey {
+    find_mint_address(program_id, token).0
+}
+
+pub fn find_mint_address(program_id: &Pubkey, token: &EverAddress) -> (Pubkey, u8) {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+    Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], program_id)
+}
+
+pub fn get_associated_vault_address(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"vault", &mint.to_bytes()], program_id).0
+}
+
+/// Vault of the given generation. The first vault keeps seeds without generation.
+pub fn find_vault_address(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    ever_configuration: Option<&Pubkey>,
+    generation: u8,
+) -> (Pubkey, u8) {
+    let seeds = get_vault_seeds(mint, ever_configuration, generation);
+    let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    Pubkey::find_program_address(&seeds, program_id)
+}
+
+/// Seeds of the vault of the given generation, without the bump seed.
+///
+/// A vault is an SPL token account owned by itself, so the same seeds sign its transfers:
+///   - generation 0: `["vault", mint]`
+///   - generation N: `["vault", mint, [N]]`
+///
+/// Vaults of a token namespaced by an EVER event configuration have the configuration
+/// following the mint: `["vault", mint, configuration, [N]]`.
+///
+/// The seeds don't depend on the program id, so the vaults of a deployment under another
+/// program id are found from the same seeds.
+pub fn get_vault_seeds(
+    mint: &Pubkey,
+    ever_configuration: Option<&Pubkey>,
+    generation: u8,
+) -> Vec<Vec<u8>> {
+    let mut seeds = vec![br"vault".to_vec(), mint.to_bytes().to_vec()];
+
+    if let Some(ever_configuration) = ever_configuration {
+        seeds.push(ever_configuration.to_bytes().to_vec());
+    }
+
+    if generation != 0 {
+        seeds.push(vec![generation]);
+    }
+
+    seeds
+}
+
+pub fn get_associated_vault_rotation_address(program_id: &Pubkey, vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"vault_rotation", &vault.to_bytes()], program_id).0
+}
+
+pub fn get_associated_lp_position_address(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"lp_position", &mint.to_bytes(), &owner.to_bytes()],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_deposit_address(program_id: &Pubkey, sender: &Pubkey, seed: u128) -> Pubkey {
+    find_deposit_address(program_id, sender, seed, None).0
+}
+
+/// Deposit of a token in the namespace of the EVER event configuration
+pub fn find_deposit_address(
+    program_id: &Pubkey,
+    sender: &Pubkey,
+    seed: u128,
+    ever_configuration: Option<&Pubkey>,
+) -> (Pubkey, u8) {
+    match ever_configuration {
+        Some(ever_configuration) => Pubkey::find_program_address(
+            &[
+                br"deposit",
+                &sender.to_bytes(),
+                &seed.to_le_bytes(),
+                &ever_configuration.to_bytes(),
+            ],
+            program_id,
+        ),
+        None => Pubkey::find_program_address(
+            &[br"deposit", &sender.to_bytes(), &seed.to_le_bytes()],
+            program_id,
+        ),
+    }
+}
+
+/// Address of deposits created before the sender was part of the seeds
+pub fn get_associated_legacy_deposit_address(program_id: &Pubkey, seed: u128) -> Pubkey {
+    Pubkey::find_program_address(&[br"deposit", &seed.to_le_bytes()], program_id).0
+}
+
+pub fn get_associated_proxy_address(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"proxy", &mint.to_bytes(), &recipient.to_bytes()],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_hook_authority_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"hook"], program_id).0
+}
+
+pub fn get_associated_settings_history_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"settings_history"], program_id).0
+}
+
+pub fn get_associated_settings_history_page_address(program_id: &Pubkey, page: u64) -> Pubkey {
+    Pubkey::find_program_address(&[br"settings_history", &page.to_le_bytes()], program_id).0
+}
+
+pub fn get_associated_deposit_index_address(program_id: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"deposit_index", &owner.to_bytes()], program_id).0
+}
+
+pub fn get_associated_deposit_index_page_address(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    page: u64,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"deposit_index", &owner.to_bytes(), &page.to_le_bytes()],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_reward_config_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"reward_config"], program_id).0
+}
+
+pub fn get_associated_relay_round_stats_address(program_id: &Pubkey, round_number: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[br"relay_round_stats", &round_number.to_le_bytes()],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_round_reward_address(
+    program_id: &Pubkey,
+    token_settings: &Pubkey,
+    round_number: u32,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            br"round_reward",
+            &token_settings.to_bytes(),
+            &round_number.to_le_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_event_index_address(
+    program_id: &Pubkey,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            br"event_index",
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+        ],
+        program_id,
+    )
+    .0
+}
+
+pub fn get_associated_token_whitelist_address(program_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"whitelist", &mint.to_bytes()], program_id).0
+}
+
+pub fn get_associated_payload_schema_address(program_id: &Pubkey, schema_id: u32) -> Pubkey {
+    Pubkey::find_program_address(&[br"payload_schema", &schema_id.to_le_bytes()], program_id).0
+}
+
+pub fn get_associated_known_token_address(program_id: &Pubkey, token: &EverAddress) -> Pubkey {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+    Pubkey::find_program_address(&[br"known_token", token_hash.as_ref()], program_id).0
+}
+
+pub fn get_associated_exempt_account_address(program_id: &Pubkey, authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"exempt_account", &authority.to_bytes()], program_id).0
+}
+
+pub fn get_associated_deposit_delegate_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"deposit_delegate"], program_id).0
+}
+
+pub fn get_associated_nonce_address(program_id: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"nonce", &owner.to_bytes()], program_id).0
+}
+
+pub fn get_associated_discount_config_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"discount_config"], program_id).0
+}
+
+pub fn get_associated_discount_vault_address(program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"discount_vault"], program_id).0
+}
+
+pub fn get_associated_discount_stake_address(program_id: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[br"discount_stake", &owner.to_bytes()], program_id).0
+}
+
+pub fn get_associated_mint(program_id: &Pubkey, token: &EverAddress) -> Pubkey {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+    Pubkey::find_program_address(&[br"mint", token_hash.as_ref()], program_id).0
+}
+
+pub fn validate_token_settings_ever_account(
+    program_id: &Pubkey,
+    token: &EverAddress,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+
+    let account = create_pda(program_id, &[br"settings", token_hash.as_ref()], nonce)?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(account)
+}
+
+pub fn validate_token_settings_sol_account(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    ever_configuration: Option<&Pubkey>,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    let seeds = get_token_settings_sol_seeds(mint, ever_configuration);
+    let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    let account = create_pda(program_id, &seeds, nonce)?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(account)
+}
+
+pub fn validate_mint_account(
+    program_id: &Pubkey,
+    token: &EverAddress,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let token_hash = hash(&token.try_to_vec().expect("pack"));
+
+    let account = create_pda(program_id, &[br"mint", token_hash.as_ref()], nonce)?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_vault_account(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    ever_configuration: Option<&Pubkey>,
+    generation: u8,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let seeds = get_vault_seeds(mint, ever_configuration, generation);
+    let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    let account = create_pda(program_id, &seeds, nonce)?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+/// Check the deposit account is derived from the author, the seed and the namespace of the
+/// deposited token. Deposits of the default namespace accept legacy addresses as well.
+pub fn validate_deposit_account(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    seed: u128,
+    ever_configuration: Option<&Pubkey>,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let ever_configuration = match ever_configuration {
+        Some(ever_configuration) => ever_configuration,
+        None => {
+            return bridge_utils::helper::validate_deposit_account(
+                program_id,
+                author,
+                seed,
+                nonce,
+                account_info,
+            )
+        }
+    };
+
+    let account = create_pda(
+        program_id,
+        &[
+            br"deposit",
+            &author.to_bytes(),
+            &seed.to_le_bytes(),
+            &ever_configuration.to_bytes(),
+        ],
+        nonce,
+    )?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_multi_vault_account(
+    program_id: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let account = create_pda(program_id, &[br"multivault"], nonce)?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_discount_config_account(
+    program_id: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let account = create_pda(program_id, &[br"discount_config"], nonce)?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_discount_vault_account(
+    program_id: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let account = create_pda(program_id, &[br"discount_vault"], nonce)?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+pub fn validate_proxy_account(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+    nonce: u8,
+    account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let account = create_pda(
+        program_id,
+        &[br"proxy", &mint.to_bytes(), &recipient.to_bytes()],
+        nonce,
+    )?;
+
+    if account != *account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
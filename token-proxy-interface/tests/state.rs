@@ -0,0 +1,425 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::signing::{signing_payload, SIGNING_DOMAIN};
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::{EverAddress, UInt256};
+use solana_program::clock::Clock;
+use solana_program::pubkey::Pubkey;
+use token_proxy_interface::{
+    find_token_settings_sol_address, find_vault_address, get_associated_token_settings_sol_address,
+    get_associated_vault_address, DepositTokenMeta, DepositTokenMetaWithLen, DiscountConfig,
+    DiscountStake, GaslessDepositMessage, Nonce, Settings, Timestamp, TokenProxyInstruction,
+    WithdrawalTokenMetaWithLen, WithdrawalTokenStatus, GUARDIAN_INACTIVITY_WINDOW, NONCE_WINDOW,
+};
+
+const STATUSES: [WithdrawalTokenStatus; 7] = [
+    WithdrawalTokenStatus::New,
+    WithdrawalTokenStatus::Processed,
+    WithdrawalTokenStatus::Cancelled,
+    WithdrawalTokenStatus::Pending,
+    WithdrawalTokenStatus::WaitingForApprove,
+    WithdrawalTokenStatus::WaitingForExecute,
+    WithdrawalTokenStatus::PartiallyProcessed,
+];
+
+fn allowed_transitions(from: WithdrawalTokenStatus) -> Vec<WithdrawalTokenStatus> {
+    STATUSES
+        .into_iter()
+        .filter(|next| {
+            let mut status = from;
+            status.transition(*next).is_ok()
+        })
+        .collect()
+}
+
+#[test]
+fn test_withdrawal_status_transition() {
+    let mut status = WithdrawalTokenStatus::New;
+
+    status
+        .transition(WithdrawalTokenStatus::WaitingForApprove)
+        .unwrap();
+    status.transition(WithdrawalTokenStatus::Pending).unwrap();
+    status
+        .transition(WithdrawalTokenStatus::PartiallyProcessed)
+        .unwrap();
+    status.transition(WithdrawalTokenStatus::Processed).unwrap();
+
+    assert_eq!(status, WithdrawalTokenStatus::Processed);
+}
+
+#[test]
+fn test_withdrawal_status_invalid_transition() {
+    let mut status = WithdrawalTokenStatus::WaitingForExecute;
+
+    let err = status
+        .transition(WithdrawalTokenStatus::Pending)
+        .unwrap_err();
+
+    assert!(matches!(err, SolanaBridgeError::InvalidStatusTransition));
+
+    // Rejected transition keeps the status
+    assert_eq!(status, WithdrawalTokenStatus::WaitingForExecute);
+}
+
+#[test]
+fn test_withdrawal_status_transitions() {
+    use WithdrawalTokenStatus::*;
+
+    assert_eq!(
+        allowed_transitions(New),
+        vec![
+            Processed,
+            Cancelled,
+            Pending,
+            WaitingForApprove,
+            WaitingForExecute
+        ]
+    );
+    assert_eq!(
+        allowed_transitions(WaitingForApprove),
+        vec![Processed, Cancelled, Pending, WaitingForExecute]
+    );
+    assert_eq!(
+        allowed_transitions(Pending),
+        vec![
+            Processed,
+            Cancelled,
+            Pending,
+            WaitingForExecute,
+            PartiallyProcessed
+        ]
+    );
+    assert_eq!(
+        allowed_transitions(PartiallyProcessed),
+        vec![Processed, Cancelled, PartiallyProcessed]
+    );
+    assert_eq!(
+        allowed_transitions(WaitingForExecute),
+        vec![Processed, Cancelled]
+    );
+
+    // Processed and cancelled withdrawals are final
+    assert!(allowed_transitions(Processed).is_empty());
+    assert!(allowed_transitions(Cancelled).is_empty());
+
+    // Nothing goes back to a new withdrawal
+    for mut status in STATUSES {
+        assert!(status.transition(New).is_err());
+    }
+}
+
+fn new_nonce() -> Nonce {
+    Nonce {
+        is_initialized: true,
+        account_kind: AccountKind::Nonce(255),
+        owner: Pubkey::new_unique(),
+        base: 0,
+        bitmap: [0; 32],
+    }
+}
+
+#[test]
+fn test_nonce_used_once() {
+    let mut nonce = new_nonce();
+
+    assert_eq!(nonce.fresh_nonce(), 0);
+
+    nonce.use_nonce(5).unwrap();
+    nonce.use_nonce(2).unwrap();
+
+    assert!(nonce.is_used(5));
+    assert!(nonce.is_used(2));
+    assert!(!nonce.is_used(3));
+
+    let err = nonce.use_nonce(5).unwrap_err();
+    assert!(matches!(err, SolanaBridgeError::NonceUsed));
+
+    // Fresh nonce follows the highest used one
+    assert_eq!(nonce.fresh_nonce(), 6);
+}
+
+#[test]
+fn test_gasless_signing_payload() {
+    let message = GaslessDepositMessage {
+        owner: Pubkey::new_unique(),
+        owner_token_account: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        deposit_seed: 1,
+        amount: 32,
+        recipient: EverAddress::with_standart(0, [1; 32]),
+        value: 1000,
+        expected_evers: UInt256::default(),
+        payload_hash: [0; 32],
+        nonce: 0,
+        expiry: i64::MAX,
+    };
+
+    // Tag is the variant index the instruction data starts with
+    let instruction_data = TokenProxyInstruction::DepositMultiTokenSolGasless {
+        deposit_seed: message.deposit_seed,
+        name: String::new(),
+        symbol: String::new(),
+        amount: message.amount,
+        recipient: message.recipient,
+        value: message.value,
+        expected_evers: message.expected_evers,
+        payload: vec![],
+        payload_schema: None,
+        nonce: message.nonce,
+        expiry: message.expiry,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    assert_eq!(
+        instruction_data[0],
+        TokenProxyInstruction::DEPOSIT_MULTI_TOKEN_SOL_GASLESS_TAG
+    );
+
+    let program_id = Pubkey::new_unique();
+    let payload = message.signing_payload(&program_id);
+
+    assert!(payload.starts_with(SIGNING_DOMAIN));
+    assert_eq!(
+        payload,
+        signing_payload(
+            &program_id,
+            TokenProxyInstruction::DEPOSIT_MULTI_TOKEN_SOL_GASLESS_TAG,
+            &message.try_to_vec().unwrap()
+        )
+    );
+}
+
+#[test]
+fn test_nonce_window_slides() {
+    let mut nonce = new_nonce();
+
+    nonce.use_nonce(1).unwrap();
+    nonce.use_nonce(NONCE_WINDOW).unwrap();
+
+    assert_eq!(nonce.base, 1);
+    assert!(nonce.is_used(1));
+    assert!(nonce.is_used(NONCE_WINDOW));
+    assert!(!nonce.is_used(2));
+
+    // Nonces left behind the window can't be used anymore
+    nonce.use_nonce(3 * NONCE_WINDOW).unwrap();
+
+    assert_eq!(nonce.base, 2 * NONCE_WINDOW + 1);
+    assert!(nonce.is_used(NONCE_WINDOW + 1));
+    assert!(!nonce.is_used(3 * NONCE_WINDOW - 1));
+    assert_eq!(nonce.fresh_nonce(), 3 * NONCE_WINDOW + 1);
+}
+
+#[test]
+fn test_discounted_fee() {
+    let config = DiscountConfig {
+        is_initialized: true,
+        account_kind: AccountKind::DiscountConfig(255, 255),
+        mint: Pubkey::new_unique(),
+        min_stake: 100,
+        discount_bps: 2_500,
+        unstake_cooldown: 0,
+    };
+
+    let mut stake = DiscountStake {
+        is_initialized: true,
+        account_kind: AccountKind::DiscountStake(255),
+        owner: Pubkey::new_unique(),
+        amount: 100,
+        unstaking_amount: 0,
+        unstake_available_at: 0,
+    };
+
+    assert_eq!(config.discounted_fee(1_000, &stake), 750);
+
+    // Unstaking amount doesn't count for the discount
+    stake.unstaking_amount = 1;
+    assert_eq!(config.discounted_fee(1_000, &stake), 1_000);
+}
+
+#[test]
+fn test_settings_audit() {
+    let mut settings = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(255, 255),
+        emergency: false,
+        guardian: Pubkey::default(),
+        manager: Pubkey::default(),
+        withdrawal_manager: Pubkey::default(),
+        chain_id: 0,
+        event_seq: 0,
+        veto_disabled: false,
+        deposits_restricted_to_whitelist: false,
+        roles: Settings::initial_roles(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ),
+        permissions: vec![],
+        emergency_cosigners: vec![Pubkey::new_unique()],
+        emergency_cosigners_threshold: 1,
+        guardian_last_active: 0,
+    };
+
+    settings.audit().unwrap();
+
+    // More co-signers required than there are
+    settings.emergency_cosigners_threshold = 2;
+    let err = settings.audit().unwrap_err();
+    assert!(matches!(err, SolanaBridgeError::InvalidQuorum));
+
+    // Role held by nobody
+    settings.emergency_cosigners_threshold = 1;
+    settings.roles[0].authority = Pubkey::default();
+    let err = settings.audit().unwrap_err();
+    assert!(matches!(err, SolanaBridgeError::DefaultRoleKey));
+}
+
+#[test]
+fn test_guardian_inactivity() {
+    let guardian = Pubkey::new_unique();
+    let now = 1_700_000_000;
+
+    // Settings created before the guardian activity was tracked
+    let mut settings = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(255, 255),
+        emergency: false,
+        guardian: Pubkey::default(),
+        manager: Pubkey::default(),
+        withdrawal_manager: Pubkey::default(),
+        chain_id: 0,
+        event_seq: 0,
+        veto_disabled: false,
+        deposits_restricted_to_whitelist: false,
+        roles: Settings::initial_roles(guardian, Pubkey::new_unique(), Pubkey::new_unique()),
+        permissions: vec![],
+        emergency_cosigners: vec![],
+        emergency_cosigners_threshold: 0,
+        guardian_last_active: 0,
+    };
+
+    // Unknown activity is never treated as inactivity
+    assert!(!settings.is_guardian_inactive(now));
+    assert!(!settings.is_guardian_inactive(i64::MAX));
+
+    // Only guardians start the window
+    settings.record_guardian_activity(&Pubkey::new_unique(), now);
+    assert_eq!(settings.guardian_last_active, 0);
+
+    settings.record_guardian_activity(&guardian, now);
+    assert!(!settings.is_guardian_inactive(now + GUARDIAN_INACTIVITY_WINDOW - 1));
+    assert!(settings.is_guardian_inactive(now + GUARDIAN_INACTIVITY_WINDOW));
+}
+
+#[test]
+fn test_legacy_deposit_meta() {
+    let seed = u128::MAX;
+
+    // Meta written before the namespace was added holds the seed only
+    let legacy_meta = seed.to_le_bytes();
+
+    let meta = DepositTokenMeta::unpack_from_slice(&legacy_meta).unwrap();
+    assert_eq!(meta.seed, seed);
+    assert_eq!(meta.ever_configuration, None);
+
+    let mut legacy_meta_with_len = (legacy_meta.len() as u32).to_le_bytes().to_vec();
+    legacy_meta_with_len.extend_from_slice(&legacy_meta);
+
+    let meta = DepositTokenMetaWithLen::try_from_slice(&legacy_meta_with_len).unwrap();
+    assert_eq!(meta.data.seed, seed);
+    assert_eq!(meta.data.ever_configuration, None);
+
+    let ever_configuration = Pubkey::new_unique();
+    // Meta takes the same space in the account with and without the namespace
+    let meta = DepositTokenMetaWithLen::new(seed);
+    assert_eq!(meta.try_to_vec().unwrap().len(), 4 + meta.len as usize);
+
+    let meta = DepositTokenMetaWithLen::new(seed).with_ever_configuration(Some(ever_configuration));
+    assert_eq!(meta.try_to_vec().unwrap().len(), 4 + meta.len as usize);
+
+    // Padding stays out of the meta itself, its schema describes every byte
+    let legacy_meta_data = DepositTokenMetaWithLen::new(seed)
+        .data
+        .try_to_vec()
+        .unwrap();
+    assert_eq!(legacy_meta_data, [legacy_meta.as_slice(), &[0]].concat());
+
+    let unpacked = DepositTokenMetaWithLen::try_from_slice(&meta.try_to_vec().unwrap()).unwrap();
+    assert_eq!(unpacked.data.ever_configuration, Some(ever_configuration));
+}
+
+#[test]
+fn test_namespaced_addresses() {
+    let program_id = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let ever_configuration = Pubkey::new_unique();
+
+    // The default namespace keeps the addresses of existing tokens
+    assert_eq!(
+        find_token_settings_sol_address(&program_id, &mint, None).0,
+        get_associated_token_settings_sol_address(&program_id, &mint)
+    );
+    assert_eq!(
+        find_vault_address(&program_id, &mint, None, 0).0,
+        get_associated_vault_address(&program_id, &mint)
+    );
+
+    assert_ne!(
+        find_token_settings_sol_address(&program_id, &mint, Some(&ever_configuration)).0,
+        get_associated_token_settings_sol_address(&program_id, &mint)
+    );
+    assert_ne!(
+        find_vault_address(&program_id, &mint, Some(&ever_configuration), 0).0,
+        get_associated_vault_address(&program_id, &mint)
+    );
+}
+
+#[test]
+fn test_withdrawal_meta_timestamps() {
+    let clock_at = |slot: u64| Clock {
+        slot,
+        unix_timestamp: slot as i64 * 2,
+        ..Default::default()
+    };
+
+    let mut meta = WithdrawalTokenMetaWithLen::default().data;
+
+    meta.transition(WithdrawalTokenStatus::Pending, &clock_at(10))
+        .unwrap();
+    meta.transition(WithdrawalTokenStatus::Pending, &clock_at(20))
+        .unwrap();
+
+    assert_eq!(meta.quorum_reached_at, Some(Timestamp::from(&clock_at(10))));
+    assert_eq!(meta.processed_at, None);
+
+    meta.transition(WithdrawalTokenStatus::Processed, &clock_at(30))
+        .unwrap();
+
+    assert_eq!(meta.quorum_reached_at, Some(Timestamp::from(&clock_at(10))));
+    assert_eq!(meta.processed_at, Some(Timestamp::from(&clock_at(30))));
+
+    // Cancelled withdrawal never reached the quorum
+    let mut meta = WithdrawalTokenMetaWithLen::default().data;
+
+    meta.transition(WithdrawalTokenStatus::Cancelled, &clock_at(10))
+        .unwrap();
+
+    assert_eq!(meta.quorum_reached_at, None);
+    assert_eq!(meta.processed_at, None);
+
+    // Meta takes the same space in the account with and without the timestamps
+    let meta = WithdrawalTokenMetaWithLen::default();
+    assert_eq!(meta.data.try_to_vec().unwrap().len(), meta.len as usize);
+
+    // Meta written before the timestamps were added
+    let mut legacy_meta = meta.try_to_vec().unwrap();
+    legacy_meta.truncate(legacy_meta.len() - 2 * (1 + 16));
+    legacy_meta[..4].copy_from_slice(&((legacy_meta.len() - 4) as u32).to_le_bytes());
+
+    let meta = WithdrawalTokenMetaWithLen::try_from_slice(&legacy_meta).unwrap();
+    assert_eq!(meta.data.quorum_reached_at, None);
+    assert_eq!(meta.data.processed_at, None);
+}
@@ -0,0 +1,119 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AddTokenToWhitelistAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_whitelist_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, AddTokenToWhitelistAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    mint: Pubkey,
+) -> ProgramResult {
+    let AddTokenToWhitelistAccounts {
+        authority_account_info,
+        token_whitelist_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Validate Token Whitelist Account
+    let (token_whitelist_pubkey, token_whitelist_nonce) =
+        Pubkey::find_program_address(&[br"whitelist", &mint.to_bytes()], program_id);
+
+    if token_whitelist_pubkey != *token_whitelist_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if token_whitelist_account_info.owner == program_id {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Create Token Whitelist Account
+    let token_whitelist_account_signer_seeds: &[&[_]] =
+        &[br"whitelist", &mint.to_bytes(), &[token_whitelist_nonce]];
+
+    invoke_signed(
+        &system_instruction::create_account(
+            authority_account_info.key,
+            token_whitelist_account_info.key,
+            1.max(rent.minimum_balance(TokenWhitelist::LEN)),
+            TokenWhitelist::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[token_whitelist_account_signer_seeds],
+    )?;
+
+    // Init Token Whitelist Account
+    let token_whitelist_account_data = TokenWhitelist {
+        is_initialized: true,
+        account_kind: AccountKind::TokenWhitelist(token_whitelist_nonce),
+        mint,
+    };
+
+    TokenWhitelist::pack(
+        token_whitelist_account_data,
+        &mut token_whitelist_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
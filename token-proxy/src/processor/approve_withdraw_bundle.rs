@@ -0,0 +1,149 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::release_round_withdrawal;
+use super::withdraw_multi_token_bundle::{
+    notify_bundle_hooks, pay_out_bundle, unpack_bundle_entries, validate_bundle_account,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ApproveWithdrawBundleAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ApproveWithdrawBundleAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+) -> ProgramResult {
+    let ApproveWithdrawBundleAccounts {
+        authority_account_info,
+        withdrawal_account_info,
+        settings_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenBundle::unpack(&withdrawal_account_info.data.borrow())?;
+
+    validate_bundle_account(
+        program_id,
+        &withdrawal_account_data,
+        withdrawal_account_info,
+    )?;
+
+    if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
+        return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+    }
+
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    let mut entries = unpack_bundle_entries(
+        program_id,
+        accounts,
+        account_info_iter,
+        &mut withdrawal_account_data,
+        clock.unix_timestamp,
+    )?;
+
+    pay_out_bundle(&entries, &mut withdrawal_account_data, accounts, &clock)?;
+
+    // Decrease withdrawal daily amounts if the withdrawal was counted in the current epoch
+    for entry in &mut entries {
+        let token_settings = &mut entry.token_settings_account_data;
+
+        let mut tracker = LimitTracker::new(
+            &mut token_settings.withdrawal_epoch,
+            &mut token_settings.withdrawal_daily_amount,
+        );
+        tracker.roll_over(LimitTracker::epoch_of(clock.unix_timestamp));
+        tracker.release(
+            withdrawal_account_data.meta.data.epoch,
+            entry.transfer_amount,
+        );
+
+        token_settings.pack_into_slice(&mut entry.token_settings_account_info.data.borrow_mut());
+    }
+
+    let processed = withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::Processed;
+
+    if processed {
+        release_round_withdrawal(program_id, accounts, withdrawal_account_data.round_number)?;
+    }
+
+    let event = withdrawal_account_data.event.data.try_to_vec()?;
+    let recipient = withdrawal_account_data.event.data.recipient;
+
+    WithdrawalMultiTokenBundle::pack(
+        withdrawal_account_data,
+        &mut withdrawal_account_info.data.borrow_mut(),
+    )?;
+
+    // Notify Hook Programs of every paid out token
+    if processed {
+        notify_bundle_hooks(
+            program_id,
+            accounts,
+            &entries,
+            withdrawal_account_info,
+            recipient,
+            &event,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,294 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    emit_event, get_withdrawal_amount, invoke_hook, make_ever_transfer, release_round_withdrawal,
+    unpack_mint_account, unpack_token_account, withdrawal_fee,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ApproveWithdrawEverAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub recipient_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ApproveWithdrawEverAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+) -> ProgramResult {
+    let ApproveWithdrawEverAccounts {
+        authority_account_info,
+        mint_account_info,
+        withdrawal_account_info,
+        recipient_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove {
+        return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+    }
+
+    // Validate Token Setting Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+    let hook_program = token_settings_account_data.hook_program;
+
+    let (token_settings_nonce, mint_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (_, token, _) = token_settings_account_data
+        .kind
+        .into_ever()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_ever_account(
+        program_id,
+        &token,
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if token_settings_account_data.is_paused(TokenOperation::Payout) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Validate Mint Account
+    validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+    // Check connection between token and proposal
+    if token != withdrawal_account_data.event.data.token {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    let mint_account_data = unpack_mint_account(mint_account_info)?;
+    let solana_decimals = mint_account_data.decimals;
+    let ever_decimals = withdrawal_account_data.event.data.decimals;
+
+    let withdrawal_amount = get_withdrawal_amount(
+        withdrawal_account_data.event.data.amount,
+        ever_decimals,
+        solana_decimals,
+    )?;
+
+    let fee = withdrawal_fee(
+        program_id,
+        accounts,
+        &mut withdrawal_account_data.meta.data,
+        0,
+        &withdrawal_account_data.event.data.recipient,
+        &token_settings_account_data.fee_withdrawal_info,
+        withdrawal_amount,
+    )?;
+
+    // Amount without fee
+    let transfer_withdrawal_amount = withdrawal_amount
+        .checked_sub(fee)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    match withdrawal_account_data.event.data.payload.is_empty() {
+        true => {
+            // Validate Recipient Account
+            let recipient_account_data = unpack_token_account(recipient_account_info)?;
+
+            if recipient_account_data.owner != withdrawal_account_data.event.data.recipient {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            make_ever_transfer(
+                mint_account_info,
+                recipient_account_info,
+                &token_settings_account_data,
+                accounts,
+                transfer_withdrawal_amount,
+            )?;
+
+            withdrawal_account_data
+                .meta
+                .data
+                .transition(WithdrawalTokenStatus::Processed, &clock)?;
+        }
+        false => {
+            // Validate Proxy Account
+            let proxy_nonce = withdrawal_account_data
+                .account_kind
+                .into_proposal()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                .1
+                .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+            validate_proxy_account(
+                program_id,
+                mint_account_info.key,
+                &withdrawal_account_data.event.data.recipient,
+                proxy_nonce,
+                recipient_account_info,
+            )?;
+
+            make_ever_transfer(
+                mint_account_info,
+                recipient_account_info,
+                &token_settings_account_data,
+                accounts,
+                transfer_withdrawal_amount,
+            )?;
+
+            withdrawal_account_data
+                .meta
+                .data
+                .transition(WithdrawalTokenStatus::WaitingForExecute, &clock)?;
+        }
+    }
+
+    let mut tracker = LimitTracker::new(
+        &mut token_settings_account_data.withdrawal_epoch,
+        &mut token_settings_account_data.withdrawal_daily_amount,
+    );
+    tracker.roll_over(LimitTracker::epoch_of(clock.unix_timestamp));
+
+    // Decrease withdrawal daily amount if the withdrawal was counted in the current epoch
+    tracker.release(
+        withdrawal_account_data.meta.data.epoch,
+        transfer_withdrawal_amount,
+    );
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    if withdrawal_account_data.meta.data.status.is_final() {
+        release_round_withdrawal(program_id, accounts, round_number)?;
+    }
+
+    let payout = match withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::Processed
+    {
+        true => Some(HookInstruction::Withdrawal {
+            mint: *mint_account_info.key,
+            recipient: withdrawal_account_data.event.data.recipient,
+            amount: transfer_withdrawal_amount,
+            event: withdrawal_account_data.event.data.try_to_vec()?,
+        }),
+        false => None,
+    };
+
+    emit_event(
+        settings_account_info,
+        &UpdateWithdrawalStatusEvent {
+            account: withdrawal_pubkey,
+            status: withdrawal_account_data.meta.data.status,
+            quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+            processed_at: withdrawal_account_data.meta.data.processed_at,
+        },
+    )?;
+
+    WithdrawalMultiTokenEver::pack(
+        withdrawal_account_data,
+        &mut withdrawal_account_info.data.borrow_mut(),
+    )?;
+
+    // Notify Hook Program
+    if let Some(hook_instruction) = payout {
+        invoke_hook(
+            program_id,
+            accounts,
+            hook_program,
+            token_settings_account_info,
+            withdrawal_account_info,
+            hook_instruction,
+        )?;
+    }
+
+    Ok(())
+}
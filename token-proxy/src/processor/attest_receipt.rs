@@ -0,0 +1,104 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::Proposal;
+use bridge_utils::types::Vote;
+use round_loader_interface::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AttestReceiptAccounts<'a, 'info> {
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    pub clock_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, AttestReceiptAccounts<'a, 'info>>,
+    receipt: WithdrawalReceipt,
+) -> ProgramResult {
+    let AttestReceiptAccounts {
+        withdrawal_account_info,
+        relay_round_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    let clock = Clock::get()?;
+
+    if receipt.withdrawal != *withdrawal_account_info.key || receipt.slot > clock.slot {
+        return Err(SolanaBridgeError::InvalidWithdrawalReceipt.into());
+    }
+
+    // Validate Withdrawal Account
+    let withdrawal_account_data =
+        Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    round_loader_interface::validate_relay_round_account(
+        &round_loader_interface::id(),
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Votes can be cast after the receipt slot but never changed
+    let votes_match = receipt.signers.len() == withdrawal_account_data.signers.len()
+        && receipt
+            .signers
+            .iter()
+            .zip(withdrawal_account_data.signers.iter())
+            .all(|(receipt_vote, vote)| *receipt_vote == Vote::None || receipt_vote == vote);
+
+    if receipt.author != withdrawal_account_data.author
+        || receipt.round_number != round_number
+        || receipt.required_votes != withdrawal_account_data.required_votes
+        || receipt.pda.event_timestamp != event_timestamp
+        || receipt.pda.event_transaction_lt != event_transaction_lt
+        || receipt.pda.event_configuration != event_configuration
+        || receipt.event != withdrawal_account_data.event
+        || receipt.relays != relay_round_account_data.relays
+        || receipt.round_end != relay_round_account_data.round_end
+        || !votes_match
+    {
+        return Err(SolanaBridgeError::InvalidWithdrawalReceipt.into());
+    }
+
+    Ok(())
+}
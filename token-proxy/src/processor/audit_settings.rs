@@ -0,0 +1,78 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AuditSettingsAccounts<'a, 'info> {
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub rl_settings_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, AuditSettingsAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let AuditSettingsAccounts {
+        settings_account_info,
+        rl_settings_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    settings_account_data.audit()?;
+
+    // Validate Round Loader Settings Account
+    let rl_settings_account_data =
+        round_loader_interface::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+    let (rl_settings_nonce, _) = rl_settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        &round_loader_interface::id(),
+        rl_settings_nonce,
+        rl_settings_account_info,
+    )?;
+
+    rl_settings_account_data.audit()?;
+
+    // Token settings accounts to check are passed as remaining accounts
+    for token_settings_account_info in ctx.remaining_accounts {
+        if token_settings_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        let token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        token_settings_account_data.audit()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::Proposal;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use super::create_event_index_account;
+
+#[derive(Accounts)]
+pub struct BackfillEventIndexAccounts<'a, 'info> {
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub event_index_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, BackfillEventIndexAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let BackfillEventIndexAccounts {
+        funder_account_info,
+        withdrawal_account_info,
+        event_index_account_info,
+        system_program_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    let rent = Rent::get()?;
+
+    // Validate Withdrawal Account
+    if withdrawal_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let withdrawal_account_data =
+        Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+    withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    create_event_index_account(
+        program_id,
+        withdrawal_account_info.key,
+        &withdrawal_account_data.pda,
+        &rent,
+        funder_account_info,
+        event_index_account_info,
+        system_program_info,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,286 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::{EverAddress, UInt256};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    create_deposit_account, emit_event, release_round_withdrawal, unpack_mint_account,
+    validate_ever_address,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CancelWithdrawSolAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub deposit_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub multi_vault_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CancelWithdrawSolAccounts<'a, 'info>>,
+    deposit_seed: u128,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+) -> ProgramResult {
+    let CancelWithdrawSolAccounts {
+        funder_account_info,
+        author_account_info,
+        mint_account_info,
+        withdrawal_account_info,
+        deposit_account_info,
+        settings_account_info,
+        token_settings_account_info,
+        rent_sysvar_info,
+        multi_vault_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate EVER Recipient Address
+    validate_ever_address(&recipient)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    if withdrawal_account_data.author != *author_account_info.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending {
+        return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+    }
+
+    // Validate Token Setting Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (token_settings_nonce, _) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (mint, _) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if token_settings_account_data.is_paused(TokenOperation::Deposit) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Validate Multi Vault Account
+    let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+    let multi_vault_nonce = multi_vault_account_data
+        .account_kind
+        .into_multi_vault()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+    // Send sol amount to multi vault
+    invoke(
+        &system_instruction::transfer(funder_account_info.key, multi_vault_account_info.key, value),
+        accounts,
+    )?;
+
+    // Validate Mint Account
+    if *mint_account_info.key != mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mint_account_data = unpack_mint_account(mint_account_info)?;
+    let decimals = mint_account_data.decimals;
+
+    // Check connection between token and proposal
+    if mint != withdrawal_account_data.event.data.mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create Deposit Account
+    let deposit_pubkey = *deposit_account_info.key;
+    let deposit_nonce = create_deposit_account(
+        program_id,
+        author_account_info.key,
+        deposit_seed,
+        token_settings_account_data.ever_configuration.as_ref(),
+        DepositMultiTokenSol::LEN,
+        rent,
+        funder_account_info,
+        deposit_account_info,
+        accounts,
+    )?;
+
+    // Init Deposit Account
+    let name = token_settings_account_data.name.clone();
+    let symbol = token_settings_account_data.symbol.clone();
+    let amount = withdrawal_account_data.event.data.amount;
+
+    // Calculate fee
+    let fee_info = &token_settings_account_data.fee_deposit_info;
+
+    let fee = 1.max(
+        amount
+            .checked_div(fee_info.divisor as u128)
+            .ok_or(SolanaBridgeError::Overflow)?
+            .checked_mul(fee_info.multiplier as u128)
+            .ok_or(SolanaBridgeError::Overflow)?,
+    );
+
+    // Increase fee supply
+    token_settings_account_data.fee_supply = token_settings_account_data
+        .fee_supply
+        .checked_add(fee.try_into().map_err(|_| SolanaBridgeError::Overflow)?)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    // Amount without fee
+    let transfer_amount = amount.checked_sub(fee).ok_or(SolanaBridgeError::Overflow)?;
+
+    let deposit_account_data = DepositMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Deposit(deposit_nonce),
+        author: *author_account_info.key,
+        event: DepositMultiTokenSolEventWithLen::new(
+            settings_account_data.chain_id,
+            mint,
+            name,
+            symbol,
+            decimals,
+            transfer_amount,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        ),
+        meta: DepositTokenMetaWithLen::new(deposit_seed)
+            .with_ever_configuration(token_settings_account_data.ever_configuration),
+    };
+
+    let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
+        .to_bytes()
+        .to_vec();
+
+    emit_event(
+        settings_account_info,
+        &DepositMultiTokenEvent {
+            account: withdrawal_pubkey,
+            recipient,
+            transfer_amount,
+            seed: deposit_seed,
+            value,
+            expected_evers,
+            event_data,
+            amount,
+        },
+    )?;
+
+    DepositMultiTokenSol::pack(
+        deposit_account_data,
+        &mut deposit_account_info.data.borrow_mut(),
+    )?;
+
+    withdrawal_account_data
+        .meta
+        .data
+        .transition(WithdrawalTokenStatus::Cancelled, &clock)?;
+
+    release_round_withdrawal(program_id, accounts, round_number)?;
+
+    emit_event(
+        settings_account_info,
+        &UpdateWithdrawalStatusEvent {
+            account: withdrawal_pubkey,
+            status: withdrawal_account_data.meta.data.status,
+            quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+            processed_at: withdrawal_account_data.meta.data.processed_at,
+        },
+    )?;
+
+    WithdrawalMultiTokenSol::pack(
+        withdrawal_account_data,
+        &mut withdrawal_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ChangeBountyForWithdrawSolAccounts<'a, 'info> {
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ChangeBountyForWithdrawSolAccounts<'a, 'info>>,
+    bounty: u64,
+) -> ProgramResult {
+    let ChangeBountyForWithdrawSolAccounts {
+        author_account_info,
+        withdrawal_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending {
+        return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+    }
+
+    if withdrawal_account_data.author != *author_account_info.key
+        && withdrawal_account_data.event.data.recipient != *author_account_info.key
+    {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    withdrawal_account_data.meta.data.bounty = bounty;
+
+    WithdrawalMultiTokenSol::pack(
+        withdrawal_account_data,
+        &mut withdrawal_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
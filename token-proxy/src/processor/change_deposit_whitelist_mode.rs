@@ -0,0 +1,82 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use super::record_settings_change;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ChangeDepositWhitelistModeAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ChangeDepositWhitelistModeAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    restricted: bool,
+) -> ProgramResult {
+    let ChangeDepositWhitelistModeAccounts {
+        authority_account_info,
+        settings_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    // Validate Settings Account
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    record_settings_change(
+        program_id,
+        accounts,
+        authority_account_info.key,
+        None,
+        SettingsField::DepositsRestrictedToWhitelist,
+        SettingsValue::Flag(settings_account_data.deposits_restricted_to_whitelist),
+        SettingsValue::Flag(restricted),
+    )?;
+
+    settings_account_data.deposits_restricted_to_whitelist = restricted;
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use super::{get_cosigners, record_settings_change, validate_guardian_replacement};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ChangeGuardianAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ChangeGuardianAccounts<'a, 'info>>,
+    new_guardian: Pubkey,
+) -> ProgramResult {
+    let ChangeGuardianAccounts {
+        authority_account_info,
+        settings_account_info,
+        programdata_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Co-signers
+    let cosigners = get_cosigners(authority_account_info.key, ctx.remaining_accounts);
+
+    validate_guardian_replacement(&settings_account_data, &cosigners, clock.unix_timestamp)?;
+
+    record_settings_change(
+        program_id,
+        accounts,
+        authority_account_info.key,
+        None,
+        SettingsField::Guardian,
+        SettingsValue::Address(
+            settings_account_data
+                .role_members(Role::Guardian)
+                .first()
+                .cloned()
+                .unwrap_or_default(),
+        ),
+        SettingsValue::Address(new_guardian),
+    )?;
+
+    settings_account_data.set_role(Role::Guardian, new_guardian);
+    settings_account_data.guardian_last_active = clock.unix_timestamp;
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
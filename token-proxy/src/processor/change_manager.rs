@@ -0,0 +1,84 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use super::record_settings_change;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ChangeManagerAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ChangeManagerAccounts<'a, 'info>>,
+    new_manager: Pubkey,
+) -> ProgramResult {
+    let ChangeManagerAccounts {
+        authority_account_info,
+        settings_account_info,
+        programdata_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate Settings Account
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    record_settings_change(
+        program_id,
+        accounts,
+        authority_account_info.key,
+        None,
+        SettingsField::Manager,
+        SettingsValue::Address(
+            settings_account_data
+                .role_members(Role::Manager)
+                .first()
+                .cloned()
+                .unwrap_or_default(),
+        ),
+        SettingsValue::Address(new_manager),
+    )?;
+
+    settings_account_data.set_role(Role::Manager, new_manager);
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
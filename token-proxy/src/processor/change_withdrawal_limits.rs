@@ -0,0 +1,139 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use super::{emit_event, record_settings_change};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ChangeWithdrawalLimitsAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ChangeWithdrawalLimitsAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
+) -> ProgramResult {
+    let ChangeWithdrawalLimitsAccounts {
+        authority_account_info,
+        token_settings_account_info,
+        settings_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Validate Token Settings Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (token_settings_nonce, _) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    match token_settings_account_data.kind {
+        TokenKind::Ever { token, .. } => {
+            validate_token_settings_ever_account(
+                program_id,
+                &token,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?;
+        }
+        TokenKind::Solana { mint, .. } => {
+            validate_token_settings_sol_account(
+                program_id,
+                &mint,
+                token_settings_account_data.ever_configuration.as_ref(),
+                token_settings_nonce,
+                token_settings_account_info,
+            )?;
+        }
+    }
+
+    if let Some(new_withdrawal_limit) = new_withdrawal_limit {
+        record_settings_change(
+            program_id,
+            accounts,
+            authority_account_info.key,
+            Some(*token_settings_account_info.key),
+            SettingsField::WithdrawalLimit,
+            SettingsValue::Amount(token_settings_account_data.withdrawal_limit),
+            SettingsValue::Amount(new_withdrawal_limit),
+        )?;
+
+        token_settings_account_data.withdrawal_limit = new_withdrawal_limit;
+    }
+
+    if let Some(new_withdrawal_daily_limit) = new_withdrawal_daily_limit {
+        record_settings_change(
+            program_id,
+            accounts,
+            authority_account_info.key,
+            Some(*token_settings_account_info.key),
+            SettingsField::WithdrawalDailyLimit,
+            SettingsValue::Amount(token_settings_account_data.withdrawal_daily_limit),
+            SettingsValue::Amount(new_withdrawal_daily_limit),
+        )?;
+
+        token_settings_account_data.withdrawal_daily_limit = new_withdrawal_daily_limit;
+    }
+
+    emit_event(
+        settings_account_info,
+        &UpdateWithdrawalLimitsEvent {
+            token_settings: *token_settings_account_info.key,
+            withdrawal_limit: token_settings_account_data.withdrawal_limit,
+            withdrawal_daily_limit: token_settings_account_data.withdrawal_daily_limit,
+        },
+    )?;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
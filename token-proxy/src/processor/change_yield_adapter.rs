@@ -0,0 +1,114 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ChangeYieldAdapterAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    pub clock_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ChangeYieldAdapterAccounts<'a, 'info>>,
+    new_yield_adapter: Option<Pubkey>,
+    max_deployed_share: u8,
+) -> ProgramResult {
+    let ChangeYieldAdapterAccounts {
+        authority_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        programdata_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    let clock = Clock::get()?;
+
+    // Share is in percents of the vault balance
+    if max_deployed_share > 100 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Token Settings Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (mint, _) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (token_settings_nonce, _) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    // Liquidity has to be recalled from the previous adapter first
+    if token_settings_account_data.yield_adapter != new_yield_adapter {
+        if token_settings_account_data.deployed_amount != 0 {
+            return Err(SolanaBridgeError::LiquidityDeployed.into());
+        }
+
+        token_settings_account_data.yield_adapter = new_yield_adapter;
+        token_settings_account_data.yield_adapter_unlock_time = clock
+            .unix_timestamp
+            .checked_add(YIELD_ADAPTER_TIMELOCK)
+            .ok_or(SolanaBridgeError::Overflow)?;
+    }
+
+    token_settings_account_data.max_deployed_share = max_deployed_share;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
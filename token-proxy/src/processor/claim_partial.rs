@@ -0,0 +1,262 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    emit_event, invoke_hook, make_sol_transfer, release_round_withdrawal, unpack_token_account,
+    withdrawal_fee,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ClaimPartialAccounts<'a, 'info> {
+    #[account(signer)]
+    pub recipient_account_info: &'a AccountInfo<'info>,
+    pub recipient_token_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ClaimPartialAccounts<'a, 'info>>,
+    amount: u64,
+) -> ProgramResult {
+    let ClaimPartialAccounts {
+        recipient_account_info,
+        recipient_token_account_info,
+        withdrawal_account_info,
+        vault_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending
+        && withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::PartiallyProcessed
+    {
+        return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+    }
+
+    // Withdrawals with payload are sent to the proxy account as a whole
+    if !withdrawal_account_data.event.data.payload.is_empty() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if withdrawal_account_data.event.data.recipient != *recipient_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Token Setting Account
+    let token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+    let hook_program = token_settings_account_data.hook_program;
+
+    let (token_settings_nonce, vault_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (mint, _) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if token_settings_account_data.is_paused(TokenOperation::Payout) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Check connection between token and proposal
+    if mint != withdrawal_account_data.event.data.mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Vault Account
+    validate_vault_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_account_data.vault_generation,
+        vault_nonce,
+        vault_account_info,
+    )?;
+
+    // Validate Recipient Token Account
+    let recipient_token_account_data = unpack_token_account(recipient_token_account_info)?;
+
+    if recipient_token_account_data.owner != withdrawal_account_data.event.data.recipient {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let withdrawal_amount: u64 = withdrawal_account_data
+        .event
+        .data
+        .amount
+        .try_into()
+        .map_err(|_| SolanaBridgeError::Overflow)?;
+
+    // Fee booked with the withdrawal, so the net amount is the same for every tranche
+    let fee = withdrawal_fee(
+        program_id,
+        accounts,
+        &mut withdrawal_account_data.meta.data,
+        0,
+        &withdrawal_account_data.event.data.recipient,
+        &token_settings_account_data.fee_withdrawal_info,
+        withdrawal_amount,
+    )?;
+
+    // Amount without fee
+    let transfer_withdrawal_amount = withdrawal_amount
+        .checked_sub(fee)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    let amount_claimed = withdrawal_account_data
+        .meta
+        .data
+        .amount_claimed
+        .checked_add(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    if amount == 0 || amount_claimed > transfer_withdrawal_amount {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let vault_account_data = unpack_token_account(vault_account_info)?;
+
+    if amount > vault_account_data.amount {
+        return Err(SolanaBridgeError::InsufficientVaultBalance.into());
+    }
+
+    make_sol_transfer(
+        vault_account_info,
+        recipient_token_account_info,
+        &token_settings_account_data,
+        accounts,
+        amount,
+    )?;
+
+    withdrawal_account_data.meta.data.amount_claimed = amount_claimed;
+    withdrawal_account_data.meta.data.transition(
+        match amount_claimed == transfer_withdrawal_amount {
+            true => WithdrawalTokenStatus::Processed,
+            false => WithdrawalTokenStatus::PartiallyProcessed,
+        },
+        &clock,
+    )?;
+
+    if withdrawal_account_data.meta.data.status.is_final() {
+        release_round_withdrawal(program_id, accounts, round_number)?;
+    }
+
+    let payout = match withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::Processed
+    {
+        true => Some(HookInstruction::Withdrawal {
+            mint,
+            recipient: withdrawal_account_data.event.data.recipient,
+            amount: transfer_withdrawal_amount,
+            event: withdrawal_account_data.event.data.try_to_vec()?,
+        }),
+        false => None,
+    };
+
+    emit_event(
+        settings_account_info,
+        &UpdateWithdrawalStatusEvent {
+            account: withdrawal_pubkey,
+            status: withdrawal_account_data.meta.data.status,
+            quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+            processed_at: withdrawal_account_data.meta.data.processed_at,
+        },
+    )?;
+
+    WithdrawalMultiTokenSol::pack(
+        withdrawal_account_data,
+        &mut withdrawal_account_info.data.borrow_mut(),
+    )?;
+
+    // Notify Hook Program
+    if let Some(hook_instruction) = payout {
+        invoke_hook(
+            program_id,
+            accounts,
+            hook_program,
+            token_settings_account_info,
+            withdrawal_account_info,
+            hook_instruction,
+        )?;
+    }
+
+    Ok(())
+}
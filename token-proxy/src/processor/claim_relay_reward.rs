@@ -0,0 +1,185 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use round_loader_interface::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use super::{make_ever_transfer, make_sol_transfer, unpack_token_account};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ClaimRelayRewardAccounts<'a, 'info> {
+    #[account(signer)]
+    pub relay_account_info: &'a AccountInfo<'info>,
+    pub relay_token_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub round_reward_account_info: &'a AccountInfo<'info>,
+    pub relay_round_stats_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ClaimRelayRewardAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let ClaimRelayRewardAccounts {
+        relay_account_info,
+        relay_token_account_info,
+        round_reward_account_info,
+        relay_round_stats_account_info,
+        relay_round_account_info,
+        token_settings_account_info,
+        mint_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    // Validate Round Reward Account
+    let mut round_reward_account_data =
+        RoundReward::unpack(&round_reward_account_info.data.borrow())?;
+
+    let round_number = round_reward_account_data.round_number;
+
+    if get_associated_round_reward_address(
+        program_id,
+        token_settings_account_info.key,
+        round_number,
+    ) != *round_reward_account_info.key
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Relay Round Stats Account
+    let relay_round_stats_account_data =
+        RelayRoundStats::unpack(&relay_round_stats_account_info.data.borrow())?;
+
+    if get_associated_relay_round_stats_address(program_id, round_number)
+        != *relay_round_stats_account_info.key
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    round_loader_interface::validate_relay_round_account(
+        &round_loader_interface::id(),
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Validate Relay Account
+    let index = relay_round_account_data
+        .relays
+        .iter()
+        .position(|pubkey| pubkey == relay_account_info.key)
+        .ok_or(SolanaBridgeError::InvalidRelay)?;
+
+    if round_reward_account_data.claimed[index] {
+        return Err(SolanaBridgeError::RewardAlreadyClaimed.into());
+    }
+
+    let amount = round_reward_account_data.relay_reward(&relay_round_stats_account_data, index);
+
+    // Validate Token Settings Account
+    let token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (token_settings_nonce, vault_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    // Validate Relay Token Account
+    let relay_token_account_data = unpack_token_account(relay_token_account_info)?;
+
+    if relay_token_account_data.mint != *mint_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    match token_settings_account_data.kind {
+        TokenKind::Ever { mint, token, .. } => {
+            validate_token_settings_ever_account(
+                program_id,
+                &token,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?;
+
+            if *mint_account_info.key != mint {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            make_ever_transfer(
+                mint_account_info,
+                relay_token_account_info,
+                &token_settings_account_data,
+                accounts,
+                amount,
+            )?;
+        }
+        TokenKind::Solana { mint, .. } => {
+            validate_token_settings_sol_account(
+                program_id,
+                &mint,
+                token_settings_account_data.ever_configuration.as_ref(),
+                token_settings_nonce,
+                token_settings_account_info,
+            )?;
+
+            if *mint_account_info.key != mint {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            // Validate Vault Account
+            let vault_account_info = next_account_info(account_info_iter)?;
+
+            validate_vault_account(
+                program_id,
+                &mint,
+                token_settings_account_data.ever_configuration.as_ref(),
+                token_settings_account_data.vault_generation,
+                vault_nonce,
+                vault_account_info,
+            )?;
+
+            let vault_account_data = unpack_token_account(vault_account_info)?;
+
+            if amount > vault_account_data.amount {
+                return Err(SolanaBridgeError::InsufficientVaultBalance.into());
+            }
+
+            make_sol_transfer(
+                vault_account_info,
+                relay_token_account_info,
+                &token_settings_account_data,
+                accounts,
+                amount,
+            )?;
+        }
+    }
+
+    // Update Round Reward Account
+    round_reward_account_data.claimed[index] = true;
+
+    RoundReward::pack(
+        round_reward_account_data,
+        &mut round_reward_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+
+use super::delete_account;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CloseDepositAccounts<'a, 'info> {
+    #[account(writable, signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub deposit_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CloseDepositAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let CloseDepositAccounts {
+        author_account_info,
+        deposit_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Deposit Account
+    let deposit_account_data = Deposit::unpack_from_slice(&deposit_account_info.data.borrow())?;
+
+    let meta = DepositTokenMeta::unpack_from_slice(&deposit_account_data.meta)?;
+    let nonce = deposit_account_data
+        .account_kind
+        .into_deposit()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_deposit_account(
+        program_id,
+        &deposit_account_data.author,
+        meta.seed,
+        meta.ever_configuration.as_ref(),
+        nonce,
+        deposit_account_info,
+    )?;
+
+    if *author_account_info.key != deposit_account_data.author {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    delete_account(deposit_account_info, author_account_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::Proposal;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+
+use super::delete_account;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CloseWithdrawalAccounts<'a, 'info> {
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_author_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CloseWithdrawalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let CloseWithdrawalAccounts {
+        withdrawal_account_info,
+        withdrawal_author_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Withdrawal Account
+    let withdrawal_account_data =
+        Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    if *withdrawal_author_account_info.key != withdrawal_account_data.author {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let meta = WithdrawalTokenMeta::try_from_slice(&withdrawal_account_data.meta)?;
+    if meta.status != WithdrawalTokenStatus::Processed
+        && meta.status != WithdrawalTokenStatus::Cancelled
+    {
+        return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+    }
+
+    delete_account(withdrawal_account_info, withdrawal_author_account_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateDepositIndexAccounts<'a, 'info> {
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    pub owner_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub deposit_index_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CreateDepositIndexAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let CreateDepositIndexAccounts {
+        funder_account_info,
+        owner_account_info,
+        deposit_index_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Create Deposit Index Account
+    let (deposit_index_pubkey, deposit_index_nonce) = Pubkey::find_program_address(
+        &[br"deposit_index", &owner_account_info.key.to_bytes()],
+        program_id,
+    );
+    let deposit_index_account_signer_seeds: &[&[_]] = &[
+        br"deposit_index",
+        &owner_account_info.key.to_bytes(),
+        &[deposit_index_nonce],
+    ];
+
+    if deposit_index_pubkey != *deposit_index_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            deposit_index_account_info.key,
+            1.max(rent.minimum_balance(DepositIndex::LEN)),
+            DepositIndex::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[deposit_index_account_signer_seeds],
+    )?;
+
+    // Init Deposit Index Account
+    let deposit_index_account_data = DepositIndex {
+        is_initialized: true,
+        account_kind: AccountKind::DepositIndex(deposit_index_nonce),
+        owner: *owner_account_info.key,
+        total: 0,
+    };
+
+    DepositIndex::pack(
+        deposit_index_account_data,
+        &mut deposit_index_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use round_loader_interface::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateRelayRoundStatsAccounts<'a, 'info> {
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub relay_round_stats_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CreateRelayRoundStatsAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let CreateRelayRoundStatsAccounts {
+        funder_account_info,
+        relay_round_stats_account_info,
+        relay_round_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let round_number = relay_round_account_data.round_number;
+
+    round_loader_interface::validate_relay_round_account(
+        &round_loader_interface::id(),
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Create Relay Round Stats Account
+    let (relay_round_stats_pubkey, relay_round_stats_nonce) = Pubkey::find_program_address(
+        &[br"relay_round_stats", &round_number.to_le_bytes()],
+        program_id,
+    );
+    let relay_round_stats_account_signer_seeds: &[&[_]] = &[
+        br"relay_round_stats",
+        &round_number.to_le_bytes(),
+        &[relay_round_stats_nonce],
+    ];
+
+    if relay_round_stats_pubkey != *relay_round_stats_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            relay_round_stats_account_info.key,
+            1.max(rent.minimum_balance(RelayRoundStats::LEN)),
+            RelayRoundStats::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[relay_round_stats_account_signer_seeds],
+    )?;
+
+    // Init Relay Round Stats Account
+    let relay_round_stats_account_data = RelayRoundStats {
+        is_initialized: true,
+        account_kind: AccountKind::RelayRoundStats(relay_round_stats_nonce),
+        round_number,
+        total: 0,
+        votes: vec![0; relay_round_account_data.relays_len()],
+        withdrawals: 0,
+        volume: 0,
+        active_withdrawals: 0,
+    };
+
+    RelayRoundStats::pack(
+        relay_round_stats_account_data,
+        &mut relay_round_stats_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateSettingsHistoryPageAccounts<'a, 'info> {
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_history_page_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CreateSettingsHistoryPageAccounts<'a, 'info>>,
+    page: u64,
+) -> ProgramResult {
+    let CreateSettingsHistoryPageAccounts {
+        funder_account_info,
+        settings_history_page_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Create Settings History Page Account
+    let (settings_history_page_pubkey, settings_history_page_nonce) =
+        Pubkey::find_program_address(&[br"settings_history", &page.to_le_bytes()], program_id);
+    let settings_history_page_account_signer_seeds: &[&[_]] = &[
+        br"settings_history",
+        &page.to_le_bytes(),
+        &[settings_history_page_nonce],
+    ];
+
+    if settings_history_page_pubkey != *settings_history_page_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            settings_history_page_account_info.key,
+            1.max(rent.minimum_balance(SettingsHistoryPage::LEN)),
+            SettingsHistoryPage::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[settings_history_page_account_signer_seeds],
+    )?;
+
+    // Init Settings History Page Account
+    let settings_history_page_account_data = SettingsHistoryPage {
+        is_initialized: true,
+        account_kind: AccountKind::SettingsHistoryPage(settings_history_page_nonce),
+        page,
+        changes: Vec::new(),
+    };
+
+    SettingsHistoryPage::pack(
+        settings_history_page_account_data,
+        &mut settings_history_page_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
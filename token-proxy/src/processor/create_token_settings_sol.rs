@@ -0,0 +1,133 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use super::create_token_settings_sol_account;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateTokenSettingsSolAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CreateTokenSettingsSolAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    name: String,
+    symbol: String,
+    deposit_limit: u64,
+    withdrawal_limit: u64,
+    withdrawal_daily_limit: u64,
+    allow_freeze_authority: bool,
+    ever_configuration: Option<Pubkey>,
+) -> ProgramResult {
+    let CreateTokenSettingsSolAccounts {
+        funder_account_info,
+        authority_account_info,
+        mint_account_info,
+        vault_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Check asset name length
+    if name.len() > MAX_NAME_LEN {
+        return Err(SolanaBridgeError::TokenNameLenLimit.into());
+    }
+
+    // Check asset symbol length
+    if symbol.len() > MAX_SYMBOL_LEN {
+        return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+    }
+
+    if token_settings_account_info.lamports() != 0 {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    create_token_settings_sol_account(
+        program_id,
+        accounts,
+        funder_account_info,
+        mint_account_info,
+        vault_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        rent,
+        name,
+        symbol,
+        allow_freeze_authority,
+        ever_configuration,
+    )?;
+
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    token_settings_account_data.deposit_limit = deposit_limit;
+    token_settings_account_data.withdrawal_limit = withdrawal_limit;
+    token_settings_account_data.withdrawal_daily_limit = withdrawal_daily_limit;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
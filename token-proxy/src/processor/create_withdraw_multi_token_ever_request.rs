@@ -0,0 +1,158 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::{AccountKind, Proposal, PDA};
+use round_loader_interface::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::Hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use super::create_event_index_account;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateWithdrawMultiTokenEverRequestAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub event_index_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, CreateWithdrawMultiTokenEverRequestAccounts<'a, 'info>>,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    event_data: Hash,
+) -> ProgramResult {
+    let CreateWithdrawMultiTokenEverRequestAccounts {
+        funder_account_info,
+        author_account_info,
+        withdrawal_account_info,
+        event_index_account_info,
+        relay_round_account_info,
+        system_program_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    let clock = Clock::get()?;
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let round_number = relay_round_account_data.round_number;
+
+    round_loader_interface::validate_relay_round_account(
+        &round_loader_interface::id(),
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
+        return Err(SolanaBridgeError::RelayRoundExpired.into());
+    }
+
+    if !relay_round_account_data.is_active(clock.unix_timestamp as u32) {
+        return Err(SolanaBridgeError::RelayRoundNotActive.into());
+    }
+
+    // Create Withdraw Account
+    let (withdrawal_pubkey, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data.to_bytes(),
+        ],
+        program_id,
+    );
+    let withdrawal_account_signer_seeds: &[&[_]] = &[
+        br"proposal",
+        &round_number.to_le_bytes(),
+        &event_timestamp.to_le_bytes(),
+        &event_transaction_lt.to_le_bytes(),
+        &event_configuration.to_bytes(),
+        &event_data.to_bytes(),
+        &[withdrawal_nonce],
+    ];
+
+    if withdrawal_pubkey != *withdrawal_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if bridge_utils::helper::is_uncreated_account(withdrawal_account_info) {
+        let pda = PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        };
+
+        create_event_index_account(
+            program_id,
+            &withdrawal_pubkey,
+            &pda,
+            rent,
+            funder_account_info,
+            event_index_account_info,
+            system_program_info,
+        )?;
+
+        bridge_utils::helper::create_pda_account(
+            funder_account_info,
+            withdrawal_account_info,
+            rent,
+            WithdrawalMultiTokenEver::LEN,
+            program_id,
+            accounts,
+            withdrawal_account_signer_seeds,
+        )?;
+
+        // Event, meta and signers are filled in on finalize
+        let withdrawal_account_data = Proposal {
+            is_initialized: false,
+            account_kind: AccountKind::Proposal(withdrawal_nonce, None),
+            author: *author_account_info.key,
+            round_number,
+            required_votes: 0,
+            pda,
+            event: Default::default(),
+            meta: Default::default(),
+            signers: Default::default(),
+        };
+
+        withdrawal_account_data.pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+    } else {
+        if withdrawal_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+    }
+
+    Ok(())
+}
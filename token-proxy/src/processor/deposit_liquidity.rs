@@ -0,0 +1,226 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::token_cpi;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::emit_event;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct DepositLiquidityAccounts<'a, 'info> {
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub provider_account_info: &'a AccountInfo<'info>,
+    pub provider_token_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub lp_position_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, DepositLiquidityAccounts<'a, 'info>>,
+    amount: u64,
+) -> ProgramResult {
+    let DepositLiquidityAccounts {
+        funder_account_info,
+        provider_account_info,
+        provider_token_account_info,
+        lp_position_account_info,
+        token_settings_account_info,
+        vault_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Token Settings Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (token_settings_nonce, vault_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (mint, _) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Vault Account
+    validate_vault_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_account_data.vault_generation,
+        vault_nonce,
+        vault_account_info,
+    )?;
+
+    // Calculate shares for the current liquidity
+    let shares = match token_settings_account_data.lp_supply {
+        0 => amount,
+        lp_supply => (amount as u128)
+            .checked_mul(lp_supply as u128)
+            .and_then(|v| v.checked_div(token_settings_account_data.lp_liquidity as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(SolanaBridgeError::Overflow)?,
+    };
+
+    if shares == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Transfer SOL tokens to Vault Account
+    token_cpi::transfer(
+        provider_token_account_info,
+        vault_account_info,
+        provider_account_info,
+        amount,
+        accounts,
+        &[],
+    )?;
+
+    // Validate LP Position Account
+    let (lp_position_pubkey, lp_position_nonce) = Pubkey::find_program_address(
+        &[
+            br"lp_position",
+            &mint.to_bytes(),
+            &provider_account_info.key.to_bytes(),
+        ],
+        program_id,
+    );
+
+    if lp_position_pubkey != *lp_position_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mut lp_position_account_data = if lp_position_account_info.data_is_empty() {
+        // Create LP Position Account
+        let lp_position_account_signer_seeds: &[&[_]] = &[
+            br"lp_position",
+            &mint.to_bytes(),
+            &provider_account_info.key.to_bytes(),
+            &[lp_position_nonce],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                lp_position_account_info.key,
+                1.max(rent.minimum_balance(LpPosition::LEN)),
+                LpPosition::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[lp_position_account_signer_seeds],
+        )?;
+
+        LpPosition {
+            is_initialized: true,
+            account_kind: AccountKind::LpPosition(lp_position_nonce),
+            token_settings: *token_settings_account_info.key,
+            owner: *provider_account_info.key,
+            shares: 0,
+        }
+    } else {
+        LpPosition::unpack(&lp_position_account_info.data.borrow())?
+    };
+
+    // Position belongs to a single Token Settings of the mint
+    if lp_position_account_data.token_settings != *token_settings_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Update LP accounting
+    lp_position_account_data.shares = lp_position_account_data
+        .shares
+        .checked_add(shares)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    token_settings_account_data.lp_supply = token_settings_account_data
+        .lp_supply
+        .checked_add(shares)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    token_settings_account_data.lp_liquidity = token_settings_account_data
+        .lp_liquidity
+        .checked_add(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    emit_event(
+        settings_account_info,
+        &DepositLiquidityEvent {
+            lp_position: lp_position_pubkey,
+            owner: *provider_account_info.key,
+            amount,
+            shares,
+        },
+    )?;
+
+    LpPosition::pack(
+        lp_position_account_data,
+        &mut lp_position_account_info.data.borrow_mut(),
+    )?;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
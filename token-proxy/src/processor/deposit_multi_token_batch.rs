@@ -0,0 +1,359 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::token_cpi;
+use bridge_utils::types::{EverAddress, UInt256};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::{invoke, set_return_data};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    create_deposit_account, emit_event, invoke_hook, record_deposit, unpack_mint_account,
+    unpack_token_account, use_limit_exemption, validate_ever_address, validate_payload_schema,
+    validate_token_whitelisted,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct DepositMultiTokenBatchAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub creator_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub deposit_account_info: &'a AccountInfo<'info>,
+    pub multi_vault_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, DepositMultiTokenBatchAccounts<'a, 'info>>,
+    deposit_seed: u128,
+    entries: Vec<DepositBatchAmount>,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let DepositMultiTokenBatchAccounts {
+        funder_account_info,
+        creator_account_info,
+        deposit_account_info,
+        multi_vault_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    if entries.is_empty() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if entries.len() > MAX_DEPOSIT_BATCH_ENTRIES {
+        return Err(SolanaBridgeError::DepositBatchLimit.into());
+    }
+
+    // Every token can be deposited once per batch
+    for (i, entry) in entries.iter().enumerate() {
+        if entries[..i].iter().any(|e| e.mint == entry.mint) {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    // Validate EVER Recipient Address
+    validate_ever_address(&recipient)?;
+
+    // Validate Payload against the declared schema
+    validate_payload_schema(program_id, accounts, payload_schema, &payload)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Multi Vault Account
+    let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+    let multi_vault_nonce = multi_vault_account_data
+        .account_kind
+        .into_multi_vault()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+    // Token accounts of every entry are passed as remaining accounts in order of entries
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let mut event_entries = Vec::with_capacity(entries.len());
+    let mut fees = Vec::with_capacity(entries.len());
+    let mut transfer_amounts = Vec::with_capacity(entries.len());
+    let mut hooks = Vec::with_capacity(entries.len());
+
+    // The batch is read by a single EVER configuration, so its tokens share the namespace
+    let mut batch_ever_configuration = None;
+
+    for entry in &entries {
+        let creator_token_account_info = next_account_info(account_info_iter)?;
+        let vault_account_info = next_account_info(account_info_iter)?;
+        let mint_account_info = next_account_info(account_info_iter)?;
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+
+        if settings_account_data.deposits_restricted_to_whitelist {
+            validate_token_whitelisted(program_id, accounts, &entry.mint)?;
+        }
+
+        // Token settings are created by the first single deposit of the token
+        if token_settings_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        // Validate Token Settings Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        let (mint, vault) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+        let (token_settings_nonce, _vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_account_data.ever_configuration.as_ref(),
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        match batch_ever_configuration {
+            Some(ever_configuration)
+                if ever_configuration != token_settings_account_data.ever_configuration =>
+            {
+                return Err(SolanaBridgeError::EverConfigurationMismatch.into());
+            }
+            _ => batch_ever_configuration = Some(token_settings_account_data.ever_configuration),
+        }
+
+        if token_settings_account_data.emergency {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if token_settings_account_data.is_paused(TokenOperation::Deposit) {
+            return Err(SolanaBridgeError::OperationPaused.into());
+        }
+
+        // Validate Mint Account
+        if *mint_account_info.key != mint || entry.mint != mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mint_account_data = unpack_mint_account(mint_account_info)?;
+
+        // Validate Vault Account
+        if *vault_account_info.key != vault {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let vault_account_data = unpack_token_account(vault_account_info)?;
+
+        if token_settings_account_data
+            .locked_amount(vault_account_data.amount)
+            .checked_add(entry.amount)
+            .ok_or(SolanaBridgeError::Overflow)?
+            > token_settings_account_data.deposit_limit
+            && !use_limit_exemption(
+                program_id,
+                accounts,
+                creator_account_info.key,
+                instruction_data,
+            )?
+        {
+            return Err(SolanaBridgeError::DepositLimit.into());
+        }
+
+        // Transfer SOL tokens to Vault Account
+        token_cpi::transfer(
+            creator_token_account_info,
+            vault_account_info,
+            creator_account_info,
+            entry.amount,
+            accounts,
+            &[],
+        )?;
+
+        let fee_info = &token_settings_account_data.fee_deposit_info;
+
+        let fee = 1.max(
+            entry
+                .amount
+                .checked_div(fee_info.divisor)
+                .ok_or(SolanaBridgeError::Overflow)?
+                .checked_mul(fee_info.multiplier)
+                .ok_or(SolanaBridgeError::Overflow)?,
+        );
+
+        // Increase fee supply
+        token_settings_account_data.fee_supply = token_settings_account_data
+            .fee_supply
+            .checked_add(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Amount without fee
+        let transfer_amount: u128 = entry
+            .amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?
+            .into();
+
+        event_entries.push(DepositMultiTokenBatchEntry {
+            base_token: mint,
+            name: token_settings_account_data.name.clone(),
+            symbol: token_settings_account_data.symbol.clone(),
+            decimals: mint_account_data.decimals,
+            amount: transfer_amount,
+        });
+        fees.push(fee);
+        transfer_amounts.push(transfer_amount);
+        hooks.push((
+            token_settings_account_data.hook_program,
+            token_settings_account_info,
+        ));
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+    }
+
+    // Send sol amount to multi vault
+    invoke(
+        &system_instruction::transfer(funder_account_info.key, multi_vault_account_info.key, value),
+        accounts,
+    )?;
+
+    // Create Deposit Account
+    let ever_configuration = batch_ever_configuration.flatten();
+
+    let deposit_pubkey = *deposit_account_info.key;
+    let deposit_nonce = create_deposit_account(
+        program_id,
+        creator_account_info.key,
+        deposit_seed,
+        ever_configuration.as_ref(),
+        DepositMultiTokenBatch::LEN,
+        rent,
+        funder_account_info,
+        deposit_account_info,
+        accounts,
+    )?;
+
+    // Init Deposit Account
+    let deposit_account_data = DepositMultiTokenBatch {
+        is_initialized: true,
+        account_kind: AccountKind::Deposit(deposit_nonce),
+        author: *creator_account_info.key,
+        event: DepositMultiTokenBatchEventWithLen::new(
+            settings_account_data.chain_id,
+            event_entries,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        ),
+        meta: DepositTokenMetaWithLen::new(deposit_seed)
+            .with_ever_configuration(ever_configuration),
+    };
+
+    let event = deposit_account_data.event.data.try_to_vec()?;
+    let event_data = hash(&event).to_bytes().to_vec();
+
+    emit_event(
+        settings_account_info,
+        &DepositBatchEvent {
+            account: deposit_pubkey,
+            recipient,
+            transfer_amounts: transfer_amounts.clone(),
+            seed: deposit_seed,
+            value,
+            expected_evers,
+            event_data,
+        },
+    )?;
+
+    DepositMultiTokenBatch::pack(
+        deposit_account_data,
+        &mut deposit_account_info.data.borrow_mut(),
+    )?;
+
+    // Record Deposit into the Deposit Index
+    record_deposit(
+        program_id,
+        accounts,
+        funder_account_info,
+        creator_account_info.key,
+        &deposit_pubkey,
+        rent,
+    )?;
+
+    // Notify Hook Programs of every deposited token
+    for (entry, (hook_program, token_settings_account_info)) in entries.iter().zip(hooks) {
+        invoke_hook(
+            program_id,
+            accounts,
+            hook_program,
+            token_settings_account_info,
+            deposit_account_info,
+            HookInstruction::Deposit {
+                mint: entry.mint,
+                author: *creator_account_info.key,
+                amount: entry.amount,
+                event: event.clone(),
+            },
+        )?;
+    }
+
+    set_return_data(
+        &DepositBatchResult {
+            deposit: deposit_pubkey,
+            fees,
+            transfer_amounts,
+        }
+        .try_to_vec()?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use bridge_utils::context::Context;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::types::{EverAddress, UInt256};
+
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_pack::Pack;
+
+use super::deposit_multi_token_ever::{self, DepositMultiTokenEverAccounts};
+use super::{get_gross_deposit_amount, get_pure_deposit_amount, unpack_mint_account};
+use crate::*;
+
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, DepositMultiTokenEverAccounts<'a, 'info>>,
+    deposit_seed: u128,
+    transfer_amount: u128,
+    max_amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+) -> ProgramResult {
+    let DepositMultiTokenEverAccounts {
+        mint_account_info,
+        token_settings_account_info,
+        ..
+    } = ctx.accounts;
+
+    // Token settings and mint accounts are validated by the deposit
+    let token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (_, _, ever_decimals) = token_settings_account_data
+        .kind
+        .into_ever()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let solana_decimals = unpack_mint_account(mint_account_info)?.decimals;
+
+    // Amount in Solana decimals
+    let pure_amount = get_pure_deposit_amount(transfer_amount, ever_decimals, solana_decimals)?;
+
+    let amount =
+        get_gross_deposit_amount(pure_amount, &token_settings_account_data.fee_deposit_info)?;
+
+    if amount > max_amount {
+        return Err(SolanaBridgeError::MaxAmountExceeded.into());
+    }
+
+    deposit_multi_token_ever::process(
+        ctx,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+    )
+}
@@ -0,0 +1,389 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::token_cpi;
+use bridge_utils::types::{EverAddress, UInt256};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::{invoke, set_return_data};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    create_deposit_account, create_token_settings_sol_account, emit_event, invoke_hook,
+    record_deposit, unpack_mint_account, unpack_token_account, use_limit_exemption,
+    validate_ever_address, validate_payload_schema, validate_token_whitelisted,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct DepositMultiTokenSolAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub creator_account_info: &'a AccountInfo<'info>,
+    pub creator_token_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub deposit_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub multi_vault_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, DepositMultiTokenSolAccounts<'a, 'info>>,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+    ever_configuration: Option<Pubkey>,
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let creator_account_info = ctx.accounts.creator_account_info;
+
+    deposit(
+        ctx,
+        deposit_seed,
+        name,
+        symbol,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+        ever_configuration,
+        instruction_data,
+        creator_account_info,
+        &[],
+    )
+}
+
+/// Deposit of the creator's tokens, transferred by `transfer_authority_account_info` which is
+/// either the creator or a program delegate signing with `transfer_signer_seeds`
+#[allow(clippy::too_many_arguments)]
+pub(super) fn deposit<'a, 'info>(
+    ctx: Context<'a, 'info, DepositMultiTokenSolAccounts<'a, 'info>>,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+    ever_configuration: Option<Pubkey>,
+    instruction_data: &[u8],
+    transfer_authority_account_info: &AccountInfo<'info>,
+    transfer_signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let DepositMultiTokenSolAccounts {
+        funder_account_info,
+        creator_account_info,
+        creator_token_account_info,
+        vault_account_info,
+        deposit_account_info,
+        mint_account_info,
+        multi_vault_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate EVER Recipient Address
+    validate_ever_address(&recipient)?;
+
+    // Validate Payload against the declared schema
+    validate_payload_schema(program_id, accounts, payload_schema, &payload)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if settings_account_data.deposits_restricted_to_whitelist {
+        validate_token_whitelisted(program_id, accounts, mint_account_info.key)?;
+    }
+
+    // Check asset name length
+    if name.len() > MAX_NAME_LEN {
+        return Err(SolanaBridgeError::TokenNameLenLimit.into());
+    }
+
+    // Check asset symbol length
+    if symbol.len() > MAX_SYMBOL_LEN {
+        return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+    }
+
+    // If token settings account is not created
+    if bridge_utils::helper::is_uncreated_account(token_settings_account_info) {
+        create_token_settings_sol_account(
+            program_id,
+            accounts,
+            funder_account_info,
+            mint_account_info,
+            vault_account_info,
+            token_settings_account_info,
+            settings_account_info,
+            rent,
+            name.clone(),
+            symbol.clone(),
+            false,
+            ever_configuration,
+        )?;
+    }
+
+    // Validate Token Settings Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (mint, vault) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (token_settings_nonce, _vault_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.ever_configuration != ever_configuration {
+        return Err(SolanaBridgeError::EverConfigurationMismatch.into());
+    }
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if token_settings_account_data.is_paused(TokenOperation::Deposit) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Validate Mint Account
+    if *mint_account_info.key != mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mint_account_data = unpack_mint_account(mint_account_info)?;
+    let decimals = mint_account_data.decimals;
+
+    // Validate Vault Account
+    if *vault_account_info.key != vault {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Multi Vault Account
+    let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+    let multi_vault_nonce = multi_vault_account_data
+        .account_kind
+        .into_multi_vault()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+    // Make transfer
+    let vault_account_data = unpack_token_account(vault_account_info)?;
+
+    if token_settings_account_data
+        .locked_amount(vault_account_data.amount)
+        .checked_add(amount)
+        .ok_or(SolanaBridgeError::Overflow)?
+        > token_settings_account_data.deposit_limit
+        && !use_limit_exemption(
+            program_id,
+            accounts,
+            creator_account_info.key,
+            instruction_data,
+        )?
+    {
+        return Err(SolanaBridgeError::DepositLimit.into());
+    }
+
+    // Transfer SOL tokens to Vault Account
+    token_cpi::transfer(
+        creator_token_account_info,
+        vault_account_info,
+        transfer_authority_account_info,
+        amount,
+        accounts,
+        transfer_signer_seeds,
+    )?;
+
+    // Send sol amount to multi vault
+    invoke(
+        &system_instruction::transfer(funder_account_info.key, multi_vault_account_info.key, value),
+        accounts,
+    )?;
+
+    // Create Deposit Account
+    let deposit_pubkey = *deposit_account_info.key;
+    let deposit_nonce = create_deposit_account(
+        program_id,
+        creator_account_info.key,
+        deposit_seed,
+        token_settings_account_data.ever_configuration.as_ref(),
+        DepositMultiTokenSol::LEN,
+        rent,
+        funder_account_info,
+        deposit_account_info,
+        accounts,
+    )?;
+
+    // Init Deposit Account
+    let fee_info = &token_settings_account_data.fee_deposit_info;
+
+    let fee = 1.max(
+        amount
+            .checked_div(fee_info.divisor)
+            .ok_or(SolanaBridgeError::Overflow)?
+            .checked_mul(fee_info.multiplier)
+            .ok_or(SolanaBridgeError::Overflow)?,
+    );
+
+    // Increase fee supply
+    token_settings_account_data.fee_supply = token_settings_account_data
+        .fee_supply
+        .checked_add(fee)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    // Amount without fee
+    let transfer_amount: u128 = amount
+        .checked_sub(fee)
+        .ok_or(SolanaBridgeError::Overflow)?
+        .into();
+
+    let name = token_settings_account_data.name.clone();
+    let symbol = token_settings_account_data.symbol.clone();
+
+    let deposit_account_data = DepositMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Deposit(deposit_nonce),
+        author: *creator_account_info.key,
+        event: DepositMultiTokenSolEventWithLen::new(
+            settings_account_data.chain_id,
+            *mint_account_info.key,
+            name,
+            symbol,
+            decimals,
+            transfer_amount,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        ),
+        meta: DepositTokenMetaWithLen::new(deposit_seed)
+            .with_ever_configuration(token_settings_account_data.ever_configuration),
+    };
+
+    let event = deposit_account_data.event.data.try_to_vec()?;
+    let event_data = hash(&event).to_bytes().to_vec();
+
+    emit_event(
+        settings_account_info,
+        &DepositMultiTokenEvent {
+            account: deposit_pubkey,
+            recipient,
+            transfer_amount,
+            seed: deposit_seed,
+            value,
+            expected_evers,
+            event_data,
+            amount: amount.into(),
+        },
+    )?;
+
+    DepositMultiTokenSol::pack(
+        deposit_account_data,
+        &mut deposit_account_info.data.borrow_mut(),
+    )?;
+
+    let hook_program = token_settings_account_data.hook_program;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    // Record Deposit into the Deposit Index
+    record_deposit(
+        program_id,
+        accounts,
+        funder_account_info,
+        creator_account_info.key,
+        &deposit_pubkey,
+        rent,
+    )?;
+
+    // Notify Hook Program
+    invoke_hook(
+        program_id,
+        accounts,
+        hook_program,
+        token_settings_account_info,
+        deposit_account_info,
+        HookInstruction::Deposit {
+            mint: *mint_account_info.key,
+            author: *creator_account_info.key,
+            amount,
+            event,
+        },
+    )?;
+
+    set_return_data(
+        &DepositResult {
+            deposit: deposit_pubkey,
+            fee,
+            transfer_amount,
+        }
+        .try_to_vec()?,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use bridge_utils::context::Context;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::types::{EverAddress, UInt256};
+
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use super::deposit_multi_token_sol::{self, DepositMultiTokenSolAccounts};
+use super::get_gross_deposit_amount;
+use crate::*;
+
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, DepositMultiTokenSolAccounts<'a, 'info>>,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    transfer_amount: u128,
+    max_amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+    ever_configuration: Option<Pubkey>,
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let creator_account_info = ctx.accounts.creator_account_info;
+    let token_settings_account_info = ctx.accounts.token_settings_account_info;
+
+    // Validated by the deposit, the first deposit creates token settings with the default fee
+    let fee_info = if token_settings_account_info.lamports() == 0 {
+        FeeInfo::default()
+    } else {
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?.fee_deposit_info
+    };
+
+    // SOL tokens are transferred in Solana decimals
+    let pure_amount: u64 = transfer_amount
+        .try_into()
+        .map_err(|_| SolanaBridgeError::Overflow)?;
+
+    let amount = get_gross_deposit_amount(pure_amount, &fee_info)?;
+
+    if amount > max_amount {
+        return Err(SolanaBridgeError::MaxAmountExceeded.into());
+    }
+
+    deposit_multi_token_sol::deposit(
+        ctx,
+        deposit_seed,
+        name,
+        symbol,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+        ever_configuration,
+        instruction_data,
+        creator_account_info,
+        &[],
+    )
+}
@@ -0,0 +1,212 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::ed25519;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::{EverAddress, UInt256};
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::deposit_multi_token_sol::{self, DepositMultiTokenSolAccounts};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct DepositMultiTokenSolGaslessAccounts<'a, 'info> {
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    pub owner_account_info: &'a AccountInfo<'info>,
+    pub owner_token_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    pub deposit_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub multi_vault_account_info: &'a AccountInfo<'info>,
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub delegate_account_info: &'a AccountInfo<'info>,
+    pub instructions_sysvar_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub nonce_account_info: &'a AccountInfo<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, DepositMultiTokenSolGaslessAccounts<'a, 'info>>,
+    deposit_seed: u128,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient: EverAddress,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+    payload_schema: Option<u32>,
+    nonce: u64,
+    expiry: i64,
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let DepositMultiTokenSolGaslessAccounts {
+        funder_account_info,
+        owner_account_info,
+        owner_token_account_info,
+        vault_account_info,
+        deposit_account_info,
+        mint_account_info,
+        multi_vault_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        system_program_info,
+        token_program_info,
+        rent_sysvar_info,
+        delegate_account_info,
+        instructions_sysvar_info,
+        nonce_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Check message expiry
+    if Clock::get()?.unix_timestamp > expiry {
+        return Err(SolanaBridgeError::MessageExpired.into());
+    }
+
+    // Validate Owner Signature
+    let message = GaslessDepositMessage {
+        owner: *owner_account_info.key,
+        owner_token_account: *owner_token_account_info.key,
+        mint: *mint_account_info.key,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload_hash: hash(&payload).to_bytes(),
+        nonce,
+        expiry,
+    };
+
+    ed25519::validate_ed25519_signature(
+        instructions_sysvar_info,
+        owner_account_info.key,
+        &message.signing_payload(program_id),
+    )?;
+
+    // Validate Nonce Account
+    let (nonce_pubkey, nonce_account_nonce) =
+        Pubkey::find_program_address(&[br"nonce", &owner_account_info.key.to_bytes()], program_id);
+
+    if nonce_pubkey != *nonce_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create Nonce Account on the first sponsored operation
+    if nonce_account_info.owner != program_id {
+        let nonce_account_signer_seeds: &[&[_]] = &[
+            br"nonce",
+            &owner_account_info.key.to_bytes(),
+            &[nonce_account_nonce],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                nonce_account_info.key,
+                1.max(rent.minimum_balance(Nonce::LEN)),
+                Nonce::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[nonce_account_signer_seeds],
+        )?;
+
+        Nonce::pack(
+            Nonce {
+                is_initialized: true,
+                account_kind: AccountKind::Nonce(nonce_account_nonce),
+                owner: *owner_account_info.key,
+                base: 0,
+                bitmap: [0; 32],
+            },
+            &mut nonce_account_info.data.borrow_mut(),
+        )?;
+    }
+
+    // Protect the signed message from replay
+    let mut nonce_account_data = Nonce::unpack(&nonce_account_info.data.borrow())?;
+    nonce_account_data.use_nonce(nonce)?;
+    Nonce::pack(
+        nonce_account_data,
+        &mut nonce_account_info.data.borrow_mut(),
+    )?;
+
+    // Validate Owner Token Account
+    let owner_token_account_data = super::unpack_token_account(owner_token_account_info)?;
+
+    if owner_token_account_data.owner != *owner_account_info.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Validate Delegate Account
+    let (delegate_pubkey, delegate_nonce) =
+        Pubkey::find_program_address(&[br"deposit_delegate"], program_id);
+    let delegate_account_signer_seeds: &[&[_]] = &[br"deposit_delegate", &[delegate_nonce]];
+
+    if delegate_pubkey != *delegate_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let ctx = Context {
+        program_id,
+        accounts: DepositMultiTokenSolAccounts {
+            funder_account_info,
+            creator_account_info: owner_account_info,
+            creator_token_account_info: owner_token_account_info,
+            vault_account_info,
+            deposit_account_info,
+            mint_account_info,
+            multi_vault_account_info,
+            token_settings_account_info,
+            settings_account_info,
+            system_program_info,
+            token_program_info,
+            rent_sysvar_info,
+        },
+        remaining_accounts: ctx.remaining_accounts,
+        account_infos: ctx.account_infos,
+    };
+
+    // Transfer the owner's tokens by the Delegate
+    deposit_multi_token_sol::deposit(
+        ctx,
+        deposit_seed,
+        name,
+        symbol,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+        // Gasless deposits are made in the default namespace
+        None,
+        instruction_data,
+        delegate_account_info,
+        &[delegate_account_signer_seeds],
+    )
+}
@@ -0,0 +1,200 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use round_loader_interface::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct DistributeRoundRewardsAccounts<'a, 'info> {
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub relay_round_stats_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub round_reward_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    #[account(seeds = [br"reward_config"])]
+    pub reward_config_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub clock_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, DistributeRoundRewardsAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let DistributeRoundRewardsAccounts {
+        funder_account_info,
+        token_settings_account_info,
+        relay_round_stats_account_info,
+        round_reward_account_info,
+        relay_round_account_info,
+        reward_config_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+    let clock = Clock::get()?;
+
+    // Validate Reward Config Account
+    let reward_config_account_data =
+        RewardConfig::unpack(&reward_config_account_info.data.borrow())?;
+
+    // Validate Relay Round Stats Account
+    let relay_round_stats_account_data =
+        RelayRoundStats::unpack(&relay_round_stats_account_info.data.borrow())?;
+
+    let round_number = relay_round_stats_account_data.round_number;
+
+    if get_associated_relay_round_stats_address(program_id, round_number)
+        != *relay_round_stats_account_info.key
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    round_loader_interface::validate_relay_round_account(
+        &round_loader_interface::id(),
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    if relay_round_account_data.round_end >= clock.unix_timestamp as u32 {
+        return Err(SolanaBridgeError::RelayRoundNotCompleted.into());
+    }
+
+    // Validate Token Settings Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (token_settings_nonce, _) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let token_settings_pubkey = match token_settings_account_data.kind {
+        TokenKind::Ever { token, .. } => validate_token_settings_ever_account(
+            program_id,
+            &token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?,
+        TokenKind::Solana { mint, .. } => validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_account_data.ever_configuration.as_ref(),
+            token_settings_nonce,
+            token_settings_account_info,
+        )?,
+    };
+
+    // Fees are distributed once per round and in the round order, so the fees collected
+    // during a round can't be handed to a later one
+    if let Some(last_rewarded_round) = token_settings_account_data.last_rewarded_round {
+        if last_rewarded_round.checked_add(1) != Some(round_number) {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    // Nobody to reward, the fees stay for the next round
+    if relay_round_stats_account_data.total == 0 {
+        token_settings_account_data.last_rewarded_round = Some(round_number);
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        return Ok(());
+    }
+
+    let amount = (token_settings_account_data.fee_supply as u128
+        * reward_config_account_data.relay_share as u128
+        / 100) as u64;
+
+    // Create Round Reward Account
+    let (round_reward_pubkey, round_reward_nonce) = Pubkey::find_program_address(
+        &[
+            br"round_reward",
+            &token_settings_pubkey.to_bytes(),
+            &round_number.to_le_bytes(),
+        ],
+        program_id,
+    );
+    let round_reward_account_signer_seeds: &[&[_]] = &[
+        br"round_reward",
+        &token_settings_pubkey.to_bytes(),
+        &round_number.to_le_bytes(),
+        &[round_reward_nonce],
+    ];
+
+    if round_reward_pubkey != *round_reward_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            round_reward_account_info.key,
+            1.max(rent.minimum_balance(RoundReward::LEN)),
+            RoundReward::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[round_reward_account_signer_seeds],
+    )?;
+
+    // Init Round Reward Account
+    let round_reward_account_data = RoundReward {
+        is_initialized: true,
+        account_kind: AccountKind::RoundReward(round_reward_nonce),
+        token_settings: token_settings_pubkey,
+        round_number,
+        amount,
+        claimed: vec![false; relay_round_stats_account_data.votes.len()],
+    };
+
+    RoundReward::pack(
+        round_reward_account_data,
+        &mut round_reward_account_info.data.borrow_mut(),
+    )?;
+
+    // Move distributed fees out of the fee supply
+    token_settings_account_data.fee_supply = token_settings_account_data
+        .fee_supply
+        .checked_sub(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+    token_settings_account_data.last_rewarded_round = Some(round_number);
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::release_round_withdrawal;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExecutePayloadSolAccounts<'a, 'info> {
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ExecutePayloadSolAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let ExecutePayloadSolAccounts {
+        withdrawal_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+
+    if withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::WaitingForExecute {
+        let mint = withdrawal_account_data.event.data.mint;
+        let recipient = withdrawal_account_data.event.data.recipient;
+        let (_, nonce) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let proxy_signer_seeds: &[&[_]] = &[
+            br"proxy",
+            &mint.to_bytes(),
+            &recipient.to_bytes(),
+            &[nonce.unwrap_or_default()],
+        ];
+
+        let ixs: Vec<solana_program::instruction::Instruction> =
+            bincode::deserialize(&withdrawal_account_data.event.data.payload)
+                .map_err(|_| SolanaBridgeError::DeserializePayload)?;
+
+        for ix in ixs {
+            invoke_signed(&ix, accounts, &[proxy_signer_seeds])?;
+        }
+
+        withdrawal_account_data
+            .meta
+            .data
+            .transition(WithdrawalTokenStatus::Processed, &clock)?;
+
+        release_round_withdrawal(program_id, accounts, withdrawal_account_data.round_number)?;
+
+        WithdrawalMultiTokenSol::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,194 @@
+use borsh::BorshDeserialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExpandSettingsAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+/// Settings layout written before the discriminator and roles were introduced
+#[derive(BorshDeserialize)]
+struct LegacySettings {
+    is_initialized: bool,
+    account_kind: AccountKind,
+    emergency: bool,
+    guardian: Pubkey,
+    manager: Pubkey,
+    withdrawal_manager: Pubkey,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ExpandSettingsAccounts<'a, 'info>>,
+    chain_id: u32,
+) -> ProgramResult {
+    let ExpandSettingsAccounts {
+        authority_account_info,
+        funder_account_info,
+        settings_account_info,
+        programdata_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Settings Account
+    if settings_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let expand = settings_account_info.data_len() < Settings::LEN;
+
+    if expand {
+        expand_settings_account(
+            funder_account_info,
+            settings_account_info,
+            rent,
+            accounts,
+            chain_id,
+        )?;
+    }
+
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    // Nothing to expand nor to seed
+    if !expand && settings_account_data.guardian_last_active != 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Guardians of settings written before the activity field start their inactivity window now
+    if settings_account_data.guardian_last_active == 0 {
+        settings_account_data.guardian_last_active = Clock::get()?.unix_timestamp;
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn expand_settings_account<'info>(
+    funder_account_info: &AccountInfo<'info>,
+    settings_account_info: &AccountInfo<'info>,
+    rent: &Rent,
+    accounts: &[AccountInfo<'info>],
+    chain_id: u32,
+) -> ProgramResult {
+    // Legacy layout lacks the discriminator and has to be rewritten, a newer one only grows
+    let legacy_settings_account_data = match settings_account_info
+        .data
+        .borrow()
+        .starts_with(&Settings::DISCRIMINATOR)
+    {
+        true => None,
+        false => Some(LegacySettings::deserialize(
+            &mut &settings_account_info.data.borrow()[..],
+        )?),
+    };
+
+    // Fund rent of the extra space
+    let required_lamports = rent
+        .minimum_balance(Settings::LEN)
+        .saturating_sub(settings_account_info.lamports());
+
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(
+                funder_account_info.key,
+                settings_account_info.key,
+                required_lamports,
+            ),
+            accounts,
+        )?;
+    }
+
+    // New space is zeroed, so fields appended to the layout unpack to their zero values
+    settings_account_info.realloc(Settings::LEN, true)?;
+
+    if let Some(legacy_settings_account_data) = legacy_settings_account_data {
+        let LegacySettings {
+            is_initialized,
+            account_kind,
+            emergency,
+            guardian,
+            manager,
+            withdrawal_manager,
+        } = legacy_settings_account_data;
+
+        let settings_account_data = Settings {
+            is_initialized,
+            account_kind,
+            emergency,
+            guardian,
+            manager,
+            withdrawal_manager,
+            chain_id,
+            event_seq: 0,
+            veto_disabled: false,
+            deposits_restricted_to_whitelist: false,
+            roles: Settings::initial_roles(guardian, manager, withdrawal_manager),
+            permissions: vec![],
+            emergency_cosigners: vec![],
+            emergency_cosigners_threshold: 0,
+            guardian_last_active: 0,
+        };
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,384 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::token_cpi;
+use bridge_utils::types::{EverAddress, UInt256};
+
+use solana_program::account_info::{next_account_info, next_account_infos, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    create_deposit_account, emit_event, release_round_withdrawal, unpack_mint_account,
+    unpack_token_account, validate_ever_address,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct FillWithdrawSolAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    pub author_token_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub deposit_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub multi_vault_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, FillWithdrawSolAccounts<'a, 'info>>,
+    deposit_seed: u128,
+    recipient: EverAddress,
+    amount: u64,
+    value: u64,
+    expected_evers: UInt256,
+    payload: Vec<u8>,
+) -> ProgramResult {
+    let FillWithdrawSolAccounts {
+        funder_account_info,
+        author_account_info,
+        author_token_account_info,
+        mint_account_info,
+        deposit_account_info,
+        settings_account_info,
+        token_settings_account_info,
+        rent_sysvar_info,
+        multi_vault_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let clock = Clock::get()?;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate EVER Recipient Address
+    validate_ever_address(&recipient)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Token Setting Account
+    let token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (token_settings_nonce, vault_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (mint, _) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if token_settings_account_data.is_paused(TokenOperation::Deposit) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Validate Multi Vault Account
+    let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+    let multi_vault_nonce = multi_vault_account_data
+        .account_kind
+        .into_multi_vault()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+    // Send sol amount to multi vault
+    invoke(
+        &system_instruction::transfer(funder_account_info.key, multi_vault_account_info.key, value),
+        accounts,
+    )?;
+
+    // Validate Mint Account
+    if *mint_account_info.key != mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mint_account_data = unpack_mint_account(mint_account_info)?;
+    let decimals = mint_account_data.decimals;
+
+    // Create Deposit Account
+    let deposit_pubkey = *deposit_account_info.key;
+    let deposit_nonce = create_deposit_account(
+        program_id,
+        author_account_info.key,
+        deposit_seed,
+        token_settings_account_data.ever_configuration.as_ref(),
+        DepositMultiTokenSol::LEN,
+        rent,
+        funder_account_info,
+        deposit_account_info,
+        accounts,
+    )?;
+
+    let mut fee_amount_sum = 0;
+    let mut withdrawals_amount_sum = 0;
+
+    let fee_info = &token_settings_account_data.fee_withdrawal_info;
+
+    // collect Withdrawal Accounts
+    while let Ok(withdrawal_account_infos) = next_account_infos(account_info_iter, 2) {
+        let withdrawal_account_info = &withdrawal_account_infos[0];
+        let recipient_token_account_info = &withdrawal_account_infos[1];
+
+        let mut withdrawal_account_data =
+            WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+        let round_number = withdrawal_account_data.round_number;
+        let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+        let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+        let event_configuration = withdrawal_account_data.pda.event_configuration;
+        let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+        let (nonce, _) = withdrawal_account_data
+            .account_kind
+            .into_proposal()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+            program_id,
+            round_number,
+            event_timestamp,
+            event_transaction_lt,
+            &event_configuration,
+            &event_data,
+            nonce,
+            withdrawal_account_info,
+        )?;
+
+        if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::Pending {
+            return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+        }
+
+        // Check connection between token and proposal
+        if mint != withdrawal_account_data.event.data.mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Recipient account
+        let recipient_token_account_data = unpack_token_account(recipient_token_account_info)?;
+
+        if recipient_token_account_data.owner != withdrawal_account_data.event.data.recipient {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if recipient_token_account_info.owner != &spl_token::id() {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let withdrawal_amount: u64 = withdrawal_account_data
+            .event
+            .data
+            .amount
+            .try_into()
+            .map_err(|_| SolanaBridgeError::Overflow)?;
+
+        let fee = 1.max(
+            withdrawal_amount
+                .checked_div(fee_info.divisor)
+                .ok_or(SolanaBridgeError::Overflow)?
+                .checked_mul(fee_info.multiplier)
+                .ok_or(SolanaBridgeError::Overflow)?,
+        );
+
+        fee_amount_sum += fee;
+
+        // Amount without fee
+        let withdrawal_amount = withdrawal_amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        withdrawals_amount_sum += withdrawal_amount;
+
+        // Amount without bounty
+        let transfer_withdrawal_amount = withdrawal_amount
+            .checked_sub(withdrawal_account_data.meta.data.bounty)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Transfer SOL tokens
+        token_cpi::transfer(
+            author_token_account_info,
+            recipient_token_account_info,
+            author_account_info,
+            transfer_withdrawal_amount,
+            accounts,
+            &[],
+        )?;
+
+        withdrawal_account_data
+            .meta
+            .data
+            .transition(WithdrawalTokenStatus::Processed, &clock)?;
+
+        release_round_withdrawal(program_id, accounts, withdrawal_account_data.round_number)?;
+
+        emit_event(
+            settings_account_info,
+            &UpdateWithdrawalStatusEvent {
+                account: withdrawal_pubkey,
+                status: withdrawal_account_data.meta.data.status,
+                quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+                processed_at: withdrawal_account_data.meta.data.processed_at,
+            },
+        )?;
+
+        WithdrawalMultiTokenSol::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        emit_event(
+            settings_account_info,
+            &LiquidityRequestEvent {
+                deposit: deposit_pubkey,
+                withdrawal: withdrawal_pubkey,
+            },
+        )?;
+    }
+
+    if fee_amount_sum + withdrawals_amount_sum > amount {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    let deposit_amount = amount - withdrawals_amount_sum;
+    let vault_account_info = next_account_info(account_info_iter)?;
+    validate_vault_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_account_data.vault_generation,
+        vault_nonce,
+        vault_account_info,
+    )?;
+
+    // Make transfer
+    let vault_account_data = unpack_token_account(vault_account_info)?;
+
+    if token_settings_account_data
+        .locked_amount(vault_account_data.amount)
+        .checked_add(amount)
+        .ok_or(SolanaBridgeError::Overflow)?
+        > token_settings_account_data.deposit_limit
+    {
+        return Err(SolanaBridgeError::DepositLimit.into());
+    }
+
+    // Transfer SOL tokens
+    token_cpi::transfer(
+        author_token_account_info,
+        vault_account_info,
+        author_account_info,
+        deposit_amount,
+        accounts,
+        &[],
+    )?;
+
+    // Calculate fee
+    let fee = 1.max(
+        amount
+            .checked_div(fee_info.divisor)
+            .ok_or(SolanaBridgeError::Overflow)?
+            .checked_mul(fee_info.multiplier)
+            .ok_or(SolanaBridgeError::Overflow)?,
+    );
+
+    // Init Deposit Account
+    let transfer_amount: u128 = amount
+        .checked_sub(fee)
+        .ok_or(SolanaBridgeError::Overflow)?
+        .into();
+
+    let name = token_settings_account_data.name.clone();
+    let symbol = token_settings_account_data.symbol.clone();
+
+    let deposit_account_data = DepositMultiTokenSol {
+        is_initialized: true,
+        account_kind: AccountKind::Deposit(deposit_nonce),
+        author: *author_account_info.key,
+        event: DepositMultiTokenSolEventWithLen::new(
+            settings_account_data.chain_id,
+            mint,
+            name,
+            symbol,
+            decimals,
+            transfer_amount,
+            recipient,
+            value,
+            expected_evers,
+            payload,
+        ),
+        meta: DepositTokenMetaWithLen::new(deposit_seed)
+            .with_ever_configuration(token_settings_account_data.ever_configuration),
+    };
+
+    let event_data = hash(&deposit_account_data.event.data.try_to_vec()?)
+        .to_bytes()
+        .to_vec();
+
+    emit_event(
+        settings_account_info,
+        &DepositMultiTokenEvent {
+            account: deposit_pubkey,
+            recipient,
+            transfer_amount,
+            seed: deposit_seed,
+            value,
+            expected_evers,
+            event_data,
+            amount: amount.into(),
+        },
+    )?;
+
+    DepositMultiTokenSol::pack(
+        deposit_account_data,
+        &mut deposit_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,297 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::{Vote, RELAY_REPARATION};
+use round_loader_interface::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    create_proxy_account, emit_event, get_ever_token_settings, get_withdrawal_requirements,
+    open_round_withdrawal, validate_ever_address, validate_known_token, validate_withdrawal_header,
+    validate_withdrawal_payload,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct FinalizeWithdrawMultiTokenEverRequestAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub rl_settings_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, FinalizeWithdrawMultiTokenEverRequestAccounts<'a, 'info>>,
+    attached_amount: u64,
+) -> ProgramResult {
+    let FinalizeWithdrawMultiTokenEverRequestAccounts {
+        funder_account_info,
+        author_account_info,
+        withdrawal_account_info,
+        settings_account_info,
+        rl_settings_account_info,
+        relay_round_account_info,
+        system_program_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let clock = Clock::get()?;
+
+    validate_withdrawal_header(program_id, author_account_info, withdrawal_account_info)?;
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenEver::unpack_undiscriminated(&withdrawal_account_info.data.borrow())?;
+
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = withdrawal_account_data.event.data.try_to_vec()?;
+    let (withdrawal_nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    // Written event must match the hash committed in the withdrawal address
+    validate_withdrawal_payload(&withdrawal_account_info.data.borrow(), &event_data)?;
+
+    let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &hash(&event_data),
+        withdrawal_nonce,
+        withdrawal_account_info,
+    )
+    .map_err(|_| SolanaBridgeError::InvalidPayloadHash)?;
+
+    let event = &withdrawal_account_data.event.data;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Written event must be bound to this chain
+    if event.chain_id != settings_account_data.chain_id {
+        return Err(SolanaBridgeError::InvalidChainId.into());
+    }
+
+    // Validate EVER Token Address
+    validate_ever_address(&event.token)?;
+
+    // Check asset name length
+    if event.name.len() > MAX_NAME_LEN {
+        return Err(SolanaBridgeError::TokenNameLenLimit.into());
+    }
+
+    // Check asset symbol length
+    if event.symbol.len() > MAX_SYMBOL_LEN {
+        return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+    }
+
+    // Validate Token Metadata
+    validate_known_token(
+        program_id,
+        accounts,
+        &event.token,
+        &event.name,
+        &event.symbol,
+        event.decimals,
+    )?;
+
+    // Validate Round Loader Settings Account
+    let rl_settings_account_data =
+        round_loader_interface::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+    let (rl_settings_nonce, _) = rl_settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        &round_loader_interface::id(),
+        rl_settings_nonce,
+        rl_settings_account_info,
+    )?;
+
+    // Validate Event Configuration
+    if rl_settings_account_data.event_configurations_restricted {
+        round_loader_interface::validate_event_configuration(
+            &round_loader_interface::id(),
+            accounts,
+            &event_configuration,
+        )?;
+    }
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    round_loader_interface::validate_relay_round_account(
+        &round_loader_interface::id(),
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
+        return Err(SolanaBridgeError::RelayRoundExpired.into());
+    }
+
+    if !relay_round_account_data.is_active(clock.unix_timestamp as u32) {
+        return Err(SolanaBridgeError::RelayRoundNotActive.into());
+    }
+
+    let token_settings_account_data = get_ever_token_settings(program_id, accounts, &event.token)?;
+
+    if token_settings_account_data.as_ref().map_or(false, |data| {
+        data.is_paused(TokenOperation::WithdrawalRequest)
+    }) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Bumps of the token to be deployed by the payout are found once, the payout signs with them
+    let token_nonces = match token_settings_account_data {
+        Some(_) => None,
+        None => Some((
+            find_token_settings_ever_address(program_id, &event.token).1,
+            find_mint_address(program_id, &event.token).1,
+        )),
+    };
+
+    let (required_votes, approval_required) = get_withdrawal_requirements(
+        relay_round_account_data.relays_len(),
+        rl_settings_account_data.min_required_votes,
+        token_settings_account_data.as_ref(),
+        event.amount,
+    );
+
+    let epoch = LimitTracker::epoch_of(clock.unix_timestamp);
+
+    // Create Proxy Account
+    let proxy_nonce = match event.payload.is_empty() {
+        true => None,
+        false => {
+            let proxy_account_info = next_account_info(account_info_iter)?;
+            let mint_account_info = next_account_info(account_info_iter)?;
+            let spl_token_program_info = next_account_info(account_info_iter)?;
+
+            let mint = get_associated_mint(program_id, &event.token);
+            if mint != *mint_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let nonce = create_proxy_account(
+                program_id,
+                &event.recipient,
+                funder_account_info,
+                proxy_account_info,
+                mint_account_info,
+                system_program_info,
+                spl_token_program_info,
+            )?;
+
+            // Attach SOL to proxy account
+            invoke(
+                &system_instruction::transfer(
+                    funder_account_info.key,
+                    proxy_account_info.key,
+                    attached_amount,
+                ),
+                accounts,
+            )?;
+
+            Some(nonce)
+        }
+    };
+
+    let withdraw_event = WithdrawMultiTokenRequestEvent {
+        account: withdrawal_pubkey,
+        token: event.token.to_string(),
+        recipient: event.recipient,
+        amount: event.amount,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        event_data: hash(&event_data).to_bytes().to_vec(),
+        bounty: 0,
+    };
+
+    // Init Withdrawal Account
+    withdrawal_account_data.is_initialized = true;
+    withdrawal_account_data.account_kind = AccountKind::Proposal(withdrawal_nonce, proxy_nonce);
+    withdrawal_account_data.required_votes = required_votes;
+    withdrawal_account_data.meta = WithdrawalTokenMetaWithLen::new(0, epoch)
+        .with_approval(approval_required)
+        .with_token_nonces(token_nonces);
+    withdrawal_account_data.signers = vec![Vote::None; relay_round_account_data.relays_len()];
+
+    WithdrawalMultiTokenEver::pack(
+        withdrawal_account_data,
+        &mut withdrawal_account_info.data.borrow_mut(),
+    )?;
+
+    // Keep the round referenced until the withdrawal is final
+    open_round_withdrawal(
+        program_id,
+        accounts,
+        funder_account_info,
+        round_number,
+        relay_round_account_data.relays_len(),
+    )?;
+
+    emit_event(settings_account_info, &withdraw_event)?;
+
+    // Send voting reparation for Relay to withdrawal account
+    let relays_lamports = RELAY_REPARATION * relay_round_account_data.relays_len() as u64;
+
+    invoke(
+        &system_instruction::transfer(
+            funder_account_info.key,
+            withdrawal_account_info.key,
+            relays_lamports,
+        ),
+        accounts,
+    )?;
+
+    Ok(())
+}
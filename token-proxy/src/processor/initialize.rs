@@ -0,0 +1,164 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::Cluster;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct InitializeAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub initializer_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub multi_vault_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, InitializeAccounts<'a, 'info>>,
+    guardian: Pubkey,
+    manager: Pubkey,
+    withdrawal_manager: Pubkey,
+    chain_id: u32,
+) -> ProgramResult {
+    let InitializeAccounts {
+        funder_account_info,
+        initializer_account_info,
+        settings_account_info,
+        multi_vault_account_info,
+        programdata_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+    let clock = Clock::get()?;
+
+    // Validate Initializer Account
+    let programdata_nonce = bridge_utils::helper::validate_initializer(
+        program_id,
+        initializer_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Create Settings Account
+    let (settings_pubkey, settings_nonce) =
+        Pubkey::find_program_address(&[br"settings"], program_id);
+    let settings_account_signer_seeds: &[&[_]] = &[br"settings", &[settings_nonce]];
+
+    if settings_pubkey != *settings_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if settings_account_info.owner == program_id {
+        if !Cluster::CURRENT.reinit_allowed() {
+            return Err(SolanaBridgeError::AlreadyInitialized.into());
+        }
+
+        // Re-configure the settings, members of the other roles are kept
+        let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+        settings_account_data.chain_id = chain_id;
+        settings_account_data.set_role(Role::Guardian, guardian);
+        settings_account_data.guardian_last_active = clock.unix_timestamp;
+        settings_account_data.set_role(Role::Manager, manager);
+        settings_account_data.set_role(Role::WithdrawalManager, withdrawal_manager);
+
+        Settings::pack(
+            settings_account_data,
+            &mut settings_account_info.data.borrow_mut(),
+        )?;
+
+        return Ok(());
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            settings_account_info.key,
+            1.max(rent.minimum_balance(Settings::LEN)),
+            Settings::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[settings_account_signer_seeds],
+    )?;
+
+    // Init Settings Account
+    let settings_account_data = Settings {
+        is_initialized: true,
+        account_kind: AccountKind::Settings(settings_nonce, programdata_nonce),
+        emergency: false,
+        guardian: Pubkey::default(),
+        manager: Pubkey::default(),
+        withdrawal_manager: Pubkey::default(),
+        chain_id,
+        event_seq: 0,
+        veto_disabled: false,
+        deposits_restricted_to_whitelist: false,
+        roles: Settings::initial_roles(guardian, manager, withdrawal_manager),
+        permissions: vec![],
+        emergency_cosigners: vec![],
+        emergency_cosigners_threshold: 0,
+        guardian_last_active: clock.unix_timestamp,
+    };
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    // Create Multi Vault Account
+    let (multi_vault_pubkey, multi_vault_nonce) =
+        Pubkey::find_program_address(&[br"multivault"], program_id);
+    let multi_vault_account_signer_seeds: &[&[_]] = &[br"multivault", &[multi_vault_nonce]];
+
+    if multi_vault_pubkey != *multi_vault_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            multi_vault_account_info.key,
+            1.max(rent.minimum_balance(MultiVault::LEN)),
+            MultiVault::LEN as u64,
+            program_id,
+        ),
+        accounts,
+        &[multi_vault_account_signer_seeds],
+    )?;
+
+    // Init Multi Vault Account
+    let multi_vault_account_data = MultiVault {
+        is_initialized: true,
+        account_kind: AccountKind::MultiVault(multi_vault_nonce),
+    };
+
+    MultiVault::pack(
+        multi_vault_account_data,
+        &mut multi_vault_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,2625 @@
+pub mod add_token_to_whitelist;
+pub mod approve_withdraw_bundle;
+pub mod approve_withdraw_ever;
+pub mod approve_withdraw_sol;
+pub mod attest_receipt;
+pub mod audit_settings;
+pub mod backfill_event_index;
+pub mod cancel_withdraw_sol;
+pub mod change_bounty_for_withdraw_sol;
+pub mod change_confirmation_tiers;
+pub mod change_deposit_limit;
+pub mod change_deposit_whitelist_mode;
+pub mod change_guardian;
+pub mod change_hook_program;
+pub mod change_instruction_permission;
+pub mod change_manager;
+pub mod change_paused_operations;
+pub mod change_required_votes;
+pub mod change_withdrawal_limits;
+pub mod change_withdrawal_manager;
+pub mod change_yield_adapter;
+pub mod claim_partial;
+pub mod claim_relay_reward;
+pub mod close_deposit;
+pub mod close_withdrawal;
+pub mod create_admin_audit_log;
+pub mod create_deposit_index;
+pub mod create_relay_round_stats;
+pub mod create_settings_history;
+pub mod create_settings_history_page;
+pub mod create_token_settings_sol;
+pub mod create_withdraw_multi_token_ever_request;
+pub mod deploy_liquidity;
+pub mod deposit_liquidity;
+pub mod deposit_multi_token_batch;
+pub mod deposit_multi_token_ever;
+pub mod deposit_multi_token_ever_exact_output;
+pub mod deposit_multi_token_sol;
+pub mod deposit_multi_token_sol_exact_output;
+pub mod deposit_multi_token_sol_gasless;
+pub mod deposit_native_sol;
+pub mod disable_emergency_mode;
+pub mod disable_token_emergency_mode;
+pub mod disable_veto;
+pub mod distribute_round_rewards;
+pub mod enable_emergency_mode;
+pub mod enable_token_emergency_mode;
+pub mod execute_payload_ever;
+pub mod execute_payload_sol;
+pub mod expand_settings;
+pub mod fill_withdraw_sol;
+pub mod finalize_withdraw_multi_token_ever_request;
+pub mod grant_role;
+pub mod guardian_heartbeat;
+pub mod initialize;
+pub mod preview_deposit;
+pub mod recall_liquidity;
+pub mod recover_authority;
+pub mod recover_frozen_vault;
+pub mod remove_token_from_whitelist;
+pub mod request_discount_unstake;
+pub mod retry_pending_withdrawal;
+pub mod revoke_role;
+pub mod rotate_vault;
+pub mod schedule_limit_change;
+pub mod schedule_vault_rotation;
+pub mod set_emergency_cosigners;
+pub mod set_exempt_account;
+pub mod set_known_token;
+pub mod set_payload_schema;
+pub mod stake_for_discount;
+pub mod tick;
+pub mod token_name;
+pub mod transfer_withdrawal_authorship;
+pub mod update_discount_config;
+pub mod update_fee;
+pub mod update_reward_config;
+pub mod veto_withdrawal;
+pub mod vote_for_withdraw_request;
+pub mod withdraw_discount_stake;
+pub mod withdraw_ever_fee;
+pub mod withdraw_liquidity;
+pub mod withdraw_multi_token_bundle;
+pub mod withdraw_multi_token_bundle_request;
+pub mod withdraw_multi_token_ever;
+pub mod withdraw_multi_token_ever_request;
+pub mod withdraw_multi_token_sol;
+pub mod withdraw_multi_token_sol_request;
+pub mod withdraw_multi_vault;
+pub mod withdraw_proxy;
+pub mod withdraw_sol_fee;
+pub mod write_withdraw_multi_token_ever_request;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_utils::context::Context;
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+use bridge_utils::state::{AccountKind, ACCOUNT_DISCRIMINATOR_LEN, PDA};
+use bridge_utils::token_cpi;
+use bridge_utils::types::EverAddress;
+
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+use solana_program::{msg, system_instruction, system_program};
+
+use crate::*;
+
+pub struct Processor;
+impl Processor {
+    pub fn process(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        let instruction = TokenProxyInstruction::try_from_slice(instruction_data)?;
+
+        let admin_authority_index = instruction.admin_authority_index();
+
+        // Roles allowed to invoke the instruction unless overridden in the Settings
+        let permission = InstructionPermission {
+            instruction: instruction_data[0],
+            roles: instruction.default_roles(),
+        };
+
+        match instruction {
+            TokenProxyInstruction::Initialize {
+                guardian,
+                manager,
+                withdrawal_manager,
+                chain_id,
+            } => {
+                msg!("Instruction: Initialize Token Proxy");
+                initialize::process(
+                    Context::new(program_id, accounts)?,
+                    guardian,
+                    manager,
+                    withdrawal_manager,
+                    chain_id,
+                )?;
+            }
+            TokenProxyInstruction::DepositMultiTokenEver {
+                deposit_seed,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                payload_schema,
+            } => {
+                msg!("Instruction: Deposit MULTI TOKEN EVER");
+                deposit_multi_token_ever::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    payload_schema,
+                )?;
+            }
+            TokenProxyInstruction::DepositMultiTokenSol {
+                deposit_seed,
+                name,
+                symbol,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                payload_schema,
+                ever_configuration,
+            } => {
+                msg!("Instruction: Deposit MULTI TOKEN SOL");
+                deposit_multi_token_sol::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    name,
+                    symbol,
+                    amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    payload_schema,
+                    ever_configuration,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenEverRequest {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                token,
+                name,
+                symbol,
+                decimals,
+                recipient,
+                amount,
+                payload,
+                attached_amount,
+            } => {
+                msg!("Instruction: Withdraw Multi token EVER request");
+                withdraw_multi_token_ever_request::process(
+                    Context::new(program_id, accounts)?,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    token,
+                    name,
+                    symbol,
+                    decimals,
+                    recipient,
+                    amount,
+                    payload,
+                    attached_amount,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenSolRequest {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                recipient,
+                amount,
+                payload,
+                attached_amount,
+            } => {
+                msg!("Instruction: Withdraw multi token SOL request");
+                withdraw_multi_token_sol_request::process(
+                    Context::new(program_id, accounts)?,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    recipient,
+                    amount,
+                    payload,
+                    attached_amount,
+                )?;
+            }
+            TokenProxyInstruction::VoteForWithdrawRequest { vote } => {
+                msg!("Instruction: Vote for Withdraw EVER/SOL request");
+                vote_for_withdraw_request::process(
+                    Context::new(program_id, accounts)?,
+                    vote,
+                    None,
+                    None,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenEver => {
+                msg!("Instruction: Withdraw Multi Token EVER");
+                withdraw_multi_token_ever::process(
+                    Context::new(program_id, accounts)?,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenSol => {
+                msg!("Instruction: Withdraw Multi Token SOL");
+                withdraw_multi_token_sol::process(
+                    Context::new(program_id, accounts)?,
+                    false,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::ChangeGuardian { new_guardian } => {
+                msg!("Instruction: Update guardian");
+                change_guardian::process(Context::new(program_id, accounts)?, new_guardian)?;
+            }
+            TokenProxyInstruction::ChangeManager { new_manager } => {
+                msg!("Instruction: Update manager");
+                change_manager::process(Context::new(program_id, accounts)?, new_manager)?;
+            }
+            TokenProxyInstruction::ChangeWithdrawalManager {
+                new_withdrawal_manager,
+            } => {
+                msg!("Instruction: Update withdrawal manager");
+                change_withdrawal_manager::process(
+                    Context::new(program_id, accounts)?,
+                    new_withdrawal_manager,
+                )?;
+            }
+            TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit } => {
+                msg!("Instruction: Update deposit limit");
+                change_deposit_limit::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    new_deposit_limit,
+                )?;
+            }
+            TokenProxyInstruction::ChangeWithdrawalLimits {
+                new_withdrawal_limit,
+                new_withdrawal_daily_limit,
+            } => {
+                msg!("Instruction: Update withdrawal limits");
+                change_withdrawal_limits::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    new_withdrawal_limit,
+                    new_withdrawal_daily_limit,
+                )?;
+            }
+            TokenProxyInstruction::EnableEmergencyMode => {
+                msg!("Instruction: Enable emergency mode");
+                enable_emergency_mode::process(Context::new(program_id, accounts)?, permission)?;
+            }
+            TokenProxyInstruction::DisableEmergencyMode => {
+                msg!("Instruction: Disable emergency mode");
+                disable_emergency_mode::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::EnableTokenEmergencyMode => {
+                msg!("Instruction: Enable token emergency mode");
+                enable_token_emergency_mode::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                )?;
+            }
+            TokenProxyInstruction::DisableTokenEmergencyMode => {
+                msg!("Instruction: Disable token emergency mode");
+                disable_token_emergency_mode::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::ApproveWithdrawEver => {
+                msg!("Instruction: Approve Withdraw Multi Token EVER");
+                approve_withdraw_ever::process(Context::new(program_id, accounts)?, permission)?;
+            }
+            TokenProxyInstruction::ApproveWithdrawSol => {
+                msg!("Instruction: Approve Withdraw Multi Token SOL");
+                approve_withdraw_sol::process(Context::new(program_id, accounts)?, permission)?;
+            }
+            TokenProxyInstruction::UpdateFee {
+                fee_type,
+                multiplier,
+                divisor,
+            } => {
+                msg!("Instruction: Update Fee");
+                update_fee::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    fee_type,
+                    multiplier,
+                    divisor,
+                )?;
+            }
+            TokenProxyInstruction::UpdateTokenName { symbol, name } => {
+                msg!("Instruction: Update Token Name");
+                token_name::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    symbol,
+                    name,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawEverFee { amount } => {
+                msg!("Instruction: Withdraw EVER Fee");
+                withdraw_ever_fee::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    amount,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawSolFee { amount } => {
+                msg!("Instruction: Withdraw SOL Fee");
+                withdraw_sol_fee::process(Context::new(program_id, accounts)?, permission, amount)?;
+            }
+            TokenProxyInstruction::ChangeBountyForWithdrawSol { bounty } => {
+                msg!("Instruction: Change Bounty For Withdraw Sol");
+                change_bounty_for_withdraw_sol::process(
+                    Context::new(program_id, accounts)?,
+                    bounty,
+                )?;
+            }
+            TokenProxyInstruction::CancelWithdrawSol {
+                deposit_seed,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+            } => {
+                msg!("Instruction: Cancel Withdraw SOL");
+                cancel_withdraw_sol::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                )?;
+            }
+            TokenProxyInstruction::FillWithdrawSol {
+                deposit_seed,
+                recipient,
+                amount,
+                value,
+                expected_evers,
+                payload,
+            } => {
+                msg!("Instruction: Fill Withdraw SOL");
+                fill_withdraw_sol::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    recipient,
+                    amount,
+                    value,
+                    expected_evers,
+                    payload,
+                )?;
+            }
+            TokenProxyInstruction::ExecutePayloadEver => {
+                msg!("Instruction: Execute Payload EVER");
+                execute_payload_ever::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::ExecutePayloadSol => {
+                msg!("Instruction: Execute Payload SOL");
+                execute_payload_sol::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::WithdrawProxy { amount } => {
+                msg!("Instruction: Withdraw Proxy");
+                withdraw_proxy::process(Context::new(program_id, accounts)?, amount)?;
+            }
+            TokenProxyInstruction::CloseDeposit => {
+                msg!("Instruction: Close Deposit");
+                close_deposit::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::CloseWithdrawal => {
+                msg!("Instruction: Close Withdrawal");
+                close_withdrawal::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::WithdrawMultiVault { amount } => {
+                msg!("Instruction: Withdraw Multi Vault");
+                withdraw_multi_vault::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    amount,
+                )?;
+            }
+            TokenProxyInstruction::CreateTokenSettingsSol {
+                name,
+                symbol,
+                deposit_limit,
+                withdrawal_limit,
+                withdrawal_daily_limit,
+                allow_freeze_authority,
+                ever_configuration,
+            } => {
+                msg!("Instruction: Create Token Settings SOL");
+                create_token_settings_sol::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    name,
+                    symbol,
+                    deposit_limit,
+                    withdrawal_limit,
+                    withdrawal_daily_limit,
+                    allow_freeze_authority,
+                    ever_configuration,
+                )?;
+            }
+            TokenProxyInstruction::CreateAdminAuditLog => {
+                msg!("Instruction: Create Admin Audit Log");
+                create_admin_audit_log::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::CreateWithdrawMultiTokenEverRequest {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                event_data,
+            } => {
+                msg!("Instruction: Create Withdraw Multi token EVER request");
+                create_withdraw_multi_token_ever_request::process(
+                    Context::new(program_id, accounts)?,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    event_data,
+                )?;
+            }
+            TokenProxyInstruction::WriteWithdrawMultiTokenEverRequest { offset, bytes } => {
+                msg!("Instruction: Write Withdraw Multi token EVER request");
+                write_withdraw_multi_token_ever_request::process(
+                    Context::new(program_id, accounts)?,
+                    offset,
+                    bytes,
+                )?;
+            }
+            TokenProxyInstruction::FinalizeWithdrawMultiTokenEverRequest { attached_amount } => {
+                msg!("Instruction: Finalize Withdraw Multi token EVER request");
+                finalize_withdraw_multi_token_ever_request::process(
+                    Context::new(program_id, accounts)?,
+                    attached_amount,
+                )?;
+            }
+            TokenProxyInstruction::RetryPendingWithdrawal => {
+                msg!("Instruction: Retry pending withdrawal");
+                retry_pending_withdrawal::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::CreateSettingsHistory => {
+                msg!("Instruction: Create Settings History");
+                create_settings_history::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::CreateSettingsHistoryPage { page } => {
+                msg!("Instruction: Create Settings History Page");
+                create_settings_history_page::process(Context::new(program_id, accounts)?, page)?;
+            }
+            TokenProxyInstruction::PreviewDeposit { amount, value } => {
+                msg!("Instruction: Preview Deposit");
+                preview_deposit::process(Context::new(program_id, accounts)?, amount, value)?;
+            }
+            TokenProxyInstruction::RecoverFrozenVault => {
+                msg!("Instruction: Recover Frozen Vault");
+                recover_frozen_vault::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::ScheduleVaultRotation => {
+                msg!("Instruction: Schedule Vault Rotation");
+                schedule_vault_rotation::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::RotateVault => {
+                msg!("Instruction: Rotate Vault");
+                rotate_vault::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::ClaimPartial { amount } => {
+                msg!("Instruction: Claim Partial");
+                claim_partial::process(Context::new(program_id, accounts)?, amount)?;
+            }
+            TokenProxyInstruction::DepositLiquidity { amount } => {
+                msg!("Instruction: Deposit Liquidity");
+                deposit_liquidity::process(Context::new(program_id, accounts)?, amount)?;
+            }
+            TokenProxyInstruction::WithdrawLiquidity { shares } => {
+                msg!("Instruction: Withdraw Liquidity");
+                withdraw_liquidity::process(Context::new(program_id, accounts)?, shares)?;
+            }
+            TokenProxyInstruction::ChangeYieldAdapter {
+                new_yield_adapter,
+                max_deployed_share,
+            } => {
+                msg!("Instruction: Change Yield Adapter");
+                change_yield_adapter::process(
+                    Context::new(program_id, accounts)?,
+                    new_yield_adapter,
+                    max_deployed_share,
+                )?;
+            }
+            TokenProxyInstruction::DeployLiquidity { amount } => {
+                msg!("Instruction: Deploy Liquidity");
+                deploy_liquidity::process(Context::new(program_id, accounts)?, amount)?;
+            }
+            TokenProxyInstruction::RecallLiquidity { amount } => {
+                msg!("Instruction: Recall Liquidity");
+                recall_liquidity::process(Context::new(program_id, accounts)?, amount)?;
+            }
+            TokenProxyInstruction::CreateDepositIndex => {
+                msg!("Instruction: Create Deposit Index");
+                create_deposit_index::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::AttestReceipt { receipt } => {
+                msg!("Instruction: Attest Receipt");
+                attest_receipt::process(Context::new(program_id, accounts)?, receipt)?;
+            }
+            TokenProxyInstruction::UpdateRewardConfig { relay_share } => {
+                msg!("Instruction: Update Reward Config");
+                update_reward_config::process(Context::new(program_id, accounts)?, relay_share)?;
+            }
+            TokenProxyInstruction::CreateRelayRoundStats => {
+                msg!("Instruction: Create Relay Round Stats");
+                create_relay_round_stats::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::DistributeRoundRewards => {
+                msg!("Instruction: Distribute Round Rewards");
+                distribute_round_rewards::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::ClaimRelayReward => {
+                msg!("Instruction: Claim Relay Reward");
+                claim_relay_reward::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::VoteForWithdrawRequestWithProof { vote, proof } => {
+                msg!("Instruction: Vote for Withdraw EVER/SOL request with proof");
+                vote_for_withdraw_request::process(
+                    Context::new(program_id, accounts)?,
+                    vote,
+                    None,
+                    Some(proof),
+                )?;
+            }
+            TokenProxyInstruction::ChangeHookProgram { new_hook_program } => {
+                msg!("Instruction: Change hook program");
+                change_hook_program::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    new_hook_program,
+                )?;
+            }
+            TokenProxyInstruction::BackfillEventIndex => {
+                msg!("Instruction: Backfill Event Index");
+                backfill_event_index::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::VetoWithdrawal { reason } => {
+                msg!("Instruction: Veto Withdrawal");
+                veto_withdrawal::process(Context::new(program_id, accounts)?, permission, reason)?;
+            }
+            TokenProxyInstruction::DisableVeto => {
+                msg!("Instruction: Disable Veto");
+                disable_veto::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::Tick => {
+                msg!("Instruction: Tick");
+                tick::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::GrantRole { role, authority } => {
+                msg!("Instruction: Grant Role");
+                grant_role::process(Context::new(program_id, accounts)?, role, authority)?;
+            }
+            TokenProxyInstruction::RevokeRole { role, authority } => {
+                msg!("Instruction: Revoke Role");
+                revoke_role::process(Context::new(program_id, accounts)?, role, authority)?;
+            }
+            TokenProxyInstruction::ChangeInstructionPermission { instruction, roles } => {
+                msg!("Instruction: Change Instruction Permission");
+                change_instruction_permission::process(
+                    Context::new(program_id, accounts)?,
+                    instruction,
+                    roles,
+                )?;
+            }
+            TokenProxyInstruction::AddTokenToWhitelist { mint } => {
+                msg!("Instruction: Add Token To Whitelist");
+                add_token_to_whitelist::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    mint,
+                )?;
+            }
+            TokenProxyInstruction::RemoveTokenFromWhitelist { mint } => {
+                msg!("Instruction: Remove Token From Whitelist");
+                remove_token_from_whitelist::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    mint,
+                )?;
+            }
+            TokenProxyInstruction::ChangeDepositWhitelistMode { restricted } => {
+                msg!("Instruction: Change Deposit Whitelist Mode");
+                change_deposit_whitelist_mode::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    restricted,
+                )?;
+            }
+            TokenProxyInstruction::ChangeRequiredVotes {
+                required_votes_override,
+            } => {
+                msg!("Instruction: Change Required Votes");
+                change_required_votes::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    required_votes_override,
+                )?;
+            }
+            TokenProxyInstruction::ChangeConfirmationTiers { confirmation_tiers } => {
+                msg!("Instruction: Change Confirmation Tiers");
+                change_confirmation_tiers::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    confirmation_tiers,
+                )?;
+            }
+            TokenProxyInstruction::DepositMultiTokenBatch {
+                deposit_seed,
+                entries,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                payload_schema,
+            } => {
+                msg!("Instruction: Deposit MULTI TOKEN BATCH");
+                deposit_multi_token_batch::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    entries,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    payload_schema,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::DepositNativeSol {
+                deposit_seed,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                payload_schema,
+            } => {
+                msg!("Instruction: Deposit NATIVE SOL");
+                deposit_native_sol::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    payload_schema,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenSolUnwrap => {
+                msg!("Instruction: Withdraw Multi Token SOL Unwrap");
+                withdraw_multi_token_sol::process(
+                    Context::new(program_id, accounts)?,
+                    true,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::TransferWithdrawalAuthorship { new_author } => {
+                msg!("Instruction: Transfer Withdrawal Authorship");
+                transfer_withdrawal_authorship::process(
+                    Context::new(program_id, accounts)?,
+                    new_author,
+                )?;
+            }
+            TokenProxyInstruction::ExpandSettings { chain_id } => {
+                msg!("Instruction: Expand Settings");
+                expand_settings::process(Context::new(program_id, accounts)?, chain_id)?;
+            }
+            TokenProxyInstruction::ChangePausedOperations { paused_operations } => {
+                msg!("Instruction: Change Paused Operations");
+                change_paused_operations::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    paused_operations,
+                )?;
+            }
+            TokenProxyInstruction::SetPayloadSchema {
+                schema_id,
+                min_len,
+                max_len,
+                header_len,
+                header_hash,
+            } => {
+                msg!("Instruction: Set Payload Schema");
+                set_payload_schema::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    schema_id,
+                    min_len,
+                    max_len,
+                    header_len,
+                    header_hash,
+                )?;
+            }
+            TokenProxyInstruction::SetEmergencyCosigners {
+                cosigners,
+                threshold,
+            } => {
+                msg!("Instruction: Set Emergency Cosigners");
+                set_emergency_cosigners::process(
+                    Context::new(program_id, accounts)?,
+                    cosigners,
+                    threshold,
+                )?;
+            }
+            TokenProxyInstruction::SetExemptAccount {
+                authority,
+                expires_at,
+            } => {
+                msg!("Instruction: Set Exempt Account");
+                set_exempt_account::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    authority,
+                    expires_at,
+                )?;
+            }
+            TokenProxyInstruction::DepositMultiTokenSolGasless {
+                deposit_seed,
+                name,
+                symbol,
+                amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                payload_schema,
+                nonce,
+                expiry,
+            } => {
+                msg!("Instruction: Deposit MULTI TOKEN SOL Gasless");
+                deposit_multi_token_sol_gasless::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    name,
+                    symbol,
+                    amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    payload_schema,
+                    nonce,
+                    expiry,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::ScheduleLimitChange {
+                new_withdrawal_limit,
+                new_withdrawal_daily_limit,
+                effective_at,
+            } => {
+                msg!("Instruction: Schedule limit change");
+                schedule_limit_change::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    new_withdrawal_limit,
+                    new_withdrawal_daily_limit,
+                    effective_at,
+                )?;
+            }
+            TokenProxyInstruction::UpdateDiscountConfig {
+                mint,
+                min_stake,
+                discount_bps,
+                unstake_cooldown,
+            } => {
+                msg!("Instruction: Update discount config");
+                update_discount_config::process(
+                    Context::new(program_id, accounts)?,
+                    mint,
+                    min_stake,
+                    discount_bps,
+                    unstake_cooldown,
+                )?;
+            }
+            TokenProxyInstruction::StakeForDiscount { amount } => {
+                msg!("Instruction: Stake for discount");
+                stake_for_discount::process(Context::new(program_id, accounts)?, amount)?;
+            }
+            TokenProxyInstruction::RequestDiscountUnstake { amount } => {
+                msg!("Instruction: Request discount unstake");
+                request_discount_unstake::process(Context::new(program_id, accounts)?, amount)?;
+            }
+            TokenProxyInstruction::WithdrawDiscountStake => {
+                msg!("Instruction: Withdraw discount stake");
+                withdraw_discount_stake::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::AuditSettings => {
+                msg!("Instruction: Audit settings");
+                audit_settings::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenBundleRequest {
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                recipient,
+                entries,
+            } => {
+                msg!("Instruction: Withdraw Multi Token BUNDLE request");
+                withdraw_multi_token_bundle_request::process(
+                    Context::new(program_id, accounts)?,
+                    event_timestamp,
+                    event_transaction_lt,
+                    event_configuration,
+                    recipient,
+                    entries,
+                )?;
+            }
+            TokenProxyInstruction::WithdrawMultiTokenBundle => {
+                msg!("Instruction: Withdraw Multi Token BUNDLE");
+                withdraw_multi_token_bundle::process(
+                    Context::new(program_id, accounts)?,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::ApproveWithdrawBundle => {
+                msg!("Instruction: Approve Withdraw Multi Token BUNDLE");
+                approve_withdraw_bundle::process(Context::new(program_id, accounts)?, permission)?;
+            }
+            TokenProxyInstruction::RecoverAuthority { new_program_id } => {
+                msg!("Instruction: Recover Authority");
+                recover_authority::process(Context::new(program_id, accounts)?, new_program_id)?;
+            }
+            TokenProxyInstruction::SetKnownToken {
+                token,
+                name,
+                symbol,
+                decimals,
+            } => {
+                msg!("Instruction: Set Known Token");
+                set_known_token::process(
+                    Context::new(program_id, accounts)?,
+                    permission,
+                    token,
+                    name,
+                    symbol,
+                    decimals,
+                )?;
+            }
+            TokenProxyInstruction::DepositMultiTokenSolExactOutput {
+                deposit_seed,
+                name,
+                symbol,
+                transfer_amount,
+                max_amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                payload_schema,
+                ever_configuration,
+            } => {
+                msg!("Instruction: Deposit MULTI TOKEN SOL Exact Output");
+                deposit_multi_token_sol_exact_output::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    name,
+                    symbol,
+                    transfer_amount,
+                    max_amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    payload_schema,
+                    ever_configuration,
+                    instruction_data,
+                )?;
+            }
+            TokenProxyInstruction::DepositMultiTokenEverExactOutput {
+                deposit_seed,
+                transfer_amount,
+                max_amount,
+                recipient,
+                value,
+                expected_evers,
+                payload,
+                payload_schema,
+            } => {
+                msg!("Instruction: Deposit MULTI TOKEN EVER Exact Output");
+                deposit_multi_token_ever_exact_output::process(
+                    Context::new(program_id, accounts)?,
+                    deposit_seed,
+                    transfer_amount,
+                    max_amount,
+                    recipient,
+                    value,
+                    expected_evers,
+                    payload,
+                    payload_schema,
+                )?;
+            }
+            TokenProxyInstruction::GuardianHeartbeat => {
+                msg!("Instruction: Guardian Heartbeat");
+                guardian_heartbeat::process(Context::new(program_id, accounts)?)?;
+            }
+            TokenProxyInstruction::VoteForWithdrawRequestWithIndex { vote, index } => {
+                msg!("Instruction: Vote for Withdraw EVER/SOL request with index");
+                vote_for_withdraw_request::process(
+                    Context::new(program_id, accounts)?,
+                    vote,
+                    Some(index),
+                    None,
+                )?;
+            }
+        };
+
+        if let Some(index) = admin_authority_index {
+            let authority_account_info = accounts
+                .get(index)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            bridge_utils::helper::append_admin_audit_log(
+                program_id,
+                accounts,
+                authority_account_info.key,
+                instruction_data,
+            )?;
+        }
+
+        record_guardian_activity(program_id, accounts)?;
+
+        Ok(())
+    }
+}
+
+/// Record guardians signing any instruction, admin or not.
+///
+/// Nothing is recorded if the instruction passes the settings read-only, instructions guardians
+/// sign by default take them writable.
+fn record_guardian_activity(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    if !accounts.iter().any(|a| a.is_signer) {
+        return Ok(());
+    }
+
+    let settings_pubkey = bridge_utils::helper::get_associated_settings_address(program_id);
+
+    let settings_account_info = match accounts.iter().find(|a| *a.key == settings_pubkey) {
+        Some(account_info) if account_info.is_writable && account_info.owner == program_id => {
+            account_info
+        }
+        _ => return Ok(()),
+    };
+
+    // Settings not migrated yet can't be unpacked, they are seeded on migration
+    let mut settings_account_data = match Settings::unpack(&settings_account_info.data.borrow()) {
+        Ok(settings_account_data) => settings_account_data,
+        Err(_) => return Ok(()),
+    };
+
+    let guardian = match accounts
+        .iter()
+        .find(|a| a.is_signer && settings_account_data.has_role(Role::Guardian, a.key))
+    {
+        Some(account_info) => account_info.key,
+        None => return Ok(()),
+    };
+
+    settings_account_data.record_guardian_activity(guardian, Clock::get()?.unix_timestamp);
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
+
+/// Check the guardians can be replaced by the owner. An active guardian co-signs its
+/// replacement, the one inactive for the inactivity window is replaced without it.
+fn validate_guardian_replacement(
+    settings: &Settings,
+    cosigners: &[Pubkey],
+    now: i64,
+) -> ProgramResult {
+    let guardians = settings.role_members(Role::Guardian);
+
+    if guardians.is_empty()
+        || settings.is_guardian_inactive(now)
+        || guardians
+            .iter()
+            .any(|guardian| cosigners.contains(guardian))
+    {
+        return Ok(());
+    }
+
+    Err(SolanaBridgeError::GuardianActive.into())
+}
+
+/// Record role or limit change into the settings history.
+///
+/// The history accounts are looked up among the instruction accounts. Nothing is recorded if
+/// the settings history is missing or was not created yet, but once it is passed the current
+/// page has to be passed as well.
+fn record_settings_change(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    actor: &Pubkey,
+    token_settings: Option<Pubkey>,
+    field: SettingsField,
+    old_value: SettingsValue,
+    new_value: SettingsValue,
+) -> ProgramResult {
+    let settings_history_pubkey = get_associated_settings_history_address(program_id);
+
+    let settings_history_account_info =
+        match accounts.iter().find(|a| *a.key == settings_history_pubkey) {
+            Some(account_info) if account_info.owner == program_id => account_info,
+            _ => return Ok(()),
+        };
+
+    let mut settings_history_account_data =
+        SettingsHistory::unpack(&settings_history_account_info.data.borrow())?;
+
+    // Validate Settings History Page Account
+    let page = settings_history_account_data.current_page();
+    let settings_history_page_pubkey =
+        get_associated_settings_history_page_address(program_id, page);
+
+    let settings_history_page_account_info = accounts
+        .iter()
+        .find(|a| *a.key == settings_history_page_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if settings_history_page_account_info.owner != program_id {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let mut settings_history_page_account_data =
+        SettingsHistoryPage::unpack(&settings_history_page_account_info.data.borrow())?;
+
+    let clock = Clock::get()?;
+
+    settings_history_page_account_data
+        .changes
+        .push(SettingsChange {
+            field,
+            token_settings,
+            old_value,
+            new_value,
+            actor: *actor,
+            slot: clock.slot,
+        });
+
+    settings_history_account_data.total += 1;
+
+    SettingsHistoryPage::pack(
+        settings_history_page_account_data,
+        &mut settings_history_page_account_info.data.borrow_mut(),
+    )?;
+
+    SettingsHistory::pack(
+        settings_history_account_data,
+        &mut settings_history_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
+
+/// Record the deposit into the deposit index of its author.
+///
+/// The index accounts are looked up among the instruction accounts. Nothing is recorded if the
+/// author has no deposit index, but once it is passed the current page has to be passed as well.
+/// The page is created by the funder when the previous one is full.
+fn record_deposit<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    funder_account_info: &AccountInfo<'a>,
+    author: &Pubkey,
+    deposit: &Pubkey,
+    rent: &Rent,
+) -> ProgramResult {
+    let deposit_index_pubkey = get_associated_deposit_index_address(program_id, author);
+
+    let deposit_index_account_info = match accounts.iter().find(|a| *a.key == deposit_index_pubkey)
+    {
+        Some(account_info) if account_info.owner == program_id => account_info,
+        _ => return Ok(()),
+    };
+
+    let mut deposit_index_account_data =
+        DepositIndex::unpack(&deposit_index_account_info.data.borrow())?;
+
+    // Validate Deposit Index Page Account
+    let page = deposit_index_account_data.current_page();
+    let (deposit_index_page_pubkey, deposit_index_page_nonce) = Pubkey::find_program_address(
+        &[br"deposit_index", &author.to_bytes(), &page.to_le_bytes()],
+        program_id,
+    );
+
+    let deposit_index_page_account_info = accounts
+        .iter()
+        .find(|a| *a.key == deposit_index_page_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let mut deposit_index_page_account_data =
+        if bridge_utils::helper::is_uncreated_account(deposit_index_page_account_info) {
+            let deposit_index_page_account_signer_seeds: &[&[_]] = &[
+                br"deposit_index",
+                &author.to_bytes(),
+                &page.to_le_bytes(),
+                &[deposit_index_page_nonce],
+            ];
+
+            bridge_utils::helper::create_pda_account(
+                funder_account_info,
+                deposit_index_page_account_info,
+                rent,
+                DepositIndexPage::LEN,
+                program_id,
+                accounts,
+                deposit_index_page_account_signer_seeds,
+            )?;
+
+            DepositIndexPage {
+                is_initialized: true,
+                account_kind: AccountKind::DepositIndexPage(deposit_index_page_nonce),
+                owner: *author,
+                page,
+                deposits: Vec::new(),
+            }
+        } else {
+            DepositIndexPage::unpack(&deposit_index_page_account_info.data.borrow())?
+        };
+
+    deposit_index_page_account_data.deposits.push(*deposit);
+
+    deposit_index_account_data.total += 1;
+
+    DepositIndexPage::pack(
+        deposit_index_page_account_data,
+        &mut deposit_index_page_account_info.data.borrow_mut(),
+    )?;
+
+    DepositIndex::pack(
+        deposit_index_account_data,
+        &mut deposit_index_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
+
+/// Count the relay vote in the relay round stats.
+///
+/// The stats account is looked up among the instruction accounts and nothing is recorded if it
+/// is missing. The stats of the round are created by the relay with the first recorded vote.
+/// The withdrawal amount is given for the first vote of a withdrawal, to count it in the volume.
+fn record_relay_vote<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    relay_account_info: &AccountInfo<'a>,
+    round_number: u32,
+    relays_count: usize,
+    index: usize,
+    amount: Option<u128>,
+) -> ProgramResult {
+    let relay_round_stats_pubkey =
+        get_associated_relay_round_stats_address(program_id, round_number);
+
+    let relay_round_stats_account_info =
+        match accounts.iter().find(|a| *a.key == relay_round_stats_pubkey) {
+            Some(account_info) => account_info,
+            None => return Ok(()),
+        };
+
+    let mut relay_round_stats_account_data = load_relay_round_stats(
+        program_id,
+        accounts,
+        relay_account_info,
+        relay_round_stats_account_info,
+        round_number,
+        relays_count,
+    )?;
+
+    let votes = relay_round_stats_account_data
+        .votes
+        .get_mut(index)
+        .ok_or(SolanaBridgeError::InvalidRelay)?;
+    *votes = votes.checked_add(1).ok_or(SolanaBridgeError::Overflow)?;
+
+    relay_round_stats_account_data.total = relay_round_stats_account_data
+        .total
+        .checked_add(1)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    if let Some(amount) = amount {
+        relay_round_stats_account_data.withdrawals = relay_round_stats_account_data
+            .withdrawals
+            .checked_add(1)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        relay_round_stats_account_data.volume = relay_round_stats_account_data
+            .volume
+            .checked_add(amount)
+            .ok_or(SolanaBridgeError::Overflow)?;
+    }
+
+    RelayRoundStats::pack(
+        relay_round_stats_account_data,
+        &mut relay_round_stats_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
+
+/// Count a new withdrawal as active in the relay round stats until it reaches a final status.
+///
+/// The stats account must be passed along, the stats of the round are created by the funder of
+/// the withdrawal if no vote was recorded in the round yet.
+fn open_round_withdrawal<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    funder_account_info: &AccountInfo<'a>,
+    round_number: u32,
+    relays_count: usize,
+) -> ProgramResult {
+    let relay_round_stats_pubkey =
+        get_associated_relay_round_stats_address(program_id, round_number);
+
+    let relay_round_stats_account_info = accounts
+        .iter()
+        .find(|a| *a.key == relay_round_stats_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let mut relay_round_stats_account_data = load_relay_round_stats(
+        program_id,
+        accounts,
+        funder_account_info,
+        relay_round_stats_account_info,
+        round_number,
+        relays_count,
+    )?;
+
+    relay_round_stats_account_data.active_withdrawals = relay_round_stats_account_data
+        .active_withdrawals
+        .checked_add(1)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    RelayRoundStats::pack(
+        relay_round_stats_account_data,
+        &mut relay_round_stats_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
+
+/// Release the withdrawal that reached a final status from the relay round stats.
+///
+/// The stats account is looked up among the instruction accounts. Nothing is released if it is
+/// missing, so the round stays referenced and can't be closed.
+fn release_round_withdrawal(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    round_number: u32,
+) -> ProgramResult {
+    let relay_round_stats_pubkey =
+        get_associated_relay_round_stats_address(program_id, round_number);
+
+    let relay_round_stats_account_info = match accounts
+        .iter()
+        .find(|a| *a.key == relay_round_stats_pubkey && a.owner == program_id)
+    {
+        Some(account_info) => account_info,
+        None => return Ok(()),
+    };
+
+    let mut relay_round_stats_account_data =
+        RelayRoundStats::unpack(&relay_round_stats_account_info.data.borrow())?;
+
+    // Withdrawals created before the counter was kept were never counted
+    relay_round_stats_account_data.active_withdrawals = relay_round_stats_account_data
+        .active_withdrawals
+        .saturating_sub(1);
+
+    RelayRoundStats::pack(
+        relay_round_stats_account_data,
+        &mut relay_round_stats_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
+
+/// Unpack the relay round stats, creating the account on behalf of the funder if it doesn't
+/// exist yet
+fn load_relay_round_stats<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    funder_account_info: &AccountInfo<'a>,
+    relay_round_stats_account_info: &AccountInfo<'a>,
+    round_number: u32,
+    relays_count: usize,
+) -> Result<RelayRoundStats, ProgramError> {
+    if bridge_utils::helper::is_uncreated_account(relay_round_stats_account_info) {
+        let (_, relay_round_stats_nonce) = Pubkey::find_program_address(
+            &[br"relay_round_stats", &round_number.to_le_bytes()],
+            program_id,
+        );
+        let relay_round_stats_account_signer_seeds: &[&[_]] = &[
+            br"relay_round_stats",
+            &round_number.to_le_bytes(),
+            &[relay_round_stats_nonce],
+        ];
+
+        let rent = Rent::get()?;
+
+        bridge_utils::helper::create_pda_account(
+            funder_account_info,
+            relay_round_stats_account_info,
+            &rent,
+            RelayRoundStats::LEN,
+            program_id,
+            accounts,
+            relay_round_stats_account_signer_seeds,
+        )?;
+
+        Ok(RelayRoundStats {
+            is_initialized: true,
+            account_kind: AccountKind::RelayRoundStats(relay_round_stats_nonce),
+            round_number,
+            total: 0,
+            votes: vec![0; relays_count],
+            withdrawals: 0,
+            volume: 0,
+            active_withdrawals: 0,
+        })
+    } else {
+        if relay_round_stats_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        RelayRoundStats::unpack(&relay_round_stats_account_info.data.borrow())
+    }
+}
+
+fn validate_withdrawal_header(
+    program_id: &Pubkey,
+    author_account_info: &AccountInfo,
+    withdrawal_account_info: &AccountInfo,
+) -> ProgramResult {
+    if withdrawal_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let header = ProposalHeader::deserialize(
+        &mut &withdrawal_account_info.data.borrow()[ACCOUNT_DISCRIMINATOR_LEN..],
+    )?;
+
+    if header.is_initialized {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    if header.author != *author_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+fn validate_withdrawal_payload(data: &[u8], event_data: &[u8]) -> Result<(), ProgramError> {
+    let offset = WITHDRAWAL_LOAD_DATA_BEGIN_OFFSET;
+
+    let len = u32::try_from_slice(&data[offset..offset + 4])? as usize;
+    if len != event_data.len() || data[offset + 4..offset + 4 + len] != *event_data {
+        return Err(SolanaBridgeError::InvalidPayloadHash.into());
+    }
+
+    Ok(())
+}
+
+fn write_withdrawal_data(data: &mut [u8], offset: usize, bytes: &[u8]) -> ProgramResult {
+    let offset = WITHDRAWAL_LOAD_DATA_BEGIN_OFFSET + offset;
+
+    let len = bytes.len();
+    if WITHDRAWAL_LOAD_DATA_END_OFFSET < offset + len {
+        msg!(
+            "Write overflow: {} < {}",
+            WITHDRAWAL_LOAD_DATA_END_OFFSET,
+            offset + len
+        );
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+
+    data[offset..offset + len].copy_from_slice(bytes);
+
+    Ok(())
+}
+
+fn make_ever_transfer<'a>(
+    mint_account_info: &AccountInfo<'a>,
+    recipient_account_info: &AccountInfo<'a>,
+    settings_account_data: &TokenSettings,
+    accounts: &[AccountInfo],
+    withdrawal_amount: u64,
+) -> ProgramResult {
+    // Mint EVER tokens to Proxy Account
+    let (_, token, _) = settings_account_data
+        .kind
+        .into_ever()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (_, mint_nonce) = settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let token_hash = hash(&token.try_to_vec()?);
+    let mint_account_signer_seeds: &[&[_]] = &[br"mint", token_hash.as_ref(), &[mint_nonce]];
+
+    token_cpi::mint_to(
+        mint_account_info,
+        recipient_account_info,
+        mint_account_info,
+        withdrawal_amount,
+        accounts,
+        &[mint_account_signer_seeds],
+    )
+}
+
+fn make_sol_transfer<'a>(
+    vault_account_info: &AccountInfo<'a>,
+    recipient_account_info: &AccountInfo<'a>,
+    settings_account_data: &TokenSettings,
+    accounts: &[AccountInfo],
+    withdrawal_amount: u64,
+) -> ProgramResult {
+    // Transfer tokens from Vault Account to Recipient Account
+    sign_by_vault(settings_account_data, |signer_seeds| {
+        token_cpi::transfer(
+            vault_account_info,
+            recipient_account_info,
+            vault_account_info,
+            withdrawal_amount,
+            accounts,
+            signer_seeds,
+        )
+    })
+}
+
+/// Close the wSOL account of the recipient, delivering its lamports to the owner
+fn unwrap_sol<'a>(
+    recipient_account_info: &AccountInfo<'a>,
+    owner_account_info: &AccountInfo<'a>,
+    accounts: &[AccountInfo<'a>],
+) -> ProgramResult {
+    token_cpi::close_account(
+        recipient_account_info,
+        owner_account_info,
+        owner_account_info,
+        accounts,
+        &[],
+    )
+}
+
+/// Create and initialize vault of the given generation
+#[allow(clippy::too_many_arguments)]
+fn create_vault_account<'a>(
+    accounts: &[AccountInfo<'a>],
+    funder_account_info: &AccountInfo<'a>,
+    mint_account_info: &AccountInfo<'a>,
+    vault_account_info: &AccountInfo<'a>,
+    ever_configuration: Option<&Pubkey>,
+    generation: u8,
+    vault_nonce: u8,
+    rent: &Rent,
+) -> ProgramResult {
+    let mut seeds = get_vault_seeds(mint_account_info.key, ever_configuration, generation);
+    seeds.push(vec![vault_nonce]);
+
+    let vault_account_signer_seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let vault_account_signer_seeds = vault_account_signer_seeds.as_slice();
+
+    bridge_utils::helper::create_pda_account(
+        funder_account_info,
+        vault_account_info,
+        rent,
+        spl_token::state::Account::LEN,
+        &spl_token::id(),
+        accounts,
+        vault_account_signer_seeds,
+    )?;
+
+    invoke_signed(
+        &spl_token::instruction::initialize_account3(
+            &spl_token::id(),
+            vault_account_info.key,
+            mint_account_info.key,
+            vault_account_info.key,
+        )?,
+        accounts,
+        &[vault_account_signer_seeds],
+    )
+}
+
+/// Invoke the yield adapter for the liquidity of the vault. Adapter accounts are passed through.
+///
+/// The vault never signs for the adapter, so the adapter can't approve a delegate or take over
+/// the vault. Deployed liquidity is transferred by Token Proxy to the adapter token account, the
+/// first of the adapter accounts, and recalled liquidity is transferred back by the adapter.
+fn invoke_yield_adapter<'a>(
+    accounts: &[AccountInfo<'a>],
+    yield_adapter: &Pubkey,
+    vault_account_info: &AccountInfo<'a>,
+    token_settings_account_info: &AccountInfo<'a>,
+    adapter_account_infos: &[AccountInfo<'a>],
+    adapter_instruction: YieldAdapterInstruction,
+    token_settings_account_data: &TokenSettings,
+) -> ProgramResult {
+    if let YieldAdapterInstruction::Deposit { amount } = adapter_instruction {
+        let adapter_token_account_info = adapter_account_infos
+            .first()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        // Validate Adapter Token Account
+        let adapter_token_account_data = unpack_token_account(adapter_token_account_info)?;
+        let vault_account_data = unpack_token_account(vault_account_info)?;
+
+        if adapter_token_account_info.key == vault_account_info.key
+            || adapter_token_account_data.mint != vault_account_data.mint
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        make_sol_transfer(
+            vault_account_info,
+            adapter_token_account_info,
+            token_settings_account_data,
+            accounts,
+            amount,
+        )?;
+    }
+
+    let mut adapter_accounts = vec![
+        AccountMeta::new(*vault_account_info.key, false),
+        AccountMeta::new_readonly(*token_settings_account_info.key, false),
+    ];
+
+    adapter_accounts.extend(
+        adapter_account_infos
+            .iter()
+            .map(|account_info| AccountMeta {
+                pubkey: *account_info.key,
+                is_signer: account_info.is_signer,
+                is_writable: account_info.is_writable,
+            }),
+    );
+
+    let instruction = Instruction {
+        program_id: *yield_adapter,
+        accounts: adapter_accounts,
+        data: adapter_instruction.try_to_vec()?,
+    };
+
+    invoke(&instruction, accounts)
+}
+
+/// Run a CPI signed by the vault of the current generation
+fn sign_by_vault<F>(settings_account_data: &TokenSettings, f: F) -> ProgramResult
+where
+    F: FnOnce(&[&[&[u8]]]) -> ProgramResult,
+{
+    let (mint, _) = settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (_, vault_nonce) = settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let mut seeds = get_vault_seeds(
+        &mint,
+        settings_account_data.ever_configuration.as_ref(),
+        settings_account_data.vault_generation,
+    );
+    seeds.push(vec![vault_nonce]);
+
+    let signer_seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+    f(&[signer_seeds.as_slice()])
+}
+
+/// Notify token hook program, if any, via CPI signed by the hook authority PDA
+fn invoke_hook<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    hook_program: Option<Pubkey>,
+    token_settings_account_info: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    hook_instruction: HookInstruction,
+) -> ProgramResult {
+    let hook_program = match hook_program {
+        Some(hook_program) => hook_program,
+        None => return Ok(()),
+    };
+
+    if hook_program == *program_id {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (hook_authority_pubkey, hook_authority_nonce) =
+        Pubkey::find_program_address(&[br"hook"], program_id);
+    let hook_authority_signer_seeds: &[&[_]] = &[br"hook", &[hook_authority_nonce]];
+
+    if !accounts.iter().any(|a| *a.key == hook_program)
+        || !accounts.iter().any(|a| *a.key == hook_authority_pubkey)
+    {
+        msg!("Error: Hook program and hook authority accounts are required");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    invoke_signed(
+        &Instruction {
+            program_id: hook_program,
+            accounts: vec![
+                AccountMeta::new_readonly(hook_authority_pubkey, true),
+                AccountMeta::new_readonly(*token_settings_account_info.key, false),
+                AccountMeta::new_readonly(*account_info.key, false),
+            ],
+            data: hook_instruction.try_to_vec()?,
+        },
+        accounts,
+        &[hook_authority_signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Bridge events are built for standard EVER addresses only
+fn validate_ever_address(address: &EverAddress) -> ProgramResult {
+    if !address.is_standart() {
+        return Err(SolanaBridgeError::UnsupportedEverAddress.into());
+    }
+
+    Ok(())
+}
+
+fn unpack_token_account(
+    account_info: &AccountInfo,
+) -> Result<spl_token::state::Account, ProgramError> {
+    if *account_info.owner != spl_token::id() {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    spl_token::state::Account::unpack(&account_info.data.borrow())
+}
+
+fn unpack_mint_account(account_info: &AccountInfo) -> Result<spl_token::state::Mint, ProgramError> {
+    if *account_info.owner != spl_token::id() {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    spl_token::state::Mint::unpack(&account_info.data.borrow())
+}
+
+/// Count a withdrawal held for approval against the current withdrawal limits.
+///
+/// Its amount is already included in the daily amount if it was held in the current epoch.
+fn recount_withdrawal_limits(
+    token_settings_account_data: &mut TokenSettings,
+    withdrawal_epoch: i64,
+    current_epoch: i64,
+    amount: u64,
+) -> ProgramResult {
+    let mut tracker = LimitTracker::new(
+        &mut token_settings_account_data.withdrawal_epoch,
+        &mut token_settings_account_data.withdrawal_daily_amount,
+    );
+    tracker.roll_over(current_epoch);
+
+    if withdrawal_epoch != tracker.epoch() {
+        // Increase withdrawal daily amount
+        tracker.add(amount);
+    }
+
+    if amount > token_settings_account_data.withdrawal_limit
+        || tracker.exceeds(token_settings_account_data.withdrawal_daily_limit)
+    {
+        return Err(SolanaBridgeError::WithdrawalLimit.into());
+    }
+
+    Ok(())
+}
+
+/// Votes required to confirm a withdrawal of the amount in the round and whether the withdrawal
+/// manager has to approve it. Token requirements never lower the round quorum and are capped by
+/// the number of round relays.
+fn get_withdrawal_requirements(
+    relays_len: usize,
+    min_required_votes: u32,
+    token_settings: Option<&TokenSettings>,
+    amount: u128,
+) -> (u32, bool) {
+    let mut required_votes = (relays_len * 2 / 3 + 1) as u32;
+    if min_required_votes > required_votes {
+        required_votes = min_required_votes;
+    }
+
+    let token_settings = match token_settings {
+        Some(token_settings) => token_settings,
+        None => return (required_votes, false),
+    };
+
+    let mut token_required_votes = token_settings.required_votes_override.unwrap_or_default();
+    let mut approval_required = false;
+
+    if let Some(tier) = token_settings.confirmation_tier(amount) {
+        token_required_votes = token_required_votes
+            .max(required_votes)
+            .saturating_add(tier.extra_votes);
+        approval_required = tier.approval_required;
+    }
+
+    (
+        required_votes.max(token_required_votes.min(relays_len as u32)),
+        approval_required,
+    )
+}
+
+/// Settings of an EVER token keeping its withdrawal requirements. The token settings account
+/// has to be passed, there are no requirements until it is created.
+fn get_ever_token_settings(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    token: &EverAddress,
+) -> Result<Option<TokenSettings>, ProgramError> {
+    let token_settings_pubkey = get_associated_token_settings_ever_address(program_id, token);
+
+    find_token_settings(program_id, accounts, &token_settings_pubkey)
+}
+
+/// Token settings of the token withdrawn by the proposal, `None` if the token isn't deployed yet
+fn get_withdrawal_token_settings(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    withdrawal_account_info: &AccountInfo,
+) -> Result<Option<TokenSettings>, ProgramError> {
+    let data = withdrawal_account_info.data.borrow();
+
+    let token_settings_pubkey = match data.get(..ACCOUNT_DISCRIMINATOR_LEN) {
+        Some(discriminator) if discriminator == WithdrawalMultiTokenSol::DISCRIMINATOR => {
+            let withdrawal = WithdrawalMultiTokenSol::unpack_from_slice(&data)?;
+            let mint = &withdrawal.event.data.mint;
+
+            // Token namespaced by the event configuration is used if passed
+            let (namespaced_pubkey, _) = find_token_settings_sol_address(
+                program_id,
+                mint,
+                Some(&withdrawal.pda.event_configuration),
+            );
+
+            if accounts.iter().any(|a| *a.key == namespaced_pubkey) {
+                namespaced_pubkey
+            } else {
+                get_associated_token_settings_sol_address(program_id, mint)
+            }
+        }
+        Some(discriminator) if discriminator == WithdrawalMultiTokenEver::DISCRIMINATOR => {
+            let withdrawal = WithdrawalMultiTokenEver::unpack_from_slice(&data)?;
+            get_associated_token_settings_ever_address(program_id, &withdrawal.event.data.token)
+        }
+        // Bundles span several tokens, their pauses are checked at payout
+        Some(discriminator) if discriminator == WithdrawalMultiTokenBundle::DISCRIMINATOR => {
+            return Ok(None)
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    find_token_settings(program_id, accounts, &token_settings_pubkey)
+}
+
+/// Amount of the withdrawal counted in the round volume, the sum of its entries for bundles
+fn get_withdrawal_volume(withdrawal_account_info: &AccountInfo) -> Result<u128, ProgramError> {
+    let data = withdrawal_account_info.data.borrow();
+
+    let amount = match data.get(..ACCOUNT_DISCRIMINATOR_LEN) {
+        Some(discriminator) if discriminator == WithdrawalMultiTokenSol::DISCRIMINATOR => {
+            WithdrawalMultiTokenSol::unpack_from_slice(&data)?
+                .event
+                .data
+                .amount
+        }
+        Some(discriminator) if discriminator == WithdrawalMultiTokenEver::DISCRIMINATOR => {
+            WithdrawalMultiTokenEver::unpack_from_slice(&data)?
+                .event
+                .data
+                .amount
+        }
+        Some(discriminator) if discriminator == WithdrawalMultiTokenBundle::DISCRIMINATOR => {
+            WithdrawalMultiTokenBundle::unpack_from_slice(&data)?
+                .event
+                .data
+                .entries
+                .iter()
+                .try_fold(0u128, |sum, entry| sum.checked_add(entry.amount))
+                .ok_or(SolanaBridgeError::Overflow)?
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    Ok(amount)
+}
+
+fn find_token_settings(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    token_settings_pubkey: &Pubkey,
+) -> Result<Option<TokenSettings>, ProgramError> {
+    let token_settings_account_info = accounts
+        .iter()
+        .find(|a| a.key == token_settings_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if token_settings_account_info.owner != program_id {
+        return Ok(None);
+    }
+
+    let token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    Ok(Some(token_settings_account_data))
+}
+
+/// Whether the authority holds an active exemption from the per-transaction limits.
+///
+/// The exemption account is looked up among the instruction accounts, every use of it is
+/// recorded in the admin audit log.
+fn use_limit_exemption(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    authority: &Pubkey,
+    instruction_data: &[u8],
+) -> Result<bool, ProgramError> {
+    let exempt_account_pubkey = get_associated_exempt_account_address(program_id, authority);
+
+    let exempt_account_info = match accounts.iter().find(|a| *a.key == exempt_account_pubkey) {
+        Some(account_info) if account_info.owner == program_id => account_info,
+        _ => return Ok(false),
+    };
+
+    let exempt_account_data = ExemptAccount::unpack(&exempt_account_info.data.borrow())?;
+
+    if !exempt_account_data.is_active(Clock::get()?.unix_timestamp) {
+        return Ok(false);
+    }
+
+    bridge_utils::helper::append_admin_audit_log(
+        program_id,
+        accounts,
+        authority,
+        instruction_data,
+    )?;
+
+    Ok(true)
+}
+
+/// Fee of the withdrawal token at `index` in the event. It's computed once, when the withdrawal
+/// is booked, and stored in the meta, so later steps don't depend on the accounts they are passed.
+fn withdrawal_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    meta: &mut WithdrawalTokenMeta,
+    index: usize,
+    recipient: &Pubkey,
+    fee_info: &FeeInfo,
+    amount: u64,
+) -> Result<u64, ProgramError> {
+    if let Some(fee) = meta.fees.get(index) {
+        return Ok(*fee);
+    }
+
+    // Fees are booked in order of the tokens
+    if index != meta.fees.len() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let fee = 1.max(
+        amount
+            .checked_div(fee_info.divisor)
+            .ok_or(SolanaBridgeError::Overflow)?
+            .checked_mul(fee_info.multiplier)
+            .ok_or(SolanaBridgeError::Overflow)?,
+    );
+
+    // Stakers of the discount token pay a reduced fee
+    let fee = discount_withdrawal_fee(program_id, accounts, recipient, fee)?;
+
+    meta.fees.push(fee);
+
+    Ok(fee)
+}
+
+/// Withdrawal fee of the recipient after the discount of their stake. Discount applies if the
+/// Discount Config and the recipient's Discount Stake accounts are passed with the instruction.
+fn discount_withdrawal_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    recipient: &Pubkey,
+    fee: u64,
+) -> Result<u64, ProgramError> {
+    let discount_config_pubkey = get_associated_discount_config_address(program_id);
+    let discount_stake_pubkey = get_associated_discount_stake_address(program_id, recipient);
+
+    let discount_config_account_info =
+        match accounts.iter().find(|a| *a.key == discount_config_pubkey) {
+            Some(account_info) if account_info.owner == program_id => account_info,
+            _ => return Ok(fee),
+        };
+
+    let discount_stake_account_info =
+        match accounts.iter().find(|a| *a.key == discount_stake_pubkey) {
+            Some(account_info) if account_info.owner == program_id => account_info,
+            _ => return Ok(fee),
+        };
+
+    let discount_config_account_data =
+        DiscountConfig::unpack(&discount_config_account_info.data.borrow())?;
+    let discount_stake_account_data =
+        DiscountStake::unpack(&discount_stake_account_info.data.borrow())?;
+
+    Ok(discount_config_account_data.discounted_fee(fee, &discount_stake_account_data))
+}
+
+/// Validate Discount Config and its stake vault, returns the vault nonce
+fn validate_discount_accounts(
+    program_id: &Pubkey,
+    discount_config_account_info: &AccountInfo,
+    discount_vault_account_info: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    let discount_config_account_data =
+        DiscountConfig::unpack(&discount_config_account_info.data.borrow())?;
+
+    let (discount_config_nonce, discount_vault_nonce) = discount_config_account_data
+        .account_kind
+        .into_discount_config()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_discount_config_account(
+        program_id,
+        discount_config_nonce,
+        discount_config_account_info,
+    )?;
+    validate_discount_vault_account(
+        program_id,
+        discount_vault_nonce,
+        discount_vault_account_info,
+    )?;
+
+    Ok(discount_vault_nonce)
+}
+
+fn validate_discount_stake_account(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    discount_stake_account_info: &AccountInfo,
+) -> Result<DiscountStake, ProgramError> {
+    let discount_stake_account_data =
+        DiscountStake::unpack(&discount_stake_account_info.data.borrow())?;
+
+    let discount_stake_nonce = discount_stake_account_data
+        .account_kind
+        .into_discount_stake()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let discount_stake_pubkey = bridge_utils::helper::create_pda(
+        program_id,
+        &[br"discount_stake", &owner.to_bytes()],
+        discount_stake_nonce,
+    )?;
+
+    if discount_stake_pubkey != *discount_stake_account_info.key
+        || discount_stake_account_data.owner != *owner
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(discount_stake_account_data)
+}
+
+/// Signers of the instruction besides the authority
+fn get_cosigners(authority: &Pubkey, remaining_accounts: &[AccountInfo]) -> Vec<Pubkey> {
+    remaining_accounts
+        .iter()
+        .filter(|a| a.is_signer && a.key != authority)
+        .map(|a| *a.key)
+        .collect()
+}
+
+fn get_withdrawal_amount(
+    amount: u128,
+    ever_decimals: u8,
+    solana_decimals: u8,
+) -> Result<u64, ProgramError> {
+    let amount = if ever_decimals > solana_decimals {
+        let trunc_divisor = 10u128.pow((ever_decimals - solana_decimals) as u32);
+        amount
+            .checked_div(trunc_divisor)
+            .ok_or(SolanaBridgeError::Overflow)?
+    } else {
+        let trunc_multiplier = 10u128.pow((solana_decimals - ever_decimals) as u32);
+        amount
+            .checked_mul(trunc_multiplier)
+            .ok_or(SolanaBridgeError::Overflow)?
+    } as u64;
+
+    Ok(amount)
+}
+
+fn get_deposit_amount(
+    amount: u64,
+    ever_decimals: u8,
+    solana_decimals: u8,
+) -> Result<u128, ProgramError> {
+    let amount = if ever_decimals > solana_decimals {
+        let trunc_multiplier = 10u128.pow((ever_decimals - solana_decimals) as u32);
+        (amount as u128)
+            .checked_mul(trunc_multiplier)
+            .ok_or(SolanaBridgeError::Overflow)?
+    } else {
+        let trunc_divisor = 10u128.pow((solana_decimals - ever_decimals) as u32);
+        (amount as u128)
+            .checked_div(trunc_divisor)
+            .ok_or(SolanaBridgeError::Overflow)?
+    };
+
+    Ok(amount)
+}
+
+/// Amount in Solana decimals that `get_deposit_amount` scales to exactly `transfer_amount`
+fn get_pure_deposit_amount(
+    transfer_amount: u128,
+    ever_decimals: u8,
+    solana_decimals: u8,
+) -> Result<u64, ProgramError> {
+    let amount = if ever_decimals > solana_decimals {
+        let trunc_multiplier = 10u128.pow((ever_decimals - solana_decimals) as u32);
+
+        // Digits below Solana precision can't be delivered
+        if transfer_amount % trunc_multiplier != 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        transfer_amount / trunc_multiplier
+    } else {
+        let trunc_divisor = 10u128.pow((solana_decimals - ever_decimals) as u32);
+        transfer_amount
+            .checked_mul(trunc_divisor)
+            .ok_or(SolanaBridgeError::Overflow)?
+    };
+
+    u64::try_from(amount).map_err(|_| SolanaBridgeError::Overflow.into())
+}
+
+/// Smallest deposit amount that leaves `pure_amount` after the deposit fee
+fn get_gross_deposit_amount(pure_amount: u64, fee_info: &FeeInfo) -> Result<u64, ProgramError> {
+    // A fee taking the whole amount leaves nothing to deliver
+    if fee_info.multiplier >= fee_info.divisor {
+        return Err(SolanaBridgeError::InvalidFeeInfo.into());
+    }
+
+    // Iterate `amount = pure_amount + fee(amount)` up to its least fixed point
+    let mut amount = pure_amount;
+
+    loop {
+        let fee = 1.max(
+            amount
+                .checked_div(fee_info.divisor)
+                .ok_or(SolanaBridgeError::Overflow)?
+                .checked_mul(fee_info.multiplier)
+                .ok_or(SolanaBridgeError::Overflow)?,
+        );
+
+        let next_amount = pure_amount
+            .checked_add(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        if next_amount == amount {
+            return Ok(amount);
+        }
+
+        amount = next_amount;
+    }
+}
+
+fn delete_account(
+    account_info: &AccountInfo,
+    author_account_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let authority_starting_lamports = author_account_info.lamports();
+    **author_account_info.lamports.borrow_mut() = authority_starting_lamports
+        .checked_add(account_info.lamports())
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    **account_info.lamports.borrow_mut() = 0;
+
+    bridge_utils::helper::delete_account(account_info);
+
+    Ok(())
+}
+
+/// Check the deposit payload against the registered schema it declares
+fn validate_payload_schema(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    payload_schema: Option<u32>,
+    payload: &[u8],
+) -> ProgramResult {
+    let schema_id = match payload_schema {
+        Some(schema_id) => schema_id,
+        None => return Ok(()),
+    };
+
+    let payload_schema_pubkey = get_associated_payload_schema_address(program_id, schema_id);
+
+    let payload_schema_account_info = accounts
+        .iter()
+        .find(|a| *a.key == payload_schema_pubkey && a.owner == program_id)
+        .ok_or(SolanaBridgeError::PayloadSchemaNotRegistered)?;
+
+    let payload_schema_account_data =
+        PayloadSchema::unpack(&payload_schema_account_info.data.borrow())?;
+
+    if !payload_schema_account_data.matches(payload) {
+        return Err(SolanaBridgeError::PayloadSchemaMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Check that the mint has a whitelist account among the instruction accounts
+fn validate_token_whitelisted(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    mint: &Pubkey,
+) -> ProgramResult {
+    let token_whitelist_pubkey = get_associated_token_whitelist_address(program_id, mint);
+
+    let token_whitelist_account_info = accounts
+        .iter()
+        .find(|a| *a.key == token_whitelist_pubkey && a.owner == program_id)
+        .ok_or(SolanaBridgeError::TokenNotWhitelisted)?;
+
+    let token_whitelist_account_data =
+        TokenWhitelist::unpack(&token_whitelist_account_info.data.borrow())?;
+
+    if token_whitelist_account_data.mint != *mint {
+        return Err(SolanaBridgeError::TokenNotWhitelisted.into());
+    }
+
+    Ok(())
+}
+
+/// Check the token metadata supplied by a relay against the known-token mirror. The mirror
+/// account has to be among the instruction accounts, an empty one means the token isn't
+/// registered yet.
+fn validate_known_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    token: &EverAddress,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+) -> ProgramResult {
+    let known_token_pubkey = get_associated_known_token_address(program_id, token);
+
+    let known_token_account_info = accounts
+        .iter()
+        .find(|a| *a.key == known_token_pubkey)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    if known_token_account_info.owner != program_id {
+        return Ok(());
+    }
+
+    let known_token_account_data = KnownToken::unpack(&known_token_account_info.data.borrow())?;
+
+    if !known_token_account_data.matches(name, symbol, decimals) {
+        return Err(SolanaBridgeError::KnownTokenMismatch.into());
+    }
+
+    Ok(())
+}
+
+fn create_proxy_account<'a>(
+    program_id: &Pubkey,
+    recipient: &Pubkey,
+    funder_account_info: &AccountInfo<'a>,
+    proxy_account_info: &AccountInfo<'a>,
+    spl_token_mint_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    spl_token_program_info: &AccountInfo<'a>,
+) -> Result<u8, ProgramError> {
+    let spl_token_program_id = spl_token_program_info.key;
+
+    let (proxy_pubkey, nonce) = Pubkey::find_program_address(
+        &[
+            br"proxy",
+            &spl_token_mint_info.key.to_bytes(),
+            &recipient.to_bytes(),
+        ],
+        program_id,
+    );
+
+    if proxy_pubkey != *proxy_account_info.key {
+        msg!("Error: Associated proxy address does not match seed derivation");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let proxy_signer_seeds: &[&[_]] = &[
+        br"proxy",
+        &spl_token_mint_info.key.to_bytes(),
+        &recipient.to_bytes(),
+        &[nonce],
+    ];
+
+    if *proxy_account_info.owner != system_program::id() {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    if proxy_account_info.data_is_empty() {
+        let rent = Rent::get()?;
+
+        let account_len = spl_token::state::Account::LEN;
+        /*let account_len = spl_associated_token_account::tools::account::get_account_len(
+            spl_token_mint_info,
+            spl_token_program_info,
+            &[spl_token_2022::extension::ExtensionType::ImmutableOwner],
+        )?;*/
+
+        spl_associated_token_account::tools::account::create_pda_account(
+            funder_account_info,
+            &rent,
+            account_len,
+            spl_token_program_id,
+            system_program_info,
+            proxy_account_info,
+            proxy_signer_seeds,
+        )?;
+
+        msg!("Initialize the proxy account");
+        invoke(
+            &spl_token::instruction::initialize_immutable_owner(
+                spl_token_program_id,
+                proxy_account_info.key,
+            )?,
+            &[proxy_account_info.clone(), spl_token_program_info.clone()],
+        )?;
+        invoke(
+            &spl_token::instruction::initialize_account3(
+                spl_token_program_id,
+                proxy_account_info.key,
+                spl_token_mint_info.key,
+                proxy_account_info.key,
+            )?,
+            &[
+                proxy_account_info.clone(),
+                spl_token_mint_info.clone(),
+                spl_token_program_info.clone(),
+            ],
+        )?;
+    }
+
+    Ok(nonce)
+}
+
+/// Create the deposit account of the sender, returns the nonce of the account.
+///
+/// Deposits are derived from the sender and the deposit seed, so senders can't collide on a seed
+/// and clients can enumerate the deposits of a sender. The legacy address derived from the seed
+/// only is accepted while clients migrate.
+#[allow(clippy::too_many_arguments)]
+fn create_deposit_account<'a>(
+    program_id: &Pubkey,
+    sender: &Pubkey,
+    deposit_seed: u128,
+    ever_configuration: Option<&Pubkey>,
+    deposit_len: usize,
+    rent: &Rent,
+    funder_account_info: &AccountInfo<'a>,
+    deposit_account_info: &AccountInfo<'a>,
+    accounts: &[AccountInfo<'a>],
+) -> Result<u8, ProgramError> {
+    let seed = deposit_seed.to_le_bytes();
+
+    let (deposit_pubkey, deposit_nonce) =
+        find_deposit_address(program_id, sender, deposit_seed, ever_configuration);
+
+    // Legacy addresses are only accepted in the default namespace
+    let (deposit_nonce, legacy) = if deposit_pubkey == *deposit_account_info.key {
+        (deposit_nonce, false)
+    } else if ever_configuration.is_none() {
+        let (legacy_deposit_pubkey, legacy_deposit_nonce) =
+            Pubkey::find_program_address(&[br"deposit", &seed], program_id);
+
+        if legacy_deposit_pubkey != *deposit_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        (legacy_deposit_nonce, true)
+    } else {
+        return Err(ProgramError::InvalidArgument);
+    };
+
+    let sender = sender.to_bytes();
+    let nonce = [deposit_nonce];
+    let ever_configuration =
+        ever_configuration.map(|ever_configuration| ever_configuration.to_bytes());
+    let deposit_account_signer_seeds: &[&[_]] = match (legacy, &ever_configuration) {
+        (true, _) => &[br"deposit", &seed, &nonce],
+        (false, Some(ever_configuration)) => {
+            &[br"deposit", &sender, &seed, ever_configuration, &nonce]
+        }
+        (false, None) => &[br"deposit", &sender, &seed, &nonce],
+    };
+
+    invoke_signed(
+        &system_instruction::create_account(
+            funder_account_info.key,
+            deposit_account_info.key,
+            1.max(rent.minimum_balance(deposit_len)),
+            deposit_len as u64,
+            program_id,
+        ),
+        accounts,
+        &[deposit_account_signer_seeds],
+    )?;
+
+    Ok(deposit_nonce)
+}
+
+/// Mark the EVER event as represented by the withdrawal.
+///
+/// Fails if the event is already marked with another withdrawal, so a single event can't
+/// be withdrawn twice through accounts that differ in round number or event data.
+#[allow(clippy::too_many_arguments)]
+fn create_event_index_account<'a>(
+    program_id: &Pubkey,
+    withdrawal_pubkey: &Pubkey,
+    pda: &PDA,
+    rent: &Rent,
+    funder_account_info: &AccountInfo<'a>,
+    event_index_account_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+) -> ProgramResult {
+    let (event_index_pubkey, event_index_nonce) = Pubkey::find_program_address(
+        &[
+            br"event_index",
+            &pda.event_timestamp.to_le_bytes(),
+            &pda.event_transaction_lt.to_le_bytes(),
+            &pda.event_configuration.to_bytes(),
+        ],
+        program_id,
+    );
+    let event_index_account_signer_seeds: &[&[_]] = &[
+        br"event_index",
+        &pda.event_timestamp.to_le_bytes(),
+        &pda.event_transaction_lt.to_le_bytes(),
+        &pda.event_configuration.to_bytes(),
+        &[event_index_nonce],
+    ];
+
+    if event_index_pubkey != *event_index_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if event_index_account_info.owner == program_id {
+        let event_index_account_data = EventIndex::unpack(&event_index_account_info.data.borrow())?;
+
+        if event_index_account_data.withdrawal != *withdrawal_pubkey {
+            return Err(SolanaBridgeError::EventAlreadyProcessed.into());
+        }
+
+        return Ok(());
+    }
+
+    // Tops up lamports sent to the address before the account is created
+    spl_associated_token_account::tools::account::create_pda_account(
+        funder_account_info,
+        rent,
+        EventIndex::LEN,
+        program_id,
+        system_program_info,
+        event_index_account_info,
+        event_index_account_signer_seeds,
+    )?;
+
+    let event_index_account_data = EventIndex {
+        is_initialized: true,
+        account_kind: AccountKind::EventIndex(event_index_nonce),
+        withdrawal: *withdrawal_pubkey,
+    };
+
+    EventIndex::pack(
+        event_index_account_data,
+        &mut event_index_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
+
+/// Log the event along with the next event sequence number.
+///
+/// The sequence number is kept in Settings and logged as the second data field, so off-chain
+/// consumers can detect missed events and backfill them by number.
+fn emit_event<T: BorshSerialize>(settings_account_info: &AccountInfo, event: &T) -> ProgramResult {
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    settings_account_data.event_seq = settings_account_data
+        .event_seq
+        .checked_add(1)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    let event_seq = settings_account_data.event_seq;
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    solana_program::log::sol_log_data(&[&event.try_to_vec()?, &event_seq.to_le_bytes()]);
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn create_token_settings_sol_account<'a>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'a>],
+    funder_account_info: &AccountInfo<'a>,
+    mint_account_info: &AccountInfo<'a>,
+    vault_account_info: &AccountInfo<'a>,
+    token_settings_account_info: &AccountInfo<'a>,
+    settings_account_info: &AccountInfo<'a>,
+    rent: &Rent,
+    name: String,
+    symbol: String,
+    allow_freeze_authority: bool,
+    ever_configuration: Option<Pubkey>,
+) -> ProgramResult {
+    // Validate Mint Account
+    let mint_account_data = unpack_mint_account(mint_account_info)?;
+
+    // Freeze authority is able to freeze the vault
+    let freeze_risk = mint_account_data.freeze_authority.is_some();
+
+    if freeze_risk && !allow_freeze_authority {
+        return Err(SolanaBridgeError::MintFreezeAuthority.into());
+    }
+
+    // Create Vault Account
+    let (vault_pubkey, vault_nonce) = find_vault_address(
+        program_id,
+        mint_account_info.key,
+        ever_configuration.as_ref(),
+        0,
+    );
+
+    if vault_pubkey != *vault_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    create_vault_account(
+        accounts,
+        funder_account_info,
+        mint_account_info,
+        vault_account_info,
+        ever_configuration.as_ref(),
+        0,
+        vault_nonce,
+        rent,
+    )?;
+
+    // Create Token Settings Account
+    let (token_settings_pubkey, token_settings_nonce) = find_token_settings_sol_address(
+        program_id,
+        mint_account_info.key,
+        ever_configuration.as_ref(),
+    );
+
+    let mut seeds =
+        get_token_settings_sol_seeds(mint_account_info.key, ever_configuration.as_ref());
+    seeds.push(vec![token_settings_nonce]);
+
+    let token_settings_account_signer_seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let token_settings_account_signer_seeds = token_settings_account_signer_seeds.as_slice();
+
+    if token_settings_pubkey != *token_settings_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    bridge_utils::helper::create_pda_account(
+        funder_account_info,
+        token_settings_account_info,
+        rent,
+        TokenSettings::LEN,
+        program_id,
+        accounts,
+        token_settings_account_signer_seeds,
+    )?;
+
+    // Init Settings Account
+    let token_settings_account_data = TokenSettings {
+        is_initialized: true,
+        account_kind: AccountKind::TokenSettings(token_settings_nonce, vault_nonce),
+        kind: TokenKind::Solana {
+            mint: *mint_account_info.key,
+            vault: *vault_account_info.key,
+        },
+        name: name.clone(),
+        symbol: symbol.clone(),
+        withdrawal_epoch: 0,
+        deposit_limit: u64::MAX,
+        withdrawal_limit: u64::MAX,
+        withdrawal_daily_limit: u64::MAX,
+        withdrawal_daily_amount: 0,
+        emergency: false,
+        fee_supply: Default::default(),
+        fee_deposit_info: Default::default(),
+        fee_withdrawal_info: Default::default(),
+        hook_program: None,
+        freeze_risk,
+        vault_generation: 0,
+        lp_supply: 0,
+        lp_liquidity: 0,
+        yield_adapter: None,
+        yield_adapter_unlock_time: 0,
+        max_deployed_share: 0,
+        deployed_amount: 0,
+        last_rewarded_round: None,
+        required_votes_override: None,
+        confirmation_tiers: vec![],
+        paused_operations: 0,
+        scheduled_limit_change: None,
+        ever_configuration,
+    };
+
+    emit_event(
+        settings_account_info,
+        &TokenSettingsEvent {
+            account: token_settings_pubkey,
+            symbol: token_settings_account_data.symbol.clone(),
+            name: token_settings_account_data.name.clone(),
+            mint: *mint_account_info.key,
+            vault: Some(*vault_account_info.key),
+            ever_decimals: None,
+            solana_decimals: None,
+            root: None,
+            fee: Default::default(),
+        },
+    )?;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::set_return_data;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use super::{get_deposit_amount, unpack_mint_account, unpack_token_account};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct PreviewDepositAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    pub creator_token_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, PreviewDepositAccounts<'a, 'info>>,
+    amount: u64,
+    value: u64,
+) -> ProgramResult {
+    let PreviewDepositAccounts {
+        funder_account_info,
+        creator_token_account_info,
+        mint_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Mint Account
+    let mint_account_data = unpack_mint_account(mint_account_info)?;
+    let solana_decimals = mint_account_data.decimals;
+
+    let mut required_lamports = value;
+
+    // Validate Token Settings Account
+    let (token_emergency, deposit_paused, deposit_limit, fee_info, ever_decimals) =
+        if token_settings_account_info.lamports() == 0 {
+            // Token settings and vault of a new SOL token are created with the first deposit
+            if get_associated_token_settings_sol_address(program_id, mint_account_info.key)
+                != *token_settings_account_info.key
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            required_lamports = required_lamports
+                .checked_add(rent.minimum_balance(TokenSettings::LEN))
+                .and_then(|l| l.checked_add(rent.minimum_balance(spl_token::state::Account::LEN)))
+                .and_then(|l| l.checked_add(rent.minimum_balance(DepositMultiTokenSol::LEN)))
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            (false, false, Some(u64::MAX), FeeInfo::default(), None)
+        } else {
+            let token_settings_account_data =
+                TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+            let (token_settings_nonce, _) = token_settings_account_data
+                .account_kind
+                .into_token_settings()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            match token_settings_account_data.kind {
+                TokenKind::Ever {
+                    mint,
+                    token,
+                    decimals,
+                } => {
+                    validate_token_settings_ever_account(
+                        program_id,
+                        &token,
+                        token_settings_nonce,
+                        token_settings_account_info,
+                    )?;
+
+                    if *mint_account_info.key != mint {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+
+                    required_lamports = required_lamports
+                        .checked_add(rent.minimum_balance(DepositMultiTokenEver::LEN))
+                        .ok_or(SolanaBridgeError::Overflow)?;
+
+                    (
+                        token_settings_account_data.emergency,
+                        token_settings_account_data.is_paused(TokenOperation::Deposit),
+                        None,
+                        token_settings_account_data.fee_deposit_info,
+                        Some(decimals),
+                    )
+                }
+                TokenKind::Solana { mint, vault } => {
+                    validate_token_settings_sol_account(
+                        program_id,
+                        &mint,
+                        token_settings_account_data.ever_configuration.as_ref(),
+                        token_settings_nonce,
+                        token_settings_account_info,
+                    )?;
+
+                    if *mint_account_info.key != mint {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+
+                    // Validate Vault Account
+                    let vault_account_info = next_account_info(account_info_iter)?;
+
+                    if *vault_account_info.key != vault {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+
+                    let vault_account_data = unpack_token_account(vault_account_info)?;
+
+                    required_lamports = required_lamports
+                        .checked_add(rent.minimum_balance(DepositMultiTokenSol::LEN))
+                        .ok_or(SolanaBridgeError::Overflow)?;
+
+                    // Part of the deposit limit not taken by tokens locked in the vault
+                    let deposit_limit = token_settings_account_data.deposit_limit.saturating_sub(
+                        token_settings_account_data.locked_amount(vault_account_data.amount),
+                    );
+
+                    (
+                        token_settings_account_data.emergency,
+                        token_settings_account_data.is_paused(TokenOperation::Deposit),
+                        Some(deposit_limit),
+                        token_settings_account_data.fee_deposit_info,
+                        None,
+                    )
+                }
+            }
+        };
+
+    // Validate Creator Token Account
+    let creator_token_balance = if creator_token_account_info.lamports() == 0 {
+        None
+    } else {
+        let creator_token_account_data = unpack_token_account(creator_token_account_info)?;
+
+        if creator_token_account_data.mint != *mint_account_info.key {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Some(creator_token_account_data.amount)
+    };
+
+    // Calculate fee and transfer amount the same way the deposit does
+    let fee = 1.max(
+        amount
+            .checked_div(fee_info.divisor)
+            .ok_or(SolanaBridgeError::Overflow)?
+            .checked_mul(fee_info.multiplier)
+            .ok_or(SolanaBridgeError::Overflow)?,
+    );
+
+    let pure_amount = amount.checked_sub(fee);
+
+    let transfer_amount = match (pure_amount, ever_decimals) {
+        (Some(pure_amount), Some(ever_decimals)) => {
+            get_deposit_amount(pure_amount, ever_decimals, solana_decimals)?
+        }
+        (Some(pure_amount), None) => pure_amount.into(),
+        (None, _) => 0,
+    };
+
+    // Checks are made in the order the deposit makes them
+    let status = if settings_account_data.emergency {
+        DepositPreviewStatus::EmergencyEnabled
+    } else if token_emergency {
+        DepositPreviewStatus::TokenEmergencyEnabled
+    } else if deposit_paused {
+        DepositPreviewStatus::DepositPaused
+    } else if creator_token_balance.is_none() {
+        DepositPreviewStatus::TokenAccountMissing
+    } else if creator_token_balance < Some(amount) {
+        DepositPreviewStatus::InsufficientTokenBalance
+    } else if pure_amount.is_none() {
+        DepositPreviewStatus::AmountTooSmall
+    } else if deposit_limit.is_some_and(|limit| amount > limit) {
+        DepositPreviewStatus::DepositLimit
+    } else if funder_account_info.lamports() < required_lamports {
+        DepositPreviewStatus::InsufficientFunds
+    } else {
+        DepositPreviewStatus::Ok
+    };
+
+    set_return_data(
+        &DepositPreview {
+            status,
+            fee,
+            transfer_amount,
+            required_lamports,
+        }
+        .try_to_vec()?,
+    );
+
+    // Never let the preview be confused with a deposit
+    Err(SolanaBridgeError::DepositPreview.into())
+}
@@ -0,0 +1,154 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use super::{emit_event, invoke_yield_adapter, unpack_token_account};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RecallLiquidityAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    pub admin_audit_log_account_info: &'a AccountInfo<'info>,
+    pub yield_adapter_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, RecallLiquidityAccounts<'a, 'info>>,
+    amount: u64,
+) -> ProgramResult {
+    let RecallLiquidityAccounts {
+        authority_account_info,
+        token_settings_account_info,
+        vault_account_info,
+        settings_account_info,
+        programdata_account_info,
+        yield_adapter_program_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let adapter_account_infos = ctx.remaining_accounts;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Token Settings Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (mint, vault) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (token_settings_nonce, _) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    // Validate Yield Adapter. Recall is not time locked.
+    let yield_adapter = token_settings_account_data
+        .yield_adapter
+        .ok_or(ProgramError::InvalidArgument)?;
+
+    if *yield_adapter_program_info.key != yield_adapter {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let deployed_amount = token_settings_account_data
+        .deployed_amount
+        .checked_sub(amount)
+        .ok_or(ProgramError::InvalidArgument)?;
+
+    // Validate Vault Account
+    if *vault_account_info.key != vault {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let vault_amount = unpack_token_account(vault_account_info)?.amount;
+
+    invoke_yield_adapter(
+        accounts,
+        &yield_adapter,
+        vault_account_info,
+        token_settings_account_info,
+        adapter_account_infos,
+        YieldAdapterInstruction::Withdraw { amount },
+        &token_settings_account_data,
+    )?;
+
+    // Adapter must return at least the recalled amount, the rest is yield
+    let returned_amount = unpack_token_account(vault_account_info)?
+        .amount
+        .checked_sub(vault_amount)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    let yield_amount = returned_amount
+        .checked_sub(amount)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    token_settings_account_data.deployed_amount = deployed_amount;
+    token_settings_account_data.fee_supply = token_settings_account_data
+        .fee_supply
+        .checked_add(yield_amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    emit_event(
+        settings_account_info,
+        &RecallLiquidityEvent {
+            token_settings: *token_settings_account_info.key,
+            yield_adapter,
+            amount,
+            yield_amount,
+        },
+    )?;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::token_cpi;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use super::{emit_event, get_cosigners, sign_by_vault, unpack_token_account};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RecoverAuthorityAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub vault_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub new_vault_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, RecoverAuthorityAccounts<'a, 'info>>,
+    new_program_id: Pubkey,
+) -> ProgramResult {
+    let RecoverAuthorityAccounts {
+        authority_account_info,
+        token_settings_account_info,
+        mint_account_info,
+        vault_account_info,
+        new_vault_account_info,
+        settings_account_info,
+        programdata_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Funds leave the deployment only while it is stopped
+    if !settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyDisabled.into());
+    }
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Co-signers
+    let cosigners = get_cosigners(authority_account_info.key, ctx.remaining_accounts);
+
+    if !settings_account_data.is_emergency_cosigned(&cosigners) {
+        return Err(SolanaBridgeError::NotEnoughCosigners.into());
+    }
+
+    // Validate Token Settings Account
+    let token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (mint, vault) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (token_settings_nonce, _) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    // Validate Mint Account
+    if *mint_account_info.key != mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Vault Account
+    if *vault_account_info.key != vault {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let vault_account_data = unpack_token_account(vault_account_info)?;
+
+    // Deployed liquidity is returned to the vault it was taken from
+    if token_settings_account_data.deployed_amount != 0 {
+        return Err(SolanaBridgeError::LiquidityDeployed.into());
+    }
+
+    // Validate New Vault Account
+    if new_program_id == *program_id {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (new_vault_pubkey, _) = find_vault_address(
+        &new_program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        0,
+    );
+
+    if new_vault_pubkey != *new_vault_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let new_vault_account_data = unpack_token_account(new_vault_account_info)?;
+
+    if new_vault_account_data.mint != mint || new_vault_account_data.owner != new_vault_pubkey {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Move vault balance
+    let amount = vault_account_data.amount;
+
+    sign_by_vault(&token_settings_account_data, |signer_seeds| {
+        token_cpi::transfer(
+            vault_account_info,
+            new_vault_account_info,
+            vault_account_info,
+            amount,
+            accounts,
+            signer_seeds,
+        )
+    })?;
+
+    emit_event(
+        settings_account_info,
+        &RecoverAuthorityEvent {
+            token_settings: *token_settings_account_info.key,
+            vault,
+            new_program_id,
+            new_vault: new_vault_pubkey,
+            amount,
+        },
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use super::{create_vault_account, emit_event, unpack_token_account};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RecoverFrozenVaultAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    pub new_vault_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, RecoverFrozenVaultAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let RecoverFrozenVaultAccounts {
+        authority_account_info,
+        funder_account_info,
+        token_settings_account_info,
+        mint_account_info,
+        vault_account_info,
+        new_vault_account_info,
+        settings_account_info,
+        programdata_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Token Settings Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (mint, vault) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (token_settings_nonce, _) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    // Validate Mint Account
+    if *mint_account_info.key != mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Vault Account
+    if *vault_account_info.key != vault {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let vault_account_data = unpack_token_account(vault_account_info)?;
+
+    if !vault_account_data.is_frozen() {
+        return Err(SolanaBridgeError::VaultNotFrozen.into());
+    }
+
+    // Create New Vault Account
+    let generation = token_settings_account_data
+        .vault_generation
+        .checked_add(1)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    let (new_vault_pubkey, new_vault_nonce) = find_vault_address(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        generation,
+    );
+
+    if new_vault_pubkey != *new_vault_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    create_vault_account(
+        accounts,
+        funder_account_info,
+        mint_account_info,
+        new_vault_account_info,
+        token_settings_account_data.ever_configuration.as_ref(),
+        generation,
+        new_vault_nonce,
+        rent,
+    )?;
+
+    // Switch Token Settings to the new vault
+    token_settings_account_data.kind = TokenKind::Solana {
+        mint,
+        vault: new_vault_pubkey,
+    };
+    token_settings_account_data.account_kind =
+        AccountKind::TokenSettings(token_settings_nonce, new_vault_nonce);
+    token_settings_account_data.vault_generation = generation;
+
+    emit_event(
+        settings_account_info,
+        &RecoverFrozenVaultEvent {
+            token_settings: *token_settings_account_info.key,
+            frozen_vault: vault,
+            frozen_amount: vault_account_data.amount,
+            vault: new_vault_pubkey,
+        },
+    )?;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
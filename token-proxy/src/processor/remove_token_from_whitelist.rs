@@ -0,0 +1,92 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use super::delete_account;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RemoveTokenFromWhitelistAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_whitelist_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, RemoveTokenFromWhitelistAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    mint: Pubkey,
+) -> ProgramResult {
+    let RemoveTokenFromWhitelistAccounts {
+        authority_account_info,
+        token_whitelist_account_info,
+        settings_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Validate Token Whitelist Account
+    if token_whitelist_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let token_whitelist_account_data =
+        TokenWhitelist::unpack(&token_whitelist_account_info.data.borrow())?;
+
+    let token_whitelist_nonce = token_whitelist_account_data
+        .account_kind
+        .into_token_whitelist()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let token_whitelist_pubkey = Pubkey::create_program_address(
+        &[br"whitelist", &mint.to_bytes(), &[token_whitelist_nonce]],
+        program_id,
+    )?;
+
+    if token_whitelist_pubkey != *token_whitelist_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Close Token Whitelist Account
+    delete_account(token_whitelist_account_info, authority_account_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::validate_discount_stake_account;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RequestDiscountUnstakeAccounts<'a, 'info> {
+    #[account(signer)]
+    pub owner_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub discount_stake_account_info: &'a AccountInfo<'info>,
+    pub discount_config_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, RequestDiscountUnstakeAccounts<'a, 'info>>,
+    amount: u64,
+) -> ProgramResult {
+    let RequestDiscountUnstakeAccounts {
+        owner_account_info,
+        discount_stake_account_info,
+        discount_config_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    let clock = Clock::get()?;
+
+    // Validate Discount Config Account
+    let discount_config_account_data =
+        DiscountConfig::unpack(&discount_config_account_info.data.borrow())?;
+
+    let (discount_config_nonce, _) = discount_config_account_data
+        .account_kind
+        .into_discount_config()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_discount_config_account(
+        program_id,
+        discount_config_nonce,
+        discount_config_account_info,
+    )?;
+
+    // Validate Discount Stake Account
+    let mut discount_stake_account_data = validate_discount_stake_account(
+        program_id,
+        owner_account_info.key,
+        discount_stake_account_info,
+    )?;
+
+    if amount == 0 || amount > discount_stake_account_data.active_amount() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    discount_stake_account_data.unstaking_amount += amount;
+    discount_stake_account_data.unstake_available_at = clock
+        .unix_timestamp
+        .checked_add(discount_config_account_data.unstake_cooldown)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    DiscountStake::pack(
+        discount_stake_account_data,
+        &mut discount_stake_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
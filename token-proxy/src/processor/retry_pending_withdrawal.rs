@@ -0,0 +1,448 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    emit_event, get_withdrawal_amount, invoke_hook, make_ever_transfer, make_sol_transfer,
+    recount_withdrawal_limits, release_round_withdrawal, unpack_mint_account, unpack_token_account,
+    withdrawal_fee,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RetryPendingWithdrawalAccounts<'a, 'info> {
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub recipient_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, RetryPendingWithdrawalAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let RetryPendingWithdrawalAccounts {
+        withdrawal_account_info,
+        recipient_account_info,
+        token_settings_account_info,
+        mint_account_info,
+        settings_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let clock = Clock::get()?;
+    let current_epoch = LimitTracker::epoch_of(clock.unix_timestamp);
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Token Setting Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+    let hook_program = token_settings_account_data.hook_program;
+
+    // Apply the scheduled limits once effective
+    token_settings_account_data.apply_scheduled_limit_change(clock.unix_timestamp);
+
+    let (token_settings_nonce, mint_or_vault_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if token_settings_account_data.is_paused(TokenOperation::Payout) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    let payout = match token_settings_account_data.kind {
+        TokenKind::Ever { token, .. } => {
+            validate_token_settings_ever_account(
+                program_id,
+                &token,
+                token_settings_nonce,
+                token_settings_account_info,
+            )?;
+
+            // Validate Mint Account
+            validate_mint_account(program_id, &token, mint_or_vault_nonce, mint_account_info)?;
+
+            // Validate Withdrawal Account
+            let mut withdrawal_account_data =
+                WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
+            let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+            let (nonce, _) = withdrawal_account_data
+                .account_kind
+                .into_proposal()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+                program_id,
+                withdrawal_account_data.round_number,
+                withdrawal_account_data.pda.event_timestamp,
+                withdrawal_account_data.pda.event_transaction_lt,
+                &withdrawal_account_data.pda.event_configuration,
+                &event_data,
+                nonce,
+                withdrawal_account_info,
+            )?;
+
+            if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove
+            {
+                return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+            }
+
+            // Check connection between token and proposal
+            if token != withdrawal_account_data.event.data.token {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let mint_account_data = unpack_mint_account(mint_account_info)?;
+
+            let withdrawal_amount = get_withdrawal_amount(
+                withdrawal_account_data.event.data.amount,
+                withdrawal_account_data.event.data.decimals,
+                mint_account_data.decimals,
+            )?;
+
+            let fee = withdrawal_fee(
+                program_id,
+                accounts,
+                &mut withdrawal_account_data.meta.data,
+                0,
+                &withdrawal_account_data.event.data.recipient,
+                &token_settings_account_data.fee_withdrawal_info,
+                withdrawal_amount,
+            )?;
+
+            // Amount without fee
+            let transfer_withdrawal_amount = withdrawal_amount
+                .checked_sub(fee)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            recount_withdrawal_limits(
+                &mut token_settings_account_data,
+                withdrawal_account_data.meta.data.epoch,
+                current_epoch,
+                transfer_withdrawal_amount,
+            )?;
+
+            match withdrawal_account_data.event.data.payload.is_empty() {
+                true => {
+                    // Validate Recipient Account
+                    let recipient_account_data = unpack_token_account(recipient_account_info)?;
+
+                    if recipient_account_data.owner != withdrawal_account_data.event.data.recipient
+                    {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+
+                    make_ever_transfer(
+                        mint_account_info,
+                        recipient_account_info,
+                        &token_settings_account_data,
+                        accounts,
+                        transfer_withdrawal_amount,
+                    )?;
+
+                    withdrawal_account_data
+                        .meta
+                        .data
+                        .transition(WithdrawalTokenStatus::Processed, &clock)?;
+                }
+                false => {
+                    // Validate Proxy Account
+                    let proxy_nonce = withdrawal_account_data
+                        .account_kind
+                        .into_proposal()
+                        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                        .1
+                        .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                    validate_proxy_account(
+                        program_id,
+                        mint_account_info.key,
+                        &withdrawal_account_data.event.data.recipient,
+                        proxy_nonce,
+                        recipient_account_info,
+                    )?;
+
+                    make_ever_transfer(
+                        mint_account_info,
+                        recipient_account_info,
+                        &token_settings_account_data,
+                        accounts,
+                        transfer_withdrawal_amount,
+                    )?;
+
+                    withdrawal_account_data
+                        .meta
+                        .data
+                        .transition(WithdrawalTokenStatus::WaitingForExecute, &clock)?;
+                }
+            }
+
+            if withdrawal_account_data.meta.data.status.is_final() {
+                release_round_withdrawal(
+                    program_id,
+                    accounts,
+                    withdrawal_account_data.round_number,
+                )?;
+            }
+
+            let payout = match withdrawal_account_data.meta.data.status
+                == WithdrawalTokenStatus::Processed
+            {
+                true => Some(HookInstruction::Withdrawal {
+                    mint: *mint_account_info.key,
+                    recipient: withdrawal_account_data.event.data.recipient,
+                    amount: transfer_withdrawal_amount,
+                    event: withdrawal_account_data.event.data.try_to_vec()?,
+                }),
+                false => None,
+            };
+
+            emit_event(
+                settings_account_info,
+                &UpdateWithdrawalStatusEvent {
+                    account: withdrawal_pubkey,
+                    status: withdrawal_account_data.meta.data.status,
+                    quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+                    processed_at: withdrawal_account_data.meta.data.processed_at,
+                },
+            )?;
+
+            WithdrawalMultiTokenEver::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+
+            payout
+        }
+        TokenKind::Solana { mint, .. } => {
+            let vault_account_info = next_account_info(account_info_iter)?;
+
+            validate_token_settings_sol_account(
+                program_id,
+                &mint,
+                token_settings_account_data.ever_configuration.as_ref(),
+                token_settings_nonce,
+                token_settings_account_info,
+            )?;
+
+            // Validate Vault Account
+            validate_vault_account(
+                program_id,
+                &mint,
+                token_settings_account_data.ever_configuration.as_ref(),
+                token_settings_account_data.vault_generation,
+                mint_or_vault_nonce,
+                vault_account_info,
+            )?;
+
+            // Validate Mint Account
+            if *mint_account_info.key != mint {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            // Validate Withdrawal Account
+            let mut withdrawal_account_data =
+                WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+            let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+            let (nonce, _) = withdrawal_account_data
+                .account_kind
+                .into_proposal()
+                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+            let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+                program_id,
+                withdrawal_account_data.round_number,
+                withdrawal_account_data.pda.event_timestamp,
+                withdrawal_account_data.pda.event_transaction_lt,
+                &withdrawal_account_data.pda.event_configuration,
+                &event_data,
+                nonce,
+                withdrawal_account_info,
+            )?;
+
+            if withdrawal_account_data.meta.data.status != WithdrawalTokenStatus::WaitingForApprove
+            {
+                return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+            }
+
+            // Check connection between token and proposal
+            if mint != withdrawal_account_data.event.data.mint {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let withdrawal_amount = withdrawal_account_data.event.data.amount as u64;
+
+            let fee = withdrawal_fee(
+                program_id,
+                accounts,
+                &mut withdrawal_account_data.meta.data,
+                0,
+                &withdrawal_account_data.event.data.recipient,
+                &token_settings_account_data.fee_withdrawal_info,
+                withdrawal_amount,
+            )?;
+
+            // Amount without fee
+            let transfer_withdrawal_amount = withdrawal_amount
+                .checked_sub(fee)
+                .ok_or(SolanaBridgeError::Overflow)?;
+
+            recount_withdrawal_limits(
+                &mut token_settings_account_data,
+                withdrawal_account_data.meta.data.epoch,
+                current_epoch,
+                transfer_withdrawal_amount,
+            )?;
+
+            match withdrawal_account_data.event.data.payload.is_empty() {
+                true => {
+                    // Validate Recipient Account
+                    let recipient_account_data = unpack_token_account(recipient_account_info)?;
+
+                    if recipient_account_data.owner != withdrawal_account_data.event.data.recipient
+                    {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+                }
+                false => {
+                    // Validate Proxy Account
+                    let proxy_nonce = withdrawal_account_data
+                        .account_kind
+                        .into_proposal()
+                        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                        .1
+                        .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                    validate_proxy_account(
+                        program_id,
+                        &mint,
+                        &withdrawal_account_data.event.data.recipient,
+                        proxy_nonce,
+                        recipient_account_info,
+                    )?;
+                }
+            }
+
+            let vault_account_data = unpack_token_account(vault_account_info)?;
+
+            if transfer_withdrawal_amount > vault_account_data.amount {
+                withdrawal_account_data
+                    .meta
+                    .data
+                    .transition(WithdrawalTokenStatus::Pending, &clock)?;
+            } else {
+                make_sol_transfer(
+                    vault_account_info,
+                    recipient_account_info,
+                    &token_settings_account_data,
+                    accounts,
+                    transfer_withdrawal_amount,
+                )?;
+
+                withdrawal_account_data.meta.data.transition(
+                    match withdrawal_account_data.event.data.payload.is_empty() {
+                        true => WithdrawalTokenStatus::Processed,
+                        false => WithdrawalTokenStatus::WaitingForExecute,
+                    },
+                    &clock,
+                )?;
+            }
+
+            if withdrawal_account_data.meta.data.status.is_final() {
+                release_round_withdrawal(
+                    program_id,
+                    accounts,
+                    withdrawal_account_data.round_number,
+                )?;
+            }
+
+            let payout = match withdrawal_account_data.meta.data.status
+                == WithdrawalTokenStatus::Processed
+            {
+                true => Some(HookInstruction::Withdrawal {
+                    mint,
+                    recipient: withdrawal_account_data.event.data.recipient,
+                    amount: transfer_withdrawal_amount,
+                    event: withdrawal_account_data.event.data.try_to_vec()?,
+                }),
+                false => None,
+            };
+
+            emit_event(
+                settings_account_info,
+                &UpdateWithdrawalStatusEvent {
+                    account: withdrawal_pubkey,
+                    status: withdrawal_account_data.meta.data.status,
+                    quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+                    processed_at: withdrawal_account_data.meta.data.processed_at,
+                },
+            )?;
+
+            WithdrawalMultiTokenSol::pack(
+                withdrawal_account_data,
+                &mut withdrawal_account_info.data.borrow_mut(),
+            )?;
+
+            payout
+        }
+    };
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    // Notify Hook Program
+    if let Some(hook_instruction) = payout {
+        invoke_hook(
+            program_id,
+            accounts,
+            hook_program,
+            token_settings_account_info,
+            withdrawal_account_info,
+            hook_instruction,
+        )?;
+    }
+
+    Ok(())
+}
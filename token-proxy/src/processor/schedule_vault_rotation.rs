@@ -0,0 +1,170 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ScheduleVaultRotationAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    pub funder_account_info: &'a AccountInfo<'info>,
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub vault_rotation_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub clock_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, ScheduleVaultRotationAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let ScheduleVaultRotationAccounts {
+        authority_account_info,
+        funder_account_info,
+        token_settings_account_info,
+        vault_rotation_account_info,
+        settings_account_info,
+        programdata_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Token Settings Account
+    let token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (mint, vault) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+    let (token_settings_nonce, _) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    let generation = token_settings_account_data
+        .vault_generation
+        .checked_add(1)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    let (new_vault, _) = find_vault_address(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        generation,
+    );
+
+    let unlock_time = clock
+        .unix_timestamp
+        .checked_add(VAULT_ROTATION_TIMELOCK)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    // Validate Vault Rotation Account
+    let (vault_rotation_pubkey, vault_rotation_nonce) =
+        Pubkey::find_program_address(&[br"vault_rotation", &vault.to_bytes()], program_id);
+
+    if vault_rotation_pubkey != *vault_rotation_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if vault_rotation_account_info.data_is_empty() {
+        // Create Vault Rotation Account
+        let vault_rotation_account_signer_seeds: &[&[_]] = &[
+            br"vault_rotation",
+            &vault.to_bytes(),
+            &[vault_rotation_nonce],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                funder_account_info.key,
+                vault_rotation_account_info.key,
+                1.max(rent.minimum_balance(VaultRotation::LEN)),
+                VaultRotation::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[vault_rotation_account_signer_seeds],
+        )?;
+    } else {
+        // Reschedule not executed rotation
+        let vault_rotation_account_data =
+            VaultRotation::unpack(&vault_rotation_account_info.data.borrow())?;
+
+        if vault_rotation_account_data.executed {
+            return Err(SolanaBridgeError::VaultRotationExecuted.into());
+        }
+    }
+
+    let vault_rotation_account_data = VaultRotation {
+        is_initialized: true,
+        account_kind: AccountKind::VaultRotation(vault_rotation_nonce),
+        token_settings: *token_settings_account_info.key,
+        vault,
+        new_vault,
+        unlock_time,
+        executed: false,
+    };
+
+    VaultRotation::pack(
+        vault_rotation_account_data,
+        &mut vault_rotation_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use super::get_cosigners;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetEmergencyCosignersAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, SetEmergencyCosignersAccounts<'a, 'info>>,
+    cosigners: Vec<Pubkey>,
+    threshold: u8,
+) -> ProgramResult {
+    let SetEmergencyCosignersAccounts {
+        authority_account_info,
+        settings_account_info,
+        programdata_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    if cosigners.len() > MAX_EMERGENCY_COSIGNERS || threshold as usize > cosigners.len() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Every co-signer counts once
+    if cosigners
+        .iter()
+        .enumerate()
+        .any(|(i, cosigner)| cosigners[..i].contains(cosigner))
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Settings Account
+    let mut settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Owner Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Co-signers
+    let signers = get_cosigners(authority_account_info.key, ctx.remaining_accounts);
+
+    if !settings_account_data.is_emergency_cosigned(&signers) {
+        return Err(SolanaBridgeError::NotEnoughCosigners.into());
+    }
+
+    settings_account_data.emergency_cosigners = cosigners;
+    settings_account_data.emergency_cosigners_threshold = threshold;
+
+    Settings::pack(
+        settings_account_data,
+        &mut settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetExemptAccountAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub exempt_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, SetExemptAccountAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    exempt_authority: Pubkey,
+    expires_at: i64,
+) -> ProgramResult {
+    let SetExemptAccountAccounts {
+        authority_account_info,
+        exempt_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Validate Exempt Account
+    let (exempt_account_pubkey, exempt_account_nonce) = Pubkey::find_program_address(
+        &[br"exempt_account", &exempt_authority.to_bytes()],
+        program_id,
+    );
+
+    if exempt_account_pubkey != *exempt_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create Exempt Account on the first exemption
+    if exempt_account_info.owner != program_id {
+        let exempt_account_signer_seeds: &[&[_]] = &[
+            br"exempt_account",
+            &exempt_authority.to_bytes(),
+            &[exempt_account_nonce],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                exempt_account_info.key,
+                1.max(rent.minimum_balance(ExemptAccount::LEN)),
+                ExemptAccount::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[exempt_account_signer_seeds],
+        )?;
+    }
+
+    // Init Exempt Account
+    let exempt_account_data = ExemptAccount {
+        is_initialized: true,
+        account_kind: AccountKind::ExemptAccount(exempt_account_nonce),
+        authority: exempt_authority,
+        expires_at,
+    };
+
+    ExemptAccount::pack(
+        exempt_account_data,
+        &mut exempt_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
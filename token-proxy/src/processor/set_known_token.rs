@@ -0,0 +1,143 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::EverAddress;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::validate_ever_address;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetKnownTokenAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub known_token_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, SetKnownTokenAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    decimals: u8,
+) -> ProgramResult {
+    let SetKnownTokenAccounts {
+        authority_account_info,
+        known_token_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Validate EVER Token Address
+    validate_ever_address(&token)?;
+
+    // Check asset name length
+    if name.len() > MAX_NAME_LEN {
+        return Err(SolanaBridgeError::TokenNameLenLimit.into());
+    }
+
+    // Check asset symbol length
+    if symbol.len() > MAX_SYMBOL_LEN {
+        return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+    }
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Validate Known Token Account
+    let token_hash = hash(&token.try_to_vec()?);
+
+    let (known_token_pubkey, known_token_nonce) =
+        Pubkey::find_program_address(&[br"known_token", token_hash.as_ref()], program_id);
+
+    if known_token_pubkey != *known_token_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create Known Token Account on the first registration
+    if known_token_account_info.owner != program_id {
+        let known_token_account_signer_seeds: &[&[_]] =
+            &[br"known_token", token_hash.as_ref(), &[known_token_nonce]];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                known_token_account_info.key,
+                1.max(rent.minimum_balance(KnownToken::LEN)),
+                KnownToken::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[known_token_account_signer_seeds],
+        )?;
+    }
+
+    // Init Known Token Account
+    let known_token_account_data = KnownToken {
+        is_initialized: true,
+        account_kind: AccountKind::KnownToken(known_token_nonce),
+        token,
+        mint: get_associated_mint(program_id, &token),
+        name,
+        symbol,
+        decimals,
+    };
+
+    KnownToken::pack(
+        known_token_account_data,
+        &mut known_token_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetPayloadSchemaAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub payload_schema_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, SetPayloadSchemaAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    schema_id: u32,
+    min_len: u32,
+    max_len: u32,
+    header_len: u32,
+    header_hash: Option<[u8; 32]>,
+) -> ProgramResult {
+    let SetPayloadSchemaAccounts {
+        authority_account_info,
+        payload_schema_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Header is part of every accepted payload
+    if min_len > max_len || (header_hash.is_some() && header_len > min_len) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Validate Payload Schema Account
+    let (payload_schema_pubkey, payload_schema_nonce) =
+        Pubkey::find_program_address(&[br"payload_schema", &schema_id.to_le_bytes()], program_id);
+
+    if payload_schema_pubkey != *payload_schema_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create Payload Schema Account on the first registration
+    if payload_schema_account_info.owner != program_id {
+        let payload_schema_account_signer_seeds: &[&[_]] = &[
+            br"payload_schema",
+            &schema_id.to_le_bytes(),
+            &[payload_schema_nonce],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                payload_schema_account_info.key,
+                1.max(rent.minimum_balance(PayloadSchema::LEN)),
+                PayloadSchema::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[payload_schema_account_signer_seeds],
+        )?;
+    }
+
+    // Init Payload Schema Account
+    let payload_schema_account_data = PayloadSchema {
+        is_initialized: true,
+        account_kind: AccountKind::PayloadSchema(payload_schema_nonce),
+        schema_id,
+        min_len,
+        max_len,
+        header_len,
+        header_hash,
+    };
+
+    PayloadSchema::pack(
+        payload_schema_account_data,
+        &mut payload_schema_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
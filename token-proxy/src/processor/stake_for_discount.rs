@@ -0,0 +1,132 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+use bridge_utils::token_cpi;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::validate_discount_accounts;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct StakeForDiscountAccounts<'a, 'info> {
+    #[account(signer)]
+    pub owner_account_info: &'a AccountInfo<'info>,
+    pub owner_token_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub discount_stake_account_info: &'a AccountInfo<'info>,
+    pub discount_vault_account_info: &'a AccountInfo<'info>,
+    pub discount_config_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, StakeForDiscountAccounts<'a, 'info>>,
+    amount: u64,
+) -> ProgramResult {
+    let StakeForDiscountAccounts {
+        owner_account_info,
+        owner_token_account_info,
+        discount_stake_account_info,
+        discount_vault_account_info,
+        discount_config_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Discount Config and Vault Accounts
+    validate_discount_accounts(
+        program_id,
+        discount_config_account_info,
+        discount_vault_account_info,
+    )?;
+
+    // Validate Discount Stake Account
+    let (discount_stake_pubkey, discount_stake_nonce) = Pubkey::find_program_address(
+        &[br"discount_stake", &owner_account_info.key.to_bytes()],
+        program_id,
+    );
+
+    if discount_stake_pubkey != *discount_stake_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create Discount Stake Account on the first stake
+    if discount_stake_account_info.owner != program_id {
+        let discount_stake_account_signer_seeds: &[&[_]] = &[
+            br"discount_stake",
+            &owner_account_info.key.to_bytes(),
+            &[discount_stake_nonce],
+        ];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                owner_account_info.key,
+                discount_stake_account_info.key,
+                1.max(rent.minimum_balance(DiscountStake::LEN)),
+                DiscountStake::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[discount_stake_account_signer_seeds],
+        )?;
+
+        DiscountStake::pack(
+            DiscountStake {
+                is_initialized: true,
+                account_kind: AccountKind::DiscountStake(discount_stake_nonce),
+                owner: *owner_account_info.key,
+                amount: 0,
+                unstaking_amount: 0,
+                unstake_available_at: 0,
+            },
+            &mut discount_stake_account_info.data.borrow_mut(),
+        )?;
+    }
+
+    // Transfer staked tokens to the Discount Vault Account
+    token_cpi::transfer(
+        owner_token_account_info,
+        discount_vault_account_info,
+        owner_account_info,
+        amount,
+        accounts,
+        &[],
+    )?;
+
+    let mut discount_stake_account_data =
+        DiscountStake::unpack(&discount_stake_account_info.data.borrow())?;
+
+    discount_stake_account_data.amount = discount_stake_account_data
+        .amount
+        .checked_add(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    DiscountStake::pack(
+        discount_stake_account_data,
+        &mut discount_stake_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct TickAccounts<'a, 'info> {
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(ctx: Context<'a, 'info, TickAccounts<'a, 'info>>) -> ProgramResult {
+    let TickAccounts {
+        token_settings_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    let clock = Clock::get()?;
+
+    // Token settings accounts past the first one are passed as remaining accounts
+    let mut token_settings_account_info = Some(token_settings_account_info);
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    while let Some(account_info) = token_settings_account_info {
+        tick_token_settings(program_id, clock.unix_timestamp, account_info)?;
+
+        token_settings_account_info = next_account_info(account_info_iter).ok();
+    }
+
+    Ok(())
+}
+
+fn tick_token_settings(
+    program_id: &Pubkey,
+    now: i64,
+    token_settings_account_info: &AccountInfo,
+) -> ProgramResult {
+    // Validate Token Settings Account
+    if token_settings_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    // Reset the daily withdrawal amount once the epoch has changed
+    let rolled_over = LimitTracker::new(
+        &mut token_settings_account_data.withdrawal_epoch,
+        &mut token_settings_account_data.withdrawal_daily_amount,
+    )
+    .roll_over(LimitTracker::epoch_of(now));
+
+    // Apply the scheduled limits once effective
+    let limits_changed = token_settings_account_data.apply_scheduled_limit_change(now);
+
+    if rolled_over || limits_changed {
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+    }
+
+    Ok(())
+}
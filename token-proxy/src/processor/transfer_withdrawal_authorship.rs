@@ -0,0 +1,73 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::Proposal;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[derive(Accounts)]
+pub struct TransferWithdrawalAuthorshipAccounts<'a, 'info> {
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, TransferWithdrawalAuthorshipAccounts<'a, 'info>>,
+    new_author: Pubkey,
+) -> ProgramResult {
+    let TransferWithdrawalAuthorshipAccounts {
+        author_account_info,
+        withdrawal_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Validate Withdrawal Account
+    if withdrawal_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let mut withdrawal_account_data =
+        Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+    if !withdrawal_account_data.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    // Only the current author can hand over the rent refund and bounty rights
+    if withdrawal_account_data.author != *author_account_info.key {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    withdrawal_account_data.author = new_author;
+    withdrawal_account_data.pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+
+    Ok(())
+}
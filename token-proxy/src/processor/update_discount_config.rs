@@ -0,0 +1,179 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::unpack_mint_account;
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UpdateDiscountConfigAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub discount_config_account_info: &'a AccountInfo<'info>,
+    pub discount_vault_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, UpdateDiscountConfigAccounts<'a, 'info>>,
+    mint: Pubkey,
+    min_stake: u64,
+    discount_bps: u16,
+    unstake_cooldown: i64,
+) -> ProgramResult {
+    let UpdateDiscountConfigAccounts {
+        authority_account_info,
+        discount_config_account_info,
+        discount_vault_account_info,
+        mint_account_info,
+        settings_account_info,
+        programdata_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    if discount_bps > DISCOUNT_BPS_DENOMINATOR || unstake_cooldown < 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Discount Config Account
+    let (discount_config_pubkey, discount_config_nonce) =
+        Pubkey::find_program_address(&[br"discount_config"], program_id);
+
+    if discount_config_pubkey != *discount_config_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Discount Vault Account
+    let (discount_vault_pubkey, discount_vault_nonce) =
+        Pubkey::find_program_address(&[br"discount_vault"], program_id);
+
+    if discount_vault_pubkey != *discount_vault_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Mint Account
+    if *mint_account_info.key != mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    unpack_mint_account(mint_account_info)?;
+
+    if discount_config_account_info.owner != program_id {
+        // Create Discount Vault Account on the first update
+        let discount_vault_account_signer_seeds: &[&[_]] =
+            &[br"discount_vault", &[discount_vault_nonce]];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                discount_vault_account_info.key,
+                1.max(rent.minimum_balance(spl_token::state::Account::LEN)),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::id(),
+            ),
+            accounts,
+            &[discount_vault_account_signer_seeds],
+        )?;
+
+        invoke_signed(
+            &spl_token::instruction::initialize_account3(
+                &spl_token::id(),
+                discount_vault_account_info.key,
+                mint_account_info.key,
+                discount_vault_account_info.key,
+            )?,
+            accounts,
+            &[discount_vault_account_signer_seeds],
+        )?;
+
+        // Create Discount Config Account
+        let discount_config_account_signer_seeds: &[&[_]] =
+            &[br"discount_config", &[discount_config_nonce]];
+
+        invoke_signed(
+            &system_instruction::create_account(
+                authority_account_info.key,
+                discount_config_account_info.key,
+                1.max(rent.minimum_balance(DiscountConfig::LEN)),
+                DiscountConfig::LEN as u64,
+                program_id,
+            ),
+            accounts,
+            &[discount_config_account_signer_seeds],
+        )?;
+    } else {
+        // Stakes are held in the staked token
+        let discount_config_account_data =
+            DiscountConfig::unpack(&discount_config_account_info.data.borrow())?;
+
+        if discount_config_account_data.mint != mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    // Update Discount Config Account
+    let discount_config_account_data = DiscountConfig {
+        is_initialized: true,
+        account_kind: AccountKind::DiscountConfig(discount_config_nonce, discount_vault_nonce),
+        mint,
+        min_stake,
+        discount_bps,
+        unstake_cooldown,
+    };
+
+    DiscountConfig::pack(
+        discount_config_account_data,
+        &mut discount_config_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
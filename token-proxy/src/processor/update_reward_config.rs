@@ -0,0 +1,117 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::AccountKind;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UpdateRewardConfigAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub reward_config_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub programdata_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, UpdateRewardConfigAccounts<'a, 'info>>,
+    relay_share: u8,
+) -> ProgramResult {
+    let UpdateRewardConfigAccounts {
+        authority_account_info,
+        reward_config_account_info,
+        settings_account_info,
+        programdata_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    // Share is in percents of the fee supply
+    if relay_share > 100 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Initializer Account
+    bridge_utils::helper::validate_programdata_account(
+        program_id,
+        programdata_nonce,
+        programdata_account_info.key,
+    )?;
+    bridge_utils::helper::validate_initializer_account(
+        authority_account_info.key,
+        programdata_account_info,
+    )?;
+
+    // Validate Reward Config Account
+    let (reward_config_pubkey, reward_config_nonce) =
+        Pubkey::find_program_address(&[br"reward_config"], program_id);
+
+    if reward_config_pubkey != *reward_config_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Create Reward Config Account on the first update
+    if bridge_utils::helper::is_uncreated_account(reward_config_account_info) {
+        let reward_config_account_signer_seeds: &[&[_]] =
+            &[br"reward_config", &[reward_config_nonce]];
+
+        bridge_utils::helper::create_pda_account(
+            authority_account_info,
+            reward_config_account_info,
+            rent,
+            RewardConfig::LEN,
+            program_id,
+            accounts,
+            reward_config_account_signer_seeds,
+        )?;
+    } else {
+        if reward_config_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+    }
+
+    // Update Reward Config Account
+    let reward_config_account_data = RewardConfig {
+        is_initialized: true,
+        account_kind: AccountKind::RewardConfig(reward_config_nonce),
+        relay_share,
+    };
+
+    RewardConfig::pack(
+        reward_config_account_data,
+        &mut reward_config_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
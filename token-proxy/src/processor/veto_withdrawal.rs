@@ -0,0 +1,133 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::state::Proposal;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::{emit_event, release_round_withdrawal};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct VetoWithdrawalAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    // Writable to record activity of the guardian
+    #[account(writable)]
+    pub settings_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, VetoWithdrawalAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    reason: String,
+) -> ProgramResult {
+    let VetoWithdrawalAccounts {
+        authority_account_info,
+        withdrawal_account_info,
+        settings_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+
+    if reason.len() > MAX_VETO_REASON_LEN {
+        return Err(SolanaBridgeError::VetoReasonLenLimit.into());
+    }
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.veto_disabled {
+        return Err(SolanaBridgeError::VetoDisabled.into());
+    }
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Validate Withdrawal Account
+    if withdrawal_account_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let mut withdrawal_account_data =
+        Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+    if !withdrawal_account_data.is_initialized {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    // Ever and Solana withdrawals share the meta layout
+    let mut meta =
+        WithdrawalTokenMetaWithLen::try_from_slice(&withdrawal_account_data.meta.try_to_vec()?)?;
+
+    if meta.data.status == WithdrawalTokenStatus::Processed
+        || meta.data.status == WithdrawalTokenStatus::Cancelled
+    {
+        return Err(SolanaBridgeError::InvalidWithdrawalStatus.into());
+    }
+
+    meta.data
+        .transition(WithdrawalTokenStatus::Cancelled, &clock)?;
+
+    release_round_withdrawal(program_id, accounts, round_number)?;
+
+    // Meta written before new fields were added is resized to the current layout
+    withdrawal_account_data.meta = meta.data.try_to_vec()?;
+    withdrawal_account_data.pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+
+    emit_event(
+        settings_account_info,
+        &VetoWithdrawalEvent {
+            account: *withdrawal_account_info.key,
+            guardian: *authority_account_info.key,
+            reason,
+        },
+    )?;
+
+    Ok(())
+}
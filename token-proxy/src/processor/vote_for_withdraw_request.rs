@@ -0,0 +1,144 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::merkle::MerkleProof;
+use bridge_utils::state::Proposal;
+use bridge_utils::types::{Vote, RELAY_REPARATION};
+use round_loader_interface::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use super::{get_withdrawal_token_settings, get_withdrawal_volume, record_relay_vote};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct VoteForWithdrawRequestAccounts<'a, 'info> {
+    #[account(writable, signer)]
+    pub relay_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, VoteForWithdrawRequestAccounts<'a, 'info>>,
+    vote: Vote,
+    index: Option<u32>,
+    proof: Option<MerkleProof>,
+) -> ProgramResult {
+    let VoteForWithdrawRequestAccounts {
+        relay_account_info,
+        withdrawal_account_info,
+        relay_round_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate vote
+    if vote == Vote::None {
+        return Err(SolanaBridgeError::InvalidVote.into());
+    }
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        Proposal::unpack_from_slice(&withdrawal_account_info.data.borrow())?;
+
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.try_to_vec()?[4..]);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    // Voting is paused for the withdrawn token
+    if get_withdrawal_token_settings(program_id, accounts, withdrawal_account_info)?
+        .map_or(false, |data| data.is_paused(TokenOperation::Vote))
+    {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    round_loader_interface::validate_relay_round_account(
+        &round_loader_interface::id(),
+        withdrawal_account_data.round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    // Vote for withdraw request. The claimed index saves the scan of large rounds.
+    let index = match index {
+        Some(index) => relay_round_account_data.check_relay_index(relay_account_info.key, index)?,
+        None => relay_round_account_data.find_relay(relay_account_info.key, proof.as_ref())?,
+    };
+
+    if withdrawal_account_data.signers[index] == Vote::None {
+        // The first vote counts the withdrawal in the round volume
+        let amount = if withdrawal_account_data
+            .signers
+            .iter()
+            .all(|vote| *vote == Vote::None)
+        {
+            Some(get_withdrawal_volume(withdrawal_account_info)?)
+        } else {
+            None
+        };
+
+        // Vote for proposal
+        withdrawal_account_data.signers[index] = vote;
+        withdrawal_account_data.pack_into_slice(&mut withdrawal_account_info.data.borrow_mut());
+
+        // Count the vote in the relay round stats
+        record_relay_vote(
+            program_id,
+            accounts,
+            relay_account_info,
+            round_number,
+            relay_round_account_data.relays_len(),
+            index,
+            amount,
+        )?;
+
+        // Get back voting reparation to Relay
+        let withdrawal_starting_lamports = withdrawal_account_info.lamports();
+        **withdrawal_account_info.lamports.borrow_mut() = withdrawal_starting_lamports
+            .checked_sub(RELAY_REPARATION)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        let relay_starting_lamports = relay_account_info.lamports();
+        **relay_account_info.lamports.borrow_mut() = relay_starting_lamports
+            .checked_add(RELAY_REPARATION)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Reparation must not be paid out of the rent of the withdrawal account
+        bridge_utils::helper::validate_rent_exempt(&Rent::get()?, withdrawal_account_info)?;
+    }
+
+    Ok(())
+}
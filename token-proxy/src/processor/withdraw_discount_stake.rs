@@ -0,0 +1,91 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::token_cpi;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::{validate_discount_accounts, validate_discount_stake_account};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawDiscountStakeAccounts<'a, 'info> {
+    #[account(signer)]
+    pub owner_account_info: &'a AccountInfo<'info>,
+    pub owner_token_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub discount_stake_account_info: &'a AccountInfo<'info>,
+    pub discount_vault_account_info: &'a AccountInfo<'info>,
+    pub discount_config_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawDiscountStakeAccounts<'a, 'info>>,
+) -> ProgramResult {
+    let WithdrawDiscountStakeAccounts {
+        owner_account_info,
+        owner_token_account_info,
+        discount_stake_account_info,
+        discount_vault_account_info,
+        discount_config_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+
+    // Validate Discount Config and Vault Accounts
+    let discount_vault_nonce = validate_discount_accounts(
+        program_id,
+        discount_config_account_info,
+        discount_vault_account_info,
+    )?;
+
+    // Validate Discount Stake Account
+    let mut discount_stake_account_data = validate_discount_stake_account(
+        program_id,
+        owner_account_info.key,
+        discount_stake_account_info,
+    )?;
+
+    let amount = discount_stake_account_data.unstaking_amount;
+
+    if amount == 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if clock.unix_timestamp < discount_stake_account_data.unstake_available_at {
+        return Err(SolanaBridgeError::UnstakeCooldown.into());
+    }
+
+    // Return unstaked tokens to the owner
+    let discount_vault_account_signer_seeds: &[&[_]] =
+        &[br"discount_vault", &[discount_vault_nonce]];
+
+    token_cpi::transfer(
+        discount_vault_account_info,
+        owner_token_account_info,
+        discount_vault_account_info,
+        amount,
+        accounts,
+        &[discount_vault_account_signer_seeds],
+    )?;
+
+    discount_stake_account_data.amount -= amount;
+    discount_stake_account_data.unstaking_amount = 0;
+
+    DiscountStake::pack(
+        discount_stake_account_data,
+        &mut discount_stake_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
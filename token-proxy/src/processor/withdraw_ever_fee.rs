@@ -0,0 +1,136 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::token_cpi;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawEverFeeAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub recipient_token_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawEverFeeAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    amount: u64,
+) -> ProgramResult {
+    let WithdrawEverFeeAccounts {
+        authority_account_info,
+        mint_account_info,
+        recipient_token_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Validate Token Setting Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (token_settings_nonce, mint_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (_, token, _) = token_settings_account_data
+        .kind
+        .into_ever()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_ever_account(
+        program_id,
+        &token,
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    // Validate Mint Account
+    validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+    if mint_account_info.owner != &spl_token::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Validate Recipient Account
+    if recipient_token_account_info.owner != &spl_token::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if amount > token_settings_account_data.fee_supply {
+        return Err(SolanaBridgeError::InsufficientBalance.into());
+    }
+
+    let token_hash = hash(&token.try_to_vec()?);
+    let mint_account_signer_seeds: &[&[_]] = &[br"mint", token_hash.as_ref(), &[mint_nonce]];
+
+    token_cpi::mint_to(
+        mint_account_info,
+        recipient_token_account_info,
+        mint_account_info,
+        amount,
+        accounts,
+        &[mint_account_signer_seeds],
+    )?;
+
+    // Decrease fee supply
+    token_settings_account_data.fee_supply = token_settings_account_data
+        .fee_supply
+        .checked_sub(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+
+use super::{emit_event, make_sol_transfer, unpack_token_account};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawLiquidityAccounts<'a, 'info> {
+    #[account(signer)]
+    pub provider_account_info: &'a AccountInfo<'info>,
+    pub provider_token_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub lp_position_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawLiquidityAccounts<'a, 'info>>,
+    shares: u64,
+) -> ProgramResult {
+    let WithdrawLiquidityAccounts {
+        provider_account_info,
+        provider_token_account_info,
+        lp_position_account_info,
+        token_settings_account_info,
+        vault_account_info,
+        settings_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Token Settings Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+    let (token_settings_nonce, vault_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (mint, _) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Vault Account
+    validate_vault_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_account_data.vault_generation,
+        vault_nonce,
+        vault_account_info,
+    )?;
+
+    // Validate LP Position Account
+    let mut lp_position_account_data = LpPosition::unpack(&lp_position_account_info.data.borrow())?;
+
+    let lp_position_nonce = lp_position_account_data
+        .account_kind
+        .into_lp_position()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let lp_position_pubkey = Pubkey::create_program_address(
+        &[
+            br"lp_position",
+            &mint.to_bytes(),
+            &provider_account_info.key.to_bytes(),
+            &[lp_position_nonce],
+        ],
+        program_id,
+    )?;
+
+    if lp_position_pubkey != *lp_position_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Position belongs to a single Token Settings of the mint
+    if lp_position_account_data.token_settings != *token_settings_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if shares == 0 || shares > lp_position_account_data.shares {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Calculate amount for the current liquidity
+    let amount = (shares as u128)
+        .checked_mul(token_settings_account_data.lp_liquidity as u128)
+        .and_then(|v| v.checked_div(token_settings_account_data.lp_supply as u128))
+        .and_then(|v| u64::try_from(v).ok())
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    // Validate Provider Token Account
+    let provider_token_account_data = unpack_token_account(provider_token_account_info)?;
+
+    if provider_token_account_data.mint != mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let vault_account_data = unpack_token_account(vault_account_info)?;
+
+    if amount > vault_account_data.amount {
+        return Err(SolanaBridgeError::InsufficientVaultBalance.into());
+    }
+
+    make_sol_transfer(
+        vault_account_info,
+        provider_token_account_info,
+        &token_settings_account_data,
+        accounts,
+        amount,
+    )?;
+
+    // Update LP accounting
+    lp_position_account_data.shares -= shares;
+
+    token_settings_account_data.lp_supply = token_settings_account_data
+        .lp_supply
+        .checked_sub(shares)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    token_settings_account_data.lp_liquidity = token_settings_account_data
+        .lp_liquidity
+        .checked_sub(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    emit_event(
+        settings_account_info,
+        &WithdrawLiquidityEvent {
+            lp_position: lp_position_pubkey,
+            owner: *provider_account_info.key,
+            amount,
+            shares,
+        },
+    )?;
+
+    LpPosition::pack(
+        lp_position_account_data,
+        &mut lp_position_account_info.data.borrow_mut(),
+    )?;
+
+    TokenSettings::pack(
+        token_settings_account_data,
+        &mut token_settings_account_info.data.borrow_mut(),
+    )?;
+
+    Ok(())
+}
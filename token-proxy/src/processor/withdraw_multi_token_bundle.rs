@@ -0,0 +1,420 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+use bridge_utils::types::Vote;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    emit_event, invoke_hook, make_sol_transfer, release_round_withdrawal, unpack_token_account,
+    use_limit_exemption, withdrawal_fee,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawMultiTokenBundleAccounts<'a, 'info> {
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawMultiTokenBundleAccounts<'a, 'info>>,
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let WithdrawMultiTokenBundleAccounts {
+        withdrawal_account_info,
+        settings_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenBundle::unpack(&withdrawal_account_info.data.borrow())?;
+
+    let withdrawal_pubkey = validate_bundle_account(
+        program_id,
+        &withdrawal_account_data,
+        withdrawal_account_info,
+    )?;
+
+    let withdrawal_status = withdrawal_account_data.meta.data.status;
+
+    let mut entries = unpack_bundle_entries(
+        program_id,
+        accounts,
+        &mut ctx.remaining_accounts.iter(),
+        &mut withdrawal_account_data,
+        clock.unix_timestamp,
+    )?;
+
+    // Do we have enough signers.
+    let sig_count = withdrawal_account_data
+        .signers
+        .iter()
+        .filter(|vote| **vote == Vote::Confirm)
+        .count() as u32;
+
+    if sig_count < withdrawal_account_data.required_votes {
+        return Ok(());
+    }
+
+    match withdrawal_status {
+        WithdrawalTokenStatus::New => {
+            let mut exceeds_withdrawal_limit = false;
+            let mut exceeds_daily_limit = false;
+
+            // Every token is accounted against its own limits with its own share of the bundle
+            for entry in &mut entries {
+                let token_settings = &mut entry.token_settings_account_data;
+
+                let mut tracker = LimitTracker::new(
+                    &mut token_settings.withdrawal_epoch,
+                    &mut token_settings.withdrawal_daily_amount,
+                );
+                tracker.roll_over(LimitTracker::epoch_of(clock.unix_timestamp));
+                tracker.add(entry.transfer_amount);
+
+                exceeds_daily_limit |= tracker.exceeds(token_settings.withdrawal_daily_limit);
+                exceeds_withdrawal_limit |= entry.transfer_amount > token_settings.withdrawal_limit;
+
+                // Liquidity providers get their cut of the fee
+                let lp_fee = match token_settings.lp_supply {
+                    0 => 0,
+                    _ => {
+                        entry
+                            .fee
+                            .checked_mul(LP_WITHDRAWAL_FEE_SHARE)
+                            .ok_or(SolanaBridgeError::Overflow)?
+                            / 100
+                    }
+                };
+
+                token_settings.lp_liquidity = token_settings
+                    .lp_liquidity
+                    .checked_add(lp_fee)
+                    .ok_or(SolanaBridgeError::Overflow)?;
+
+                // Increase fee supply
+                token_settings.fee_supply = token_settings
+                    .fee_supply
+                    .checked_add(entry.fee - lp_fee)
+                    .ok_or(SolanaBridgeError::Overflow)?;
+            }
+
+            if (exceeds_withdrawal_limit
+                && !use_limit_exemption(
+                    program_id,
+                    accounts,
+                    &withdrawal_account_data.event.data.recipient,
+                    instruction_data,
+                )?)
+                || exceeds_daily_limit
+                || withdrawal_account_data.meta.data.approval_required
+            {
+                withdrawal_account_data
+                    .meta
+                    .data
+                    .transition(WithdrawalTokenStatus::WaitingForApprove, &clock)?;
+            } else {
+                pay_out_bundle(&entries, &mut withdrawal_account_data, accounts, &clock)?;
+            }
+
+            for entry in &entries {
+                entry
+                    .token_settings_account_data
+                    .pack_into_slice(&mut entry.token_settings_account_info.data.borrow_mut());
+            }
+        }
+        WithdrawalTokenStatus::Pending => {
+            pay_out_bundle(&entries, &mut withdrawal_account_data, accounts, &clock)?;
+        }
+        _ => (),
+    }
+
+    emit_event(
+        settings_account_info,
+        &UpdateWithdrawalStatusEvent {
+            account: withdrawal_pubkey,
+            status: withdrawal_account_data.meta.data.status,
+            quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+            processed_at: withdrawal_account_data.meta.data.processed_at,
+        },
+    )?;
+
+    let processed = withdrawal_status != WithdrawalTokenStatus::Processed
+        && withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::Processed;
+
+    if processed {
+        release_round_withdrawal(program_id, accounts, withdrawal_account_data.round_number)?;
+    }
+
+    let event = withdrawal_account_data.event.data.try_to_vec()?;
+    let recipient = withdrawal_account_data.event.data.recipient;
+
+    WithdrawalMultiTokenBundle::pack(
+        withdrawal_account_data,
+        &mut withdrawal_account_info.data.borrow_mut(),
+    )?;
+
+    // Notify Hook Programs of every paid out token
+    if processed {
+        notify_bundle_hooks(
+            program_id,
+            accounts,
+            &entries,
+            withdrawal_account_info,
+            recipient,
+            &event,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Token accounts and amounts of a bundle entry
+pub(super) struct BundleEntry<'a, 'info> {
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    pub recipient_account_info: &'a AccountInfo<'info>,
+    pub token_settings_account_data: TokenSettings,
+    pub mint: Pubkey,
+    pub fee: u64,
+    pub transfer_amount: u64,
+}
+
+/// Validate the bundle withdrawal account, returns its address
+pub(super) fn validate_bundle_account(
+    program_id: &Pubkey,
+    withdrawal_account_data: &WithdrawalMultiTokenBundle,
+    withdrawal_account_info: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_proposal_account(
+        program_id,
+        withdrawal_account_data.round_number,
+        withdrawal_account_data.pda.event_timestamp,
+        withdrawal_account_data.pda.event_transaction_lt,
+        &withdrawal_account_data.pda.event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )
+}
+
+/// Token settings, vault and recipient token accounts of every entry are passed as remaining
+/// accounts in order of entries
+pub(super) fn unpack_bundle_entries<'a, 'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'info>>,
+    withdrawal_account_data: &mut WithdrawalMultiTokenBundle,
+    unix_timestamp: i64,
+) -> Result<Vec<BundleEntry<'a, 'info>>, ProgramError> {
+    let recipient = withdrawal_account_data.event.data.recipient;
+
+    let mut entries = Vec::with_capacity(withdrawal_account_data.event.data.entries.len());
+
+    for (index, entry) in withdrawal_account_data
+        .event
+        .data
+        .entries
+        .iter()
+        .enumerate()
+    {
+        let token_settings_account_info = next_account_info(account_info_iter)?;
+        let vault_account_info = next_account_info(account_info_iter)?;
+        let recipient_account_info = next_account_info(account_info_iter)?;
+
+        if token_settings_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        // Validate Token Setting Account
+        let mut token_settings_account_data =
+            TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+
+        // Apply the scheduled limits once effective
+        token_settings_account_data.apply_scheduled_limit_change(unix_timestamp);
+
+        let (token_settings_nonce, vault_nonce) = token_settings_account_data
+            .account_kind
+            .into_token_settings()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        let (mint, _) = token_settings_account_data
+            .kind
+            .into_solana()
+            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+        validate_token_settings_sol_account(
+            program_id,
+            &mint,
+            token_settings_account_data.ever_configuration.as_ref(),
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+
+        if token_settings_account_data.emergency {
+            return Err(SolanaBridgeError::EmergencyEnabled.into());
+        }
+
+        if token_settings_account_data.is_paused(TokenOperation::Payout) {
+            return Err(SolanaBridgeError::OperationPaused.into());
+        }
+
+        // Check connection between token and proposal
+        if mint != entry.mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Validate Vault Account
+        validate_vault_account(
+            program_id,
+            &mint,
+            token_settings_account_data.ever_configuration.as_ref(),
+            token_settings_account_data.vault_generation,
+            vault_nonce,
+            vault_account_info,
+        )?;
+
+        // Validate Recipient Account
+        let recipient_account_data = unpack_token_account(recipient_account_info)?;
+
+        if recipient_account_data.owner != recipient || recipient_account_data.mint != mint {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let withdrawal_amount = entry.amount as u64;
+
+        let fee = withdrawal_fee(
+            program_id,
+            accounts,
+            &mut withdrawal_account_data.meta.data,
+            index,
+            &recipient,
+            &token_settings_account_data.fee_withdrawal_info,
+            withdrawal_amount,
+        )?;
+
+        // Amount without fee
+        let transfer_amount = withdrawal_amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        entries.push(BundleEntry {
+            token_settings_account_info,
+            vault_account_info,
+            recipient_account_info,
+            token_settings_account_data,
+            mint,
+            fee,
+            transfer_amount,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Transfer every token of the bundle, or none of them if any vault lacks funds
+pub(super) fn pay_out_bundle(
+    entries: &[BundleEntry],
+    withdrawal_account_data: &mut WithdrawalMultiTokenBundle,
+    accounts: &[AccountInfo],
+    clock: &Clock,
+) -> ProgramResult {
+    for entry in entries {
+        let vault_account_data = unpack_token_account(entry.vault_account_info)?;
+
+        if entry.transfer_amount > vault_account_data.amount {
+            return withdrawal_account_data
+                .meta
+                .data
+                .transition(WithdrawalTokenStatus::Pending, clock)
+                .map_err(Into::into);
+        }
+    }
+
+    for entry in entries {
+        make_sol_transfer(
+            entry.vault_account_info,
+            entry.recipient_account_info,
+            &entry.token_settings_account_data,
+            accounts,
+            entry.transfer_amount,
+        )?;
+    }
+
+    withdrawal_account_data
+        .meta
+        .data
+        .transition(WithdrawalTokenStatus::Processed, clock)?;
+
+    Ok(())
+}
+
+pub(super) fn notify_bundle_hooks<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    entries: &[BundleEntry<'_, 'info>],
+    withdrawal_account_info: &AccountInfo<'info>,
+    recipient: Pubkey,
+    event: &[u8],
+) -> ProgramResult {
+    for entry in entries {
+        invoke_hook(
+            program_id,
+            accounts,
+            entry.token_settings_account_data.hook_program,
+            entry.token_settings_account_info,
+            withdrawal_account_info,
+            HookInstruction::Withdrawal {
+                mint: entry.mint,
+                recipient,
+                amount: entry.transfer_amount,
+                event: event.to_vec(),
+            },
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,472 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+use bridge_utils::state::AccountKind;
+use bridge_utils::types::Vote;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::{invoke, invoke_signed, set_return_data};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    emit_event, get_withdrawal_amount, invoke_hook, make_ever_transfer, release_round_withdrawal,
+    unpack_mint_account, unpack_token_account, use_limit_exemption, withdrawal_fee,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawMultiTokenEverAccounts<'a, 'info> {
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub recipient_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawMultiTokenEverAccounts<'a, 'info>>,
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let WithdrawMultiTokenEverAccounts {
+        withdrawal_account_info,
+        mint_account_info,
+        recipient_account_info,
+        token_settings_account_info,
+        settings_account_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    let clock = Clock::get()?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenEver::unpack(&withdrawal_account_info.data.borrow())?;
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    // If token settings account is not created
+    if bridge_utils::helper::is_uncreated_account(token_settings_account_info) {
+        let funder_account_info = match next_account_info(account_info_iter) {
+            Ok(funder_account_info) => funder_account_info,
+            Err(_) => return Ok(()),
+        };
+
+        // Create Mint Account
+        let ever_decimals = withdrawal_account_data.event.data.decimals;
+        let solana_decimals = if ever_decimals > spl_token::native_mint::DECIMALS {
+            spl_token::native_mint::DECIMALS
+        } else {
+            ever_decimals
+        };
+        let ever_token = &withdrawal_account_data.event.data.token;
+        let token = hash(&ever_token.try_to_vec()?);
+
+        let (token_settings_nonce, mint_nonce) =
+            match withdrawal_account_data.meta.data.token_nonces {
+                Some(token_nonces) => token_nonces,
+                // Withdrawals finalized before the bumps were stored
+                None => (
+                    find_token_settings_ever_address(program_id, ever_token).1,
+                    find_mint_address(program_id, ever_token).1,
+                ),
+            };
+
+        validate_mint_account(program_id, ever_token, mint_nonce, mint_account_info)?;
+
+        let mint_account_signer_seeds: &[&[_]] = &[br"mint", token.as_ref(), &[mint_nonce]];
+
+        bridge_utils::helper::create_pda_account(
+            funder_account_info,
+            mint_account_info,
+            rent,
+            spl_token::state::Mint::LEN,
+            &spl_token::id(),
+            accounts,
+            mint_account_signer_seeds,
+        )?;
+
+        // Init Mint Account
+        invoke_signed(
+            &spl_token::instruction::initialize_mint(
+                &spl_token::id(),
+                mint_account_info.key,
+                mint_account_info.key,
+                None,
+                solana_decimals,
+            )?,
+            accounts,
+            &[mint_account_signer_seeds],
+        )?;
+
+        // Create Token Account, the recipient may be off the curve
+        invoke(
+            &spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                funder_account_info.key,
+                &withdrawal_account_data.event.data.recipient,
+                mint_account_info.key,
+                &spl_token::id(),
+            ),
+            accounts,
+        )?;
+
+        // Create Token Settings Account
+        let token_settings_pubkey = validate_token_settings_ever_account(
+            program_id,
+            ever_token,
+            token_settings_nonce,
+            token_settings_account_info,
+        )?;
+        let token_settings_account_signer_seeds: &[&[_]] =
+            &[br"settings", token.as_ref(), &[token_settings_nonce]];
+
+        bridge_utils::helper::create_pda_account(
+            funder_account_info,
+            token_settings_account_info,
+            rent,
+            TokenSettings::LEN,
+            program_id,
+            accounts,
+            token_settings_account_signer_seeds,
+        )?;
+
+        // Init Token Settings Account
+        let token_settings_account_data = TokenSettings {
+            is_initialized: true,
+            account_kind: AccountKind::TokenSettings(token_settings_nonce, mint_nonce),
+            kind: TokenKind::Ever {
+                mint: *mint_account_info.key,
+                decimals: ever_decimals,
+                token: withdrawal_account_data.event.data.token,
+            },
+            name: withdrawal_account_data.event.data.name.clone(),
+            symbol: withdrawal_account_data.event.data.symbol.clone(),
+            withdrawal_epoch: 0,
+            deposit_limit: u64::MAX,
+            withdrawal_limit: u64::MAX,
+            withdrawal_daily_limit: u64::MAX,
+            withdrawal_daily_amount: 0,
+            emergency: false,
+            fee_supply: Default::default(),
+            fee_deposit_info: Default::default(),
+            fee_withdrawal_info: Default::default(),
+            hook_program: None,
+            freeze_risk: false,
+            vault_generation: 0,
+            lp_supply: 0,
+            lp_liquidity: 0,
+            yield_adapter: None,
+            yield_adapter_unlock_time: 0,
+            max_deployed_share: 0,
+            deployed_amount: 0,
+            last_rewarded_round: None,
+            required_votes_override: None,
+            confirmation_tiers: vec![],
+            paused_operations: 0,
+            scheduled_limit_change: None,
+            ever_configuration: None,
+        };
+
+        emit_event(
+            settings_account_info,
+            &TokenSettingsEvent {
+                account: token_settings_pubkey,
+                symbol: token_settings_account_data.symbol.clone(),
+                name: token_settings_account_data.name.clone(),
+                mint: *mint_account_info.key,
+                vault: None,
+                ever_decimals: Some(ever_decimals),
+                solana_decimals: Some(solana_decimals),
+                root: Some(withdrawal_account_data.event.data.token),
+                fee: Default::default(),
+            },
+        )?;
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+    }
+
+    // Validate Token Setting Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+    let hook_program = token_settings_account_data.hook_program;
+
+    // Apply the scheduled limits once effective
+    token_settings_account_data.apply_scheduled_limit_change(clock.unix_timestamp);
+
+    let (token_settings_nonce, mint_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (_, token, _) = token_settings_account_data
+        .kind
+        .into_ever()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_ever_account(
+        program_id,
+        &token,
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if token_settings_account_data.is_paused(TokenOperation::Payout) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Validate Mint Account
+    validate_mint_account(program_id, &token, mint_nonce, mint_account_info)?;
+
+    // Check connection between token and proposal
+    if token != withdrawal_account_data.event.data.token {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let mint_account_data = unpack_mint_account(mint_account_info)?;
+    let solana_decimals = mint_account_data.decimals;
+    let ever_decimals = withdrawal_account_data.event.data.decimals;
+
+    // Do we have enough signers.
+    let sig_count = withdrawal_account_data
+        .signers
+        .iter()
+        .filter(|vote| **vote == Vote::Confirm)
+        .count() as u32;
+
+    if sig_count >= withdrawal_account_data.required_votes
+        && withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::New
+    {
+        // Calculate amount
+        let withdrawal_amount = get_withdrawal_amount(
+            withdrawal_account_data.event.data.amount,
+            ever_decimals,
+            solana_decimals,
+        )?;
+
+        let fee = withdrawal_fee(
+            program_id,
+            accounts,
+            &mut withdrawal_account_data.meta.data,
+            0,
+            &withdrawal_account_data.event.data.recipient,
+            &token_settings_account_data.fee_withdrawal_info,
+            withdrawal_amount,
+        )?;
+
+        // Increase fee supply
+        token_settings_account_data.fee_supply = token_settings_account_data
+            .fee_supply
+            .checked_add(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Amount without fee
+        let transfer_withdrawal_amount = withdrawal_amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        // Increase withdrawal daily amount
+        let mut tracker = LimitTracker::new(
+            &mut token_settings_account_data.withdrawal_epoch,
+            &mut token_settings_account_data.withdrawal_daily_amount,
+        );
+        tracker.roll_over(LimitTracker::epoch_of(clock.unix_timestamp));
+        tracker.add(transfer_withdrawal_amount);
+
+        if (transfer_withdrawal_amount > token_settings_account_data.withdrawal_limit
+            && !use_limit_exemption(
+                program_id,
+                accounts,
+                &withdrawal_account_data.event.data.recipient,
+                instruction_data,
+            )?)
+            || tracker.exceeds(token_settings_account_data.withdrawal_daily_limit)
+            || withdrawal_account_data.meta.data.approval_required
+        {
+            withdrawal_account_data
+                .meta
+                .data
+                .transition(WithdrawalTokenStatus::WaitingForApprove, &clock)?;
+        } else {
+            match withdrawal_account_data.event.data.payload.is_empty() {
+                true => {
+                    // Validate Recipient Account
+                    let recipient_account_data = unpack_token_account(recipient_account_info)?;
+
+                    if recipient_account_data.owner != withdrawal_account_data.event.data.recipient
+                    {
+                        return Err(ProgramError::InvalidArgument);
+                    }
+
+                    make_ever_transfer(
+                        mint_account_info,
+                        recipient_account_info,
+                        &token_settings_account_data,
+                        accounts,
+                        transfer_withdrawal_amount,
+                    )?;
+
+                    withdrawal_account_data
+                        .meta
+                        .data
+                        .transition(WithdrawalTokenStatus::Processed, &clock)?;
+                }
+                false => {
+                    // Validate Proxy Account
+                    let proxy_nonce = withdrawal_account_data
+                        .account_kind
+                        .into_proposal()
+                        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                        .1
+                        .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                    validate_proxy_account(
+                        program_id,
+                        mint_account_info.key,
+                        &withdrawal_account_data.event.data.recipient,
+                        proxy_nonce,
+                        recipient_account_info,
+                    )?;
+
+                    // Make transfer to Proxy Account
+                    make_ever_transfer(
+                        mint_account_info,
+                        recipient_account_info,
+                        &token_settings_account_data,
+                        accounts,
+                        transfer_withdrawal_amount,
+                    )?;
+
+                    withdrawal_account_data
+                        .meta
+                        .data
+                        .transition(WithdrawalTokenStatus::WaitingForExecute, &clock)?;
+                }
+            }
+        }
+
+        TokenSettings::pack(
+            token_settings_account_data,
+            &mut token_settings_account_info.data.borrow_mut(),
+        )?;
+
+        if withdrawal_account_data.meta.data.status.is_final() {
+            release_round_withdrawal(program_id, accounts, round_number)?;
+        }
+
+        let payout =
+            match withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::Processed {
+                true => Some(HookInstruction::Withdrawal {
+                    mint: *mint_account_info.key,
+                    recipient: withdrawal_account_data.event.data.recipient,
+                    amount: transfer_withdrawal_amount,
+                    event: withdrawal_account_data.event.data.try_to_vec()?,
+                }),
+                false => None,
+            };
+
+        emit_event(
+            settings_account_info,
+            &UpdateWithdrawalStatusEvent {
+                account: withdrawal_pubkey,
+                status: withdrawal_account_data.meta.data.status,
+                quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+                processed_at: withdrawal_account_data.meta.data.processed_at,
+            },
+        )?;
+
+        let status = withdrawal_account_data.meta.data.status;
+
+        WithdrawalMultiTokenEver::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        // Notify Hook Program
+        if let Some(hook_instruction) = payout {
+            invoke_hook(
+                program_id,
+                accounts,
+                hook_program,
+                token_settings_account_info,
+                withdrawal_account_info,
+                hook_instruction,
+            )?;
+        }
+
+        set_return_data(
+            &WithdrawalResult {
+                withdrawal: withdrawal_pubkey,
+                status,
+                fee,
+                transfer_amount: transfer_withdrawal_amount,
+            }
+            .try_to_vec()?,
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,352 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+use bridge_utils::state::{AccountKind, PDA};
+use bridge_utils::types::{EverAddress, Vote, RELAY_REPARATION};
+use round_loader_interface::RelayRound;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    create_event_index_account, create_proxy_account, emit_event, get_ever_token_settings,
+    get_withdrawal_requirements, open_round_withdrawal, validate_ever_address,
+    validate_known_token,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawMultiTokenEverRequestAccounts<'a, 'info> {
+    pub funder_account_info: &'a AccountInfo<'info>,
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub event_index_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    pub rl_settings_account_info: &'a AccountInfo<'info>,
+    pub relay_round_account_info: &'a AccountInfo<'info>,
+    #[account(program = solana_program::system_program::id())]
+    pub system_program_info: &'a AccountInfo<'info>,
+    pub rent_sysvar_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawMultiTokenEverRequestAccounts<'a, 'info>>,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: Pubkey,
+    token: EverAddress,
+    name: String,
+    symbol: String,
+    decimals: u8,
+    recipient: Pubkey,
+    amount: u128,
+    payload: Vec<u8>,
+    attached_amount: u64,
+) -> ProgramResult {
+    let WithdrawMultiTokenEverRequestAccounts {
+        funder_account_info,
+        author_account_info,
+        withdrawal_account_info,
+        event_index_account_info,
+        settings_account_info,
+        rl_settings_account_info,
+        relay_round_account_info,
+        system_program_info,
+        rent_sysvar_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    let rent = &Rent::from_account_info(rent_sysvar_info)?;
+
+    let clock = Clock::get()?;
+
+    // Validate EVER Token Address
+    validate_ever_address(&token)?;
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Check asset name length
+    if name.len() > MAX_NAME_LEN {
+        return Err(SolanaBridgeError::TokenNameLenLimit.into());
+    }
+
+    // Check asset symbol length
+    if symbol.len() > MAX_SYMBOL_LEN {
+        return Err(SolanaBridgeError::TokenSymbolLenLimit.into());
+    }
+
+    // Validate Token Metadata
+    validate_known_token(program_id, accounts, &token, &name, &symbol, decimals)?;
+
+    // Validate Round Loader Settings Account
+    let rl_settings_account_data =
+        round_loader_interface::Settings::unpack(&rl_settings_account_info.data.borrow())?;
+
+    let (rl_settings_nonce, _) = rl_settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        &round_loader_interface::id(),
+        rl_settings_nonce,
+        rl_settings_account_info,
+    )?;
+
+    // Validate Event Configuration
+    if rl_settings_account_data.event_configurations_restricted {
+        round_loader_interface::validate_event_configuration(
+            &round_loader_interface::id(),
+            accounts,
+            &event_configuration,
+        )?;
+    }
+
+    // Validate Relay Round Account
+    let relay_round_account_data = RelayRound::unpack(&relay_round_account_info.data.borrow())?;
+
+    let relay_round_nonce = relay_round_account_data
+        .account_kind
+        .into_relay_round()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let round_number = relay_round_account_data.round_number;
+
+    round_loader_interface::validate_relay_round_account(
+        &round_loader_interface::id(),
+        round_number,
+        relay_round_nonce,
+        relay_round_account_info,
+    )?;
+
+    if relay_round_account_data.round_end <= clock.unix_timestamp as u32 {
+        return Err(SolanaBridgeError::RelayRoundExpired.into());
+    }
+
+    if !relay_round_account_data.is_active(clock.unix_timestamp as u32) {
+        return Err(SolanaBridgeError::RelayRoundNotActive.into());
+    }
+
+    let token_settings_account_data = get_ever_token_settings(program_id, accounts, &token)?;
+
+    if token_settings_account_data.as_ref().map_or(false, |data| {
+        data.is_paused(TokenOperation::WithdrawalRequest)
+    }) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Bumps of the token to be deployed by the payout are found once, the payout signs with them
+    let token_nonces = match token_settings_account_data {
+        Some(_) => None,
+        None => Some((
+            find_token_settings_ever_address(program_id, &token).1,
+            find_mint_address(program_id, &token).1,
+        )),
+    };
+
+    let (required_votes, approval_required) = get_withdrawal_requirements(
+        relay_round_account_data.relays_len(),
+        rl_settings_account_data.min_required_votes,
+        token_settings_account_data.as_ref(),
+        amount,
+    );
+
+    let epoch = LimitTracker::epoch_of(clock.unix_timestamp);
+
+    // Create Proxy Account
+    let proxy_nonce = match payload.is_empty() {
+        true => None,
+        false => {
+            let proxy_account_info = next_account_info(account_info_iter)?;
+            let mint_account_info = next_account_info(account_info_iter)?;
+            let spl_token_program_info = next_account_info(account_info_iter)?;
+
+            let mint = get_associated_mint(program_id, &token);
+            if mint != *mint_account_info.key {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let nonce = create_proxy_account(
+                program_id,
+                &recipient,
+                funder_account_info,
+                proxy_account_info,
+                mint_account_info,
+                system_program_info,
+                spl_token_program_info,
+            )?;
+
+            // Attach SOL to proxy account
+            invoke(
+                &system_instruction::transfer(
+                    funder_account_info.key,
+                    proxy_account_info.key,
+                    attached_amount,
+                ),
+                accounts,
+            )?;
+
+            Some(nonce)
+        }
+    };
+
+    // Create Withdraw Account
+    let event = WithdrawalMultiTokenEverEventWithLen::new(
+        settings_account_data.chain_id,
+        token,
+        name,
+        symbol,
+        decimals,
+        amount,
+        recipient,
+        payload,
+    );
+
+    let event_data = hash(&event.data.try_to_vec()?);
+
+    let (withdrawal_pubkey, withdrawal_nonce) = Pubkey::find_program_address(
+        &[
+            br"proposal",
+            &round_number.to_le_bytes(),
+            &event_timestamp.to_le_bytes(),
+            &event_transaction_lt.to_le_bytes(),
+            &event_configuration.to_bytes(),
+            &event_data.to_bytes(),
+        ],
+        program_id,
+    );
+    let withdrawal_account_signer_seeds: &[&[_]] = &[
+        br"proposal",
+        &round_number.to_le_bytes(),
+        &event_timestamp.to_le_bytes(),
+        &event_transaction_lt.to_le_bytes(),
+        &event_configuration.to_bytes(),
+        &event_data.to_bytes(),
+        &[withdrawal_nonce],
+    ];
+
+    if withdrawal_pubkey != *withdrawal_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if bridge_utils::helper::is_uncreated_account(withdrawal_account_info) {
+        let pda = PDA {
+            event_timestamp,
+            event_transaction_lt,
+            event_configuration,
+        };
+
+        create_event_index_account(
+            program_id,
+            &withdrawal_pubkey,
+            &pda,
+            rent,
+            funder_account_info,
+            event_index_account_info,
+            system_program_info,
+        )?;
+
+        bridge_utils::helper::create_pda_account(
+            funder_account_info,
+            withdrawal_account_info,
+            rent,
+            WithdrawalMultiTokenEver::LEN,
+            program_id,
+            accounts,
+            withdrawal_account_signer_seeds,
+        )?;
+
+        let withdrawal_account_data = WithdrawalMultiTokenEver {
+            is_initialized: true,
+            account_kind: AccountKind::Proposal(withdrawal_nonce, proxy_nonce),
+            author: *author_account_info.key,
+            round_number,
+            required_votes,
+            event,
+            pda,
+            meta: WithdrawalTokenMetaWithLen::new(0, epoch)
+                .with_approval(approval_required)
+                .with_token_nonces(token_nonces),
+            signers: vec![Vote::None; relay_round_account_data.relays_len()],
+        };
+
+        WithdrawalMultiTokenEver::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        // Keep the round referenced until the withdrawal is final
+        open_round_withdrawal(
+            program_id,
+            accounts,
+            funder_account_info,
+            round_number,
+            relay_round_account_data.relays_len(),
+        )?;
+
+        let event_data = event_data.to_bytes().to_vec();
+
+        emit_event(
+            settings_account_info,
+            &WithdrawMultiTokenRequestEvent {
+                account: withdrawal_pubkey,
+                token: token.to_string(),
+                recipient,
+                amount,
+                event_timestamp,
+                event_transaction_lt,
+                event_configuration,
+                event_data,
+                bounty: 0,
+            },
+        )?;
+
+        // Send voting reparation for Relay to withdrawal account
+        let relays_lamports = RELAY_REPARATION * relay_round_account_data.relays_len() as u64;
+
+        invoke(
+            &system_instruction::transfer(
+                funder_account_info.key,
+                withdrawal_account_info.key,
+                relays_lamports,
+            ),
+            accounts,
+        )?;
+    } else {
+        if withdrawal_account_info.owner != program_id {
+            return Err(ProgramError::IllegalOwner);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,467 @@
+use borsh::BorshSerialize;
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+use bridge_utils::limit::LimitTracker;
+use bridge_utils::types::Vote;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::hash::hash;
+use solana_program::program::set_return_data;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::sysvar::Sysvar;
+
+use super::{
+    emit_event, invoke_hook, make_sol_transfer, release_round_withdrawal, unpack_token_account,
+    unwrap_sol, use_limit_exemption, withdrawal_fee,
+};
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawMultiTokenSolAccounts<'a, 'info> {
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+    pub vault_account_info: &'a AccountInfo<'info>,
+    pub recipient_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub token_settings_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+    #[account(program = spl_token::id())]
+    pub token_program_info: &'a AccountInfo<'info>,
+    pub clock_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawMultiTokenSolAccounts<'a, 'info>>,
+    unwrap: bool,
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let WithdrawMultiTokenSolAccounts {
+        withdrawal_account_info,
+        vault_account_info,
+        recipient_account_info,
+        token_settings_account_info,
+        mint_account_info,
+        settings_account_info,
+        ..
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let clock = Clock::get()?;
+
+    // Recipient signs to get wSOL unwrapped into its own system account
+    let owner_account_info = match unwrap {
+        true => {
+            let owner_account_info = next_account_info(&mut ctx.remaining_accounts.iter())?;
+
+            if !owner_account_info.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            if *mint_account_info.key != spl_token::native_mint::id() {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            Some(owner_account_info)
+        }
+        false => None,
+    };
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, _) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    if settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    // Validate Withdrawal Account
+    let mut withdrawal_account_data =
+        WithdrawalMultiTokenSol::unpack(&withdrawal_account_info.data.borrow())?;
+    let round_number = withdrawal_account_data.round_number;
+    let event_timestamp = withdrawal_account_data.pda.event_timestamp;
+    let event_transaction_lt = withdrawal_account_data.pda.event_transaction_lt;
+    let event_configuration = withdrawal_account_data.pda.event_configuration;
+    let event_data = hash(&withdrawal_account_data.event.data.try_to_vec()?);
+    let (nonce, _) = withdrawal_account_data
+        .account_kind
+        .into_proposal()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let withdrawal_pubkey = bridge_utils::helper::validate_proposal_account(
+        program_id,
+        round_number,
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+        &event_data,
+        nonce,
+        withdrawal_account_info,
+    )?;
+
+    let withdrawal_status = withdrawal_account_data.meta.data.status;
+
+    // Validate Token Setting Account
+    let mut token_settings_account_data =
+        TokenSettings::unpack(&token_settings_account_info.data.borrow())?;
+    let hook_program = token_settings_account_data.hook_program;
+
+    // Apply the scheduled limits once effective
+    token_settings_account_data.apply_scheduled_limit_change(clock.unix_timestamp);
+
+    let (token_settings_nonce, vault_nonce) = token_settings_account_data
+        .account_kind
+        .into_token_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    let (mint, _) = token_settings_account_data
+        .kind
+        .into_solana()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_token_settings_sol_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_nonce,
+        token_settings_account_info,
+    )?;
+
+    if token_settings_account_data.emergency {
+        return Err(SolanaBridgeError::EmergencyEnabled.into());
+    }
+
+    if token_settings_account_data.is_paused(TokenOperation::Payout) {
+        return Err(SolanaBridgeError::OperationPaused.into());
+    }
+
+    // Validate Vault Account
+    validate_vault_account(
+        program_id,
+        &mint,
+        token_settings_account_data.ever_configuration.as_ref(),
+        token_settings_account_data.vault_generation,
+        vault_nonce,
+        vault_account_info,
+    )?;
+
+    // Check connection between token and proposal
+    if mint != withdrawal_account_data.event.data.mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Do we have enough signers.
+    let sig_count = withdrawal_account_data
+        .signers
+        .iter()
+        .filter(|vote| **vote == Vote::Confirm)
+        .count() as u32;
+
+    if sig_count >= withdrawal_account_data.required_votes {
+        let withdrawal_amount = withdrawal_account_data.event.data.amount as u64;
+
+        let fee = withdrawal_fee(
+            program_id,
+            accounts,
+            &mut withdrawal_account_data.meta.data,
+            0,
+            &withdrawal_account_data.event.data.recipient,
+            &token_settings_account_data.fee_withdrawal_info,
+            withdrawal_amount,
+        )?;
+
+        // Amount without fee
+        let transfer_withdrawal_amount = withdrawal_amount
+            .checked_sub(fee)
+            .ok_or(SolanaBridgeError::Overflow)?;
+
+        match withdrawal_status {
+            WithdrawalTokenStatus::New => {
+                // Increase withdrawal daily amount
+                let mut tracker = LimitTracker::new(
+                    &mut token_settings_account_data.withdrawal_epoch,
+                    &mut token_settings_account_data.withdrawal_daily_amount,
+                );
+                tracker.roll_over(LimitTracker::epoch_of(clock.unix_timestamp));
+                tracker.add(transfer_withdrawal_amount);
+
+                // Liquidity providers get their cut of the fee
+                let lp_fee = match token_settings_account_data.lp_supply {
+                    0 => 0,
+                    _ => {
+                        fee.checked_mul(LP_WITHDRAWAL_FEE_SHARE)
+                            .ok_or(SolanaBridgeError::Overflow)?
+                            / 100
+                    }
+                };
+
+                token_settings_account_data.lp_liquidity = token_settings_account_data
+                    .lp_liquidity
+                    .checked_add(lp_fee)
+                    .ok_or(SolanaBridgeError::Overflow)?;
+
+                // Increase fee supply
+                token_settings_account_data.fee_supply = token_settings_account_data
+                    .fee_supply
+                    .checked_add(fee - lp_fee)
+                    .ok_or(SolanaBridgeError::Overflow)?;
+
+                if (transfer_withdrawal_amount > token_settings_account_data.withdrawal_limit
+                    && !use_limit_exemption(
+                        program_id,
+                        accounts,
+                        &withdrawal_account_data.event.data.recipient,
+                        instruction_data,
+                    )?)
+                    || tracker.exceeds(token_settings_account_data.withdrawal_daily_limit)
+                    || withdrawal_account_data.meta.data.approval_required
+                {
+                    withdrawal_account_data
+                        .meta
+                        .data
+                        .transition(WithdrawalTokenStatus::WaitingForApprove, &clock)?;
+                } else {
+                    match withdrawal_account_data.event.data.payload.is_empty() {
+                        true => {
+                            // Validate Recipient Account
+                            let recipient_account_data =
+                                unpack_token_account(recipient_account_info)?;
+
+                            if recipient_account_data.owner
+                                != withdrawal_account_data.event.data.recipient
+                            {
+                                return Err(ProgramError::InvalidArgument);
+                            }
+
+                            let vault_account_data = unpack_token_account(vault_account_info)?;
+
+                            if transfer_withdrawal_amount > vault_account_data.amount {
+                                withdrawal_account_data
+                                    .meta
+                                    .data
+                                    .transition(WithdrawalTokenStatus::Pending, &clock)?;
+                            } else {
+                                make_sol_transfer(
+                                    vault_account_info,
+                                    recipient_account_info,
+                                    &token_settings_account_data,
+                                    accounts,
+                                    transfer_withdrawal_amount,
+                                )?;
+
+                                if let Some(owner_account_info) = owner_account_info {
+                                    unwrap_sol(
+                                        recipient_account_info,
+                                        owner_account_info,
+                                        accounts,
+                                    )?;
+                                }
+
+                                withdrawal_account_data
+                                    .meta
+                                    .data
+                                    .transition(WithdrawalTokenStatus::Processed, &clock)?;
+                            }
+                        }
+                        false => {
+                            // Validate Proxy Account
+                            let proxy_nonce = withdrawal_account_data
+                                .account_kind
+                                .into_proposal()
+                                .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                                .1
+                                .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                            validate_proxy_account(
+                                program_id,
+                                mint_account_info.key,
+                                &withdrawal_account_data.event.data.recipient,
+                                proxy_nonce,
+                                recipient_account_info,
+                            )?;
+
+                            let vault_account_data = unpack_token_account(vault_account_info)?;
+
+                            if transfer_withdrawal_amount > vault_account_data.amount {
+                                withdrawal_account_data
+                                    .meta
+                                    .data
+                                    .transition(WithdrawalTokenStatus::Pending, &clock)?;
+                            } else {
+                                make_sol_transfer(
+                                    vault_account_info,
+                                    recipient_account_info,
+                                    &token_settings_account_data,
+                                    accounts,
+                                    transfer_withdrawal_amount,
+                                )?;
+
+                                withdrawal_account_data
+                                    .meta
+                                    .data
+                                    .transition(WithdrawalTokenStatus::WaitingForExecute, &clock)?;
+                            }
+                        }
+                    }
+                }
+
+                TokenSettings::pack(
+                    token_settings_account_data,
+                    &mut token_settings_account_info.data.borrow_mut(),
+                )?;
+            }
+            WithdrawalTokenStatus::Pending | WithdrawalTokenStatus::PartiallyProcessed => {
+                match withdrawal_account_data.event.data.payload.is_empty() {
+                    true => {
+                        // Validate Recipient Account
+                        let recipient_account_data = unpack_token_account(recipient_account_info)?;
+
+                        if recipient_account_data.owner
+                            != withdrawal_account_data.event.data.recipient
+                        {
+                            return Err(ProgramError::InvalidArgument);
+                        }
+
+                        // Amount not yet claimed in tranches
+                        let remaining_withdrawal_amount = transfer_withdrawal_amount
+                            .checked_sub(withdrawal_account_data.meta.data.amount_claimed)
+                            .ok_or(SolanaBridgeError::Overflow)?;
+
+                        let vault_account_data = unpack_token_account(vault_account_info)?;
+
+                        if remaining_withdrawal_amount <= vault_account_data.amount {
+                            make_sol_transfer(
+                                vault_account_info,
+                                recipient_account_info,
+                                &token_settings_account_data,
+                                accounts,
+                                remaining_withdrawal_amount,
+                            )?;
+
+                            if let Some(owner_account_info) = owner_account_info {
+                                unwrap_sol(recipient_account_info, owner_account_info, accounts)?;
+                            }
+
+                            withdrawal_account_data
+                                .meta
+                                .data
+                                .transition(WithdrawalTokenStatus::Processed, &clock)?;
+                        }
+                    }
+                    false => {
+                        // Validate Proxy Account
+                        let proxy_nonce = withdrawal_account_data
+                            .account_kind
+                            .into_proposal()
+                            .map_err(|_| SolanaBridgeError::InvalidTokenKind)?
+                            .1
+                            .ok_or(SolanaBridgeError::InvalidTokenKind)?;
+
+                        validate_proxy_account(
+                            program_id,
+                            mint_account_info.key,
+                            &withdrawal_account_data.event.data.recipient,
+                            proxy_nonce,
+                            recipient_account_info,
+                        )?;
+
+                        let vault_account_data = unpack_token_account(vault_account_info)?;
+
+                        if transfer_withdrawal_amount > vault_account_data.amount {
+                            withdrawal_account_data
+                                .meta
+                                .data
+                                .transition(WithdrawalTokenStatus::Pending, &clock)?;
+                        } else {
+                            make_sol_transfer(
+                                vault_account_info,
+                                recipient_account_info,
+                                &token_settings_account_data,
+                                accounts,
+                                transfer_withdrawal_amount,
+                            )?;
+
+                            withdrawal_account_data
+                                .meta
+                                .data
+                                .transition(WithdrawalTokenStatus::WaitingForExecute, &clock)?;
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        if !withdrawal_status.is_final() && withdrawal_account_data.meta.data.status.is_final() {
+            release_round_withdrawal(program_id, accounts, round_number)?;
+        }
+
+        let payout = match withdrawal_status != WithdrawalTokenStatus::Processed
+            && withdrawal_account_data.meta.data.status == WithdrawalTokenStatus::Processed
+        {
+            true => Some(HookInstruction::Withdrawal {
+                mint,
+                recipient: withdrawal_account_data.event.data.recipient,
+                amount: transfer_withdrawal_amount,
+                event: withdrawal_account_data.event.data.try_to_vec()?,
+            }),
+            false => None,
+        };
+
+        emit_event(
+            settings_account_info,
+            &UpdateWithdrawalStatusEvent {
+                account: withdrawal_pubkey,
+                status: withdrawal_account_data.meta.data.status,
+                quorum_reached_at: withdrawal_account_data.meta.data.quorum_reached_at,
+                processed_at: withdrawal_account_data.meta.data.processed_at,
+            },
+        )?;
+
+        let status = withdrawal_account_data.meta.data.status;
+
+        WithdrawalMultiTokenSol::pack(
+            withdrawal_account_data,
+            &mut withdrawal_account_info.data.borrow_mut(),
+        )?;
+
+        // Notify Hook Program
+        if let Some(hook_instruction) = payout {
+            invoke_hook(
+                program_id,
+                accounts,
+                hook_program,
+                token_settings_account_info,
+                withdrawal_account_info,
+                hook_instruction,
+            )?;
+        }
+
+        set_return_data(
+            &WithdrawalResult {
+                withdrawal: withdrawal_pubkey,
+                status,
+                fee,
+                transfer_amount: transfer_withdrawal_amount,
+            }
+            .try_to_vec()?,
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::errors::SolanaBridgeError;
+
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::*;
+
+#[derive(Accounts)]
+pub struct WithdrawMultiVaultAccounts<'a, 'info> {
+    #[account(signer)]
+    pub authority_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub recipient_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub multi_vault_account_info: &'a AccountInfo<'info>,
+    pub settings_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawMultiVaultAccounts<'a, 'info>>,
+    permission: InstructionPermission,
+    amount: u64,
+) -> ProgramResult {
+    let WithdrawMultiVaultAccounts {
+        authority_account_info,
+        recipient_account_info,
+        multi_vault_account_info,
+        settings_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let account_info_iter = &mut ctx.remaining_accounts.iter();
+
+    // Validate Settings Account
+    let settings_account_data = Settings::unpack(&settings_account_info.data.borrow())?;
+
+    let (settings_nonce, programdata_nonce) = settings_account_data
+        .account_kind
+        .into_settings()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    bridge_utils::helper::validate_settings_account(
+        program_id,
+        settings_nonce,
+        settings_account_info,
+    )?;
+
+    // Validate Authority Role
+    if !settings_account_data.is_permitted(&permission, authority_account_info.key) {
+        let programdata_account_info = next_account_info(account_info_iter)?;
+
+        // Validate Initializer Account
+        bridge_utils::helper::validate_programdata_account(
+            program_id,
+            programdata_nonce,
+            programdata_account_info.key,
+        )?;
+        bridge_utils::helper::validate_initializer_account(
+            authority_account_info.key,
+            programdata_account_info,
+        )?;
+    }
+
+    // Validate Multi Vault Account
+    let multi_vault_account_data = MultiVault::unpack(&multi_vault_account_info.data.borrow())?;
+    let multi_vault_nonce = multi_vault_account_data
+        .account_kind
+        .into_multi_vault()
+        .map_err(|_| SolanaBridgeError::InvalidTokenKind)?;
+
+    validate_multi_vault_account(program_id, multi_vault_nonce, multi_vault_account_info)?;
+
+    // Transfer
+    let multi_vault_starting_lamports = multi_vault_account_info.lamports();
+    **multi_vault_account_info.lamports.borrow_mut() = multi_vault_starting_lamports
+        .checked_sub(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    let recipient_starting_lamports = recipient_account_info.lamports();
+    **recipient_account_info.lamports.borrow_mut() = recipient_starting_lamports
+        .checked_add(amount)
+        .ok_or(SolanaBridgeError::Overflow)?;
+
+    // Neither the vault nor a new recipient can be left below the rent-exempt minimum
+    let rent = Rent::get()?;
+    bridge_utils::helper::validate_rent_exempt(&rent, multi_vault_account_info)?;
+    bridge_utils::helper::validate_rent_exempt(&rent, recipient_account_info)?;
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use bridge_utils::token_cpi;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+#[derive(Accounts)]
+pub struct WithdrawProxyAccounts<'a, 'info> {
+    #[account(signer)]
+    pub creator_account_info: &'a AccountInfo<'info>,
+    pub recipient_token_account_info: &'a AccountInfo<'info>,
+    pub proxy_account_info: &'a AccountInfo<'info>,
+    pub mint_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WithdrawProxyAccounts<'a, 'info>>,
+    amount: u64,
+) -> ProgramResult {
+    let WithdrawProxyAccounts {
+        creator_account_info,
+        recipient_token_account_info,
+        proxy_account_info,
+        mint_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+    let accounts = ctx.account_infos;
+
+    let (proxy, nonce) = Pubkey::find_program_address(
+        &[
+            br"proxy",
+            &mint_account_info.key.to_bytes(),
+            &creator_account_info.key.to_bytes(),
+        ],
+        program_id,
+    );
+
+    if proxy != *proxy_account_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let proxy_signer_seeds: &[&[_]] = &[
+        br"proxy",
+        &mint_account_info.key.to_bytes(),
+        &creator_account_info.key.to_bytes(),
+        &[nonce],
+    ];
+
+    token_cpi::transfer(
+        proxy_account_info,
+        recipient_token_account_info,
+        proxy_account_info,
+        amount,
+        accounts,
+        &[proxy_signer_seeds],
+    )
+}
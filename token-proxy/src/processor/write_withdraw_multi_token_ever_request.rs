@@ -0,0 +1,38 @@
+use bridge_derive::Accounts;
+use bridge_utils::context::{Accounts, Context};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
+
+use super::{validate_withdrawal_header, write_withdrawal_data};
+
+#[derive(Accounts)]
+pub struct WriteWithdrawMultiTokenEverRequestAccounts<'a, 'info> {
+    #[account(signer)]
+    pub author_account_info: &'a AccountInfo<'info>,
+    #[account(writable)]
+    pub withdrawal_account_info: &'a AccountInfo<'info>,
+}
+
+pub fn process<'a, 'info>(
+    ctx: Context<'a, 'info, WriteWithdrawMultiTokenEverRequestAccounts<'a, 'info>>,
+    offset: u32,
+    bytes: Vec<u8>,
+) -> ProgramResult {
+    let WriteWithdrawMultiTokenEverRequestAccounts {
+        author_account_info,
+        withdrawal_account_info,
+    } = ctx.accounts;
+    let program_id = ctx.program_id;
+
+    // Don't allow to write to finalized withdrawal
+    validate_withdrawal_header(program_id, author_account_info, withdrawal_account_info)?;
+
+    write_withdrawal_data(
+        &mut withdrawal_account_info.data.borrow_mut(),
+        offset as usize,
+        &bytes,
+    )?;
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_return, clippy::too_many_arguments)]
+
 use std::str::FromStr;
 
 use base64::engine::general_purpose;
@@ -7,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::{JsCast, JsValue};
 
+use solana_program::hash::Hash;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
@@ -41,6 +44,7 @@ pub fn initialize_settings_ix(
     guardian: String,
     withdrawal_manager: String,
     manager: String,
+    chain_id: u32,
 ) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
     let program_data_pubkey = token_proxy::get_programdata_address();
@@ -56,6 +60,7 @@ pub fn initialize_settings_ix(
         guardian,
         manager,
         withdrawal_manager,
+        chain_id,
     }
     .try_to_vec()
     .handle_error()?;
@@ -81,6 +86,7 @@ pub fn initialize_settings_ix(
 pub fn withdrawal_multi_token_ever_request_ix(
     funder_pubkey: String,
     author_pubkey: String,
+    chain_id: u32,
     name: String,
     symbol: String,
     decimals: u8,
@@ -107,6 +113,7 @@ pub fn withdrawal_multi_token_ever_request_ix(
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
     let withdrawal_pubkey = token_proxy::get_withdrawal_ever_address(
+        chain_id,
         round_number,
         event_timestamp,
         event_transaction_lt,
@@ -120,13 +127,22 @@ pub fn withdrawal_multi_token_ever_request_ix(
         payload.clone(),
     );
 
+    let settings_pubkey = token_proxy::get_settings_address();
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
 
+    let event_index_pubkey = token_proxy::get_event_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+    );
+
     let mut accounts = vec![
         AccountMeta::new(funder_pubkey, true),
         AccountMeta::new(author_pubkey, true),
         AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(event_index_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
         AccountMeta::new_readonly(rl_settings_pubkey, false),
         AccountMeta::new_readonly(relay_round_pubkey, false),
         AccountMeta::new_readonly(system_program::id(), false),
@@ -143,6 +159,22 @@ pub fn withdrawal_multi_token_ever_request_ix(
         accounts.push(AccountMeta::new(spl_token::id(), false));
     }
 
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    accounts.push(AccountMeta::new_readonly(token_settings_pubkey, false));
+
+    let known_token_pubkey = token_proxy::get_known_token_address(&token);
+    accounts.push(AccountMeta::new_readonly(known_token_pubkey, false));
+
+    let rl_event_configuration_pubkey =
+        round_loader::get_event_configuration_address(&event_configuration);
+    accounts.push(AccountMeta::new_readonly(
+        rl_event_configuration_pubkey,
+        false,
+    ));
+
+    let relay_round_stats_pubkey = token_proxy::get_relay_round_stats_address(round_number);
+    accounts.push(AccountMeta::new(relay_round_stats_pubkey, false));
+
     let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenEverRequest {
         event_timestamp,
         event_transaction_lt,
@@ -172,6 +204,7 @@ pub fn withdrawal_multi_token_ever_request_ix(
 pub fn withdrawal_multi_token_sol_request_ix(
     funder_pubkey: String,
     author_pubkey: String,
+    chain_id: u32,
     mint_pubkey: String,
     event_timestamp: u32,
     event_transaction_lt: u64,
@@ -181,13 +214,19 @@ pub fn withdrawal_multi_token_sol_request_ix(
     round_number: u32,
     payload: String,
     attached_amount: u64,
+    ever_configuration: Option<String>,
 ) -> Result<JsValue, JsValue> {
     let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
     let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
     let mint = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
     let recipient = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
     let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
-    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint);
+    let ever_configuration = ever_configuration
+        .map(|ever_configuration| Pubkey::from_str(ever_configuration.as_str()))
+        .transpose()
+        .handle_error()?;
+    let token_settings_pubkey =
+        token_proxy::get_namespaced_token_settings_sol_address(&mint, ever_configuration.as_ref());
     let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
 
     let amount = u128::from_str(&amount).handle_error()?;
@@ -196,6 +235,7 @@ pub fn withdrawal_multi_token_sol_request_ix(
         bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
 
     let withdrawal_pubkey = token_proxy::get_withdrawal_sol_address(
+        chain_id,
         round_number,
         event_timestamp,
         event_transaction_lt,
@@ -206,14 +246,23 @@ pub fn withdrawal_multi_token_sol_request_ix(
         payload.clone(),
     );
 
+    let settings_pubkey = token_proxy::get_settings_address();
     let rl_settings_pubkey =
         bridge_utils::helper::get_associated_settings_address(&round_loader::id());
 
+    let event_index_pubkey = token_proxy::get_event_index_address(
+        event_timestamp,
+        event_transaction_lt,
+        &event_configuration,
+    );
+
     let mut accounts = vec![
         AccountMeta::new(funder_pubkey, true),
         AccountMeta::new(author_pubkey, true),
         AccountMeta::new(withdrawal_pubkey, false),
+        AccountMeta::new(event_index_pubkey, false),
         AccountMeta::new_readonly(token_settings_pubkey, false),
+        AccountMeta::new(settings_pubkey, false),
         AccountMeta::new_readonly(rl_settings_pubkey, false),
         AccountMeta::new_readonly(relay_round_pubkey, false),
         AccountMeta::new_readonly(system_program::id(), false),
@@ -229,6 +278,16 @@ pub fn withdrawal_multi_token_sol_request_ix(
         accounts.push(AccountMeta::new(spl_token::id(), false));
     }
 
+    let rl_event_configuration_pubkey =
+        round_loader::get_event_configuration_address(&event_configuration);
+    accounts.push(AccountMeta::new_readonly(
+        rl_event_configuration_pubkey,
+        false,
+    ));
+
+    let relay_round_stats_pubkey = token_proxy::get_relay_round_stats_address(round_number);
+    accounts.push(AccountMeta::new(relay_round_stats_pubkey, false));
+
     let data = token_proxy::TokenProxyInstruction::WithdrawMultiTokenSolRequest {
         event_timestamp,
         event_transaction_lt,
@@ -276,7 +335,7 @@ pub fn withdrawal_multi_token_ever_ix(
             AccountMeta::new(mint_pubkey, false),
             AccountMeta::new(recipient_token_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
@@ -288,6 +347,47 @@ pub fn withdrawal_multi_token_ever_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
+#[wasm_bindgen(js_name = "getRecipientTokenAddress")]
+pub fn get_recipient_token_address(
+    recipient_pubkey: String,
+    mint_pubkey: String,
+    allow_owner_off_curve: bool,
+) -> Result<JsValue, JsValue> {
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let recipient_token_pubkey = token_proxy::get_recipient_token_address(
+        &recipient_pubkey,
+        &mint_pubkey,
+        allow_owner_off_curve,
+    )
+    .handle_error()?;
+
+    return serde_wasm_bindgen::to_value(&recipient_token_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "createRecipientTokenAccount")]
+pub fn create_recipient_token_account_ix(
+    funder_pubkey: String,
+    recipient_pubkey: String,
+    mint_pubkey: String,
+    allow_owner_off_curve: bool,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::create_recipient_token_account_ix(
+        funder_pubkey,
+        recipient_pubkey,
+        mint_pubkey,
+        allow_owner_off_curve,
+    )
+    .handle_error()?;
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
 #[wasm_bindgen(js_name = "createMultiTokenEver")]
 pub fn create_ever_token_ix(
     funder_pubkey: String,
@@ -318,7 +418,7 @@ pub fn create_ever_token_ix(
             AccountMeta::new(mint_pubkey, false),
             AccountMeta::new(recipient_token_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
@@ -361,7 +461,7 @@ pub fn withdrawal_multi_token_sol_ix(
             AccountMeta::new(recipient_token_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
             AccountMeta::new_readonly(mint, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::clock::id(), false),
         ],
@@ -402,7 +502,7 @@ pub fn deposit_native_sol_ix(
     let multivault_pubkey = token_proxy::get_multivault_address();
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
 
-    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let deposit_pubkey = token_proxy::get_deposit_address(&author_pubkey, deposit_seed);
 
     let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
 
@@ -428,7 +528,7 @@ pub fn deposit_native_sol_ix(
             AccountMeta::new(mint_pubkey, false),
             AccountMeta::new(multivault_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
@@ -453,6 +553,8 @@ pub fn deposit_multi_token_ever_ix(
     value: u64,
     expected_evers: u64,
     payload: String,
+    payload_schema: Option<u32>,
+    deposit_index_page: Option<u64>,
 ) -> Result<JsValue, JsValue> {
     let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
         .handle_error()?
@@ -470,7 +572,7 @@ pub fn deposit_multi_token_ever_ix(
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
     let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
-    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let deposit_pubkey = token_proxy::get_deposit_address(&author_pubkey, deposit_seed);
 
     let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
 
@@ -481,11 +583,12 @@ pub fn deposit_multi_token_ever_ix(
         value,
         expected_evers,
         payload,
+        payload_schema,
     }
     .try_to_vec()
     .handle_error()?;
 
-    let ix = Instruction {
+    let mut ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(funder_pubkey, true),
@@ -495,7 +598,7 @@ pub fn deposit_multi_token_ever_ix(
             AccountMeta::new(mint_pubkey, false),
             AccountMeta::new(multivault_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
@@ -503,6 +606,18 @@ pub fn deposit_multi_token_ever_ix(
         data,
     };
 
+    if let Some(schema_id) = payload_schema {
+        ix.accounts.push(AccountMeta::new_readonly(
+            token_proxy::get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    if let Some(page) = deposit_index_page {
+        ix.accounts
+            .extend(token_proxy::deposit_index_accounts(&author_pubkey, page));
+    }
+
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
@@ -520,6 +635,9 @@ pub fn deposit_multi_token_sol_ix(
     value: u64,
     expected_evers: u64,
     payload: String,
+    payload_schema: Option<u32>,
+    deposit_index_page: Option<u64>,
+    ever_configuration: Option<String>,
 ) -> Result<JsValue, JsValue> {
     let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
         .handle_error()?
@@ -530,15 +648,28 @@ pub fn deposit_multi_token_sol_ix(
     let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
     let author_token_pubkey = Pubkey::from_str(author_token_pubkey.as_str()).handle_error()?;
     let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+    let ever_configuration = ever_configuration
+        .map(|ever_configuration| Pubkey::from_str(ever_configuration.as_str()))
+        .transpose()
+        .handle_error()?;
 
     let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
 
-    let vault_pubkey = token_proxy::get_vault_address(&mint_pubkey);
+    let vault_pubkey =
+        token_proxy::get_namespaced_vault_address(&mint_pubkey, ever_configuration.as_ref());
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
-    let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
+    let token_settings_pubkey = token_proxy::get_namespaced_token_settings_sol_address(
+        &mint_pubkey,
+        ever_configuration.as_ref(),
+    );
+    let token_whitelist_pubkey = token_proxy::get_token_whitelist_address(&mint_pubkey);
 
-    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let deposit_pubkey = token_proxy::get_namespaced_deposit_address(
+        &author_pubkey,
+        deposit_seed,
+        ever_configuration.as_ref(),
+    );
 
     let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
 
@@ -551,11 +682,13 @@ pub fn deposit_multi_token_sol_ix(
         value,
         expected_evers,
         payload,
+        payload_schema,
+        ever_configuration,
     }
     .try_to_vec()
     .handle_error()?;
 
-    let ix = Instruction {
+    let mut ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(funder_pubkey, true),
@@ -566,182 +699,1201 @@ pub fn deposit_multi_token_sol_ix(
             AccountMeta::new(mint_pubkey, false),
             AccountMeta::new(multivault_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(token_whitelist_pubkey, false),
         ],
         data,
     };
 
+    if let Some(schema_id) = payload_schema {
+        ix.accounts.push(AccountMeta::new_readonly(
+            token_proxy::get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    if let Some(page) = deposit_index_page {
+        ix.accounts
+            .extend(token_proxy::deposit_index_accounts(&author_pubkey, page));
+    }
+
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "executePayloadSol")]
-pub fn execute_payload_sol_ix(
-    withdrawal_pubkey: String,
+#[wasm_bindgen(js_name = "depositMultiTokenSolExactOutput")]
+pub fn deposit_multi_token_sol_exact_output_ix(
+    funder_pubkey: String,
+    author_pubkey: String,
+    author_token_pubkey: String,
+    mint_pubkey: String,
+    deposit_seed: String,
+    name: String,
+    symbol: String,
+    transfer_amount: String,
+    max_amount: u64,
     recipient_address: String,
-    mint_address: String,
-    recipient_token_pubkey: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    payload_schema: Option<u32>,
+    deposit_index_page: Option<u64>,
+    ever_configuration: Option<String>,
 ) -> Result<JsValue, JsValue> {
-    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
-    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
-    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
-    let proxy_address = token_proxy::get_proxy_address(&mint_address, &recipient_address);
-    let recipient_token_pubkey =
-        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+    let transfer_amount = u128::from_str(&transfer_amount).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::ExecutePayloadSol
-        .try_to_vec()
-        .expect("pack");
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let author_token_pubkey = Pubkey::from_str(author_token_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+    let ever_configuration = ever_configuration
+        .map(|ever_configuration| Pubkey::from_str(ever_configuration.as_str()))
+        .transpose()
+        .handle_error()?;
 
-    let ix = Instruction {
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+
+    let vault_pubkey =
+        token_proxy::get_namespaced_vault_address(&mint_pubkey, ever_configuration.as_ref());
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multivault_pubkey = token_proxy::get_multivault_address();
+    let token_settings_pubkey = token_proxy::get_namespaced_token_settings_sol_address(
+        &mint_pubkey,
+        ever_configuration.as_ref(),
+    );
+    let token_whitelist_pubkey = token_proxy::get_token_whitelist_address(&mint_pubkey);
+
+    let deposit_pubkey = token_proxy::get_namespaced_deposit_address(
+        &author_pubkey,
+        deposit_seed,
+        ever_configuration.as_ref(),
+    );
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::DepositMultiTokenSolExactOutput {
+        deposit_seed,
+        name,
+        symbol,
+        transfer_amount,
+        max_amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+        ever_configuration,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let mut ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(proxy_address, false),
-            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(vault_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(token_whitelist_pubkey, false),
         ],
         data,
     };
 
+    if let Some(schema_id) = payload_schema {
+        ix.accounts.push(AccountMeta::new_readonly(
+            token_proxy::get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    if let Some(page) = deposit_index_page {
+        ix.accounts
+            .extend(token_proxy::deposit_index_accounts(&author_pubkey, page));
+    }
+
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "executePayloadEver")]
-pub fn execute_payload_ever_ix(
-    withdrawal_pubkey: String,
+#[wasm_bindgen(js_name = "depositMultiTokenEverExactOutput")]
+pub fn deposit_multi_token_ever_exact_output_ix(
+    funder_pubkey: String,
+    author_pubkey: String,
+    author_token_pubkey: String,
+    token_address: String,
+    deposit_seed: String,
+    transfer_amount: String,
+    max_amount: u64,
     recipient_address: String,
-    mint_address: String,
-    recipient_token_pubkey: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    payload_schema: Option<u32>,
+    deposit_index_page: Option<u64>,
 ) -> Result<JsValue, JsValue> {
-    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
-    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
-    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
-    let proxy_address = token_proxy::get_proxy_address(&mint_address, &recipient_address);
-    let recipient_token_pubkey =
-        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+    let transfer_amount = u128::from_str(&transfer_amount).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::ExecutePayloadEver
-        .try_to_vec()
-        .expect("pack");
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+    let token = EverAddress::from_str(&token_address).handle_error()?;
+    let author_token_pubkey = Pubkey::from_str(author_token_pubkey.as_str()).handle_error()?;
 
-    let ix = Instruction {
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+
+    let mint_pubkey = token_proxy::get_mint_address(&token);
+    let settings_pubkey = token_proxy::get_settings_address();
+    let multivault_pubkey = token_proxy::get_multivault_address();
+    let token_settings_pubkey = token_proxy::get_token_settings_ever_address(&token);
+    let deposit_pubkey = token_proxy::get_deposit_address(&author_pubkey, deposit_seed);
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::DepositMultiTokenEverExactOutput {
+        deposit_seed,
+        transfer_amount,
+        max_amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let mut ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new(proxy_address, false),
-            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new(funder_pubkey, true),
+            AccountMeta::new(author_pubkey, true),
+            AccountMeta::new(author_token_pubkey, false),
+            AccountMeta::new(deposit_pubkey, false),
+            AccountMeta::new(mint_pubkey, false),
+            AccountMeta::new(multivault_pubkey, false),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(spl_token::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
         ],
         data,
     };
 
+    if let Some(schema_id) = payload_schema {
+        ix.accounts.push(AccountMeta::new_readonly(
+            token_proxy::get_payload_schema_address(schema_id),
+            false,
+        ));
+    }
+
+    if let Some(page) = deposit_index_page {
+        ix.accounts
+            .extend(token_proxy::deposit_index_accounts(&author_pubkey, page));
+    }
+
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "voteForWithdrawRequest")]
-pub fn vote_for_withdraw_request_ix(
-    authority_pubkey: String,
-    withdrawal_pubkey: String,
-    round_number: u32,
+#[wasm_bindgen(js_name = "gaslessDepositMessage")]
+pub fn gasless_deposit_message(
+    owner_pubkey: String,
+    owner_token_pubkey: String,
+    mint_pubkey: String,
+    deposit_seed: String,
+    amount: u64,
+    recipient_address: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    nonce: u64,
+    expiry: i64,
 ) -> Result<JsValue, JsValue> {
-    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let message = gasless_message(
+        owner_pubkey,
+        owner_token_pubkey,
+        mint_pubkey,
+        deposit_seed,
+        amount,
+        recipient_address,
+        value,
+        expected_evers,
+        &payload,
+        nonce,
+        expiry,
+    )?;
 
-    let relay_round_pubkey =
-        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let message = message.signing_payload(&token_proxy::id());
 
-    let data = token_proxy::TokenProxyInstruction::VoteForWithdrawRequest {
-        vote: Vote::Confirm,
-    }
-    .try_to_vec()
-    .handle_error()?;
+    return serde_wasm_bindgen::to_value(&general_purpose::STANDARD.encode(message)).handle_error();
+}
 
-    let ix = Instruction {
-        program_id: token_proxy::id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(withdrawal_pubkey, false),
-            AccountMeta::new_readonly(relay_round_pubkey, false),
-        ],
-        data,
+#[wasm_bindgen(js_name = "depositMultiTokenSolGasless")]
+pub fn deposit_multi_token_sol_gasless_ix(
+    funder_pubkey: String,
+    owner_pubkey: String,
+    owner_token_pubkey: String,
+    mint_pubkey: String,
+    deposit_seed: String,
+    name: String,
+    symbol: String,
+    amount: u64,
+    recipient_address: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    payload_schema: Option<u32>,
+    nonce: u64,
+    expiry: i64,
+    signature: String,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+
+    let message = gasless_message(
+        owner_pubkey,
+        owner_token_pubkey,
+        mint_pubkey,
+        deposit_seed,
+        amount,
+        recipient_address,
+        value,
+        expected_evers,
+        &payload,
+        nonce,
+        expiry,
+    )?;
+
+    let signature: [u8; bridge_utils::ed25519::SIGNATURE_BYTES] = general_purpose::STANDARD
+        .decode(signature)
+        .handle_error()?
+        .try_into()
+        .map_err(|_| "Invalid signature length")
+        .handle_error()?;
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let verify_ix = bridge_utils::ed25519::new_ed25519_instruction(
+        &message.owner,
+        &signature,
+        &message.signing_payload(&token_proxy::id()),
+    );
+
+    let deposit_ix = token_proxy::deposit_multi_token_sol_gasless_ix(
+        funder_pubkey,
+        &message,
+        name,
+        symbol,
+        payload,
+        payload_schema,
+    );
+
+    return serde_wasm_bindgen::to_value(&[verify_ix, deposit_ix]).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getNonceAddress")]
+pub fn get_nonce_address_request(owner_pubkey: String) -> Result<JsValue, JsValue> {
+    let owner_pubkey = Pubkey::from_str(owner_pubkey.as_str()).handle_error()?;
+    let nonce_pubkey = token_proxy::get_nonce_address(&owner_pubkey);
+    return serde_wasm_bindgen::to_value(&nonce_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getFreshNonce")]
+pub fn get_fresh_nonce(nonce_account_data: Option<Vec<u8>>) -> Result<JsValue, JsValue> {
+    let nonce = token_proxy::get_fresh_nonce(nonce_account_data.as_deref()).handle_error()?;
+    return serde_wasm_bindgen::to_value(&nonce.to_string()).handle_error();
+}
+
+fn gasless_message(
+    owner_pubkey: String,
+    owner_token_pubkey: String,
+    mint_pubkey: String,
+    deposit_seed: String,
+    amount: u64,
+    recipient_address: String,
+    value: u64,
+    expected_evers: u64,
+    payload: &str,
+    nonce: u64,
+    expiry: i64,
+) -> Result<token_proxy::GaslessDepositMessage, JsValue> {
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+
+    let owner = Pubkey::from_str(owner_pubkey.as_str()).handle_error()?;
+    let owner_token_account = Pubkey::from_str(owner_token_pubkey.as_str()).handle_error()?;
+    let mint = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    Ok(token_proxy::GaslessDepositMessage {
+        owner,
+        owner_token_account,
+        mint,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload_hash: solana_program::hash::hash(&payload).to_bytes(),
+        nonce,
+        expiry,
+    })
+}
+
+#[wasm_bindgen(js_name = "withdrawalMultiTokenSolUnwrap")]
+pub fn withdrawal_multi_token_sol_unwrap_ix(
+    withdrawal_pubkey: String,
+    recipient_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let recipient_pubkey = Pubkey::from_str(recipient_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::withdrawal_sol_unwrap_ix(withdrawal_pubkey, recipient_pubkey);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = "depositNativeSolToVault")]
+pub fn deposit_native_sol_to_vault_ix(
+    funder_pubkey: String,
+    author_pubkey: String,
+    deposit_seed: String,
+    amount: u64,
+    recipient_address: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    payload_schema: Option<u32>,
+    deposit_index_page: Option<u64>,
+) -> Result<JsValue, JsValue> {
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let mut ix = token_proxy::deposit_native_sol_ix(
+        funder_pubkey,
+        author_pubkey,
+        deposit_seed,
+        amount,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+    );
+
+    if let Some(page) = deposit_index_page {
+        ix.accounts
+            .extend(token_proxy::deposit_index_accounts(&author_pubkey, page));
+    }
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Deserialize)]
+pub struct DepositBatchEntry {
+    pub mint_pubkey: String,
+    pub amount: u64,
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = "depositMultiTokenBatch")]
+pub fn deposit_multi_token_batch_ix(
+    funder_pubkey: String,
+    author_pubkey: String,
+    deposit_seed: String,
+    entries: Vec<JsValue>,
+    recipient_address: String,
+    value: u64,
+    expected_evers: u64,
+    payload: String,
+    payload_schema: Option<u32>,
+    deposit_index_page: Option<u64>,
+) -> Result<JsValue, JsValue> {
+    let deposit_seed = uuid::Uuid::from_str(&deposit_seed)
+        .handle_error()?
+        .as_u128();
+
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let recipient = EverAddress::from_str(&recipient_address).handle_error()?;
+
+    let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
+
+    let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
+
+    let mut batch_entries = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let entry: DepositBatchEntry = serde_wasm_bindgen::from_value(entry).handle_error()?;
+        batch_entries.push(token_proxy::DepositBatchAmount {
+            mint: Pubkey::from_str(entry.mint_pubkey.as_str()).handle_error()?,
+            amount: entry.amount,
+        });
+    }
+
+    let mut ix = token_proxy::deposit_multi_token_batch_ix(
+        funder_pubkey,
+        author_pubkey,
+        deposit_seed,
+        batch_entries,
+        recipient,
+        value,
+        expected_evers,
+        payload,
+        payload_schema,
+    );
+
+    if let Some(page) = deposit_index_page {
+        ix.accounts
+            .extend(token_proxy::deposit_index_accounts(&author_pubkey, page));
+    }
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Deserialize)]
+pub struct WithdrawalBundleEntry {
+    pub mint_pubkey: String,
+    pub amount: String,
+}
+
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = "withdrawalMultiTokenBundleRequest")]
+pub fn withdrawal_multi_token_bundle_request_ix(
+    funder_pubkey: String,
+    author_pubkey: String,
+    chain_id: u32,
+    event_timestamp: u32,
+    event_transaction_lt: u64,
+    event_configuration: String,
+    recipient_address: String,
+    entries: Vec<JsValue>,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let recipient = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let event_configuration = Pubkey::from_str(event_configuration.as_str()).handle_error()?;
+
+    let mut bundle_entries = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let entry: WithdrawalBundleEntry = serde_wasm_bindgen::from_value(entry).handle_error()?;
+        bundle_entries.push(token_proxy::WithdrawalMultiTokenBundleEntry {
+            mint: Pubkey::from_str(entry.mint_pubkey.as_str()).handle_error()?,
+            amount: u128::from_str(&entry.amount).handle_error()?,
+        });
+    }
+
+    let ix = token_proxy::withdrawal_multi_token_bundle_request_ix(
+        funder_pubkey,
+        author_pubkey,
+        chain_id,
+        event_timestamp,
+        event_transaction_lt,
+        event_configuration,
+        round_number,
+        recipient,
+        bundle_entries,
+    );
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "withdrawalBundle")]
+pub fn withdrawal_bundle_ix(
+    withdrawal_pubkey: String,
+    recipient_address: String,
+    mints: Vec<String>,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let recipient = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let mints = mints
+        .iter()
+        .map(|mint| Pubkey::from_str(mint.as_str()))
+        .collect::<Result<Vec<_>, _>>()
+        .handle_error()?;
+
+    let ix = token_proxy::withdrawal_bundle_ix(withdrawal_pubkey, recipient, &mints);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "approveWithdrawalBundle")]
+pub fn approve_withdrawal_bundle(
+    authority_pubkey: String,
+    withdrawal_pubkey: String,
+    recipient_address: String,
+    mints: Vec<String>,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let recipient = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let mints = mints
+        .iter()
+        .map(|mint| Pubkey::from_str(mint.as_str()))
+        .collect::<Result<Vec<_>, _>>()
+        .handle_error()?;
+
+    let ix = token_proxy::approve_withdrawal_bundle_ix(
+        authority_pubkey,
+        withdrawal_pubkey,
+        recipient,
+        &mints,
+    );
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "executePayloadSol")]
+pub fn execute_payload_sol_ix(
+    withdrawal_pubkey: String,
+    recipient_address: String,
+    mint_address: String,
+    recipient_token_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
+    let proxy_address = token_proxy::get_proxy_address(&mint_address, &recipient_address);
+    let recipient_token_pubkey =
+        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ExecutePayloadSol
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(proxy_address, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "executePayloadEver")]
+pub fn execute_payload_ever_ix(
+    withdrawal_pubkey: String,
+    recipient_address: String,
+    mint_address: String,
+    recipient_token_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let recipient_address = Pubkey::from_str(recipient_address.as_str()).handle_error()?;
+    let mint_address = Pubkey::from_str(mint_address.as_str()).handle_error()?;
+    let proxy_address = token_proxy::get_proxy_address(&mint_address, &recipient_address);
+    let recipient_token_pubkey =
+        Pubkey::from_str(recipient_token_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ExecutePayloadEver
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new(proxy_address, false),
+            AccountMeta::new(recipient_token_pubkey, false),
+            AccountMeta::new_readonly(spl_token::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "voteForWithdrawRequest")]
+pub fn vote_for_withdraw_request_ix(
+    authority_pubkey: String,
+    withdrawal_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+
+    let relay_round_pubkey =
+        bridge_utils::helper::get_associated_relay_round_address(&round_loader::id(), round_number);
+    let relay_round_stats_pubkey = token_proxy::get_relay_round_stats_address(round_number);
+
+    let data = token_proxy::TokenProxyInstruction::VoteForWithdrawRequest {
+        vote: Vote::Confirm,
+    }
+    .try_to_vec()
+    .handle_error()?;
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(withdrawal_pubkey, false),
+            AccountMeta::new_readonly(relay_round_pubkey, false),
+            AccountMeta::new_readonly(token_settings_pubkey, false),
+            AccountMeta::new(relay_round_stats_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeGuardian")]
+pub fn change_guardian_ix(
+    authority_pubkey: String,
+    new_guardian: String,
+    cosigners: JsValue,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let new_guardian = Pubkey::from_str(new_guardian.as_str()).handle_error()?;
+    let cosigners: Vec<Pubkey> = serde_wasm_bindgen::from_value(cosigners).handle_error()?;
+
+    let ix =
+        token_proxy::change_guardian_with_cosigners_ix(authority_pubkey, new_guardian, &cosigners);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "guardianHeartbeat")]
+pub fn guardian_heartbeat_ix(guardian_pubkey: String) -> Result<JsValue, JsValue> {
+    let guardian_pubkey = Pubkey::from_str(guardian_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::guardian_heartbeat_ix(guardian_pubkey);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "expandSettings")]
+pub fn expand_settings_ix(
+    funder_pubkey: String,
+    authority_pubkey: String,
+    chain_id: u32,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::expand_settings_ix(funder_pubkey, authority_pubkey, chain_id);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "grantRole")]
+pub fn grant_role_ix(
+    authority_pubkey: String,
+    role: JsValue,
+    role_authority: String,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let role: token_proxy::Role = serde_wasm_bindgen::from_value(role).handle_error()?;
+    let role_authority = Pubkey::from_str(role_authority.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::GrantRole {
+        role,
+        authority: role_authority,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "revokeRole")]
+pub fn revoke_role_ix(
+    authority_pubkey: String,
+    role: JsValue,
+    role_authority: String,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let role: token_proxy::Role = serde_wasm_bindgen::from_value(role).handle_error()?;
+    let role_authority = Pubkey::from_str(role_authority.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::RevokeRole {
+        role,
+        authority: role_authority,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "addTokenToWhitelist")]
+pub fn add_token_to_whitelist_ix(
+    authority_pubkey: String,
+    mint_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let token_whitelist_pubkey = token_proxy::get_token_whitelist_address(&mint_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::AddTokenToWhitelist { mint: mint_pubkey }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_whitelist_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "removeTokenFromWhitelist")]
+pub fn remove_token_from_whitelist_ix(
+    authority_pubkey: String,
+    mint_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
+
+    let token_whitelist_pubkey = token_proxy::get_token_whitelist_address(&mint_pubkey);
+
+    let data = token_proxy::TokenProxyInstruction::RemoveTokenFromWhitelist { mint: mint_pubkey }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_whitelist_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeDepositWhitelistMode")]
+pub fn change_deposit_whitelist_mode_ix(
+    authority_pubkey: String,
+    restricted: bool,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeDepositWhitelistMode { restricted }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeWithdrawalManager")]
+pub fn change_withdrawal_manager_ix(
+    authority_pubkey: String,
+    new_withdrawal_manager: String,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let new_withdrawal_manager =
+        Pubkey::from_str(new_withdrawal_manager.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeWithdrawalManager {
+        new_withdrawal_manager,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeDepositLimit")]
+pub fn change_deposit_limit_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    new_deposit_limit: u64,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeRequiredVotes")]
+pub fn change_required_votes_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    required_votes_override: Option<u32>,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangeRequiredVotes {
+        required_votes_override,
+    }
+    .try_to_vec()
+    .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeConfirmationTiers")]
+pub fn change_confirmation_tiers_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    confirmation_tiers: JsValue,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let confirmation_tiers: Vec<token_proxy::ConfirmationTier> =
+        serde_wasm_bindgen::from_value(confirmation_tiers).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeConfirmationTiers { confirmation_tiers }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
+    };
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changePausedOperations")]
+pub fn change_paused_operations_ix(
+    authority_pubkey: String,
+    token: String,
+    token_is_sol: bool,
+    paused_operations: u8,
+) -> Result<JsValue, JsValue> {
+    let settings_pubkey = token_proxy::get_settings_address();
+
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let token_settings_pubkey = if token_is_sol {
+        let mint = Pubkey::from_str(token.as_str()).handle_error()?;
+        token_proxy::get_token_settings_sol_address(&mint)
+    } else {
+        let token = EverAddress::from_str(&token).handle_error()?;
+        token_proxy::get_token_settings_ever_address(&token)
+    };
+    let program_data_pubkey = token_proxy::get_programdata_address();
+
+    let data = token_proxy::TokenProxyInstruction::ChangePausedOperations { paused_operations }
+        .try_to_vec()
+        .expect("pack");
+
+    let ix = Instruction {
+        program_id: token_proxy::id(),
+        accounts: vec![
+            AccountMeta::new(authority_pubkey, true),
+            AccountMeta::new(token_settings_pubkey, false),
+            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new_readonly(program_data_pubkey, false),
+        ],
+        data,
     };
 
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeGuardian")]
-pub fn change_guardian_ix(
+#[wasm_bindgen(js_name = "setPayloadSchema")]
+pub fn set_payload_schema_ix(
+    authority_pubkey: String,
+    schema_id: u32,
+    min_len: u32,
+    max_len: u32,
+    header_len: u32,
+    header_hash: Option<String>,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let header_hash = header_hash
+        .map(|hash| Hash::from_str(&hash).map(|hash| hash.to_bytes()))
+        .transpose()
+        .handle_error()?;
+
+    let ix = token_proxy::set_payload_schema_ix(
+        authority_pubkey,
+        schema_id,
+        min_len,
+        max_len,
+        header_len,
+        header_hash,
+    );
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "setKnownToken")]
+pub fn set_known_token_ix(
+    authority_pubkey: String,
+    token_address: String,
+    name: String,
+    symbol: String,
+    decimals: u8,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let token = EverAddress::from_str(&token_address).handle_error()?;
+
+    let ix = token_proxy::set_known_token_ix(authority_pubkey, token, name, symbol, decimals);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "setExemptAccount")]
+pub fn set_exempt_account_ix(
     authority_pubkey: String,
-    new_guardian: String,
+    exempt_authority: String,
+    expires_at: i64,
 ) -> Result<JsValue, JsValue> {
-    let settings_pubkey = token_proxy::get_settings_address();
-    let program_data_pubkey = token_proxy::get_programdata_address();
-
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let new_guardian = Pubkey::from_str(new_guardian.as_str()).handle_error()?;
+    let exempt_authority = Pubkey::from_str(exempt_authority.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::ChangeGuardian { new_guardian }
-        .try_to_vec()
-        .expect("pack");
-
-    let ix = Instruction {
-        program_id: token_proxy::id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
-        data,
-    };
+    let ix = token_proxy::set_exempt_account_ix(authority_pubkey, exempt_authority, expires_at);
 
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeWithdrawalManager")]
-pub fn change_withdrawal_manager_ix(
+#[wasm_bindgen(js_name = "updateDiscountConfig")]
+pub fn update_discount_config_ix(
     authority_pubkey: String,
-    new_withdrawal_manager: String,
+    mint_pubkey: String,
+    min_stake: u64,
+    discount_bps: u16,
+    unstake_cooldown: i64,
 ) -> Result<JsValue, JsValue> {
-    let settings_pubkey = token_proxy::get_settings_address();
-    let program_data_pubkey = token_proxy::get_programdata_address();
-
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-    let new_withdrawal_manager =
-        Pubkey::from_str(new_withdrawal_manager.as_str()).handle_error()?;
+    let mint_pubkey = Pubkey::from_str(mint_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::ChangeWithdrawalManager {
-        new_withdrawal_manager,
-    }
-    .try_to_vec()
-    .expect("pack");
+    let ix = token_proxy::update_discount_config_ix(
+        authority_pubkey,
+        mint_pubkey,
+        min_stake,
+        discount_bps,
+        unstake_cooldown,
+    );
 
-    let ix = Instruction {
-        program_id: token_proxy::id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
-        data,
-    };
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "stakeForDiscount")]
+pub fn stake_for_discount_ix(
+    owner_pubkey: String,
+    owner_token_pubkey: String,
+    amount: u64,
+) -> Result<JsValue, JsValue> {
+    let owner_pubkey = Pubkey::from_str(owner_pubkey.as_str()).handle_error()?;
+    let owner_token_pubkey = Pubkey::from_str(owner_token_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::stake_for_discount_ix(owner_pubkey, owner_token_pubkey, amount);
 
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeDepositLimit")]
-pub fn change_deposit_limit_ix(
+#[wasm_bindgen(js_name = "requestDiscountUnstake")]
+pub fn request_discount_unstake_ix(owner_pubkey: String, amount: u64) -> Result<JsValue, JsValue> {
+    let owner_pubkey = Pubkey::from_str(owner_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::request_discount_unstake_ix(owner_pubkey, amount);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "withdrawDiscountStake")]
+pub fn withdraw_discount_stake_ix(
+    owner_pubkey: String,
+    owner_token_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let owner_pubkey = Pubkey::from_str(owner_pubkey.as_str()).handle_error()?;
+    let owner_token_pubkey = Pubkey::from_str(owner_token_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::withdraw_discount_stake_ix(owner_pubkey, owner_token_pubkey);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "changeWithdrawalLimits")]
+pub fn change_withdrawal_limits_ix(
     authority_pubkey: String,
     token: String,
     token_is_sol: bool,
-    new_deposit_limit: u64,
+    new_withdrawal_limit: Option<u64>,
+    new_withdrawal_daily_limit: Option<u64>,
 ) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
 
-    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
-
     let token_settings_pubkey = if token_is_sol {
         let mint = Pubkey::from_str(token.as_str()).handle_error()?;
         token_proxy::get_token_settings_sol_address(&mint)
@@ -751,16 +1903,21 @@ pub fn change_deposit_limit_ix(
     };
     let program_data_pubkey = token_proxy::get_programdata_address();
 
-    let data = token_proxy::TokenProxyInstruction::ChangeDepositLimit { new_deposit_limit }
-        .try_to_vec()
-        .expect("pack");
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let data = token_proxy::TokenProxyInstruction::ChangeWithdrawalLimits {
+        new_withdrawal_limit,
+        new_withdrawal_daily_limit,
+    }
+    .try_to_vec()
+    .expect("pack");
 
     let ix = Instruction {
         program_id: token_proxy::id(),
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
@@ -769,13 +1926,14 @@ pub fn change_deposit_limit_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
-#[wasm_bindgen(js_name = "changeWithdrawalLimits")]
-pub fn change_withdrawal_limits_ix(
+#[wasm_bindgen(js_name = "scheduleLimitChange")]
+pub fn schedule_limit_change_ix(
     authority_pubkey: String,
     token: String,
     token_is_sol: bool,
     new_withdrawal_limit: Option<u64>,
     new_withdrawal_daily_limit: Option<u64>,
+    effective_at: i64,
 ) -> Result<JsValue, JsValue> {
     let settings_pubkey = token_proxy::get_settings_address();
 
@@ -790,9 +1948,10 @@ pub fn change_withdrawal_limits_ix(
 
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
 
-    let data = token_proxy::TokenProxyInstruction::ChangeWithdrawalLimits {
+    let data = token_proxy::TokenProxyInstruction::ScheduleLimitChange {
         new_withdrawal_limit,
         new_withdrawal_daily_limit,
+        effective_at,
     }
     .try_to_vec()
     .expect("pack");
@@ -802,7 +1961,7 @@ pub fn change_withdrawal_limits_ix(
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(program_data_pubkey, false),
         ],
         data,
@@ -858,25 +2017,76 @@ pub fn enable_emergency_by_owner_ix(authority_pubkey: String) -> Result<JsValue,
 }
 
 #[wasm_bindgen(js_name = "disableEmergency")]
-pub fn disable_emergency_ix(authority_pubkey: String) -> Result<JsValue, JsValue> {
-    let settings_pubkey = token_proxy::get_settings_address();
-    let program_data_pubkey = token_proxy::get_programdata_address();
+pub fn disable_emergency_ix(
+    authority_pubkey: String,
+    cosigners: JsValue,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let cosigners: Vec<Pubkey> = serde_wasm_bindgen::from_value(cosigners).handle_error()?;
+
+    let ix = token_proxy::disable_emergency_with_cosigners_ix(authority_pubkey, &cosigners);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
 
+#[wasm_bindgen(js_name = "setEmergencyCosigners")]
+pub fn set_emergency_cosigners_ix(
+    authority_pubkey: String,
+    cosigners: JsValue,
+    threshold: u8,
+    current_cosigners: JsValue,
+) -> Result<JsValue, JsValue> {
     let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let cosigners: Vec<Pubkey> = serde_wasm_bindgen::from_value(cosigners).handle_error()?;
+    let current_cosigners: Vec<Pubkey> =
+        serde_wasm_bindgen::from_value(current_cosigners).handle_error()?;
+
+    let ix = token_proxy::set_emergency_cosigners_ix(
+        authority_pubkey,
+        cosigners,
+        threshold,
+        &current_cosigners,
+    );
 
-    let data = token_proxy::TokenProxyInstruction::DisableEmergencyMode
-        .try_to_vec()
-        .expect("pack");
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
 
-    let ix = Instruction {
-        program_id: token_proxy::id(),
-        accounts: vec![
-            AccountMeta::new(authority_pubkey, true),
-            AccountMeta::new(settings_pubkey, false),
-            AccountMeta::new_readonly(program_data_pubkey, false),
-        ],
-        data,
-    };
+#[wasm_bindgen(js_name = "recoverAuthority")]
+pub fn recover_authority_ix(
+    authority_pubkey: String,
+    mint: String,
+    ever_configuration: Option<String>,
+    vault_generation: u8,
+    new_program_id: String,
+    cosigners: JsValue,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+    let mint = Pubkey::from_str(mint.as_str()).handle_error()?;
+    let ever_configuration = ever_configuration
+        .map(|ever_configuration| Pubkey::from_str(ever_configuration.as_str()))
+        .transpose()
+        .handle_error()?;
+    let new_program_id = Pubkey::from_str(new_program_id.as_str()).handle_error()?;
+    let cosigners: Vec<Pubkey> = serde_wasm_bindgen::from_value(cosigners).handle_error()?;
+
+    let ix = token_proxy::recover_authority_ix(
+        authority_pubkey,
+        mint,
+        ever_configuration.as_ref(),
+        vault_generation,
+        new_program_id,
+        &cosigners,
+    );
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "auditSettings")]
+pub fn audit_settings_ix(token_settings_pubkeys: JsValue) -> Result<JsValue, JsValue> {
+    let token_settings_pubkeys: Vec<Pubkey> =
+        serde_wasm_bindgen::from_value(token_settings_pubkeys).handle_error()?;
+
+    let ix = token_proxy::audit_settings_ix(&token_settings_pubkeys);
 
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
@@ -1097,7 +2307,7 @@ pub fn update_fee(
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
         ],
         data,
     };
@@ -1129,7 +2339,7 @@ pub fn update_token_name(
         accounts: vec![
             AccountMeta::new(authority_pubkey, true),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
         ],
         data,
     };
@@ -1167,7 +2377,7 @@ pub fn approve_withdrawal_ever(
             AccountMeta::new(withdrawal_pubkey, false),
             AccountMeta::new(recipient_token_pubkey, false),
             AccountMeta::new(token_settings_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(spl_token::id(), false),
             AccountMeta::new_readonly(sysvar::clock::id(), false),
         ],
@@ -1240,7 +2450,7 @@ pub fn cancel_withdrawal_sol(
 
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
-    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let deposit_pubkey = token_proxy::get_deposit_address(&author_pubkey, deposit_seed);
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
 
     let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
@@ -1265,7 +2475,7 @@ pub fn cancel_withdrawal_sol(
             AccountMeta::new(mint_pubkey, false),
             AccountMeta::new(withdrawal_pubkey, false),
             AccountMeta::new(deposit_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(token_settings_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(sysvar::rent::id(), false),
@@ -1312,7 +2522,7 @@ pub fn fill_withdrawal_sol(
 
     let settings_pubkey = token_proxy::get_settings_address();
     let multivault_pubkey = token_proxy::get_multivault_address();
-    let deposit_pubkey = token_proxy::get_deposit_address(deposit_seed);
+    let deposit_pubkey = token_proxy::get_deposit_address(&author_pubkey, deposit_seed);
     let token_settings_pubkey = token_proxy::get_token_settings_sol_address(&mint_pubkey);
 
     let expected_evers = UInt256::from_be_bytes(expected_evers.to_be_bytes().as_slice());
@@ -1337,7 +2547,7 @@ pub fn fill_withdrawal_sol(
             AccountMeta::new(author_token_pubkey, false),
             AccountMeta::new(mint_pubkey, false),
             AccountMeta::new(deposit_pubkey, false),
-            AccountMeta::new_readonly(settings_pubkey, false),
+            AccountMeta::new(settings_pubkey, false),
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta::new_readonly(token_settings_pubkey, false),
             AccountMeta::new_readonly(spl_token::id(), false),
@@ -1391,6 +2601,25 @@ pub fn change_bounty_for_withdrawal_sol_ix(
     return serde_wasm_bindgen::to_value(&ix).handle_error();
 }
 
+#[wasm_bindgen(js_name = "transferWithdrawalAuthorship")]
+pub fn transfer_withdrawal_authorship_ix(
+    author_pubkey: String,
+    withdrawal_pubkey: String,
+    new_author: String,
+) -> Result<JsValue, JsValue> {
+    let author_pubkey = Pubkey::from_str(author_pubkey.as_str()).handle_error()?;
+    let withdrawal_pubkey = Pubkey::from_str(withdrawal_pubkey.as_str()).handle_error()?;
+    let new_author = Pubkey::from_str(new_author.as_str()).handle_error()?;
+
+    let ix = token_proxy::transfer_withdrawal_authorship_ix(
+        &author_pubkey,
+        &withdrawal_pubkey,
+        new_author,
+    );
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
 #[wasm_bindgen(js_name = "withdrawalMultiVault")]
 pub fn withdrawal_multi_vault_ix(
     authority_pubkey: String,
@@ -1502,9 +2731,12 @@ pub fn unpack_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
 
     let s = WasmSettings {
         emergency: settings.emergency,
-        guardian: settings.guardian,
-        withdrawal_manager: settings.withdrawal_manager,
-        manager: settings.manager,
+        chain_id: settings.chain_id,
+        roles: settings.roles,
+        permissions: settings.permissions,
+        emergency_cosigners: settings.emergency_cosigners,
+        emergency_cosigners_threshold: settings.emergency_cosigners_threshold,
+        guardian_last_active: settings.guardian_last_active,
     };
 
     return serde_wasm_bindgen::to_value(&s).handle_error();
@@ -1512,6 +2744,7 @@ pub fn unpack_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
 
 #[wasm_bindgen(js_name = "getProposalSolAddress")]
 pub fn get_proposal_sol_address(
+    chain_id: u32,
     round_number: u32,
     event_timestamp: u32,
     event_transaction_lt: u64,
@@ -1530,6 +2763,7 @@ pub fn get_proposal_sol_address(
     let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
 
     let withdrawal_pubkey = token_proxy::get_withdrawal_sol_address(
+        chain_id,
         round_number,
         event_timestamp,
         event_transaction_lt,
@@ -1556,6 +2790,7 @@ pub fn get_proxy_address_payload(
 
 #[wasm_bindgen(js_name = "getProposalEverAddress")]
 pub fn get_proposal_ever_address(
+    chain_id: u32,
     round_number: u32,
     event_timestamp: u32,
     event_transaction_lt: u64,
@@ -1577,6 +2812,7 @@ pub fn get_proposal_ever_address(
     let payload = general_purpose::STANDARD.decode(payload).handle_error()?;
 
     let withdrawal_pubkey = token_proxy::get_withdrawal_ever_address(
+        chain_id,
         round_number,
         event_timestamp,
         event_transaction_lt,
@@ -1612,6 +2848,10 @@ pub fn unpack_token_settings(data: Vec<u8>) -> Result<JsValue, JsValue> {
         fee_supply: token_settings.fee_supply,
         fee_deposit_info: token_settings.fee_deposit_info,
         fee_withdrawal_info: token_settings.fee_withdrawal_info,
+        hook_program: token_settings.hook_program,
+        paused_operations: token_settings.paused_operations,
+        scheduled_limit_change: token_settings.scheduled_limit_change,
+        ever_configuration: token_settings.ever_configuration,
     };
 
     return serde_wasm_bindgen::to_value(&s).handle_error();
@@ -1665,6 +2905,7 @@ pub fn unpack_deposit_ever(data: Vec<u8>) -> Result<JsValue, JsValue> {
         event: deposit.event,
         meta: WasmDepositTokenMeta {
             seed: deposit.meta.data.seed.to_string(),
+            ever_configuration: deposit.meta.data.ever_configuration,
         },
     };
 
@@ -1681,18 +2922,222 @@ pub fn unpack_deposit_sol(data: Vec<u8>) -> Result<JsValue, JsValue> {
         event: deposit.event,
         meta: WasmDepositTokenMeta {
             seed: deposit.meta.data.seed.to_string(),
+            ever_configuration: deposit.meta.data.ever_configuration,
         },
     };
 
     return serde_wasm_bindgen::to_value(&d).handle_error();
 }
 
+#[wasm_bindgen(js_name = "createDepositIndex")]
+pub fn create_deposit_index_ix(
+    funder_pubkey: String,
+    owner_pubkey: String,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let owner_pubkey = Pubkey::from_str(owner_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::create_deposit_index_ix(funder_pubkey, owner_pubkey);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getDepositIndexAddress")]
+pub fn get_deposit_index_address(owner_pubkey: String) -> Result<JsValue, JsValue> {
+    let owner_pubkey = Pubkey::from_str(owner_pubkey.as_str()).handle_error()?;
+
+    let deposit_index_pubkey = token_proxy::get_deposit_index_address(&owner_pubkey);
+
+    return serde_wasm_bindgen::to_value(&deposit_index_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getDepositIndexPageAddresses")]
+pub fn get_deposit_index_page_addresses(
+    owner_pubkey: String,
+    offset: u64,
+    limit: u64,
+) -> Result<JsValue, JsValue> {
+    let owner_pubkey = Pubkey::from_str(owner_pubkey.as_str()).handle_error()?;
+
+    let page_pubkeys = token_proxy::get_deposit_index_page_addresses(&owner_pubkey, offset, limit);
+
+    return serde_wasm_bindgen::to_value(&page_pubkeys).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackDepositIndex")]
+pub fn unpack_deposit_index(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let deposit_index = token_proxy::DepositIndex::unpack(&data).handle_error()?;
+
+    let d = WasmDepositIndex {
+        owner: deposit_index.owner,
+        total: deposit_index.total.to_string(),
+        current_page: deposit_index.current_page().to_string(),
+    };
+
+    return serde_wasm_bindgen::to_value(&d).handle_error();
+}
+
+#[wasm_bindgen(js_name = "unpackDepositIndexPage")]
+pub fn unpack_deposit_index_page(data: Vec<u8>) -> Result<JsValue, JsValue> {
+    let deposit_index_page = token_proxy::DepositIndexPage::unpack(&data).handle_error()?;
+
+    let d = WasmDepositIndexPage {
+        owner: deposit_index_page.owner,
+        page: deposit_index_page.page.to_string(),
+        deposits: deposit_index_page.deposits,
+    };
+
+    return serde_wasm_bindgen::to_value(&d).handle_error();
+}
+
+#[wasm_bindgen(js_name = "buildWithdrawalReceipt")]
+pub fn build_withdrawal_receipt(
+    withdrawal_address: String,
+    withdrawal_data: Vec<u8>,
+    relay_round_data: Vec<u8>,
+    slot: u64,
+) -> Result<JsValue, JsValue> {
+    let withdrawal_address = Pubkey::from_str(withdrawal_address.as_str()).handle_error()?;
+
+    let receipt = token_proxy::build_withdrawal_receipt(
+        withdrawal_address,
+        &withdrawal_data,
+        &relay_round_data,
+        slot,
+    )
+    .handle_error()?;
+
+    return serde_wasm_bindgen::to_value(&receipt).handle_error();
+}
+
+#[wasm_bindgen(js_name = "packWithdrawalReceipt")]
+pub fn pack_withdrawal_receipt(receipt: JsValue) -> Result<String, JsValue> {
+    let receipt: token_proxy::WithdrawalReceipt =
+        serde_wasm_bindgen::from_value(receipt).handle_error()?;
+
+    let data = receipt.try_to_vec().handle_error()?;
+
+    return Ok(general_purpose::STANDARD.encode(data));
+}
+
+#[wasm_bindgen(js_name = "attestReceipt")]
+pub fn attest_receipt_ix(receipt: JsValue) -> Result<JsValue, JsValue> {
+    let receipt: token_proxy::WithdrawalReceipt =
+        serde_wasm_bindgen::from_value(receipt).handle_error()?;
+
+    let ix = token_proxy::attest_receipt_ix(receipt);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "updateRewardConfig")]
+pub fn update_reward_config_ix(
+    authority_pubkey: String,
+    relay_share: u8,
+) -> Result<JsValue, JsValue> {
+    let authority_pubkey = Pubkey::from_str(authority_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::update_reward_config_ix(authority_pubkey, relay_share);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "createRelayRoundStats")]
+pub fn create_relay_round_stats_ix(
+    funder_pubkey: String,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::create_relay_round_stats_ix(funder_pubkey, round_number);
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getRelayRoundStatsAddress")]
+pub fn get_relay_round_stats_address(round_number: u32) -> Result<JsValue, JsValue> {
+    let relay_round_stats_pubkey = token_proxy::get_relay_round_stats_address(round_number);
+
+    return serde_wasm_bindgen::to_value(&relay_round_stats_pubkey).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getRelayVoteCounts")]
+pub fn get_relay_vote_counts(
+    relay_round_stats_data: Vec<u8>,
+    relay_round_data: Vec<u8>,
+) -> Result<JsValue, JsValue> {
+    let vote_counts =
+        token_proxy::get_relay_vote_counts(&relay_round_stats_data, &relay_round_data)
+            .handle_error()?
+            .into_iter()
+            .map(|(relay, votes)| WasmRelayVotes { relay, votes })
+            .collect::<Vec<_>>();
+
+    return serde_wasm_bindgen::to_value(&vote_counts).handle_error();
+}
+
+#[wasm_bindgen(js_name = "getInactiveRelays")]
+pub fn get_inactive_relays(
+    relay_round_stats_data: Vec<u8>,
+    relay_round_data: Vec<u8>,
+    min_votes: u32,
+) -> Result<JsValue, JsValue> {
+    let relays =
+        token_proxy::get_inactive_relays(&relay_round_stats_data, &relay_round_data, min_votes)
+            .handle_error()?;
+
+    return serde_wasm_bindgen::to_value(&relays).handle_error();
+}
+
+#[wasm_bindgen(js_name = "distributeRoundRewards")]
+pub fn distribute_round_rewards_ix(
+    funder_pubkey: String,
+    token_settings_pubkey: String,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let funder_pubkey = Pubkey::from_str(funder_pubkey.as_str()).handle_error()?;
+    let token_settings_pubkey = Pubkey::from_str(token_settings_pubkey.as_str()).handle_error()?;
+
+    let ix = token_proxy::distribute_round_rewards_ix(
+        funder_pubkey,
+        token_settings_pubkey,
+        round_number,
+    );
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
+#[wasm_bindgen(js_name = "claimRelayReward")]
+pub fn claim_relay_reward_ix(
+    relay_pubkey: String,
+    relay_token_pubkey: String,
+    token_settings_data: Vec<u8>,
+    round_number: u32,
+) -> Result<JsValue, JsValue> {
+    let relay_pubkey = Pubkey::from_str(relay_pubkey.as_str()).handle_error()?;
+    let relay_token_pubkey = Pubkey::from_str(relay_token_pubkey.as_str()).handle_error()?;
+
+    let token_settings = token_proxy::TokenSettings::unpack(&token_settings_data).handle_error()?;
+
+    let ix = token_proxy::claim_relay_reward_ix(
+        relay_pubkey,
+        relay_token_pubkey,
+        &token_settings.kind,
+        round_number,
+    );
+
+    return serde_wasm_bindgen::to_value(&ix).handle_error();
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct WasmSettings {
     pub emergency: bool,
-    pub guardian: Pubkey,
-    pub withdrawal_manager: Pubkey,
-    pub manager: Pubkey,
+    pub chain_id: u32,
+    pub roles: Vec<token_proxy::RoleMember>,
+    pub permissions: Vec<token_proxy::InstructionPermission>,
+    pub emergency_cosigners: Vec<Pubkey>,
+    pub emergency_cosigners_threshold: u8,
+    pub guardian_last_active: i64,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1711,6 +3156,10 @@ pub struct WasmTokenSettings {
     pub fee_supply: u64,
     pub fee_deposit_info: token_proxy::FeeInfo,
     pub fee_withdrawal_info: token_proxy::FeeInfo,
+    pub hook_program: Option<Pubkey>,
+    pub paused_operations: u8,
+    pub scheduled_limit_change: Option<token_proxy::ScheduledLimitChange>,
+    pub ever_configuration: Option<Pubkey>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -1755,9 +3204,30 @@ pub struct WasmDepositMultiTokenSol {
     pub meta: WasmDepositTokenMeta,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct WasmDepositIndex {
+    pub owner: Pubkey,
+    pub total: String,
+    pub current_page: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmDepositIndexPage {
+    pub owner: Pubkey,
+    pub page: String,
+    pub deposits: Vec<Pubkey>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WasmRelayVotes {
+    pub relay: Pubkey,
+    pub votes: u32,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct WasmDepositTokenMeta {
     pub seed: String,
+    pub ever_configuration: Option<Pubkey>,
 }
 
 #[derive(Serialize, Deserialize)]